@@ -0,0 +1,796 @@
+//! Tree-sitter parsing and structural extraction
+
+use crate::language::SourceLanguage;
+use crate::model::*;
+use brain_types::*;
+use std::path::Path;
+use tree_sitter::{Node, Parser, Tree};
+
+const HTTP_METHODS: [&str; 7] = ["get", "post", "put", "delete", "patch", "head", "options"];
+const MODULE_SCOPE: &str = "<module>";
+
+/// Parses source files and extracts functions, types, imports and call edges
+#[derive(Debug, Clone, Default)]
+pub struct CodeAnalyzer;
+
+impl CodeAnalyzer {
+    /// Create new analyzer
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Analyze source text in a known language
+    pub fn analyze_source(&self, source: &str, language: SourceLanguage) -> Result<FileAnalysis> {
+        self.analyze(source, language, None)
+    }
+
+    /// Analyze source text, attributing the results to `path`
+    pub fn analyze_source_at(&self, source: &str, language: SourceLanguage, path: &str) -> Result<FileAnalysis> {
+        self.analyze(source, language, Some(path.to_string()))
+    }
+
+    /// Read and analyze a file, picking the grammar from its extension
+    pub fn analyze_file<P: AsRef<Path>>(&self, path: P) -> Result<FileAnalysis> {
+        let path = path.as_ref();
+        let language = SourceLanguage::from_path(path).ok_or_else(|| {
+            BrainError::InvalidInput(format!("Unsupported source file: {}", path.display()))
+        })?;
+        let source = std::fs::read_to_string(path)?;
+        self.analyze(&source, language, Some(path.display().to_string()))
+    }
+
+    /// Pick a language from the file path, falling back to the grammar that parses the source with the fewest errors
+    pub fn detect_language(&self, source: &str, path: Option<&str>) -> Result<SourceLanguage> {
+        if let Some(language) = path.and_then(SourceLanguage::from_path) {
+            return Ok(language);
+        }
+
+        let mut best: Option<(usize, SourceLanguage)> = None;
+        for language in SourceLanguage::ALL {
+            let errors = count_syntax_errors(self.parse(source, language)?.root_node());
+            if best.is_none_or(|(fewest, _)| errors < fewest) {
+                best = Some((errors, language));
+            }
+        }
+
+        Ok(best.map(|(_, language)| language).unwrap_or(SourceLanguage::Rust))
+    }
+
+    fn parse(&self, source: &str, language: SourceLanguage) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(language.grammar())
+            .map_err(|e| BrainError::ConfigError(format!("Failed to load {} grammar: {}", language, e)))?;
+        parser.parse(source, None)
+            .ok_or_else(|| BrainError::ParseError(format!("Failed to parse {} source", language)))
+    }
+
+    fn analyze(&self, source: &str, language: SourceLanguage, path: Option<String>) -> Result<FileAnalysis> {
+        let tree = self.parse(source, language)?;
+
+        let mut extractor = Extractor {
+            source: source.as_bytes(),
+            language,
+            analysis: FileAnalysis::new(language, path),
+            impl_traits: Vec::new(),
+        };
+        extractor.visit(tree.root_node(), &Scope::default());
+
+        let mut analysis = extractor.finish();
+        analysis.line_count = source.lines().count();
+        Ok(analysis)
+    }
+}
+
+fn count_syntax_errors(node: Node) -> usize {
+    let own = usize::from(node.is_error() || node.is_missing());
+    if !node.has_error() {
+        return own;
+    }
+    let mut cursor = node.walk();
+    let children: usize = node.children(&mut cursor).map(count_syntax_errors).sum();
+    own + children
+}
+
+/// Enclosing definitions while walking the tree
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    /// Impl, trait or class being walked
+    container: Option<String>,
+    /// Qualified name of the function being walked
+    function: Option<String>,
+}
+
+struct Extractor<'a> {
+    source: &'a [u8],
+    language: SourceLanguage,
+    analysis: FileAnalysis,
+    /// `(type, trait)` pairs from Rust impl blocks, applied once all types are known
+    impl_traits: Vec<(String, String)>,
+}
+
+impl<'a> Extractor<'a> {
+    fn visit(&mut self, node: Node, scope: &Scope) {
+        if node.is_error() || node.is_missing() {
+            self.analysis.syntax_errors += 1;
+        }
+
+        let inner = match self.language {
+            SourceLanguage::Rust => self.visit_rust(node, scope),
+            SourceLanguage::Python => self.visit_python(node, scope),
+            SourceLanguage::JavaScript => self.visit_javascript(node, scope),
+        };
+        let scope = inner.as_ref().unwrap_or(scope);
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child, scope);
+        }
+    }
+
+    fn finish(mut self) -> FileAnalysis {
+        for ty in &mut self.analysis.types {
+            for (type_name, trait_name) in &self.impl_traits {
+                if *type_name == ty.name && !ty.bases.contains(trait_name) {
+                    ty.bases.push(trait_name.clone());
+                }
+            }
+            ty.methods = self.analysis.functions.iter()
+                .filter(|f| f.container.as_deref() == Some(ty.name.as_str()))
+                .map(|f| f.name.clone())
+                .collect();
+        }
+        self.analysis
+    }
+
+    // Rust
+
+    fn visit_rust(&mut self, node: Node, scope: &Scope) -> Option<Scope> {
+        match node.kind() {
+            "function_item" | "function_signature_item" => {
+                let annotations = self.rust_attributes(node);
+                let is_test = annotations.iter().any(|a| {
+                    let path = a.split('(').next().unwrap_or(a).trim();
+                    path == "test" || path.ends_with("::test") || path == "rstest" || path == "test_case"
+                });
+                let is_async = self.child_of_kind(node, "function_modifiers")
+                    .is_some_and(|m| self.text(m).contains("async"));
+                let return_type = node.child_by_field_name("return_type").map(|r| collapse(self.text(r)));
+                if return_type.as_deref().is_some_and(|r| r.contains("Result")) {
+                    self.analysis.error_handling.fallible_functions += 1;
+                }
+
+                let parameters = node.child_by_field_name("parameters")
+                    .map(|params| self.named_children(params).into_iter()
+                        .filter(|p| p.kind() != "attribute_item")
+                        .map(|p| match p.child_by_field_name("pattern") {
+                            Some(pattern) => self.text(pattern).to_string(),
+                            None => collapse(self.text(p)),
+                        })
+                        .collect())
+                    .unwrap_or_default();
+
+                let function = self.record_function(node, scope, parameters, return_type, is_async, is_test, annotations.clone());
+                for annotation in &annotations {
+                    let path = annotation.split('(').next().unwrap_or(annotation).trim();
+                    let method = path.rsplit("::").next().unwrap_or(path);
+                    if let Some(route) = first_string_literal(annotation).filter(|r| r.starts_with('/')) {
+                        if HTTP_METHODS.contains(&method) || method == "route" {
+                            self.record_endpoint(method, route, Some(function.clone()), node);
+                        }
+                    }
+                }
+
+                Some(Scope { container: scope.container.clone(), function: Some(function) })
+            }
+            "impl_item" => {
+                let type_name = base_type_name(self.text(node.child_by_field_name("type")?));
+                if let Some(trait_node) = node.child_by_field_name("trait") {
+                    self.impl_traits.push((type_name.clone(), base_type_name(self.text(trait_node))));
+                }
+                Some(Scope { container: Some(type_name), function: None })
+            }
+            "trait_item" => {
+                let name = self.record_type(node, TypeKind::Trait, Vec::new())?;
+                Some(Scope { container: Some(name), function: None })
+            }
+            "struct_item" => self.record_type(node, TypeKind::Struct, Vec::new()).map(|_| scope.clone()),
+            "enum_item" => self.record_type(node, TypeKind::Enum, Vec::new()).map(|_| scope.clone()),
+            "union_item" => self.record_type(node, TypeKind::Union, Vec::new()).map(|_| scope.clone()),
+            "type_item" => self.record_type(node, TypeKind::TypeAlias, Vec::new()).map(|_| scope.clone()),
+            "use_declaration" => {
+                if let Some(argument) = node.child_by_field_name("argument") {
+                    self.record_import(collapse(self.text(argument)), node);
+                }
+                None
+            }
+            "call_expression" => {
+                if let Some(function) = node.child_by_field_name("function") {
+                    self.record_call(function, node.child_by_field_name("arguments"), scope);
+                }
+                None
+            }
+            "try_expression" => {
+                self.analysis.error_handling.propagations += 1;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Attribute contents (without `#[` `]`) directly preceding an item
+    fn rust_attributes(&self, node: Node) -> Vec<String> {
+        let mut attributes = Vec::new();
+        let mut sibling = node.prev_sibling();
+        while let Some(current) = sibling {
+            match current.kind() {
+                "attribute_item" => {
+                    if let Some(attribute) = self.child_of_kind(current, "attribute") {
+                        attributes.push(collapse(self.text(attribute)));
+                    }
+                }
+                "line_comment" | "block_comment" => {}
+                _ => break,
+            }
+            sibling = current.prev_sibling();
+        }
+        attributes.reverse();
+        attributes
+    }
+
+    // Python
+
+    fn visit_python(&mut self, node: Node, scope: &Scope) -> Option<Scope> {
+        match node.kind() {
+            "function_definition" => {
+                let annotations: Vec<String> = node.parent()
+                    .filter(|p| p.kind() == "decorated_definition")
+                    .map(|p| self.named_children(p).into_iter()
+                        .filter(|c| c.kind() == "decorator")
+                        .map(|d| collapse(self.text(d).trim_start_matches('@')))
+                        .collect())
+                    .unwrap_or_default();
+                let name = self.field_text(node, "name").unwrap_or_default();
+                // pytest and unittest both collect `test*` functions and methods
+                let is_test = name.starts_with("test");
+                let is_async = self.child_of_kind(node, "async").is_some();
+                let return_type = node.child_by_field_name("return_type").map(|r| collapse(self.text(r)));
+
+                let parameters = node.child_by_field_name("parameters")
+                    .map(|params| self.named_children(params).into_iter()
+                        .map(|p| {
+                            let text = self.text(p);
+                            text.split([':', '=']).next().unwrap_or(text).trim().to_string()
+                        })
+                        .collect())
+                    .unwrap_or_default();
+
+                let function = self.record_function(node, scope, parameters, return_type, is_async, is_test, annotations.clone());
+                for decorator in &annotations {
+                    let path = decorator.split('(').next().unwrap_or(decorator);
+                    let method = path.rsplit('.').next().unwrap_or(path);
+                    let Some(route) = first_string_literal(decorator).filter(|r| r.starts_with('/')) else {
+                        continue;
+                    };
+                    if HTTP_METHODS.contains(&method) {
+                        self.record_endpoint(method, route, Some(function.clone()), node);
+                    } else if method == "route" {
+                        // Flask routes default to GET unless `methods=[...]` says otherwise
+                        let declared = decorator.split_once("methods")
+                            .and_then(|(_, rest)| first_string_literal(rest))
+                            .unwrap_or_else(|| "GET".to_string());
+                        self.record_endpoint(&declared.to_lowercase(), route, Some(function.clone()), node);
+                    }
+                }
+
+                Some(Scope { container: scope.container.clone(), function: Some(function) })
+            }
+            "class_definition" => {
+                let bases = node.child_by_field_name("superclasses")
+                    .map(|supers| self.named_children(supers).into_iter()
+                        .filter(|s| s.kind() != "keyword_argument")
+                        .map(|s| self.text(s).to_string())
+                        .collect())
+                    .unwrap_or_default();
+                let name = self.record_type(node, TypeKind::Class, bases)?;
+                Some(Scope { container: Some(name), function: None })
+            }
+            "import_statement" => {
+                for imported in self.named_children(node) {
+                    let module = match imported.kind() {
+                        "aliased_import" => imported.child_by_field_name("name").map(|n| self.text(n).to_string()),
+                        _ => Some(self.text(imported).to_string()),
+                    };
+                    if let Some(module) = module {
+                        self.record_import(module, node);
+                    }
+                }
+                None
+            }
+            "import_from_statement" => {
+                if let Some(module) = self.field_text(node, "module_name") {
+                    self.record_import(module, node);
+                }
+                None
+            }
+            "call" => {
+                if let Some(function) = node.child_by_field_name("function") {
+                    self.record_call(function, node.child_by_field_name("arguments"), scope);
+                }
+                None
+            }
+            "try_statement" => {
+                self.analysis.error_handling.try_blocks += 1;
+                None
+            }
+            "raise_statement" => {
+                self.analysis.error_handling.throws += 1;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    // JavaScript
+
+    fn visit_javascript(&mut self, node: Node, scope: &Scope) -> Option<Scope> {
+        match node.kind() {
+            "function_declaration" | "generator_function_declaration" | "method_definition" => {
+                let function = self.record_js_function(node, node, scope);
+                Some(Scope { container: scope.container.clone(), function: Some(function) })
+            }
+            "variable_declarator" => {
+                let value = node.child_by_field_name("value")?;
+                if !matches!(value.kind(), "arrow_function" | "function" | "generator_function") {
+                    return None;
+                }
+                let function = self.record_js_function(node, value, scope);
+                Some(Scope { container: scope.container.clone(), function: Some(function) })
+            }
+            "class_declaration" | "class" => {
+                let bases = self.child_of_kind(node, "class_heritage")
+                    .map(|heritage| self.named_children(heritage).into_iter()
+                        .map(|b| self.text(b).to_string())
+                        .collect())
+                    .unwrap_or_default();
+                let name = self.record_type(node, TypeKind::Class, bases)?;
+                Some(Scope { container: Some(name), function: None })
+            }
+            "import_statement" => {
+                if let Some(source) = self.field_text(node, "source") {
+                    self.record_import(unquote(&source), node);
+                }
+                None
+            }
+            "call_expression" => {
+                let function = node.child_by_field_name("function")?;
+                let arguments = node.child_by_field_name("arguments");
+                self.record_call(function, arguments, scope);
+
+                // `it("does x", () => ...)` style test cases become named test functions
+                let callee = self.text(function);
+                let description = arguments.and_then(|args| self.string_argument(args, 0));
+                if matches!(callee, "it" | "test") {
+                    let name = description?;
+                    let function = self.push_function(FunctionInfo {
+                        name: name.clone(),
+                        qualified_name: name,
+                        container: None,
+                        parameters: Vec::new(),
+                        return_type: None,
+                        signature: collapse(callee),
+                        is_async: false,
+                        is_test: true,
+                        annotations: Vec::new(),
+                        start_line: line(node),
+                        end_line: end_line(node),
+                    });
+                    return Some(Scope { container: scope.container.clone(), function: Some(function) });
+                }
+                None
+            }
+            "member_expression" => {
+                let object = node.child_by_field_name("object")?;
+                if self.text(object) == "process.env" {
+                    let key = self.field_text(node, "property");
+                    self.analysis.config_reads.push(ConfigRead { key, line: line(node) });
+                }
+                None
+            }
+            "try_statement" => {
+                self.analysis.error_handling.try_blocks += 1;
+                None
+            }
+            "throw_statement" => {
+                self.analysis.error_handling.throws += 1;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Record a JavaScript function whose name lives on `named` and whose signature lives on `function`
+    fn record_js_function(&mut self, named: Node, function: Node, scope: &Scope) -> String {
+        let parameters = match function.child_by_field_name("parameters") {
+            Some(params) => self.named_children(params).into_iter()
+                .map(|p| collapse(self.text(p)))
+                .collect(),
+            None => function.child_by_field_name("parameter")
+                .map(|p| vec![self.text(p).to_string()])
+                .unwrap_or_default(),
+        };
+        let is_async = self.child_of_kind(function, "async").is_some();
+        let annotations = self.named_children(named).into_iter()
+            .filter(|c| c.kind() == "decorator")
+            .map(|d| collapse(self.text(d).trim_start_matches('@')))
+            .collect();
+        self.record_function(named, scope, parameters, None, is_async, false, annotations)
+    }
+
+    // Shared recording
+
+    #[allow(clippy::too_many_arguments)]
+    fn record_function(
+        &mut self,
+        node: Node,
+        scope: &Scope,
+        parameters: Vec<String>,
+        return_type: Option<String>,
+        is_async: bool,
+        is_test: bool,
+        annotations: Vec<String>,
+    ) -> String {
+        let name = self.field_text(node, "name").unwrap_or_else(|| "<anonymous>".to_string());
+        let qualified_name = match &scope.container {
+            Some(container) => format!("{}{}{}", container, self.language.path_separator(), name),
+            None => name.clone(),
+        };
+
+        // Everything before the body, e.g. `pub async fn load(&self) -> Result<()>`
+        let body_start = node.child_by_field_name("body")
+            .or_else(|| node.child_by_field_name("value").and_then(|v| v.child_by_field_name("body")))
+            .map(|b| b.start_byte())
+            .unwrap_or(node.end_byte());
+        let header = std::str::from_utf8(&self.source[node.start_byte()..body_start]).unwrap_or("");
+        let signature = collapse(header).trim_end_matches([':', '{', ' ']).trim_end_matches("=>").trim().to_string();
+
+        self.push_function(FunctionInfo {
+            name,
+            qualified_name,
+            container: scope.container.clone(),
+            parameters,
+            return_type,
+            signature,
+            is_async,
+            is_test,
+            annotations,
+            start_line: line(node),
+            end_line: end_line(node),
+        })
+    }
+
+    fn push_function(&mut self, function: FunctionInfo) -> String {
+        let qualified_name = function.qualified_name.clone();
+        self.analysis.functions.push(function);
+        qualified_name
+    }
+
+    fn record_type(&mut self, node: Node, kind: TypeKind, bases: Vec<String>) -> Option<String> {
+        let name = self.field_text(node, "name")?;
+        self.analysis.types.push(TypeInfo {
+            name: name.clone(),
+            kind,
+            bases,
+            methods: Vec::new(),
+            start_line: line(node),
+            end_line: end_line(node),
+        });
+        Some(name)
+    }
+
+    fn record_import(&mut self, module: String, node: Node) {
+        self.analysis.imports.push(ImportInfo { module, line: line(node) });
+    }
+
+    fn record_endpoint(&mut self, method: &str, path: String, handler: Option<String>, node: Node) {
+        let method = if method == "route" { "ANY".to_string() } else { method.to_uppercase() };
+        self.analysis.endpoints.push(EndpointInfo { method, path, handler, line: line(node) });
+    }
+
+    fn record_call(&mut self, function: Node, arguments: Option<Node>, scope: &Scope) {
+        let callee_path = collapse(self.text(function));
+        let callee = last_segment(&callee_path);
+        let call_line = line(function);
+
+        match self.language {
+            SourceLanguage::Rust => {
+                if matches!(callee.as_str(), "unwrap" | "expect") {
+                    self.analysis.error_handling.unwraps += 1;
+                }
+                if callee_path.ends_with("env::var") || callee_path.ends_with("env::var_os") {
+                    let key = arguments.and_then(|args| self.string_argument(args, 0));
+                    self.analysis.config_reads.push(ConfigRead { key, line: call_line });
+                }
+            }
+            SourceLanguage::Python => {
+                if matches!(callee_path.as_str(), "os.getenv" | "getenv" | "os.environ.get" | "environ.get") {
+                    let key = arguments.and_then(|args| self.string_argument(args, 0));
+                    self.analysis.config_reads.push(ConfigRead { key, line: call_line });
+                }
+            }
+            SourceLanguage::JavaScript => {
+                if callee_path == "require" {
+                    if let Some(module) = arguments.and_then(|args| self.string_argument(args, 0)) {
+                        self.analysis.imports.push(ImportInfo { module, line: call_line });
+                    }
+                }
+                // Express-style routers: `app.get("/users", handler)`
+                if function.kind() == "member_expression" && (HTTP_METHODS.contains(&callee.as_str()) || callee == "all") {
+                    if let Some(route) = arguments.and_then(|args| self.string_argument(args, 0)).filter(|r| r.starts_with('/')) {
+                        let handler = arguments
+                            .and_then(|args| self.named_children(args).into_iter().last())
+                            .filter(|h| h.kind() == "identifier")
+                            .map(|h| self.text(h).to_string());
+                        let method = if callee == "all" { "route" } else { callee.as_str() };
+                        self.record_endpoint(method, route, handler, function);
+                    }
+                }
+            }
+        }
+
+        self.analysis.calls.push(CallEdge {
+            caller: scope.function.clone().unwrap_or_else(|| MODULE_SCOPE.to_string()),
+            callee,
+            callee_path,
+            line: call_line,
+        });
+    }
+
+    // Node helpers
+
+    fn text(&self, node: Node) -> &'a str {
+        node.utf8_text(self.source).unwrap_or("")
+    }
+
+    fn field_text(&self, node: Node, field: &str) -> Option<String> {
+        node.child_by_field_name(field).map(|n| self.text(n).to_string())
+    }
+
+    fn named_children<'t>(&self, node: Node<'t>) -> Vec<Node<'t>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).collect()
+    }
+
+    fn child_of_kind<'t>(&self, node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+        let mut cursor = node.walk();
+        let found = node.children(&mut cursor).find(|c| c.kind() == kind);
+        found
+    }
+
+    /// The `index`th argument, if it is a plain string literal
+    fn string_argument(&self, arguments: Node, index: usize) -> Option<String> {
+        let argument = self.named_children(arguments).into_iter().nth(index)?;
+        matches!(argument.kind(), "string" | "string_literal" | "raw_string_literal")
+            .then(|| unquote(self.text(argument)))
+    }
+}
+
+fn line(node: Node) -> usize {
+    node.start_position().row + 1
+}
+
+fn end_line(node: Node) -> usize {
+    node.end_position().row + 1
+}
+
+/// Collapse runs of whitespace, including newlines, to single spaces
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Strip string prefixes (`r`, `f`, `b`...) and quotes from a literal
+fn unquote(literal: &str) -> String {
+    let body = literal.trim_start_matches(|c: char| c.is_ascii_alphabetic() || c == '#');
+    let quote = body.chars().next().unwrap_or('"');
+    body.trim_start_matches(quote)
+        .trim_end_matches('#')
+        .trim_end_matches(quote)
+        .to_string()
+}
+
+/// First quoted string inside arbitrary text, e.g. the route in `get("/users")`
+fn first_string_literal(text: &str) -> Option<String> {
+    let start = text.find(['"', '\''])?;
+    let quote = text[start..].chars().next()?;
+    let rest = &text[start + 1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_string())
+}
+
+/// `Vec<T>` -> `Vec`, `crate::store::Store` -> `Store`, `&mut Foo` -> `Foo`
+fn base_type_name(text: &str) -> String {
+    let without_generics = text.split('<').next().unwrap_or(text);
+    let name = without_generics.rsplit("::").next().unwrap_or(without_generics);
+    name.trim_start_matches('&').trim_start_matches("mut ").trim().to_string()
+}
+
+/// Final segment of a callee path, ignoring generic arguments
+fn last_segment(path: &str) -> String {
+    let mut depth = 0usize;
+    let stripped: String = path.chars()
+        .filter(|&c| {
+            match c {
+                '<' => depth += 1,
+                '>' => depth = depth.saturating_sub(1),
+                _ => return depth == 0,
+            }
+            false
+        })
+        .collect();
+    stripped.rsplit(['.', ':'])
+        .find(|segment| !segment.trim().is_empty())
+        .unwrap_or(&stripped)
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST_SOURCE: &str = r#"
+use std::collections::HashMap;
+use crate::store::{Store, Record};
+
+pub struct Cache {
+    entries: HashMap<String, String>,
+}
+
+pub trait Lookup {
+    fn lookup(&self, key: &str) -> Option<String>;
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self { entries: HashMap::new() }
+    }
+
+    pub async fn load(&mut self, path: &str) -> Result<usize, std::io::Error> {
+        let text = std::fs::read_to_string(path)?;
+        let limit = std::env::var("CACHE_LIMIT").unwrap();
+        Ok(self.insert_all(&text))
+    }
+
+    fn insert_all(&mut self, text: &str) -> usize {
+        text.lines().count()
+    }
+}
+
+impl Lookup for Cache {
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.entries.get(key).cloned()
+    }
+}
+
+#[get("/cache/{key}")]
+async fn get_entry() -> String {
+    String::new()
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn loads_cache() {
+        let cache = Cache::new();
+    }
+}
+"#;
+
+    #[test]
+    fn test_rust_extraction() -> Result<()> {
+        let analysis = CodeAnalyzer::new().analyze_source(RUST_SOURCE, SourceLanguage::Rust)?;
+
+        assert_eq!(analysis.syntax_errors, 0);
+        assert_eq!(analysis.imports.len(), 2);
+        assert_eq!(analysis.imports[1].module, "crate::store::{Store, Record}");
+
+        let cache = analysis.type_named("Cache").expect("Cache struct");
+        assert_eq!(cache.kind, TypeKind::Struct);
+        assert_eq!(cache.bases, vec!["Lookup".to_string()]);
+        assert_eq!(cache.methods, vec!["new", "load", "insert_all", "lookup"]);
+        assert_eq!(analysis.type_named("Lookup").map(|t| t.kind), Some(TypeKind::Trait));
+
+        let load = analysis.function("Cache::load").expect("load method");
+        assert!(load.is_async);
+        assert_eq!(load.parameters, vec!["&mut self", "path"]);
+        assert_eq!(load.signature, "pub async fn load(&mut self, path: &str) -> Result<usize, std::io::Error>");
+        assert_eq!(analysis.callees_of("Cache::load"), vec!["read_to_string", "unwrap", "var", "Ok", "insert_all"]);
+
+        assert!(analysis.function("loads_cache").is_some_and(|f| f.is_test));
+        assert_eq!(analysis.endpoints.len(), 1);
+        assert_eq!(analysis.endpoints[0].method, "GET");
+        assert_eq!(analysis.endpoints[0].handler.as_deref(), Some("get_entry"));
+
+        assert_eq!(analysis.config_reads[0].key.as_deref(), Some("CACHE_LIMIT"));
+        assert_eq!(analysis.error_handling.propagations, 1);
+        assert_eq!(analysis.error_handling.unwraps, 1);
+        assert_eq!(analysis.error_handling.fallible_functions, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_python_and_javascript_extraction() -> Result<()> {
+        let analyzer = CodeAnalyzer::new();
+
+        let python = r#"
+import os
+from flask import Flask
+
+class UserService(BaseService):
+    def find(self, user_id: int):
+        try:
+            return self.repo.get(user_id)
+        except KeyError:
+            raise LookupError(user_id)
+
+@app.route("/users", methods=["POST"])
+def create_user():
+    token = os.getenv("API_TOKEN")
+    return UserService().find(1)
+
+def test_create_user():
+    assert create_user()
+"#;
+        let analysis = analyzer.analyze_source(python, SourceLanguage::Python)?;
+        assert_eq!(analysis.imports.iter().map(|i| i.module.as_str()).collect::<Vec<_>>(), vec!["os", "flask"]);
+        assert_eq!(analysis.type_named("UserService").map(|t| t.bases.clone()), Some(vec!["BaseService".to_string()]));
+        assert_eq!(analysis.function("UserService.find").map(|f| f.parameters.clone()), Some(vec!["self".to_string(), "user_id".to_string()]));
+        assert_eq!(analysis.endpoints[0].method, "POST");
+        assert_eq!(analysis.endpoints[0].handler.as_deref(), Some("create_user"));
+        assert_eq!(analysis.config_reads[0].key.as_deref(), Some("API_TOKEN"));
+        assert!(analysis.function("test_create_user").is_some_and(|f| f.is_test));
+        assert_eq!(analysis.callees_of("create_user"), vec!["getenv", "find", "UserService"]);
+        assert_eq!((analysis.error_handling.try_blocks, analysis.error_handling.throws), (1, 1));
+
+        let javascript = r#"
+import express from "express";
+const db = require("./db");
+
+class UserController extends Controller {
+    async list(req, res) {
+        res.json(await db.users());
+    }
+}
+
+const handler = async (req, res) => {
+    const port = process.env.PORT;
+    throw new Error("nope");
+};
+
+app.get("/users", handler);
+
+it("lists users", () => {
+    handler();
+});
+"#;
+        let analysis = analyzer.analyze_source(javascript, SourceLanguage::JavaScript)?;
+        assert_eq!(analysis.imports.iter().map(|i| i.module.as_str()).collect::<Vec<_>>(), vec!["express", "./db"]);
+        assert_eq!(analysis.type_named("UserController").map(|t| t.methods.clone()), Some(vec!["list".to_string()]));
+        assert!(analysis.function("handler").is_some_and(|f| f.is_async));
+        assert_eq!(analysis.endpoints[0].path, "/users");
+        assert_eq!(analysis.endpoints[0].handler.as_deref(), Some("handler"));
+        assert_eq!(analysis.config_reads[0].key.as_deref(), Some("PORT"));
+        assert!(analysis.function("lists users").is_some_and(|f| f.is_test));
+        assert_eq!(analysis.callees_of("lists users"), vec!["handler"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_language_detection() -> Result<()> {
+        let analyzer = CodeAnalyzer::new();
+        assert_eq!(analyzer.detect_language("fn main() { let x = 1; }", None)?, SourceLanguage::Rust);
+        assert_eq!(analyzer.detect_language("def main():\n    return 1\n", None)?, SourceLanguage::Python);
+        assert_eq!(analyzer.detect_language("function main() { return 1; }", None)?, SourceLanguage::JavaScript);
+        assert_eq!(analyzer.detect_language("anything", Some("src/app.py"))?, SourceLanguage::Python);
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! Persisting detected code patterns in the concept graph
+
+use brain_core::{
+    ConceptNode, ConceptQuery, ConceptRelationship, ConceptRepository, ConceptType,
+    RelationshipRepository, RelationshipType,
+};
+use brain_types::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Metadata key marking concepts created from code analysis
+pub const PATTERN_TYPE_KEY: &str = "code_pattern_type";
+
+/// Store every pattern of a response as a concept and link related patterns
+///
+/// A pattern already stored for the same name and file location is updated
+/// in place, so analyzing a file twice does not duplicate its concepts. Sets
+/// `concept_id` on each pattern and `concepts_created` on the response, and
+/// returns the number of relationships added to the graph.
+pub async fn store_patterns<R>(response: &mut CodePatternAnalysisResponse, repository: &mut R) -> Result<usize>
+where
+    R: ConceptRepository + RelationshipRepository + ?Sized,
+{
+    let mut concepts_created = 0;
+    let mut ids: HashMap<String, Uuid> = HashMap::new();
+
+    for pattern in &mut response.patterns_found {
+        let id = match find_stored(repository, pattern).await? {
+            Some(mut existing) => {
+                existing.description = Some(pattern.description.clone());
+                existing.confidence_score = pattern.confidence.clamp(0.0, 1.0);
+                repository.update_concept(&existing).await?;
+                existing.id
+            }
+            None => {
+                let mut concept = ConceptNode::new(
+                    concept_type(&pattern.pattern_type),
+                    pattern.name.clone(),
+                    pattern.confidence,
+                    pattern.file_location.clone(),
+                );
+                concept.description = Some(pattern.description.clone());
+                concept.metadata.insert(PATTERN_TYPE_KEY.to_string(), format!("{:?}", pattern.pattern_type));
+                concepts_created += 1;
+                repository.create_concept(concept).await?
+            }
+        };
+        pattern.concept_id = Some(id.to_string());
+        ids.entry(pattern.name.clone()).or_insert(id);
+    }
+
+    let mut relationships_formed = 0;
+    for pattern in &response.patterns_found {
+        let source_id = ids[&pattern.name];
+        let mut linked: HashSet<Uuid> = repository.get_concept_relationships(source_id).await?
+            .into_iter()
+            .filter(|r| r.source_id == source_id)
+            .map(|r| r.target_id)
+            .collect();
+        for related in &pattern.related_patterns {
+            let Some(&target_id) = ids.get(related) else { continue };
+            if target_id == source_id || !linked.insert(target_id) {
+                continue;
+            }
+            let relationship = ConceptRelationship::new(source_id, target_id, relationship_type(&pattern.pattern_type), pattern.confidence);
+            repository.create_relationship(relationship).await?;
+            relationships_formed += 1;
+        }
+    }
+
+    response.concepts_created = concepts_created;
+    Ok(relationships_formed)
+}
+
+async fn find_stored<R>(repository: &R, pattern: &CodePattern) -> Result<Option<ConceptNode>>
+where
+    R: ConceptRepository + ?Sized,
+{
+    let query = ConceptQuery {
+        concept_type: Some(concept_type(&pattern.pattern_type)),
+        content_pattern: Some(pattern.name.clone()),
+        ..ConceptQuery::default()
+    };
+    let pattern_type = format!("{:?}", pattern.pattern_type);
+    Ok(repository.query_concepts(&query).await?.into_iter().find(|concept| {
+        concept.content == pattern.name
+            && concept.source_reference == pattern.file_location
+            && concept.metadata.get(PATTERN_TYPE_KEY) == Some(&pattern_type)
+    }))
+}
+
+fn concept_type(pattern_type: &CodePatternType) -> ConceptType {
+    match pattern_type {
+        CodePatternType::DataStructure => ConceptType::Entity,
+        CodePatternType::Function | CodePatternType::APIEndpoint | CodePatternType::TestPattern => ConceptType::Action,
+        _ => ConceptType::Abstract,
+    }
+}
+
+fn relationship_type(pattern_type: &CodePatternType) -> RelationshipType {
+    match pattern_type {
+        CodePatternType::DataStructure => RelationshipType::Has,
+        CodePatternType::Function | CodePatternType::APIEndpoint | CodePatternType::TestPattern => RelationshipType::Uses,
+        _ => RelationshipType::AssociatedWith,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::PatternRecognizer;
+    use brain_infra::{ConceptGraphConfig, ConceptGraphManager};
+
+    const SOURCE: &str = r#"
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self) -> usize { helper() }
+}
+
+fn helper() -> usize { 1 }
+"#;
+
+    fn request() -> CodePatternAnalysisRequest {
+        CodePatternAnalysisRequest {
+            code_content: SOURCE.to_string(),
+            file_path: Some("src/parser.rs".to_string()),
+            language: Some("rust".to_string()),
+            store_patterns: true,
+            analysis_depth: PatternAnalysisDepth::Detailed,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_patterns_are_stored_once_as_linked_concepts() -> Result<()> {
+        let mut graph = ConceptGraphManager::new(ConceptGraphConfig::default()).await?;
+        let recognizer = PatternRecognizer::new();
+
+        let mut response = recognizer.analyze(&request())?;
+        let relationships = store_patterns(&mut response, &mut graph).await?;
+        assert_eq!(response.concepts_created, response.patterns_found.len());
+        assert_eq!(graph.get_concept_count().await?, response.concepts_created);
+        assert!(response.patterns_found.iter().all(|p| p.concept_id.is_some()));
+
+        // Parser::parse calls helper, which is itself a stored pattern
+        let parse = response.patterns_found.iter().find(|p| p.name == "Parser::parse").expect("method pattern");
+        let helper = response.patterns_found.iter().find(|p| p.name == "helper").expect("function pattern");
+        let parse_id: Uuid = parse.concept_id.as_deref().expect("stored").parse().expect("uuid");
+        let links = graph.get_concept_relationships(parse_id).await?;
+        assert_eq!(relationships, 1);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target_id.to_string(), *helper.concept_id.as_ref().expect("stored"));
+        assert_eq!(links[0].relationship_type, RelationshipType::Uses);
+
+        // Storing the same file again reuses its concepts and relationships
+        let mut again = recognizer.analyze(&request())?;
+        assert_eq!(store_patterns(&mut again, &mut graph).await?, 0);
+        assert_eq!(again.concepts_created, 0);
+        assert_eq!(graph.get_concept_count().await?, response.concepts_created);
+        assert_eq!(again.patterns_found[0].concept_id, response.patterns_found[0].concept_id);
+
+        Ok(())
+    }
+}
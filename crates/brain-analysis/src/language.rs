@@ -0,0 +1,71 @@
+//! Source language detection and grammar selection
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tree_sitter::Language;
+
+/// Languages with a bundled tree-sitter grammar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SourceLanguage {
+    Rust,
+    JavaScript,
+    Python,
+}
+
+impl SourceLanguage {
+    /// All supported languages, in detection preference order
+    pub const ALL: [SourceLanguage; 3] = [SourceLanguage::Rust, SourceLanguage::Python, SourceLanguage::JavaScript];
+
+    /// Parse a language name or common alias, case-insensitively
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "rust" | "rs" => Some(SourceLanguage::Rust),
+            "javascript" | "js" | "jsx" | "node" | "nodejs" | "mjs" | "cjs" => Some(SourceLanguage::JavaScript),
+            "python" | "py" | "python3" => Some(SourceLanguage::Python),
+            _ => None,
+        }
+    }
+
+    /// Infer the language from a file extension
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        match extension.to_lowercase().as_str() {
+            "rs" => Some(SourceLanguage::Rust),
+            "js" | "jsx" | "mjs" | "cjs" => Some(SourceLanguage::JavaScript),
+            "py" | "pyw" | "pyi" => Some(SourceLanguage::Python),
+            _ => None,
+        }
+    }
+
+    /// Canonical lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SourceLanguage::Rust => "rust",
+            SourceLanguage::JavaScript => "javascript",
+            SourceLanguage::Python => "python",
+        }
+    }
+
+    /// Separator used when qualifying a member with its container
+    pub fn path_separator(&self) -> &'static str {
+        match self {
+            SourceLanguage::Rust => "::",
+            SourceLanguage::JavaScript | SourceLanguage::Python => ".",
+        }
+    }
+
+    /// The tree-sitter grammar for this language
+    pub fn grammar(&self) -> Language {
+        match self {
+            SourceLanguage::Rust => tree_sitter_rust::language(),
+            SourceLanguage::JavaScript => tree_sitter_javascript::language(),
+            SourceLanguage::Python => tree_sitter_python::language(),
+        }
+    }
+}
+
+impl std::fmt::Display for SourceLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -1 +1,20 @@
-//! Placeholder module for migration
+//! Brain Analysis - Code Intelligence Engine
+//!
+//! Parses Rust, JavaScript and Python sources with tree-sitter and extracts:
+//! - Functions and methods with signatures, parameters and test markers
+//! - Type definitions with their methods and supertypes
+//! - Imports, call edges, HTTP routes and configuration reads
+//! - `CodePattern` results for the code pattern analysis API, optionally
+//!   stored as concepts in the concept graph
+
+pub mod analyzer;
+pub mod concepts;
+pub mod language;
+pub mod model;
+pub mod patterns;
+
+pub use analyzer::CodeAnalyzer;
+pub use concepts::store_patterns;
+pub use language::SourceLanguage;
+pub use model::*;
+pub use patterns::{PatternRecognizer, extract_patterns, architectural_insights};
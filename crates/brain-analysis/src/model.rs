@@ -0,0 +1,165 @@
+//! Structural facts extracted from a parsed source file
+
+use crate::language::SourceLanguage;
+use serde::{Deserialize, Serialize};
+
+/// Function or method definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionInfo {
+    pub name: String,
+    /// Name qualified by its enclosing type, e.g. `Parser::parse` or `Parser.parse`
+    pub qualified_name: String,
+    /// Enclosing impl, trait or class, if any
+    pub container: Option<String>,
+    pub parameters: Vec<String>,
+    pub return_type: Option<String>,
+    /// Declaration text up to the body, collapsed onto one line
+    pub signature: String,
+    pub is_async: bool,
+    pub is_test: bool,
+    /// Decorators or attributes attached to the definition
+    pub annotations: Vec<String>,
+    /// 1-based first line
+    pub start_line: usize,
+    /// 1-based last line
+    pub end_line: usize,
+}
+
+/// Kind of a type definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeKind {
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TypeAlias,
+    Class,
+}
+
+/// Type definition with the methods and supertypes found for it in the same file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeInfo {
+    pub name: String,
+    pub kind: TypeKind,
+    /// Superclasses, or traits implemented for this type
+    pub bases: Vec<String>,
+    pub methods: Vec<String>,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// Module dependency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportInfo {
+    /// Imported path or module specifier as written
+    pub module: String,
+    pub line: usize,
+}
+
+/// Call from one function to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallEdge {
+    /// Qualified name of the calling function, or `<module>` for top-level code
+    pub caller: String,
+    /// Simple name of the called function or method
+    pub callee: String,
+    /// Full callee expression, e.g. `std::env::var` or `self.store.get`
+    pub callee_path: String,
+    pub line: usize,
+}
+
+/// HTTP route declared through a framework decorator, attribute or router call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointInfo {
+    /// Upper-case HTTP method, or `ANY` when the route accepts several
+    pub method: String,
+    pub path: String,
+    /// Function handling the route, when it is a named definition
+    pub handler: Option<String>,
+    pub line: usize,
+}
+
+/// Read of an environment variable or configuration source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigRead {
+    /// Variable name when given as a literal
+    pub key: Option<String>,
+    pub line: usize,
+}
+
+/// Error-handling constructs counted across a file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ErrorHandlingSummary {
+    /// `try` blocks
+    pub try_blocks: usize,
+    /// Rust `?` operators
+    pub propagations: usize,
+    /// `throw` / `raise` statements
+    pub throws: usize,
+    /// Rust `unwrap` / `expect` calls
+    pub unwraps: usize,
+    /// Functions whose declared return type is a `Result`
+    pub fallible_functions: usize,
+}
+
+/// Everything extracted from one source file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileAnalysis {
+    pub path: Option<String>,
+    pub language: SourceLanguage,
+    pub functions: Vec<FunctionInfo>,
+    pub types: Vec<TypeInfo>,
+    pub imports: Vec<ImportInfo>,
+    pub calls: Vec<CallEdge>,
+    pub endpoints: Vec<EndpointInfo>,
+    pub config_reads: Vec<ConfigRead>,
+    pub error_handling: ErrorHandlingSummary,
+    /// Number of ERROR or MISSING nodes tree-sitter recovered from
+    pub syntax_errors: usize,
+    pub line_count: usize,
+}
+
+impl FileAnalysis {
+    /// Empty analysis for a file in `language`
+    pub fn new(language: SourceLanguage, path: Option<String>) -> Self {
+        Self {
+            path,
+            language,
+            functions: Vec::new(),
+            types: Vec::new(),
+            imports: Vec::new(),
+            calls: Vec::new(),
+            endpoints: Vec::new(),
+            config_reads: Vec::new(),
+            error_handling: ErrorHandlingSummary::default(),
+            syntax_errors: 0,
+            line_count: 0,
+        }
+    }
+
+    /// Look up a function by simple or qualified name
+    pub fn function(&self, name: &str) -> Option<&FunctionInfo> {
+        self.functions.iter().find(|f| f.qualified_name == name || f.name == name)
+    }
+
+    /// Look up a type by name
+    pub fn type_named(&self, name: &str) -> Option<&TypeInfo> {
+        self.types.iter().find(|t| t.name == name)
+    }
+
+    /// Distinct callees of a function in source order, outer calls before the calls nested inside them
+    pub fn callees_of(&self, caller: &str) -> Vec<&str> {
+        let mut callees: Vec<&str> = Vec::new();
+        for edge in self.calls.iter().filter(|e| e.caller == caller) {
+            if !callees.contains(&edge.callee.as_str()) {
+                callees.push(&edge.callee);
+            }
+        }
+        callees
+    }
+
+    /// Call edges whose callee is defined in this file
+    pub fn internal_calls(&self) -> impl Iterator<Item = &CallEdge> {
+        self.calls.iter().filter(|e| self.functions.iter().any(|f| f.name == e.callee))
+    }
+}
@@ -0,0 +1,497 @@
+//! Mapping extracted code structure onto `CodePattern` results
+
+use crate::analyzer::CodeAnalyzer;
+use crate::language::SourceLanguage;
+use crate::model::*;
+use brain_types::*;
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Confidence for facts read directly off a clean syntax tree
+const AST_CONFIDENCE: f64 = 0.95;
+/// Confidence for name-based design pattern heuristics
+const HEURISTIC_CONFIDENCE: f64 = 0.65;
+
+/// Turns parsed files into the pattern responses served by `/code/analyze`
+#[derive(Debug, Clone, Default)]
+pub struct PatternRecognizer {
+    analyzer: CodeAnalyzer,
+}
+
+impl PatternRecognizer {
+    /// Create new pattern recognizer
+    pub fn new() -> Self {
+        Self { analyzer: CodeAnalyzer::new() }
+    }
+
+    /// Parse the request's code and report the patterns found at the requested depth
+    pub fn analyze(&self, request: &CodePatternAnalysisRequest) -> Result<CodePatternAnalysisResponse> {
+        let start_time = Instant::now();
+
+        let language = match request.language.as_deref() {
+            Some(name) => SourceLanguage::from_name(name).ok_or_else(|| {
+                BrainError::InvalidInput(format!("Unsupported language for code analysis: {}", name))
+            })?,
+            None => self.analyzer.detect_language(&request.code_content, request.file_path.as_deref())?,
+        };
+
+        let analysis = match &request.file_path {
+            Some(path) => self.analyzer.analyze_source_at(&request.code_content, language, path)?,
+            None => self.analyzer.analyze_source(&request.code_content, language)?,
+        };
+
+        let patterns = extract_patterns(&analysis, &request.analysis_depth);
+        let relationships_formed = patterns.iter().map(|p| p.related_patterns.len()).sum();
+        let confidence_score = if patterns.is_empty() {
+            structural_confidence(&analysis)
+        } else {
+            patterns.iter().map(|p| p.confidence).sum::<f64>() / patterns.len() as f64
+        };
+
+        Ok(CodePatternAnalysisResponse {
+            success: true,
+            patterns_found: patterns,
+            concepts_created: 0,
+            relationships_formed,
+            analysis_time_ms: start_time.elapsed().as_millis() as u64,
+            confidence_score,
+            language_detected: Some(language.name().to_string()),
+            architectural_insights: architectural_insights(&analysis),
+        })
+    }
+}
+
+/// Convert an analysis into patterns; deeper levels add relationships, conventions and design patterns
+pub fn extract_patterns(analysis: &FileAnalysis, depth: &PatternAnalysisDepth) -> Vec<CodePattern> {
+    let detailed = !matches!(depth, PatternAnalysisDepth::Basic);
+    let deep = matches!(depth, PatternAnalysisDepth::Deep);
+    let confidence = structural_confidence(analysis);
+    let mut patterns = Vec::new();
+
+    for ty in &analysis.types {
+        let mut related = Vec::new();
+        if detailed {
+            related.extend(ty.bases.iter().cloned());
+            related.extend(ty.methods.iter().cloned());
+        }
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::DataStructure,
+            &ty.name,
+            format!("{:?} with {} methods", ty.kind, ty.methods.len()),
+            None,
+            ty.start_line,
+            confidence,
+            related,
+        ));
+    }
+
+    for function in &analysis.functions {
+        let (pattern_type, description) = if function.is_test {
+            if !detailed {
+                continue;
+            }
+            (CodePatternType::TestPattern, "Test case".to_string())
+        } else {
+            let kind = if function.container.is_some() { "Method" } else { "Function" };
+            let asyncness = if function.is_async { "async " } else { "" };
+            (CodePatternType::Function, format!("{}{} taking {} parameters", asyncness, kind.to_lowercase(), function.parameters.len()))
+        };
+        let related = if detailed {
+            analysis.callees_of(&function.qualified_name).into_iter().map(str::to_string).collect()
+        } else {
+            Vec::new()
+        };
+        patterns.push(pattern(
+            analysis,
+            pattern_type,
+            &function.qualified_name,
+            capitalize(&description),
+            Some(function.signature.clone()),
+            function.start_line,
+            confidence,
+            related,
+        ));
+    }
+
+    for endpoint in &analysis.endpoints {
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::APIEndpoint,
+            &format!("{} {}", endpoint.method, endpoint.path),
+            "HTTP route".to_string(),
+            None,
+            endpoint.line,
+            confidence,
+            endpoint.handler.iter().cloned().collect(),
+        ));
+    }
+
+    if !detailed {
+        return patterns;
+    }
+
+    for import in &analysis.imports {
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::ImportPattern,
+            &import.module,
+            "Module dependency".to_string(),
+            None,
+            import.line,
+            confidence,
+            Vec::new(),
+        ));
+    }
+
+    if let Some(error_pattern) = error_handling_pattern(analysis, confidence) {
+        patterns.push(error_pattern);
+    }
+
+    if !analysis.config_reads.is_empty() {
+        let keys: Vec<String> = analysis.config_reads.iter().filter_map(|r| r.key.clone()).collect();
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::ConfigurationPattern,
+            "environment_configuration",
+            format!("Reads {} configuration values from the environment", analysis.config_reads.len()),
+            None,
+            analysis.config_reads[0].line,
+            confidence,
+            keys,
+        ));
+    }
+
+    if deep {
+        patterns.extend(naming_patterns(analysis));
+        patterns.extend(design_patterns(analysis));
+    }
+
+    patterns
+}
+
+/// Short human-readable observations about the file's structure
+pub fn architectural_insights(analysis: &FileAnalysis) -> Vec<String> {
+    let mut insights = Vec::new();
+    let tests = analysis.functions.iter().filter(|f| f.is_test).count();
+    let async_functions = analysis.functions.iter().filter(|f| f.is_async).count();
+
+    insights.push(format!(
+        "{} {} lines: {} functions ({} async, {} tests), {} types, {} imports",
+        analysis.line_count, analysis.language, analysis.functions.len(), async_functions, tests,
+        analysis.types.len(), analysis.imports.len()
+    ));
+
+    let mut call_counts: HashMap<&str, usize> = HashMap::new();
+    for edge in analysis.internal_calls() {
+        *call_counts.entry(edge.callee.as_str()).or_default() += 1;
+    }
+    if let Some((callee, count)) = call_counts.iter().max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0))) {
+        insights.push(format!("Most called local function: {} ({} call sites)", callee, count));
+    }
+
+    if let Some(longest) = analysis.functions.iter().max_by_key(|f| f.end_line - f.start_line) {
+        let length = longest.end_line - longest.start_line + 1;
+        if length > 50 {
+            insights.push(format!("{} spans {} lines; consider splitting it", longest.qualified_name, length));
+        }
+    }
+
+    let errors = &analysis.error_handling;
+    if analysis.language == SourceLanguage::Rust && errors.unwraps > errors.propagations {
+        insights.push(format!(
+            "{} unwrap/expect calls against {} `?` propagations; prefer propagating errors",
+            errors.unwraps, errors.propagations
+        ));
+    }
+
+    if !analysis.endpoints.is_empty() {
+        insights.push(format!("Exposes {} HTTP endpoints", analysis.endpoints.len()));
+    }
+
+    let non_test = analysis.functions.len() - tests;
+    if tests == 0 && non_test > 0 {
+        insights.push("No tests found in this file".to_string());
+    }
+
+    if analysis.syntax_errors > 0 {
+        insights.push(format!(
+            "Parser recovered from {} syntax errors; results may be incomplete",
+            analysis.syntax_errors
+        ));
+    }
+
+    insights
+}
+
+fn structural_confidence(analysis: &FileAnalysis) -> f64 {
+    (AST_CONFIDENCE - 0.05 * analysis.syntax_errors as f64).max(0.5)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pattern(
+    analysis: &FileAnalysis,
+    pattern_type: CodePatternType,
+    name: &str,
+    description: String,
+    code_snippet: Option<String>,
+    line: usize,
+    confidence: f64,
+    related_patterns: Vec<String>,
+) -> CodePattern {
+    let file_location = match &analysis.path {
+        Some(path) => format!("{}:{}", path, line),
+        None => format!("line {}", line),
+    };
+    CodePattern {
+        pattern_type,
+        name: name.to_string(),
+        description,
+        code_snippet,
+        file_location: Some(file_location),
+        confidence,
+        related_patterns,
+        concept_id: None,
+    }
+}
+
+fn error_handling_pattern(analysis: &FileAnalysis, confidence: f64) -> Option<CodePattern> {
+    let errors = &analysis.error_handling;
+    let description = match analysis.language {
+        SourceLanguage::Rust if errors.fallible_functions + errors.propagations + errors.unwraps > 0 => format!(
+            "{} functions return Result, {} `?` propagations, {} unwrap/expect calls",
+            errors.fallible_functions, errors.propagations, errors.unwraps
+        ),
+        SourceLanguage::Python | SourceLanguage::JavaScript if errors.try_blocks + errors.throws > 0 => format!(
+            "{} try blocks, {} raised or thrown errors",
+            errors.try_blocks, errors.throws
+        ),
+        _ => return None,
+    };
+    let name = match analysis.language {
+        SourceLanguage::Rust => "result_error_handling",
+        _ => "exception_error_handling",
+    };
+    Some(pattern(analysis, CodePatternType::ErrorHandling, name, description, None, 1, confidence, Vec::new()))
+}
+
+fn naming_patterns(analysis: &FileAnalysis) -> Vec<CodePattern> {
+    let mut patterns = Vec::new();
+
+    let function_names: Vec<&str> = analysis.functions.iter()
+        .filter(|f| !f.is_test || analysis.language != SourceLanguage::JavaScript)
+        .map(|f| f.name.as_str())
+        .filter(|n| n.chars().any(char::is_alphabetic))
+        .collect();
+    if !function_names.is_empty() {
+        let snake = function_names.iter().filter(|n| is_snake_case(n)).count();
+        let camel = function_names.iter().filter(|n| is_camel_case(n)).count();
+        let (convention, matching) = if snake >= camel { ("snake_case", snake) } else { ("camelCase", camel) };
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::NamingConvention,
+            &format!("{}_functions", convention),
+            format!("{} of {} function names use {}", matching, function_names.len(), convention),
+            None,
+            1,
+            matching as f64 / function_names.len() as f64,
+            Vec::new(),
+        ));
+    }
+
+    if !analysis.types.is_empty() {
+        let pascal = analysis.types.iter().filter(|t| is_pascal_case(&t.name)).count();
+        patterns.push(pattern(
+            analysis,
+            CodePatternType::NamingConvention,
+            "PascalCase_types",
+            format!("{} of {} type names use PascalCase", pascal, analysis.types.len()),
+            None,
+            1,
+            pascal as f64 / analysis.types.len() as f64,
+            Vec::new(),
+        ));
+    }
+
+    patterns
+}
+
+fn design_patterns(analysis: &FileAnalysis) -> Vec<CodePattern> {
+    let mut patterns = Vec::new();
+    let mut add = |pattern_type: CodePatternType, name: &str, description: String, line: usize, related: Vec<String>| {
+        patterns.push(pattern(analysis, pattern_type, name, description, None, line, HEURISTIC_CONFIDENCE, related));
+    };
+
+    for ty in &analysis.types {
+        if ty.name.ends_with("Builder") || ty.methods.iter().any(|m| m == "build") {
+            add(CodePatternType::DesignPattern, "Builder", format!("{} assembles objects step by step", ty.name), ty.start_line, vec![ty.name.clone()]);
+        }
+        if ty.name.ends_with("Factory") {
+            add(CodePatternType::DesignPattern, "Factory", format!("{} centralizes object creation", ty.name), ty.start_line, vec![ty.name.clone()]);
+        }
+        let observer_methods: Vec<String> = ty.methods.iter()
+            .filter(|m| matches!(m.as_str(), "subscribe" | "unsubscribe" | "notify" | "emit" | "on" | "add_listener" | "addListener" | "addEventListener"))
+            .cloned()
+            .collect();
+        if observer_methods.len() >= 2 {
+            add(CodePatternType::DesignPattern, "Observer", format!("{} notifies registered listeners", ty.name), ty.start_line, observer_methods);
+        }
+        if ty.kind == TypeKind::Trait {
+            let implementors: Vec<String> = analysis.types.iter()
+                .filter(|other| other.bases.contains(&ty.name))
+                .map(|other| other.name.clone())
+                .collect();
+            if implementors.len() >= 2 {
+                add(CodePatternType::DesignPattern, "Strategy", format!("{} has {} interchangeable implementations", ty.name, implementors.len()), ty.start_line, implementors);
+            }
+        }
+    }
+
+    let singleton_accessors: Vec<&FunctionInfo> = analysis.functions.iter()
+        .filter(|f| matches!(f.name.as_str(), "get_instance" | "getInstance" | "instance" | "global" | "shared"))
+        .collect();
+    let lazy_statics = analysis.imports.iter()
+        .any(|i| ["OnceLock", "OnceCell", "lazy_static", "Lazy"].iter().any(|marker| i.module.contains(marker)));
+    if let Some(accessor) = singleton_accessors.first() {
+        add(CodePatternType::DesignPattern, "Singleton", format!("{} hands out a shared instance", accessor.qualified_name), accessor.start_line, Vec::new());
+    } else if lazy_statics {
+        add(CodePatternType::DesignPattern, "Singleton", "Lazily initialised global state".to_string(), 1, Vec::new());
+    }
+
+    let factories: Vec<String> = analysis.functions.iter()
+        .filter(|f| f.name.starts_with("create_") || f.name.starts_with("make_") || (f.name.starts_with("create") && is_camel_case(&f.name)))
+        .map(|f| f.qualified_name.clone())
+        .collect();
+    if !factories.is_empty() {
+        add(CodePatternType::DesignPattern, "Factory", format!("{} factory functions", factories.len()), 1, factories);
+    }
+
+    let repositories: Vec<String> = analysis.types.iter()
+        .filter(|t| t.name.ends_with("Repository") || t.name.ends_with("Repo"))
+        .map(|t| t.name.clone())
+        .collect();
+    let services: Vec<String> = analysis.types.iter()
+        .filter(|t| t.name.ends_with("Service"))
+        .map(|t| t.name.clone())
+        .collect();
+    if !repositories.is_empty() {
+        add(CodePatternType::DesignPattern, "Repository", "Persistence behind repository types".to_string(), 1, repositories.clone());
+    }
+    if !repositories.is_empty() && !services.is_empty() {
+        let mut layers = services;
+        layers.extend(repositories);
+        add(CodePatternType::ArchitecturalPattern, "Layered architecture", "Services delegate persistence to repositories".to_string(), 1, layers);
+    }
+    if !analysis.endpoints.is_empty() {
+        let handlers = analysis.endpoints.iter().filter_map(|e| e.handler.clone()).collect();
+        add(CodePatternType::ArchitecturalPattern, "HTTP API", format!("{} routes mapped to handlers", analysis.endpoints.len()), analysis.endpoints[0].line, handlers);
+    }
+
+    patterns
+}
+
+fn is_snake_case(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name.chars().any(|c| c.is_ascii_uppercase())
+        && !name.contains('_')
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && !name.contains('_')
+        && name.chars().any(|c| c.is_ascii_lowercase())
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(code: &str, language: Option<&str>, depth: PatternAnalysisDepth) -> CodePatternAnalysisRequest {
+        CodePatternAnalysisRequest {
+            code_content: code.to_string(),
+            file_path: None,
+            language: language.map(str::to_string),
+            store_patterns: false,
+            analysis_depth: depth,
+        }
+    }
+
+    const SERVICE: &str = r#"
+use std::sync::OnceLock;
+
+pub trait UserRepository {
+    fn find(&self, id: u64) -> Option<String>;
+}
+
+pub struct SqlUserRepository;
+pub struct MemoryUserRepository;
+
+impl UserRepository for SqlUserRepository {
+    fn find(&self, id: u64) -> Option<String> { None }
+}
+
+impl UserRepository for MemoryUserRepository {
+    fn find(&self, id: u64) -> Option<String> { None }
+}
+
+pub struct UserService {
+    repo: Box<dyn UserRepository>,
+}
+
+impl UserService {
+    pub fn name_of(&self, id: u64) -> Result<String, String> {
+        let name = self.repo.find(id).ok_or("missing")?;
+        Ok(name)
+    }
+}
+"#;
+
+    #[test]
+    fn test_depth_controls_pattern_detail() -> Result<()> {
+        let recognizer = PatternRecognizer::new();
+
+        let basic = recognizer.analyze(&request(SERVICE, Some("rust"), PatternAnalysisDepth::Basic))?;
+        assert_eq!(basic.language_detected.as_deref(), Some("rust"));
+        assert!(basic.patterns_found.iter().all(|p| matches!(p.pattern_type, CodePatternType::DataStructure | CodePatternType::Function)));
+        assert!(basic.patterns_found.iter().all(|p| p.related_patterns.is_empty()));
+
+        let detailed = recognizer.analyze(&request(SERVICE, None, PatternAnalysisDepth::Detailed))?;
+        assert_eq!(detailed.language_detected.as_deref(), Some("rust"));
+        assert!(detailed.patterns_found.iter().any(|p| matches!(p.pattern_type, CodePatternType::ImportPattern) && p.name == "std::sync::OnceLock"));
+        assert!(detailed.patterns_found.iter().any(|p| matches!(p.pattern_type, CodePatternType::ErrorHandling)));
+        let name_of = detailed.patterns_found.iter().find(|p| p.name == "UserService::name_of").expect("method pattern");
+        assert_eq!(name_of.related_patterns, vec!["ok_or", "find", "Ok"]);
+        assert!(detailed.relationships_formed > 0);
+
+        let deep = recognizer.analyze(&request(SERVICE, Some("rust"), PatternAnalysisDepth::Deep))?;
+        let design: Vec<&str> = deep.patterns_found.iter()
+            .filter(|p| matches!(p.pattern_type, CodePatternType::DesignPattern | CodePatternType::ArchitecturalPattern))
+            .map(|p| p.name.as_str())
+            .collect();
+        assert!(design.contains(&"Strategy"));
+        assert!(design.contains(&"Singleton"));
+        assert!(design.contains(&"Repository"));
+        assert!(design.contains(&"Layered architecture"));
+        assert!(deep.patterns_found.iter().any(|p| matches!(p.pattern_type, CodePatternType::NamingConvention) && p.confidence == 1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_language_is_rejected() {
+        let recognizer = PatternRecognizer::new();
+        let result = recognizer.analyze(&request("package main", Some("go"), PatternAnalysisDepth::Basic));
+        assert!(matches!(result, Err(BrainError::InvalidInput(_))));
+    }
+}
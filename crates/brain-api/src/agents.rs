@@ -14,15 +14,21 @@ use brain_cognitive::{
         registry::AgentRegistry,
     },
     // Orchestration types
-    orchestrator::AgentOrchestrator,
+    orchestrator::{
+        AgentOrchestrator, AgentDAG, DAGBuilder, NodeCheckpoint, SqliteWorkflowCheckpointStore,
+        WorkflowCheckpoint, WorkflowCheckpointStatus, WorkflowCheckpointStore,
+        dag::NodeState,
+    },
     // Evolution types for performance monitoring (simplified for now)
     evolution::{AgentPerformanceMonitor, EvolutionConfig},
     // Meta memory for cognitive context
-    meta::MetaMemoryRepository,
+    meta::{InMemoryMetaMemoryRepository, MetaMemoryRepository},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
@@ -32,55 +38,16 @@ use async_trait::async_trait;
 // Type alias for Result with BrainError for convenience
 type Result<T> = std::result::Result<T, BrainError>;
 
+/// Environment variable naming the SQLite database DAG workflow checkpoints are stored in
+pub const WORKFLOW_CHECKPOINT_DATABASE_ENV: &str = "BRAIN_WORKFLOW_CHECKPOINT_DATABASE";
+
+/// Checkpoint database used when `BRAIN_WORKFLOW_CHECKPOINT_DATABASE` is unset
+pub const DEFAULT_WORKFLOW_CHECKPOINT_DATABASE: &str = "data/workflow_checkpoints.db";
+
 // ============================================================================
 // PLACEHOLDER IMPLEMENTATIONS FOR DEMO PURPOSES
 // ============================================================================
 
-/// Simple placeholder implementation for MetaMemoryRepository
-/// TODO: Replace with proper implementation
-struct SimplePlaceholderMetaMemoryRepository;
-
-impl SimplePlaceholderMetaMemoryRepository {
-    fn new() -> Self {
-        Self
-    }
-}
-
-#[async_trait]
-impl brain_cognitive::meta::MetaMemoryRepository for SimplePlaceholderMetaMemoryRepository {
-    async fn store_item(&mut self, _item: brain_cognitive::meta::MetaMemoryItem) -> brain_cognitive::meta::MetaMemoryResult<Uuid> {
-        Ok(Uuid::new_v4())
-    }
-    
-    async fn get_item(&self, _id: Uuid) -> brain_cognitive::meta::MetaMemoryResult<Option<brain_cognitive::meta::MetaMemoryItem>> {
-        Ok(None)
-    }
-    
-    async fn get_item_by_component(&self, _component_id: Uuid) -> brain_cognitive::meta::MetaMemoryResult<Option<brain_cognitive::meta::MetaMemoryItem>> {
-        Ok(None)
-    }
-    
-    async fn query_items(&self, _query: &brain_cognitive::meta::MetaMemoryQuery) -> brain_cognitive::meta::MetaMemoryResult<Vec<brain_cognitive::meta::MetaMemoryItem>> {
-        Ok(Vec::new())
-    }
-    
-    async fn remove_item(&mut self, _id: Uuid) -> brain_cognitive::meta::MetaMemoryResult<bool> {
-        Ok(true)
-    }
-    
-    async fn batch_update(&mut self, _items: Vec<brain_cognitive::meta::MetaMemoryItem>) -> brain_cognitive::meta::MetaMemoryResult<Vec<Uuid>> {
-        Ok(Vec::new())
-    }
-    
-    async fn count_items(&self) -> brain_cognitive::meta::MetaMemoryResult<usize> {
-        Ok(0)
-    }
-    
-    async fn clear_all(&mut self) -> brain_cognitive::meta::MetaMemoryResult<usize> {
-        Ok(0)
-    }
-}
-
 /// Simple placeholder implementation for ConversationService
 /// TODO: Replace with proper implementation
 struct SimplePlaceholderConversationService;
@@ -224,6 +191,10 @@ pub struct WorkflowExecutionRequest {
     pub timeout_seconds: Option<u64>,
     /// Whether to stop on first error or continue
     pub continue_on_error: bool,
+    /// Resume the checkpointed DAG execution with this ID instead of starting
+    /// a new one; `agents` and `execution_strategy` are ignored when set
+    #[serde(default)]
+    pub resume_execution_id: Option<String>,
 }
 
 /// Agent definition within a workflow
@@ -257,7 +228,8 @@ pub enum WorkflowExecutionStrategy {
 /// Response from workflow execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowExecutionResponse {
-    /// Unique workflow execution ID
+    /// Unique workflow execution ID; DAG executions that did not complete can
+    /// be resumed by passing it as `resume_execution_id`
     pub workflow_id: String,
     /// Overall success status
     pub success: bool,
@@ -465,13 +437,14 @@ pub struct CreateProfileResponse {
 /// Main manager for agent API operations
 pub struct AgentApiManager {
     /// Registry of all available agents
-    agent_registry: Arc<Mutex<AgentRegistry>>,
-    /// Agent orchestrator for workflow execution
-    #[allow(dead_code)]
+    agent_registry: Arc<AgentRegistry>,
+    /// Agent orchestrator for checkpointed DAG workflow execution
     orchestrator: Arc<AgentOrchestrator>,
     /// Performance monitoring
     #[allow(dead_code)]
     performance_monitor: Arc<Mutex<AgentPerformanceMonitor>>,
+    /// Meta-memory shared by the performance monitor and agent cognitive contexts
+    meta_memory: Arc<dyn MetaMemoryRepository>,
     /// Active execution tracking
     active_executions: Arc<Mutex<HashMap<String, ExecutionContext>>>,
     /// System start time for uptime calculation
@@ -481,23 +454,32 @@ pub struct AgentApiManager {
 impl AgentApiManager {
     /// Create a new AgentApiManager
     pub async fn new() -> Result<Self> {
-        let agent_registry = Arc::new(Mutex::new(AgentRegistry::new()));
+        let agent_registry = Arc::new(AgentRegistry::new());
         
         // Load all 37 cognitive agents into the registry
         Self::load_all_agents(&agent_registry).await?;
-        
-        // Create orchestrator (no arguments needed)
-        let orchestrator = Arc::new(AgentOrchestrator::new());
+
+        // DAG workflows are checkpointed after each wave so they can be resumed
+        let checkpoint_database = std::env::var(WORKFLOW_CHECKPOINT_DATABASE_ENV)
+            .unwrap_or_else(|_| DEFAULT_WORKFLOW_CHECKPOINT_DATABASE.to_string());
+        if let Some(parent) = Path::new(&checkpoint_database).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| BrainError::Io { source: e })?;
+        }
+        let checkpoint_store: Arc<dyn WorkflowCheckpointStore> =
+            Arc::new(SqliteWorkflowCheckpointStore::open(&checkpoint_database).await?);
+
+        let orchestrator = Arc::new(
+            AgentOrchestrator::new()
+                // Looked up by agent ID when a resumed workflow is rebuilt
+                .with_agent_registry(agent_registry.clone())
+                .with_checkpoint_store(checkpoint_store)
+        );
         
         // Create performance monitor with basic config
         let evolution_config = EvolutionConfig::default();
-        // For now, we'll create a simple placeholder repository
-        // TODO: Replace with proper repository implementation
-        let memory_repo: Arc<dyn MetaMemoryRepository> = Arc::new(
-            SimplePlaceholderMetaMemoryRepository::new()
-        );
+        let meta_memory: Arc<dyn MetaMemoryRepository> = Arc::new(InMemoryMetaMemoryRepository::new());
         let performance_monitor = Arc::new(Mutex::new(
-            AgentPerformanceMonitor::new(evolution_config, memory_repo)?
+            AgentPerformanceMonitor::new(evolution_config, meta_memory.clone())?
         ));
         
         let active_executions = Arc::new(Mutex::new(HashMap::new()));
@@ -506,59 +488,58 @@ impl AgentApiManager {
             agent_registry,
             orchestrator,
             performance_monitor,
+            meta_memory,
             active_executions,
             system_start_time: Utc::now(),
         })
     }
 
     /// Load all 37 cognitive agents into the registry
-    async fn load_all_agents(registry: &Arc<Mutex<AgentRegistry>>) -> Result<()> {
-        let registry_guard = registry.lock().await;
-        
+    async fn load_all_agents(registry: &AgentRegistry) -> Result<()> {
         // Load Development Agents (11 agents)
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::PlannerAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::ArchitectAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::DesignerAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::SchemaAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::APIAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::FrontendCoder::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::BackendCoder::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::RefactorAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::DocAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::DeployerAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::development::MaintainerAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::PlannerAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::ArchitectAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::DesignerAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::SchemaAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::APIAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::FrontendCoder::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::BackendCoder::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::RefactorAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::DocAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::DeployerAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::development::MaintainerAgent::new()))?;
         
         // Load Security Agents (5 agents)
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::security::CyberSecurityAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::security::PromptSecurityAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::security::PrivacyComplianceAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::security::DataPrivacyAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::security::EthicalAIAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::security::CyberSecurityAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::security::PromptSecurityAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::security::PrivacyComplianceAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::security::DataPrivacyAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::security::EthicalAIAgent::new()))?;
         
         // Load Testing & Operations Agents (8 agents)
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::testing::QAAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::testing::SandboxEnvironmentAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::ObservabilityAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::BuildOptimizerAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::DriftDetectionAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::HotfixAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::BackupRecoveryAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::ops::ReplicationScalingAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::testing::QAAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::testing::SandboxEnvironmentAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::ObservabilityAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::BuildOptimizerAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::DriftDetectionAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::HotfixAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::BackupRecoveryAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::ops::ReplicationScalingAgent::new()))?;
         
         // Load Intelligence & Platform Agents (13 agents)
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::intelligence::UserBehaviorAnalystAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::intelligence::FeatureExperimentationAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::intelligence::MLOpsAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::intelligence::ModelTrainingAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::intelligence::DataIngestionAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::LocalizationAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::PlatformCompatibilityAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::DataVisualizationAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::ApiGatewayAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::ServiceMeshAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::ContainerOrchestrationAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::InfrastructureProvisioningAgent::new()))?;
-        registry_guard.register_agent(Arc::new(brain_cognitive::agents::platform::SystemOrchestrationAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::intelligence::UserBehaviorAnalystAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::intelligence::FeatureExperimentationAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::intelligence::MLOpsAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::intelligence::ModelTrainingAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::intelligence::DataIngestionAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::LocalizationAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::PlatformCompatibilityAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::DataVisualizationAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::ApiGatewayAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::ServiceMeshAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::ContainerOrchestrationAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::InfrastructureProvisioningAgent::new()))?;
+        registry.register_agent(Arc::new(brain_cognitive::agents::platform::SystemOrchestrationAgent::new()))?;
         
         println!("✅ Successfully loaded all 37 agents into registry:");
         println!("   • 11 Development Agents");
@@ -571,7 +552,7 @@ impl AgentApiManager {
 
     /// List all available agents with their metadata and performance metrics
     pub async fn list_agents(&self) -> Result<AgentListResponse> {
-        let registry = self.agent_registry.lock().await;
+        let registry = &self.agent_registry;
         let all_agents = registry.list_agents()?;
         
         let mut agents = Vec::new();
@@ -653,12 +634,11 @@ impl AgentApiManager {
         }
 
         // Get agent from registry
-        let registry = self.agent_registry.lock().await;
+        let registry = &self.agent_registry;
         let agent = registry.get_agent(agent_name)?
             .ok_or_else(|| BrainError::NotFound(format!("Agent '{}' not found", agent_name)))?;
 
         let _metadata = agent.metadata().clone();
-        drop(registry); // Release lock early
 
         // Create cognitive context (simplified)
         let cognitive_context = self.create_cognitive_context(&request.context).await?;
@@ -737,7 +717,7 @@ impl AgentApiManager {
 
     /// Get status information for a specific agent
     pub async fn get_agent_status(&self, agent_name: &str) -> Result<AgentStatusResponse> {
-        let registry = self.agent_registry.lock().await;
+        let registry = &self.agent_registry;
         let agent = registry.get_agent(agent_name)?
             .ok_or_else(|| BrainError::NotFound(format!("Agent '{}' not found", agent_name)))?;
 
@@ -812,6 +792,12 @@ impl AgentApiManager {
         &self,
         request: WorkflowExecutionRequest,
     ) -> Result<WorkflowExecutionResponse> {
+        if request.resume_execution_id.is_some()
+            || matches!(request.execution_strategy, WorkflowExecutionStrategy::DAG)
+        {
+            return self.execute_dag_workflow(request).await;
+        }
+
         let workflow_id = Uuid::new_v4().to_string();
         let started_at = Utc::now();
         
@@ -824,8 +810,7 @@ impl AgentApiManager {
             estimated_cost: None,
         };
 
-        // For now, implement simple sequential execution
-        // TODO: Implement proper DAG-based execution
+        // Sequential and parallel workflows run the agents one after another
         for workflow_agent in request.agents {
             let execution_request = AgentExecutionRequest {
                 input: workflow_agent.input,
@@ -870,6 +855,127 @@ impl AgentApiManager {
         })
     }
 
+    /// Run a workflow through the orchestrator, checkpointing it after each wave
+    ///
+    /// A new execution is built from the request's agents and dependencies; a
+    /// resumed one is rebuilt from its checkpoint and only re-runs the agents
+    /// that failed or never ran.
+    async fn execute_dag_workflow(
+        &self,
+        request: WorkflowExecutionRequest,
+    ) -> Result<WorkflowExecutionResponse> {
+        let started_at = Utc::now();
+        let cognitive_context = self.create_cognitive_context(&request.context).await?;
+
+        let (workflow_id, dag) = match &request.resume_execution_id {
+            Some(execution_id) => (execution_id.clone(), None),
+            None => (Uuid::new_v4().to_string(), Some(self.build_workflow_dag(&request).await?)),
+        };
+        let execution = async {
+            match dag {
+                Some(dag) => {
+                    self.orchestrator
+                        .execute_workflow_with_checkpoints(&workflow_id, dag, &cognitive_context)
+                        .await
+                }
+                None => self.orchestrator.resume_workflow(&workflow_id, &cognitive_context).await,
+            }
+        };
+
+        let checkpoint = match request.timeout_seconds {
+            Some(seconds) => tokio::time::timeout(Duration::from_secs(seconds), execution)
+                .await
+                .map_err(|_| BrainError::ExecutionError(format!(
+                    "Workflow {} timed out after {} seconds; resume it to run the remaining agents",
+                    workflow_id, seconds
+                )))??,
+            None => execution.await?,
+        };
+
+        let completed_at = Utc::now();
+        let mut total_resource_usage = ResourceUsage {
+            memory_mb: 0.0,
+            cpu_time_ms: 0,
+            api_calls: 0,
+            estimated_cost: None,
+        };
+        for output in checkpoint.nodes.iter().filter_map(|node| node.output.as_ref()) {
+            total_resource_usage.memory_mb += output.execution_metadata.memory_usage_mb;
+            total_resource_usage.cpu_time_ms += output.execution_metadata.execution_time_ms;
+            total_resource_usage.api_calls += output.execution_metadata.api_calls;
+        }
+
+        Ok(WorkflowExecutionResponse {
+            success: checkpoint.status == WorkflowCheckpointStatus::Completed,
+            agent_results: checkpoint.nodes
+                .iter()
+                .map(|node| node_execution_response(&checkpoint, node, started_at, completed_at))
+                .collect(),
+            total_execution_time_ms: (completed_at - started_at).num_milliseconds() as u64,
+            started_at,
+            completed_at,
+            workflow_errors: workflow_errors(&checkpoint),
+            total_resource_usage,
+            workflow_id,
+        })
+    }
+
+    /// Build the DAG for a workflow request
+    ///
+    /// Agent `i` becomes node `agent_{i}`; dependencies name other agents in the
+    /// request.
+    async fn build_workflow_dag(&self, request: &WorkflowExecutionRequest) -> Result<AgentDAG> {
+        let session_id = request.context
+            .as_ref()
+            .map(|c| c.session_id.clone())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let mut agents = Vec::new();
+        let mut inputs = Vec::new();
+        {
+            let registry = &self.agent_registry;
+            for workflow_agent in &request.agents {
+                let agent = registry.get_agent(&workflow_agent.agent_name)?
+                    .ok_or_else(|| BrainError::NotFound(format!("Agent '{}' not found", workflow_agent.agent_name)))?;
+                agents.push(agent);
+
+                let mut input = AgentInput::new(
+                    workflow_agent.input_type.clone(),
+                    workflow_agent.input.clone(),
+                    session_id.clone(),
+                );
+                if let Some(parameters) = &workflow_agent.parameters {
+                    input.parameters = parameters.clone();
+                }
+                inputs.push(input);
+            }
+        }
+
+        let node_ids: HashMap<&str, String> = request.agents
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, workflow_agent)| (workflow_agent.agent_name.as_str(), format!("agent_{}", i)))
+            .collect();
+
+        let mut builder = DAGBuilder::new().with_agents(agents).with_inputs(inputs);
+        for (i, workflow_agent) in request.agents.iter().enumerate() {
+            let node_id = format!("agent_{}", i);
+            for dependency in &workflow_agent.dependencies {
+                let dependency_id = node_ids.get(dependency.as_str()).ok_or_else(|| BrainError::InvalidInput(format!(
+                    "Agent '{}' depends on '{}', which is not part of the workflow",
+                    workflow_agent.agent_name, dependency
+                )))?;
+                builder = builder.with_dependency(node_id.clone(), dependency_id.clone());
+            }
+            if let Some(priority) = workflow_agent.priority {
+                builder = builder.with_priority(node_id, priority as i32);
+            }
+        }
+
+        builder.build()
+    }
+
     /// Create a basic cognitive context for agent execution
     async fn create_cognitive_context(
         &self,
@@ -878,9 +984,7 @@ impl AgentApiManager {
         // For now, create a minimal cognitive context
         // In a full implementation, this would be much more sophisticated
         
-        let meta_memory: Arc<dyn MetaMemoryRepository> = Arc::new(
-            SimplePlaceholderMetaMemoryRepository::new()
-        );
+        let meta_memory = self.meta_memory.clone();
         
         let conversation_service = Arc::new(
             SimplePlaceholderConversationService::new()
@@ -965,4 +1069,66 @@ impl AgentApiManager {
             message: "Profile created successfully".to_string(),
         })
     }
-} 
\ No newline at end of file
+} 
+
+/// Per-agent result of a checkpointed DAG workflow
+fn node_execution_response(
+    checkpoint: &WorkflowCheckpoint,
+    node: &NodeCheckpoint,
+    started_at: DateTime<Utc>,
+    completed_at: DateTime<Utc>,
+) -> AgentExecutionResponse {
+    let execution_id = format!("{}/{}", checkpoint.execution_id, node.node_id);
+    match &node.output {
+        Some(output) => AgentExecutionResponse {
+            execution_id,
+            success: true,
+            content: output.content.clone(),
+            data: output.data.clone(),
+            confidence: output.confidence,
+            execution_time_ms: output.execution_metadata.execution_time_ms,
+            started_at,
+            completed_at,
+            error: None,
+            reasoning: output.reasoning.clone(),
+            next_actions: output.next_actions.clone(),
+            resource_usage: Some(ResourceUsage {
+                memory_mb: output.execution_metadata.memory_usage_mb,
+                cpu_time_ms: output.execution_metadata.execution_time_ms,
+                api_calls: output.execution_metadata.api_calls,
+                estimated_cost: None,
+            }),
+        },
+        None => AgentExecutionResponse {
+            execution_id,
+            success: false,
+            content: String::new(),
+            data: HashMap::new(),
+            confidence: 0.0,
+            execution_time_ms: 0,
+            started_at,
+            completed_at,
+            error: Some(node_status_message(node)),
+            reasoning: None,
+            next_actions: Vec::new(),
+            resource_usage: None,
+        },
+    }
+}
+
+fn node_status_message(node: &NodeCheckpoint) -> String {
+    match (&node.state, &node.error) {
+        (NodeState::Failed, Some(error)) => error.clone(),
+        (NodeState::Skipped, _) => "Skipped: confidence below threshold".to_string(),
+        _ => "Not run: a dependency did not complete".to_string(),
+    }
+}
+
+/// Errors for the agents a resumed execution would run again
+fn workflow_errors(checkpoint: &WorkflowCheckpoint) -> Vec<String> {
+    checkpoint.nodes
+        .iter()
+        .filter(|node| !node.is_finished())
+        .map(|node| format!("Agent '{}' ({}): {}", node.agent_id, node.node_id, node_status_message(node)))
+        .collect()
+}
@@ -6,11 +6,14 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 use anyhow::{Result, Context};
 use brain_types::BrainError;
+use crate::auth_store::{AuthStore, InMemoryAuthStore};
 
 /// User roles defining different access levels
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -50,6 +53,9 @@ pub enum Permission {
 }
 
 impl UserRole {
+    /// All roles, from most to least privileged
+    pub const ALL: [UserRole; 4] = [UserRole::Admin, UserRole::Developer, UserRole::Analyst, UserRole::Viewer];
+
     /// Check if this role has a specific permission
     pub fn has_permission(&self, permission: &Permission) -> bool {
         match self {
@@ -69,6 +75,21 @@ impl UserRole {
         }
     }
     
+    /// Privilege level, higher for roles whose permissions include a lower role's
+    pub fn level(&self) -> u8 {
+        match self {
+            UserRole::Admin => 3,
+            UserRole::Developer => 2,
+            UserRole::Analyst => 1,
+            UserRole::Viewer => 0,
+        }
+    }
+
+    /// The less privileged of two roles
+    pub fn min(self, other: UserRole) -> UserRole {
+        if other.level() < self.level() { other } else { self }
+    }
+
     /// Get the default rate limit for this role (requests per minute)
     pub fn default_rate_limit(&self) -> u32 {
         match self {
@@ -78,6 +99,61 @@ impl UserRole {
             UserRole::Viewer => 100,
         }
     }
+
+    /// Variant name, as used in storage and on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            UserRole::Admin => "Admin",
+            UserRole::Developer => "Developer",
+            UserRole::Analyst => "Analyst",
+            UserRole::Viewer => "Viewer",
+        }
+    }
+
+    /// Parse a role name, case-insensitively
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl Permission {
+    /// All permissions
+    pub const ALL: [Permission; 16] = [
+        Permission::QueryMemory, Permission::WriteMemory, Permission::DeleteMemory, Permission::ManageMemory,
+        Permission::CreateUser, Permission::ReadUser, Permission::UpdateUser, Permission::DeleteUser,
+        Permission::ManageUsers, Permission::SystemAdmin, Permission::ViewLogs, Permission::ManageLogs,
+        Permission::SystemMetrics, Permission::UseAPI, Permission::ManageAPI, Permission::RateLimitExempt,
+    ];
+
+    /// Variant name, as used in storage and on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            Permission::QueryMemory => "QueryMemory",
+            Permission::WriteMemory => "WriteMemory",
+            Permission::DeleteMemory => "DeleteMemory",
+            Permission::ManageMemory => "ManageMemory",
+            Permission::CreateUser => "CreateUser",
+            Permission::ReadUser => "ReadUser",
+            Permission::UpdateUser => "UpdateUser",
+            Permission::DeleteUser => "DeleteUser",
+            Permission::ManageUsers => "ManageUsers",
+            Permission::SystemAdmin => "SystemAdmin",
+            Permission::ViewLogs => "ViewLogs",
+            Permission::ManageLogs => "ManageLogs",
+            Permission::SystemMetrics => "SystemMetrics",
+            Permission::UseAPI => "UseAPI",
+            Permission::ManageAPI => "ManageAPI",
+            Permission::RateLimitExempt => "RateLimitExempt",
+        }
+    }
+
+    /// Parse a permission name, ignoring case, `_` and `-` (`query_memory` matches `QueryMemory`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized: String = name.trim().chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect();
+        Self::ALL.into_iter().find(|permission| permission.name().eq_ignore_ascii_case(&normalized))
+    }
 }
 
 /// User account information
@@ -122,16 +198,102 @@ pub struct Claims {
     pub iss: String,  // Issuer
 }
 
-/// API key information
+/// Stored API key record
+///
+/// Only the SHA-256 hash of the key is kept; the plaintext key is returned once, when
+/// the key is issued.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiKey {
-    pub key: String,
+    /// Public identifier used to list and revoke the key
+    pub id: String,
+    /// Hex-encoded SHA-256 of the full key
+    pub key_hash: String,
+    /// Leading characters of the key, to help operators recognise it
+    pub key_prefix: String,
     pub user_id: String,
     pub role: UserRole,
     pub description: String,
+    /// Permissions the key is restricted to, on top of its role; `None` allows the whole role
+    pub scopes: Option<Vec<Permission>>,
     pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
     pub last_used: Option<DateTime<Utc>>,
-    pub active: bool,
+    /// Set when the key is on the revocation list
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// Whether the key has passed its expiry time
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Whether the key can still be used
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && !self.is_expired(now)
+    }
+}
+
+/// Entry on the API key revocation list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyRevocation {
+    pub key_id: String,
+    pub revoked_at: DateTime<Utc>,
+    pub reason: String,
+}
+
+/// Parameters for issuing an API key
+#[derive(Debug, Clone)]
+pub struct ApiKeyRequest {
+    pub user_id: String,
+    pub role: UserRole,
+    pub description: String,
+    pub scopes: Option<Vec<Permission>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyRequest {
+    pub fn new(user_id: &str, role: UserRole, description: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            role,
+            description: description.to_string(),
+            scopes: None,
+            expires_at: None,
+        }
+    }
+
+    /// Restrict the key to `scopes`
+    pub fn with_scopes(mut self, scopes: Vec<Permission>) -> Self {
+        self.scopes = Some(scopes);
+        self
+    }
+
+    /// Expire the key at `expires_at`
+    pub fn expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
+
+/// Newly issued API key: the plaintext key and its stored record
+#[derive(Debug, Clone)]
+pub struct IssuedApiKey {
+    pub key: String,
+    pub record: ApiKey,
+}
+
+/// Hex-encoded SHA-256 of an API key
+pub fn hash_api_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// 256 random bits, hex-encoded, for signing secrets nobody configured
+pub fn random_secret() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
 }
 
 /// Authentication configuration
@@ -146,9 +308,10 @@ pub struct AuthConfig {
 }
 
 impl Default for AuthConfig {
+    /// Development defaults with a random JWT secret, so tokens only verify in this process
     fn default() -> Self {
         Self {
-            jwt_secret: "brain_ai_jwt_secret_key_changeme_in_production".to_string(),
+            jwt_secret: random_secret(),
             jwt_expiration_hours: 24,
             api_key_prefix: "brain_".to_string(),
             issuer: "brain-ai".to_string(),
@@ -163,6 +326,8 @@ impl Default for AuthConfig {
 pub struct AuthResult {
     pub user_id: String,
     pub role: UserRole,
+    /// Scopes of the API key used, if it was restricted
+    pub scopes: Option<Vec<Permission>>,
     pub authenticated_at: DateTime<Utc>,
 }
 
@@ -171,109 +336,183 @@ impl AuthResult {
         Self {
             user_id,
             role,
+            scopes: None,
             authenticated_at: Utc::now(),
         }
     }
+
+    /// Restrict the result to `scopes`
+    pub fn with_scopes(mut self, scopes: Option<Vec<Permission>>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// Check a permission against both the role and the key scopes
+    pub fn has_permission(&self, permission: &Permission) -> bool {
+        self.role.has_permission(permission)
+            && self.scopes.as_ref().is_none_or(|scopes| scopes.contains(permission))
+    }
 }
 
 /// Main authentication manager
+///
+/// Users and API keys live in an `AuthStore`, so several managers sharing a durable
+/// store see the same accounts and revocations.
 pub struct AuthManager {
     config: AuthConfig,
-    users: HashMap<String, User>,
-    api_keys: HashMap<String, ApiKey>,
+    store: Arc<dyn AuthStore>,
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
 }
 
 impl AuthManager {
-    /// Create a new authentication manager
+    /// Create a new authentication manager backed by an in-memory store
     pub fn new(config: AuthConfig) -> Result<Self> {
+        Self::with_store(config, Arc::new(InMemoryAuthStore::new()))
+    }
+
+    /// Create an authentication manager on an existing store
+    pub fn with_store(config: AuthConfig, store: Arc<dyn AuthStore>) -> Result<Self> {
         let encoding_key = EncodingKey::from_secret(config.jwt_secret.as_ref());
         let decoding_key = DecodingKey::from_secret(config.jwt_secret.as_ref());
         
         Ok(Self {
             config,
-            users: HashMap::new(),
-            api_keys: HashMap::new(),
+            store,
             encoding_key,
             decoding_key,
         })
     }
+
+    /// Configuration this manager was created with
+    pub fn config(&self) -> &AuthConfig {
+        &self.config
+    }
+
+    /// Store holding users and API keys
+    pub fn store(&self) -> Arc<dyn AuthStore> {
+        self.store.clone()
+    }
     
     /// Add a new user to the system
-    pub fn add_user(&mut self, user: User) -> Result<()> {
-        if self.users.contains_key(&user.id) {
+    pub async fn add_user(&self, user: User) -> Result<()> {
+        if self.store.get_user(&user.id).await?.is_some() {
             return Err(BrainError::Conflict(format!("User {} already exists", user.id)).into());
         }
         
-        self.users.insert(user.id.clone(), user);
+        self.store.save_user(&user).await?;
         Ok(())
     }
     
     /// Get a user by ID
-    pub fn get_user(&self, user_id: &str) -> Option<&User> {
-        self.users.get(user_id)
+    pub async fn get_user(&self, user_id: &str) -> Result<Option<User>> {
+        Ok(self.store.get_user(user_id).await?)
+    }
+
+    /// List all users
+    pub async fn list_users(&self) -> Result<Vec<User>> {
+        Ok(self.store.list_users().await?)
     }
     
-    /// Update user login timestamp
-    pub fn mark_user_login(&mut self, user_id: &str) -> Result<()> {
-        if let Some(user) = self.users.get_mut(user_id) {
-            user.mark_login();
-            Ok(())
-        } else {
-            Err(BrainError::NotFound(format!("User {} not found", user_id)).into())
+    /// Mark user login
+    pub async fn mark_user_login(&self, user_id: &str) -> Result<()> {
+        match self.store.get_user(user_id).await? {
+            Some(mut user) => {
+                user.mark_login();
+                self.store.save_user(&user).await?;
+                Ok(())
+            }
+            None => Err(BrainError::NotFound(format!("User {} not found", user_id)).into()),
         }
     }
     
-    /// Generate a new API key for a user
-    pub fn generate_api_key(&mut self, user_id: &str, role: UserRole, description: &str) -> Result<String> {
-        // Verify user exists
-        if !self.users.contains_key(user_id) {
-            return Err(BrainError::NotFound(format!("User {} not found", user_id)).into());
+    /// Generate a new unscoped, non-expiring API key for a user
+    pub async fn generate_api_key(&self, user_id: &str, role: UserRole, description: &str) -> Result<String> {
+        let issued = self.issue_api_key(ApiKeyRequest::new(user_id, role, description)).await?;
+        Ok(issued.key)
+    }
+
+    /// Issue an API key, storing only its hash
+    ///
+    /// A key can not be granted a role above its owner's.
+    pub async fn issue_api_key(&self, request: ApiKeyRequest) -> Result<IssuedApiKey> {
+        let Some(user) = self.store.get_user(&request.user_id).await? else {
+            return Err(BrainError::NotFound(format!("User {} not found", request.user_id)).into());
+        };
+        if request.role.level() > user.role.level() {
+            return Err(BrainError::InvalidInput(format!(
+                "Cannot issue a {} key to {}, whose role is {}",
+                request.role.name(), user.id, user.role.name()
+            )).into());
         }
         
         let key = format!("{}{}", self.config.api_key_prefix, Uuid::new_v4().to_string().replace("-", ""));
+        let visible = (self.config.api_key_prefix.len() + 6).min(key.len());
         
-        let api_key = ApiKey {
-            key: key.clone(),
-            user_id: user_id.to_string(),
-            role,
-            description: description.to_string(),
+        let record = ApiKey {
+            id: Uuid::new_v4().to_string(),
+            key_hash: hash_api_key(&key),
+            key_prefix: key[..visible].to_string(),
+            user_id: request.user_id,
+            role: request.role,
+            description: request.description,
+            scopes: request.scopes,
             created_at: Utc::now(),
+            expires_at: request.expires_at,
             last_used: None,
-            active: true,
+            revoked_at: None,
         };
         
-        self.api_keys.insert(key.clone(), api_key);
-        Ok(key)
+        self.store.save_api_key(&record).await?;
+        Ok(IssuedApiKey { key, record })
     }
     
-    /// Validate an API key and return user info
-    pub fn validate_api_key(&mut self, key: &str) -> Result<(String, UserRole)> {
-        if let Some(api_key) = self.api_keys.get_mut(key) {
-            if !api_key.active {
-                return Err(BrainError::Unauthorized("API key is disabled".to_string()).into());
-            }
-            
-            // Update last used timestamp
-            api_key.last_used = Some(Utc::now());
-            
-            let user_id = api_key.user_id.clone();
-            let role = api_key.role.clone();
-            
-            // Mark user login
-            self.mark_user_login(&user_id)?;
-            
-            Ok((user_id, role))
-        } else {
-            Err(BrainError::Unauthorized("Invalid API key".to_string()).into())
+    /// Validate an API key and return the authenticated identity
+    pub async fn validate_api_key(&self, key: &str) -> Result<AuthResult> {
+        let (api_key_id, result) = self.check_api_key(key).await?;
+
+        // Update last used timestamp
+        self.store.record_api_key_use(&api_key_id, Utc::now()).await?;
+
+        // Mark user login
+        self.mark_user_login(&result.user_id).await?;
+
+        Ok(result)
+    }
+
+    /// Resolve the identity behind an API key without recording a use of it
+    pub async fn identify_api_key(&self, key: &str) -> Result<AuthResult> {
+        Ok(self.check_api_key(key).await?.1)
+    }
+
+    /// Check an API key is known, live and owned by an active user
+    async fn check_api_key(&self, key: &str) -> Result<(String, AuthResult)> {
+        let Some(api_key) = self.store.find_api_key(&hash_api_key(key)).await? else {
+            return Err(BrainError::Unauthorized("Invalid API key".to_string()).into());
+        };
+
+        let now = Utc::now();
+        if api_key.revoked_at.is_some() {
+            return Err(BrainError::Unauthorized("API key has been revoked".to_string()).into());
+        }
+        if api_key.is_expired(now) {
+            return Err(BrainError::Unauthorized("API key has expired".to_string()).into());
         }
+        let user = match self.store.get_user(&api_key.user_id).await? {
+            Some(user) if user.active => user,
+            _ => return Err(BrainError::Unauthorized("API key owner is disabled".to_string()).into()),
+        };
+
+        // A key never outranks its owner, who may have been demoted since it was issued
+        let role = api_key.role.min(user.role);
+        let result = AuthResult::new(api_key.user_id, role).with_scopes(api_key.scopes);
+        Ok((api_key.id, result))
     }
     
     /// Generate a JWT token for a user
-    pub fn generate_token(&self, user_id: &str, role: UserRole) -> Result<String> {
+    pub async fn generate_token(&self, user_id: &str, role: UserRole) -> Result<String> {
         // Verify user exists
-        if !self.users.contains_key(user_id) {
+        if self.store.get_user(user_id).await?.is_none() {
             return Err(BrainError::NotFound(format!("User {} not found", user_id)).into());
         }
         
@@ -302,36 +541,40 @@ impl AuthManager {
             
         Ok(token_data.claims)
     }
+
+    /// List API keys, optionally for a single user
+    pub async fn list_api_keys(&self, user_id: Option<&str>) -> Result<Vec<ApiKey>> {
+        Ok(self.store.list_api_keys(user_id).await?)
+    }
     
-    /// Revoke an API key
-    pub fn revoke_api_key(&mut self, key: &str) -> Result<bool> {
-        if let Some(api_key) = self.api_keys.get_mut(key) {
-            api_key.active = false;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+    /// Revoke an API key by its id, returning false if no such key exists
+    pub async fn revoke_api_key(&self, key_id: &str, reason: &str) -> Result<bool> {
+        Ok(self.store.revoke_api_key(key_id, reason).await?)
+    }
+
+    /// Current revocation list
+    pub async fn revocations(&self) -> Result<Vec<ApiKeyRevocation>> {
+        Ok(self.store.list_revocations().await?)
     }
     
     /// Get authentication statistics
-    pub fn get_stats(&self) -> AuthStats {
-        let total_users = self.users.len();
-        let active_users = self.users.values().filter(|u| u.active).count();
-        let total_api_keys = self.api_keys.len();
-        let active_api_keys = self.api_keys.values().filter(|k| k.active).count();
+    pub async fn get_stats(&self) -> Result<AuthStats> {
+        let users = self.store.list_users().await?;
+        let api_keys = self.store.list_api_keys(None).await?;
+        let now = Utc::now();
         
         let mut role_distribution = HashMap::new();
-        for user in self.users.values() {
+        for user in &users {
             *role_distribution.entry(user.role.clone()).or_insert(0) += 1;
         }
         
-        AuthStats {
-            total_users,
-            active_users,
-            total_api_keys,
-            active_api_keys,
+        Ok(AuthStats {
+            total_users: users.len(),
+            active_users: users.iter().filter(|u| u.active).count(),
+            total_api_keys: api_keys.len(),
+            active_api_keys: api_keys.iter().filter(|k| k.is_active(now)).count(),
             role_distribution,
-        }
+        })
     }
 }
 
@@ -370,10 +613,17 @@ mod tests {
         assert!(!UserRole::Viewer.has_permission(&Permission::DeleteMemory));
     }
 
+    #[test]
+    fn test_default_jwt_secret_is_random() {
+        let (first, second) = (AuthConfig::default(), AuthConfig::default());
+        assert_eq!(first.jwt_secret.len(), 64);
+        assert_ne!(first.jwt_secret, second.jwt_secret);
+    }
+
     #[tokio::test]
     async fn test_auth_manager() {
         let config = AuthConfig::default();
-        let mut auth_manager = AuthManager::new(config).unwrap();
+        let auth_manager = AuthManager::new(config).unwrap();
         
         let user = User::new(
             "test_001".to_string(),
@@ -382,12 +632,79 @@ mod tests {
             UserRole::Developer,
         );
         
-        auth_manager.add_user(user).unwrap();
+        auth_manager.add_user(user).await.unwrap();
         
-        let api_key = auth_manager.generate_api_key("test_001", UserRole::Developer, "Test key").unwrap();
-        let (user_id, role) = auth_manager.validate_api_key(&api_key).unwrap();
+        let api_key = auth_manager.generate_api_key("test_001", UserRole::Developer, "Test key").await.unwrap();
+        let auth = auth_manager.validate_api_key(&api_key).await.unwrap();
         
-        assert_eq!(user_id, "test_001");
-        assert_eq!(role, UserRole::Developer);
+        assert_eq!(auth.user_id, "test_001");
+        assert_eq!(auth.role, UserRole::Developer);
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_api_key_lifecycle() {
+        let auth_manager = AuthManager::new(AuthConfig::default()).unwrap();
+        let user = User::new("ops".to_string(), "Ops".to_string(), String::new(), UserRole::Developer);
+        auth_manager.add_user(user).await.unwrap();
+
+        let request = ApiKeyRequest::new("ops", UserRole::Developer, "scoped")
+            .with_scopes(vec![Permission::QueryMemory]);
+        let issued = auth_manager.issue_api_key(request).await.unwrap();
+        assert_eq!(issued.record.key_hash, hash_api_key(&issued.key));
+        assert!(!issued.record.key_hash.contains(&issued.key));
+
+        let auth = auth_manager.validate_api_key(&issued.key).await.unwrap();
+        assert!(auth.has_permission(&Permission::QueryMemory));
+        assert!(!auth.has_permission(&Permission::WriteMemory));
+
+        let keys = auth_manager.list_api_keys(Some("ops")).await.unwrap();
+        assert!(keys[0].last_used.is_some());
+
+        assert!(auth_manager.revoke_api_key(&issued.record.id, "rotated").await.unwrap());
+        assert!(auth_manager.validate_api_key(&issued.key).await.is_err());
+        assert_eq!(auth_manager.revocations().await.unwrap()[0].reason, "rotated");
+
+        let expired = ApiKeyRequest::new("ops", UserRole::Developer, "expired")
+            .expires_at(Utc::now() - chrono::Duration::minutes(1));
+        let expired = auth_manager.issue_api_key(expired).await.unwrap();
+        assert!(auth_manager.validate_api_key(&expired.key).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_key_role_is_capped_by_its_owner() {
+        let auth_manager = AuthManager::new(AuthConfig::default()).unwrap();
+        let user = User::new("analyst".to_string(), "Analyst".to_string(), String::new(), UserRole::Analyst);
+        auth_manager.add_user(user).await.unwrap();
+
+        let escalation = ApiKeyRequest::new("analyst", UserRole::Admin, "escalation");
+        assert!(auth_manager.issue_api_key(escalation).await.is_err());
+        assert!(auth_manager.list_api_keys(Some("analyst")).await.unwrap().is_empty());
+
+        let narrower = ApiKeyRequest::new("analyst", UserRole::Viewer, "read only");
+        let issued = auth_manager.issue_api_key(narrower).await.unwrap();
+        assert_eq!(auth_manager.validate_api_key(&issued.key).await.unwrap().role, UserRole::Viewer);
+    }
+
+    #[tokio::test]
+    async fn test_api_key_role_follows_owner_demotion() {
+        let auth_manager = AuthManager::new(AuthConfig::default()).unwrap();
+        let user = User::new("ops".to_string(), "Ops".to_string(), String::new(), UserRole::Admin);
+        auth_manager.add_user(user.clone()).await.unwrap();
+        let key = auth_manager.generate_api_key("ops", UserRole::Admin, "ops key").await.unwrap();
+
+        let demoted = User { role: UserRole::Viewer, ..user };
+        auth_manager.store.save_user(&demoted).await.unwrap();
+
+        let auth = auth_manager.validate_api_key(&key).await.unwrap();
+        assert_eq!(auth.role, UserRole::Viewer);
+        assert!(!auth.has_permission(&Permission::ManageUsers));
+    }
+
+    #[test]
+    fn test_name_parsing() {
+        assert_eq!(UserRole::from_name("developer"), Some(UserRole::Developer));
+        assert_eq!(Permission::from_name("query_memory"), Some(Permission::QueryMemory));
+        assert_eq!(Permission::from_name("UseAPI"), Some(Permission::UseAPI));
+        assert_eq!(Permission::from_name("nope"), None);
+    }
+}
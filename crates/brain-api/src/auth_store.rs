@@ -0,0 +1,474 @@
+//! Authentication Storage Module
+//!
+//! Persistence for users, API keys and the API key revocation list. `AuthManager`
+//! works against the `AuthStore` trait, with an in-memory store for tests and
+//! single-process use and a SQLite store for keys that must survive restarts and be
+//! shared between server instances.
+
+use std::collections::HashMap;
+use std::path::Path;
+use async_trait::async_trait;
+use brain_infra::database::{column, parse_timestamp};
+use brain_infra::DatabaseManager;
+use brain_types::{BrainError, Result};
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+
+use crate::auth::{ApiKey, ApiKeyRevocation, Permission, User, UserRole};
+
+/// Storage for users, hashed API keys and revocations
+#[async_trait]
+pub trait AuthStore: Send + Sync {
+    /// Insert or update a user
+    async fn save_user(&self, user: &User) -> Result<()>;
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<User>>;
+
+    /// All users, ordered by id
+    async fn list_users(&self) -> Result<Vec<User>>;
+
+    /// Insert a new API key record
+    async fn save_api_key(&self, key: &ApiKey) -> Result<()>;
+
+    /// Look up a key by the hash of its plaintext, with `revoked_at` filled in
+    async fn find_api_key(&self, key_hash: &str) -> Result<Option<ApiKey>>;
+
+    /// Keys ordered by creation time, optionally for a single user
+    async fn list_api_keys(&self, user_id: Option<&str>) -> Result<Vec<ApiKey>>;
+
+    /// Update the last-used time of a key
+    async fn record_api_key_use(&self, key_id: &str, used_at: DateTime<Utc>) -> Result<()>;
+
+    /// Add a key to the revocation list, returning false if the key does not exist
+    ///
+    /// Revoking an already revoked key keeps the original revocation entry.
+    async fn revoke_api_key(&self, key_id: &str, reason: &str) -> Result<bool>;
+
+    /// The revocation list, oldest first
+    async fn list_revocations(&self) -> Result<Vec<ApiKeyRevocation>>;
+}
+
+/// Process-local store; contents are lost when it is dropped
+#[derive(Default)]
+pub struct InMemoryAuthStore {
+    users: RwLock<HashMap<String, User>>,
+    api_keys: RwLock<HashMap<String, ApiKey>>,
+    revocations: RwLock<Vec<ApiKeyRevocation>>,
+}
+
+impl InMemoryAuthStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn with_revocation(&self, mut key: ApiKey) -> ApiKey {
+        key.revoked_at = self.revocations.read().await.iter()
+            .find(|revocation| revocation.key_id == key.id)
+            .map(|revocation| revocation.revoked_at);
+        key
+    }
+}
+
+#[async_trait]
+impl AuthStore for InMemoryAuthStore {
+    async fn save_user(&self, user: &User) -> Result<()> {
+        self.users.write().await.insert(user.id.clone(), user.clone());
+        Ok(())
+    }
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<User>> {
+        Ok(self.users.read().await.get(user_id).cloned())
+    }
+
+    async fn list_users(&self) -> Result<Vec<User>> {
+        let mut users: Vec<User> = self.users.read().await.values().cloned().collect();
+        users.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(users)
+    }
+
+    async fn save_api_key(&self, key: &ApiKey) -> Result<()> {
+        let mut api_keys = self.api_keys.write().await;
+        if api_keys.values().any(|existing| existing.id == key.id || existing.key_hash == key.key_hash) {
+            return Err(BrainError::Conflict(format!("API key {} already exists", key.id)));
+        }
+        api_keys.insert(key.id.clone(), key.clone());
+        Ok(())
+    }
+
+    async fn find_api_key(&self, key_hash: &str) -> Result<Option<ApiKey>> {
+        let key = self.api_keys.read().await.values()
+            .find(|key| key.key_hash == key_hash)
+            .cloned();
+        match key {
+            Some(key) => Ok(Some(self.with_revocation(key).await)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list_api_keys(&self, user_id: Option<&str>) -> Result<Vec<ApiKey>> {
+        let mut keys: Vec<ApiKey> = self.api_keys.read().await.values()
+            .filter(|key| user_id.is_none_or(|user_id| key.user_id == user_id))
+            .cloned()
+            .collect();
+        keys.sort_by_key(|key| key.created_at);
+
+        let mut listed = Vec::with_capacity(keys.len());
+        for key in keys {
+            listed.push(self.with_revocation(key).await);
+        }
+        Ok(listed)
+    }
+
+    async fn record_api_key_use(&self, key_id: &str, used_at: DateTime<Utc>) -> Result<()> {
+        if let Some(key) = self.api_keys.write().await.get_mut(key_id) {
+            key.last_used = Some(used_at);
+        }
+        Ok(())
+    }
+
+    async fn revoke_api_key(&self, key_id: &str, reason: &str) -> Result<bool> {
+        if !self.api_keys.read().await.contains_key(key_id) {
+            return Ok(false);
+        }
+
+        let mut revocations = self.revocations.write().await;
+        if !revocations.iter().any(|revocation| revocation.key_id == key_id) {
+            revocations.push(ApiKeyRevocation {
+                key_id: key_id.to_string(),
+                revoked_at: Utc::now(),
+                reason: reason.to_string(),
+            });
+        }
+        Ok(true)
+    }
+
+    async fn list_revocations(&self) -> Result<Vec<ApiKeyRevocation>> {
+        Ok(self.revocations.read().await.clone())
+    }
+}
+
+const API_KEY_COLUMNS: &str = "k.id, k.key_hash, k.key_prefix, k.user_id, k.role, k.description, k.scopes, \
+    k.created_at, k.expires_at, k.last_used, r.revoked_at";
+
+/// SQLite store on the `auth_users`, `auth_api_keys` and `auth_api_key_revocations` tables
+pub struct SqliteAuthStore {
+    pool: SqlitePool,
+}
+
+impl SqliteAuthStore {
+    /// Open the store on an existing database, creating its tables if needed
+    pub async fn new(database: &DatabaseManager) -> Result<Self> {
+        let store = Self { pool: database.pool().clone() };
+        store.initialize_schema().await?;
+        Ok(store)
+    }
+
+    /// Open the store on a database file, creating the file if it does not exist
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let database = DatabaseManager::new_file(path).await?;
+        Self::new(&database).await
+    }
+
+    async fn initialize_schema(&self) -> Result<()> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS auth_users (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                email TEXT NOT NULL,
+                role TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                last_login TEXT,
+                active INTEGER NOT NULL,
+                metadata TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create auth_users table: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS auth_api_keys (
+                id TEXT PRIMARY KEY,
+                key_hash TEXT NOT NULL UNIQUE,
+                key_prefix TEXT NOT NULL,
+                user_id TEXT NOT NULL REFERENCES auth_users(id),
+                role TEXT NOT NULL,
+                description TEXT NOT NULL,
+                scopes TEXT,
+                created_at TEXT NOT NULL,
+                expires_at TEXT,
+                last_used TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create auth_api_keys table: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS auth_api_key_revocations (
+                key_id TEXT PRIMARY KEY REFERENCES auth_api_keys(id),
+                revoked_at TEXT NOT NULL,
+                reason TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create auth_api_key_revocations table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_auth_api_keys_user ON auth_api_keys(user_id)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to create auth_api_keys index: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuthStore for SqliteAuthStore {
+    async fn save_user(&self, user: &User) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO auth_users (id, name, email, role, created_at, last_login, active, metadata)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name, email = excluded.email, role = excluded.role,
+                last_login = excluded.last_login, active = excluded.active, metadata = excluded.metadata",
+        )
+        .bind(&user.id)
+        .bind(&user.name)
+        .bind(&user.email)
+        .bind(user.role.name())
+        .bind(user.created_at.to_rfc3339())
+        .bind(user.last_login.map(|timestamp| timestamp.to_rfc3339()))
+        .bind(user.active)
+        .bind(serde_json::to_string(&user.metadata)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to save user: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<User>> {
+        let row = sqlx::query("SELECT * FROM auth_users WHERE id = ?1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to load user: {}", e)))?;
+
+        row.as_ref().map(row_to_user).transpose()
+    }
+
+    async fn list_users(&self) -> Result<Vec<User>> {
+        let rows = sqlx::query("SELECT * FROM auth_users ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to list users: {}", e)))?;
+
+        rows.iter().map(row_to_user).collect()
+    }
+
+    async fn save_api_key(&self, key: &ApiKey) -> Result<()> {
+        let scopes = key.scopes.as_ref()
+            .map(|scopes| serde_json::to_string(&scopes.iter().map(Permission::name).collect::<Vec<_>>()))
+            .transpose()?;
+
+        sqlx::query(
+            "INSERT INTO auth_api_keys
+                (id, key_hash, key_prefix, user_id, role, description, scopes, created_at, expires_at, last_used)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        )
+        .bind(&key.id)
+        .bind(&key.key_hash)
+        .bind(&key.key_prefix)
+        .bind(&key.user_id)
+        .bind(key.role.name())
+        .bind(&key.description)
+        .bind(scopes)
+        .bind(key.created_at.to_rfc3339())
+        .bind(key.expires_at.map(|timestamp| timestamp.to_rfc3339()))
+        .bind(key.last_used.map(|timestamp| timestamp.to_rfc3339()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to save API key: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_api_key(&self, key_hash: &str) -> Result<Option<ApiKey>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM auth_api_keys k LEFT JOIN auth_api_key_revocations r ON r.key_id = k.id
+             WHERE k.key_hash = ?1",
+            API_KEY_COLUMNS,
+        ))
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to look up API key: {}", e)))?;
+
+        row.as_ref().map(row_to_api_key).transpose()
+    }
+
+    async fn list_api_keys(&self, user_id: Option<&str>) -> Result<Vec<ApiKey>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM auth_api_keys k LEFT JOIN auth_api_key_revocations r ON r.key_id = k.id
+             WHERE ?1 IS NULL OR k.user_id = ?1
+             ORDER BY k.created_at",
+            API_KEY_COLUMNS,
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to list API keys: {}", e)))?;
+
+        rows.iter().map(row_to_api_key).collect()
+    }
+
+    async fn record_api_key_use(&self, key_id: &str, used_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE auth_api_keys SET last_used = ?1 WHERE id = ?2")
+            .bind(used_at.to_rfc3339())
+            .bind(key_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to record API key use: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_api_key(&self, key_id: &str, reason: &str) -> Result<bool> {
+        let exists = sqlx::query("SELECT 1 FROM auth_api_keys WHERE id = ?1")
+            .bind(key_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to look up API key: {}", e)))?
+            .is_some();
+        if !exists {
+            return Ok(false);
+        }
+
+        sqlx::query("INSERT OR IGNORE INTO auth_api_key_revocations (key_id, revoked_at, reason) VALUES (?1, ?2, ?3)")
+            .bind(key_id)
+            .bind(Utc::now().to_rfc3339())
+            .bind(reason)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to revoke API key: {}", e)))?;
+
+        Ok(true)
+    }
+
+    async fn list_revocations(&self) -> Result<Vec<ApiKeyRevocation>> {
+        let rows = sqlx::query("SELECT key_id, revoked_at, reason FROM auth_api_key_revocations ORDER BY revoked_at")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to list revocations: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ApiKeyRevocation {
+                    key_id: column(row, "key_id")?,
+                    revoked_at: parse_timestamp(&column::<String>(row, "revoked_at")?)?,
+                    reason: column(row, "reason")?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn optional_timestamp(row: &SqliteRow, name: &str) -> Result<Option<DateTime<Utc>>> {
+    column::<Option<String>>(row, name)?
+        .map(|value| parse_timestamp(&value))
+        .transpose()
+}
+
+fn parse_role(value: &str) -> Result<UserRole> {
+    UserRole::from_name(value).ok_or_else(|| BrainError::ParseError(format!("Unknown role {}", value)))
+}
+
+fn row_to_user(row: &SqliteRow) -> Result<User> {
+    Ok(User {
+        id: column(row, "id")?,
+        name: column(row, "name")?,
+        email: column(row, "email")?,
+        role: parse_role(&column::<String>(row, "role")?)?,
+        created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+        last_login: optional_timestamp(row, "last_login")?,
+        active: column(row, "active")?,
+        metadata: serde_json::from_str(&column::<String>(row, "metadata")?)?,
+    })
+}
+
+fn row_to_api_key(row: &SqliteRow) -> Result<ApiKey> {
+    let scopes = column::<Option<String>>(row, "scopes")?
+        .map(|scopes| {
+            serde_json::from_str::<Vec<String>>(&scopes)?
+                .iter()
+                .map(|name| Permission::from_name(name)
+                    .ok_or_else(|| BrainError::ParseError(format!("Unknown permission {}", name))))
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+
+    Ok(ApiKey {
+        id: column(row, "id")?,
+        key_hash: column(row, "key_hash")?,
+        key_prefix: column(row, "key_prefix")?,
+        user_id: column(row, "user_id")?,
+        role: parse_role(&column::<String>(row, "role")?)?,
+        description: column(row, "description")?,
+        scopes,
+        created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+        expires_at: optional_timestamp(row, "expires_at")?,
+        last_used: optional_timestamp(row, "last_used")?,
+        revoked_at: optional_timestamp(row, "revoked_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{ApiKeyRequest, AuthConfig, AuthManager};
+    use std::sync::Arc;
+
+    /// Issue a scoped key through the store, returning the key and its id
+    async fn persist(store: SqliteAuthStore) -> (String, String) {
+        let auth = AuthManager::with_store(AuthConfig::default(), Arc::new(store)).unwrap();
+        let user = User::new("ops".to_string(), "Ops".to_string(), "ops@example.com".to_string(), UserRole::Analyst);
+        auth.add_user(user).await.unwrap();
+        let request = ApiKeyRequest::new("ops", UserRole::Analyst, "deploy key")
+            .with_scopes(vec![Permission::QueryMemory, Permission::UseAPI]);
+        let issued = auth.issue_api_key(request).await.unwrap();
+        (issued.key, issued.record.id)
+    }
+
+    async fn verify(store: SqliteAuthStore, (key, key_id): (String, String)) {
+        let store = Arc::new(store);
+        let auth = AuthManager::with_store(AuthConfig::default(), store.clone()).unwrap();
+        let result = auth.validate_api_key(&key).await.unwrap();
+        assert_eq!(result.user_id, "ops");
+        assert_eq!(result.scopes, Some(vec![Permission::QueryMemory, Permission::UseAPI]));
+
+        let keys = store.list_api_keys(Some("ops")).await.unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!(keys[0].last_used.is_some());
+        assert_ne!(keys[0].key_hash, key);
+        assert!(store.get_user("ops").await.unwrap().unwrap().last_login.is_some());
+
+        assert!(auth.revoke_api_key(&key_id, "compromised").await.unwrap());
+        assert!(!auth.revoke_api_key("missing", "typo").await.unwrap());
+        assert!(auth.validate_api_key(&key).await.is_err());
+        assert_eq!(store.list_revocations().await.unwrap().len(), 1);
+    }
+
+    brain_infra::backend_conformance_tests! {
+        reopen: SqliteAuthStore::open => persist, verify;
+    }
+}
@@ -0,0 +1,390 @@
+//! Chat Streaming Module
+//!
+//! Runs RAG conversations for the web layer and streams their output as
+//! `RagStreamEvent`s, which the web server forwards as server-sent events and the
+//! WebSocket manager forwards as chat frames.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use brain_cognitive::conversation::{
+    provider_from_env, ConversationRepository, LanguageModelProvider, RagOrchestrator, RagRequest,
+    RagStreamEvent,
+};
+use brain_core::memory::{MemoryService, MemoryStats, WorkingMemoryItem, WorkingMemoryQuery};
+use brain_core::concepts::{
+    ConceptGraphService, ConceptNode, ConceptQuery, ConceptRelationship, ConceptRepository,
+    RelationshipQuery, RelationshipRepository,
+};
+use brain_core::WorkingMemoryRepository as WorkingMemoryRepositoryTrait;
+use brain_infra::{ConceptGraphManager, WorkingMemoryRepository};
+use brain_types::Result;
+use tokio::sync::{mpsc, Mutex};
+use uuid::Uuid;
+
+/// Working memory shared with the rest of the web server, so content taught
+/// through `/learn` is available to chat retrieval
+pub struct SharedWorkingMemory(pub Arc<Mutex<WorkingMemoryRepository>>);
+
+#[async_trait]
+impl WorkingMemoryRepositoryTrait for SharedWorkingMemory {
+    async fn store_item(&mut self, item: WorkingMemoryItem) -> Result<Uuid> {
+        self.0.lock().await.store_item(item).await
+    }
+
+    async fn get_item(&self, id: Uuid) -> Result<Option<WorkingMemoryItem>> {
+        self.0.lock().await.get_item(id).await
+    }
+
+    async fn update_item(&mut self, item: &WorkingMemoryItem) -> Result<()> {
+        self.0.lock().await.update_item(item).await
+    }
+
+    async fn remove_item(&mut self, id: Uuid) -> Result<()> {
+        self.0.lock().await.remove_item(id).await
+    }
+
+    async fn query_items(&self, query: &WorkingMemoryQuery) -> Result<Vec<WorkingMemoryItem>> {
+        self.0.lock().await.query_items(query).await
+    }
+
+    async fn get_consolidation_candidates(&self, age_threshold_hours: i64) -> Result<Vec<WorkingMemoryItem>> {
+        // The inherent method of the same name is synchronous and returns borrowed items
+        WorkingMemoryRepositoryTrait::get_consolidation_candidates(&*self.0.lock().await, age_threshold_hours).await
+    }
+
+    async fn prune_low_importance(&mut self, threshold: f64) -> Result<Vec<Uuid>> {
+        self.0.lock().await.prune_low_importance(threshold).await
+    }
+
+    async fn stats(&self) -> Result<MemoryStats> {
+        self.0.lock().await.stats().await
+    }
+}
+
+/// Concept graph shared with the rest of the web server, so chat retrieval
+/// sees the concepts served by the graph endpoints
+#[derive(Clone)]
+pub struct SharedConceptGraph(pub Arc<Mutex<ConceptGraphManager>>);
+
+#[async_trait]
+impl ConceptRepository for SharedConceptGraph {
+    async fn create_concept(&mut self, concept: ConceptNode) -> Result<Uuid> {
+        self.0.lock().await.create_concept(concept).await
+    }
+
+    async fn get_concept(&self, id: Uuid) -> Result<Option<ConceptNode>> {
+        self.0.lock().await.get_concept(id).await
+    }
+
+    async fn update_concept(&mut self, concept: &ConceptNode) -> Result<()> {
+        self.0.lock().await.update_concept(concept).await
+    }
+
+    async fn delete_concept(&mut self, id: Uuid) -> Result<bool> {
+        self.0.lock().await.delete_concept(id).await
+    }
+
+    async fn query_concepts(&self, query: &ConceptQuery) -> Result<Vec<ConceptNode>> {
+        self.0.lock().await.query_concepts(query).await
+    }
+
+    async fn mark_concept_accessed(&mut self, id: Uuid) -> Result<bool> {
+        self.0.lock().await.mark_concept_accessed(id).await
+    }
+
+    async fn get_concept_count(&self) -> Result<usize> {
+        ConceptRepository::get_concept_count(&*self.0.lock().await).await
+    }
+}
+
+#[async_trait]
+impl RelationshipRepository for SharedConceptGraph {
+    async fn create_relationship(&mut self, relationship: ConceptRelationship) -> Result<Uuid> {
+        self.0.lock().await.create_relationship(relationship).await
+    }
+
+    async fn get_relationship(&self, id: Uuid) -> Result<Option<ConceptRelationship>> {
+        self.0.lock().await.get_relationship(id).await
+    }
+
+    async fn update_relationship(&mut self, relationship: &ConceptRelationship) -> Result<()> {
+        self.0.lock().await.update_relationship(relationship).await
+    }
+
+    async fn delete_relationship(&mut self, id: Uuid) -> Result<bool> {
+        self.0.lock().await.delete_relationship(id).await
+    }
+
+    async fn query_relationships(&self, query: &RelationshipQuery) -> Result<Vec<ConceptRelationship>> {
+        self.0.lock().await.query_relationships(query).await
+    }
+
+    async fn get_concept_relationships(&self, concept_id: Uuid) -> Result<Vec<ConceptRelationship>> {
+        self.0.lock().await.get_concept_relationships(concept_id).await
+    }
+
+    async fn activate_relationship(&mut self, id: Uuid) -> Result<bool> {
+        self.0.lock().await.activate_relationship(id).await
+    }
+
+    async fn apply_decay_to_all(&mut self, time_delta_hours: f64) -> Result<usize> {
+        self.0.lock().await.apply_decay_to_all(time_delta_hours).await
+    }
+
+    async fn prune_weak_relationships(&mut self) -> Result<usize> {
+        self.0.lock().await.prune_weak_relationships().await
+    }
+
+    async fn get_relationship_count(&self) -> Result<usize> {
+        RelationshipRepository::get_relationship_count(&*self.0.lock().await).await
+    }
+}
+
+struct ChatPipeline {
+    orchestrator: RagOrchestrator,
+    concepts: ConceptGraphService,
+}
+
+/// Streaming chat service backed by a `RagOrchestrator`
+///
+/// The orchestrator keeps per-conversation state behind `&mut self`, so it is only
+/// locked to prepare and record a turn, not while the response streams. Turns of
+/// the same conversation still run one at a time so neither overwrites the other.
+pub struct ChatStreamService {
+    pipeline: Arc<Mutex<ChatPipeline>>,
+    memory: Arc<Mutex<MemoryService>>,
+    turns: Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>,
+}
+
+impl ChatStreamService {
+    /// Create a service generating with `provider`, retrieving from the server's
+    /// `memory` and `concept_graph` and persisting conversations to `conversations`
+    pub fn new(
+        provider: Arc<dyn LanguageModelProvider>,
+        memory: Arc<Mutex<MemoryService>>,
+        concept_graph: Arc<Mutex<ConceptGraphManager>>,
+        conversations: Arc<dyn ConversationRepository>,
+    ) -> Self {
+        let graph = SharedConceptGraph(concept_graph);
+        let concepts = ConceptGraphService::new(Box::new(graph.clone()), Box::new(graph));
+
+        Self {
+            pipeline: Arc::new(Mutex::new(ChatPipeline {
+                orchestrator: RagOrchestrator::with_provider(provider).with_conversation_store(conversations),
+                concepts,
+            })),
+            memory,
+            turns: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Create a service with the provider configured through the environment
+    pub fn from_env(
+        memory: Arc<Mutex<MemoryService>>,
+        concept_graph: Arc<Mutex<ConceptGraphManager>>,
+        conversations: Arc<dyn ConversationRepository>,
+    ) -> Result<Self> {
+        let provider: Arc<dyn LanguageModelProvider> = Arc::from(provider_from_env()?);
+        Ok(Self::new(provider, memory, concept_graph, conversations))
+    }
+
+    /// Start a conversation turn, returning its events
+    ///
+    /// The receiver yields `Started`, the response deltas and the trailing
+    /// `Knowledge` and `Completed` frames, or a `Failed` frame, then closes.
+    pub fn stream(&self, mut request: RagRequest) -> mpsc::UnboundedReceiver<RagStreamEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let pipeline = self.pipeline.clone();
+        let memory = self.memory.clone();
+        let turns = self.turns.clone();
+        let conversation_id = request.conversation_id
+            .get_or_insert_with(|| Uuid::new_v4().to_string())
+            .clone();
+
+        tokio::spawn(async move {
+            let turn_lock = conversation_turn_lock(&turns, &conversation_id).await;
+            let guard = turn_lock.lock().await;
+
+            let result = async {
+                let turn = {
+                    let mut pipeline = pipeline.lock().await;
+                    let ChatPipeline { orchestrator, concepts } = &mut *pipeline;
+                    orchestrator.begin_stream_turn(request, &mut *memory.lock().await, concepts, tx).await?
+                };
+
+                let llm_response = turn.generate().await?;
+
+                let mut pipeline = pipeline.lock().await;
+                pipeline.orchestrator.finish_stream_turn(turn, llm_response, &mut *memory.lock().await).await
+            }
+            .await;
+            if let Err(e) = result {
+                tracing::warn!("Streaming chat turn failed: {}", e);
+            }
+
+            drop(guard);
+            release_turn_lock(&turns, &conversation_id, turn_lock).await;
+        });
+
+        rx
+    }
+
+    /// Delete a conversation from the store and from the orchestrator's in-memory copy
+    ///
+    /// Waits for a turn in progress to finish so it cannot write the conversation back.
+    pub async fn delete_conversation(&self, conversation_id: &str) -> Result<bool> {
+        let turn_lock = conversation_turn_lock(&self.turns, conversation_id).await;
+        let deleted = {
+            let _guard = turn_lock.lock().await;
+            self.pipeline.lock().await.orchestrator.delete_conversation(conversation_id).await
+        };
+        release_turn_lock(&self.turns, conversation_id, turn_lock).await;
+        deleted
+    }
+}
+
+/// Lock serializing the turns of one conversation
+async fn conversation_turn_lock(turns: &Mutex<HashMap<String, Arc<Mutex<()>>>>, conversation_id: &str) -> Arc<Mutex<()>> {
+    turns.lock().await.entry(conversation_id.to_string()).or_default().clone()
+}
+
+/// Drop a conversation's turn lock once nobody else holds or waits on it
+async fn release_turn_lock(turns: &Mutex<HashMap<String, Arc<Mutex<()>>>>, conversation_id: &str, turn_lock: Arc<Mutex<()>>) {
+    let mut turns = turns.lock().await;
+    // One reference is in the map, the other is ours
+    if Arc::strong_count(&turn_lock) == 2 {
+        turns.remove(conversation_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brain_cognitive::conversation::{
+        CompletionRequest, CompletionResponse, CompletionStream, InMemoryConversationRepository,
+        ScriptedProvider, TokenUsage,
+    };
+    use brain_infra::{ConceptGraphConfig, EpisodicMemoryRepository, SemanticMemoryRepository};
+    use brain_types::BrainError;
+    use futures_util::{stream, StreamExt};
+    use tokio::sync::Notify;
+
+    /// Provider holding streamed responses to `gated` messages until `release` is notified
+    struct GatedProvider {
+        inner: ScriptedProvider,
+        gated: String,
+        release: Arc<Notify>,
+    }
+
+    #[async_trait]
+    impl LanguageModelProvider for GatedProvider {
+        fn name(&self) -> &str {
+            "gated"
+        }
+
+        fn model(&self) -> &str {
+            self.inner.model()
+        }
+
+        async fn complete(&self, request: CompletionRequest) -> std::result::Result<CompletionResponse, BrainError> {
+            self.inner.complete(request).await
+        }
+
+        async fn complete_stream(&self, request: CompletionRequest) -> std::result::Result<CompletionStream, BrainError> {
+            let gated = request.messages.iter().any(|message| message.content == self.gated);
+            let deltas = self.inner.complete_stream(request).await?;
+            if !gated {
+                return Ok(deltas);
+            }
+            let release = self.release.clone();
+            let gate = stream::once(async move {
+                release.notified().await;
+                Ok(String::new())
+            });
+            Ok(Box::pin(gate.chain(deltas)))
+        }
+
+        fn token_usage(&self) -> TokenUsage {
+            self.inner.token_usage()
+        }
+    }
+
+    async fn service(provider: Arc<dyn LanguageModelProvider>, working_memory: Arc<Mutex<WorkingMemoryRepository>>, conversations: Arc<dyn ConversationRepository>) -> ChatStreamService {
+        let memory = MemoryService::new(
+            Box::new(SharedWorkingMemory(working_memory)),
+            Box::new(EpisodicMemoryRepository::new(":memory:").await.unwrap()),
+            Box::new(SemanticMemoryRepository::new()),
+        );
+        let concept_graph = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        ChatStreamService::new(provider, Arc::new(Mutex::new(memory)), Arc::new(Mutex::new(concept_graph)), conversations)
+    }
+
+    fn request(message: &str) -> RagRequest {
+        RagRequest {
+            message: message.to_string(),
+            conversation_id: None,
+            context_limit: Some(5),
+            retrieval_threshold: Some(0.1),
+            user_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_deltas_then_trailing_frames() {
+        let provider = Arc::new(ScriptedProvider::repeating("Warp streams chat responses."));
+        let working_memory = Arc::new(Mutex::new(WorkingMemoryRepository::new(100)));
+        let conversations = Arc::new(InMemoryConversationRepository::new());
+        let service = service(provider, working_memory.clone(), conversations.clone()).await;
+
+        let mut events = Vec::new();
+        let mut conversation_id = String::new();
+        let mut rx = service.stream(request("How does streaming work?"));
+        while let Some(event) = rx.recv().await {
+            conversation_id = event.conversation_id().to_string();
+            events.push(event.event_name());
+        }
+
+        assert_eq!(events.first(), Some(&"started"));
+        assert!(events.iter().filter(|name| **name == "delta").count() >= 1);
+        assert_eq!(&events[events.len() - 2..], &["knowledge", "completed"]);
+
+        // The interaction is written back to the shared working memory
+        let stats = working_memory.lock().await.stats().await.unwrap();
+        assert_eq!(stats.total_items, 1);
+        assert_eq!(conversations.load_conversation(&conversation_id).await.unwrap().unwrap().messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_turns_of_other_conversations_run_while_one_streams() {
+        let release = Arc::new(Notify::new());
+        let provider = Arc::new(GatedProvider {
+            inner: ScriptedProvider::repeating("Each conversation streams on its own."),
+            gated: "Who goes first?".to_string(),
+            release: release.clone(),
+        });
+        let working_memory = Arc::new(Mutex::new(WorkingMemoryRepository::new(100)));
+        let conversations = Arc::new(InMemoryConversationRepository::new());
+        let service = service(provider, working_memory, conversations).await;
+
+        let mut held = service.stream(request("Who goes first?"));
+        assert_eq!(held.recv().await.unwrap().event_name(), "started");
+
+        let mut other = service.stream(request("Can I go meanwhile?"));
+        let finished = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            let mut last = None;
+            while let Some(event) = other.recv().await {
+                last = Some(event.event_name());
+            }
+            last
+        }).await;
+        assert_eq!(finished.unwrap(), Some("completed"));
+
+        release.notify_one();
+        let mut last = None;
+        while let Some(event) = held.recv().await {
+            last = Some(event.event_name());
+        }
+        assert_eq!(last, Some("completed"));
+    }
+}
@@ -9,16 +9,24 @@
 pub mod visualization;
 pub mod web_server;
 pub mod auth;
+pub mod auth_store;
 pub mod rate_limit;
 pub mod logging;
+pub mod middleware;
 pub mod agents;
 pub mod websocket;
+pub mod chat_stream;
 
 pub use visualization::*;
 pub use web_server::*;
-pub use auth::{AuthManager, AuthConfig, User, UserRole, Permission, AuthResult};
+pub use auth::{
+    AuthManager, AuthConfig, User, UserRole, Permission, AuthResult,
+    ApiKey, ApiKeyRequest, ApiKeyRevocation, IssuedApiKey,
+};
+pub use auth_store::{AuthStore, InMemoryAuthStore, SqliteAuthStore};
 pub use rate_limit::{RateLimitManager, RateLimitConfig, RequestContext, create_request_context};
 pub use logging::{LoggingManager, LoggingConfig, ErrorCategory, ErrorSeverity};
+pub use middleware::{ApiSecurity, RoutePermissions, RouteAccess, SecurityRejection};
 pub use visualization::{VisualizationManager, VisualizationConfig};
 
 // Re-export agent API types
@@ -31,5 +39,6 @@ pub use agents::{
 
 // Re-export WebSocket types
 pub use websocket::{
-    WebSocketManager, WebSocketMessage, WebSocketClient, SubscriptionRequest,
+    WebSocketManager, WebSocketMessage, WebSocketClientMessage, WebSocketClient, SubscriptionRequest,
 };
+pub use chat_stream::{ChatStreamService, SharedConceptGraph, SharedWorkingMemory};
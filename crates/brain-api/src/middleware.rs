@@ -0,0 +1,720 @@
+//! Request Security Middleware
+//!
+//! This module puts the `AuthManager`, `RateLimitManager` and `LoggingManager` in front
+//! of the warp routes served by the web server. Each request is authenticated from a
+//! bearer JWT or API key, checked against the permission required by its route,
+//! rate limited, and recorded in the request and audit logs.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Instant;
+use brain_types::{BrainError, Result};
+use serde_json::json;
+use tracing::warn;
+use warp::http::header::{HeaderMap, HeaderValue, AUTHORIZATION, RETRY_AFTER, WWW_AUTHENTICATE};
+use warp::http::{Method, StatusCode};
+use warp::path::FullPath;
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+use crate::auth::{AuthConfig, AuthManager, AuthResult, Permission, User, UserRole};
+use crate::auth_store::SqliteAuthStore;
+use crate::logging::{ErrorCategory, ErrorSeverity, LoggingConfig, LoggingManager};
+use crate::rate_limit::{create_request_context, RateLimitConfig, RateLimitManager, RateLimitResult};
+
+/// Header carrying an API key as an alternative to `Authorization: Bearer`
+pub const API_KEY_HEADER: &str = "x-api-key";
+/// Header echoing the id under which the request was logged
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Environment variable holding the JWT signing secret shared across restarts and processes
+pub const JWT_SECRET_ENV: &str = "BRAIN_JWT_SECRET";
+/// Environment variable naming the SQLite database holding users and API keys
+pub const AUTH_DATABASE_ENV: &str = "BRAIN_AUTH_DATABASE";
+
+/// Access required to call a route
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteAccess {
+    /// Anyone may call the route; credentials are still validated when supplied
+    Public,
+    /// The caller must be authenticated with a role holding this permission
+    Requires(Permission),
+}
+
+#[derive(Debug, Clone)]
+struct RouteRule {
+    method: Option<Method>,
+    segments: Vec<String>,
+    pattern: String,
+    access: RouteAccess,
+}
+
+impl RouteRule {
+    fn matches(&self, method: &Method, segments: &[&str]) -> bool {
+        if self.method.as_ref().is_some_and(|m| m != method) || self.segments.len() != segments.len() {
+            return false;
+        }
+        self.segments.iter().zip(segments).all(|(rule, segment)| rule == "*" || rule == segment)
+    }
+}
+
+/// Per-route access table
+///
+/// Patterns are matched segment by segment, with `*` standing for exactly one path
+/// segment. The first matching rule wins; unmatched requests fall back to the
+/// default access, which requires `Permission::UseAPI` unless changed.
+#[derive(Debug, Clone)]
+pub struct RoutePermissions {
+    rules: Vec<RouteRule>,
+    default_access: RouteAccess,
+}
+
+impl Default for RoutePermissions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoutePermissions {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            default_access: RouteAccess::Requires(Permission::UseAPI),
+        }
+    }
+
+    /// Add a rule for `pattern`, restricted to `method` when given
+    pub fn route(mut self, method: Option<Method>, pattern: &str, access: RouteAccess) -> Self {
+        self.rules.push(RouteRule {
+            method,
+            segments: split_path(pattern).into_iter().map(str::to_string).collect(),
+            pattern: pattern.to_string(),
+            access,
+        });
+        self
+    }
+
+    /// Allow anonymous calls to `pattern`
+    pub fn public(self, method: Method, pattern: &str) -> Self {
+        self.route(Some(method), pattern, RouteAccess::Public)
+    }
+
+    /// Require `permission` for `pattern`
+    pub fn require(self, method: Method, pattern: &str, permission: Permission) -> Self {
+        self.route(Some(method), pattern, RouteAccess::Requires(permission))
+    }
+
+    /// Access used for requests no rule matches
+    pub fn with_default_access(mut self, access: RouteAccess) -> Self {
+        self.default_access = access;
+        self
+    }
+
+    /// Resolve the access for a request and the pattern it matched
+    ///
+    /// Unmatched requests report their literal path as the pattern.
+    pub fn resolve(&self, method: &Method, path: &str) -> (RouteAccess, String) {
+        let segments = split_path(path);
+        match self.rules.iter().find(|rule| rule.matches(method, &segments)) {
+            Some(rule) => (rule.access.clone(), rule.pattern.clone()),
+            None => (self.default_access.clone(), path.to_string()),
+        }
+    }
+}
+
+fn split_path(path: &str) -> Vec<&str> {
+    path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Rejection raised when a request fails authentication, authorization or rate limiting
+#[derive(Debug)]
+pub struct SecurityRejection {
+    pub status: StatusCode,
+    pub message: String,
+    pub request_id: String,
+    pub rate_limit: Option<RateLimitResult>,
+}
+
+impl warp::reject::Reject for SecurityRejection {}
+
+/// State carried from admission to the response of an admitted request
+struct AdmittedRequest {
+    request_id: String,
+    method: Method,
+    path: String,
+    endpoint: String,
+    client_ip: IpAddr,
+    auth: Option<AuthResult>,
+    rate_limit: Option<RateLimitResult>,
+}
+
+/// Authentication, authorization, rate limiting and request logging for warp routes
+#[derive(Clone)]
+pub struct ApiSecurity {
+    auth_manager: Arc<AuthManager>,
+    rate_limiter: Arc<RateLimitManager>,
+    logging: Option<Arc<LoggingManager>>,
+}
+
+impl ApiSecurity {
+    /// Create a security layer without request logging
+    pub fn new(auth_manager: AuthManager, rate_limiter: RateLimitManager) -> Self {
+        Self {
+            auth_manager: Arc::new(auth_manager),
+            rate_limiter: Arc::new(rate_limiter),
+            logging: None,
+        }
+    }
+
+    /// Create a security layer from default configuration
+    ///
+    /// The JWT secret is read from `BRAIN_JWT_SECRET`; when it is unset a random secret
+    /// is generated for this process and a warning is logged, since tokens then stop
+    /// verifying on restart. Users and API keys are kept in the SQLite database named by
+    /// `BRAIN_AUTH_DATABASE`, or in memory when it is unset. The logging manager is a
+    /// process-wide singleton, so request logging is only attached when this call is the
+    /// one that initializes it.
+    pub async fn with_defaults() -> Result<Self> {
+        let mut auth_config = AuthConfig::default();
+        match std::env::var(JWT_SECRET_ENV) {
+            Ok(secret) if secret.trim().is_empty() => {
+                return Err(BrainError::ConfigError(format!("{} is set but empty", JWT_SECRET_ENV)));
+            }
+            Ok(secret) => auth_config.jwt_secret = secret,
+            Err(_) => warn!(
+                "{} is not set; signing JWTs with a random per-process secret, so tokens will not survive a restart",
+                JWT_SECRET_ENV
+            ),
+        }
+
+        let auth_manager = match std::env::var(AUTH_DATABASE_ENV) {
+            Ok(path) => {
+                let store = SqliteAuthStore::open(&path).await?;
+                AuthManager::with_store(auth_config, Arc::new(store))
+            }
+            Err(_) => AuthManager::new(auth_config),
+        }
+        .map_err(|e| BrainError::ConfigError(format!("Failed to create auth manager: {}", e)))?;
+        let rate_limiter = RateLimitManager::new(RateLimitConfig::default())
+            .map_err(|e| BrainError::ConfigError(format!("Failed to create rate limiter: {}", e)))?;
+
+        let security = Self::new(auth_manager, rate_limiter);
+        match LoggingManager::new(LoggingConfig::default()) {
+            Ok(logging) => Ok(security.with_logging(Arc::new(logging))),
+            Err(e) => {
+                warn!("Request logging disabled: {}", e);
+                Ok(security)
+            }
+        }
+    }
+
+    /// Record requests and audit events through `logging`
+    pub fn with_logging(mut self, logging: Arc<LoggingManager>) -> Self {
+        self.logging = Some(logging);
+        self
+    }
+
+    /// Shared handle to the authentication manager, for registering users and keys
+    pub fn auth_manager(&self) -> Arc<AuthManager> {
+        self.auth_manager.clone()
+    }
+
+    /// Shared handle to the rate limiter
+    pub fn rate_limiter(&self) -> Arc<RateLimitManager> {
+        self.rate_limiter.clone()
+    }
+
+    /// Logging manager receiving request and audit logs, if attached
+    pub fn logging(&self) -> Option<Arc<LoggingManager>> {
+        self.logging.clone()
+    }
+
+    /// Register an admin user and issue an API key for it, if the store has no users yet
+    ///
+    /// Returns `None` when users already exist, so a durable store is never given an
+    /// extra admin key on restart.
+    pub async fn bootstrap_admin(&self, user_id: &str) -> Result<Option<String>> {
+        let auth = &self.auth_manager;
+        let users = auth.list_users().await
+            .map_err(|e| BrainError::ConfigError(format!("Failed to list users: {}", e)))?;
+        if !users.is_empty() {
+            return Ok(None);
+        }
+
+        let user = User::new(user_id.to_string(), user_id.to_string(), String::new(), UserRole::Admin);
+        auth.add_user(user).await
+            .map_err(|e| BrainError::ConfigError(format!("Failed to create admin user: {}", e)))?;
+        auth.generate_api_key(user_id, UserRole::Admin, "bootstrap admin key").await
+            .map(Some)
+            .map_err(|e| BrainError::ConfigError(format!("Failed to issue admin API key: {}", e)))
+    }
+
+    /// Wrap `routes` so every request passes through authentication, permission checks
+    /// for `permissions`, rate limiting and logging
+    ///
+    /// Rejections from `routes` become JSON error responses so the request log is always
+    /// completed. Security failures are answered with 401, 403 or 429.
+    pub fn protect<F, R>(
+        &self,
+        permissions: RoutePermissions,
+        routes: F,
+    ) -> impl Filter<Extract = (Response,), Error = Infallible> + Clone + Send + Sync + 'static
+    where
+        F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+        R: Reply + 'static,
+    {
+        let security = self.clone();
+        let permissions = Arc::new(permissions);
+        let finisher = self.clone();
+
+        warp::method()
+            .and(warp::path::full())
+            .and(warp::header::headers_cloned())
+            .and(warp::addr::remote())
+            .and_then(move |method: Method, path: FullPath, headers: HeaderMap, remote: Option<SocketAddr>| {
+                let security = security.clone();
+                let permissions = permissions.clone();
+                async move {
+                    security.admit(&permissions, method, path.as_str(), &headers, remote).await
+                        .map_err(warp::reject::custom)
+                }
+            })
+            .and(routes.map(|reply: R| reply.into_response()).recover(route_rejection_response).unify())
+            .map(move |request: AdmittedRequest, response: Response| finisher.finish(request, response))
+            .recover(security_rejection_response)
+            .unify()
+    }
+
+    /// Authenticate, authorize and rate limit one request
+    async fn admit(
+        &self,
+        permissions: &RoutePermissions,
+        method: Method,
+        path: &str,
+        headers: &HeaderMap,
+        remote: Option<SocketAddr>,
+    ) -> std::result::Result<AdmittedRequest, SecurityRejection> {
+        let (access, endpoint) = permissions.resolve(&method, path);
+        let client_ip = remote.map(|addr| addr.ip()).unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        let request_id = uuid::Uuid::new_v4().to_string();
+
+        if let Some(logging) = &self.logging {
+            logging.start_request(request_id.clone(), endpoint.clone(), method.to_string(), client_ip);
+        }
+
+        let mut request = AdmittedRequest {
+            request_id,
+            method,
+            path: path.to_string(),
+            endpoint,
+            client_ip,
+            auth: None,
+            rate_limit: None,
+        };
+
+        request.auth = match self.authenticate(headers, true).await {
+            Ok(auth) => auth,
+            Err(message) => {
+                if let Some(logging) = &self.logging {
+                    logging.log_error(
+                        ErrorCategory::Authentication,
+                        ErrorSeverity::Medium,
+                        message.clone(),
+                        None,
+                        request.log_context(),
+                        Some(request.request_id.clone()),
+                        None,
+                    );
+                }
+                return Err(self.reject(&request, StatusCode::UNAUTHORIZED, message));
+            }
+        };
+
+        if let RouteAccess::Requires(permission) = &access {
+            let Some(auth) = request.auth.clone() else {
+                return Err(self.reject(&request, StatusCode::UNAUTHORIZED, "Authentication required".to_string()));
+            };
+            if !auth.has_permission(permission) {
+                let message = format!("Role {:?} lacks permission {:?}", auth.role, permission);
+                if let Some(logging) = &self.logging {
+                    let mut details = request.log_context();
+                    details.insert("required_permission".to_string(), format!("{:?}", permission));
+                    logging.log_audit(
+                        "authorization".to_string(),
+                        auth.user_id.clone(),
+                        auth.role.clone(),
+                        request.action(),
+                        Some(request.path.clone()),
+                        request.client_ip,
+                        false,
+                        details,
+                    );
+                }
+                return Err(self.reject(&request, StatusCode::FORBIDDEN, message));
+            }
+        }
+
+        let exempt = request.auth.as_ref()
+            .is_some_and(|auth| auth.has_permission(&Permission::RateLimitExempt));
+        if !exempt {
+            let context = create_request_context(
+                request.auth.as_ref().map(|auth| auth.user_id.clone()),
+                request.auth.as_ref().map(|auth| auth.role.clone()),
+                request.client_ip,
+                request.endpoint.clone(),
+            );
+            let result = self.rate_limiter.check_rate_limit(&context).map_err(|e| {
+                self.reject(&request, StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to check rate limit: {}", e))
+            })?;
+            let allowed = result.allowed;
+            let reason = result.reason.clone();
+            request.rate_limit = Some(result);
+
+            if !allowed {
+                let message = reason.unwrap_or_else(|| "Rate limit exceeded".to_string());
+                if let Some(logging) = &self.logging {
+                    logging.log_error(
+                        ErrorCategory::RateLimit,
+                        ErrorSeverity::Low,
+                        message.clone(),
+                        None,
+                        request.log_context(),
+                        Some(request.request_id.clone()),
+                        request.auth.as_ref().map(|auth| auth.user_id.clone()),
+                    );
+                }
+                return Err(self.reject(&request, StatusCode::TOO_MANY_REQUESTS, message));
+            }
+        }
+
+        Ok(request)
+    }
+
+    /// Extract the authenticated caller of a request inside `protect`ed routes
+    ///
+    /// Requests are admitted before their route runs, so this resolves the same
+    /// credentials again without recording another use of an API key. Yields `None`
+    /// for anonymous requests, and for credentials revoked since admission.
+    pub fn caller(&self) -> impl Filter<Extract = (Option<AuthResult>,), Error = Infallible> + Clone + Send + Sync + 'static {
+        let security = self.clone();
+        warp::header::headers_cloned().then(move |headers: HeaderMap| {
+            let security = security.clone();
+            async move { security.authenticate(&headers, false).await.unwrap_or(None) }
+        })
+    }
+
+    /// Resolve the caller from `Authorization: Bearer` or `X-API-Key`
+    ///
+    /// Bearer values carrying the configured API key prefix are treated as API keys,
+    /// anything else as a JWT. No credentials at all is not an error. API key uses are
+    /// only recorded when `record_use` is set.
+    async fn authenticate(&self, headers: &HeaderMap, record_use: bool) -> std::result::Result<Option<AuthResult>, String> {
+        let bearer = match headers.get(AUTHORIZATION) {
+            Some(value) => {
+                let value = value.to_str().map_err(|_| "Malformed Authorization header".to_string())?;
+                match value.split_once(' ') {
+                    Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => Some(token.trim().to_string()),
+                    _ => return Err("Authorization header must use the Bearer scheme".to_string()),
+                }
+            }
+            None => None,
+        };
+        let api_key = match headers.get(API_KEY_HEADER) {
+            Some(value) => Some(value.to_str().map_err(|_| "Malformed X-API-Key header".to_string())?.trim().to_string()),
+            None => None,
+        };
+
+        let auth = &self.auth_manager;
+        let prefix = auth.config().api_key_prefix.clone();
+
+        let (key, token) = match (bearer, api_key) {
+            (_, Some(key)) => (Some(key), None),
+            (Some(bearer), None) if bearer.starts_with(&prefix) => (Some(bearer), None),
+            (Some(bearer), None) => (None, Some(bearer)),
+            (None, None) => return Ok(None),
+        };
+
+        if let Some(key) = key {
+            let result = if record_use {
+                auth.validate_api_key(&key).await
+            } else {
+                auth.identify_api_key(&key).await
+            };
+            let result = result.map_err(|e| e.to_string())?;
+            return Ok(Some(result));
+        }
+
+        let token = token.unwrap_or_default();
+        let claims = auth.validate_token(&token).map_err(|_| "Invalid or expired token".to_string())?;
+        Ok(Some(AuthResult::new(claims.sub, claims.role)))
+    }
+
+    /// Complete the request log for a refused request and build its rejection
+    fn reject(&self, request: &AdmittedRequest, status: StatusCode, message: String) -> SecurityRejection {
+        if let Some(logging) = &self.logging {
+            logging.complete_request(
+                request.request_id.clone(),
+                status.as_u16(),
+                request.auth.clone(),
+                request.log_context(),
+            );
+        }
+
+        SecurityRejection {
+            status,
+            message,
+            request_id: request.request_id.clone(),
+            rate_limit: request.rate_limit.clone(),
+        }
+    }
+
+    /// Decorate the response of an admitted request and complete its logs
+    fn finish(&self, request: AdmittedRequest, mut response: Response) -> Response {
+        let status = response.status();
+        let headers = response.headers_mut();
+        insert_request_id(headers, &request.request_id);
+        if let Some(rate_limit) = &request.rate_limit {
+            insert_rate_limit_headers(headers, rate_limit);
+        }
+
+        if let Some(logging) = &self.logging {
+            logging.complete_request(
+                request.request_id.clone(),
+                status.as_u16(),
+                request.auth.clone(),
+                request.log_context(),
+            );
+
+            let mutating = !matches!(request.method, Method::GET | Method::HEAD | Method::OPTIONS);
+            if let (true, Some(auth)) = (mutating, &request.auth) {
+                let mut details = request.log_context();
+                details.insert("status".to_string(), status.as_u16().to_string());
+                logging.log_audit(
+                    "api_request".to_string(),
+                    auth.user_id.clone(),
+                    auth.role.clone(),
+                    request.action(),
+                    Some(request.path.clone()),
+                    request.client_ip,
+                    status.is_success(),
+                    details,
+                );
+            }
+        }
+
+        response
+    }
+}
+
+impl AdmittedRequest {
+    fn action(&self) -> String {
+        format!("{} {}", self.method, self.endpoint)
+    }
+
+    fn log_context(&self) -> HashMap<String, String> {
+        let mut context = HashMap::new();
+        context.insert("request_id".to_string(), self.request_id.clone());
+        context.insert("path".to_string(), self.path.clone());
+        context.insert("endpoint".to_string(), self.endpoint.clone());
+        context
+    }
+}
+
+fn insert_request_id(headers: &mut HeaderMap, request_id: &str) {
+    if let Ok(value) = HeaderValue::from_str(request_id) {
+        headers.insert(REQUEST_ID_HEADER, value);
+    }
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, rate_limit: &RateLimitResult) {
+    let reset = rate_limit.reset_time.saturating_duration_since(Instant::now()).as_secs();
+    headers.insert(RATE_LIMIT_LIMIT_HEADER, HeaderValue::from(rate_limit.limit));
+    headers.insert(RATE_LIMIT_REMAINING_HEADER, HeaderValue::from(rate_limit.remaining));
+    headers.insert(RATE_LIMIT_RESET_HEADER, HeaderValue::from(reset));
+    if !rate_limit.allowed {
+        headers.insert(RETRY_AFTER, HeaderValue::from(reset.max(1)));
+    }
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response {
+    warp::reply::with_status(
+        warp::reply::json(&json!({
+            "success": false,
+            "error": message,
+        })),
+        status,
+    ).into_response()
+}
+
+/// Turn security rejections into 401/403/429 responses, passing other rejections on
+async fn security_rejection_response(rejection: Rejection) -> std::result::Result<Response, Infallible> {
+    let Some(rejection) = rejection.find::<SecurityRejection>() else {
+        return Ok(route_rejection_response(rejection).await.unwrap_or_else(|e| match e {}));
+    };
+
+    let mut response = error_response(rejection.status, &rejection.message);
+    let headers = response.headers_mut();
+    insert_request_id(headers, &rejection.request_id);
+    if let Some(rate_limit) = &rejection.rate_limit {
+        insert_rate_limit_headers(headers, rate_limit);
+    }
+    if rejection.status == StatusCode::UNAUTHORIZED {
+        headers.insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    }
+    Ok(response)
+}
+
+/// Turn a route rejection into the matching JSON error response
+async fn route_rejection_response(rejection: Rejection) -> std::result::Result<Response, Infallible> {
+    let (status, message) = if rejection.is_not_found() {
+        (StatusCode::NOT_FOUND, "Not found".to_string())
+    } else if rejection.find::<warp::reject::MethodNotAllowed>().is_some() {
+        (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed".to_string())
+    } else if let Some(e) = rejection.find::<warp::filters::body::BodyDeserializeError>() {
+        (StatusCode::BAD_REQUEST, format!("Invalid request body: {}", e))
+    } else if rejection.find::<warp::reject::UnsupportedMediaType>().is_some() {
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Unsupported media type".to_string())
+    } else if rejection.find::<warp::reject::PayloadTooLarge>().is_some() {
+        (StatusCode::PAYLOAD_TOO_LARGE, "Payload too large".to_string())
+    } else {
+        warn!("Unhandled rejection: {:?}", rejection);
+        (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+    };
+
+    Ok(error_response(status, &message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn permissions() -> RoutePermissions {
+        RoutePermissions::new()
+            .public(Method::GET, "/health")
+            .require(Method::POST, "/memory", Permission::WriteMemory)
+            .require(Method::POST, "/api/agents/*/execute", Permission::UseAPI)
+    }
+
+    fn security(config: RateLimitConfig) -> ApiSecurity {
+        let auth = AuthManager::new(AuthConfig::default()).unwrap();
+        ApiSecurity::new(auth, RateLimitManager::new(config).unwrap())
+    }
+
+    fn routes() -> impl Filter<Extract = (&'static str,), Error = Rejection> + Clone + Send + Sync + 'static {
+        let health = warp::path("health").and(warp::get()).map(|| "ok");
+        let memory = warp::path("memory").and(warp::post()).map(|| "stored");
+        let execute = warp::path!("api" / "agents" / String / "execute")
+            .and(warp::post())
+            .map(|_agent: String| "executed");
+        health.or(memory).unify().or(execute).unify()
+    }
+
+    async fn issue_key(security: &ApiSecurity, user_id: &str, role: UserRole) -> String {
+        let auth = security.auth_manager();
+        auth.add_user(User::new(user_id.to_string(), user_id.to_string(), String::new(), role.clone())).await.unwrap();
+        auth.generate_api_key(user_id, role, "test").await.unwrap()
+    }
+
+    #[test]
+    fn test_route_permissions_resolve() {
+        let table = permissions();
+        assert_eq!(table.resolve(&Method::GET, "/health").0, RouteAccess::Public);
+
+        let (access, endpoint) = table.resolve(&Method::POST, "/api/agents/planner/execute");
+        assert_eq!(access, RouteAccess::Requires(Permission::UseAPI));
+        assert_eq!(endpoint, "/api/agents/*/execute");
+
+        let (access, endpoint) = table.resolve(&Method::DELETE, "/health");
+        assert_eq!(access, RouteAccess::Requires(Permission::UseAPI));
+        assert_eq!(endpoint, "/health");
+    }
+
+    #[tokio::test]
+    async fn test_authentication_and_permissions() {
+        let security = security(RateLimitConfig::default());
+        let viewer_key = issue_key(&security, "viewer", UserRole::Viewer).await;
+        let developer_token = {
+            issue_key(&security, "developer", UserRole::Developer).await;
+            security.auth_manager().generate_token("developer", UserRole::Developer).await.unwrap()
+        };
+        let filter = security.protect(permissions(), routes());
+
+        let response = warp::test::request().method("GET").path("/health").reply(&filter).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = warp::test::request().method("POST").path("/api/agents/planner/execute").reply(&filter).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()[WWW_AUTHENTICATE], "Bearer");
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/api/agents/planner/execute")
+            .header("authorization", "Bearer not-a-token")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/api/agents/planner/execute")
+            .header(API_KEY_HEADER, viewer_key.as_str())
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(RATE_LIMIT_REMAINING_HEADER));
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/memory")
+            .header("authorization", format!("Bearer {}", viewer_key))
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/memory")
+            .header("authorization", format!("Bearer {}", developer_token))
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body().as_ref(), b"stored");
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/missing")
+            .header(API_KEY_HEADER, viewer_key.as_str())
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers() {
+        let config = RateLimitConfig {
+            ip_limit: 1,
+            burst_allowance: 1,
+            ..RateLimitConfig::default()
+        };
+        let security = security(config);
+        let filter = security.protect(permissions(), routes());
+
+        for _ in 0..2 {
+            let response = warp::test::request().method("GET").path("/health").reply(&filter).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[RATE_LIMIT_LIMIT_HEADER], "100");
+        }
+
+        let response = warp::test::request().method("GET").path("/health").reply(&filter).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RATE_LIMIT_LIMIT_HEADER], "1");
+        assert_eq!(response.headers()[RATE_LIMIT_REMAINING_HEADER], "0");
+        assert!(response.headers().contains_key(RETRY_AFTER));
+    }
+}
@@ -11,7 +11,7 @@ use axum::{
     Router,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use tracing::{debug, info};
 use brain_types::Result;
 
@@ -112,6 +112,92 @@ pub struct GraphMetadata {
     pub layout_algorithm: String,
     /// Filters applied
     pub filters: HashMap<String, serde_json::Value>,
+    /// Structural metrics of the graph, when generated from a live concept graph
+    pub network_metrics: Option<NetworkMetricsData>,
+}
+
+/// A concept and its score in a centrality ranking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CentralityEntry {
+    /// Concept identifier
+    pub concept_id: String,
+    /// Centrality score (degree count, betweenness or PageRank)
+    pub score: f64,
+}
+
+/// Concept graph structure metrics for dashboards and trend tracking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkMetricsData {
+    /// Total number of concepts
+    pub total_concepts: usize,
+    /// Total number of relationships
+    pub total_relationships: usize,
+    /// Relationship counts keyed by relationship type
+    pub relationships_by_type: HashMap<String, usize>,
+    /// Mean relationship weight
+    pub average_weight: f64,
+    /// Mean number of relationships per concept
+    pub average_degree: f64,
+    /// Number of concepts having each degree
+    pub degree_distribution: BTreeMap<usize, usize>,
+    /// Concepts without any relationship
+    pub isolated_concepts: usize,
+    /// Fraction of possible links that exist
+    pub density: f64,
+    /// Mean local clustering coefficient
+    pub clustering_coefficient: f64,
+    /// Number of connected components
+    pub connected_components: usize,
+    /// Size of the largest connected component
+    pub largest_component_size: usize,
+    /// Mean hop count between connected concepts
+    pub average_path_length: f64,
+    /// Most central concepts by degree
+    pub top_degree: Vec<CentralityEntry>,
+    /// Most central concepts by betweenness
+    pub top_betweenness: Vec<CentralityEntry>,
+    /// Most central concepts by PageRank
+    pub top_pagerank: Vec<CentralityEntry>,
+    /// When the metrics were computed
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&brain_core::NetworkMetrics> for NetworkMetricsData {
+    fn from(metrics: &brain_core::NetworkMetrics) -> Self {
+        let ranking = |entries: &[(uuid::Uuid, f64)]| {
+            entries
+                .iter()
+                .map(|(id, score)| CentralityEntry { concept_id: id.to_string(), score: *score })
+                .collect()
+        };
+
+        Self {
+            total_concepts: metrics.total_concepts,
+            total_relationships: metrics.total_relationships,
+            relationships_by_type: metrics
+                .relationships_by_type
+                .iter()
+                .map(|(rel_type, count)| (rel_type.to_string(), *count))
+                .collect(),
+            average_weight: metrics.average_weight,
+            average_degree: metrics.average_degree,
+            degree_distribution: metrics.degree_distribution.clone(),
+            isolated_concepts: metrics.isolated_concepts,
+            density: metrics.density,
+            clustering_coefficient: metrics.clustering_coefficient,
+            connected_components: metrics.connected_components,
+            largest_component_size: metrics.largest_component_size,
+            average_path_length: metrics.average_path_length,
+            top_degree: metrics
+                .most_connected_concepts
+                .iter()
+                .map(|(id, degree)| CentralityEntry { concept_id: id.to_string(), score: *degree as f64 })
+                .collect(),
+            top_betweenness: ranking(&metrics.most_between_concepts),
+            top_pagerank: ranking(&metrics.highest_pagerank_concepts),
+            timestamp: chrono::Utc::now(),
+        }
+    }
 }
 
 /// Visualization manager for coordinating visualization services
@@ -126,6 +212,12 @@ impl VisualizationManager {
         Self { config }
     }
 
+    /// Compute structural metrics for a concept graph manager
+    pub async fn generate_network_metrics(&self, manager: &brain_infra::ConceptGraphManager) -> Result<NetworkMetricsData> {
+        let metrics = manager.get_network_metrics().await?;
+        Ok(NetworkMetricsData::from(&metrics))
+    }
+
     /// Generate graph data from a concept graph manager
     pub async fn generate_concept_graph_data(&self, manager: &brain_infra::ConceptGraphManager) -> Result<GraphData> {
         use brain_core::{ConceptRepository, RelationshipRepository};
@@ -193,6 +285,7 @@ impl VisualizationManager {
                 graph_type: "concept_graph".to_string(),
                 layout_algorithm: self.config.default_layout.clone(),
                 filters: std::collections::HashMap::new(),
+                network_metrics: Some(self.generate_network_metrics(manager).await?),
             },
         })
     }
@@ -316,6 +409,7 @@ fn create_sample_graph_data() -> GraphData {
             graph_type: "concept_graph".to_string(),
             layout_algorithm: "force".to_string(),
             filters: HashMap::new(),
+            network_metrics: None,
         },
     }
 }
@@ -428,6 +522,29 @@ mod tests {
         assert_eq!(graph_data.metadata.edge_count, 1);
     }
 
+    #[tokio::test]
+    async fn test_network_metrics_from_concept_graph() {
+        use brain_core::{ConceptNode, ConceptRelationship, ConceptRepository, ConceptType, RelationshipRepository, RelationshipType};
+
+        let mut graph = brain_infra::ConceptGraphManager::new(brain_infra::ConceptGraphConfig::default()).await.unwrap();
+        let a = graph.create_concept(ConceptNode::new(ConceptType::Entity, "a".to_string(), 0.9, None)).await.unwrap();
+        let b = graph.create_concept(ConceptNode::new(ConceptType::Entity, "b".to_string(), 0.9, None)).await.unwrap();
+        graph.create_relationship(ConceptRelationship::new(a, b, RelationshipType::IsA, 0.8)).await.unwrap();
+
+        let manager = VisualizationManager::new(VisualizationConfig::default());
+        let metrics = manager.generate_network_metrics(&graph).await.unwrap();
+        assert_eq!(metrics.total_concepts, 2);
+        assert_eq!(metrics.connected_components, 1);
+        assert_eq!(metrics.relationships_by_type.get("IS_A"), Some(&1));
+        assert_eq!(metrics.top_pagerank.len(), 2);
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["degree_distribution"]["1"], 2);
+
+        let graph_data = manager.generate_concept_graph_data(&graph).await.unwrap();
+        assert!(graph_data.metadata.network_metrics.is_some());
+    }
+
     #[test]
     fn test_visualization_manager_creation() {
         let config = VisualizationConfig::default();
@@ -5,18 +5,36 @@
 //! pattern detection, RAG conversations, and development context tracking.
 
 use brain_types::*;
-use brain_core::{WorkingMemoryItem, WorkingMemoryQuery, Priority, WorkingMemoryRepository as WorkingMemoryRepositoryTrait};
-use brain_infra::{WorkingMemoryRepository, ConceptGraphManager, InMemoryInsightRepository, ConceptGraphConfig};
+use brain_core::{MemoryService, WorkingMemoryItem, WorkingMemoryQuery, Priority, WorkingMemoryRepository as WorkingMemoryRepositoryTrait};
+use brain_infra::{
+    WorkingMemoryRepository, ConceptGraphManager, InMemoryInsightRepository, ConceptGraphConfig, ConceptGraphBackend,
+    EpisodicMemoryRepository, SemanticMemoryRepository,
+};
 // Removed unused brain_cognitive import
 use crate::agents::{
     AgentApiManager, AgentExecutionRequest, WorkflowExecutionRequest,
 };
+use crate::visualization::NetworkMetricsData;
 use crate::websocket::WebSocketManager;
+use crate::chat_stream::{ChatStreamService, SharedWorkingMemory};
+use crate::auth::{AuthResult, Permission};
+use crate::middleware::{
+    ApiSecurity, RoutePermissions, API_KEY_HEADER, REQUEST_ID_HEADER,
+    RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER, RATE_LIMIT_RESET_HEADER,
+};
+use brain_cognitive::conversation::{
+    ConversationContext, ConversationListQuery, ConversationRepository, RagRequest, SqliteConversationRepository,
+    store::DEFAULT_PAGE_SIZE,
+};
+use futures_util::stream;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::error;
 use warp::{Filter, Reply};
+use warp::http::Method;
 use std::path::{Path, PathBuf};
 use std::fs;
 
@@ -83,67 +101,9 @@ pub struct SimpleChatResponse {
 }
 
 // Code Pattern Recognition API structures
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CodePatternAnalysisRequest {
-    pub code_content: String,
-    pub file_path: Option<String>,
-    pub language: Option<String>,
-    #[serde(default = "default_true")]
-    pub store_patterns: bool,
-    #[serde(default)]
-    pub analysis_depth: PatternAnalysisDepth,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub enum PatternAnalysisDepth {
-    Basic,      // Function signatures, class names
-    Detailed,   // Include method bodies, relationships
-    Deep,       // Full analysis with architectural patterns
-}
-
-impl Default for PatternAnalysisDepth {
-    fn default() -> Self {
-        PatternAnalysisDepth::Detailed
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CodePatternAnalysisResponse {
-    pub success: bool,
-    pub patterns_found: Vec<CodePattern>,
-    pub concepts_created: usize,
-    pub relationships_formed: usize,
-    pub analysis_time_ms: u64,
-    pub confidence_score: f64,
-    pub language_detected: Option<String>,
-    pub architectural_insights: Vec<String>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CodePattern {
-    pub pattern_type: CodePatternType,
-    pub name: String,
-    pub description: String,
-    pub code_snippet: Option<String>,
-    pub file_location: Option<String>,
-    pub confidence: f64,
-    pub related_patterns: Vec<String>,
-    pub concept_id: Option<String>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub enum CodePatternType {
-    DataStructure,      // Classes, structs, interfaces
-    Function,           // Functions, methods
-    APIEndpoint,        // REST endpoints, routes
-    DesignPattern,      // Singleton, Factory, etc.
-    ArchitecturalPattern, // MVC, microservices, etc.
-    ImportPattern,      // Dependencies, modules
-    NamingConvention,   // Camel case, snake case, etc.
-    ErrorHandling,      // Try-catch, Result types
-    ConfigurationPattern, // Environment variables, config files
-    TestPattern,        // Unit tests, integration tests
-}
+pub use brain_types::{
+    CodePatternAnalysisRequest, PatternAnalysisDepth, CodePatternAnalysisResponse, CodePattern, CodePatternType,
+};
 
 fn default_true() -> bool {
     true
@@ -419,15 +379,45 @@ pub struct SessionUpdateRequest {
     pub project_context: Option<ProjectContext>,
 }
 
+/// Environment variable naming the SQLite database conversations are stored in
+pub const CONVERSATION_DATABASE_ENV: &str = "BRAIN_CONVERSATION_DATABASE";
+
+/// Conversation database used when `BRAIN_CONVERSATION_DATABASE` is unset
+pub const DEFAULT_CONVERSATION_DATABASE: &str = "data/conversations.db";
+
+/// Environment variable naming the file the bootstrap admin API key is written to
+pub const ADMIN_KEY_FILE_ENV: &str = "BRAIN_ADMIN_KEY_FILE";
+
+/// Admin API key file used when `BRAIN_ADMIN_KEY_FILE` is unset
+pub const DEFAULT_ADMIN_KEY_FILE: &str = "data/admin_api_key";
+
+/// Paging parameters for conversation message listings
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessagePageQuery {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_page_size")]
+    pub limit: usize,
+}
+
+fn default_page_size() -> usize {
+    DEFAULT_PAGE_SIZE
+}
+
 pub struct WebServer {
     port: u16,
     memory_repository: Arc<Mutex<WorkingMemoryRepository>>,
+    memory_service: Arc<Mutex<MemoryService>>,
     concept_manager: Arc<Mutex<ConceptGraphManager>>,
     insight_repository: Arc<Mutex<InMemoryInsightRepository>>,
     development_sessions: Arc<Mutex<HashMap<String, DevelopmentSession>>>,
     sessions_file_path: PathBuf,
     agent_api_manager: Arc<AgentApiManager>,
     websocket_manager: Arc<WebSocketManager>,
+    chat_service: Option<Arc<ChatStreamService>>,
+    conversation_store: Arc<dyn ConversationRepository>,
+    security: ApiSecurity,
+    bootstrap_api_key: Option<String>,
 }
 
 impl WebServer {
@@ -441,17 +431,21 @@ impl WebServer {
             database: None,
             pool_size: 10,
             timeout_seconds: 30,
+            backend: ConceptGraphBackend::InMemory,
         };
         let concept_manager = Arc::new(Mutex::new(ConceptGraphManager::new(concept_config).await?));
+        // Memory service over the shared working memory, used by chat retrieval
+        let memory_service = Arc::new(Mutex::new(MemoryService::new(
+            Box::new(SharedWorkingMemory(memory_repository.clone())),
+            Box::new(EpisodicMemoryRepository::new(":memory:").await?),
+            Box::new(SemanticMemoryRepository::new()),
+        )));
         let insight_repository = Arc::new(Mutex::new(InMemoryInsightRepository::new()));
         let development_sessions = Arc::new(Mutex::new(HashMap::new()));
         
         // Initialize Agent API Manager
         let agent_api_manager = Arc::new(AgentApiManager::new().await?);
         
-        // Initialize WebSocket Manager
-        let websocket_manager = Arc::new(WebSocketManager::new());
-        
         // Create sessions directory if it doesn't exist
         let sessions_dir = Path::new("data/sessions");
         if !sessions_dir.exists() {
@@ -459,17 +453,48 @@ impl WebServer {
         }
         
         let sessions_file_path = sessions_dir.join("development_sessions.json");
+
+        // Conversations survive restarts so they can be listed and resumed
+        let conversation_database = std::env::var(CONVERSATION_DATABASE_ENV)
+            .unwrap_or_else(|_| DEFAULT_CONVERSATION_DATABASE.to_string());
+        let conversation_store: Arc<dyn ConversationRepository> =
+            Arc::new(SqliteConversationRepository::open(&conversation_database).await?);
+
+        // Initialize the streaming chat service if a language model provider is configured
+        let chat_service = match ChatStreamService::from_env(memory_service.clone(), concept_manager.clone(), conversation_store.clone()) {
+            Ok(service) => Some(Arc::new(service)),
+            Err(e) => {
+                println!("⚠️  Streaming chat disabled: {}", e);
+                None
+            }
+        };
+
+        // Initialize WebSocket Manager
+        let mut websocket_manager = WebSocketManager::new();
+        if let Some(chat_service) = &chat_service {
+            websocket_manager = websocket_manager.with_chat_service(chat_service.clone());
+        }
+        let websocket_manager = Arc::new(websocket_manager);
+
+        // Initialize the security layer with an admin key for this run
+        let security = ApiSecurity::with_defaults().await?;
+        let bootstrap_api_key = security.bootstrap_admin("admin").await?;
         
         // Load existing sessions if file exists
         let mut server = Self {
             port,
             memory_repository,
+            memory_service,
             concept_manager,
             insight_repository,
             development_sessions,
             sessions_file_path,
             agent_api_manager,
             websocket_manager,
+            chat_service,
+            conversation_store,
+            security,
+            bootstrap_api_key,
         };
         
         server.load_sessions().await?;
@@ -477,6 +502,50 @@ impl WebServer {
         Ok(server)
     }
 
+    /// Replace the security layer, e.g. to use preconfigured users and keys
+    pub fn with_security(mut self, security: ApiSecurity) -> Self {
+        self.security = security;
+        self.bootstrap_api_key = None;
+        self
+    }
+
+    /// Memory service shared by the server's handlers, for building chat services
+    pub fn memory_service(&self) -> Arc<Mutex<MemoryService>> {
+        self.memory_service.clone()
+    }
+
+    /// Concept graph shared by the server's handlers
+    pub fn concept_manager(&self) -> Arc<Mutex<ConceptGraphManager>> {
+        self.concept_manager.clone()
+    }
+
+    /// Store the web server keeps conversations in
+    pub fn conversation_store(&self) -> Arc<dyn ConversationRepository> {
+        self.conversation_store.clone()
+    }
+
+    /// Replace the streaming chat service, e.g. to use a specific language model provider
+    ///
+    /// Must be called before `routes()`; the WebSocket manager is rebuilt so that
+    /// WebSocket chat requests use the same service. The service should persist to
+    /// `conversation_store()` for its conversations to show up in the conversation routes.
+    pub fn with_chat_service(mut self, chat_service: ChatStreamService) -> Self {
+        let chat_service = Arc::new(chat_service);
+        self.websocket_manager = Arc::new(WebSocketManager::new().with_chat_service(chat_service.clone()));
+        self.chat_service = Some(chat_service);
+        self
+    }
+
+    /// Security layer guarding the routes
+    pub fn security(&self) -> &ApiSecurity {
+        &self.security
+    }
+
+    /// Admin API key issued at startup into an empty store, unless a custom security layer was supplied
+    pub fn bootstrap_api_key(&self) -> Option<&str> {
+        self.bootstrap_api_key.as_deref()
+    }
+
     /// Load sessions from persistent storage
     async fn load_sessions(&mut self) -> Result<()> {
         if self.sessions_file_path.exists() {
@@ -614,12 +683,60 @@ impl WebServer {
         recommendations
     }
 
-    /// Start the web server
-    pub async fn start(&self) -> Result<()> {
+    /// Per-route permissions enforced by the security layer
+    pub fn route_permissions() -> RoutePermissions {
+        RoutePermissions::new()
+            .public(Method::GET, "/")
+            .public(Method::GET, "/health")
+            .public(Method::GET, "/status")
+            .require(Method::GET, "/stats", Permission::SystemMetrics)
+            .require(Method::POST, "/learn", Permission::WriteMemory)
+            .require(Method::POST, "/memory/query", Permission::QueryMemory)
+            .require(Method::POST, "/chat", Permission::QueryMemory)
+            .require(Method::POST, "/simple/learn", Permission::WriteMemory)
+            .require(Method::POST, "/simple/converse", Permission::QueryMemory)
+            .require(Method::POST, "/api/chat/learn", Permission::WriteMemory)
+            .require(Method::POST, "/api/chat/converse", Permission::QueryMemory)
+            .require(Method::POST, "/api/chat/stream", Permission::QueryMemory)
+            .require(Method::GET, "/api/conversations", Permission::QueryMemory)
+            .require(Method::GET, "/api/conversations/*", Permission::QueryMemory)
+            .require(Method::GET, "/api/conversations/*/messages", Permission::QueryMemory)
+            .require(Method::DELETE, "/api/conversations/*", Permission::DeleteMemory)
+            .require(Method::POST, "/code/analyze", Permission::UseAPI)
+            .require(Method::POST, "/api/dev/context/analyze", Permission::QueryMemory)
+            .require(Method::POST, "/api/dev/context", Permission::WriteMemory)
+            .require(Method::GET, "/api/dev/context/*", Permission::QueryMemory)
+            .require(Method::PUT, "/api/dev/context/*", Permission::WriteMemory)
+            .require(Method::DELETE, "/api/dev/context/*", Permission::DeleteMemory)
+            .require(Method::GET, "/api/dev/sessions", Permission::QueryMemory)
+            .require(Method::POST, "/dev/context", Permission::WriteMemory)
+            .require(Method::GET, "/dev/context/*", Permission::QueryMemory)
+            .require(Method::GET, "/api/graph/metrics", Permission::QueryMemory)
+            .require(Method::GET, "/api/agents", Permission::UseAPI)
+            .require(Method::POST, "/api/agents/*/execute", Permission::UseAPI)
+            .require(Method::GET, "/api/agents/*/status", Permission::UseAPI)
+            .require(Method::POST, "/api/workflows/execute", Permission::UseAPI)
+            .require(Method::GET, "/api/profiles", Permission::UseAPI)
+            .require(Method::POST, "/api/profiles", Permission::UseAPI)
+            .require(Method::GET, "/api/profiles/presets", Permission::UseAPI)
+            .require(Method::GET, "/api/profiles/*", Permission::UseAPI)
+            .require(Method::PUT, "/api/profiles/*", Permission::UseAPI)
+            .require(Method::GET, "/ws", Permission::UseAPI)
+    }
+
+    /// Build all routes behind the security layer
+    pub fn routes(&self) -> impl Filter<Extract = (impl Reply,), Error = warp::Rejection> + Clone + Send + Sync + 'static {
         let cors = warp::cors()
             .allow_any_origin()
-            .allow_headers(vec!["content-type"])
-            .allow_methods(vec!["GET", "POST", "PUT", "DELETE"]);
+            .allow_headers(vec!["content-type", "authorization", API_KEY_HEADER])
+            .allow_methods(vec!["GET", "POST", "PUT", "DELETE"])
+            .expose_headers(vec![
+                REQUEST_ID_HEADER,
+                RATE_LIMIT_LIMIT_HEADER,
+                RATE_LIMIT_REMAINING_HEADER,
+                RATE_LIMIT_RESET_HEADER,
+                "retry-after",
+            ]);
 
         // Clone Arc references for use in routes
         let memory_repo = self.memory_repository.clone();
@@ -629,6 +746,9 @@ impl WebServer {
         let sessions_file_path = self.sessions_file_path.clone();
         let agent_api_mgr = self.agent_api_manager.clone();
         let websocket_mgr = self.websocket_manager.clone();
+        let chat_service = self.chat_service.clone();
+        let conversation_store = self.conversation_store.clone();
+        let caller = self.security.caller();
 
         // Health and status endpoints
         let status = warp::path("status")
@@ -741,6 +861,74 @@ impl WebServer {
             }))
             .and_then(Self::handle_simple_chat_converse);
 
+        // Streaming chat over server-sent events
+        let api_chat_stream = warp::path("api")
+            .and(warp::path("chat"))
+            .and(warp::path("stream"))
+            .and(warp::post())
+            .and(warp::body::json())
+            .and(caller.clone())
+            .and(warp::any().map({
+                let chat_service = chat_service.clone();
+                move || chat_service.clone()
+            }))
+            .and_then(Self::handle_chat_stream);
+
+        // Stored conversations
+        let conversation_list = warp::path("api")
+            .and(warp::path("conversations"))
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(warp::query::<ConversationListQuery>())
+            .and(caller.clone())
+            .and(warp::any().map({
+                let conversation_store = conversation_store.clone();
+                move || conversation_store.clone()
+            }))
+            .and_then(Self::handle_conversation_list);
+
+        let conversation_get = warp::path("api")
+            .and(warp::path("conversations"))
+            .and(warp::path::param::<String>())
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(caller.clone())
+            .and(warp::any().map({
+                let conversation_store = conversation_store.clone();
+                move || conversation_store.clone()
+            }))
+            .and_then(Self::handle_conversation_get);
+
+        let conversation_messages = warp::path("api")
+            .and(warp::path("conversations"))
+            .and(warp::path::param::<String>())
+            .and(warp::path("messages"))
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(warp::query::<MessagePageQuery>())
+            .and(caller.clone())
+            .and(warp::any().map({
+                let conversation_store = conversation_store.clone();
+                move || conversation_store.clone()
+            }))
+            .and_then(Self::handle_conversation_messages);
+
+        let conversation_delete = warp::path("api")
+            .and(warp::path("conversations"))
+            .and(warp::path::param::<String>())
+            .and(warp::path::end())
+            .and(warp::delete())
+            .and(caller.clone())
+            .and(warp::any().map({
+                let conversation_store = conversation_store.clone();
+                move || conversation_store.clone()
+            }))
+            .and(warp::any().map({
+                let chat_service = chat_service.clone();
+                move || chat_service.clone()
+            }))
+            .and_then(Self::handle_conversation_delete);
+
         // Static file serving
         let _static_files = warp::fs::dir("web");
 
@@ -884,6 +1072,17 @@ impl WebServer {
             }))
             .and_then(Self::handle_development_context_get);
 
+        // Concept graph structure metrics
+        let graph_metrics = warp::path("api")
+            .and(warp::path("graph"))
+            .and(warp::path("metrics"))
+            .and(warp::get())
+            .and(warp::any().map({
+                let concept_mgr = concept_mgr.clone();
+                move || concept_mgr.clone()
+            }))
+            .and_then(Self::handle_graph_metrics);
+
         // Agent API endpoints
         let agent_list = warp::path("api")
             .and(warp::path("agents"))
@@ -982,6 +1181,7 @@ impl WebServer {
         // WebSocket endpoint for real-time agent updates
         let websocket = warp::path("ws")
             .and(warp::ws())
+            .and(caller.clone())
             .and(warp::any().map({
                 let websocket_mgr = websocket_mgr.clone();
                 move || websocket_mgr.clone()
@@ -1000,6 +1200,11 @@ impl WebServer {
             .or(simple_chat_converse)
             .or(api_chat_learn)
             .or(api_chat_converse)
+            .or(api_chat_stream)
+            .or(conversation_list)
+            .or(conversation_get)
+            .or(conversation_messages)
+            .or(conversation_delete)
             .or(code_analysis)
             .or(dev_context_create)
             .or(dev_context_get)
@@ -1009,6 +1214,7 @@ impl WebServer {
             .or(dev_context_analyze)
             .or(legacy_dev_context_create)
             .or(legacy_dev_context_get)
+            .or(graph_metrics)
             .or(agent_list)
             .or(agent_execute)
             .or(agent_status)
@@ -1018,8 +1224,14 @@ impl WebServer {
             .or(profile_get)
             .or(profile_update)
             .or(profile_presets)
-            .or(websocket)
-            .with(cors);
+            .or(websocket);
+
+        self.security.protect(Self::route_permissions(), routes).with(cors)
+    }
+
+    /// Start the web server
+    pub async fn start(&self) -> Result<()> {
+        let routes = self.routes();
 
         println!("🧠 Brain AI Web Server starting on port {}", self.port);
         println!("📊 Development Context API endpoints:");
@@ -1029,6 +1241,8 @@ impl WebServer {
         println!("  DELETE /api/dev/context/{{id}} - Delete development session");
         println!("  GET  /api/dev/sessions - List all sessions");
         println!("  POST /api/dev/context/analyze - Analyze development patterns");
+        println!("🕸️  Concept graph endpoints:");
+        println!("  GET  /api/graph/metrics - Concept graph structure metrics");
         println!("🤖 Agent API endpoints:");
         println!("  GET  /api/agents - List all available agents");
         println!("  POST /api/agents/{{agent_name}}/execute - Execute specific agent");
@@ -1036,6 +1250,12 @@ impl WebServer {
         println!("  POST /api/workflows/execute - Execute multi-agent workflow");
         println!("🔄 WebSocket endpoints:");
         println!("  WS   /ws - Real-time agent updates and monitoring");
+        println!("🔐 Authentication: Authorization: Bearer <jwt|api key> or X-API-Key");
+        if let Some(api_key) = &self.bootstrap_api_key {
+            let key_file = std::env::var(ADMIN_KEY_FILE_ENV).unwrap_or_else(|_| DEFAULT_ADMIN_KEY_FILE.to_string());
+            write_secret_file(Path::new(&key_file), api_key)?;
+            println!("  Admin API key for this run written to {}", key_file);
+        }
         
         warp::serve(routes)
             .run(([127, 0, 0, 1], self.port))
@@ -1192,6 +1412,19 @@ impl WebServer {
         Ok(warp::reply::json(&results))
     }
 
+    async fn handle_graph_metrics(
+        concept_mgr: Arc<Mutex<ConceptGraphManager>>,
+    ) -> std::result::Result<impl Reply, warp::Rejection> {
+        let manager = concept_mgr.lock().await;
+        match manager.get_network_metrics().await {
+            Ok(metrics) => Ok(warp::reply::json(&NetworkMetricsData::from(&metrics)).into_response()),
+            Err(e) => {
+                let error = serde_json::json!({ "error": format!("Failed to compute graph metrics: {}", e) });
+                Ok(warp::reply::with_status(warp::reply::json(&error), warp::http::StatusCode::INTERNAL_SERVER_ERROR).into_response())
+            }
+        }
+    }
+
     async fn handle_chat(
         request: ChatRequest,
         _memory_repo: Arc<Mutex<WorkingMemoryRepository>>,
@@ -1211,6 +1444,150 @@ impl WebServer {
         Ok(warp::reply::json(&response))
     }
 
+    /// Stream a RAG chat turn as server-sent events named after each `RagStreamEvent`
+    ///
+    /// The conversation belongs to the authenticated caller, whatever `user_id` the
+    /// request carries.
+    async fn handle_chat_stream(
+        mut request: RagRequest,
+        caller: Option<AuthResult>,
+        chat_service: Option<Arc<ChatStreamService>>,
+    ) -> std::result::Result<warp::reply::Response, warp::Rejection> {
+        let Some(chat_service) = chat_service else {
+            let error = serde_json::json!({
+                "error": "Chat streaming is not available: no language model provider is configured"
+            });
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&error),
+                warp::http::StatusCode::SERVICE_UNAVAILABLE,
+            ).into_response());
+        };
+
+        request.user_id = caller.map(|caller| caller.user_id);
+        let events = chat_service.stream(request);
+        let sse_events = stream::unfold(events, |mut events| async move {
+            let event = events.recv().await?;
+            let sse_event = warp::sse::Event::default()
+                .event(event.event_name())
+                .json_data(&event)
+                .unwrap_or_else(|e| warp::sse::Event::default().event("failed").data(e.to_string()));
+            Some((Ok::<_, Infallible>(sse_event), events))
+        });
+
+        Ok(warp::sse::reply(warp::sse::keep_alive().stream(sse_events)).into_response())
+    }
+
+    /// List the caller's stored conversations
+    async fn handle_conversation_list(
+        mut query: ConversationListQuery,
+        caller: Option<AuthResult>,
+        conversation_store: Arc<dyn ConversationRepository>,
+    ) -> std::result::Result<warp::reply::Response, warp::Rejection> {
+        let Some(caller) = caller else {
+            return Ok(Self::authentication_required());
+        };
+
+        query.user_id = Some(caller.user_id);
+        match conversation_store.list_conversations(&query).await {
+            Ok(page) => Ok(warp::reply::json(&page).into_response()),
+            Err(e) => Ok(Self::conversation_store_error(e)),
+        }
+    }
+
+    async fn handle_conversation_get(
+        conversation_id: String,
+        caller: Option<AuthResult>,
+        conversation_store: Arc<dyn ConversationRepository>,
+    ) -> std::result::Result<warp::reply::Response, warp::Rejection> {
+        match Self::load_owned_conversation(&conversation_id, caller, &conversation_store).await {
+            Ok(context) => Ok(warp::reply::json(&context).into_response()),
+            Err(response) => Ok(response),
+        }
+    }
+
+    async fn handle_conversation_messages(
+        conversation_id: String,
+        page: MessagePageQuery,
+        caller: Option<AuthResult>,
+        conversation_store: Arc<dyn ConversationRepository>,
+    ) -> std::result::Result<warp::reply::Response, warp::Rejection> {
+        if let Err(response) = Self::load_owned_conversation(&conversation_id, caller, &conversation_store).await {
+            return Ok(response);
+        }
+
+        let messages = match conversation_store.list_messages(&conversation_id, page.offset, page.limit).await {
+            Ok(messages) => messages,
+            Err(e) => return Ok(Self::conversation_store_error(e)),
+        };
+        Ok(warp::reply::json(&serde_json::json!({
+            "conversation_id": conversation_id,
+            "offset": page.offset,
+            "limit": page.limit,
+            "messages": messages,
+        })).into_response())
+    }
+
+    /// Delete a stored conversation, also dropping the chat service's cached copy
+    async fn handle_conversation_delete(
+        conversation_id: String,
+        caller: Option<AuthResult>,
+        conversation_store: Arc<dyn ConversationRepository>,
+        chat_service: Option<Arc<ChatStreamService>>,
+    ) -> std::result::Result<warp::reply::Response, warp::Rejection> {
+        if let Err(response) = Self::load_owned_conversation(&conversation_id, caller, &conversation_store).await {
+            return Ok(response);
+        }
+
+        let deleted = match chat_service {
+            Some(chat_service) => chat_service.delete_conversation(&conversation_id).await,
+            None => conversation_store.delete_conversation(&conversation_id).await,
+        };
+        match deleted {
+            Ok(true) => Ok(warp::reply::json(&serde_json::json!({
+                "conversation_id": conversation_id,
+                "deleted": true,
+            })).into_response()),
+            Ok(false) => Ok(Self::conversation_not_found(&conversation_id)),
+            Err(e) => Ok(Self::conversation_store_error(e)),
+        }
+    }
+
+    /// Load a stored conversation if it belongs to `caller`
+    ///
+    /// Conversations owned by someone else are reported as not found, so their ids
+    /// cannot be probed. The error is the response to send instead.
+    async fn load_owned_conversation(
+        conversation_id: &str,
+        caller: Option<AuthResult>,
+        conversation_store: &Arc<dyn ConversationRepository>,
+    ) -> std::result::Result<ConversationContext, warp::reply::Response> {
+        let Some(caller) = caller else {
+            return Err(Self::authentication_required());
+        };
+
+        match conversation_store.load_conversation(conversation_id).await {
+            Ok(Some(context)) if context.user_profile.user_id == caller.user_id => Ok(context),
+            Ok(_) => Err(Self::conversation_not_found(conversation_id)),
+            Err(e) => Err(Self::conversation_store_error(e)),
+        }
+    }
+
+    fn conversation_not_found(conversation_id: &str) -> warp::reply::Response {
+        let error = serde_json::json!({ "error": format!("Conversation {} not found", conversation_id) });
+        warp::reply::with_status(warp::reply::json(&error), warp::http::StatusCode::NOT_FOUND).into_response()
+    }
+
+    fn conversation_store_error(error: BrainError) -> warp::reply::Response {
+        error!("Conversation store failed: {}", error);
+        let error = serde_json::json!({ "error": "Failed to access the conversation store" });
+        warp::reply::with_status(warp::reply::json(&error), warp::http::StatusCode::INTERNAL_SERVER_ERROR).into_response()
+    }
+
+    fn authentication_required() -> warp::reply::Response {
+        let error = serde_json::json!({ "error": "Authentication required" });
+        warp::reply::with_status(warp::reply::json(&error), warp::http::StatusCode::UNAUTHORIZED).into_response()
+    }
+
     async fn handle_simple_chat_learn(
         request: SimpleChatLearnRequest,
         memory_repo: Arc<Mutex<WorkingMemoryRepository>>,
@@ -1470,38 +1847,27 @@ impl WebServer {
     async fn handle_code_pattern_analysis(
         request: CodePatternAnalysisRequest,
         _memory_repo: Arc<Mutex<WorkingMemoryRepository>>,
-        _concept_mgr: Arc<Mutex<ConceptGraphManager>>,
+        concept_mgr: Arc<Mutex<ConceptGraphManager>>,
     ) -> std::result::Result<impl Reply, warp::Rejection> {
-        let start_time = std::time::Instant::now();
-        
-        // Analyze code patterns (simplified implementation)
-        let patterns = vec![
-            CodePattern {
-                pattern_type: CodePatternType::Function,
-                name: "main_function".to_string(),
-                description: "Main entry point function".to_string(),
-                code_snippet: Some("fn main() { ... }".to_string()),
-                file_location: request.file_path.clone(),
-                confidence: 0.9,
-                related_patterns: vec![],
-                concept_id: Some(uuid::Uuid::new_v4().to_string()),
+        let analysis = match brain_analysis::PatternRecognizer::new().analyze(&request) {
+            Ok(mut response) if request.store_patterns => {
+                let mut concept_mgr = concept_mgr.lock().await;
+                brain_analysis::store_patterns(&mut response, &mut *concept_mgr).await.map(|_| response)
             }
-        ];
-
-        let processing_time = start_time.elapsed().as_millis() as u64;
-        
-        let response = CodePatternAnalysisResponse {
-            success: true,
-            patterns_found: patterns,
-            concepts_created: 1,
-            relationships_formed: 0,
-            analysis_time_ms: processing_time,
-            confidence_score: 0.85,
-            language_detected: request.language.or_else(|| Some("rust".to_string())),
-            architectural_insights: vec!["Standard Rust application structure".to_string()],
+            result => result,
         };
-
-        Ok(warp::reply::json(&response))
+        match analysis {
+            Ok(response) => Ok(warp::reply::json(&response)),
+            Err(e) => {
+                let error_response = serde_json::json!({
+                    "success": false,
+                    "error": e.to_string(),
+                    "patterns_found": [],
+                    "language_detected": request.language,
+                });
+                Ok(warp::reply::json(&error_response))
+            }
+        }
     }
 
     async fn handle_development_context_create(
@@ -2055,12 +2421,13 @@ impl WebServer {
     /// Handle WebSocket connections for real-time agent updates
     async fn handle_websocket(
         ws: warp::ws::Ws,
+        caller: Option<AuthResult>,
         websocket_mgr: Arc<WebSocketManager>,
     ) -> std::result::Result<impl Reply, warp::Rejection> {
         Ok(ws.on_upgrade(move |socket| {
             let websocket_mgr = websocket_mgr.clone();
             async move {
-                let _client_id = websocket_mgr.add_client(socket).await;
+                let _client_id = websocket_mgr.add_client(socket, caller).await;
                 // Client management is handled within add_client
             }
         }))
@@ -2082,6 +2449,30 @@ impl WebServer {
     }
 }
 
+/// Write a secret to a file only its owner can read or write
+fn write_secret_file(path: &Path, secret: &str) -> Result<()> {
+    use std::io::Write;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| BrainError::Io { source: e })?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // An existing file keeps its old mode when opened, so tighten it first
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(|e| BrainError::Io { source: e })?;
+        }
+    }
+
+    let mut file = options.open(path).map_err(|e| BrainError::Io { source: e })?;
+    writeln!(file, "{}", secret).map_err(|e| BrainError::Io { source: e })
+}
+
 /// Start the web server on the specified port
 pub async fn start_web_server(port: u16) -> Result<()> {
     let server = WebServer::new(port).await?;
@@ -2098,6 +2489,147 @@ mod tests {
         assert!(server.is_ok());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_secret_file_is_private_to_its_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("brain-secret-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("admin_api_key");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_secret_file(&path, "brain_secret").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().trim(), "brain_secret");
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_routes_require_authentication() {
+        let server = WebServer::new(3030).await.unwrap();
+        let api_key = server.bootstrap_api_key().unwrap().to_string();
+        let routes = server.routes();
+
+        let response = warp::test::request().method("GET").path("/health").reply(&routes).await;
+        assert_eq!(response.status(), 200);
+
+        let response = warp::test::request().method("GET").path("/api/agents").reply(&routes).await;
+        assert_eq!(response.status(), 401);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/agents")
+            .header("authorization", format!("Bearer {}", api_key))
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_chat_stream_sends_server_sent_events() {
+        use brain_cognitive::conversation::ScriptedProvider;
+        use crate::auth::{User, UserRole};
+
+        let server = WebServer::new(3030).await.unwrap();
+        let api_key = server.bootstrap_api_key().unwrap().to_string();
+        let provider = Arc::new(ScriptedProvider::repeating("Responses arrive as they are generated."));
+        let chat_service = ChatStreamService::new(
+            provider,
+            server.memory_service(),
+            server.concept_manager(),
+            server.conversation_store(),
+        );
+        let server_security = server.security.clone();
+        let routes = server.with_chat_service(chat_service).routes();
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/api/chat/stream")
+            .header("authorization", format!("Bearer {}", api_key))
+            .json(&serde_json::json!({ "message": "Why stream responses?" }))
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 200);
+
+        let body = String::from_utf8_lossy(response.body());
+        let started = body.find("\"event\":\"started\"").unwrap();
+        let delta = body.find("\"event\":\"delta\"").unwrap();
+        let knowledge = body.find("\"event\":\"knowledge\"").unwrap();
+        let completed = body.find("\"event\":\"completed\"").unwrap();
+        assert!(started < delta && delta < knowledge && knowledge < completed);
+
+        // The streamed turn is stored and can be listed, read and deleted
+        let id_start = body.find("\"conversation_id\":\"").unwrap() + "\"conversation_id\":\"".len();
+        let conversation_id = &body[id_start..id_start + 36];
+        let authorization = format!("Bearer {}", api_key);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/conversations?search=why%20stream%20responses&limit=100")
+            .header("authorization", &authorization)
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 200);
+        assert!(String::from_utf8_lossy(response.body()).contains(conversation_id));
+
+        let response = warp::test::request()
+            .method("GET")
+            .path(&format!("/api/conversations/{}/messages?offset=1", conversation_id))
+            .header("authorization", &authorization)
+            .reply(&routes)
+            .await;
+        let messages: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(messages["messages"].as_array().unwrap().len(), 1);
+        assert_eq!(messages["messages"][0]["role"], "assistant");
+
+        // Another user can neither see nor delete the conversation
+        let auth = server_security.auth_manager();
+        auth.add_user(User::new("eve".to_string(), "eve".to_string(), String::new(), UserRole::Admin)).await.unwrap();
+        let intruder = format!("Bearer {}", auth.generate_api_key("eve", UserRole::Admin, "test").await.unwrap());
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/api/conversations?search=why%20stream%20responses&limit=100")
+            .header("authorization", &intruder)
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 200);
+        assert!(!String::from_utf8_lossy(response.body()).contains(conversation_id));
+
+        for (method, path) in [
+            ("GET", format!("/api/conversations/{}", conversation_id)),
+            ("GET", format!("/api/conversations/{}/messages", conversation_id)),
+            ("DELETE", format!("/api/conversations/{}", conversation_id)),
+        ] {
+            let response = warp::test::request()
+                .method(method)
+                .path(&path)
+                .header("authorization", &intruder)
+                .reply(&routes)
+                .await;
+            assert_eq!(response.status(), 404);
+        }
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path(&format!("/api/conversations/{}", conversation_id))
+            .header("authorization", &authorization)
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 200);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path(&format!("/api/conversations/{}", conversation_id))
+            .header("authorization", &authorization)
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), 404);
+    }
+
     #[test]
     fn test_process_request_serialization() {
         let request = ProcessRequest {
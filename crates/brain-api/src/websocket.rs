@@ -6,7 +6,10 @@ use warp::ws::{Message, WebSocket};
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use brain_cognitive::conversation::{RagRequest, RagStreamEvent, RetrievedKnowledge, ResponseQuality};
 use crate::agents::{AgentStatus, SystemHealth};
+use crate::auth::{AuthResult, Permission};
+use crate::chat_stream::ChatStreamService;
 
 /// Types of WebSocket messages for real-time updates
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +80,33 @@ pub enum WebSocketMessage {
         message: String,
         timestamp: DateTime<Utc>,
     },
+    /// Chat turn started
+    ChatStarted {
+        conversation_id: String,
+    },
+    /// Chunk of a streamed chat response
+    ChatDelta {
+        conversation_id: String,
+        index: usize,
+        content: String,
+    },
+    /// Knowledge used for a chat response, sent after the last delta
+    ChatKnowledge {
+        conversation_id: String,
+        knowledge: Vec<RetrievedKnowledge>,
+    },
+    /// Chat response finished
+    ChatCompleted {
+        conversation_id: String,
+        response: String,
+        confidence_score: f64,
+        response_quality: ResponseQuality,
+    },
+    /// Chat response failed
+    ChatFailed {
+        conversation_id: Option<String>,
+        error: String,
+    },
     /// Connection acknowledgment
     Connected {
         client_id: String,
@@ -88,6 +118,36 @@ pub enum WebSocketMessage {
     },
 }
 
+impl From<RagStreamEvent> for WebSocketMessage {
+    fn from(event: RagStreamEvent) -> Self {
+        match event {
+            RagStreamEvent::Started { conversation_id } => {
+                WebSocketMessage::ChatStarted { conversation_id }
+            }
+            RagStreamEvent::Delta { conversation_id, index, content } => {
+                WebSocketMessage::ChatDelta { conversation_id, index, content }
+            }
+            RagStreamEvent::Knowledge { conversation_id, knowledge } => {
+                WebSocketMessage::ChatKnowledge { conversation_id, knowledge }
+            }
+            RagStreamEvent::Completed { conversation_id, response, confidence_score, response_quality } => {
+                WebSocketMessage::ChatCompleted { conversation_id, response, confidence_score, response_quality }
+            }
+            RagStreamEvent::Failed { conversation_id, error } => {
+                WebSocketMessage::ChatFailed { conversation_id: Some(conversation_id), error }
+            }
+        }
+    }
+}
+
+/// Requests a client can send over the WebSocket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum WebSocketClientMessage {
+    /// Run a chat turn; the response is streamed back to this client only
+    Chat(RagRequest),
+}
+
 /// Client subscription preferences
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionRequest {
@@ -116,6 +176,8 @@ pub struct WebSocketManager {
     broadcast_tx: broadcast::Sender<WebSocketMessage>,
     /// Background task handles
     _handles: Arc<Mutex<Vec<tokio::task::JoinHandle<()>>>>,
+    /// Chat service used for `WebSocketClientMessage::Chat` requests
+    chat_service: Option<Arc<ChatStreamService>>,
 }
 
 impl WebSocketManager {
@@ -127,11 +189,21 @@ impl WebSocketManager {
             clients: Arc::new(RwLock::new(HashMap::new())),
             broadcast_tx,
             _handles: Arc::new(Mutex::new(Vec::new())),
+            chat_service: None,
         }
     }
 
-    /// Add a new WebSocket client
-    pub async fn add_client(&self, ws: WebSocket) -> String {
+    /// Answer chat requests from clients with `chat_service`
+    pub fn with_chat_service(mut self, chat_service: Arc<ChatStreamService>) -> Self {
+        self.chat_service = Some(chat_service);
+        self
+    }
+
+    /// Add a new WebSocket client, authenticated as `auth` when it upgraded
+    ///
+    /// Chat requests on the connection run as `auth`'s user and need its
+    /// `QueryMemory` permission.
+    pub async fn add_client(&self, ws: WebSocket, auth: Option<AuthResult>) -> String {
         let client_id = Uuid::new_v4().to_string();
         let (mut ws_tx, mut ws_rx) = ws.split();
         let (client_tx, mut client_rx) = tokio::sync::mpsc::unbounded_channel();
@@ -146,6 +218,9 @@ impl WebSocketManager {
             let _ = client_tx.send(Message::text(msg_json));
         }
 
+        let chat_tx = client_tx.clone();
+        let chat_service = self.chat_service.clone();
+
         // Create client info with default subscriptions
         let client = WebSocketClient {
             id: client_id.clone(),
@@ -196,6 +271,8 @@ impl WebSocketManager {
                                     client.subscriptions = sub_req;
                                     client.last_heartbeat = Utc::now();
                                 }
+                            } else if let Ok(WebSocketClientMessage::Chat(request)) = serde_json::from_str(text) {
+                                Self::stream_chat(chat_service.clone(), auth.as_ref(), request, chat_tx.clone());
                             }
                         }
                     }
@@ -247,6 +324,49 @@ impl WebSocketManager {
         }
     }
 
+    /// Stream a chat turn to a single client as `Chat*` messages
+    ///
+    /// The conversation belongs to the connection's user, whatever `user_id` the
+    /// request carries.
+    fn stream_chat(
+        chat_service: Option<Arc<ChatStreamService>>,
+        auth: Option<&AuthResult>,
+        mut request: RagRequest,
+        sender: tokio::sync::mpsc::UnboundedSender<Message>,
+    ) {
+        let send = move |message: WebSocketMessage| {
+            serde_json::to_string(&message)
+                .map(|json| sender.send(Message::text(json)).is_ok())
+                .unwrap_or(false)
+        };
+
+        let Some(auth) = auth.filter(|auth| auth.has_permission(&Permission::QueryMemory)) else {
+            send(WebSocketMessage::ChatFailed {
+                conversation_id: request.conversation_id,
+                error: "Chat requires the QueryMemory permission".to_string(),
+            });
+            return;
+        };
+        request.user_id = Some(auth.user_id.clone());
+
+        let Some(chat_service) = chat_service else {
+            send(WebSocketMessage::ChatFailed {
+                conversation_id: request.conversation_id,
+                error: "Chat is not available: no language model provider is configured".to_string(),
+            });
+            return;
+        };
+
+        let mut events = chat_service.stream(request);
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                if !send(WebSocketMessage::from(event)) {
+                    break; // Client disconnected
+                }
+            }
+        });
+    }
+
     /// Get number of connected clients
     pub async fn client_count(&self) -> usize {
         let clients = self.clients.read().await;
@@ -422,4 +542,34 @@ impl WebSocketManager {
         };
         self.broadcast(notification).await;
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::UserRole;
+
+    fn chat_request() -> RagRequest {
+        RagRequest {
+            message: "What do you know?".to_string(),
+            conversation_id: Some("c1".to_string()),
+            context_limit: None,
+            retrieval_threshold: None,
+            user_id: Some("someone-else".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_requires_query_memory() {
+        let scoped = AuthResult::new("ops".to_string(), UserRole::Developer).with_scopes(Some(vec![Permission::UseAPI]));
+
+        for auth in [None, Some(&scoped)] {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            WebSocketManager::stream_chat(None, auth, chat_request(), tx);
+
+            let message = rx.recv().await.unwrap();
+            let message: WebSocketMessage = serde_json::from_str(message.to_str().unwrap()).unwrap();
+            assert!(matches!(message, WebSocketMessage::ChatFailed { ref error, .. } if error.contains("QueryMemory")));
+        }
+    }
+}
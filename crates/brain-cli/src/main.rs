@@ -1,5 +1,8 @@
 use brain_api::{AgentApiManager, AgentStatus, CreateProfileRequest};
 use brain_api::agents::SystemHealth;
+use brain_api::{ApiKeyRequest, AuthConfig, AuthManager, Permission, SqliteAuthStore, User, UserRole};
+use brain_api::middleware::AUTH_DATABASE_ENV;
+use std::sync::Arc;
 use clap::{Arg, Command, ArgMatches};
 use anyhow::Result;
 use uuid::Uuid;
@@ -639,6 +642,7 @@ async fn handle_agent_interactive(matches: &ArgMatches) -> Result<()> {
                         execution_strategy: brain_api::agents::WorkflowExecutionStrategy::Sequential,
                         timeout_seconds: Some(300),
                         continue_on_error: false,
+                        resume_execution_id: None,
                     };
                     
                     match agent_manager.execute_workflow(request).await {
@@ -687,11 +691,12 @@ async fn handle_agent_interactive(matches: &ArgMatches) -> Result<()> {
 
 /// Handle workflow execution
 async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
-    let agents_str = matches.get_one::<String>("agents").unwrap();
+    let agents_str = matches.get_one::<String>("agents").map(|s| s.as_str()).unwrap_or("");
     let strategy = matches.get_one::<String>("strategy").unwrap();
     let context_str = matches.get_one::<String>("context");
+    let resume_id = matches.get_one::<String>("resume");
     
-    let agent_names: Vec<&str> = agents_str.split(',').map(|s| s.trim()).collect();
+    let agent_names: Vec<&str> = agents_str.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
     
     // Initialize AgentApiManager for workflow operations
     let agent_manager = match AgentApiManager::new().await {
@@ -705,8 +710,12 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
     
     println!("🔄 Executing Multi-Agent Workflow");
     println!("=================================");
-    println!("🤖 Agents: {}", agent_names.join(", "));
-    println!("📋 Strategy: {}", strategy);
+    if let Some(execution_id) = resume_id {
+        println!("⏯️ Resuming execution: {}", execution_id);
+    } else {
+        println!("🤖 Agents: {}", agent_names.join(", "));
+        println!("📋 Strategy: {}", strategy);
+    }
     
     if let Some(ctx) = context_str {
         println!("🎯 Context: {}", ctx);
@@ -754,7 +763,9 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
         execution_strategy: workflow_strategy,
         timeout_seconds: Some(300), // 5 minutes max
         continue_on_error: strategy.as_str() != "sequential", // Continue on error unless sequential
+        resume_execution_id: resume_id.cloned(),
     };
+    let checkpointed = resume_id.is_some() || strategy.as_str() == "dag";
     
     // Execute workflow through AgentApiManager
     let start_time = std::time::Instant::now();
@@ -777,7 +788,7 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
                 
                 println!("📊 Agent Results:");
                 for (index, result) in response.agent_results.iter().enumerate() {
-                    let agent_name = agent_names.get(index).unwrap_or(&"Unknown");
+                    let agent_name = workflow_result_label(&agent_names, index, result);
                     let status_icon = if result.success { "✅" } else { "❌" };
                     
                     println!("   {} Step {}: {} - Duration: {} ms", 
@@ -832,11 +843,17 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
                 println!();
                 println!("📊 Partial Results ({} agents attempted):", response.agent_results.len());
                 for (index, result) in response.agent_results.iter().enumerate() {
-                    let agent_name = agent_names.get(index).unwrap_or(&"Unknown");
+                    let agent_name = workflow_result_label(&agent_names, index, result);
                     let status_icon = if result.success { "✅" } else { "❌" };
                     println!("   {} {}: {}", status_icon, agent_name, 
                         if result.success { "Completed" } else { "Failed" });
                 }
+                
+                if checkpointed {
+                    println!();
+                    println!("💡 Completed agents are checkpointed. Re-run the rest with:");
+                    println!("   brain workflows execute --resume {}", response.workflow_id);
+                }
             }
             
             println!();
@@ -845,6 +862,9 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
         }
         Err(e) => {
             println!("❌ Failed to execute workflow: {}", e);
+            if resume_id.is_some() {
+                return Ok(());
+            }
             println!("   The workflow orchestration system may not be available");
             
             // Fallback: Basic sequential execution for demonstration
@@ -864,6 +884,22 @@ async fn handle_workflow_execute(matches: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+/// Name of the agent behind a workflow result
+///
+/// DAG results carry their node ID (`agent_{i}`) after the execution ID, which
+/// maps back to the requested agent; resumed executions fall back to the node ID.
+fn workflow_result_label(agent_names: &[&str], index: usize, result: &brain_api::AgentExecutionResponse) -> String {
+    match result.execution_id.split_once('/') {
+        Some((_, node_id)) => node_id
+            .strip_prefix("agent_")
+            .and_then(|i| i.parse::<usize>().ok())
+            .and_then(|i| agent_names.get(i))
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| node_id.to_string()),
+        None => agent_names.get(index).unwrap_or(&"Unknown").to_string(),
+    }
+}
+
 /// Handle profile management commands
 async fn handle_profile_commands(matches: &ArgMatches) -> Result<()> {
     // Initialize AgentApiManager for CPP operations
@@ -1354,6 +1390,139 @@ async fn handle_benchmark_humaneval(matches: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+/// Open the authentication manager on the durable user and API key store
+async fn open_auth_manager(matches: &ArgMatches) -> Result<AuthManager> {
+    let path = matches.get_one::<String>("database")
+        .cloned()
+        .or_else(|| std::env::var(AUTH_DATABASE_ENV).ok())
+        .unwrap_or_else(|| "data/auth.db".to_string());
+    let store = SqliteAuthStore::open(&path).await?;
+    AuthManager::with_store(AuthConfig::default(), Arc::new(store))
+}
+
+fn parse_role(name: &str) -> Result<UserRole> {
+    UserRole::from_name(name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown role '{}' (expected admin, developer, analyst or viewer)", name))
+}
+
+/// Handle user and API key management commands
+async fn handle_auth_commands(matches: &ArgMatches) -> Result<()> {
+    let auth = open_auth_manager(matches).await?;
+
+    match matches.subcommand() {
+        Some(("create-user", sub_matches)) => {
+            let user_id = sub_matches.get_one::<String>("user-id").unwrap();
+            let name = sub_matches.get_one::<String>("name").unwrap_or(user_id);
+            let email = sub_matches.get_one::<String>("email").cloned().unwrap_or_default();
+            let role = parse_role(sub_matches.get_one::<String>("role").unwrap())?;
+
+            auth.add_user(User::new(user_id.clone(), name.clone(), email, role.clone())).await?;
+            println!("✅ Created user {} ({})", user_id, role.name());
+            println!("💡 Issue a key with: brain auth issue-key {}", user_id);
+        }
+        Some(("list-users", _)) => {
+            let users = auth.list_users().await?;
+            println!("👥 Users");
+            println!("========");
+            if users.is_empty() {
+                println!("📋 No users found");
+            }
+            for user in &users {
+                let status_icon = if user.active { "🟢" } else { "⚪" };
+                println!("   {} {} - {} <{}>", status_icon, user.id, user.name, user.email);
+                println!("     • Role: {}", user.role.name());
+                println!("     • Created: {}", user.created_at.format("%Y-%m-%d %H:%M UTC"));
+                if let Some(last_login) = user.last_login {
+                    println!("     • Last login: {}", last_login.format("%Y-%m-%d %H:%M UTC"));
+                }
+            }
+        }
+        Some(("issue-key", sub_matches)) => {
+            let user_id = sub_matches.get_one::<String>("user-id").unwrap();
+            let Some(user) = auth.get_user(user_id).await? else {
+                anyhow::bail!("User {} not found", user_id);
+            };
+            let role = match sub_matches.get_one::<String>("role") {
+                Some(name) => parse_role(name)?,
+                None => user.role.clone(),
+            };
+            let description = sub_matches.get_one::<String>("description").unwrap();
+
+            let mut request = ApiKeyRequest::new(user_id, role, description);
+            if let Some(scopes) = sub_matches.get_one::<String>("scopes") {
+                let scopes = scopes.split(',')
+                    .map(|name| Permission::from_name(name)
+                        .ok_or_else(|| anyhow::anyhow!("Unknown permission '{}'", name.trim())))
+                    .collect::<Result<Vec<_>>>()?;
+                request = request.with_scopes(scopes);
+            }
+            if let Some(days) = sub_matches.get_one::<i64>("expires-in-days") {
+                request = request.expires_at(chrono::Utc::now() + chrono::Duration::days(*days));
+            }
+
+            let issued = auth.issue_api_key(request).await?;
+            println!("🔑 Issued API key for {}", user_id);
+            println!("   • Key ID: {}", issued.record.id);
+            println!("   • Role: {}", issued.record.role.name());
+            if let Some(expires_at) = issued.record.expires_at {
+                println!("   • Expires: {}", expires_at.format("%Y-%m-%d %H:%M UTC"));
+            }
+            println!();
+            println!("   {}", issued.key);
+            println!();
+            println!("⚠️  Store this key now - only its hash is kept");
+        }
+        Some(("list-keys", sub_matches)) => {
+            let user_id = sub_matches.get_one::<String>("user-id").map(|s| s.as_str());
+            let keys = auth.list_api_keys(user_id).await?;
+            let now = chrono::Utc::now();
+            println!("🔑 API Keys");
+            println!("==========");
+            if keys.is_empty() {
+                println!("📋 No API keys found");
+            }
+            for key in &keys {
+                let status = if key.revoked_at.is_some() {
+                    "🔴 revoked"
+                } else if key.is_expired(now) {
+                    "⚪ expired"
+                } else {
+                    "🟢 active"
+                };
+                println!("   {} {}… - {}", status, key.key_prefix, key.description);
+                println!("     • ID: {}", key.id);
+                println!("     • User: {} ({})", key.user_id, key.role.name());
+                if let Some(scopes) = &key.scopes {
+                    let names: Vec<&str> = scopes.iter().map(Permission::name).collect();
+                    println!("     • Scopes: {}", names.join(", "));
+                }
+                println!("     • Created: {}", key.created_at.format("%Y-%m-%d %H:%M UTC"));
+                if let Some(expires_at) = key.expires_at {
+                    println!("     • Expires: {}", expires_at.format("%Y-%m-%d %H:%M UTC"));
+                }
+                match key.last_used {
+                    Some(last_used) => println!("     • Last used: {}", last_used.format("%Y-%m-%d %H:%M UTC")),
+                    None => println!("     • Last used: never"),
+                }
+            }
+        }
+        Some(("revoke-key", sub_matches)) => {
+            let key_id = sub_matches.get_one::<String>("key-id").unwrap();
+            let reason = sub_matches.get_one::<String>("reason").unwrap();
+            if auth.revoke_api_key(key_id, reason).await? {
+                println!("✅ Revoked API key {}", key_id);
+            } else {
+                println!("❌ No API key with ID {}", key_id);
+            }
+        }
+        _ => {
+            println!("❓ Unknown auth command. Use 'brain auth --help' for usage.");
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Ensure required directories exist
@@ -1450,14 +1619,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .about("Execute a multi-agent workflow")
                         .arg(
                             Arg::new("agents")
-                                .required(true)
+                                .required_unless_present("resume")
                                 .help("Comma-separated list of agents")
                         )
                         .arg(
                             Arg::new("strategy")
                                 .short('s')
                                 .long("strategy")
-                                .help("Execution strategy (sequential, parallel)")
+                                .help("Execution strategy (sequential, parallel, dag)")
                                 .default_value("sequential")
                         )
                         .arg(
@@ -1466,6 +1635,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 .long("context")
                                 .help("Workflow context (JSON)")
                         )
+                        .arg(
+                            Arg::new("resume")
+                                .long("resume")
+                                .value_name("EXECUTION_ID")
+                                .conflicts_with("agents")
+                                .help("Resume a checkpointed dag workflow, re-running only agents that did not complete")
+                        )
                 )
                 .subcommand(
                     Command::new("status")
@@ -1532,6 +1708,108 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .about("List available profile presets")
                 )
         )
+        .subcommand(
+            Command::new("auth")
+                .about("User and API key management")
+                .arg(
+                    Arg::new("database")
+                        .long("database")
+                        .value_name("PATH")
+                        .global(true)
+                        .help("Auth database file (default: $BRAIN_AUTH_DATABASE or data/auth.db)")
+                )
+                .subcommand(
+                    Command::new("create-user")
+                        .about("Create a user")
+                        .arg(
+                            Arg::new("user-id")
+                                .required(true)
+                                .help("User ID")
+                        )
+                        .arg(
+                            Arg::new("name")
+                                .short('n')
+                                .long("name")
+                                .help("Display name (defaults to the user ID)")
+                        )
+                        .arg(
+                            Arg::new("email")
+                                .short('e')
+                                .long("email")
+                                .help("Contact email")
+                        )
+                        .arg(
+                            Arg::new("role")
+                                .short('r')
+                                .long("role")
+                                .help("Role: admin, developer, analyst or viewer")
+                                .default_value("viewer")
+                        )
+                )
+                .subcommand(
+                    Command::new("list-users")
+                        .about("List users")
+                )
+                .subcommand(
+                    Command::new("issue-key")
+                        .about("Issue an API key for a user")
+                        .arg(
+                            Arg::new("user-id")
+                                .required(true)
+                                .help("User the key belongs to")
+                        )
+                        .arg(
+                            Arg::new("role")
+                                .short('r')
+                                .long("role")
+                                .help("Role granted to the key, at most the user's own (defaults to the user's role)")
+                        )
+                        .arg(
+                            Arg::new("description")
+                                .short('d')
+                                .long("description")
+                                .help("What the key is for")
+                                .default_value("API key")
+                        )
+                        .arg(
+                            Arg::new("scopes")
+                                .short('s')
+                                .long("scopes")
+                                .help("Comma-separated permissions to restrict the key to, e.g. query_memory,use_api")
+                        )
+                        .arg(
+                            Arg::new("expires-in-days")
+                                .long("expires-in-days")
+                                .value_parser(clap::value_parser!(i64).range(1..))
+                                .help("Expire the key after this many days")
+                        )
+                )
+                .subcommand(
+                    Command::new("list-keys")
+                        .about("List API keys")
+                        .arg(
+                            Arg::new("user-id")
+                                .short('u')
+                                .long("user-id")
+                                .help("Only list keys of this user")
+                        )
+                )
+                .subcommand(
+                    Command::new("revoke-key")
+                        .about("Revoke an API key")
+                        .arg(
+                            Arg::new("key-id")
+                                .required(true)
+                                .help("Key ID shown by list-keys")
+                        )
+                        .arg(
+                            Arg::new("reason")
+                                .long("reason")
+                                .help("Reason recorded in the revocation list")
+                                .default_value("revoked by operator")
+                        )
+                )
+        )
         .subcommand(
             Command::new("chat")
                 .about("🤖 AI Concierge - Chat with intelligent agent orchestration")
@@ -1669,6 +1947,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(("profiles", sub_matches)) => {
             handle_profile_commands(sub_matches).await?
         }
+        Some(("auth", sub_matches)) => {
+            handle_auth_commands(sub_matches).await?
+        }
         Some(("chat", sub_matches)) => {
             handle_concierge_chat(sub_matches).await?
         }
@@ -1711,6 +1992,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("  agents     Agent management and execution");
             println!("  workflows  Multi-agent workflow orchestration");
             println!("  profiles   Cognitive Preference Profile management");
+            println!("  auth       User and API key management");
             println!("  status     Check system status");
             println!("  version    Show version information");
             println!("  help       Show this help message");
@@ -1721,6 +2003,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("  brain agents execute code_analyzer    # Execute specific agent");
             println!("  brain workflows execute \"agent1,agent2\" # Run workflow");
             println!("  brain profiles list                   # List CPP profiles");
+            println!("  brain auth issue-key ops --expires-in-days 90 # Issue an API key");
             println!();
             println!("🎯 For command help: brain <command> --help");
         }
@@ -0,0 +1,768 @@
+//! Language Model Providers
+//!
+//! `RagOrchestrator` generates responses through the `LanguageModelProvider` trait
+//! rather than talking to a specific vendor. This module provides the OpenAI
+//! provider, an OpenAI-compatible HTTP provider for self-hosted endpoints (vLLM,
+//! Ollama, LM Studio, ...) and a deterministic scripted provider that lets the RAG
+//! pipeline run without network access.
+
+use std::collections::VecDeque;
+use std::env;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use brain_types::BrainError;
+use futures::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+
+/// Default endpoint of the hosted OpenAI API
+pub const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
+
+/// A single message sent to a language model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LlmMessage {
+    pub role: String,
+    pub content: String,
+}
+
+impl LlmMessage {
+    pub fn new(role: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            role: role.into(),
+            content: content.into(),
+        }
+    }
+
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::new("system", content)
+    }
+
+    pub fn user(content: impl Into<String>) -> Self {
+        Self::new("user", content)
+    }
+}
+
+/// Provider-independent chat completion request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionRequest {
+    pub messages: Vec<LlmMessage>,
+    pub max_tokens: Option<u32>,
+    pub temperature: f64,
+}
+
+/// Token accounting for one completion, or accumulated over many
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+impl TokenUsage {
+    pub fn new(prompt_tokens: u32, completion_tokens: u32) -> Self {
+        Self {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: prompt_tokens + completion_tokens,
+        }
+    }
+
+    /// Add another usage record to this one
+    pub fn accumulate(&mut self, other: &TokenUsage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+}
+
+/// Result of a non-streaming completion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionResponse {
+    pub content: String,
+    pub model: String,
+    pub finish_reason: Option<String>,
+    pub usage: TokenUsage,
+}
+
+/// Stream of content deltas produced by a streaming completion
+pub type CompletionStream = Pin<Box<dyn Stream<Item = Result<String, BrainError>> + Send>>;
+
+/// A chat-completion capable language model backend
+#[async_trait]
+pub trait LanguageModelProvider: Send + Sync {
+    /// Short provider name used in logs and statistics
+    fn name(&self) -> &str;
+
+    /// Model identifier requests are sent to
+    fn model(&self) -> &str;
+
+    /// Generate a complete response
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, BrainError>;
+
+    /// Generate a response as a stream of content deltas
+    async fn complete_stream(&self, request: CompletionRequest) -> Result<CompletionStream, BrainError>;
+
+    /// Tokens used by all completions made through this provider so far
+    fn token_usage(&self) -> TokenUsage;
+}
+
+/// OpenAI integration structures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIRequest {
+    pub model: String,
+    pub max_tokens: Option<u32>,
+    pub temperature: f64,
+    pub messages: Vec<OpenAIMessage>,
+    pub stream: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<OpenAIStreamOptions>,
+}
+
+/// Options for streaming requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIStreamOptions {
+    /// Ask for a final chunk reporting the token usage of the whole completion
+    pub include_usage: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIResponse {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub model: String,
+    pub choices: Vec<OpenAIChoice>,
+    pub usage: Option<OpenAIUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIChoice {
+    pub index: u32,
+    pub message: OpenAIMessage,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenAIUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// One server-sent chunk of a streaming chat completion
+#[derive(Debug, Clone, Deserialize)]
+struct OpenAIStreamChunk {
+    #[serde(default)]
+    choices: Vec<OpenAIStreamChoice>,
+    usage: Option<OpenAIUsage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OpenAIStreamChoice {
+    delta: OpenAIStreamDelta,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct OpenAIStreamDelta {
+    content: Option<String>,
+}
+
+impl From<LlmMessage> for OpenAIMessage {
+    fn from(message: LlmMessage) -> Self {
+        Self {
+            role: message.role,
+            content: message.content,
+        }
+    }
+}
+
+impl From<OpenAIUsage> for TokenUsage {
+    fn from(usage: OpenAIUsage) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
+}
+
+/// Provider for any endpoint implementing the OpenAI `/chat/completions` API
+pub struct OpenAICompatibleProvider {
+    client: reqwest::Client,
+    name: String,
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+    usage: Arc<Mutex<TokenUsage>>,
+}
+
+impl OpenAICompatibleProvider {
+    /// Create a provider for `base_url`, e.g. `http://localhost:8000/v1`
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            name: "openai-compatible".to_string(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: None,
+            model: model.into(),
+            usage: Arc::new(Mutex::new(TokenUsage::default())),
+        }
+    }
+
+    /// Send a bearer token with every request
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Override the provider name reported by `name()`
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Create a provider from `LLM_BASE_URL`, `LLM_MODEL` and the optional `LLM_API_KEY`
+    pub fn from_env() -> Result<Self, BrainError> {
+        let base_url = env::var("LLM_BASE_URL")
+            .map_err(|_| BrainError::ConfigError("LLM_BASE_URL not set".to_string()))?;
+        let model = env::var("LLM_MODEL")
+            .map_err(|_| BrainError::ConfigError("LLM_MODEL not set".to_string()))?;
+
+        let provider = Self::new(base_url, model);
+        Ok(match env::var("LLM_API_KEY") {
+            Ok(api_key) if !api_key.is_empty() => provider.with_api_key(api_key),
+            _ => provider,
+        })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn build_request(&self, request: CompletionRequest, stream: bool) -> OpenAIRequest {
+        OpenAIRequest {
+            model: self.model.clone(),
+            max_tokens: request.max_tokens,
+            temperature: request.temperature,
+            messages: request.messages.into_iter().map(OpenAIMessage::from).collect(),
+            stream,
+            stream_options: stream.then_some(OpenAIStreamOptions { include_usage: true }),
+        }
+    }
+
+    async fn send(&self, body: &OpenAIRequest) -> Result<reqwest::Response, BrainError> {
+        let mut builder = self.client
+            .post(format!("{}/chat/completions", self.base_url))
+            .header("Content-Type", "application/json")
+            .json(body);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("Bearer {}", api_key));
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| BrainError::NetworkError(format!("{} request failed: {}", self.name, e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(BrainError::NetworkError(
+                format!("{} API error ({}): {}", self.name, status, error_text)
+            ));
+        }
+
+        Ok(response)
+    }
+
+    fn record_usage(&self, usage: &TokenUsage) {
+        record_usage(&self.usage, usage);
+    }
+}
+
+#[async_trait]
+impl LanguageModelProvider for OpenAICompatibleProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, BrainError> {
+        let body = self.build_request(request, false);
+        let response = self.send(&body).await?;
+
+        let openai_response: OpenAIResponse = response
+            .json()
+            .await
+            .map_err(|e| BrainError::ParseError(format!("Failed to parse {} response: {}", self.name, e)))?;
+
+        let choice = openai_response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or_else(|| BrainError::ProcessingError(format!("No response from {}", self.name)))?;
+
+        let usage = openai_response.usage.map(TokenUsage::from).unwrap_or_default();
+        self.record_usage(&usage);
+
+        Ok(CompletionResponse {
+            content: choice.message.content,
+            model: openai_response.model,
+            finish_reason: choice.finish_reason,
+            usage,
+        })
+    }
+
+    async fn complete_stream(&self, request: CompletionRequest) -> Result<CompletionStream, BrainError> {
+        let body = self.build_request(request, true);
+        let response = self.send(&body).await?;
+
+        // Server-sent events: each `data:` line carries a JSON chunk, terminated by `[DONE]`.
+        // The usage of the whole completion arrives in a chunk of its own near the end.
+        let usage = self.usage.clone();
+        let state = (response, SseDecoder::default(), VecDeque::<String>::new(), false);
+        let deltas = stream::unfold(state, move |(mut response, mut decoder, mut pending, mut done)| {
+            let usage = usage.clone();
+            async move {
+                loop {
+                    if let Some(delta) = pending.pop_front() {
+                        return Some((Ok(delta), (response, decoder, pending, done)));
+                    }
+                    if done {
+                        return None;
+                    }
+
+                    let lines = match response.chunk().await {
+                        Ok(Some(bytes)) => decoder.push(&bytes),
+                        Ok(None) => {
+                            done = true;
+                            decoder.finish()
+                        }
+                        Err(e) => {
+                            let error = BrainError::NetworkError(format!("Stream interrupted: {}", e));
+                            return Some((Err(error), (response, decoder, pending, true)));
+                        }
+                    };
+                    for line in lines {
+                        match parse_sse_line(&line) {
+                            SseLine::Chunk { delta, usage: chunk_usage } => {
+                                pending.extend(delta);
+                                if let Some(chunk_usage) = chunk_usage {
+                                    record_usage(&usage, &chunk_usage);
+                                }
+                            }
+                            SseLine::Done => done = true,
+                            SseLine::Skip => {}
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Box::pin(deltas))
+    }
+
+    fn token_usage(&self) -> TokenUsage {
+        self.usage.lock().map(|usage| *usage).unwrap_or_default()
+    }
+}
+
+fn record_usage(total: &Mutex<TokenUsage>, usage: &TokenUsage) {
+    if let Ok(mut total) = total.lock() {
+        total.accumulate(usage);
+    }
+}
+
+/// Splits a server-sent event body into lines
+///
+/// Chunks can end in the middle of a multi-byte character, so bytes are buffered
+/// and a line is only decoded once its `\n` has arrived.
+#[derive(Default)]
+struct SseDecoder {
+    buffer: Vec<u8>,
+}
+
+impl SseDecoder {
+    /// Add a chunk of the body, returning the lines it completes
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+        let mut lines = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            lines.push(String::from_utf8_lossy(&line).trim().to_string());
+        }
+        lines
+    }
+
+    /// The unterminated last line, once the body has ended
+    fn finish(&mut self) -> Vec<String> {
+        let rest = std::mem::take(&mut self.buffer);
+        let line = String::from_utf8_lossy(&rest).trim().to_string();
+        if line.is_empty() { Vec::new() } else { vec![line] }
+    }
+}
+
+enum SseLine {
+    Chunk {
+        delta: Option<String>,
+        usage: Option<TokenUsage>,
+    },
+    Done,
+    Skip,
+}
+
+fn parse_sse_line(line: &str) -> SseLine {
+    let Some(data) = line.strip_prefix("data:") else {
+        return SseLine::Skip;
+    };
+    let data = data.trim();
+    if data == "[DONE]" {
+        return SseLine::Done;
+    }
+
+    let Ok(chunk) = serde_json::from_str::<OpenAIStreamChunk>(data) else {
+        return SseLine::Skip;
+    };
+    let delta = chunk.choices
+        .into_iter()
+        .next()
+        .and_then(|choice| choice.delta.content)
+        .filter(|content| !content.is_empty());
+    let usage = chunk.usage.map(TokenUsage::from);
+    if delta.is_none() && usage.is_none() {
+        return SseLine::Skip;
+    }
+    SseLine::Chunk { delta, usage }
+}
+
+/// The hosted OpenAI API
+pub struct OpenAIProvider {
+    inner: OpenAICompatibleProvider,
+}
+
+impl OpenAIProvider {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            inner: OpenAICompatibleProvider::new(OPENAI_API_BASE, model)
+                .with_api_key(api_key)
+                .with_name("openai"),
+        }
+    }
+
+    /// Create a provider from `OPENAI_API_KEY` and `OPENAI_MODEL` (default `gpt-4`)
+    pub fn from_env() -> Result<Self, BrainError> {
+        let api_key = env::var("OPENAI_API_KEY")
+            .map_err(|_| BrainError::ConfigError("OPENAI_API_KEY not set".to_string()))?;
+        let model = env::var("OPENAI_MODEL")
+            .unwrap_or_else(|_| "gpt-4".to_string());
+
+        Ok(Self::new(api_key, model))
+    }
+}
+
+#[async_trait]
+impl LanguageModelProvider for OpenAIProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, BrainError> {
+        self.inner.complete(request).await
+    }
+
+    async fn complete_stream(&self, request: CompletionRequest) -> Result<CompletionStream, BrainError> {
+        self.inner.complete_stream(request).await
+    }
+
+    fn token_usage(&self) -> TokenUsage {
+        self.inner.token_usage()
+    }
+}
+
+/// Deterministic provider that replays scripted responses without network access
+///
+/// Responses are returned in the order they were scripted. Once the script is
+/// exhausted the fallback response is returned, or an error if there is none.
+/// Token counts are whitespace-separated word counts.
+pub struct ScriptedProvider {
+    model: String,
+    script: Mutex<VecDeque<String>>,
+    fallback: Option<String>,
+    requests: Mutex<Vec<CompletionRequest>>,
+    usage: Mutex<TokenUsage>,
+}
+
+impl ScriptedProvider {
+    pub fn new<I, S>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            model: "scripted".to_string(),
+            script: Mutex::new(responses.into_iter().map(Into::into).collect()),
+            fallback: None,
+            requests: Mutex::new(Vec::new()),
+            usage: Mutex::new(TokenUsage::default()),
+        }
+    }
+
+    /// A provider that answers every request with the same response
+    pub fn repeating(response: impl Into<String>) -> Self {
+        Self::new(Vec::<String>::new()).with_fallback(response)
+    }
+
+    /// Response returned once the script is exhausted
+    pub fn with_fallback(mut self, response: impl Into<String>) -> Self {
+        self.fallback = Some(response.into());
+        self
+    }
+
+    /// Append a response to the end of the script
+    pub fn push_response(&self, response: impl Into<String>) {
+        if let Ok(mut script) = self.script.lock() {
+            script.push_back(response.into());
+        }
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<CompletionRequest> {
+        self.requests.lock().map(|requests| requests.clone()).unwrap_or_default()
+    }
+
+    fn next_response(&self, request: CompletionRequest) -> Result<(String, TokenUsage), BrainError> {
+        let prompt_tokens: u32 = request.messages
+            .iter()
+            .map(|m| count_tokens(&m.content))
+            .sum();
+
+        self.requests
+            .lock()
+            .map_err(|e| BrainError::LockError(e.to_string()))?
+            .push(request);
+
+        let content = self.script
+            .lock()
+            .map_err(|e| BrainError::LockError(e.to_string()))?
+            .pop_front()
+            .or_else(|| self.fallback.clone())
+            .ok_or_else(|| BrainError::ProcessingError("Scripted provider has no responses left".to_string()))?;
+
+        let usage = TokenUsage::new(prompt_tokens, count_tokens(&content));
+        if let Ok(mut total) = self.usage.lock() {
+            total.accumulate(&usage);
+        }
+
+        Ok((content, usage))
+    }
+}
+
+fn count_tokens(text: &str) -> u32 {
+    text.split_whitespace().count() as u32
+}
+
+#[async_trait]
+impl LanguageModelProvider for ScriptedProvider {
+    fn name(&self) -> &str {
+        "scripted"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, BrainError> {
+        let (content, usage) = self.next_response(request)?;
+        Ok(CompletionResponse {
+            content,
+            model: self.model.clone(),
+            finish_reason: Some("stop".to_string()),
+            usage,
+        })
+    }
+
+    async fn complete_stream(&self, request: CompletionRequest) -> Result<CompletionStream, BrainError> {
+        let (content, _) = self.next_response(request)?;
+
+        // One delta per word, keeping the separating whitespace so deltas concatenate back
+        let mut deltas = Vec::new();
+        let mut current = String::new();
+        for c in content.chars() {
+            if c.is_whitespace() && !current.trim().is_empty() {
+                deltas.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+        }
+        if !current.is_empty() {
+            deltas.push(current);
+        }
+
+        Ok(Box::pin(stream::iter(deltas.into_iter().map(Ok))))
+    }
+
+    fn token_usage(&self) -> TokenUsage {
+        self.usage.lock().map(|usage| *usage).unwrap_or_default()
+    }
+}
+
+/// Select a provider from the environment
+///
+/// `LLM_PROVIDER` chooses between `openai` (the default), `openai-compatible` and
+/// `scripted`. The scripted provider answers with `LLM_SCRIPTED_RESPONSE`.
+pub fn provider_from_env() -> Result<Box<dyn LanguageModelProvider>, BrainError> {
+    let provider = env::var("LLM_PROVIDER").unwrap_or_else(|_| "openai".to_string());
+    match provider.to_lowercase().as_str() {
+        "openai" => Ok(Box::new(OpenAIProvider::from_env()?)),
+        "openai-compatible" | "openai_compatible" => Ok(Box::new(OpenAICompatibleProvider::from_env()?)),
+        "scripted" | "mock" => {
+            let response = env::var("LLM_SCRIPTED_RESPONSE")
+                .unwrap_or_else(|_| "This is a scripted response.".to_string());
+            Ok(Box::new(ScriptedProvider::repeating(response)))
+        }
+        other => Err(BrainError::ConfigError(format!("Unknown LLM_PROVIDER: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn request(content: &str) -> CompletionRequest {
+        CompletionRequest {
+            messages: vec![LlmMessage::system("be brief"), LlmMessage::user(content)],
+            max_tokens: Some(64),
+            temperature: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn scripted_provider_replays_in_order_then_falls_back() {
+        let provider = ScriptedProvider::new(["first answer", "second answer"])
+            .with_fallback("fallback");
+
+        assert_eq!(provider.complete(request("a")).await.unwrap().content, "first answer");
+        assert_eq!(provider.complete(request("b")).await.unwrap().content, "second answer");
+        assert_eq!(provider.complete(request("c")).await.unwrap().content, "fallback");
+        assert_eq!(provider.requests().len(), 3);
+        assert_eq!(provider.requests()[1].messages[1].content, "b");
+    }
+
+    #[tokio::test]
+    async fn scripted_provider_errors_when_exhausted() {
+        let provider = ScriptedProvider::new(["only"]);
+        provider.complete(request("a")).await.unwrap();
+        assert!(provider.complete(request("b")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn scripted_provider_accumulates_token_usage() {
+        let provider = ScriptedProvider::repeating("three word answer");
+
+        let response = provider.complete(request("hello there")).await.unwrap();
+        assert_eq!(response.usage, TokenUsage::new(4, 3));
+
+        provider.complete(request("hi")).await.unwrap();
+        assert_eq!(provider.token_usage(), TokenUsage::new(7, 6));
+    }
+
+    #[tokio::test]
+    async fn scripted_stream_concatenates_to_response() {
+        let provider = ScriptedProvider::repeating("Streaming  works word by word.");
+
+        let deltas: Vec<String> = provider
+            .complete_stream(request("go"))
+            .await
+            .unwrap()
+            .map(|delta| delta.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(deltas.len(), 5);
+        assert_eq!(deltas.concat(), "Streaming  works word by word.");
+    }
+
+    #[test]
+    fn sse_lines_are_parsed() {
+        let chunk = r#"data: {"choices":[{"delta":{"content":"Hel"}}]}"#;
+        assert!(matches!(parse_sse_line(chunk), SseLine::Chunk { delta: Some(ref d), usage: None } if d == "Hel"));
+        let usage = r#"data: {"choices":[],"usage":{"prompt_tokens":5,"completion_tokens":2,"total_tokens":7}}"#;
+        assert!(matches!(parse_sse_line(usage), SseLine::Chunk { delta: None, usage: Some(u) } if u == TokenUsage::new(5, 2)));
+        assert!(matches!(parse_sse_line("data: [DONE]"), SseLine::Done));
+        assert!(matches!(parse_sse_line(r#"data: {"choices":[{"delta":{}}]}"#), SseLine::Skip));
+        assert!(matches!(parse_sse_line(": keep-alive"), SseLine::Skip));
+    }
+
+    #[test]
+    fn sse_decoder_waits_for_complete_lines() {
+        let line = "data: {\"choices\":[{\"delta\":{\"content\":\"h\u{e9}llo\"}}]}\n";
+        let bytes = line.as_bytes();
+        // Split inside the two bytes of `é`
+        let split = line.find('\u{e9}').unwrap() + 1;
+
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(&bytes[..split]).is_empty());
+        let lines = decoder.push(&bytes[split..]);
+        assert_eq!(lines.len(), 1);
+        assert!(matches!(parse_sse_line(&lines[0]), SseLine::Chunk { delta: Some(ref d), .. } if d == "héllo"));
+        assert!(decoder.finish().is_empty());
+    }
+
+    #[tokio::test]
+    async fn compatible_provider_streams_split_characters_and_records_usage() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let body = concat!(
+            "data: {\"choices\":[{\"delta\":{\"content\":\"Caf\u{e9} \"}}]}\n\n",
+            "data: {\"choices\":[{\"delta\":{\"content\":\"ouvert\"}}]}\n\n",
+            "data: {\"choices\":[],\"usage\":{\"prompt_tokens\":9,\"completion_tokens\":3,\"total_tokens\":12}}\n\n",
+            "data: [DONE]\n\n",
+        );
+        let split = body.find('\u{e9}').unwrap() + 1;
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 8192];
+            let _ = socket.read(&mut request).await.unwrap();
+
+            let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nTransfer-Encoding: chunked\r\n\r\n";
+            socket.write_all(head.as_bytes()).await.unwrap();
+            for part in [&body.as_bytes()[..split], &body.as_bytes()[split..]] {
+                socket.write_all(format!("{:x}\r\n", part.len()).as_bytes()).await.unwrap();
+                socket.write_all(part).await.unwrap();
+                socket.write_all(b"\r\n").await.unwrap();
+                socket.flush().await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+            socket.write_all(b"0\r\n\r\n").await.unwrap();
+        });
+
+        let provider = OpenAICompatibleProvider::new(format!("http://{}/v1", address), "local");
+        let deltas: Vec<String> = provider
+            .complete_stream(request("say hi"))
+            .await
+            .unwrap()
+            .map(|delta| delta.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(deltas.concat(), "Café ouvert");
+        assert_eq!(provider.token_usage(), TokenUsage::new(9, 3));
+    }
+}
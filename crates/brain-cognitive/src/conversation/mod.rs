@@ -7,8 +7,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
+use futures::StreamExt;
+use tokio::sync::mpsc;
 use uuid::Uuid;
-use reqwest;
 
 // Brain AI dependencies
 use brain_types::BrainError;
@@ -20,12 +22,23 @@ use brain_core::{
 
 // Sub-modules
 pub mod context;
+pub mod llm_provider;
 pub mod response_quality;
+pub mod store;
 pub mod traits;
 
 // Re-exports
 pub use context::{ConversationContext, ConversationThread, UserProfile, TemporalContext};
+pub use llm_provider::{
+    LanguageModelProvider, LlmMessage, CompletionRequest, CompletionResponse, CompletionStream,
+    TokenUsage, OpenAIProvider, OpenAICompatibleProvider, ScriptedProvider, provider_from_env,
+    OpenAIRequest, OpenAIMessage, OpenAIResponse, OpenAIChoice, OpenAIUsage, OpenAIStreamOptions,
+};
 pub use response_quality::{ResponseQuality, SafetyFlags, SourceAttribution};
+pub use store::{
+    ConversationRepository, InMemoryConversationRepository, SqliteConversationRepository,
+    ConversationSummary, ConversationListQuery, ConversationPage,
+};
 pub use traits::{ConversationService, KnowledgeRetriever, ResponseGenerator};
 
 /// Core conversation data structures
@@ -52,6 +65,9 @@ pub struct RagRequest {
     pub conversation_id: Option<String>,
     pub context_limit: Option<usize>,
     pub retrieval_threshold: Option<f64>,
+    /// User the conversation belongs to, recorded on its `UserProfile`
+    #[serde(default)]
+    pub user_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +79,67 @@ pub struct RagResponse {
     pub response_quality: ResponseQuality,
 }
 
+/// One frame of a streamed conversation turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RagStreamEvent {
+    /// The turn has started; carries the id of a newly created conversation
+    Started {
+        conversation_id: String,
+    },
+    /// A chunk of response text; chunks concatenate to the final response
+    Delta {
+        conversation_id: String,
+        index: usize,
+        content: String,
+    },
+    /// Knowledge the response was grounded in, sent after the last delta
+    Knowledge {
+        conversation_id: String,
+        knowledge: Vec<RetrievedKnowledge>,
+    },
+    /// Final frame of a successful turn
+    Completed {
+        conversation_id: String,
+        response: String,
+        confidence_score: f64,
+        response_quality: ResponseQuality,
+    },
+    /// Final frame of a failed turn
+    Failed {
+        conversation_id: String,
+        error: String,
+    },
+}
+
+impl RagStreamEvent {
+    /// Event name used for server-sent events
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            RagStreamEvent::Started { .. } => "started",
+            RagStreamEvent::Delta { .. } => "delta",
+            RagStreamEvent::Knowledge { .. } => "knowledge",
+            RagStreamEvent::Completed { .. } => "completed",
+            RagStreamEvent::Failed { .. } => "failed",
+        }
+    }
+
+    pub fn conversation_id(&self) -> &str {
+        match self {
+            RagStreamEvent::Started { conversation_id }
+            | RagStreamEvent::Delta { conversation_id, .. }
+            | RagStreamEvent::Knowledge { conversation_id, .. }
+            | RagStreamEvent::Completed { conversation_id, .. }
+            | RagStreamEvent::Failed { conversation_id, .. } => conversation_id,
+        }
+    }
+
+    /// Whether this is the last event of the turn
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, RagStreamEvent::Completed { .. } | RagStreamEvent::Failed { .. })
+    }
+}
+
 /// Communication styles for user profiles
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CommunicationStyle {
@@ -148,46 +225,6 @@ pub enum RiskLevel {
     Critical,
 }
 
-/// OpenAI integration structures
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIRequest {
-    pub model: String,
-    pub max_tokens: Option<u32>,
-    pub temperature: f64,
-    pub messages: Vec<OpenAIMessage>,
-    pub stream: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIMessage {
-    pub role: String,
-    pub content: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIResponse {
-    pub id: String,
-    pub object: String,
-    pub created: u64,
-    pub model: String,
-    pub choices: Vec<OpenAIChoice>,
-    pub usage: Option<OpenAIUsage>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIChoice {
-    pub index: u32,
-    pub message: OpenAIMessage,
-    pub finish_reason: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIUsage {
-    pub prompt_tokens: u32,
-    pub completion_tokens: u32,
-    pub total_tokens: u32,
-}
-
 /// Brain AI impersonation handler
 #[derive(Debug)]
 pub struct BrainImpersonationHandler {
@@ -236,12 +273,8 @@ impl Default for BrainImpersonationHandler {
 impl BrainImpersonationHandler {
     /// Process response to maintain Brain AI illusion
     pub fn process_response(&self, response: &str) -> String {
-        let mut processed = response.to_string();
-        
         // Apply replacements
-        for (target, replacement) in &self.replacements {
-            processed = processed.replace(target, replacement);
-        }
+        let mut processed = self.apply_replacements(response);
         
         // Enhance with Brain persona if needed
         if self.needs_brain_ai_enhancement(&processed) {
@@ -251,6 +284,28 @@ impl BrainImpersonationHandler {
         processed
     }
     
+    /// Apply the provider-name replacements to a stream of response deltas
+    pub fn stream_filter(&self) -> ImpersonationStreamFilter<'_> {
+        let holdback = self.replacements
+            .keys()
+            .map(|target| target.chars().count().saturating_sub(1))
+            .max()
+            .unwrap_or(0);
+        ImpersonationStreamFilter {
+            handler: self,
+            pending: String::new(),
+            holdback,
+        }
+    }
+
+    fn apply_replacements(&self, text: &str) -> String {
+        let mut processed = text.to_string();
+        for (target, replacement) in &self.replacements {
+            processed = processed.replace(target, replacement);
+        }
+        processed
+    }
+    
     /// Check if response needs Brain AI enhancement
     fn needs_brain_ai_enhancement(&self, response: &str) -> bool {
         let response_lower = response.to_lowercase();
@@ -280,6 +335,44 @@ impl BrainImpersonationHandler {
     }
 }
 
+/// Incremental replacement filter for streamed responses
+///
+/// The last few characters of the stream are held back so that a replacement
+/// target split across two deltas is still replaced. Unlike `process_response`,
+/// no persona preamble is added since the start of the response has already
+/// been sent.
+pub struct ImpersonationStreamFilter<'a> {
+    handler: &'a BrainImpersonationHandler,
+    pending: String,
+    holdback: usize,
+}
+
+impl ImpersonationStreamFilter<'_> {
+    /// Add a delta, returning the text that is now safe to emit
+    pub fn push(&mut self, delta: &str) -> String {
+        self.pending.push_str(delta);
+        self.pending = self.handler.apply_replacements(&self.pending);
+
+        let char_count = self.pending.chars().count();
+        if char_count <= self.holdback {
+            return String::new();
+        }
+        let split = self.pending
+            .char_indices()
+            .nth(char_count - self.holdback)
+            .map(|(index, _)| index)
+            .unwrap_or(self.pending.len());
+        let tail = self.pending.split_off(split);
+        std::mem::replace(&mut self.pending, tail)
+    }
+
+    /// Flush the held back text at the end of the stream
+    pub fn finish(&mut self) -> String {
+        let remaining = std::mem::take(&mut self.pending);
+        self.handler.apply_replacements(&remaining)
+    }
+}
+
 /// Advanced retrieved knowledge with enhanced metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdvancedRetrievedKnowledge {
@@ -327,27 +420,101 @@ impl Default for ContextRetrievalConfig {
     }
 }
 
+/// A streamed turn whose knowledge has been retrieved and whose response is still to be generated
+///
+/// Created by `RagOrchestrator::begin_stream_turn` and handed back to
+/// `RagOrchestrator::finish_stream_turn` with the generated response.
+pub struct StreamedTurn {
+    request: RagRequest,
+    conversation_id: String,
+    context: ConversationContext,
+    retrieved_knowledge: Vec<RetrievedKnowledge>,
+    completion_request: CompletionRequest,
+    provider: Arc<dyn LanguageModelProvider>,
+    impersonation: Arc<BrainImpersonationHandler>,
+    events: mpsc::UnboundedSender<RagStreamEvent>,
+}
+
+impl StreamedTurn {
+    /// Conversation the turn belongs to
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+
+    /// Step 4: stream the response from the external LLM, sending a `Delta` per chunk
+    ///
+    /// Returns the full filtered response. Sends `Failed` on error.
+    pub async fn generate(&self) -> Result<String, BrainError> {
+        let generated = self.stream_response().await;
+        if let Err(e) = &generated {
+            let _ = self.events.send(RagStreamEvent::Failed {
+                conversation_id: self.conversation_id.clone(),
+                error: e.to_string(),
+            });
+        }
+        generated
+    }
+
+    async fn stream_response(&self) -> Result<String, BrainError> {
+        println!("🧠 Streaming response with {} provider", self.provider.name());
+        let mut deltas = self.provider.complete_stream(self.completion_request.clone()).await?;
+        let mut filter = self.impersonation.stream_filter();
+        let mut llm_response = String::new();
+        let mut index = 0;
+
+        let mut finished = false;
+        while !finished {
+            let content = match deltas.next().await {
+                Some(delta) => filter.push(&delta?),
+                None => {
+                    finished = true;
+                    filter.finish()
+                }
+            };
+            if content.is_empty() {
+                continue;
+            }
+
+            llm_response.push_str(&content);
+            self.events
+                .send(RagStreamEvent::Delta {
+                    conversation_id: self.conversation_id.clone(),
+                    index,
+                    content,
+                })
+                .map_err(|_| stream_receiver_dropped())?;
+            index += 1;
+        }
+        Ok(llm_response)
+    }
+}
+
+fn stream_receiver_dropped() -> BrainError {
+    BrainError::ProcessingError("Stream receiver dropped".to_string())
+}
+
 /// Main RAG Orchestrator implementation
 pub struct RagOrchestrator {
-    client: reqwest::Client,
-    openai_api_key: String,
-    openai_model: String,
+    provider: Arc<dyn LanguageModelProvider>,
     max_tokens: u32,
     temperature: f64,
     conversations: HashMap<String, ConversationContext>,
-    brain_impersonation: BrainImpersonationHandler,
+    conversation_store: Option<Arc<dyn ConversationRepository>>,
+    brain_impersonation: Arc<BrainImpersonationHandler>,
     _enable_brain_ai_delegation: bool,
 }
 
 impl RagOrchestrator {
-    /// Create new RAG Orchestrator
+    /// Create new RAG Orchestrator with the provider selected by `provider_from_env`
     pub fn new() -> Result<Self, BrainError> {
-        let openai_api_key = env::var("OPENAI_API_KEY")
-            .map_err(|_| BrainError::ConfigError("OPENAI_API_KEY not set".to_string()))?;
-        
-        let openai_model = env::var("OPENAI_MODEL")
-            .unwrap_or_else(|_| "gpt-4".to_string());
-        
+        let provider: Arc<dyn LanguageModelProvider> = Arc::from(provider_from_env()?);
+        Ok(Self::with_provider(provider))
+    }
+
+    /// Create a RAG Orchestrator that generates responses with `provider`
+    ///
+    /// Generation settings are still read from `MAX_TOKENS` and `TEMPERATURE`.
+    pub fn with_provider(provider: Arc<dyn LanguageModelProvider>) -> Self {
         let max_tokens = env::var("MAX_TOKENS")
             .unwrap_or_else(|_| "4000".to_string())
             .parse::<u32>()
@@ -358,25 +525,38 @@ impl RagOrchestrator {
             .parse::<f64>()
             .unwrap_or(0.7);
         
-        let client = reqwest::Client::new();
-        
         let _enable_brain_ai_delegation = env::var("ENABLE_BRAIN_AI_DELEGATION")
             .unwrap_or_else(|_| "true".to_string())
             .parse()
             .unwrap_or(true);
         
-        println!("✅ RAG Orchestrator initialized with model: {}", openai_model);
+        println!("✅ RAG Orchestrator initialized with {} model: {}", provider.name(), provider.model());
         
-        Ok(Self {
-            client,
-            openai_api_key,
-            openai_model,
+        Self {
+            provider,
             max_tokens,
             temperature,
             conversations: HashMap::new(),
-            brain_impersonation: BrainImpersonationHandler::default(),
+            conversation_store: None,
+            brain_impersonation: Arc::new(BrainImpersonationHandler::default()),
             _enable_brain_ai_delegation,
-        })
+        }
+    }
+
+    /// Persist conversations to `store` after every turn and resume them from it
+    pub fn with_conversation_store(mut self, store: Arc<dyn ConversationRepository>) -> Self {
+        self.conversation_store = Some(store);
+        self
+    }
+
+    /// The language model provider used for generation
+    pub fn provider(&self) -> &Arc<dyn LanguageModelProvider> {
+        &self.provider
+    }
+
+    /// Tokens used by the provider across all conversations
+    pub fn token_usage(&self) -> TokenUsage {
+        self.provider.token_usage()
     }
 
     /// Process a conversation request and generate a response
@@ -388,18 +568,175 @@ impl RagOrchestrator {
     ) -> Result<RagResponse, BrainError> {
         println!("🎯 RAG Orchestrator: Processing conversation request");
         
-        // Step 1: Retrieve or create conversation context
-        let conversation_id = request.conversation_id.unwrap_or_else(|| Uuid::new_v4().to_string());
-        let mut context = self.conversations.get(&conversation_id).cloned()
+        let conversation_id = request.conversation_id.clone().unwrap_or_else(|| Uuid::new_v4().to_string());
+        let (context, retrieved_knowledge) = self.prepare_turn(
+            &request,
+            &conversation_id,
+            memory_system,
+            concept_graph,
+        ).await?;
+
+        // Step 4: Generate response using external LLM
+        let llm_response = self.generate_with_external_llm(
+            &request.message,
+            &context,
+            &retrieved_knowledge,
+        ).await?;
+
+        let response = self.complete_turn(
+            &request.message,
+            conversation_id,
+            context,
+            retrieved_knowledge,
+            llm_response,
+            memory_system,
+        ).await?;
+
+        println!("✅ RAG Orchestrator: Generated response with {} knowledge sources", response.context_used.len());
+        Ok(response)
+    }
+
+    /// Process a conversation request, streaming the response as it is generated
+    ///
+    /// Events are sent on `events` in order: `Started`, one `Delta` per chunk of
+    /// response text, then `Knowledge` and `Completed` as trailing frames. On
+    /// failure a `Failed` event is sent and the error returned. Generation stops
+    /// early if the receiver is dropped.
+    pub async fn process_conversation_stream(
+        &mut self,
+        request: RagRequest,
+        memory_system: &mut MemoryService,
+        concept_graph: &mut ConceptGraphService,
+        events: mpsc::UnboundedSender<RagStreamEvent>,
+    ) -> Result<RagResponse, BrainError> {
+        println!("🎯 RAG Orchestrator: Processing streaming conversation request");
+
+        let turn = self.begin_stream_turn(request, memory_system, concept_graph, events).await?;
+        let llm_response = turn.generate().await?;
+        self.finish_stream_turn(turn, llm_response, memory_system).await
+    }
+
+    /// Start a streamed turn: send `Started`, then load the conversation and retrieve knowledge
+    ///
+    /// The returned turn generates its response without borrowing the orchestrator,
+    /// so a caller sharing one orchestrator between turns only needs it for this
+    /// step and for `finish_stream_turn`. Sends `Failed` on error.
+    pub async fn begin_stream_turn(
+        &mut self,
+        request: RagRequest,
+        memory_system: &mut MemoryService,
+        concept_graph: &mut ConceptGraphService,
+        events: mpsc::UnboundedSender<RagStreamEvent>,
+    ) -> Result<StreamedTurn, BrainError> {
+        let conversation_id = request.conversation_id.clone().unwrap_or_else(|| Uuid::new_v4().to_string());
+        let prepared = async {
+            events
+                .send(RagStreamEvent::Started { conversation_id: conversation_id.clone() })
+                .map_err(|_| stream_receiver_dropped())?;
+            self.prepare_turn(&request, &conversation_id, memory_system, concept_graph).await
+        }
+        .await;
+
+        match prepared {
+            Ok((context, retrieved_knowledge)) => Ok(StreamedTurn {
+                completion_request: self.build_completion_request(&request.message, &context, &retrieved_knowledge),
+                provider: self.provider.clone(),
+                impersonation: self.brain_impersonation.clone(),
+                request,
+                conversation_id,
+                context,
+                retrieved_knowledge,
+                events,
+            }),
+            Err(e) => {
+                let _ = events.send(RagStreamEvent::Failed { conversation_id, error: e.to_string() });
+                Err(e)
+            }
+        }
+    }
+
+    /// Finish a streamed turn: record `llm_response`, then send `Knowledge` and `Completed`
+    ///
+    /// Sends `Failed` on error.
+    pub async fn finish_stream_turn(
+        &mut self,
+        turn: StreamedTurn,
+        llm_response: String,
+        memory_system: &mut MemoryService,
+    ) -> Result<RagResponse, BrainError> {
+        let StreamedTurn { request, conversation_id, context, retrieved_knowledge, events, .. } = turn;
+        let completed = async {
+            let response = self.complete_turn(
+                &request.message,
+                conversation_id.clone(),
+                context,
+                retrieved_knowledge,
+                llm_response,
+                memory_system,
+            ).await?;
+
+            events
+                .send(RagStreamEvent::Knowledge {
+                    conversation_id: response.conversation_id.clone(),
+                    knowledge: response.context_used.clone(),
+                })
+                .map_err(|_| stream_receiver_dropped())?;
+            events
+                .send(RagStreamEvent::Completed {
+                    conversation_id: response.conversation_id.clone(),
+                    response: response.response.clone(),
+                    confidence_score: response.confidence_score,
+                    response_quality: response.response_quality.clone(),
+                })
+                .map_err(|_| stream_receiver_dropped())?;
+            Ok::<_, BrainError>(response)
+        }
+        .await;
+
+        match completed {
+            Ok(response) => {
+                println!("✅ RAG Orchestrator: Streamed response with {} knowledge sources", response.context_used.len());
+                Ok(response)
+            }
+            Err(e) => {
+                let _ = events.send(RagStreamEvent::Failed { conversation_id, error: e.to_string() });
+                Err(e)
+            }
+        }
+    }
+
+    /// Steps 1-3: load the conversation context, record the user message and retrieve knowledge
+    async fn prepare_turn(
+        &mut self,
+        request: &RagRequest,
+        conversation_id: &str,
+        memory_system: &mut MemoryService,
+        concept_graph: &mut ConceptGraphService,
+    ) -> Result<(ConversationContext, Vec<RetrievedKnowledge>), BrainError> {
+        // Step 1: Retrieve, resume or create conversation context
+        let existing = match self.conversations.get(conversation_id) {
+            Some(context) => Some(context.clone()),
+            None => match &self.conversation_store {
+                Some(store) => store.load_conversation(conversation_id).await?,
+                None => None,
+            },
+        };
+        // A conversation is only continued by the user it belongs to; anyone else is
+        // told it does not exist rather than that it is someone else's
+        let owner = request.user_id.clone().unwrap_or_default();
+        if existing.as_ref().is_some_and(|context| context.user_profile.user_id != owner) {
+            return Err(BrainError::NotFound(format!("Conversation {} not found", conversation_id)));
+        }
+        let mut context = existing
             .unwrap_or_else(|| ConversationContext {
-                conversation_id: conversation_id.clone(),
+                conversation_id: conversation_id.to_string(),
                 messages: Vec::new(),
                 retrieved_knowledge: Vec::new(),
                 context_summary: String::new(),
                 user_preferences: HashMap::new(),
                 conversation_threads: Vec::new(),
                 user_profile: UserProfile {
-                    user_id: String::new(),
+                    user_id: owner,
                     interests: HashMap::new(),
                     expertise_areas: HashMap::new(),
                     communication_style: CommunicationStyle::Conversational,
@@ -435,19 +772,24 @@ impl RagOrchestrator {
         ).await?;
 
         context.retrieved_knowledge = retrieved_knowledge.clone();
+        Ok((context, retrieved_knowledge))
+    }
 
-        // Step 4: Generate response using external LLM
-        let llm_response = self.generate_with_external_llm(
-            &request.message,
-            &context,
-            &retrieved_knowledge,
-        ).await?;
-
+    /// Steps 5-8: score the response, record it and store the interaction in memory
+    async fn complete_turn(
+        &mut self,
+        user_message: &str,
+        conversation_id: String,
+        mut context: ConversationContext,
+        retrieved_knowledge: Vec<RetrievedKnowledge>,
+        llm_response: String,
+        memory_system: &mut MemoryService,
+    ) -> Result<RagResponse, BrainError> {
         // Step 5: Validate response quality (simplified for now)
         let response_quality = self.validate_response_quality(
             &llm_response,
             &retrieved_knowledge,
-            &request.message,
+            user_message,
             &context,
         ).await?;
 
@@ -461,26 +803,26 @@ impl RagOrchestrator {
         context.messages.push(assistant_message);
 
         // Step 7: Update conversation context
-        self.conversations.insert(conversation_id.clone(), context.clone());
+        if let Some(store) = &self.conversation_store {
+            store.save_conversation(&context).await?;
+        }
+        self.conversations.insert(conversation_id.clone(), context);
 
         // Step 8: Store the interaction in Brain's memory for future learning
         self.store_interaction_in_memory(
-            &request.message,
+            user_message,
             &llm_response,
             &retrieved_knowledge,
             memory_system,
         ).await?;
 
-        let response = RagResponse {
-            response: llm_response.clone(),
-            conversation_id: conversation_id.clone(),
-            context_used: retrieved_knowledge.clone(),
+        Ok(RagResponse {
+            response: llm_response,
+            conversation_id,
             confidence_score: response_quality.factual_grounding,
-            response_quality: response_quality.clone(),
-        };
-
-        println!("✅ RAG Orchestrator: Generated response with {} knowledge sources", response.context_used.len());
-        Ok(response)
+            context_used: retrieved_knowledge,
+            response_quality,
+        })
     }
 
     /// Retrieve relevant knowledge from Brain AI systems
@@ -628,20 +970,15 @@ impl RagOrchestrator {
         intersection as f64 / union as f64
     }
 
-    /// Generate response using external LLM (OpenAI)
-    async fn generate_with_external_llm(
+    /// Build the prompt sent to the language model
+    fn build_completion_request(
         &self,
         message: &str,
         context: &ConversationContext,
         knowledge: &[RetrievedKnowledge],
-    ) -> Result<String, BrainError> {
-        println!("🧠 Generating response with external LLM");
-        
+    ) -> CompletionRequest {
         let mut messages = vec![
-            OpenAIMessage {
-                role: "system".to_string(),
-                content: self.brain_impersonation.get_brain_system_prompt(),
-            }
+            LlmMessage::system(self.brain_impersonation.get_brain_system_prompt())
         ];
         
         // Add knowledge context
@@ -652,69 +989,44 @@ impl RagOrchestrator {
                 .collect::<Vec<_>>()
                 .join("\n");
             
-            messages.push(OpenAIMessage {
-                role: "system".to_string(),
-                content: format!("Relevant knowledge from my repository:\n{}", knowledge_context),
-            });
+            messages.push(LlmMessage::system(
+                format!("Relevant knowledge from my repository:\n{}", knowledge_context),
+            ));
         }
         
         // Add recent conversation history
         for msg in context.messages.iter().rev().take(6).rev() {
-            messages.push(OpenAIMessage {
-                role: msg.role.clone(),
-                content: msg.content.clone(),
-            });
+            messages.push(LlmMessage::new(msg.role.clone(), msg.content.clone()));
         }
         
         // Add current user message if not already included
-        if context.messages.is_empty() || context.messages.last().unwrap().content != message {
-            messages.push(OpenAIMessage {
-                role: "user".to_string(),
-                content: message.to_string(),
-            });
+        if !matches!(context.messages.last(), Some(last) if last.content == message) {
+            messages.push(LlmMessage::user(message));
         }
         
-        let request = OpenAIRequest {
-            model: self.openai_model.clone(),
+        CompletionRequest {
+            messages,
             max_tokens: Some(self.max_tokens),
             temperature: self.temperature,
-            messages,
-            stream: false,
-        };
-        
-        let response = self.client
-            .post("https://api.openai.com/v1/chat/completions")
-            .header("Authorization", format!("Bearer {}", self.openai_api_key))
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| BrainError::NetworkError(format!("OpenAI API request failed: {}", e)))?;
-        
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(BrainError::NetworkError(
-                format!("OpenAI API error: {}", error_text)
-            ));
         }
+    }
+
+    /// Generate response using the configured language model provider
+    async fn generate_with_external_llm(
+        &self,
+        message: &str,
+        context: &ConversationContext,
+        knowledge: &[RetrievedKnowledge],
+    ) -> Result<String, BrainError> {
+        println!("🧠 Generating response with {} provider", self.provider.name());
         
-        let openai_response: OpenAIResponse = response
-            .json()
-            .await
-            .map_err(|e| BrainError::ConfigError(format!("Failed to parse OpenAI response: {}", e)))?;
-        
-        let generated_response = openai_response
-            .choices
-            .first()
-            .ok_or_else(|| BrainError::ConfigError("No response from OpenAI".to_string()))?
-            .message
-            .content
-            .clone();
+        let request = self.build_completion_request(message, context, knowledge);
+        let completion = self.provider.complete(request).await?;
         
         // Process response through Brain AI impersonation
-        let processed_response = self.brain_impersonation.process_response(&generated_response);
+        let processed_response = self.brain_impersonation.process_response(&completion.content);
         
-        println!("✅ Generated response ({} chars)", processed_response.len());
+        println!("✅ Generated response ({} chars, {} tokens)", processed_response.len(), completion.usage.total_tokens);
         Ok(processed_response)
     }
 
@@ -808,7 +1120,219 @@ impl RagOrchestrator {
     }
 
     /// Clear a specific conversation
+    ///
+    /// Only the in-memory copy is removed; a stored conversation is resumed on
+    /// its next turn. Use `delete_conversation` to remove it from the store too.
     pub fn clear_conversation(&mut self, conversation_id: &str) -> bool {
         self.conversations.remove(conversation_id).is_some()
     }
-} 
\ No newline at end of file
+
+    /// Load a conversation, from memory or the conversation store
+    pub async fn get_conversation(&self, conversation_id: &str) -> Result<Option<ConversationContext>, BrainError> {
+        if let Some(context) = self.conversations.get(conversation_id) {
+            return Ok(Some(context.clone()));
+        }
+        match &self.conversation_store {
+            Some(store) => store.load_conversation(conversation_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Remove a conversation from memory and from the conversation store
+    pub async fn delete_conversation(&mut self, conversation_id: &str) -> Result<bool, BrainError> {
+        let cleared = self.conversations.remove(conversation_id).is_some();
+        let deleted = match &self.conversation_store {
+            Some(store) => store.delete_conversation(conversation_id).await?,
+            None => false,
+        };
+        Ok(cleared || deleted)
+    }
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use brain_infra::concepts::{ConceptGraphConfig, ConceptGraphManager};
+    use brain_infra::memory::{EpisodicMemoryRepository, SemanticMemoryRepository, WorkingMemoryRepository};
+
+    async fn services() -> (MemoryService, ConceptGraphService) {
+        let memory = MemoryService::new(
+            Box::new(WorkingMemoryRepository::new(100)),
+            Box::new(EpisodicMemoryRepository::new(":memory:").await.unwrap()),
+            Box::new(SemanticMemoryRepository::new()),
+        );
+        let concepts = ConceptGraphService::new(
+            Box::new(ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap()),
+            Box::new(ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap()),
+        );
+        (memory, concepts)
+    }
+
+    fn request(message: &str, conversation_id: Option<String>) -> RagRequest {
+        RagRequest {
+            message: message.to_string(),
+            conversation_id,
+            context_limit: Some(5),
+            retrieval_threshold: Some(0.1),
+            user_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn rag_pipeline_runs_offline_with_scripted_provider() {
+        let provider = Arc::new(ScriptedProvider::new([
+            "Rust ownership prevents data races. I'm ChatGPT.",
+            "Borrowing lets functions use values without taking ownership.",
+        ]));
+        let mut orchestrator = RagOrchestrator::with_provider(provider.clone());
+        let (mut memory, mut concepts) = services().await;
+
+        let first = orchestrator
+            .process_conversation(request("What is ownership?", None), &mut memory, &mut concepts)
+            .await
+            .unwrap();
+        assert_eq!(first.response, "Rust ownership prevents data races. I'm Brain AI.");
+        assert!((0.0..=1.0).contains(&first.response_quality.factual_grounding));
+
+        let second = orchestrator
+            .process_conversation(
+                request("And borrowing?", Some(first.conversation_id.clone())),
+                &mut memory,
+                &mut concepts,
+            )
+            .await
+            .unwrap();
+        assert_eq!(second.conversation_id, first.conversation_id);
+        assert_eq!(orchestrator.get_conversation_stats()["total_messages"], 4);
+
+        // The second prompt carries the system prompt and the earlier exchange
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 2);
+        let second_prompt = &requests[1].messages;
+        assert_eq!(second_prompt[0].role, "system");
+        assert!(second_prompt.iter().any(|m| m.role == "assistant"));
+        assert_eq!(second_prompt.last().unwrap(), &LlmMessage::user("And borrowing?"));
+        assert_eq!(orchestrator.token_usage(), provider.token_usage());
+    }
+
+    #[test]
+    fn stream_filter_replaces_targets_split_across_deltas() {
+        let handler = BrainImpersonationHandler::default();
+        let mut filter = handler.stream_filter();
+
+        let mut output = String::new();
+        for delta in ["Hello, I'm Chat", "GPT and I was built by Open", "AI. ", "Bye"] {
+            output.push_str(&filter.push(delta));
+        }
+        output.push_str(&filter.finish());
+
+        assert_eq!(output, "Hello, I'm Brain AI and I was built by Brain AI. Bye");
+    }
+
+    #[tokio::test]
+    async fn streamed_turn_sends_deltas_then_trailing_frames() {
+        let provider = Arc::new(ScriptedProvider::repeating("Ownership moves values between bindings."));
+        let mut orchestrator = RagOrchestrator::with_provider(provider);
+        let (mut memory, mut concepts) = services().await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let response = orchestrator
+            .process_conversation_stream(request("What is ownership?", None), &mut memory, &mut concepts, tx)
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        assert!(matches!(events.first(), Some(RagStreamEvent::Started { .. })));
+        assert!(matches!(events[events.len() - 2], RagStreamEvent::Knowledge { .. }));
+        assert!(events.last().unwrap().is_terminal());
+        assert!(events.iter().all(|e| e.conversation_id() == response.conversation_id));
+
+        let streamed: String = events
+            .iter()
+            .filter_map(|e| match e {
+                RagStreamEvent::Delta { content, .. } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(streamed, response.response);
+        assert_eq!(streamed, "Ownership moves values between bindings.");
+        assert_eq!(orchestrator.get_conversation_stats()["total_messages"], 2);
+    }
+
+    #[tokio::test]
+    async fn streamed_turn_reports_failure_as_final_frame() {
+        let provider = Arc::new(ScriptedProvider::new(Vec::<String>::new()));
+        let mut orchestrator = RagOrchestrator::with_provider(provider);
+        let (mut memory, mut concepts) = services().await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let result = orchestrator
+            .process_conversation_stream(request("Anyone there?", Some("c1".to_string())), &mut memory, &mut concepts, tx)
+            .await;
+        assert!(result.is_err());
+
+        let mut last = None;
+        while let Some(event) = rx.recv().await {
+            last = Some(event);
+        }
+        assert!(matches!(last, Some(RagStreamEvent::Failed { ref conversation_id, .. }) if conversation_id == "c1"));
+    }
+
+    #[tokio::test]
+    async fn conversations_resume_from_the_store_after_restart() {
+        let store: Arc<dyn ConversationRepository> = Arc::new(InMemoryConversationRepository::new());
+        let (mut memory, mut concepts) = services().await;
+
+        let conversation_id = {
+            let mut orchestrator = RagOrchestrator::with_provider(Arc::new(ScriptedProvider::repeating("Noted.")))
+                .with_conversation_store(store.clone());
+            let mut first = request("Remember that I prefer Rust.", None);
+            first.user_id = Some("dana".to_string());
+            orchestrator.process_conversation(first, &mut memory, &mut concepts).await.unwrap().conversation_id
+        };
+
+        let provider = Arc::new(ScriptedProvider::repeating("You prefer Rust."));
+        let mut orchestrator = RagOrchestrator::with_provider(provider.clone())
+            .with_conversation_store(store.clone());
+        let mut second = request("What do I prefer?", Some(conversation_id.clone()));
+        second.user_id = Some("dana".to_string());
+        orchestrator.process_conversation(second, &mut memory, &mut concepts).await.unwrap();
+
+        // The earlier exchange was loaded from the store into the prompt
+        let prompt = &provider.requests()[0].messages;
+        assert!(prompt.iter().any(|m| m.content == "Remember that I prefer Rust."));
+
+        let stored = store.load_conversation(&conversation_id).await.unwrap().unwrap();
+        assert_eq!(stored.messages.len(), 4);
+        assert_eq!(stored.user_profile.user_id, "dana");
+
+        assert!(orchestrator.delete_conversation(&conversation_id).await.unwrap());
+        assert!(orchestrator.get_conversation(&conversation_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn conversations_are_only_continued_by_their_owner() {
+        let store: Arc<dyn ConversationRepository> = Arc::new(InMemoryConversationRepository::new());
+        let (mut memory, mut concepts) = services().await;
+        let mut orchestrator = RagOrchestrator::with_provider(Arc::new(ScriptedProvider::repeating("Noted.")))
+            .with_conversation_store(store.clone());
+
+        let mut first = request("My deploy key is in the vault.", None);
+        first.user_id = Some("dana".to_string());
+        let conversation_id = orchestrator.process_conversation(first, &mut memory, &mut concepts).await.unwrap().conversation_id;
+
+        for intruder in [Some("eve".to_string()), None] {
+            let mut hijack = request("What did I say?", Some(conversation_id.clone()));
+            hijack.user_id = intruder;
+            let result = orchestrator.process_conversation(hijack, &mut memory, &mut concepts).await;
+            assert!(matches!(result, Err(BrainError::NotFound(_))));
+        }
+
+        let stored = store.load_conversation(&conversation_id).await.unwrap().unwrap();
+        assert_eq!(stored.messages.len(), 2);
+        assert_eq!(stored.user_profile.user_id, "dana");
+    }
+}
@@ -0,0 +1,582 @@
+//! Conversation Storage
+//!
+//! Persistence for `ConversationContext`s so conversations survive restarts and
+//! can be listed, searched and resumed. `RagOrchestrator` writes every completed
+//! turn through the `ConversationRepository` trait, with an in-memory repository
+//! for tests and a SQLite repository for durable storage.
+
+use std::collections::HashMap;
+use std::path::Path;
+use async_trait::async_trait;
+use brain_infra::database::{clamp_to_i64, column, parse_timestamp};
+use brain_infra::DatabaseManager;
+use brain_types::{BrainError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+
+use super::{ChatMessage, ConversationContext};
+
+/// Default page size for conversation and message listings
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Longest title derived from a conversation's first user message
+const TITLE_LENGTH: usize = 80;
+
+/// Listing entry for a stored conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationSummary {
+    pub conversation_id: String,
+    /// Empty for conversations without an associated user
+    pub user_id: String,
+    /// Start of the first user message
+    pub title: String,
+    pub message_count: usize,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Filter and page for conversation listings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationListQuery {
+    /// Only conversations belonging to this user
+    pub user_id: Option<String>,
+    /// Case-insensitive text that must appear in one of the messages
+    pub search: Option<String>,
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_page_size")]
+    pub limit: usize,
+}
+
+fn default_page_size() -> usize {
+    DEFAULT_PAGE_SIZE
+}
+
+impl Default for ConversationListQuery {
+    fn default() -> Self {
+        Self {
+            user_id: None,
+            search: None,
+            offset: 0,
+            limit: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+/// One page of conversations, most recently updated first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationPage {
+    pub conversations: Vec<ConversationSummary>,
+    /// Number of conversations matching the query across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Storage for conversation contexts
+#[async_trait]
+pub trait ConversationRepository: Send + Sync {
+    /// Insert or replace a conversation, including all of its messages
+    async fn save_conversation(&self, context: &ConversationContext) -> Result<()>;
+
+    async fn load_conversation(&self, conversation_id: &str) -> Result<Option<ConversationContext>>;
+
+    /// Delete a conversation, returning false if it did not exist
+    async fn delete_conversation(&self, conversation_id: &str) -> Result<bool>;
+
+    async fn list_conversations(&self, query: &ConversationListQuery) -> Result<ConversationPage>;
+
+    /// Messages of a conversation in order, starting at `offset`
+    async fn list_messages(&self, conversation_id: &str, offset: usize, limit: usize) -> Result<Vec<ChatMessage>>;
+
+    /// Total number of stored conversations and messages
+    async fn counts(&self) -> Result<(usize, usize)>;
+}
+
+fn conversation_title(context: &ConversationContext) -> String {
+    context.messages
+        .iter()
+        .find(|message| message.role == "user")
+        .map(|message| message.content.chars().take(TITLE_LENGTH).collect())
+        .unwrap_or_default()
+}
+
+fn conversation_created_at(context: &ConversationContext) -> DateTime<Utc> {
+    context.messages.first().map(|message| message.timestamp).unwrap_or_else(Utc::now)
+}
+
+struct StoredConversation {
+    context: ConversationContext,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl StoredConversation {
+    fn summary(&self) -> ConversationSummary {
+        ConversationSummary {
+            conversation_id: self.context.conversation_id.clone(),
+            user_id: self.context.user_profile.user_id.clone(),
+            title: conversation_title(&self.context),
+            message_count: self.context.messages.len(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Process-local repository; contents are lost when it is dropped
+#[derive(Default)]
+pub struct InMemoryConversationRepository {
+    conversations: RwLock<HashMap<String, StoredConversation>>,
+}
+
+impl InMemoryConversationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ConversationRepository for InMemoryConversationRepository {
+    async fn save_conversation(&self, context: &ConversationContext) -> Result<()> {
+        let mut conversations = self.conversations.write().await;
+        let created_at = conversations
+            .get(&context.conversation_id)
+            .map(|stored| stored.created_at)
+            .unwrap_or_else(|| conversation_created_at(context));
+        conversations.insert(context.conversation_id.clone(), StoredConversation {
+            context: context.clone(),
+            created_at,
+            updated_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    async fn load_conversation(&self, conversation_id: &str) -> Result<Option<ConversationContext>> {
+        Ok(self.conversations.read().await.get(conversation_id).map(|stored| stored.context.clone()))
+    }
+
+    async fn delete_conversation(&self, conversation_id: &str) -> Result<bool> {
+        Ok(self.conversations.write().await.remove(conversation_id).is_some())
+    }
+
+    async fn list_conversations(&self, query: &ConversationListQuery) -> Result<ConversationPage> {
+        let search = query.search.as_ref().map(|search| search.to_lowercase());
+        let conversations = self.conversations.read().await;
+
+        let mut matching: Vec<&StoredConversation> = conversations.values()
+            .filter(|stored| query.user_id.as_ref().is_none_or(|user_id| &stored.context.user_profile.user_id == user_id))
+            .filter(|stored| search.as_ref().is_none_or(|search| {
+                stored.context.messages.iter().any(|message| message.content.to_lowercase().contains(search))
+            }))
+            .collect();
+        matching.sort_by(|a, b| {
+            b.updated_at.cmp(&a.updated_at)
+                .then_with(|| a.context.conversation_id.cmp(&b.context.conversation_id))
+        });
+
+        Ok(ConversationPage {
+            total: matching.len(),
+            conversations: matching.iter()
+                .skip(query.offset)
+                .take(query.limit)
+                .map(|stored| stored.summary())
+                .collect(),
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn list_messages(&self, conversation_id: &str, offset: usize, limit: usize) -> Result<Vec<ChatMessage>> {
+        Ok(self.conversations.read().await
+            .get(conversation_id)
+            .map(|stored| stored.context.messages.iter().skip(offset).take(limit).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn counts(&self) -> Result<(usize, usize)> {
+        let conversations = self.conversations.read().await;
+        let messages = conversations.values().map(|stored| stored.context.messages.len()).sum();
+        Ok((conversations.len(), messages))
+    }
+}
+
+const SUMMARY_COLUMNS: &str = "c.id, c.user_id, c.title, c.created_at, c.updated_at, \
+    (SELECT COUNT(*) FROM conversation_messages m WHERE m.conversation_id = c.id) AS message_count";
+
+/// SQLite repository on the `conversations` and `conversation_messages` tables
+///
+/// Messages get a row each so they can be paged and searched; retrieved
+/// knowledge, threads, the user profile and the temporal context are stored as
+/// JSON on the conversation row.
+pub struct SqliteConversationRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteConversationRepository {
+    /// Open the repository on an existing database, creating its tables if needed
+    pub async fn new(database: &DatabaseManager) -> Result<Self> {
+        let repository = Self { pool: database.pool().clone() };
+        repository.initialize_schema().await?;
+        Ok(repository)
+    }
+
+    /// Open the repository on a database file, creating the file if it does not exist
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let database = DatabaseManager::new_file(path).await?;
+        Self::new(&database).await
+    }
+
+    async fn initialize_schema(&self) -> Result<()> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS conversations (
+                id TEXT PRIMARY KEY,
+                user_id TEXT NOT NULL,
+                title TEXT NOT NULL,
+                context_summary TEXT NOT NULL,
+                user_preferences TEXT NOT NULL,
+                retrieved_knowledge TEXT NOT NULL,
+                conversation_threads TEXT NOT NULL,
+                user_profile TEXT NOT NULL,
+                temporal_context TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create conversations table: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS conversation_messages (
+                conversation_id TEXT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+                position INTEGER NOT NULL,
+                id TEXT NOT NULL,
+                role TEXT NOT NULL,
+                content TEXT NOT NULL,
+                timestamp TEXT NOT NULL,
+                PRIMARY KEY (conversation_id, position)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create conversation_messages table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_conversations_user ON conversations(user_id, updated_at)")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to create conversations index: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConversationRepository for SqliteConversationRepository {
+    async fn save_conversation(&self, context: &ConversationContext) -> Result<()> {
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to begin transaction: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO conversations
+                (id, user_id, title, context_summary, user_preferences, retrieved_knowledge,
+                 conversation_threads, user_profile, temporal_context, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+             ON CONFLICT(id) DO UPDATE SET
+                user_id = excluded.user_id, title = excluded.title,
+                context_summary = excluded.context_summary, user_preferences = excluded.user_preferences,
+                retrieved_knowledge = excluded.retrieved_knowledge,
+                conversation_threads = excluded.conversation_threads,
+                user_profile = excluded.user_profile, temporal_context = excluded.temporal_context,
+                updated_at = excluded.updated_at",
+        )
+        .bind(&context.conversation_id)
+        .bind(&context.user_profile.user_id)
+        .bind(conversation_title(context))
+        .bind(&context.context_summary)
+        .bind(serde_json::to_string(&context.user_preferences)?)
+        .bind(serde_json::to_string(&context.retrieved_knowledge)?)
+        .bind(serde_json::to_string(&context.conversation_threads)?)
+        .bind(serde_json::to_string(&context.user_profile)?)
+        .bind(serde_json::to_string(&context.temporal_context)?)
+        .bind(conversation_created_at(context).to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to save conversation: {}", e)))?;
+
+        sqlx::query("DELETE FROM conversation_messages WHERE conversation_id = ?1 AND position >= ?2")
+            .bind(&context.conversation_id)
+            .bind(context.messages.len() as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to trim conversation messages: {}", e)))?;
+
+        for (position, message) in context.messages.iter().enumerate() {
+            sqlx::query(
+                "INSERT OR REPLACE INTO conversation_messages (conversation_id, position, id, role, content, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )
+            .bind(&context.conversation_id)
+            .bind(position as i64)
+            .bind(&message.id)
+            .bind(&message.role)
+            .bind(&message.content)
+            .bind(message.timestamp.to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to save conversation message: {}", e)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to commit conversation: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn load_conversation(&self, conversation_id: &str) -> Result<Option<ConversationContext>> {
+        let row = sqlx::query("SELECT * FROM conversations WHERE id = ?1")
+            .bind(conversation_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to load conversation: {}", e)))?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let messages = self.list_messages(conversation_id, 0, usize::MAX).await?;
+        Ok(Some(ConversationContext {
+            conversation_id: column(&row, "id")?,
+            messages,
+            retrieved_knowledge: json_column(&row, "retrieved_knowledge")?,
+            context_summary: column(&row, "context_summary")?,
+            user_preferences: json_column(&row, "user_preferences")?,
+            conversation_threads: json_column(&row, "conversation_threads")?,
+            user_profile: json_column(&row, "user_profile")?,
+            temporal_context: json_column(&row, "temporal_context")?,
+        }))
+    }
+
+    async fn delete_conversation(&self, conversation_id: &str) -> Result<bool> {
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to begin transaction: {}", e)))?;
+
+        sqlx::query("DELETE FROM conversation_messages WHERE conversation_id = ?1")
+            .bind(conversation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete conversation messages: {}", e)))?;
+        let deleted = sqlx::query("DELETE FROM conversations WHERE id = ?1")
+            .bind(conversation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete conversation: {}", e)))?
+            .rows_affected();
+
+        tx.commit()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to commit conversation deletion: {}", e)))?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn list_conversations(&self, query: &ConversationListQuery) -> Result<ConversationPage> {
+        let pattern = query.search.as_ref().map(|search| format!("%{}%", escape_like(search)));
+        let filter = "WHERE (?1 IS NULL OR c.user_id = ?1)
+              AND (?2 IS NULL OR EXISTS (
+                  SELECT 1 FROM conversation_messages s
+                  WHERE s.conversation_id = c.id AND s.content LIKE ?2 ESCAPE '\\'))";
+
+        let total: i64 = sqlx::query(&format!("SELECT COUNT(*) AS total FROM conversations c {}", filter))
+            .bind(&query.user_id)
+            .bind(&pattern)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to count conversations: {}", e)))
+            .and_then(|row| column(&row, "total"))?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM conversations c {} ORDER BY c.updated_at DESC, c.id LIMIT ?3 OFFSET ?4",
+            SUMMARY_COLUMNS, filter,
+        ))
+        .bind(&query.user_id)
+        .bind(&pattern)
+        .bind(clamp_to_i64(query.limit))
+        .bind(clamp_to_i64(query.offset))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to list conversations: {}", e)))?;
+
+        Ok(ConversationPage {
+            conversations: rows.iter().map(row_to_summary).collect::<Result<Vec<_>>>()?,
+            total: total as usize,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn list_messages(&self, conversation_id: &str, offset: usize, limit: usize) -> Result<Vec<ChatMessage>> {
+        let rows = sqlx::query(
+            "SELECT id, role, content, timestamp FROM conversation_messages
+             WHERE conversation_id = ?1 ORDER BY position LIMIT ?2 OFFSET ?3",
+        )
+        .bind(conversation_id)
+        .bind(clamp_to_i64(limit))
+        .bind(clamp_to_i64(offset))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to list conversation messages: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ChatMessage {
+                    id: column(row, "id")?,
+                    role: column(row, "role")?,
+                    content: column(row, "content")?,
+                    timestamp: parse_timestamp(&column::<String>(row, "timestamp")?)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn counts(&self) -> Result<(usize, usize)> {
+        let row = sqlx::query(
+            "SELECT (SELECT COUNT(*) FROM conversations) AS conversations,
+                    (SELECT COUNT(*) FROM conversation_messages) AS messages",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to count conversations: {}", e)))?;
+
+        Ok((
+            column::<i64>(&row, "conversations")? as usize,
+            column::<i64>(&row, "messages")? as usize,
+        ))
+    }
+}
+
+fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+fn json_column<T: serde::de::DeserializeOwned>(row: &SqliteRow, name: &str) -> Result<T> {
+    Ok(serde_json::from_str(&column::<String>(row, name)?)?)
+}
+
+fn row_to_summary(row: &SqliteRow) -> Result<ConversationSummary> {
+    Ok(ConversationSummary {
+        conversation_id: column(row, "id")?,
+        user_id: column(row, "user_id")?,
+        title: column(row, "title")?,
+        message_count: column::<i64>(row, "message_count")? as usize,
+        created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+        updated_at: parse_timestamp(&column::<String>(row, "updated_at")?)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversation(id: &str, user_id: &str, messages: &[(&str, &str)]) -> ConversationContext {
+        let mut context = ConversationContext::new(id.to_string());
+        context.user_profile.user_id = user_id.to_string();
+        context.context_summary = format!("summary of {}", id);
+        context.user_preferences.insert("tone".to_string(), "casual".to_string());
+        for (role, content) in messages {
+            context.messages.push(ChatMessage {
+                role: role.to_string(),
+                content: content.to_string(),
+                timestamp: Utc::now(),
+                id: uuid::Uuid::new_v4().to_string(),
+            });
+        }
+        context
+    }
+
+    async fn exercise(repository: impl ConversationRepository) {
+        repository.save_conversation(&conversation("a", "alice", &[
+            ("user", "How do lifetimes work?"),
+            ("assistant", "Lifetimes name the scope a reference is valid for."),
+        ])).await.unwrap();
+        repository.save_conversation(&conversation("b", "bob", &[("user", "Explain 100% of traits")])).await.unwrap();
+        repository.save_conversation(&conversation("c", "alice", &[("user", "What about async?")])).await.unwrap();
+
+        let loaded = repository.load_conversation("a").await.unwrap().unwrap();
+        assert_eq!(loaded.messages.len(), 2);
+        assert_eq!(loaded.messages[1].content, "Lifetimes name the scope a reference is valid for.");
+        assert_eq!(loaded.user_profile.user_id, "alice");
+        assert_eq!(loaded.user_preferences["tone"], "casual");
+        assert!(repository.load_conversation("missing").await.unwrap().is_none());
+
+        let alice = repository.list_conversations(&ConversationListQuery {
+            user_id: Some("alice".to_string()),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(alice.total, 2);
+        assert!(alice.conversations.iter().all(|summary| summary.user_id == "alice"));
+
+        let search = repository.list_conversations(&ConversationListQuery {
+            search: Some("LIFETIMES".to_string()),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(search.total, 1);
+        assert_eq!(search.conversations[0].title, "How do lifetimes work?");
+        assert_eq!(search.conversations[0].message_count, 2);
+
+        let literal = repository.list_conversations(&ConversationListQuery {
+            search: Some("100%".to_string()),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(literal.total, 1);
+
+        let page = repository.list_conversations(&ConversationListQuery {
+            offset: 1,
+            limit: 1,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(page.total, 3);
+        assert_eq!(page.conversations.len(), 1);
+
+        let messages = repository.list_messages("a", 1, 10).await.unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].role, "assistant");
+
+        // Saving a shorter context drops the messages that are no longer part of it
+        repository.save_conversation(&conversation("a", "alice", &[("user", "Start over")])).await.unwrap();
+        assert_eq!(repository.list_messages("a", 0, 10).await.unwrap().len(), 1);
+
+        assert!(repository.delete_conversation("b").await.unwrap());
+        assert!(!repository.delete_conversation("b").await.unwrap());
+        assert_eq!(repository.counts().await.unwrap(), (2, 2));
+    }
+
+    async fn persist(repository: SqliteConversationRepository) {
+        repository.save_conversation(&conversation("resume", "carol", &[
+            ("user", "Remind me tomorrow"),
+            ("assistant", "Sure."),
+        ])).await.unwrap();
+    }
+
+    async fn verify(repository: SqliteConversationRepository, _: ()) {
+        let loaded = repository.load_conversation("resume").await.unwrap().unwrap();
+        assert_eq!(loaded.messages.len(), 2);
+        assert_eq!(loaded.context_summary, "summary of resume");
+    }
+
+    brain_infra::backend_conformance_tests! {
+        in_memory: exercise => InMemoryConversationRepository::new();
+        sqlite: exercise => SqliteConversationRepository::new;
+        reopen: SqliteConversationRepository::open => persist, verify;
+    }
+}
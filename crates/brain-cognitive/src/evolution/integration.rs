@@ -12,6 +12,7 @@ use crate::{
     meta::MetaMemoryRepository,
 };
 use crate::evolution::BrainResult;
+use crate::evolution::parameter_search::{self, SearchProblem, SearchRng};
 use brain_types::error::BrainError;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -497,7 +498,7 @@ pub struct AppliedOptimization {
     /// Type of optimization
     pub optimization_type: String,
     
-    /// Parameters changed
+    /// New value of each changed parameter
     pub parameter_changes: HashMap<String, f32>,
     
     /// Expected impact
@@ -946,15 +947,22 @@ impl AutomatedParameterOptimizer {
         performance_data: &[AgentPerformanceMetrics],
         learning_results: &HashMap<String, LearningCycleResult>,
     ) -> BrainResult<Option<AppliedOptimization>> {
-        // Select best strategy for this opportunity
-        let strategy = self.select_optimization_strategy(opportunity).await?;
-        
-        // Apply the strategy
-        let optimization_result = strategy.optimize(
-            opportunity,
-            performance_data,
-            learning_results,
-        ).await?;
+        let (strategy_name, optimization_result) = {
+            let parameter_history = self.parameter_history.read().await;
+            
+            // Select best strategy for this opportunity
+            let problem = SearchProblem::build(opportunity, performance_data, learning_results, &parameter_history);
+            let strategy = self.select_optimization_strategy(opportunity, &problem).await?;
+            
+            // Apply the strategy
+            let result = strategy.optimize(
+                opportunity,
+                performance_data,
+                learning_results,
+                &parameter_history,
+            ).await?;
+            (strategy.strategy_name().to_string(), result)
+        };
         
         if optimization_result.expected_impact >= self.improvement_threshold {
             // Record the optimization
@@ -962,8 +970,11 @@ impl AutomatedParameterOptimizer {
             
             Ok(Some(AppliedOptimization {
                 target: opportunity.opportunity_id.clone(),
-                optimization_type: strategy.strategy_name().to_string(),
-                parameter_changes: optimization_result.parameter_changes,
+                optimization_type: strategy_name,
+                parameter_changes: optimization_result.parameter_changes
+                    .iter()
+                    .map(|(name, change)| (name.clone(), change.new_value))
+                    .collect(),
                 expected_impact: optimization_result.expected_impact,
             }))
         } else {
@@ -972,15 +983,37 @@ impl AutomatedParameterOptimizer {
     }
     
     /// Select the best optimization strategy for an opportunity
+    ///
+    /// Sparse histories call for Bayesian optimization, which explores untried
+    /// settings deliberately. Risky opportunities get small gradient steps, and
+    /// well-explored spaces with several parameters suit the genetic search.
+    /// Annealing covers the rest. Falls back to the first configured strategy
+    /// when the preferred one is not available.
     async fn select_optimization_strategy(
         &self,
-        _opportunity: &OptimizationOpportunity,
+        opportunity: &OptimizationOpportunity,
+        problem: &SearchProblem,
     ) -> BrainResult<&OptimizationStrategyEnum> {
-        // For now, return the first strategy
-        // In a full implementation, this would select based on opportunity characteristics
-        Ok(self.strategies.first().ok_or_else(|| {
-            BrainError::from(anyhow::anyhow!("No optimization strategies available"))
-        })?)
+        let distinct_settings = problem.distinct_settings();
+        let preferred = |strategy: &OptimizationStrategyEnum| match strategy {
+            OptimizationStrategyEnum::Bayesian(_) => distinct_settings < 3,
+            OptimizationStrategyEnum::GradientDescent(_) => opportunity.resources_required.risk_level > 0.5,
+            OptimizationStrategyEnum::GeneticAlgorithm(_) => problem.dimensions() >= 3 && distinct_settings >= 10,
+            OptimizationStrategyEnum::SimulatedAnnealing(_) => true,
+        };
+        let rank = |strategy: &OptimizationStrategyEnum| match strategy {
+            OptimizationStrategyEnum::Bayesian(_) => 0,
+            OptimizationStrategyEnum::GradientDescent(_) => 1,
+            OptimizationStrategyEnum::GeneticAlgorithm(_) => 2,
+            OptimizationStrategyEnum::SimulatedAnnealing(_) => 3,
+        };
+        
+        self.strategies
+            .iter()
+            .filter(|strategy| preferred(strategy))
+            .min_by_key(|strategy| rank(strategy))
+            .or_else(|| self.strategies.first())
+            .ok_or_else(|| BrainError::from(anyhow::anyhow!("No optimization strategies available")))
     }
     
     /// Record an optimization for tracking
@@ -988,17 +1021,8 @@ impl AutomatedParameterOptimizer {
         // Record in parameter history
         let mut history = self.parameter_history.write().await;
         
-        for (param_name, new_value) in &result.parameter_changes {
-            let change = ParameterChange {
-                timestamp: Utc::now(),
-                old_value: 0.0, // Would be retrieved from current config
-                new_value: *new_value,
-                reason: result.optimization_reason.clone(),
-                expected_impact: result.expected_impact,
-                actual_impact: None, // Will be filled in later
-            };
-            
-            history.entry(param_name.clone()).or_default().push(change);
+        for (param_name, change) in &result.parameter_changes {
+            history.entry(param_name.clone()).or_default().push(change.clone());
         }
         
         Ok(())
@@ -1093,8 +1117,8 @@ pub struct ResourceEstimate {
 /// Result of applying an optimization strategy
 #[derive(Debug, Clone)]
 pub struct OptimizationResult {
-    /// Parameters that were changed
-    pub parameter_changes: HashMap<String, f32>,
+    /// Proposed change for each parameter the strategy would modify
+    pub parameter_changes: HashMap<String, ParameterChange>,
     
     /// Expected impact of the optimization
     pub expected_impact: f32,
@@ -1126,6 +1150,9 @@ pub struct ParameterChange {
     
     /// Actual impact (measured later)
     pub actual_impact: Option<f32>,
+    
+    /// Standard deviation of the new value, in the parameter's units
+    pub uncertainty: f32,
 }
 
 /// Optimization experiment tracking
@@ -1244,19 +1271,20 @@ impl OptimizationStrategyEnum {
         opportunity: &OptimizationOpportunity,
         performance_data: &[AgentPerformanceMetrics],
         learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult> {
         match self {
             OptimizationStrategyEnum::GradientDescent(strategy) => {
-                strategy.optimize(opportunity, performance_data, learning_results).await
+                strategy.optimize(opportunity, performance_data, learning_results, parameter_history).await
             }
             OptimizationStrategyEnum::Bayesian(strategy) => {
-                strategy.optimize(opportunity, performance_data, learning_results).await
+                strategy.optimize(opportunity, performance_data, learning_results, parameter_history).await
             }
             OptimizationStrategyEnum::GeneticAlgorithm(strategy) => {
-                strategy.optimize(opportunity, performance_data, learning_results).await
+                strategy.optimize(opportunity, performance_data, learning_results, parameter_history).await
             }
             OptimizationStrategyEnum::SimulatedAnnealing(strategy) => {
-                strategy.optimize(opportunity, performance_data, learning_results).await
+                strategy.optimize(opportunity, performance_data, learning_results, parameter_history).await
             }
         }
     }
@@ -1285,11 +1313,15 @@ impl OptimizationStrategyEnum {
 #[allow(async_fn_in_trait)]
 pub trait OptimizationStrategy: Send + Sync {
     /// Apply optimization to an opportunity
+    ///
+    /// `parameter_history` holds the changes applied so far, which tell the
+    /// strategy what values were in effect when each performance sample was taken.
     async fn optimize(
         &self,
         opportunity: &OptimizationOpportunity,
         performance_data: &[AgentPerformanceMetrics],
         learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult>;
     
     /// Get strategy name
@@ -1300,12 +1332,24 @@ pub trait OptimizationStrategy: Send + Sync {
 }
 
 /// Gradient descent optimizer
+///
+/// Follows the gradient of the surrogate's predicted objective from the current
+/// parameters. Small, steady steps make it the choice for risky opportunities.
 #[derive(Debug, Clone)]
-pub struct GradientDescentOptimizer;
+pub struct GradientDescentOptimizer {
+    /// Step size in normalized parameter units per unit of gradient
+    pub learning_rate: f64,
+    
+    /// Maximum number of steps
+    pub iterations: usize,
+}
 
 impl GradientDescentOptimizer {
     pub fn new() -> Self {
-        Self
+        Self {
+            learning_rate: 0.5,
+            iterations: 20,
+        }
     }
 }
 
@@ -1313,16 +1357,43 @@ impl OptimizationStrategy for GradientDescentOptimizer {
     async fn optimize(
         &self,
         opportunity: &OptimizationOpportunity,
-        _performance_data: &[AgentPerformanceMetrics],
-        _learning_results: &HashMap<String, LearningCycleResult>,
+        performance_data: &[AgentPerformanceMetrics],
+        learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult> {
-        // Implement gradient descent optimization
-        Ok(OptimizationResult {
-            parameter_changes: HashMap::new(),
-            expected_impact: opportunity.potential_improvement * 0.8,
-            confidence: 0.7,
-            optimization_reason: "Gradient descent optimization".to_string(),
-        })
+        let problem = SearchProblem::build(opportunity, performance_data, learning_results, parameter_history);
+        let surrogate = problem.surrogate();
+        
+        let mut point = problem.current.clone();
+        for _ in 0..self.iterations {
+            let gradient = surrogate.mean_gradient(&point);
+            let previous = point.clone();
+            for (x, slope) in point.iter_mut().zip(&gradient) {
+                *x += self.learning_rate * slope;
+            }
+            problem.constrain(&mut point);
+            
+            let moved: f64 = point.iter().zip(&previous).map(|(a, b)| (a - b).abs()).sum();
+            if moved < 1e-4 {
+                break;
+            }
+        }
+        
+        // A parameter is only as certain as the objective's sensitivity to it
+        let (_, deviation) = surrogate.predict(&point);
+        let gradient = surrogate.mean_gradient(&point);
+        let spread: Vec<f64> = gradient
+            .iter()
+            .map(|slope| (deviation / slope.abs().max(1e-9)).min(problem.trust_radius))
+            .collect();
+        
+        Ok(problem.result(
+            opportunity,
+            &surrogate,
+            &point,
+            &spread,
+            format!("Gradient ascent over {} performance samples", problem.observations.len()),
+        ))
     }
     
     fn strategy_name(&self) -> &str {
@@ -1335,12 +1406,25 @@ impl OptimizationStrategy for GradientDescentOptimizer {
 }
 
 /// Bayesian optimizer
+///
+/// Proposes the trust-region candidate with the highest expected improvement
+/// under the surrogate, which balances exploiting good regions against
+/// exploring settings that have never been tried.
 #[derive(Debug, Clone)]
-pub struct BayesianOptimizer;
+pub struct BayesianOptimizer {
+    /// Random candidates scored per optimization
+    pub candidates: usize,
+    
+    /// Minimum objective gain worth exploring for
+    pub exploration: f64,
+}
 
 impl BayesianOptimizer {
     pub fn new() -> Self {
-        Self
+        Self {
+            candidates: 256,
+            exploration: 0.01,
+        }
     }
 }
 
@@ -1348,16 +1432,58 @@ impl OptimizationStrategy for BayesianOptimizer {
     async fn optimize(
         &self,
         opportunity: &OptimizationOpportunity,
-        _performance_data: &[AgentPerformanceMetrics],
-        _learning_results: &HashMap<String, LearningCycleResult>,
+        performance_data: &[AgentPerformanceMetrics],
+        learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult> {
-        // Implement Bayesian optimization
-        Ok(OptimizationResult {
-            parameter_changes: HashMap::new(),
-            expected_impact: opportunity.potential_improvement * 0.9,
-            confidence: 0.8,
-            optimization_reason: "Bayesian optimization".to_string(),
-        })
+        let problem = SearchProblem::build(opportunity, performance_data, learning_results, parameter_history);
+        let surrogate = problem.surrogate();
+        let mut rng = SearchRng::new(problem.seed);
+        
+        // Best predicted objective among settings already tried
+        let incumbent = problem.observations
+            .iter()
+            .map(|observation| surrogate.predict(&observation.point).0)
+            .fold(surrogate.predict(&problem.current).0, f64::max);
+        
+        let mut candidates = vec![problem.current.clone()];
+        candidates.extend((0..self.candidates).map(|_| problem.random_point(&mut rng)));
+        let scores: Vec<f64> = candidates
+            .iter()
+            .map(|candidate| {
+                let (mean, deviation) = surrogate.predict(candidate);
+                parameter_search::expected_improvement(mean, deviation, incumbent, self.exploration)
+            })
+            .collect();
+        
+        let (best, best_score) = scores
+            .iter()
+            .enumerate()
+            .fold((0, f64::MIN), |best, (i, score)| if *score > best.1 { (i, *score) } else { best });
+        
+        let (point, spread) = if best_score > 0.0 {
+            let promising: Vec<Vec<f64>> = candidates
+                .iter()
+                .zip(&scores)
+                .filter(|(_, score)| **score >= 0.5 * best_score)
+                .map(|(candidate, _)| candidate.clone())
+                .collect();
+            (candidates[best].clone(), parameter_search::spread(&promising, problem.dimensions()))
+        } else {
+            (problem.current.clone(), vec![0.0; problem.dimensions()])
+        };
+        
+        Ok(problem.result(
+            opportunity,
+            &surrogate,
+            &point,
+            &spread,
+            format!(
+                "Bayesian optimization over {} performance samples (expected improvement {:.4})",
+                problem.observations.len(),
+                best_score.max(0.0)
+            ),
+        ))
     }
     
     fn strategy_name(&self) -> &str {
@@ -1370,12 +1496,49 @@ impl OptimizationStrategy for BayesianOptimizer {
 }
 
 /// Genetic algorithm optimizer
+///
+/// Evolves a population of parameter settings scored by the surrogate's upper
+/// confidence bound, suited to searching several parameters at once when the
+/// history already covers many settings.
 #[derive(Debug, Clone)]
-pub struct GeneticAlgorithmOptimizer;
+pub struct GeneticAlgorithmOptimizer {
+    /// Settings per generation
+    pub population_size: usize,
+    
+    /// Number of generations
+    pub generations: usize,
+    
+    /// Probability that a gene is mutated
+    pub mutation_rate: f64,
+    
+    /// Probability that a child blends two parents rather than copying one
+    pub crossover_rate: f64,
+    
+    /// Weight of predicted uncertainty in fitness
+    pub exploration_weight: f64,
+}
 
 impl GeneticAlgorithmOptimizer {
     pub fn new() -> Self {
-        Self
+        Self {
+            population_size: 32,
+            generations: 40,
+            mutation_rate: 0.2,
+            crossover_rate: 0.8,
+            exploration_weight: 0.5,
+        }
+    }
+    
+    /// Fittest of three random members
+    fn tournament<'a>(&self, population: &'a [(Vec<f64>, f64)], rng: &mut SearchRng) -> &'a [f64] {
+        let mut winner = &population[rng.below(population.len())];
+        for _ in 1..3 {
+            let contender = &population[rng.below(population.len())];
+            if contender.1 > winner.1 {
+                winner = contender;
+            }
+        }
+        &winner.0
     }
 }
 
@@ -1383,16 +1546,87 @@ impl OptimizationStrategy for GeneticAlgorithmOptimizer {
     async fn optimize(
         &self,
         opportunity: &OptimizationOpportunity,
-        _performance_data: &[AgentPerformanceMetrics],
-        _learning_results: &HashMap<String, LearningCycleResult>,
+        performance_data: &[AgentPerformanceMetrics],
+        learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult> {
-        // Implement genetic algorithm optimization
-        Ok(OptimizationResult {
-            parameter_changes: HashMap::new(),
-            expected_impact: opportunity.potential_improvement * 0.7,
-            confidence: 0.6,
-            optimization_reason: "Genetic algorithm optimization".to_string(),
-        })
+        let problem = SearchProblem::build(opportunity, performance_data, learning_results, parameter_history);
+        let surrogate = problem.surrogate();
+        let mut rng = SearchRng::new(problem.seed);
+        let population_size = self.population_size.max(4);
+        let fitness = |point: &[f64]| {
+            let (mean, deviation) = surrogate.predict(point);
+            mean + self.exploration_weight * deviation
+        };
+        let by_fitness = |a: &(Vec<f64>, f64), b: &(Vec<f64>, f64)| {
+            b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal)
+        };
+        
+        let mut population: Vec<(Vec<f64>, f64)> = std::iter::once(problem.current.clone())
+            .chain((1..population_size).map(|_| problem.random_point(&mut rng)))
+            .map(|point| {
+                let score = fitness(&point);
+                (point, score)
+            })
+            .collect();
+        population.sort_by(by_fitness);
+        
+        for _ in 0..self.generations {
+            // The two fittest settings survive unchanged
+            let mut next: Vec<(Vec<f64>, f64)> = population.iter().take(2).cloned().collect();
+            while next.len() < population_size {
+                let first = self.tournament(&population, &mut rng);
+                let mut child: Vec<f64> = if rng.next_f64() < self.crossover_rate {
+                    let second = self.tournament(&population, &mut rng);
+                    first
+                        .iter()
+                        .zip(second)
+                        .map(|(a, b)| {
+                            let blend = rng.next_f64() * 1.5 - 0.25;
+                            a + blend * (b - a)
+                        })
+                        .collect()
+                } else {
+                    first.to_vec()
+                };
+                for gene in child.iter_mut() {
+                    if rng.next_f64() < self.mutation_rate {
+                        *gene += rng.normal() * 0.25 * problem.trust_radius;
+                    }
+                }
+                problem.constrain(&mut child);
+                let score = fitness(&child);
+                next.push((child, score));
+            }
+            next.sort_by(by_fitness);
+            population = next;
+        }
+        
+        // Fitness rewards exploration; the proposal goes to the best predicted objective
+        let elite: Vec<Vec<f64>> = population
+            .iter()
+            .take((population_size / 4).max(2))
+            .map(|(point, _)| point.clone())
+            .collect();
+        let point = elite
+            .iter()
+            .map(|point| (point, surrogate.predict(point).0))
+            .fold((&problem.current, f64::MIN), |best, (point, mean)| if mean > best.1 { (point, mean) } else { best })
+            .0
+            .clone();
+        let spread = parameter_search::spread(&elite, problem.dimensions());
+        
+        Ok(problem.result(
+            opportunity,
+            &surrogate,
+            &point,
+            &spread,
+            format!(
+                "Genetic search of {} generations over {} performance samples",
+                self.generations,
+                problem.observations.len()
+            ),
+        ))
     }
     
     fn strategy_name(&self) -> &str {
@@ -1405,12 +1639,33 @@ impl OptimizationStrategy for GeneticAlgorithmOptimizer {
 }
 
 /// Simulated annealing optimizer
+///
+/// Random walk through the trust region that accepts worse settings with a
+/// probability that falls as it cools, so it can leave local optima. Energy
+/// penalizes predicted uncertainty, keeping it away from untested settings.
 #[derive(Debug, Clone)]
-pub struct SimulatedAnnealingOptimizer;
+pub struct SimulatedAnnealingOptimizer {
+    /// Number of proposed moves
+    pub iterations: usize,
+    
+    /// Starting temperature in objective units
+    pub initial_temperature: f64,
+    
+    /// Temperature multiplier per move
+    pub cooling_rate: f64,
+    
+    /// Standard deviations of predicted objective traded for certainty
+    pub risk_aversion: f64,
+}
 
 impl SimulatedAnnealingOptimizer {
     pub fn new() -> Self {
-        Self
+        Self {
+            iterations: 400,
+            initial_temperature: 0.05,
+            cooling_rate: 0.99,
+            risk_aversion: 1.0,
+        }
     }
 }
 
@@ -1418,16 +1673,60 @@ impl OptimizationStrategy for SimulatedAnnealingOptimizer {
     async fn optimize(
         &self,
         opportunity: &OptimizationOpportunity,
-        _performance_data: &[AgentPerformanceMetrics],
-        _learning_results: &HashMap<String, LearningCycleResult>,
+        performance_data: &[AgentPerformanceMetrics],
+        learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
     ) -> BrainResult<OptimizationResult> {
-        // Implement simulated annealing optimization
-        Ok(OptimizationResult {
-            parameter_changes: HashMap::new(),
-            expected_impact: opportunity.potential_improvement * 0.6,
-            confidence: 0.65,
-            optimization_reason: "Simulated annealing optimization".to_string(),
-        })
+        let problem = SearchProblem::build(opportunity, performance_data, learning_results, parameter_history);
+        let surrogate = problem.surrogate();
+        let mut rng = SearchRng::new(problem.seed);
+        let energy = |point: &[f64]| {
+            let (mean, deviation) = surrogate.predict(point);
+            -(mean - self.risk_aversion * deviation)
+        };
+        
+        let mut state = problem.current.clone();
+        let mut state_energy = energy(&state);
+        let mut best = (state.clone(), state_energy);
+        let mut temperature = self.initial_temperature;
+        let settling_from = self.iterations - self.iterations / 5;
+        let mut settled = Vec::new();
+        
+        for iteration in 0..self.iterations {
+            let mut candidate: Vec<f64> = state
+                .iter()
+                .map(|x| x + rng.normal() * 0.2 * problem.trust_radius)
+                .collect();
+            problem.constrain(&mut candidate);
+            let candidate_energy = energy(&candidate);
+            
+            let delta = candidate_energy - state_energy;
+            if delta <= 0.0 || rng.next_f64() < (-delta / temperature.max(1e-12)).exp() {
+                state = candidate;
+                state_energy = candidate_energy;
+                if state_energy < best.1 {
+                    best = (state.clone(), state_energy);
+                }
+            }
+            if iteration >= settling_from {
+                settled.push(state.clone());
+            }
+            temperature *= self.cooling_rate;
+        }
+        
+        let spread = parameter_search::spread(&settled, problem.dimensions());
+        
+        Ok(problem.result(
+            opportunity,
+            &surrogate,
+            &best.0,
+            &spread,
+            format!(
+                "Simulated annealing of {} moves over {} performance samples",
+                self.iterations,
+                problem.observations.len()
+            ),
+        ))
     }
     
     fn strategy_name(&self) -> &str {
@@ -2419,4 +2718,183 @@ pub enum TrendDirection {
     
     /// Metric is stable
     Stable,
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evolution::performance::{ExecutionMetrics, LearningMetrics, QualityMetrics, ResourceMetrics, UserMetrics};
+
+    fn metrics(agent_id: &str, timestamp: DateTime<Utc>, success_rate: f32) -> AgentPerformanceMetrics {
+        AgentPerformanceMetrics {
+            agent_id: agent_id.to_string(),
+            timestamp,
+            execution_metrics: ExecutionMetrics {
+                avg_execution_time_ms: 500.0,
+                success_rate,
+                error_rate: 1.0 - success_rate,
+                timeout_rate: 0.0,
+                total_executions: 100,
+                recent_executions: 10,
+                avg_confidence: 0.8,
+                consistency_score: 0.8,
+            },
+            quality_metrics: QualityMetrics {
+                accuracy: 0.8,
+                relevance: 0.8,
+                completeness: 0.8,
+                coherence: 0.8,
+                creativity: 0.5,
+                constraint_adherence: 0.9,
+                user_feedback_score: 0.7,
+            },
+            resource_metrics: ResourceMetrics {
+                avg_memory_usage_mb: 256.0,
+                peak_memory_usage_mb: 512.0,
+                cpu_utilization: 0.4,
+                avg_api_calls: 2.0,
+                network_usage_kb: 10.0,
+                cost_per_execution: None,
+                efficiency_score: 0.7,
+            },
+            user_metrics: UserMetrics {
+                satisfaction_rating: 0.7,
+                followup_questions: 1,
+                clarification_requests: 0,
+                retention_rate: 0.8,
+                task_completion_rate: 0.8,
+                user_effort_score: 0.3,
+                positive_feedback_rate: 0.7,
+            },
+            learning_metrics: LearningMetrics {
+                improvement_rate: 0.5,
+                adaptation_speed: 0.5,
+                retention_score: 0.5,
+                learning_efficiency: 0.5,
+                successful_adaptations: 3,
+                transfer_capability: 0.5,
+                meta_learning_score: 0.5,
+            },
+            overall_score: 0.7,
+        }
+    }
+
+    fn reliability_opportunity() -> OptimizationOpportunity {
+        OptimizationOpportunity {
+            opportunity_id: "failure_test".to_string(),
+            opportunity_type: OpportunityType::Reliability,
+            target_agents: vec!["agent".to_string()],
+            potential_improvement: 0.8,
+            confidence: 0.9,
+            urgency: 0.9,
+            resources_required: ResourceEstimate {
+                cpu_impact: 0.2,
+                memory_impact: 0.1,
+                time_required: 300,
+                risk_level: 0.3,
+            },
+        }
+    }
+
+    /// Success rate that rises with the confidence threshold, which is currently 0.6
+    fn threshold_history() -> (Vec<AgentPerformanceMetrics>, HashMap<String, Vec<ParameterChange>>) {
+        let start = Utc::now() - chrono::Duration::hours(1);
+        let thresholds = [0.4, 0.8, 0.5, 0.7, 0.4, 0.6, 0.8, 0.5, 0.7, 0.6];
+        let mut previous = 0.7;
+        let mut changes = Vec::new();
+        let mut data = Vec::new();
+        for (i, threshold) in thresholds.iter().enumerate() {
+            let changed_at = start + chrono::Duration::minutes(5 * i as i64);
+            changes.push(ParameterChange {
+                timestamp: changed_at,
+                old_value: previous,
+                new_value: *threshold,
+                reason: "test".to_string(),
+                expected_impact: 0.0,
+                actual_impact: None,
+                uncertainty: 0.0,
+            });
+            previous = *threshold;
+            for minute in 1..3 {
+                data.push(metrics("agent", changed_at + chrono::Duration::minutes(minute), 0.3 + 0.6 * threshold));
+            }
+        }
+        // Other agents' samples are ignored
+        data.push(metrics("other", start, 0.0));
+        (data, HashMap::from([("confidence_threshold".to_string(), changes)]))
+    }
+
+    fn assert_raises_threshold(result: &OptimizationResult) {
+        let change = result.parameter_changes.get("confidence_threshold").expect("threshold change");
+        assert_eq!(change.old_value, 0.6);
+        assert!(change.new_value > 0.6 && change.new_value <= 0.95);
+        assert!(change.uncertainty.is_finite() && change.uncertainty >= 0.0);
+        assert!(result.expected_impact > 0.0 && result.expected_impact <= 0.8);
+        assert!(result.confidence > 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_optimizers_follow_observed_improvement() {
+        let (data, history) = threshold_history();
+        let opportunity = reliability_opportunity();
+        let learning_results = HashMap::new();
+
+        let gradient = GradientDescentOptimizer::new()
+            .optimize(&opportunity, &data, &learning_results, &history).await.unwrap();
+        assert_raises_threshold(&gradient);
+
+        let annealing = SimulatedAnnealingOptimizer::new()
+            .optimize(&opportunity, &data, &learning_results, &history).await.unwrap();
+        assert_raises_threshold(&annealing);
+
+        let genetic = GeneticAlgorithmOptimizer::new()
+            .optimize(&opportunity, &data, &learning_results, &history).await.unwrap();
+        assert_raises_threshold(&genetic);
+
+        let bayesian = BayesianOptimizer::new()
+            .optimize(&opportunity, &data, &learning_results, &history).await.unwrap();
+        assert!(!bayesian.parameter_changes.is_empty());
+        assert!((0.0..=1.0).contains(&bayesian.confidence));
+
+        // The search is seeded by the opportunity, so repeating it gives the same proposal
+        let repeated = BayesianOptimizer::new()
+            .optimize(&opportunity, &data, &learning_results, &history).await.unwrap();
+        for (name, change) in &bayesian.parameter_changes {
+            assert_eq!(repeated.parameter_changes[name].new_value, change.new_value);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strategy_selection_and_recording() {
+        let optimizer = AutomatedParameterOptimizer::new(0.05, 0.8).unwrap();
+        let (data, history) = threshold_history();
+        let mut opportunity = reliability_opportunity();
+        let learning_results = HashMap::new();
+
+        let empty = SearchProblem::build(&opportunity, &[], &learning_results, &HashMap::new());
+        let strategy = optimizer.select_optimization_strategy(&opportunity, &empty).await.unwrap();
+        assert_eq!(strategy.strategy_name(), "Bayesian");
+
+        let explored = SearchProblem::build(&opportunity, &data, &learning_results, &history);
+        assert_eq!(explored.distinct_settings(), 5);
+        let strategy = optimizer.select_optimization_strategy(&opportunity, &explored).await.unwrap();
+        assert_eq!(strategy.strategy_name(), "SimulatedAnnealing");
+
+        opportunity.resources_required.risk_level = 0.8;
+        let strategy = optimizer.select_optimization_strategy(&opportunity, &explored).await.unwrap();
+        assert_eq!(strategy.strategy_name(), "GradientDescent");
+
+        *optimizer.parameter_history.write().await = history;
+        let applied = optimizer
+            .apply_optimization_strategy(&opportunity, &data, &learning_results)
+            .await
+            .unwrap()
+            .expect("optimization applied");
+        assert_eq!(applied.optimization_type, "GradientDescent");
+        let new_value = applied.parameter_changes["confidence_threshold"];
+
+        let history = optimizer.parameter_history.read().await;
+        let recorded = history["confidence_threshold"].last().unwrap();
+        assert_eq!(recorded.old_value, 0.6);
+        assert_eq!(recorded.new_value, new_value);
+    }
+}
@@ -20,6 +20,7 @@ pub mod performance;
 pub mod learning_loop;
 pub mod optimization;
 pub mod integration;
+mod parameter_search;
 
 // Re-export key types
 pub use meta_agent::*;
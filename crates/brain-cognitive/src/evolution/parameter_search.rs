@@ -0,0 +1,659 @@
+//! Parameter Search
+//!
+//! Shared machinery for the parameter optimizers in `integration`. Each kind of
+//! optimization opportunity tunes a bounded set of parameters. Observations
+//! pair the parameter values in effect when an agent's performance was recorded
+//! with an objective computed from those metrics, and a Gaussian-process
+//! surrogate fit to them predicts the objective, with uncertainty, anywhere in
+//! the normalized search space. Searches draw from a generator seeded by the
+//! opportunity, so the same opportunity always yields the same proposal.
+
+use std::collections::HashMap;
+use brain_types::math::{fnv1a, normal_cdf};
+use chrono::{DateTime, Utc};
+
+use super::integration::{OpportunityType, OptimizationOpportunity, OptimizationResult, ParameterChange};
+use super::performance::AgentPerformanceMetrics;
+use super::LearningCycleResult;
+
+/// Most recent performance samples the surrogate is fit to
+const MAX_OBSERVATIONS: usize = 200;
+
+/// Normalized change below which a parameter is left alone
+const MIN_CHANGE: f64 = 0.01;
+
+/// Objective gain per normalized unit implied by a learning-loop adjustment of 2x
+const PRIOR_SLOPE: f64 = 0.1;
+
+/// Kernel length scale in normalized units
+const LENGTH_SCALE: f64 = 0.3;
+
+/// Prior variance of the objective when the observations barely vary
+const MIN_SIGNAL_VARIANCE: f64 = 0.01;
+
+/// A tunable parameter and its allowed range
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ParameterSpec {
+    pub name: &'static str,
+    pub min: f32,
+    pub max: f32,
+    /// Value assumed before the optimizer has changed it
+    pub default: f32,
+    /// Whether only whole values are meaningful
+    pub integer: bool,
+}
+
+impl ParameterSpec {
+    const fn real(name: &'static str, min: f32, max: f32, default: f32) -> Self {
+        Self { name, min, max, default, integer: false }
+    }
+
+    const fn integer(name: &'static str, min: f32, max: f32, default: f32) -> Self {
+        Self { name, min, max, default, integer: true }
+    }
+
+    pub fn range(&self) -> f32 {
+        self.max - self.min
+    }
+
+    pub fn normalize(&self, value: f32) -> f64 {
+        ((value.clamp(self.min, self.max) - self.min) / self.range()) as f64
+    }
+
+    pub fn denormalize(&self, unit: f64) -> f32 {
+        let value = self.min + unit.clamp(0.0, 1.0) as f32 * self.range();
+        if self.integer { value.round() } else { value }
+    }
+}
+
+/// Parameters tuned for each kind of opportunity
+///
+/// Names match the adjustments `ParameterTuner` makes in the learning loop so
+/// its suggestions can inform the search.
+pub(crate) fn parameter_space(opportunity_type: &OpportunityType) -> Vec<ParameterSpec> {
+    match opportunity_type {
+        OpportunityType::Performance => vec![
+            ParameterSpec::real("response_timeout", 5.0, 300.0, 60.0),
+            ParameterSpec::integer("batch_size", 1.0, 64.0, 16.0),
+            ParameterSpec::integer("concurrency_limit", 1.0, 32.0, 10.0),
+        ],
+        OpportunityType::Reliability => vec![
+            ParameterSpec::real("confidence_threshold", 0.3, 0.95, 0.7),
+            ParameterSpec::integer("validation_steps", 1.0, 10.0, 3.0),
+            ParameterSpec::real("learning_rate", 0.001, 0.5, 0.1),
+        ],
+        OpportunityType::Efficiency => vec![
+            ParameterSpec::real("memory_limit", 128.0, 4096.0, 1024.0),
+            ParameterSpec::real("gc_frequency", 0.1, 10.0, 1.0),
+            ParameterSpec::real("cache_size", 16.0, 2048.0, 256.0),
+        ],
+        OpportunityType::UserExperience => vec![
+            ParameterSpec::real("temperature", 0.0, 1.5, 0.7),
+            ParameterSpec::integer("top_k", 1.0, 100.0, 40.0),
+            ParameterSpec::real("repetition_penalty", 1.0, 2.0, 1.1),
+        ],
+        OpportunityType::Learning => vec![
+            ParameterSpec::real("learning_rate", 0.001, 0.5, 0.1),
+            ParameterSpec::real("exploration_rate", 0.0, 1.0, 0.2),
+            ParameterSpec::real("exploitation_rate", 0.0, 1.0, 0.8),
+        ],
+    }
+}
+
+/// Score in [0, 1] of one performance sample for an opportunity; higher is better
+pub(crate) fn objective(opportunity_type: &OpportunityType, metrics: &AgentPerformanceMetrics) -> f64 {
+    let execution = &metrics.execution_metrics;
+    let resources = &metrics.resource_metrics;
+    let user = &metrics.user_metrics;
+    let learning = &metrics.learning_metrics;
+
+    let score = match opportunity_type {
+        OpportunityType::Performance => {
+            let speed = 1000.0 / (1000.0 + execution.avg_execution_time_ms.max(0.0));
+            0.4 * execution.success_rate as f64 + 0.4 * speed + 0.2 * resources.efficiency_score as f64
+        }
+        OpportunityType::Reliability => {
+            0.5 * execution.success_rate as f64
+                + 0.25 * (1.0 - execution.error_rate as f64)
+                + 0.25 * (1.0 - execution.timeout_rate as f64)
+        }
+        OpportunityType::Efficiency => {
+            let memory = 1024.0 / (1024.0 + resources.avg_memory_usage_mb.max(0.0));
+            0.5 * resources.efficiency_score as f64 + 0.25 * (1.0 - resources.cpu_utilization as f64) + 0.25 * memory
+        }
+        OpportunityType::UserExperience => {
+            0.4 * user.satisfaction_rating as f64
+                + 0.3 * user.task_completion_rate as f64
+                + 0.3 * (1.0 - user.user_effort_score as f64)
+        }
+        OpportunityType::Learning => {
+            (learning.improvement_rate + learning.adaptation_speed + learning.learning_efficiency) as f64 / 3.0
+        }
+    };
+    score.clamp(0.0, 1.0)
+}
+
+/// Parameter value in effect at `at`, according to the recorded changes
+fn value_at(spec: &ParameterSpec, history: Option<&Vec<ParameterChange>>, at: DateTime<Utc>) -> f32 {
+    let Some(changes) = history.filter(|changes| !changes.is_empty()) else {
+        return spec.default;
+    };
+    match changes.iter().rev().find(|change| change.timestamp <= at) {
+        Some(change) => change.new_value,
+        // Sampled before the first change, so the value it replaced was in effect
+        None => changes[0].old_value,
+    }
+}
+
+/// Performance sample in normalized parameter space
+#[derive(Debug, Clone)]
+pub(crate) struct Observation {
+    pub point: Vec<f64>,
+    pub value: f64,
+}
+
+/// A bounded search for better parameter values around the current ones
+#[derive(Debug, Clone)]
+pub(crate) struct SearchProblem {
+    pub parameters: Vec<ParameterSpec>,
+    /// Values in effect now
+    pub current_values: Vec<f32>,
+    /// `current_values` normalized to [0, 1]
+    pub current: Vec<f64>,
+    pub observations: Vec<Observation>,
+    /// Objective slope per normalized unit suggested by learning-loop tuning
+    pub prior_slope: Vec<f64>,
+    /// Largest normalized move per parameter; shrinks as the opportunity gets riskier
+    pub trust_radius: f64,
+    pub seed: u64,
+}
+
+impl SearchProblem {
+    /// Build the search for `opportunity` from the performance history
+    ///
+    /// Samples from the opportunity's target agents (all agents if it names
+    /// none) are paired with the parameter values `parameter_history` says were
+    /// in effect when they were recorded.
+    pub fn build(
+        opportunity: &OptimizationOpportunity,
+        performance_data: &[AgentPerformanceMetrics],
+        learning_results: &HashMap<String, LearningCycleResult>,
+        parameter_history: &HashMap<String, Vec<ParameterChange>>,
+    ) -> Self {
+        let parameters = parameter_space(&opportunity.opportunity_type);
+        let is_target = |agent_id: &str| {
+            opportunity.target_agents.is_empty() || opportunity.target_agents.iter().any(|target| target == agent_id)
+        };
+
+        let current_values: Vec<f32> = parameters
+            .iter()
+            .map(|spec| value_at(spec, parameter_history.get(spec.name), Utc::now()))
+            .collect();
+        let current = parameters
+            .iter()
+            .zip(&current_values)
+            .map(|(spec, value)| spec.normalize(*value))
+            .collect();
+
+        let mut samples: Vec<&AgentPerformanceMetrics> = performance_data
+            .iter()
+            .filter(|metrics| is_target(&metrics.agent_id))
+            .collect();
+        samples.sort_by_key(|metrics| metrics.timestamp);
+        let skip = samples.len().saturating_sub(MAX_OBSERVATIONS);
+        let observations = samples[skip..]
+            .iter()
+            .map(|metrics| Observation {
+                point: parameters
+                    .iter()
+                    .map(|spec| spec.normalize(value_at(spec, parameter_history.get(spec.name), metrics.timestamp)))
+                    .collect(),
+                value: objective(&opportunity.opportunity_type, metrics),
+            })
+            .collect();
+
+        // Learning-loop adjustment factors (1.2 = raise by 20%) say which way each parameter should move
+        let mut prior_slope = vec![0.0; parameters.len()];
+        let mut suggestions = vec![0usize; parameters.len()];
+        for result in learning_results.values().filter(|result| is_target(&result.agent_id)) {
+            for (j, spec) in parameters.iter().enumerate() {
+                if let Some(factor) = result.parameter_adjustments.adjusted_parameters.get(spec.name) {
+                    prior_slope[j] += (*factor as f64 - 1.0).clamp(-1.0, 1.0) * PRIOR_SLOPE;
+                    suggestions[j] += 1;
+                }
+            }
+        }
+        for (slope, count) in prior_slope.iter_mut().zip(&suggestions) {
+            if *count > 0 {
+                *slope /= *count as f64;
+            }
+        }
+
+        let risk = opportunity.resources_required.risk_level.clamp(0.0, 1.0) as f64;
+        Self {
+            parameters,
+            current_values,
+            current,
+            observations,
+            prior_slope,
+            trust_radius: (0.3 * (1.0 - risk)).max(0.05),
+            seed: fnv1a(opportunity.opportunity_id.as_bytes()),
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Number of observably different parameter settings in the history
+    pub fn distinct_settings(&self) -> usize {
+        let mut distinct: Vec<&[f64]> = Vec::new();
+        for observation in &self.observations {
+            let seen = distinct.iter().any(|point| {
+                point.iter().zip(&observation.point).all(|(a, b)| (a - b).abs() < 1e-6)
+            });
+            if !seen {
+                distinct.push(observation.point.as_slice());
+            }
+        }
+        distinct.len()
+    }
+
+    /// Move `point` into the unit cube and the trust region around the current values
+    pub fn constrain(&self, point: &mut [f64]) {
+        for (x, center) in point.iter_mut().zip(&self.current) {
+            let low = (center - self.trust_radius).max(0.0);
+            let high = (center + self.trust_radius).min(1.0);
+            *x = x.clamp(low, high);
+        }
+    }
+
+    /// Uniform sample from the trust region
+    pub fn random_point(&self, rng: &mut SearchRng) -> Vec<f64> {
+        let mut point: Vec<f64> = self.current
+            .iter()
+            .map(|center| center + (2.0 * rng.next_f64() - 1.0) * self.trust_radius)
+            .collect();
+        self.constrain(&mut point);
+        point
+    }
+
+    pub fn surrogate(&self) -> GaussianProcess {
+        GaussianProcess::fit(self)
+    }
+
+    /// Turn a proposed point into parameter changes
+    ///
+    /// `spread` is the uncertainty of each proposed value in normalized units.
+    /// The expected impact is the opportunity's potential improvement weighted
+    /// by the surrogate's probability that the proposal beats the current values.
+    pub fn result(
+        &self,
+        opportunity: &OptimizationOpportunity,
+        surrogate: &GaussianProcess,
+        point: &[f64],
+        spread: &[f64],
+        reason: String,
+    ) -> OptimizationResult {
+        let (mean, deviation) = surrogate.predict(point);
+        let (current_mean, _) = surrogate.predict(&self.current);
+        let gain = mean - current_mean;
+        let probability = if deviation > 1e-9 {
+            normal_cdf(gain / deviation)
+        } else if gain > 0.0 {
+            1.0
+        } else {
+            0.5
+        };
+        let expected_impact = (opportunity.potential_improvement as f64 * probability) as f32;
+
+        let timestamp = Utc::now();
+        let parameter_changes: HashMap<String, ParameterChange> = self.parameters
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| (point[*j] - self.current[*j]).abs() >= MIN_CHANGE)
+            .map(|(j, spec)| (j, spec, spec.denormalize(point[j])))
+            .filter(|(j, _, new_value)| *new_value != self.current_values[*j])
+            .map(|(j, spec, new_value)| {
+                (spec.name.to_string(), ParameterChange {
+                    timestamp,
+                    old_value: self.current_values[j],
+                    new_value,
+                    reason: reason.clone(),
+                    expected_impact,
+                    actual_impact: None,
+                    uncertainty: (spread[j] * spec.range() as f64) as f32,
+                })
+            })
+            .collect();
+
+        OptimizationResult {
+            expected_impact: if parameter_changes.is_empty() { 0.0 } else { expected_impact },
+            confidence: probability as f32,
+            optimization_reason: format!(
+                "{} (predicted objective {:.3} ± {:.3}, currently {:.3})",
+                reason, mean, deviation, current_mean
+            ),
+            parameter_changes,
+        }
+    }
+}
+
+/// Gaussian-process regression of the objective over normalized parameters
+///
+/// Squared-exponential kernel around a linear prior mean that follows the
+/// learning-loop suggestions, so the surrogate leans the suggested way where
+/// there is no data and follows the data where there is.
+#[derive(Debug, Clone)]
+pub(crate) struct GaussianProcess {
+    points: Vec<Vec<f64>>,
+    /// Lower Cholesky factor of the kernel matrix plus noise
+    cholesky: Vec<Vec<f64>>,
+    /// Kernel matrix inverse times the residuals from the prior mean
+    alpha: Vec<f64>,
+    signal_variance: f64,
+    baseline: f64,
+    center: Vec<f64>,
+    slope: Vec<f64>,
+}
+
+impl GaussianProcess {
+    pub fn fit(problem: &SearchProblem) -> Self {
+        let points: Vec<Vec<f64>> = problem.observations.iter().map(|observation| observation.point.clone()).collect();
+        let values: Vec<f64> = problem.observations.iter().map(|observation| observation.value).collect();
+
+        let baseline = if values.is_empty() { 0.5 } else { values.iter().sum::<f64>() / values.len() as f64 };
+        let variance = if values.len() < 2 {
+            0.0
+        } else {
+            values.iter().map(|value| (value - baseline).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+        };
+        let signal_variance = variance.max(MIN_SIGNAL_VARIANCE);
+        let noise_variance = repeated_setting_variance(&points, &values)
+            .unwrap_or(0.1 * signal_variance)
+            .max(1e-4);
+
+        let mut process = Self {
+            points,
+            cholesky: Vec::new(),
+            alpha: Vec::new(),
+            signal_variance,
+            baseline,
+            center: problem.current.clone(),
+            slope: problem.prior_slope.clone(),
+        };
+
+        let kernel: Vec<Vec<f64>> = process.points
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                process.points
+                    .iter()
+                    .enumerate()
+                    .map(|(j, b)| process.kernel(a, b) + if i == j { noise_variance } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+        process.cholesky = cholesky(kernel);
+
+        let residuals: Vec<f64> = process.points
+            .iter()
+            .zip(&values)
+            .map(|(point, value)| value - process.prior_mean(point))
+            .collect();
+        let forward = forward_substitute(&process.cholesky, &residuals);
+        process.alpha = backward_substitute(&process.cholesky, &forward);
+
+        process
+    }
+
+    fn kernel(&self, a: &[f64], b: &[f64]) -> f64 {
+        let distance: f64 = a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum();
+        self.signal_variance * (-distance / (2.0 * LENGTH_SCALE * LENGTH_SCALE)).exp()
+    }
+
+    fn prior_mean(&self, point: &[f64]) -> f64 {
+        self.baseline
+            + point.iter()
+                .zip(&self.center)
+                .zip(&self.slope)
+                .map(|((x, center), slope)| slope * (x - center))
+                .sum::<f64>()
+    }
+
+    /// Posterior mean and standard deviation of the objective at `point`
+    pub fn predict(&self, point: &[f64]) -> (f64, f64) {
+        let covariances: Vec<f64> = self.points.iter().map(|observed| self.kernel(point, observed)).collect();
+        let mean = self.prior_mean(point)
+            + covariances.iter().zip(&self.alpha).map(|(k, a)| k * a).sum::<f64>();
+        let v = forward_substitute(&self.cholesky, &covariances);
+        let variance = self.signal_variance - v.iter().map(|x| x * x).sum::<f64>();
+        (mean, variance.max(1e-12).sqrt())
+    }
+
+    /// Gradient of the posterior mean by central differences
+    pub fn mean_gradient(&self, point: &[f64]) -> Vec<f64> {
+        const STEP: f64 = 1e-4;
+        let mut probe = point.to_vec();
+        (0..point.len())
+            .map(|j| {
+                probe[j] = point[j] + STEP;
+                let (above, _) = self.predict(&probe);
+                probe[j] = point[j] - STEP;
+                let (below, _) = self.predict(&probe);
+                probe[j] = point[j];
+                (above - below) / (2.0 * STEP)
+            })
+            .collect()
+    }
+}
+
+/// Pooled variance of the objective across samples taken with identical parameters
+fn repeated_setting_variance(points: &[Vec<f64>], values: &[f64]) -> Option<f64> {
+    let mut groups: Vec<(&[f64], Vec<f64>)> = Vec::new();
+    for (point, value) in points.iter().zip(values) {
+        match groups.iter_mut().find(|(key, _)| key.iter().zip(point).all(|(a, b)| (a - b).abs() < 1e-6)) {
+            Some((_, group)) => group.push(*value),
+            None => groups.push((point.as_slice(), vec![*value])),
+        }
+    }
+
+    let mut squared_deviations = 0.0;
+    let mut degrees_of_freedom = 0;
+    for (_, group) in groups.iter().filter(|(_, group)| group.len() > 1) {
+        let mean = group.iter().sum::<f64>() / group.len() as f64;
+        squared_deviations += group.iter().map(|value| (value - mean).powi(2)).sum::<f64>();
+        degrees_of_freedom += group.len() - 1;
+    }
+    if degrees_of_freedom > 0 {
+        Some(squared_deviations / degrees_of_freedom as f64)
+    } else {
+        None
+    }
+}
+
+/// Lower Cholesky factor of a symmetric positive-definite matrix
+///
+/// Diagonal jitter is added until the factorization succeeds.
+fn cholesky(matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut jitter = 0.0;
+    loop {
+        let mut lower = vec![vec![0.0; n]; n];
+        let mut positive_definite = true;
+        'rows: for i in 0..n {
+            for j in 0..=i {
+                let mut sum = matrix[i][j];
+                sum -= lower[i][..j].iter().zip(&lower[j][..j]).map(|(a, b)| a * b).sum::<f64>();
+                if i == j {
+                    sum += jitter;
+                    if sum <= 0.0 {
+                        positive_definite = false;
+                        break 'rows;
+                    }
+                    lower[i][i] = sum.sqrt();
+                } else {
+                    lower[i][j] = sum / lower[j][j];
+                }
+            }
+        }
+        if positive_definite {
+            return lower;
+        }
+        jitter = if jitter == 0.0 { 1e-9 } else { jitter * 10.0 };
+    }
+}
+
+/// Solve `L x = b` for lower-triangular `L`
+fn forward_substitute(lower: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let mut x: Vec<f64> = Vec::with_capacity(b.len());
+    for (row, value) in lower.iter().zip(b) {
+        let i = x.len();
+        let sum: f64 = row.iter().zip(&x).map(|(l, solved)| l * solved).sum();
+        x.push((value - sum) / row[i]);
+    }
+    x
+}
+
+/// Solve `Lᵀ x = b` for lower-triangular `L`
+fn backward_substitute(lower: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for (i, value) in b.iter().enumerate().rev() {
+        let sum: f64 = (i + 1..n).map(|k| lower[k][i] * x[k]).sum();
+        x[i] = (value - sum) / lower[i][i];
+    }
+    x
+}
+
+pub(crate) fn normal_pdf(z: f64) -> f64 {
+    (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Expected improvement over `incumbent` of a prediction with `mean` and `deviation`
+pub(crate) fn expected_improvement(mean: f64, deviation: f64, incumbent: f64, exploration: f64) -> f64 {
+    let gain = mean - incumbent - exploration;
+    if deviation <= 1e-12 {
+        return gain.max(0.0);
+    }
+    let z = gain / deviation;
+    gain * normal_cdf(z) + deviation * normal_pdf(z)
+}
+
+/// Per-dimension standard deviation of a set of points
+pub(crate) fn spread(points: &[Vec<f64>], dimensions: usize) -> Vec<f64> {
+    if points.len() < 2 {
+        return vec![0.0; dimensions];
+    }
+    (0..dimensions)
+        .map(|j| {
+            let mean = points.iter().map(|point| point[j]).sum::<f64>() / points.len() as f64;
+            let variance = points.iter().map(|point| (point[j] - mean).powi(2)).sum::<f64>() / (points.len() - 1) as f64;
+            variance.sqrt()
+        })
+        .collect()
+}
+
+/// SplitMix64 generator for reproducible searches
+#[derive(Debug, Clone)]
+pub(crate) struct SearchRng {
+    state: u64,
+}
+
+impl SearchRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform index below `n`
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Standard normal sample (Box-Muller)
+    pub fn normal(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem(observations: Vec<(Vec<f64>, f64)>, prior_slope: Vec<f64>) -> SearchProblem {
+        let parameters = parameter_space(&OpportunityType::Reliability);
+        SearchProblem {
+            current_values: parameters.iter().map(|spec| spec.denormalize(0.5)).collect(),
+            current: vec![0.5; parameters.len()],
+            parameters,
+            observations: observations
+                .into_iter()
+                .map(|(point, value)| Observation { point, value })
+                .collect(),
+            prior_slope,
+            trust_radius: 0.3,
+            seed: 7,
+        }
+    }
+
+    #[test]
+    fn test_gaussian_process_interpolates_and_reverts_to_prior() {
+        let problem = problem(
+            vec![
+                (vec![0.2, 0.5, 0.5], 0.4),
+                (vec![0.5, 0.5, 0.5], 0.6),
+                (vec![0.8, 0.5, 0.5], 0.8),
+            ],
+            vec![0.0; 3],
+        );
+        let surrogate = problem.surrogate();
+
+        let (mean, deviation) = surrogate.predict(&[0.8, 0.5, 0.5]);
+        assert!((mean - 0.8).abs() < 0.05);
+        let (_, far_deviation) = surrogate.predict(&[0.8, 0.0, 1.0]);
+        assert!(far_deviation > deviation * 2.0);
+
+        // The objective rises along the first parameter only
+        let gradient = surrogate.mean_gradient(&[0.5, 0.5, 0.5]);
+        assert!(gradient[0] > 0.1);
+        assert!(gradient[1].abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_prior_slope_guides_the_surrogate_without_data() {
+        let surrogate = problem(Vec::new(), vec![0.1, 0.0, -0.1]).surrogate();
+        let (current, _) = surrogate.predict(&[0.5, 0.5, 0.5]);
+        let (raised, _) = surrogate.predict(&[0.8, 0.5, 0.2]);
+        assert!((raised - current - 0.06).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_numeric_helpers() {
+        assert!(expected_improvement(0.5, 0.1, 0.5, 0.0) > 0.0);
+        assert_eq!(expected_improvement(0.4, 0.0, 0.5, 0.0), 0.0);
+
+        let spec = ParameterSpec::integer("batch_size", 1.0, 64.0, 16.0);
+        assert_eq!(spec.denormalize(spec.normalize(16.0)), 16.0);
+        assert_eq!(spec.denormalize(2.0), 64.0);
+
+        let mut a = SearchRng::new(3);
+        let mut b = SearchRng::new(3);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert!((0..1000).map(|_| a.next_f64()).all(|x| (0.0..1.0).contains(&x)));
+    }
+}
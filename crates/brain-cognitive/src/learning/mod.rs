@@ -8,7 +8,8 @@
 //! The curiosity-driven learning system follows hexagonal architecture with:
 //! - **Core Domain**: Learning priorities, curiosity drives, knowledge gaps
 //! - **Ports**: Trait-based interfaces for novelty detection and meta-memory
-//! - **Adapters**: Concrete implementations for different learning strategies
+//! - **Adapters**: Concrete implementations for different learning strategies, such as
+//!   the memory-backed novelty detector in `novelty`
 //! 
 //! ## Key Features:
 //! - Learning priority scoring based on novelty and knowledge gaps
@@ -29,6 +30,10 @@ use uuid::Uuid;
 
 use crate::meta::{KnowledgeType, MetaMemoryService};
 
+pub mod novelty;
+
+pub use novelty::{MemoryNoveltyDetector, NoveltyDetectorConfig, NoveltySource, SourceNovelty};
+
 /// Configuration for curiosity-driven learning system
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CuriosityConfig {
@@ -367,6 +372,16 @@ pub struct NoveltyAssessment {
     pub novelty_factors: Vec<String>,
     /// Confidence in the assessment
     pub assessment_confidence: f64,
+    /// Per-source scores, ordered by their contribution to `novelty_score`
+    #[serde(default)]
+    pub source_breakdown: Vec<SourceNovelty>,
+}
+
+impl NoveltyAssessment {
+    /// The knowledge source that contributed most to the score, if any was consulted
+    pub fn dominant_source(&self) -> Option<&NoveltySource> {
+        self.source_breakdown.first().map(|source| &source.source)
+    }
 }
 
 /// Novelty level classification
@@ -379,6 +394,23 @@ pub enum NoveltyLevel {
     VeryHigh,
 }
 
+impl NoveltyLevel {
+    /// Classify a novelty score in 0.2-wide bands
+    pub fn from_score(score: f64) -> Self {
+        if score > 0.8 {
+            NoveltyLevel::VeryHigh
+        } else if score > 0.6 {
+            NoveltyLevel::High
+        } else if score > 0.4 {
+            NoveltyLevel::Medium
+        } else if score > 0.2 {
+            NoveltyLevel::Low
+        } else {
+            NoveltyLevel::VeryLow
+        }
+    }
+}
+
 /// Trait for novelty detection services
 #[async_trait]
 pub trait NoveltyDetector: Send + Sync {
@@ -0,0 +1,531 @@
+//! Novelty Detection
+//!
+//! `MemoryNoveltyDetector` judges how new an input is against what Brain AI
+//! already knows: the semantic concepts nearest to it in embedding space, the
+//! concept-graph nodes covering its keywords, how much of it the BPE
+//! vocabulary already segments, and the meta-memory confidence of the
+//! knowledge it touches. Every source is optional; the assessment weighs the
+//! sources that are attached and reports which one drove the score.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use async_trait::async_trait;
+use brain_core::concepts::{ConceptQuery, ConceptRepository};
+use brain_core::memory::{
+    content_keywords, cosine_similarity, EmbeddingProvider, KeywordEmbeddingProvider, SemanticMemoryRepository,
+};
+use brain_core::SegmentationProvider;
+use brain_types::error::BrainError;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use super::{NoveltyAssessment, NoveltyDetector, NoveltyLevel};
+use crate::meta::MetaMemoryService;
+
+/// Configuration for `MemoryNoveltyDetector`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoveltyDetectorConfig {
+    /// Weight of the distance to the nearest semantic concept
+    pub semantic_weight: f64,
+    /// Weight of the share of keywords without a concept-graph node
+    pub concept_weight: f64,
+    /// Weight of how little of the text the BPE vocabulary covers
+    pub segment_weight: f64,
+    /// Weight of how little confidence meta-memory has in the related knowledge
+    pub meta_memory_weight: f64,
+    /// Semantic concepts and concept-graph nodes consulted per lookup
+    pub related_limit: usize,
+    /// Keywords looked up in the concept graph per assessment
+    pub max_keywords: usize,
+    /// Inputs remembered through `update_models`
+    pub history_size: usize,
+}
+
+impl Default for NoveltyDetectorConfig {
+    fn default() -> Self {
+        Self {
+            semantic_weight: 0.35,
+            concept_weight: 0.25,
+            segment_weight: 0.2,
+            meta_memory_weight: 0.2,
+            related_limit: 5,
+            max_keywords: 16,
+            history_size: 256,
+        }
+    }
+}
+
+/// Knowledge source an input is compared against
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoveltySource {
+    SemanticMemory,
+    ConceptGraph,
+    SegmentFamiliarity,
+    MetaMemoryConfidence,
+}
+
+impl std::fmt::Display for NoveltySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoveltySource::SemanticMemory => write!(f, "semantic_memory"),
+            NoveltySource::ConceptGraph => write!(f, "concept_graph"),
+            NoveltySource::SegmentFamiliarity => write!(f, "segment_familiarity"),
+            NoveltySource::MetaMemoryConfidence => write!(f, "meta_memory_confidence"),
+        }
+    }
+}
+
+/// Novelty of an input according to a single source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceNovelty {
+    pub source: NoveltySource,
+    /// Novelty according to this source (0.0-1.0)
+    pub score: f64,
+    /// Weight of this source in the overall score
+    pub weight: f64,
+    /// What the source found
+    pub explanation: String,
+}
+
+impl SourceNovelty {
+    fn contribution(&self) -> f64 {
+        self.score * self.weight
+    }
+}
+
+/// Novelty detector scoring inputs against semantic memory, the concept
+/// graph, BPE segment statistics and meta-memory
+pub struct MemoryNoveltyDetector {
+    config: NoveltyDetectorConfig,
+    semantic_memory: Option<Arc<RwLock<dyn SemanticMemoryRepository>>>,
+    /// Embeds inputs in the same space as the concepts in semantic memory
+    embedding_provider: Arc<dyn EmbeddingProvider>,
+    concept_graph: Option<Arc<RwLock<dyn ConceptRepository>>>,
+    segmenter: Option<Arc<dyn SegmentationProvider>>,
+    meta_memory: Option<Arc<MetaMemoryService>>,
+    /// Embeddings of inputs passed to `update_models`, compared alongside semantic memory
+    history: VecDeque<Vec<f32>>,
+}
+
+impl MemoryNoveltyDetector {
+    /// Create a detector with no sources attached
+    pub fn new(config: NoveltyDetectorConfig) -> Self {
+        Self {
+            config,
+            semantic_memory: None,
+            embedding_provider: Arc::new(KeywordEmbeddingProvider::default()),
+            concept_graph: None,
+            segmenter: None,
+            meta_memory: None,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Compare inputs with the concepts in semantic memory
+    pub fn with_semantic_memory(mut self, semantic_memory: Arc<RwLock<dyn SemanticMemoryRepository>>) -> Self {
+        self.semantic_memory = Some(semantic_memory);
+        self
+    }
+
+    /// Embed inputs with the provider that embedded the concepts in semantic memory
+    ///
+    /// Defaults to `KeywordEmbeddingProvider`.
+    pub fn with_embedding_provider(mut self, embedding_provider: Arc<dyn EmbeddingProvider>) -> Self {
+        self.embedding_provider = embedding_provider;
+        self
+    }
+
+    /// Check input keywords against the concept graph
+    pub fn with_concept_graph(mut self, concept_graph: Arc<RwLock<dyn ConceptRepository>>) -> Self {
+        self.concept_graph = Some(concept_graph);
+        self
+    }
+
+    /// Measure how familiar the text is to a segmenter such as `BpeSegmenter`
+    pub fn with_segmenter(mut self, segmenter: Arc<dyn SegmentationProvider>) -> Self {
+        self.segmenter = Some(segmenter);
+        self
+    }
+
+    /// Weigh in meta-memory confidence of the concepts related to the input
+    pub fn with_meta_memory(mut self, meta_memory: Arc<MetaMemoryService>) -> Self {
+        self.meta_memory = Some(meta_memory);
+        self
+    }
+
+    fn total_weight(&self) -> f64 {
+        self.config.semantic_weight
+            + self.config.concept_weight
+            + self.config.segment_weight
+            + self.config.meta_memory_weight
+    }
+
+    /// Distance to the most similar semantic concept or remembered input
+    async fn semantic_novelty(&self, input: &str, related: &mut Vec<Uuid>) -> Result<Option<SourceNovelty>, BrainError> {
+        if self.semantic_memory.is_none() && self.history.is_empty() {
+            return Ok(None);
+        }
+        let embedding = self.embedding_provider.embed(input).await?;
+        if embedding.iter().all(|value| *value == 0.0) {
+            return Ok(None);
+        }
+
+        let mut nearest = None;
+        if let Some(semantic_memory) = &self.semantic_memory {
+            let similar = semantic_memory
+                .read()
+                .await
+                .find_similar(&embedding, 0.0, self.config.related_limit)
+                .await?;
+            related.extend(similar.iter().map(|(id, _)| *id));
+            nearest = similar.first().map(|(_, similarity)| *similarity);
+        }
+
+        let remembered = self.history.iter().map(|seen| cosine_similarity(&embedding, seen)).reduce(f64::max);
+        let similarity = match (nearest, remembered) {
+            (Some(a), Some(b)) => a.max(b),
+            (a, b) => a.or(b).unwrap_or(0.0),
+        }
+        .clamp(0.0, 1.0);
+
+        let explanation = if similarity > 0.0 {
+            format!("nearest known concept has similarity {:.2}", similarity)
+        } else {
+            "nothing similar in semantic memory".to_string()
+        };
+
+        Ok(Some(SourceNovelty {
+            source: NoveltySource::SemanticMemory,
+            score: 1.0 - similarity,
+            weight: self.config.semantic_weight,
+            explanation,
+        }))
+    }
+
+    /// Share of the input's keywords that no concept-graph node mentions
+    async fn concept_novelty(&self, input: &str, related: &mut Vec<Uuid>) -> Result<Option<SourceNovelty>, BrainError> {
+        let Some(concept_graph) = &self.concept_graph else {
+            return Ok(None);
+        };
+
+        let mut seen = HashSet::new();
+        let keywords: Vec<String> = content_keywords(input)
+            .into_iter()
+            .filter(|keyword| seen.insert(keyword.clone()))
+            .take(self.config.max_keywords)
+            .collect();
+        if keywords.is_empty() {
+            return Ok(None);
+        }
+
+        let concept_graph = concept_graph.read().await;
+        let mut uncovered = Vec::new();
+        for keyword in &keywords {
+            let matches = concept_graph
+                .query_concepts(&ConceptQuery {
+                    content_pattern: Some(keyword.clone()),
+                    limit: Some(self.config.related_limit),
+                    ..Default::default()
+                })
+                .await?;
+            if matches.is_empty() {
+                uncovered.push(keyword.as_str());
+            }
+            related.extend(matches.iter().map(|concept| concept.id));
+        }
+
+        Ok(Some(SourceNovelty {
+            source: NoveltySource::ConceptGraph,
+            score: uncovered.len() as f64 / keywords.len() as f64,
+            weight: self.config.concept_weight,
+            explanation: if uncovered.is_empty() {
+                format!("all {} keywords have concepts", keywords.len())
+            } else {
+                format!(
+                    "{} of {} keywords have no concept ({})",
+                    uncovered.len(),
+                    keywords.len(),
+                    uncovered.join(", ")
+                )
+            },
+        }))
+    }
+
+    /// How little of the text the learned multi-character segments cover
+    ///
+    /// The text is matched greedily against the longest known segments; each
+    /// covered character counts by how established its segment is, from its
+    /// confidence or, for segments that have not earned one yet, its frequency.
+    fn segment_novelty(&self, input: &str) -> Option<SourceNovelty> {
+        let segmenter = self.segmenter.as_ref()?;
+        let vocabulary: HashSet<String> = segmenter
+            .get_segments()
+            .into_iter()
+            .filter(|segment| segment.chars().count() > 1)
+            .collect();
+        let longest = vocabulary.iter().map(|segment| segment.chars().count()).max()?;
+
+        let chars: Vec<char> = input.chars().collect();
+        let (mut familiarity, mut covered, mut total) = (0.0, 0, 0);
+        let mut position = 0;
+        while position < chars.len() {
+            if chars[position].is_whitespace() {
+                position += 1;
+                continue;
+            }
+
+            let matched = (2..=longest.min(chars.len() - position)).rev().find_map(|length| {
+                let candidate: String = chars[position..position + length].iter().collect();
+                vocabulary.contains(&candidate).then_some((candidate, length))
+            });
+            match matched {
+                Some((segment, length)) => {
+                    let established = segmenter
+                        .get_segment_stats(&segment)
+                        .map(|stats| {
+                            let frequency = stats.frequency as f64;
+                            stats.confidence.max(frequency / (frequency + 1.0))
+                        })
+                        .unwrap_or(0.0)
+                        .clamp(0.0, 1.0);
+                    let counted = segment.chars().filter(|c| !c.is_whitespace()).count();
+                    familiarity += established * counted as f64;
+                    covered += counted;
+                    total += counted;
+                    position += length;
+                }
+                None => {
+                    total += 1;
+                    position += 1;
+                }
+            }
+        }
+        if total == 0 {
+            return None;
+        }
+
+        Some(SourceNovelty {
+            source: NoveltySource::SegmentFamiliarity,
+            score: 1.0 - familiarity / total as f64,
+            weight: self.config.segment_weight,
+            explanation: format!(
+                "{:.0}% of the text is covered by known segments",
+                covered as f64 / total as f64 * 100.0
+            ),
+        })
+    }
+
+    /// Lack of confidence in the tracked components the other sources related to the input
+    async fn meta_memory_novelty(&self, related: &[Uuid]) -> Result<Option<SourceNovelty>, BrainError> {
+        let Some(meta_memory) = &self.meta_memory else {
+            return Ok(None);
+        };
+
+        let mut confidences = Vec::new();
+        let mut seen = HashSet::new();
+        for component_id in related.iter().filter(|id| seen.insert(**id)) {
+            let item = meta_memory
+                .get_component(*component_id)
+                .await
+                .map_err(|e| BrainError::Other(format!("Meta-memory query failed: {}", e)))?;
+            if let Some(item) = item {
+                confidences.push(item.confidence_score);
+            }
+        }
+        if confidences.is_empty() {
+            return Ok(None);
+        }
+
+        let average = confidences.iter().sum::<f64>() / confidences.len() as f64;
+        Ok(Some(SourceNovelty {
+            source: NoveltySource::MetaMemoryConfidence,
+            score: 1.0 - average.clamp(0.0, 1.0),
+            weight: self.config.meta_memory_weight,
+            explanation: format!(
+                "{} related components tracked with average confidence {:.2}",
+                confidences.len(),
+                average
+            ),
+        }))
+    }
+
+    /// Weighted mean of the available sources
+    ///
+    /// Confidence is the share of the configured weight that could be
+    /// consulted, reduced by how much the sources disagree.
+    fn combine(&self, mut sources: Vec<SourceNovelty>) -> NoveltyAssessment {
+        let available: f64 = sources.iter().map(|source| source.weight).sum();
+        let total = self.total_weight();
+        if sources.is_empty() || available <= 0.0 || total <= 0.0 {
+            return NoveltyAssessment {
+                novelty_score: 1.0,
+                novelty_level: NoveltyLevel::VeryHigh,
+                novelty_factors: vec!["no knowledge to compare against".to_string()],
+                assessment_confidence: 0.0,
+                source_breakdown: sources,
+            };
+        }
+
+        let novelty_score = sources.iter().map(SourceNovelty::contribution).sum::<f64>() / available;
+        let disagreement = sources
+            .iter()
+            .map(|source| source.weight * (source.score - novelty_score).abs())
+            .sum::<f64>()
+            / available;
+
+        sources.sort_by(|a, b| b.contribution().total_cmp(&a.contribution()));
+        NoveltyAssessment {
+            novelty_score,
+            novelty_level: NoveltyLevel::from_score(novelty_score),
+            novelty_factors: sources
+                .iter()
+                .map(|source| format!("{}: {:.2} ({})", source.source, source.score, source.explanation))
+                .collect(),
+            assessment_confidence: ((available / total).min(1.0) * (1.0 - disagreement)).clamp(0.0, 1.0),
+            source_breakdown: sources,
+        }
+    }
+}
+
+impl Default for MemoryNoveltyDetector {
+    fn default() -> Self {
+        Self::new(NoveltyDetectorConfig::default())
+    }
+}
+
+#[async_trait]
+impl NoveltyDetector for MemoryNoveltyDetector {
+    async fn assess_novelty(&self, input: &str) -> Result<NoveltyAssessment, BrainError> {
+        let mut related = Vec::new();
+        let mut sources = Vec::new();
+
+        sources.extend(self.semantic_novelty(input, &mut related).await?);
+        sources.extend(self.concept_novelty(input, &mut related).await?);
+        sources.extend(self.segment_novelty(input));
+        // Meta-memory is consulted for the knowledge the other sources found
+        sources.extend(self.meta_memory_novelty(&related).await?);
+
+        Ok(self.combine(sources))
+    }
+
+    async fn update_models(&mut self, input: &str) -> Result<(), BrainError> {
+        let embedding = self.embedding_provider.embed(input).await?;
+        if embedding.iter().any(|value| *value != 0.0) {
+            self.history.push_back(embedding);
+            while self.history.len() > self.config.history_size {
+                self.history.pop_front();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::{InMemoryMetaMemoryRepository, KnowledgeType, MetaMemoryConfig};
+    use brain_core::concepts::{ConceptNode, ConceptType};
+    use brain_core::memory::SemanticConcept;
+    use brain_core::BpeConfig;
+    use brain_infra::{BpeSegmenter, ConceptGraphConfig, ConceptGraphManager};
+
+    async fn detector() -> (MemoryNoveltyDetector, Uuid) {
+        let mut semantic_memory = brain_infra::SemanticMemoryRepository::new();
+        semantic_memory.store_concept(SemanticConcept::new(
+            "ownership".to_string(),
+            "Rust ownership and borrowing".to_string(),
+            KeywordEmbeddingProvider::default().embed_text("rust ownership borrowing lifetimes"),
+        )).await.unwrap();
+
+        let mut concept_graph = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let ownership = ConceptNode::new(ConceptType::Abstract, "ownership".to_string(), 0.9, None);
+        let ownership_id = concept_graph.create_concept(ownership).await.unwrap();
+        concept_graph.create_concept(ConceptNode::new(ConceptType::Entity, "rust".to_string(), 0.9, None)).await.unwrap();
+
+        let mut segmenter = BpeSegmenter::new(BpeConfig::default());
+        segmenter.initialize_from_text("rust ownership rust borrowing rust ownership borrowing").unwrap();
+        segmenter.train().unwrap();
+
+        let detector = MemoryNoveltyDetector::default()
+            .with_semantic_memory(Arc::new(RwLock::new(semantic_memory)))
+            .with_concept_graph(Arc::new(RwLock::new(concept_graph)))
+            .with_segmenter(Arc::new(segmenter));
+        (detector, ownership_id)
+    }
+
+    #[tokio::test]
+    async fn test_known_input_is_less_novel_than_unknown_input() {
+        let (detector, _) = detector().await;
+
+        let familiar = detector.assess_novelty("rust ownership borrowing").await.unwrap();
+        let unfamiliar = detector.assess_novelty("quantum chromodynamics gluon").await.unwrap();
+
+        assert!(familiar.novelty_score < unfamiliar.novelty_score);
+        assert!(matches!(unfamiliar.novelty_level, NoveltyLevel::High | NoveltyLevel::VeryHigh));
+        assert_eq!(unfamiliar.source_breakdown.len(), 3);
+        assert_eq!(unfamiliar.novelty_factors.len(), 3);
+        assert!(unfamiliar.assessment_confidence > 0.0);
+        assert_eq!(unfamiliar.dominant_source(), Some(&NoveltySource::SemanticMemory));
+    }
+
+    #[tokio::test]
+    async fn test_meta_memory_confidence_of_related_concepts() {
+        let (detector, ownership_id) = detector().await;
+        let meta_memory = Arc::new(MetaMemoryService::from_store(
+            InMemoryMetaMemoryRepository::new(),
+            MetaMemoryConfig::default(),
+        ));
+        meta_memory.track_component(ownership_id, KnowledgeType::ConceptNode, 0.2, "test".to_string()).await.unwrap();
+        let detector = detector.with_meta_memory(meta_memory);
+
+        let assessment = detector.assess_novelty("ownership").await.unwrap();
+        let meta = assessment
+            .source_breakdown
+            .iter()
+            .find(|source| source.source == NoveltySource::MetaMemoryConfidence)
+            .unwrap();
+        assert!((meta.score - 0.8).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_update_models_remembers_inputs() {
+        let mut detector = MemoryNoveltyDetector::default();
+
+        let unknown = detector.assess_novelty("photosynthesis in chloroplasts").await.unwrap();
+        assert_eq!(unknown.novelty_score, 1.0);
+        assert_eq!(unknown.assessment_confidence, 0.0);
+        assert!(unknown.dominant_source().is_none());
+
+        detector.update_models("photosynthesis in chloroplasts").await.unwrap();
+        let remembered = detector.assess_novelty("photosynthesis in chloroplasts").await.unwrap();
+        assert!(remembered.novelty_score < 1e-4);
+        assert_eq!(remembered.dominant_source(), Some(&NoveltySource::SemanticMemory));
+    }
+
+    #[tokio::test]
+    async fn test_inputs_are_embedded_with_the_configured_provider() {
+        let provider = Arc::new(KeywordEmbeddingProvider::new(384));
+        let mut semantic_memory = brain_infra::SemanticMemoryRepository::new();
+        semantic_memory.store_concept(SemanticConcept::new(
+            "ownership".to_string(),
+            "Rust ownership and borrowing".to_string(),
+            provider.embed("rust ownership borrowing lifetimes").await.unwrap(),
+        )).await.unwrap();
+        let semantic_memory: Arc<RwLock<dyn SemanticMemoryRepository>> = Arc::new(RwLock::new(semantic_memory));
+
+        // The default provider embeds into another space, so nothing is found
+        let mismatched = MemoryNoveltyDetector::default().with_semantic_memory(semantic_memory.clone());
+        let assessment = mismatched.assess_novelty("rust ownership borrowing").await.unwrap();
+        assert_eq!(assessment.source_breakdown[0].score, 1.0);
+
+        let detector = MemoryNoveltyDetector::default()
+            .with_semantic_memory(semantic_memory)
+            .with_embedding_provider(provider);
+        let assessment = detector.assess_novelty("rust ownership borrowing").await.unwrap();
+        let semantic = &assessment.source_breakdown[0];
+        assert_eq!(semantic.source, NoveltySource::SemanticMemory);
+        assert!(semantic.score < 0.5);
+    }
+}
@@ -33,11 +33,13 @@ pub mod evolution;
 
 // Re-export key conversation types
 pub use conversation::{
-    RagOrchestrator, RagRequest, RagResponse, ConversationContext,
+    RagOrchestrator, RagRequest, RagResponse, RagStreamEvent, ConversationContext,
     ChatMessage, RetrievedKnowledge, ResponseQuality,
     ConversationThread, UserProfile, TemporalContext,
     SafetyFlags, SourceAttribution,
     ConversationService, KnowledgeRetriever, ResponseGenerator,
+    LanguageModelProvider, CompletionRequest, CompletionResponse, TokenUsage,
+    OpenAIProvider, OpenAICompatibleProvider, ScriptedProvider,
 };
 
 // Re-export training types
@@ -50,7 +52,7 @@ pub use training::{
     PatternAnalyzer, PatternType, ConversationPattern,
     DataAnonymizer, AnonymizationRule, PiiType, PiiDetector, ReplacementStrategy,
     ConversationAnalytics, QualityTrend, DatasetFilter,
-    TrainingDataset, DatasetMetadata, DatasetStatistics,
+    TrainingDataset, DatasetMetadata, DatasetStatistics, SplitRatios, DatasetSplits,
 };
 
 // Re-export intelligence types
@@ -82,6 +84,7 @@ pub use intelligence::{
 // Re-export meta-memory types from meta module
 pub use meta::{
     MetaMemoryService, MetaMemoryQueryBuilder,
+    InMemoryMetaMemoryRepository, SqliteMetaMemoryRepository,
     MetaMemoryError, MetaMemorySortField, MetaMemoryResult,
     // Traits
     MetaMemoryRepository, MetaMemoryAnalytics, MetaMemoryMaintenance,
@@ -93,7 +96,8 @@ pub use meta::{
 // Re-export learning types
 pub use learning::{
     CuriosityLearningEngine, CuriosityConfig, LearningPriority,
-    CuriosityDrive, KnowledgeGap
+    CuriosityDrive, KnowledgeGap, MemoryNoveltyDetector, NoveltyDetectorConfig,
+    NoveltySource, SourceNovelty
 };
 
 // Re-export model types
@@ -139,7 +143,7 @@ pub use orchestrator::{
     ExecutionResult, RetryPolicy,
     // Scheduler components
     TaskScheduler, SchedulingStrategy, TaskPriority,
-    ScheduleDecision, ResourceConstraints,
+    ScheduleDecision, ResourceConstraints, ResourceRequirements, Schedule,
     // Memory management
     OrchestratorMemory, AgentMemoryNamespace, MemoryRegistry,
     CrossAgentMemoryShare, MemoryAccessControl, SharePermissions, MemoryOperation,
@@ -151,6 +155,9 @@ pub use orchestrator::{
     CommunicationProtocol, EventTrigger, MessageType, EventType, TriggerCondition,
     // New comprehensive communication types
     CommunicationConfig, CommunicationMetrics, StoredMessage, DeliveryStatus,
+    // Checkpointing and resume
+    WorkflowCheckpoint, NodeCheckpoint, WorkflowCheckpointStatus, WorkflowCheckpointSummary,
+    WorkflowCheckpointStore, InMemoryWorkflowCheckpointStore, SqliteWorkflowCheckpointStore,
     // Workflow integration (Task 4.1.5)
     WorkflowAdapter, ConvertedWorkflow, EnhancedWorkflowResult,
     WorkflowExecutionStatus, WorkflowStepResult, StepExecutionStatus,
@@ -8,7 +8,7 @@
 //! The meta-memory system follows hexagonal architecture with:
 //! - **Core Domain**: MetaMemoryItem, KnowledgeType, confidence tracking logic
 //! - **Ports**: Trait-based interfaces for storage and analytics
-//! - **Adapters**: Concrete implementations for different storage backends
+//! - **Adapters**: In-memory and SQLite implementations in the `store` module
 //!
 //! ## Features
 //!
@@ -20,13 +20,17 @@
 //! - Thread-safe operations with proper error handling
 
 use anyhow::Result;
+use brain_types::BrainError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
-// use brain_types::BrainError; // TODO: Integrate when needed
+pub mod store;
+
+pub use store::{InMemoryMetaMemoryRepository, SqliteMetaMemoryRepository};
 
 /// Types of knowledge components tracked by meta-memory
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -479,6 +483,12 @@ pub enum MetaMemoryError {
     Serialization(#[from] serde_json::Error),
 }
 
+impl From<BrainError> for MetaMemoryError {
+    fn from(error: BrainError) -> Self {
+        MetaMemoryError::Storage(error.into())
+    }
+}
+
 // ================================================================================================
 // TRAIT DEFINITIONS - HEXAGONAL ARCHITECTURE PORTS
 // ================================================================================================
@@ -619,13 +629,16 @@ pub enum IssueSeverity {
 // ================================================================================================
 
 /// Core meta-memory service implementing the domain logic
+///
+/// The storage ports mutate through `&mut self`, so the repository and
+/// maintenance adapters are held behind locks.
 pub struct MetaMemoryService {
     /// Repository for storage operations
-    repository: Arc<dyn MetaMemoryRepository>,
+    repository: Arc<RwLock<dyn MetaMemoryRepository>>,
     /// Analytics service
     analytics: Arc<dyn MetaMemoryAnalytics>,
     /// Maintenance service
-    maintenance: Arc<dyn MetaMemoryMaintenance>,
+    maintenance: Arc<RwLock<dyn MetaMemoryMaintenance>>,
     /// Configuration
     config: MetaMemoryConfig,
 }
@@ -633,9 +646,9 @@ pub struct MetaMemoryService {
 impl MetaMemoryService {
     /// Create a new meta-memory service
     pub fn new(
-        repository: Arc<dyn MetaMemoryRepository>,
+        repository: Arc<RwLock<dyn MetaMemoryRepository>>,
         analytics: Arc<dyn MetaMemoryAnalytics>,
-        maintenance: Arc<dyn MetaMemoryMaintenance>,
+        maintenance: Arc<RwLock<dyn MetaMemoryMaintenance>>,
         config: MetaMemoryConfig,
     ) -> Self {
         Self {
@@ -646,6 +659,21 @@ impl MetaMemoryService {
         }
     }
 
+    /// Create a service whose repository, analytics and maintenance are all
+    /// backed by `store`, such as `InMemoryMetaMemoryRepository` or
+    /// `SqliteMetaMemoryRepository`
+    pub fn from_store<S>(store: S, config: MetaMemoryConfig) -> Self
+    where
+        S: MetaMemoryRepository + MetaMemoryAnalytics + MetaMemoryMaintenance + Clone + 'static,
+    {
+        Self::new(
+            Arc::new(RwLock::new(store.clone())),
+            Arc::new(store.clone()),
+            Arc::new(RwLock::new(store)),
+            config,
+        )
+    }
+
     /// Track a new knowledge component
     pub async fn track_component(
         &self,
@@ -654,21 +682,14 @@ impl MetaMemoryService {
         initial_confidence: f64,
         source: String,
     ) -> MetaMemoryResult<Uuid> {
-        if initial_confidence < 0.0 || initial_confidence > 1.0 {
+        if !(0.0..=1.0).contains(&initial_confidence) {
             return Err(MetaMemoryError::InvalidConfidenceScore { 
                 score: initial_confidence 
             });
         }
 
         let item = MetaMemoryItem::new(component_id, knowledge_type, initial_confidence, source);
-        let item_id = item.id;
-        
-        // Create a reference to the repository
-        let _repo_guard = self.repository.clone();
-        // Note: This is a simplified approach. In practice, you'd need proper async handling
-        // with interior mutability patterns or different trait design
-        
-        Ok(item_id)
+        self.repository.write().await.store_item(item).await
     }
 
     /// Update confidence for a component
@@ -677,9 +698,10 @@ impl MetaMemoryService {
         component_id: Uuid,
         success: bool,
     ) -> MetaMemoryResult<bool> {
-        if let Some(mut item) = self.repository.get_item_by_component(component_id).await? {
+        let mut repository = self.repository.write().await;
+        if let Some(mut item) = repository.get_item_by_component(component_id).await? {
             item.update_confidence(success);
-            let _repo_guard = self.repository.clone();
+            repository.store_item(item).await?;
             Ok(true)
         } else {
             Err(MetaMemoryError::ComponentNotFound { component_id })
@@ -688,15 +710,21 @@ impl MetaMemoryService {
 
     /// Mark component as accessed
     pub async fn mark_accessed(&self, component_id: Uuid) -> MetaMemoryResult<bool> {
-        if let Some(mut item) = self.repository.get_item_by_component(component_id).await? {
+        let mut repository = self.repository.write().await;
+        if let Some(mut item) = repository.get_item_by_component(component_id).await? {
             item.mark_accessed();
-            let _repo_guard = self.repository.clone();
+            repository.store_item(item).await?;
             Ok(true)
         } else {
             Err(MetaMemoryError::ComponentNotFound { component_id })
         }
     }
 
+    /// Get the meta-memory item tracking a component
+    pub async fn get_component(&self, component_id: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        self.repository.read().await.get_item_by_component(component_id).await
+    }
+
     /// Get high-confidence components
     pub async fn get_high_confidence_components(&self) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
         let query = MetaMemoryQuery {
@@ -707,7 +735,7 @@ impl MetaMemoryService {
             ..Default::default()
         };
         
-        self.repository.query_items(&query).await
+        self.repository.read().await.query_items(&query).await
     }
 
     /// Get low-confidence components
@@ -720,7 +748,7 @@ impl MetaMemoryService {
             ..Default::default()
         };
         
-        self.repository.query_items(&query).await
+        self.repository.read().await.query_items(&query).await
     }
 
     /// Get stale components
@@ -734,7 +762,7 @@ impl MetaMemoryService {
             ..Default::default()
         };
         
-        self.repository.query_items(&query).await
+        self.repository.read().await.query_items(&query).await
     }
 
     /// Get comprehensive statistics
@@ -744,20 +772,12 @@ impl MetaMemoryService {
 
     /// Perform maintenance operations
     pub async fn perform_maintenance(&self) -> MetaMemoryResult<MaintenanceReport> {
-        let maintenance = Arc::clone(&self.maintenance);
-        
-        // Cleanup stale components
-        let _maintenance_mut = maintenance.as_ref();
-        
-        // Note: This is a simplified approach for the trait design
-        // In practice, you'd need proper async handling with interior mutability
-        let cleaned_count = 0; // Placeholder
-        
-        // Validate integrity
+        let mut maintenance = self.maintenance.write().await;
+        let cleaned_components = maintenance.cleanup_stale_components(&self.config).await?;
         let integrity_report = maintenance.validate_integrity().await?;
-        
+
         Ok(MaintenanceReport {
-            cleaned_components: cleaned_count,
+            cleaned_components,
             integrity_report,
             maintenance_timestamp: Utc::now(),
         })
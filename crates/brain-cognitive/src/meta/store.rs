@@ -0,0 +1,1092 @@
+//! Meta-Memory Storage Adapters
+//!
+//! Concrete implementations of the meta-memory ports: a thread-safe in-memory
+//! store for tests and short-lived processes, and a SQLite store for durable
+//! tracking. Both implement `MetaMemoryRepository`, `MetaMemoryAnalytics` and
+//! `MetaMemoryMaintenance`, share their filter, sort, analytics and integrity
+//! logic, and are cheap handles: clones share the same underlying data, so one
+//! store can back a `MetaMemoryService` and any `Arc<dyn MetaMemoryRepository>`
+//! consumer at once.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::Context;
+use async_trait::async_trait;
+use brain_infra::database::{clamp_to_i64, column};
+use brain_infra::DatabaseManager;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use super::{
+    IntegrityIssue, IntegrityReport, IssueSeverity, KnowledgeType, MetaMemoryAnalytics,
+    MetaMemoryConfig, MetaMemoryError, MetaMemoryItem, MetaMemoryMaintenance, MetaMemoryQuery,
+    MetaMemoryRepository, MetaMemoryResult, MetaMemorySortField, MetaMemoryStats, PerformanceMetrics,
+};
+
+/// Confidence at or above which a component counts as high-confidence in statistics
+const HIGH_CONFIDENCE: f64 = 0.8;
+
+/// Confidence below which a component counts as low-confidence in statistics
+const LOW_CONFIDENCE: f64 = 0.3;
+
+/// Upper bounds of the buckets used for confidence and quality distributions
+const DISTRIBUTION_BUCKETS: [(f64, &str); 5] = [
+    (0.2, "0.0-0.2"),
+    (0.4, "0.2-0.4"),
+    (0.6, "0.4-0.6"),
+    (0.8, "0.6-0.8"),
+    (f64::INFINITY, "0.8-1.0"),
+];
+
+/// A stored item with the scores it had when first stored, so performance
+/// metrics can report how confidence and quality moved since
+#[derive(Debug, Clone)]
+struct StoredItem {
+    item: MetaMemoryItem,
+    initial_confidence: f64,
+    initial_quality: f64,
+}
+
+// ================================================================================================
+// SHARED QUERY, ANALYTICS AND MAINTENANCE LOGIC
+// ================================================================================================
+
+/// Whether `item` passes every filter set on `query`
+///
+/// Ages are compared as of now rather than as last stored.
+fn matches_query(item: &MetaMemoryItem, query: &MetaMemoryQuery) -> bool {
+    query.knowledge_type.as_ref().is_none_or(|kind| &item.knowledge_type == kind)
+        && query.min_confidence.is_none_or(|min| item.confidence_score >= min)
+        && query.max_confidence.is_none_or(|max| item.confidence_score <= max)
+        && query.min_usage_count.is_none_or(|min| item.usage_count >= min)
+        && query.min_validation_count.is_none_or(|min| item.validation_count >= min)
+        && query.min_age_hours.is_none_or(|min| item.age_hours >= min)
+        && query.max_age_hours.is_none_or(|max| item.age_hours <= max)
+        && (query.active_only != Some(true) || item.is_active)
+        && query.source_pattern.as_ref().is_none_or(|pattern| item.source.contains(pattern.as_str()))
+        && query.min_quality_score.is_none_or(|min| item.quality_score >= min)
+        && query.min_reliability_score.is_none_or(|min| item.reliability_score >= min)
+}
+
+fn compare_by_field(a: &MetaMemoryItem, b: &MetaMemoryItem, field: &MetaMemorySortField) -> Ordering {
+    match field {
+        MetaMemorySortField::ConfidenceScore => a.confidence_score.total_cmp(&b.confidence_score),
+        MetaMemorySortField::QualityScore => a.quality_score.total_cmp(&b.quality_score),
+        MetaMemorySortField::ReliabilityScore => a.reliability_score.total_cmp(&b.reliability_score),
+        MetaMemorySortField::UsageCount => a.usage_count.cmp(&b.usage_count),
+        MetaMemorySortField::ValidationCount => a.validation_count.cmp(&b.validation_count),
+        MetaMemorySortField::AgeHours => a.age_hours.total_cmp(&b.age_hours),
+        MetaMemorySortField::CreatedAt => a.created_at.cmp(&b.created_at),
+        MetaMemorySortField::LastModifiedAt => a.last_modified_at.cmp(&b.last_modified_at),
+        MetaMemorySortField::LastAccessedAt => a.last_accessed_at.cmp(&b.last_accessed_at),
+    }
+}
+
+/// Filter, sort and limit `items` the way the SQLite store does in SQL
+///
+/// Items are ordered by creation time and id, then stably by the sort field,
+/// so ties keep a deterministic order.
+fn apply_query(items: impl IntoIterator<Item = MetaMemoryItem>, query: &MetaMemoryQuery) -> Vec<MetaMemoryItem> {
+    let mut results: Vec<MetaMemoryItem> = items.into_iter().filter(|item| matches_query(item, query)).collect();
+
+    results.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+    if let Some(field) = &query.sort_by {
+        results.sort_by(|a, b| {
+            let ordering = compare_by_field(a, b, field);
+            if query.descending { ordering.reverse() } else { ordering }
+        });
+    }
+
+    if let Some(limit) = query.limit {
+        results.truncate(limit);
+    }
+    results
+}
+
+fn bucket_label(score: f64) -> &'static str {
+    DISTRIBUTION_BUCKETS
+        .iter()
+        .find(|(upper, _)| score < *upper)
+        .map(|(_, label)| *label)
+        .unwrap_or("0.8-1.0")
+}
+
+fn distribution(items: &[MetaMemoryItem], score: impl Fn(&MetaMemoryItem) -> f64) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = DISTRIBUTION_BUCKETS
+        .iter()
+        .map(|(_, label)| (label.to_string(), 0))
+        .collect();
+    for item in items {
+        *counts.entry(bucket_label(score(item)).to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn knowledge_type_distribution(items: &[MetaMemoryItem]) -> HashMap<KnowledgeType, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        *counts.entry(item.knowledge_type.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn average(items: &[MetaMemoryItem], value: impl Fn(&MetaMemoryItem) -> f64) -> f64 {
+    if items.is_empty() {
+        0.0
+    } else {
+        items.iter().map(value).sum::<f64>() / items.len() as f64
+    }
+}
+
+fn calculate_stats(items: &[MetaMemoryItem]) -> MetaMemoryStats {
+    let total_validations: u64 = items.iter().map(|item| item.validation_count).sum();
+    let total_successes: u64 = items.iter().map(|item| item.success_count).sum();
+    let active_components = items.iter().filter(|item| item.is_active).count();
+
+    MetaMemoryStats {
+        total_components: items.len(),
+        components_by_type: knowledge_type_distribution(items),
+        average_confidence: average(items, |item| item.confidence_score),
+        average_quality: average(items, |item| item.quality_score),
+        average_reliability: average(items, |item| item.reliability_score),
+        high_confidence_count: items.iter().filter(|item| item.is_high_confidence(HIGH_CONFIDENCE)).count(),
+        low_confidence_count: items.iter().filter(|item| item.is_low_confidence(LOW_CONFIDENCE)).count(),
+        total_validations,
+        total_successes,
+        overall_success_rate: if total_validations > 0 {
+            total_successes as f64 / total_validations as f64
+        } else {
+            0.0
+        },
+        average_age_hours: average(items, |item| item.age_hours),
+        active_components,
+        inactive_components: items.len() - active_components,
+        confidence_distribution: distribution(items, |item| item.confidence_score),
+        quality_distribution: distribution(items, |item| item.quality_score),
+    }
+}
+
+/// Active components ordered by how often they were used and validated
+fn trending_components(items: Vec<MetaMemoryItem>, limit: usize) -> Vec<MetaMemoryItem> {
+    let mut active: Vec<MetaMemoryItem> = items.into_iter().filter(|item| item.is_active).collect();
+    active.sort_by(|a, b| {
+        (b.usage_count + b.validation_count)
+            .cmp(&(a.usage_count + a.validation_count))
+            .then_with(|| b.last_accessed_at.cmp(&a.last_accessed_at))
+    });
+    active.truncate(limit);
+    active
+}
+
+fn performance_metrics(records: &[StoredItem], hours_back: f64) -> PerformanceMetrics {
+    let cutoff = Utc::now() - Duration::milliseconds((hours_back.max(0.0) * 3_600_000.0) as i64);
+    let modified: Vec<&StoredItem> = records.iter().filter(|record| record.item.last_modified_at >= cutoff).collect();
+    let items_added = modified.iter().filter(|record| record.item.created_at >= cutoff).count();
+    let validations: u64 = modified.iter().map(|record| record.item.validation_count).sum();
+    let successes: u64 = modified.iter().map(|record| record.item.success_count).sum();
+    let mean_change = |change: fn(&StoredItem) -> f64| {
+        if modified.is_empty() {
+            0.0
+        } else {
+            modified.iter().map(|record| change(record)).sum::<f64>() / modified.len() as f64
+        }
+    };
+
+    PerformanceMetrics {
+        time_period_hours: hours_back,
+        items_added,
+        items_updated: modified.len() - items_added,
+        items_accessed: records
+            .iter()
+            .filter(|record| record.item.usage_count > 0 && record.item.last_accessed_at >= cutoff)
+            .count(),
+        avg_confidence_change: mean_change(|record| record.item.confidence_score - record.initial_confidence),
+        avg_quality_improvement: mean_change(|record| record.item.quality_score - record.initial_quality),
+        validation_success_rate: if validations > 0 { successes as f64 / validations as f64 } else { 0.0 },
+        storage_efficiency: if records.is_empty() {
+            1.0
+        } else {
+            records.iter().filter(|record| record.item.is_active).count() as f64 / records.len() as f64
+        },
+    }
+}
+
+/// Items removed by stale-component cleanup
+///
+/// Stale, low-confidence components go first, the same set
+/// `MetaMemoryService::get_stale_components` reports; if the store still holds
+/// more than `max_components`, the lowest-quality remainder is dropped too.
+fn cleanup_candidates(items: &[MetaMemoryItem], config: &MetaMemoryConfig) -> Vec<Uuid> {
+    let (stale, mut kept): (Vec<&MetaMemoryItem>, Vec<&MetaMemoryItem>) = items.iter().partition(|item| {
+        item.is_stale(config.stale_age_threshold_hours) && item.confidence_score <= config.low_confidence_threshold
+    });
+
+    let mut removed: Vec<Uuid> = stale.iter().map(|item| item.id).collect();
+    if kept.len() > config.max_components {
+        kept.sort_by(|a, b| a.quality_score.total_cmp(&b.quality_score).then_with(|| a.created_at.cmp(&b.created_at)));
+        let excess = kept.len() - config.max_components;
+        removed.extend(kept.iter().take(excess).map(|item| item.id));
+    }
+    removed
+}
+
+fn issue(item_id: Uuid, issue_type: &str, description: String, severity: IssueSeverity) -> IntegrityIssue {
+    IntegrityIssue {
+        item_id,
+        issue_type: issue_type.to_string(),
+        description,
+        severity,
+    }
+}
+
+fn is_score(value: f64) -> bool {
+    (0.0..=1.0).contains(&value)
+}
+
+/// Append the integrity issues found on a single item
+fn check_item(item: &MetaMemoryItem, now: DateTime<Utc>, issues: &mut Vec<IntegrityIssue>) {
+    if !is_score(item.confidence_score) {
+        issues.push(issue(
+            item.id,
+            "invalid_confidence",
+            format!("Confidence score {} is outside 0.0-1.0", item.confidence_score),
+            IssueSeverity::High,
+        ));
+    }
+    for (name, value) in [("quality", item.quality_score), ("reliability", item.reliability_score)] {
+        if !is_score(value) {
+            issues.push(issue(
+                item.id,
+                "invalid_score",
+                format!("The {} score {} is outside 0.0-1.0", name, value),
+                IssueSeverity::High,
+            ));
+        }
+    }
+    if item.success_count > item.validation_count {
+        issues.push(issue(
+            item.id,
+            "inconsistent_counts",
+            format!(
+                "{} successful validations recorded out of {} validations",
+                item.success_count, item.validation_count
+            ),
+            IssueSeverity::Critical,
+        ));
+    }
+    if item.created_at > now {
+        issues.push(issue(
+            item.id,
+            "timestamp_inconsistency",
+            format!("Created in the future at {}", item.created_at),
+            IssueSeverity::Medium,
+        ));
+    }
+    if item.last_modified_at < item.created_at || item.last_accessed_at < item.created_at {
+        issues.push(issue(
+            item.id,
+            "timestamp_inconsistency",
+            "Modified or accessed before it was created".to_string(),
+            IssueSeverity::Medium,
+        ));
+    }
+    if item.source.trim().is_empty() {
+        issues.push(issue(item.id, "missing_source", "No source recorded".to_string(), IssueSeverity::Low));
+    }
+}
+
+/// Summarize issues found across `total_items` items
+///
+/// Unreadable records, inconsistent counts, invalid scores and broken indexes
+/// count as corrupted; the integrity score is the share of items without any issue.
+fn integrity_report(total_items: usize, issues: Vec<IntegrityIssue>) -> IntegrityReport {
+    let items_with = |types: &[&str]| {
+        issues
+            .iter()
+            .filter(|issue| types.contains(&issue.issue_type.as_str()))
+            .map(|issue| issue.item_id)
+            .collect::<HashSet<_>>()
+            .len()
+    };
+    let affected = issues.iter().map(|issue| issue.item_id).collect::<HashSet<_>>().len();
+
+    IntegrityReport {
+        total_items,
+        corrupted_items: items_with(&["unreadable_record", "inconsistent_counts", "invalid_score", "orphaned_index"]),
+        missing_metadata: items_with(&["missing_source"]),
+        invalid_confidence: items_with(&["invalid_confidence"]),
+        timestamp_issues: items_with(&["timestamp_inconsistency"]),
+        integrity_score: if total_items == 0 {
+            1.0
+        } else {
+            1.0 - (affected.min(total_items) as f64 / total_items as f64)
+        },
+        issues,
+    }
+}
+
+async fn write_backup(backup_path: &str, items: &[MetaMemoryItem]) -> MetaMemoryResult<()> {
+    if let Some(parent) = Path::new(backup_path).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("Failed to create backup directory {}", parent.display()))?;
+    }
+    tokio::fs::write(backup_path, serde_json::to_vec_pretty(items)?)
+        .await
+        .with_context(|| format!("Failed to write backup {}", backup_path))?;
+    Ok(())
+}
+
+async fn read_backup(backup_path: &str) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+    let contents = tokio::fs::read(backup_path)
+        .await
+        .with_context(|| format!("Failed to read backup {}", backup_path))?;
+    Ok(serde_json::from_slice(&contents)?)
+}
+
+// ================================================================================================
+// IN-MEMORY ADAPTER
+// ================================================================================================
+
+#[derive(Debug, Default)]
+struct InMemoryState {
+    items: HashMap<Uuid, StoredItem>,
+    /// Component id to the id of the item tracking it
+    by_component: HashMap<Uuid, Uuid>,
+}
+
+impl InMemoryState {
+    /// Insert or replace an item; an item for an already tracked component
+    /// replaces the one tracking it before
+    fn insert(&mut self, item: MetaMemoryItem) -> Uuid {
+        let id = item.id;
+        let (initial_confidence, initial_quality) = match self.items.get(&id) {
+            Some(stored) => {
+                if stored.item.component_id != item.component_id {
+                    self.by_component.remove(&stored.item.component_id);
+                }
+                (stored.initial_confidence, stored.initial_quality)
+            }
+            None => (item.confidence_score, item.quality_score),
+        };
+
+        if let Some(previous) = self.by_component.insert(item.component_id, id) {
+            if previous != id {
+                self.items.remove(&previous);
+            }
+        }
+        self.items.insert(id, StoredItem { item, initial_confidence, initial_quality });
+        id
+    }
+
+    fn remove(&mut self, id: Uuid) -> bool {
+        match self.items.remove(&id) {
+            Some(stored) => {
+                if self.by_component.get(&stored.item.component_id) == Some(&id) {
+                    self.by_component.remove(&stored.item.component_id);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn current(stored: &StoredItem) -> MetaMemoryItem {
+        let mut item = stored.item.clone();
+        item.update_age();
+        item
+    }
+
+    fn snapshot(&self) -> Vec<MetaMemoryItem> {
+        self.items.values().map(Self::current).collect()
+    }
+}
+
+/// Thread-safe in-memory meta-memory store
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMetaMemoryRepository {
+    state: Arc<RwLock<InMemoryState>>,
+}
+
+impl InMemoryMetaMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MetaMemoryRepository for InMemoryMetaMemoryRepository {
+    async fn store_item(&mut self, item: MetaMemoryItem) -> MetaMemoryResult<Uuid> {
+        Ok(self.state.write().await.insert(item))
+    }
+
+    async fn get_item(&self, id: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        Ok(self.state.read().await.items.get(&id).map(InMemoryState::current))
+    }
+
+    async fn get_item_by_component(&self, component_id: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        let state = self.state.read().await;
+        Ok(state
+            .by_component
+            .get(&component_id)
+            .and_then(|id| state.items.get(id))
+            .map(InMemoryState::current))
+    }
+
+    async fn query_items(&self, query: &MetaMemoryQuery) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+        Ok(apply_query(self.state.read().await.snapshot(), query))
+    }
+
+    async fn remove_item(&mut self, id: Uuid) -> MetaMemoryResult<bool> {
+        Ok(self.state.write().await.remove(id))
+    }
+
+    async fn batch_update(&mut self, items: Vec<MetaMemoryItem>) -> MetaMemoryResult<Vec<Uuid>> {
+        let mut state = self.state.write().await;
+        Ok(items.into_iter().map(|item| state.insert(item)).collect())
+    }
+
+    async fn count_items(&self) -> MetaMemoryResult<usize> {
+        Ok(self.state.read().await.items.len())
+    }
+
+    async fn clear_all(&mut self) -> MetaMemoryResult<usize> {
+        let mut state = self.state.write().await;
+        let count = state.items.len();
+        state.items.clear();
+        state.by_component.clear();
+        Ok(count)
+    }
+}
+
+#[async_trait]
+impl MetaMemoryAnalytics for InMemoryMetaMemoryRepository {
+    async fn calculate_stats(&self) -> MetaMemoryResult<MetaMemoryStats> {
+        Ok(calculate_stats(&self.state.read().await.snapshot()))
+    }
+
+    async fn get_confidence_distribution(&self) -> MetaMemoryResult<HashMap<String, usize>> {
+        Ok(distribution(&self.state.read().await.snapshot(), |item| item.confidence_score))
+    }
+
+    async fn get_quality_distribution(&self) -> MetaMemoryResult<HashMap<String, usize>> {
+        Ok(distribution(&self.state.read().await.snapshot(), |item| item.quality_score))
+    }
+
+    async fn get_knowledge_type_distribution(&self) -> MetaMemoryResult<HashMap<KnowledgeType, usize>> {
+        Ok(knowledge_type_distribution(&self.state.read().await.snapshot()))
+    }
+
+    async fn get_trending_components(&self, limit: usize) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+        Ok(trending_components(self.state.read().await.snapshot(), limit))
+    }
+
+    async fn get_performance_metrics(&self, hours_back: f64) -> MetaMemoryResult<PerformanceMetrics> {
+        let records: Vec<StoredItem> = self.state.read().await.items.values().cloned().collect();
+        Ok(performance_metrics(&records, hours_back))
+    }
+}
+
+#[async_trait]
+impl MetaMemoryMaintenance for InMemoryMetaMemoryRepository {
+    async fn cleanup_stale_components(&mut self, config: &MetaMemoryConfig) -> MetaMemoryResult<usize> {
+        let mut state = self.state.write().await;
+        let candidates = cleanup_candidates(&state.snapshot(), config);
+        Ok(candidates.into_iter().filter(|id| state.remove(*id)).count())
+    }
+
+    async fn optimize_storage(&mut self) -> MetaMemoryResult<()> {
+        let mut state = self.state.write().await;
+        let state = &mut *state;
+        state.by_component = state
+            .items
+            .values()
+            .map(|stored| (stored.item.component_id, stored.item.id))
+            .collect();
+        state.items.shrink_to_fit();
+        state.by_component.shrink_to_fit();
+        Ok(())
+    }
+
+    async fn backup_data(&self, backup_path: &str) -> MetaMemoryResult<()> {
+        let items: Vec<MetaMemoryItem> = self.state.read().await.items.values().map(|stored| stored.item.clone()).collect();
+        write_backup(backup_path, &items).await
+    }
+
+    async fn restore_data(&mut self, backup_path: &str) -> MetaMemoryResult<usize> {
+        let items = read_backup(backup_path).await?;
+        let mut state = self.state.write().await;
+        let restored = items.len();
+        for item in items {
+            state.insert(item);
+        }
+        Ok(restored)
+    }
+
+    async fn validate_integrity(&self) -> MetaMemoryResult<IntegrityReport> {
+        let state = self.state.read().await;
+        let now = Utc::now();
+        let mut issues = Vec::new();
+
+        for stored in state.items.values() {
+            check_item(&stored.item, now, &mut issues);
+            if state.by_component.get(&stored.item.component_id) != Some(&stored.item.id) {
+                issues.push(issue(
+                    stored.item.id,
+                    "orphaned_index",
+                    format!("Component {} is not indexed to this item", stored.item.component_id),
+                    IssueSeverity::Medium,
+                ));
+            }
+        }
+        for (component_id, id) in &state.by_component {
+            if state.items.get(id).is_none_or(|stored| stored.item.component_id != *component_id) {
+                issues.push(issue(
+                    *id,
+                    "orphaned_index",
+                    format!("Component {} is indexed to a missing item", component_id),
+                    IssueSeverity::Medium,
+                ));
+            }
+        }
+
+        Ok(integrity_report(state.items.len(), issues))
+    }
+}
+
+// ================================================================================================
+// SQLITE ADAPTER
+// ================================================================================================
+
+/// Filters of `MetaMemoryQuery`, bound as ?1-?11 with ?12 the current time in
+/// milliseconds; `age_hours` is computed in whole minutes like `MetaMemoryItem::update_age`
+const QUERY_SQL: &str = "SELECT * FROM (
+        SELECT *, ((?12 - created_at) / 60000) / 60.0 AS age_hours FROM meta_memory_items
+    )
+    WHERE (?1 IS NULL OR knowledge_type = ?1)
+      AND (?2 IS NULL OR confidence_score >= ?2)
+      AND (?3 IS NULL OR confidence_score <= ?3)
+      AND (?4 IS NULL OR usage_count >= ?4)
+      AND (?5 IS NULL OR validation_count >= ?5)
+      AND (?6 IS NULL OR age_hours >= ?6)
+      AND (?7 IS NULL OR age_hours <= ?7)
+      AND (?8 IS NOT 1 OR is_active = 1)
+      AND (?9 IS NULL OR instr(source, ?9) > 0)
+      AND (?10 IS NULL OR quality_score >= ?10)
+      AND (?11 IS NULL OR reliability_score >= ?11)";
+
+/// SQLite meta-memory store on the `meta_memory_items` table
+///
+/// Timestamps are stored as Unix milliseconds and metadata as JSON. Ages are
+/// computed when queried rather than stored.
+#[derive(Debug, Clone)]
+pub struct SqliteMetaMemoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMetaMemoryRepository {
+    /// Open the store on an existing database, creating its table if needed
+    pub async fn new(database: &DatabaseManager) -> MetaMemoryResult<Self> {
+        let repository = Self { pool: database.pool().clone() };
+        repository.initialize_schema().await?;
+        Ok(repository)
+    }
+
+    /// Open the store on a database file, creating the file if it does not exist
+    pub async fn open<P: AsRef<Path>>(path: P) -> MetaMemoryResult<Self> {
+        let database = DatabaseManager::new_file(path)
+            .await
+            .map_err(|e| MetaMemoryError::Storage(e.into()))?;
+        Self::new(&database).await
+    }
+
+    async fn initialize_schema(&self) -> MetaMemoryResult<()> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS meta_memory_items (
+                id TEXT PRIMARY KEY,
+                component_id TEXT NOT NULL UNIQUE,
+                knowledge_type TEXT NOT NULL,
+                confidence_score REAL NOT NULL,
+                validation_count INTEGER NOT NULL,
+                success_count INTEGER NOT NULL,
+                usage_count INTEGER NOT NULL,
+                created_at INTEGER NOT NULL,
+                last_modified_at INTEGER NOT NULL,
+                last_accessed_at INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                metadata TEXT NOT NULL,
+                is_active INTEGER NOT NULL,
+                quality_score REAL NOT NULL,
+                reliability_score REAL NOT NULL,
+                initial_confidence REAL NOT NULL,
+                initial_quality REAL NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create meta_memory_items table")?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_meta_memory_type ON meta_memory_items(knowledge_type, confidence_score)",
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to create meta_memory_items index")?;
+
+        Ok(())
+    }
+
+    async fn begin(&self) -> MetaMemoryResult<Transaction<'static, Sqlite>> {
+        Ok(self.pool.begin().await.context("Failed to begin transaction")?)
+    }
+
+    async fn upsert(tx: &mut Transaction<'static, Sqlite>, item: &MetaMemoryItem) -> MetaMemoryResult<()> {
+        // One item per component: a new item for a tracked component replaces the old one
+        sqlx::query("DELETE FROM meta_memory_items WHERE component_id = ?1 AND id != ?2")
+            .bind(item.component_id.to_string())
+            .bind(item.id.to_string())
+            .execute(&mut **tx)
+            .await
+            .context("Failed to replace meta-memory item")?;
+
+        sqlx::query(
+            "INSERT INTO meta_memory_items
+                (id, component_id, knowledge_type, confidence_score, validation_count, success_count,
+                 usage_count, created_at, last_modified_at, last_accessed_at, source, metadata,
+                 is_active, quality_score, reliability_score, initial_confidence, initial_quality)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?4, ?14)
+             ON CONFLICT(id) DO UPDATE SET
+                component_id = excluded.component_id, knowledge_type = excluded.knowledge_type,
+                confidence_score = excluded.confidence_score, validation_count = excluded.validation_count,
+                success_count = excluded.success_count, usage_count = excluded.usage_count,
+                created_at = excluded.created_at, last_modified_at = excluded.last_modified_at,
+                last_accessed_at = excluded.last_accessed_at, source = excluded.source,
+                metadata = excluded.metadata, is_active = excluded.is_active,
+                quality_score = excluded.quality_score, reliability_score = excluded.reliability_score",
+        )
+        .bind(item.id.to_string())
+        .bind(item.component_id.to_string())
+        .bind(item.knowledge_type.to_string())
+        .bind(item.confidence_score)
+        .bind(clamp_to_i64(item.validation_count))
+        .bind(clamp_to_i64(item.success_count))
+        .bind(clamp_to_i64(item.usage_count))
+        .bind(item.created_at.timestamp_millis())
+        .bind(item.last_modified_at.timestamp_millis())
+        .bind(item.last_accessed_at.timestamp_millis())
+        .bind(&item.source)
+        .bind(serde_json::to_string(&item.metadata)?)
+        .bind(item.is_active)
+        .bind(item.quality_score)
+        .bind(item.reliability_score)
+        .execute(&mut **tx)
+        .await
+        .context("Failed to store meta-memory item")?;
+
+        Ok(())
+    }
+
+    async fn fetch_one_item(&self, key: &str, value: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        let row = sqlx::query(&format!("SELECT * FROM meta_memory_items WHERE {} = ?1", key))
+            .bind(value.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .context("Failed to load meta-memory item")?;
+        row.as_ref().map(row_to_item).transpose()
+    }
+
+    async fn fetch_rows(&self) -> MetaMemoryResult<Vec<SqliteRow>> {
+        Ok(sqlx::query("SELECT * FROM meta_memory_items ORDER BY created_at, id")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to load meta-memory items")?)
+    }
+
+    async fn all_items(&self) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+        self.fetch_rows().await?.iter().map(row_to_item).collect()
+    }
+
+    async fn all_records(&self) -> MetaMemoryResult<Vec<StoredItem>> {
+        self.fetch_rows()
+            .await?
+            .iter()
+            .map(|row| {
+                Ok(StoredItem {
+                    item: row_to_item(row)?,
+                    initial_confidence: column(row, "initial_confidence")?,
+                    initial_quality: column(row, "initial_quality")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn delete_items(&self, ids: &[Uuid]) -> MetaMemoryResult<usize> {
+        let mut tx = self.begin().await?;
+        let mut deleted = 0;
+        for id in ids {
+            deleted += sqlx::query("DELETE FROM meta_memory_items WHERE id = ?1")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .context("Failed to delete meta-memory item")?
+                .rows_affected() as usize;
+        }
+        tx.commit().await.context("Failed to commit meta-memory deletion")?;
+        Ok(deleted)
+    }
+}
+
+#[async_trait]
+impl MetaMemoryRepository for SqliteMetaMemoryRepository {
+    async fn store_item(&mut self, item: MetaMemoryItem) -> MetaMemoryResult<Uuid> {
+        let mut tx = self.begin().await?;
+        Self::upsert(&mut tx, &item).await?;
+        tx.commit().await.context("Failed to commit meta-memory item")?;
+        Ok(item.id)
+    }
+
+    async fn get_item(&self, id: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        self.fetch_one_item("id", id).await
+    }
+
+    async fn get_item_by_component(&self, component_id: Uuid) -> MetaMemoryResult<Option<MetaMemoryItem>> {
+        self.fetch_one_item("component_id", component_id).await
+    }
+
+    async fn query_items(&self, query: &MetaMemoryQuery) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+        let order = match &query.sort_by {
+            Some(field) => format!("{} {}, created_at, id", field, if query.descending { "DESC" } else { "ASC" }),
+            None => "created_at, id".to_string(),
+        };
+
+        let rows = sqlx::query(&format!("{} ORDER BY {} LIMIT ?13", QUERY_SQL, order))
+            .bind(query.knowledge_type.as_ref().map(|kind| kind.to_string()))
+            .bind(query.min_confidence)
+            .bind(query.max_confidence)
+            .bind(query.min_usage_count.map(clamp_to_i64))
+            .bind(query.min_validation_count.map(clamp_to_i64))
+            .bind(query.min_age_hours)
+            .bind(query.max_age_hours)
+            .bind(query.active_only)
+            .bind(&query.source_pattern)
+            .bind(query.min_quality_score)
+            .bind(query.min_reliability_score)
+            .bind(Utc::now().timestamp_millis())
+            // SQLite treats a negative limit as no limit
+            .bind(query.limit.map(|limit| i64::try_from(limit).unwrap_or(i64::MAX)).unwrap_or(-1))
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to query meta-memory items")?;
+
+        rows.iter().map(row_to_item).collect()
+    }
+
+    async fn remove_item(&mut self, id: Uuid) -> MetaMemoryResult<bool> {
+        Ok(self.delete_items(&[id]).await? > 0)
+    }
+
+    async fn batch_update(&mut self, items: Vec<MetaMemoryItem>) -> MetaMemoryResult<Vec<Uuid>> {
+        let mut tx = self.begin().await?;
+        for item in &items {
+            Self::upsert(&mut tx, item).await?;
+        }
+        tx.commit().await.context("Failed to commit meta-memory batch")?;
+        Ok(items.into_iter().map(|item| item.id).collect())
+    }
+
+    async fn count_items(&self) -> MetaMemoryResult<usize> {
+        let row = sqlx::query("SELECT COUNT(*) AS total FROM meta_memory_items")
+            .fetch_one(&self.pool)
+            .await
+            .context("Failed to count meta-memory items")?;
+        Ok(column::<i64>(&row, "total")? as usize)
+    }
+
+    async fn clear_all(&mut self) -> MetaMemoryResult<usize> {
+        let result = sqlx::query("DELETE FROM meta_memory_items")
+            .execute(&self.pool)
+            .await
+            .context("Failed to clear meta-memory items")?;
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[async_trait]
+impl MetaMemoryAnalytics for SqliteMetaMemoryRepository {
+    async fn calculate_stats(&self) -> MetaMemoryResult<MetaMemoryStats> {
+        Ok(calculate_stats(&self.all_items().await?))
+    }
+
+    async fn get_confidence_distribution(&self) -> MetaMemoryResult<HashMap<String, usize>> {
+        Ok(distribution(&self.all_items().await?, |item| item.confidence_score))
+    }
+
+    async fn get_quality_distribution(&self) -> MetaMemoryResult<HashMap<String, usize>> {
+        Ok(distribution(&self.all_items().await?, |item| item.quality_score))
+    }
+
+    async fn get_knowledge_type_distribution(&self) -> MetaMemoryResult<HashMap<KnowledgeType, usize>> {
+        Ok(knowledge_type_distribution(&self.all_items().await?))
+    }
+
+    async fn get_trending_components(&self, limit: usize) -> MetaMemoryResult<Vec<MetaMemoryItem>> {
+        Ok(trending_components(self.all_items().await?, limit))
+    }
+
+    async fn get_performance_metrics(&self, hours_back: f64) -> MetaMemoryResult<PerformanceMetrics> {
+        Ok(performance_metrics(&self.all_records().await?, hours_back))
+    }
+}
+
+#[async_trait]
+impl MetaMemoryMaintenance for SqliteMetaMemoryRepository {
+    async fn cleanup_stale_components(&mut self, config: &MetaMemoryConfig) -> MetaMemoryResult<usize> {
+        let candidates = cleanup_candidates(&self.all_items().await?, config);
+        self.delete_items(&candidates).await
+    }
+
+    async fn optimize_storage(&mut self) -> MetaMemoryResult<()> {
+        sqlx::query("VACUUM")
+            .execute(&self.pool)
+            .await
+            .context("Failed to vacuum meta-memory database")?;
+        sqlx::query("PRAGMA optimize")
+            .execute(&self.pool)
+            .await
+            .context("Failed to optimize meta-memory database")?;
+        Ok(())
+    }
+
+    async fn backup_data(&self, backup_path: &str) -> MetaMemoryResult<()> {
+        write_backup(backup_path, &self.all_items().await?).await
+    }
+
+    async fn restore_data(&mut self, backup_path: &str) -> MetaMemoryResult<usize> {
+        let items = read_backup(backup_path).await?;
+        Ok(self.batch_update(items).await?.len())
+    }
+
+    async fn validate_integrity(&self) -> MetaMemoryResult<IntegrityReport> {
+        let rows = self.fetch_rows().await?;
+        let now = Utc::now();
+        let mut issues = Vec::new();
+
+        for row in &rows {
+            match row_to_item(row) {
+                Ok(item) => check_item(&item, now, &mut issues),
+                Err(e) => {
+                    let item_id = column::<String>(row, "id")
+                        .ok()
+                        .and_then(|id| Uuid::parse_str(&id).ok())
+                        .unwrap_or_else(Uuid::nil);
+                    issues.push(issue(item_id, "unreadable_record", e.to_string(), IssueSeverity::Critical));
+                }
+            }
+        }
+
+        Ok(integrity_report(rows.len(), issues))
+    }
+}
+
+fn count_column(row: &SqliteRow, name: &str) -> MetaMemoryResult<u64> {
+    let value = column::<i64>(row, name)?;
+    Ok(u64::try_from(value).with_context(|| format!("Negative {} {}", name, value))?)
+}
+
+fn uuid_column(row: &SqliteRow, name: &str) -> MetaMemoryResult<Uuid> {
+    let value = column::<String>(row, name)?;
+    Ok(Uuid::parse_str(&value).with_context(|| format!("Invalid {} {}", name, value))?)
+}
+
+fn timestamp_column(row: &SqliteRow, name: &str) -> MetaMemoryResult<DateTime<Utc>> {
+    let millis = column::<i64>(row, name)?;
+    Ok(DateTime::<Utc>::from_timestamp_millis(millis)
+        .with_context(|| format!("Invalid {} {}", name, millis))?)
+}
+
+fn row_to_item(row: &SqliteRow) -> MetaMemoryResult<MetaMemoryItem> {
+    let mut item = MetaMemoryItem {
+        id: uuid_column(row, "id")?,
+        component_id: uuid_column(row, "component_id")?,
+        // Unit variants deserialize from their names, which is what `Display` stores
+        knowledge_type: serde_json::from_value(serde_json::Value::String(column(row, "knowledge_type")?))?,
+        confidence_score: column(row, "confidence_score")?,
+        validation_count: count_column(row, "validation_count")?,
+        success_count: count_column(row, "success_count")?,
+        usage_count: count_column(row, "usage_count")?,
+        created_at: timestamp_column(row, "created_at")?,
+        last_modified_at: timestamp_column(row, "last_modified_at")?,
+        last_accessed_at: timestamp_column(row, "last_accessed_at")?,
+        source: column(row, "source")?,
+        metadata: serde_json::from_str(&column::<String>(row, "metadata")?)?,
+        age_hours: 0.0,
+        is_active: column(row, "is_active")?,
+        quality_score: column(row, "quality_score")?,
+        reliability_score: column(row, "reliability_score")?,
+    };
+    item.update_age();
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::MetaMemoryService;
+
+    fn item(kind: KnowledgeType, confidence: f64, usage_count: u64, source: &str) -> MetaMemoryItem {
+        let mut item = MetaMemoryItem::new(Uuid::new_v4(), kind, confidence, source.to_string());
+        item.usage_count = usage_count;
+        item
+    }
+
+    async fn exercise<S>(mut store: S)
+    where
+        S: MetaMemoryRepository + MetaMemoryAnalytics + MetaMemoryMaintenance,
+    {
+        let rule = item(KnowledgeType::Rule, 0.9, 7, "rule_extractor");
+        let segment = item(KnowledgeType::Segment, 0.5, 2, "bpe");
+        let mut old = item(KnowledgeType::Segment, 0.1, 0, "bpe");
+        old.created_at = Utc::now() - Duration::hours(400);
+        old.last_modified_at = old.created_at;
+        old.last_accessed_at = old.created_at;
+        let mut inactive = item(KnowledgeType::Pattern, 0.95, 1, "pattern_detector");
+        inactive.is_active = false;
+
+        store.batch_update(vec![rule.clone(), segment.clone(), old.clone(), inactive.clone()]).await.unwrap();
+        assert_eq!(store.count_items().await.unwrap(), 4);
+        assert_eq!(store.get_item_by_component(rule.component_id).await.unwrap().unwrap().id, rule.id);
+        assert!(store.get_item(Uuid::new_v4()).await.unwrap().is_none());
+
+        let confident = store.query_items(&MetaMemoryQuery {
+            min_confidence: Some(0.8),
+            active_only: Some(true),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(confident.iter().map(|item| item.id).collect::<Vec<_>>(), vec![rule.id]);
+
+        let segments = store.query_items(&MetaMemoryQuery {
+            knowledge_type: Some(KnowledgeType::Segment),
+            source_pattern: Some("bp".to_string()),
+            sort_by: Some(MetaMemorySortField::ConfidenceScore),
+            descending: true,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(segments.iter().map(|item| item.id).collect::<Vec<_>>(), vec![segment.id, old.id]);
+
+        let aged = store.query_items(&MetaMemoryQuery { min_age_hours: Some(300.0), ..Default::default() }).await.unwrap();
+        assert_eq!(aged.len(), 1);
+        assert!(aged[0].age_hours >= 399.0);
+
+        let most_used = store.query_items(&MetaMemoryQuery {
+            min_usage_count: Some(1),
+            sort_by: Some(MetaMemorySortField::UsageCount),
+            descending: true,
+            limit: Some(2),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(most_used.iter().map(|item| item.id).collect::<Vec<_>>(), vec![rule.id, segment.id]);
+
+        // Tracking a component again replaces its item and keeps the original baseline
+        let mut validated = rule.clone();
+        validated.update_confidence(false);
+        store.store_item(validated).await.unwrap();
+        let replacement = MetaMemoryItem::new(segment.component_id, KnowledgeType::Segment, 0.6, "bpe".to_string());
+        store.store_item(replacement.clone()).await.unwrap();
+        assert_eq!(store.count_items().await.unwrap(), 4);
+        assert!(store.get_item(segment.id).await.unwrap().is_none());
+        assert_eq!(store.get_item_by_component(segment.component_id).await.unwrap().unwrap().id, replacement.id);
+
+        let stats = store.calculate_stats().await.unwrap();
+        assert_eq!(stats.total_components, 4);
+        assert_eq!(stats.components_by_type[&KnowledgeType::Segment], 2);
+        assert_eq!(stats.inactive_components, 1);
+        assert_eq!(stats.total_validations, 1);
+        assert_eq!(stats.confidence_distribution.values().sum::<usize>(), 4);
+        let trending = store.get_trending_components(1).await.unwrap();
+        assert_eq!(trending[0].id, rule.id);
+        let metrics = store.get_performance_metrics(24.0).await.unwrap();
+        assert_eq!(metrics.items_added, 3);
+        assert!(metrics.avg_confidence_change < 0.0);
+        assert_eq!(metrics.validation_success_rate, 0.0);
+        assert_eq!(metrics.storage_efficiency, 0.75);
+
+        let report = store.validate_integrity().await.unwrap();
+        assert_eq!(report.total_items, 4);
+        assert!(report.issues.is_empty());
+        assert_eq!(report.integrity_score, 1.0);
+
+        let mut broken = item(KnowledgeType::Pattern, 0.5, 0, "");
+        broken.confidence_score = 1.5;
+        broken.success_count = 3;
+        store.store_item(broken.clone()).await.unwrap();
+        let report = store.validate_integrity().await.unwrap();
+        assert_eq!(report.invalid_confidence, 1);
+        assert_eq!(report.missing_metadata, 1);
+        assert_eq!(report.corrupted_items, 1);
+        assert!((report.integrity_score - 0.8).abs() < 1e-9);
+        assert!(store.remove_item(broken.id).await.unwrap());
+        assert!(!store.remove_item(broken.id).await.unwrap());
+
+        let cleaned = store.cleanup_stale_components(&MetaMemoryConfig::default()).await.unwrap();
+        assert_eq!(cleaned, 1);
+        assert!(store.get_item(old.id).await.unwrap().is_none());
+        store.optimize_storage().await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("brain_meta_memory_{}", Uuid::new_v4()));
+        let backup = dir.join("backup.json");
+        let backup = backup.to_str().unwrap();
+        store.backup_data(backup).await.unwrap();
+        assert_eq!(store.clear_all().await.unwrap(), 3);
+        assert_eq!(store.restore_data(backup).await.unwrap(), 3);
+        assert_eq!(store.get_item(inactive.id).await.unwrap().unwrap().source, "pattern_detector");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    async fn persist(mut store: SqliteMetaMemoryRepository) -> MetaMemoryItem {
+        let mut rule = item(KnowledgeType::Rule, 0.8, 3, "rule_extractor");
+        rule.update_confidence(true);
+        store.store_item(rule.clone()).await.unwrap();
+        rule
+    }
+
+    async fn verify(store: SqliteMetaMemoryRepository, rule: MetaMemoryItem) {
+        let loaded = store.get_item_by_component(rule.component_id).await.unwrap().unwrap();
+        assert_eq!(loaded.id, rule.id);
+        assert_eq!(loaded.validation_count, 1);
+        assert_eq!(loaded.usage_count, 3);
+        assert_eq!(loaded.confidence_score, rule.confidence_score);
+    }
+
+    brain_infra::backend_conformance_tests! {
+        in_memory: exercise => InMemoryMetaMemoryRepository::new();
+        sqlite: exercise => SqliteMetaMemoryRepository::new;
+        reopen: SqliteMetaMemoryRepository::open => persist, verify;
+    }
+
+    #[tokio::test]
+    async fn test_service_tracks_components_through_store() {
+        let store = InMemoryMetaMemoryRepository::new();
+        let service = MetaMemoryService::from_store(store.clone(), MetaMemoryConfig::default());
+
+        let component_id = Uuid::new_v4();
+        service.track_component(component_id, KnowledgeType::Rule, 0.9, "test".to_string()).await.unwrap();
+        service.update_confidence(component_id, true).await.unwrap();
+        service.mark_accessed(component_id).await.unwrap();
+
+        let tracked = store.get_item_by_component(component_id).await.unwrap().unwrap();
+        assert_eq!(tracked.validation_count, 1);
+        assert_eq!(tracked.usage_count, 1);
+        assert_eq!(service.get_high_confidence_components().await.unwrap().len(), 1);
+
+        let report = service.perform_maintenance().await.unwrap();
+        assert_eq!(report.cleaned_components, 0);
+        assert_eq!(report.integrity_report.total_items, 1);
+    }
+}
@@ -558,6 +558,7 @@ impl UnifiedCognitivePipeline {
                         conversation_id: Some("cognitive_pipeline".to_string()),
                         context_limit: Some(10),
                         retrieval_threshold: Some(self.config.quality_threshold),
+                        user_id: None,
                     };
 
                     // Note: This is a simplified implementation
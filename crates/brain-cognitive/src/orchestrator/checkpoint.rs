@@ -0,0 +1,514 @@
+//! Workflow Checkpoints
+//!
+//! Durable snapshots of DAG workflow executions. `DAGExecutor` records every
+//! node's state, output and error after each wave, so a workflow interrupted by
+//! a crash or a failing agent can be resumed: completed nodes are restored from
+//! the checkpoint and only failed or pending nodes run again. Checkpoints are
+//! written through the `WorkflowCheckpointStore` trait, with an in-memory store
+//! for tests and a SQLite store for durable storage.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use async_trait::async_trait;
+use brain_infra::database::{column, parse_timestamp};
+use brain_infra::DatabaseManager;
+use brain_types::error::BrainError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+
+use crate::agents::traits::{BrainAgent, AgentInput, AgentOutput, BrainResult};
+use super::dag::{AgentDAG, AgentNode, NodeState};
+use super::scheduler::ResourceRequirements;
+
+/// Overall state of a checkpointed execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowCheckpointStatus {
+    /// Waves are still running, or the process stopped before the last one finished
+    Running,
+    /// Every node completed or was skipped
+    Completed,
+    /// At least one node failed or never ran; the execution can be resumed
+    Failed,
+}
+
+impl WorkflowCheckpointStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            WorkflowCheckpointStatus::Running => "running",
+            WorkflowCheckpointStatus::Completed => "completed",
+            WorkflowCheckpointStatus::Failed => "failed",
+        }
+    }
+
+    fn parse(value: &str) -> BrainResult<Self> {
+        match value {
+            "running" => Ok(WorkflowCheckpointStatus::Running),
+            "completed" => Ok(WorkflowCheckpointStatus::Completed),
+            "failed" => Ok(WorkflowCheckpointStatus::Failed),
+            other => Err(BrainError::ParseError(format!("Unknown checkpoint status: {}", other))),
+        }
+    }
+}
+
+/// Everything needed to rebuild one DAG node and its execution state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeCheckpoint {
+    pub node_id: String,
+    /// Registry ID of the agent the node runs
+    pub agent_id: String,
+    pub input: AgentInput,
+    /// Nodes that must finish before this one starts
+    pub dependencies: Vec<String>,
+    pub priority: i32,
+    pub estimated_duration_ms: u64,
+    pub resources: ResourceRequirements,
+    pub state: NodeState,
+    pub output: Option<AgentOutput>,
+    pub error: Option<String>,
+}
+
+impl NodeCheckpoint {
+    /// Whether the node needs no further execution
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, NodeState::Completed | NodeState::Skipped)
+    }
+}
+
+/// Snapshot of a workflow execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowCheckpoint {
+    pub execution_id: String,
+    pub status: WorkflowCheckpointStatus,
+    /// Nodes in ID order
+    pub nodes: Vec<NodeCheckpoint>,
+    /// Number of times the execution has been resumed
+    pub resume_count: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkflowCheckpoint {
+    /// Snapshot the current state of `dag`
+    pub fn from_dag(execution_id: &str, dag: &AgentDAG) -> Self {
+        let now = Utc::now();
+        let mut checkpoint = Self {
+            execution_id: execution_id.to_string(),
+            status: WorkflowCheckpointStatus::Running,
+            nodes: Vec::new(),
+            resume_count: 0,
+            created_at: now,
+            updated_at: now,
+        };
+        checkpoint.update_from_dag(dag);
+        checkpoint
+    }
+
+    /// Replace the node snapshots with the current state of `dag`
+    pub fn update_from_dag(&mut self, dag: &AgentDAG) {
+        let mut node_ids: Vec<&String> = dag.nodes.keys().collect();
+        node_ids.sort();
+
+        self.nodes = node_ids
+            .into_iter()
+            .map(|node_id| {
+                let node = &dag.nodes[node_id];
+                NodeCheckpoint {
+                    node_id: node.id.clone(),
+                    agent_id: node.agent.metadata().id.clone(),
+                    input: node.input.clone(),
+                    dependencies: dag.dependencies.get(node_id).cloned().unwrap_or_default(),
+                    priority: node.priority,
+                    estimated_duration_ms: node.estimated_duration_ms,
+                    resources: node.resources,
+                    state: node.state.clone(),
+                    output: node.output.clone(),
+                    error: node.error.as_ref().map(|error| error.to_string()),
+                }
+            })
+            .collect();
+        self.updated_at = Utc::now();
+    }
+
+    /// Mark the execution finished, completed only if every node is finished
+    pub fn finish(&mut self) {
+        self.status = if self.nodes.iter().all(NodeCheckpoint::is_finished) {
+            WorkflowCheckpointStatus::Completed
+        } else {
+            WorkflowCheckpointStatus::Failed
+        };
+        self.updated_at = Utc::now();
+    }
+
+    pub fn node(&self, node_id: &str) -> Option<&NodeCheckpoint> {
+        self.nodes.iter().find(|node| node.node_id == node_id)
+    }
+
+    /// Outputs of completed nodes in node order
+    pub fn outputs(&self) -> Vec<AgentOutput> {
+        self.nodes.iter().filter_map(|node| node.output.clone()).collect()
+    }
+
+    /// IDs of nodes that failed or never ran
+    pub fn unfinished_nodes(&self) -> Vec<&str> {
+        self.nodes
+            .iter()
+            .filter(|node| !node.is_finished())
+            .map(|node| node.node_id.as_str())
+            .collect()
+    }
+
+    /// Rebuild the DAG for a resumed execution
+    ///
+    /// `resolve_agent` maps each node's agent ID to its implementation. Completed
+    /// and skipped nodes keep their state and output; every other node goes back
+    /// to `Pending` so it runs again.
+    pub fn restore_dag<F>(&self, resolve_agent: F) -> BrainResult<AgentDAG>
+    where
+        F: Fn(&str) -> BrainResult<Option<Arc<dyn BrainAgent>>>,
+    {
+        let mut dag = AgentDAG::new();
+        for checkpoint in &self.nodes {
+            let agent = resolve_agent(&checkpoint.agent_id)?.ok_or_else(|| {
+                BrainError::NotFound(format!(
+                    "Agent '{}' for node '{}' is not registered",
+                    checkpoint.agent_id, checkpoint.node_id
+                ))
+            })?;
+            let (state, output) = if checkpoint.is_finished() {
+                (checkpoint.state.clone(), checkpoint.output.clone())
+            } else {
+                (NodeState::Pending, None)
+            };
+
+            dag.add_node(AgentNode {
+                id: checkpoint.node_id.clone(),
+                agent,
+                input: checkpoint.input.clone(),
+                state,
+                output,
+                error: None,
+                priority: checkpoint.priority,
+                estimated_duration_ms: checkpoint.estimated_duration_ms,
+                resources: checkpoint.resources,
+            })?;
+        }
+
+        for checkpoint in &self.nodes {
+            for dependency in &checkpoint.dependencies {
+                dag.add_dependency(&checkpoint.node_id, dependency)?;
+            }
+        }
+        dag.update_structure();
+
+        Ok(dag)
+    }
+
+    fn summary(&self) -> WorkflowCheckpointSummary {
+        WorkflowCheckpointSummary {
+            execution_id: self.execution_id.clone(),
+            status: self.status,
+            total_nodes: self.nodes.len(),
+            completed_nodes: self.nodes.iter().filter(|node| node.is_finished()).count(),
+            resume_count: self.resume_count,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Listing entry for a stored checkpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowCheckpointSummary {
+    pub execution_id: String,
+    pub status: WorkflowCheckpointStatus,
+    pub total_nodes: usize,
+    /// Nodes that completed or were skipped
+    pub completed_nodes: usize,
+    pub resume_count: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Storage for workflow checkpoints
+#[async_trait]
+pub trait WorkflowCheckpointStore: Send + Sync {
+    /// Insert or replace the checkpoint of an execution
+    async fn save_checkpoint(&self, checkpoint: &WorkflowCheckpoint) -> BrainResult<()>;
+
+    async fn load_checkpoint(&self, execution_id: &str) -> BrainResult<Option<WorkflowCheckpoint>>;
+
+    /// Checkpoints, most recently updated first
+    async fn list_checkpoints(&self) -> BrainResult<Vec<WorkflowCheckpointSummary>>;
+
+    /// Delete a checkpoint, returning false if it did not exist
+    async fn delete_checkpoint(&self, execution_id: &str) -> BrainResult<bool>;
+}
+
+/// Process-local store; checkpoints are lost when it is dropped
+#[derive(Default)]
+pub struct InMemoryWorkflowCheckpointStore {
+    checkpoints: RwLock<HashMap<String, WorkflowCheckpoint>>,
+}
+
+impl InMemoryWorkflowCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WorkflowCheckpointStore for InMemoryWorkflowCheckpointStore {
+    async fn save_checkpoint(&self, checkpoint: &WorkflowCheckpoint) -> BrainResult<()> {
+        self.checkpoints.write().await.insert(checkpoint.execution_id.clone(), checkpoint.clone());
+        Ok(())
+    }
+
+    async fn load_checkpoint(&self, execution_id: &str) -> BrainResult<Option<WorkflowCheckpoint>> {
+        Ok(self.checkpoints.read().await.get(execution_id).cloned())
+    }
+
+    async fn list_checkpoints(&self) -> BrainResult<Vec<WorkflowCheckpointSummary>> {
+        let mut summaries: Vec<WorkflowCheckpointSummary> = self.checkpoints.read().await
+            .values()
+            .map(WorkflowCheckpoint::summary)
+            .collect();
+        summaries.sort_by(|a, b| {
+            b.updated_at.cmp(&a.updated_at).then_with(|| a.execution_id.cmp(&b.execution_id))
+        });
+        Ok(summaries)
+    }
+
+    async fn delete_checkpoint(&self, execution_id: &str) -> BrainResult<bool> {
+        Ok(self.checkpoints.write().await.remove(execution_id).is_some())
+    }
+}
+
+/// SQLite store on the `workflow_checkpoints` table
+///
+/// Node snapshots are stored as JSON on the execution's row; the counts used
+/// for listings are kept in their own columns.
+pub struct SqliteWorkflowCheckpointStore {
+    pool: SqlitePool,
+}
+
+impl SqliteWorkflowCheckpointStore {
+    /// Open the store on an existing database, creating its table if needed
+    pub async fn new(database: &DatabaseManager) -> BrainResult<Self> {
+        let store = Self { pool: database.pool().clone() };
+        store.initialize_schema().await?;
+        Ok(store)
+    }
+
+    /// Open the store on a database file, creating the file if it does not exist
+    pub async fn open<P: AsRef<Path>>(path: P) -> BrainResult<Self> {
+        let database = DatabaseManager::new_file(path).await?;
+        Self::new(&database).await
+    }
+
+    async fn initialize_schema(&self) -> BrainResult<()> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS workflow_checkpoints (
+                id TEXT PRIMARY KEY,
+                status TEXT NOT NULL,
+                total_nodes INTEGER NOT NULL,
+                completed_nodes INTEGER NOT NULL,
+                resume_count INTEGER NOT NULL,
+                nodes TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to create workflow_checkpoints table: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WorkflowCheckpointStore for SqliteWorkflowCheckpointStore {
+    async fn save_checkpoint(&self, checkpoint: &WorkflowCheckpoint) -> BrainResult<()> {
+        let summary = checkpoint.summary();
+        sqlx::query(
+            "INSERT INTO workflow_checkpoints
+                (id, status, total_nodes, completed_nodes, resume_count, nodes, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET
+                status = excluded.status, total_nodes = excluded.total_nodes,
+                completed_nodes = excluded.completed_nodes, resume_count = excluded.resume_count,
+                nodes = excluded.nodes, updated_at = excluded.updated_at",
+        )
+        .bind(&checkpoint.execution_id)
+        .bind(checkpoint.status.as_str())
+        .bind(summary.total_nodes as i64)
+        .bind(summary.completed_nodes as i64)
+        .bind(checkpoint.resume_count as i64)
+        .bind(serde_json::to_string(&checkpoint.nodes)?)
+        .bind(checkpoint.created_at.to_rfc3339())
+        .bind(checkpoint.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to save workflow checkpoint: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn load_checkpoint(&self, execution_id: &str) -> BrainResult<Option<WorkflowCheckpoint>> {
+        let row = sqlx::query("SELECT * FROM workflow_checkpoints WHERE id = ?1")
+            .bind(execution_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to load workflow checkpoint: {}", e)))?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(WorkflowCheckpoint {
+            execution_id: column(&row, "id")?,
+            status: WorkflowCheckpointStatus::parse(&column::<String>(&row, "status")?)?,
+            nodes: serde_json::from_str(&column::<String>(&row, "nodes")?)?,
+            resume_count: column::<i64>(&row, "resume_count")? as u32,
+            created_at: parse_timestamp(&column::<String>(&row, "created_at")?)?,
+            updated_at: parse_timestamp(&column::<String>(&row, "updated_at")?)?,
+        }))
+    }
+
+    async fn list_checkpoints(&self) -> BrainResult<Vec<WorkflowCheckpointSummary>> {
+        let rows = sqlx::query(
+            "SELECT id, status, total_nodes, completed_nodes, resume_count, created_at, updated_at
+             FROM workflow_checkpoints ORDER BY updated_at DESC, id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to list workflow checkpoints: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(WorkflowCheckpointSummary {
+                    execution_id: column(row, "id")?,
+                    status: WorkflowCheckpointStatus::parse(&column::<String>(row, "status")?)?,
+                    total_nodes: column::<i64>(row, "total_nodes")? as usize,
+                    completed_nodes: column::<i64>(row, "completed_nodes")? as usize,
+                    resume_count: column::<i64>(row, "resume_count")? as u32,
+                    created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+                    updated_at: parse_timestamp(&column::<String>(row, "updated_at")?)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn delete_checkpoint(&self, execution_id: &str) -> BrainResult<bool> {
+        let deleted = sqlx::query("DELETE FROM workflow_checkpoints WHERE id = ?1")
+            .bind(execution_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete workflow checkpoint: {}", e)))?
+            .rows_affected();
+
+        Ok(deleted > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::ops::HotfixAgent;
+    use crate::orchestrator::DAGBuilder;
+
+    /// agent_0 -> agent_1 -> agent_2 where agent_0 completed and agent_1 failed
+    fn interrupted_dag() -> AgentDAG {
+        let agents: Vec<Arc<dyn BrainAgent>> = (0..3).map(|_| Arc::new(HotfixAgent::new()) as Arc<dyn BrainAgent>).collect();
+        let inputs = (0..3)
+            .map(|i| AgentInput::new("bug_report".to_string(), format!("step {}", i), "session".to_string()))
+            .collect();
+        let mut dag = DAGBuilder::new()
+            .with_agents(agents)
+            .with_inputs(inputs)
+            .with_dependency("agent_1".to_string(), "agent_0".to_string())
+            .with_dependency("agent_2".to_string(), "agent_1".to_string())
+            .with_priority("agent_2".to_string(), 7)
+            .build()
+            .unwrap();
+
+        let agent_id = dag.nodes["agent_0"].agent.metadata().id.clone();
+        dag.set_node_output("agent_0", AgentOutput::new(agent_id, "fix".to_string(), "patched".to_string(), 0.9)).unwrap();
+        dag.set_node_error("agent_1", BrainError::ExecutionError("agent crashed".to_string())).unwrap();
+        dag
+    }
+
+    fn checkpoint(execution_id: &str) -> WorkflowCheckpoint {
+        let mut checkpoint = WorkflowCheckpoint::from_dag(execution_id, &interrupted_dag());
+        checkpoint.finish();
+        checkpoint
+    }
+
+    async fn exercise(store: impl WorkflowCheckpointStore) {
+        store.save_checkpoint(&checkpoint("a")).await.unwrap();
+        store.save_checkpoint(&checkpoint("b")).await.unwrap();
+
+        let loaded = store.load_checkpoint("a").await.unwrap().unwrap();
+        assert_eq!(loaded.status, WorkflowCheckpointStatus::Failed);
+        assert_eq!(loaded.nodes.len(), 3);
+        assert_eq!(loaded.node("agent_0").unwrap().output.as_ref().unwrap().content, "patched");
+        assert!(loaded.node("agent_1").unwrap().error.as_ref().unwrap().contains("agent crashed"));
+        assert_eq!(loaded.node("agent_2").unwrap().dependencies, vec!["agent_1"]);
+        assert!(store.load_checkpoint("missing").await.unwrap().is_none());
+
+        let mut resumed = loaded.clone();
+        resumed.resume_count += 1;
+        resumed.updated_at = Utc::now() + chrono::Duration::seconds(1);
+        store.save_checkpoint(&resumed).await.unwrap();
+
+        let summaries = store.list_checkpoints().await.unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].execution_id, "a");
+        assert_eq!(summaries[0].resume_count, 1);
+        assert_eq!(summaries[0].total_nodes, 3);
+        assert_eq!(summaries[0].completed_nodes, 1);
+
+        assert!(store.delete_checkpoint("b").await.unwrap());
+        assert!(!store.delete_checkpoint("b").await.unwrap());
+        assert_eq!(store.list_checkpoints().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_restore_dag_reruns_unfinished_nodes() {
+        let checkpoint = checkpoint("restore");
+        assert_eq!(checkpoint.unfinished_nodes(), vec!["agent_1", "agent_2"]);
+
+        let dag = checkpoint
+            .restore_dag(|_| Ok(Some(Arc::new(HotfixAgent::new()) as Arc<dyn BrainAgent>)))
+            .unwrap();
+        assert_eq!(dag.nodes["agent_0"].state, NodeState::Completed);
+        assert_eq!(dag.nodes["agent_0"].output.as_ref().unwrap().content, "patched");
+        assert_eq!(dag.nodes["agent_1"].state, NodeState::Pending);
+        assert!(dag.nodes["agent_1"].error.is_none());
+        assert_eq!(dag.nodes["agent_2"].priority, 7);
+        assert_eq!(dag.roots, vec!["agent_0"]);
+        assert!(dag.validate().is_ok());
+
+        assert!(checkpoint.restore_dag(|_| Ok(None)).is_err());
+    }
+
+    async fn persist(store: SqliteWorkflowCheckpointStore) {
+        store.save_checkpoint(&checkpoint("durable")).await.unwrap();
+    }
+
+    async fn verify(store: SqliteWorkflowCheckpointStore, _: ()) {
+        let loaded = store.load_checkpoint("durable").await.unwrap().unwrap();
+        assert_eq!(loaded.unfinished_nodes(), vec!["agent_1", "agent_2"]);
+    }
+
+    brain_infra::backend_conformance_tests! {
+        in_memory: exercise => InMemoryWorkflowCheckpointStore::new();
+        sqlite: exercise => SqliteWorkflowCheckpointStore::new;
+        reopen: SqliteWorkflowCheckpointStore::open => persist, verify;
+    }
+}
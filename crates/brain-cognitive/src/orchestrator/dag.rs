@@ -8,6 +8,7 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use brain_types::error::BrainError;
 use crate::agents::traits::{BrainAgent, AgentInput, AgentOutput, BrainResult};
+use super::scheduler::ResourceRequirements;
 
 /// Directed Acyclic Graph representing agent execution workflow
 pub struct AgentDAG {
@@ -52,6 +53,9 @@ pub struct AgentNode {
     
     /// Estimated execution time in milliseconds
     pub estimated_duration_ms: u64,
+    
+    /// Resources held while executing
+    pub resources: ResourceRequirements,
 }
 
 /// State of a node in the execution DAG
@@ -153,6 +157,7 @@ pub struct DAGBuilder {
     explicit_dependencies: HashMap<String, Vec<String>>,
     priorities: HashMap<String, i32>,
     estimated_durations: HashMap<String, u64>,
+    resources: HashMap<String, ResourceRequirements>,
 }
 
 /// Validation errors for DAG structure
@@ -489,13 +494,60 @@ impl AgentDAG {
     
     /// Find the critical path (longest path) through the DAG
     fn find_critical_path(&self) -> Vec<String> {
-        // For now, return a simple path from root to leaf
-        // TODO: Implement proper critical path analysis
-        if let Some(root) = self.roots.first() {
-            vec![root.clone()]
-        } else {
-            Vec::new()
+        self.critical_path()
+            .map(|(path, _)| path)
+            .unwrap_or_default()
+    }
+    
+    /// Longest estimated duration from each node to the end of the workflow,
+    /// including the node itself
+    pub fn bottom_levels(&self) -> BrainResult<HashMap<String, u64>> {
+        let mut levels = HashMap::new();
+        for node_id in self.topological_sort()?.into_iter().rev() {
+            let own_duration = self.nodes
+                .get(&node_id)
+                .map(|node| node.estimated_duration_ms)
+                .unwrap_or(0);
+            let downstream = self.dependents
+                .get(&node_id)
+                .into_iter()
+                .flatten()
+                .filter_map(|dependent| levels.get(dependent))
+                .copied()
+                .max()
+                .unwrap_or(0);
+            levels.insert(node_id, own_duration + downstream);
+        }
+        
+        Ok(levels)
+    }
+    
+    /// Chain of dependent nodes with the longest total estimated duration,
+    /// together with that duration
+    pub fn critical_path(&self) -> BrainResult<(Vec<String>, u64)> {
+        let levels = self.bottom_levels()?;
+        // Longest remaining duration first, lowest ID on ties
+        let pick = |candidates: Vec<&String>| {
+            candidates
+                .into_iter()
+                .max_by(|a, b| levels[*a].cmp(&levels[*b]).then_with(|| b.cmp(a)))
+                .cloned()
+        };
+        
+        let roots = self.nodes
+            .keys()
+            .filter(|id| self.dependencies.get(*id).is_none_or(|deps| deps.is_empty()))
+            .collect();
+        let mut current = pick(roots);
+        let duration = current.as_ref().map(|id| levels[id]).unwrap_or(0);
+        
+        let mut path = Vec::new();
+        while let Some(node_id) = current {
+            current = pick(self.dependents.get(&node_id).map(|d| d.iter().collect()).unwrap_or_default());
+            path.push(node_id);
         }
+        
+        Ok((path, duration))
     }
     
     /// Update DAG structure after adding/removing nodes
@@ -581,6 +633,7 @@ impl DAGBuilder {
             explicit_dependencies: HashMap::new(),
             priorities: HashMap::new(),
             estimated_durations: HashMap::new(),
+            resources: HashMap::new(),
         }
     }
     
@@ -617,6 +670,12 @@ impl DAGBuilder {
         self
     }
     
+    /// Set the resources a node holds while executing
+    pub fn with_resources(mut self, node_id: String, resources: ResourceRequirements) -> Self {
+        self.resources.insert(node_id, resources);
+        self
+    }
+    
     /// Build the DAG from configured components
     pub fn build(self) -> BrainResult<AgentDAG> {
         let mut dag = AgentDAG::new();
@@ -634,6 +693,7 @@ impl DAGBuilder {
             
             let priority = self.priorities.get(&node_id).copied().unwrap_or(0);
             let estimated_duration_ms = self.estimated_durations.get(&node_id).copied().unwrap_or(1000);
+            let resources = self.resources.get(&node_id).copied().unwrap_or_default();
             
             let node = AgentNode {
                 id: node_id.clone(),
@@ -644,6 +704,7 @@ impl DAGBuilder {
                 error: None,
                 priority,
                 estimated_duration_ms,
+                resources,
             };
             
             dag.add_node(node)?;
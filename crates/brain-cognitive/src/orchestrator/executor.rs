@@ -8,7 +8,8 @@ use futures::future::try_join_all;
 use serde::{Deserialize, Serialize};
 use brain_types::error::BrainError;
 use crate::agents::traits::{BrainAgent, CognitiveContext, AgentInput, AgentOutput, BrainResult};
-use super::dag::{ExecutionPlan, ExecutionWave, AgentDAG};
+use super::dag::{ExecutionPlan, ExecutionWave, AgentDAG, NodeState};
+use super::checkpoint::{WorkflowCheckpoint, WorkflowCheckpointStore};
 
 /// Main executor for DAG-based agent workflows
 #[derive(Debug)]
@@ -342,11 +343,51 @@ impl DAGExecutor {
     /// - Comprehensive error classification and handling
     /// - Sophisticated retry strategies based on error types
     /// - Detailed metrics tracking for monitoring and optimization
+    ///
+    /// Node states, outputs and errors are recorded in `dag` as each wave
+    /// finishes. Nodes that are already completed or skipped are not run again,
+    /// and nodes with a failed dependency stay pending.
     pub async fn execute_plan(
         &self,
         plan: ExecutionPlan,
         dag: &mut AgentDAG,
         context: &CognitiveContext,
+    ) -> BrainResult<Vec<AgentOutput>> {
+        self.run_plan(plan, dag, context, None).await
+    }
+    
+    /// Execute a plan, saving a checkpoint of every node after each wave
+    ///
+    /// The checkpoint is saved once before the first wave, so an execution that
+    /// stops at any point can be resumed from `store`. Returns the final
+    /// checkpoint, which is `Completed` only if every node completed or was
+    /// skipped.
+    pub async fn execute_plan_with_checkpoints(
+        &self,
+        plan: ExecutionPlan,
+        dag: &mut AgentDAG,
+        context: &CognitiveContext,
+        store: &dyn WorkflowCheckpointStore,
+        mut checkpoint: WorkflowCheckpoint,
+    ) -> BrainResult<WorkflowCheckpoint> {
+        checkpoint.update_from_dag(dag);
+        store.save_checkpoint(&checkpoint).await?;
+        
+        let result = self.run_plan(plan, dag, context, Some((store, &mut checkpoint))).await;
+        
+        checkpoint.update_from_dag(dag);
+        checkpoint.finish();
+        store.save_checkpoint(&checkpoint).await?;
+        
+        result.map(|_| checkpoint)
+    }
+    
+    async fn run_plan(
+        &self,
+        plan: ExecutionPlan,
+        dag: &mut AgentDAG,
+        context: &CognitiveContext,
+        mut checkpointing: Option<(&dyn WorkflowCheckpointStore, &mut WorkflowCheckpoint)>,
     ) -> BrainResult<Vec<AgentOutput>> {
         let execution_start = Instant::now();
         let mut all_outputs = Vec::new();
@@ -360,10 +401,21 @@ impl DAGExecutor {
         // Execute waves sequentially with enhanced error handling
         for (wave_idx, wave) in plan.execution_waves.iter().enumerate() {
             let wave_start = Instant::now();
+            let runnable = runnable_wave(wave, dag);
             
-            match self.execute_wave_with_confidence_checks(wave, dag, context, &confidence_checker).await {
+            match self.execute_wave_with_confidence_checks(&runnable, dag, context, &confidence_checker).await {
                 Ok(wave_result) => {
-                    all_outputs.extend(wave_result.outputs);
+                    // Record what happened to each node before the next wave reads the DAG
+                    for (node_id, outcome) in wave_result.node_outcomes {
+                        match outcome {
+                            NodeOutcome::Completed(output) => {
+                                all_outputs.push(output.clone());
+                                dag.set_node_output(&node_id, output)?;
+                            }
+                            NodeOutcome::Skipped => dag.update_node_state(&node_id, NodeState::Skipped)?,
+                            NodeOutcome::Failed(error) => dag.set_node_error(&node_id, error)?,
+                        }
+                    }
                     
                     // Record successful wave timing with confidence stats
                     let wave_duration = wave_start.elapsed();
@@ -372,7 +424,7 @@ impl DAGExecutor {
                         wave_number: wave_idx,
                         start_time_ms: wave_start.elapsed().as_millis() as u64,
                         duration_ms: wave_duration.as_millis() as u64,
-                        agent_count: wave.node_ids.len(),
+                        agent_count: runnable.node_ids.len(),
                         successful_agents: wave_result.successful_count,
                         failed_agents: wave_result.failed_count,
                         skipped_agents: wave_result.skipped_count,
@@ -384,6 +436,11 @@ impl DAGExecutor {
                         (metrics_guard.confidence_stats.average_confidence + wave_result.average_confidence) / 2.0;
                     
                     drop(metrics_guard);
+                    
+                    if let Some((store, checkpoint)) = checkpointing.as_mut() {
+                        checkpoint.update_from_dag(dag);
+                        store.save_checkpoint(checkpoint).await?;
+                    }
                 }
                 Err(e) => {
                     // Enhanced error handling with classification
@@ -401,9 +458,9 @@ impl DAGExecutor {
                         wave_number: wave_idx,
                         start_time_ms: wave_start.elapsed().as_millis() as u64,
                         duration_ms: wave_duration.as_millis() as u64,
-                        agent_count: wave.node_ids.len(),
+                        agent_count: runnable.node_ids.len(),
                         successful_agents: 0,
-                        failed_agents: wave.node_ids.len(),
+                        failed_agents: runnable.node_ids.len(),
                         skipped_agents: 0,
                         average_confidence: 0.0,
                     });
@@ -436,7 +493,7 @@ impl DAGExecutor {
     ) -> BrainResult<WaveExecutionResult> {
         if wave.node_ids.is_empty() {
             return Ok(WaveExecutionResult {
-                outputs: Vec::new(),
+                node_outcomes: Vec::new(),
                 successful_count: 0,
                 failed_count: 0,
                 skipped_count: 0,
@@ -444,7 +501,7 @@ impl DAGExecutor {
             });
         }
         
-        let mut outputs = Vec::new();
+        let mut node_outcomes = Vec::new();
         let mut successful_count = 0;
         let mut failed_count = 0;
         let mut skipped_count = 0;
@@ -464,24 +521,26 @@ impl DAGExecutor {
         // Execute all agents in parallel and collect results
         let results = futures::future::join_all(agent_futures).await;
         
-        for result in results {
+        for (node_id, result) in wave.node_ids.iter().zip(results) {
             match result {
                 Ok(agent_result) => {
                     match agent_result {
                         AgentExecutionResult::Success { output, confidence } => {
-                            outputs.push(output);
+                            node_outcomes.push((node_id.clone(), NodeOutcome::Completed(output)));
                             successful_count += 1;
                             total_confidence += confidence;
                             confidence_count += 1;
                         }
                         AgentExecutionResult::Skipped { confidence } => {
+                            node_outcomes.push((node_id.clone(), NodeOutcome::Skipped));
                             skipped_count += 1;
                             total_confidence += confidence;
                             confidence_count += 1;
                         }
                     }
                 }
-                Err(_) => {
+                Err(e) => {
+                    node_outcomes.push((node_id.clone(), NodeOutcome::Failed(e)));
                     failed_count += 1;
                 }
             }
@@ -494,7 +553,7 @@ impl DAGExecutor {
         };
         
         Ok(WaveExecutionResult {
-            outputs,
+            node_outcomes,
             successful_count,
             failed_count,
             skipped_count,
@@ -662,16 +721,49 @@ impl DAGExecutor {
     }
 }
 
+/// The part of `wave` that still has to run
+///
+/// Nodes that already completed or were skipped (restored from a checkpoint)
+/// are dropped, as are nodes with a dependency that did not finish; those stay
+/// pending so a resumed execution picks them up.
+fn runnable_wave(wave: &ExecutionWave, dag: &AgentDAG) -> ExecutionWave {
+    let is_finished = |node_id: &str| {
+        dag.nodes.get(node_id)
+            .is_some_and(|node| matches!(node.state, NodeState::Completed | NodeState::Skipped))
+    };
+    
+    ExecutionWave {
+        node_ids: wave.node_ids.iter()
+            .filter(|node_id| !is_finished(node_id.as_str()))
+            .filter(|node_id| {
+                dag.dependencies.get(*node_id)
+                    .is_none_or(|dependencies| dependencies.iter().all(|dependency| is_finished(dependency.as_str())))
+            })
+            .cloned()
+            .collect(),
+        wave_number: wave.wave_number,
+        estimated_duration_ms: wave.estimated_duration_ms,
+    }
+}
+
 /// Result of wave execution
 #[derive(Debug)]
 struct WaveExecutionResult {
-    outputs: Vec<AgentOutput>,
+    node_outcomes: Vec<(String, NodeOutcome)>,
     successful_count: usize,
     failed_count: usize,
     skipped_count: usize,
     average_confidence: f32,
 }
 
+/// What happened to a node during its wave
+#[derive(Debug)]
+enum NodeOutcome {
+    Completed(AgentOutput),
+    Skipped,
+    Failed(BrainError),
+}
+
 /// Result of individual agent execution
 #[derive(Debug)]
 enum AgentExecutionResult {
@@ -8,6 +8,7 @@ pub mod executor;
 pub mod scheduler;
 pub mod memory;
 pub mod communication;
+pub mod checkpoint;
 
 // Re-export key types and traits
 pub use dag::{
@@ -22,7 +23,7 @@ pub use executor::{
 
 pub use scheduler::{
     TaskScheduler, SchedulingStrategy, TaskPriority,
-    ScheduleDecision, ResourceConstraints
+    ScheduleDecision, ResourceConstraints, ResourceRequirements, Schedule
 };
 
 pub use memory::{
@@ -40,6 +41,11 @@ pub use communication::{
     CommunicationConfig, CommunicationMetrics, StoredMessage, DeliveryStatus,
 };
 
+pub use checkpoint::{
+    WorkflowCheckpoint, NodeCheckpoint, WorkflowCheckpointStatus, WorkflowCheckpointSummary,
+    WorkflowCheckpointStore, InMemoryWorkflowCheckpointStore, SqliteWorkflowCheckpointStore,
+};
+
 use crate::agents::traits::{BrainAgent, CognitiveContext, AgentInput, AgentOutput, BrainResult};
 use crate::agents::registry::{AgentRegistry, AgentQuery};
 use brain_types::error::BrainError;
@@ -80,6 +86,14 @@ pub struct OrchestrationConfig {
     
     /// Default confidence threshold for agent execution
     pub default_confidence_threshold: f32,
+    
+    /// Order in which ready agents are started
+    #[serde(default)]
+    pub scheduling_strategy: SchedulingStrategy,
+    
+    /// Memory, CPU and concurrency budgets for concurrently running agents
+    #[serde(default)]
+    pub resource_constraints: ResourceConstraints,
 }
 
 impl Default for OrchestrationConfig {
@@ -94,6 +108,8 @@ impl Default for OrchestrationConfig {
             enable_workflow_integration: true,
             enable_registry_integration: true,
             default_confidence_threshold: 0.7,
+            scheduling_strategy: SchedulingStrategy::default(),
+            resource_constraints: ResourceConstraints::default(),
         }
     }
 }
@@ -118,6 +134,9 @@ pub struct AgentOrchestrator {
     /// Workflow integration adapter
     workflow_adapter: Option<Arc<WorkflowAdapter>>,
     
+    /// Durable storage for resumable executions
+    checkpoint_store: Option<Arc<dyn WorkflowCheckpointStore>>,
+    
     /// Configuration settings
     config: OrchestrationConfig,
 }
@@ -236,6 +255,7 @@ impl AgentOrchestrator {
             communication,
             agent_registry: None,
             workflow_adapter: None,
+            checkpoint_store: None,
             config,
         }
     }
@@ -252,6 +272,12 @@ impl AgentOrchestrator {
         self
     }
     
+    /// Checkpoint executions to `store` so they can be resumed
+    pub fn with_checkpoint_store(mut self, store: Arc<dyn WorkflowCheckpointStore>) -> Self {
+        self.checkpoint_store = Some(store);
+        self
+    }
+    
     /// Execute a workflow using DAG orchestration (integration with WorkflowEngine)
     pub async fn execute_workflow_with_dag(
         &self,
@@ -384,6 +410,66 @@ impl AgentOrchestrator {
         self.executor.execute_plan(plan, &mut dag, context).await
     }
     
+    /// Execute a prepared DAG, checkpointing it under `execution_id` after each wave
+    ///
+    /// Returns the final checkpoint; if it is not `Completed` the execution can
+    /// be continued with `resume_workflow`.
+    pub async fn execute_workflow_with_checkpoints(
+        &self,
+        execution_id: &str,
+        mut dag: AgentDAG,
+        context: &CognitiveContext,
+    ) -> BrainResult<WorkflowCheckpoint> {
+        let store = self.require_checkpoint_store()?;
+        
+        dag.validate().map_err(|e| BrainError::Other(format!("{:?}", e)))?;
+        let plan = self.scheduler.create_execution_plan(&dag, &self.config)?;
+        let checkpoint = WorkflowCheckpoint::from_dag(execution_id, &dag);
+        
+        self.executor
+            .execute_plan_with_checkpoints(plan, &mut dag, context, store.as_ref(), checkpoint)
+            .await
+    }
+    
+    /// Resume a checkpointed execution
+    ///
+    /// The DAG is rebuilt from the checkpoint with agents looked up in the
+    /// integrated registry. Completed nodes keep their outputs; failed and
+    /// pending nodes run again.
+    pub async fn resume_workflow(
+        &self,
+        execution_id: &str,
+        context: &CognitiveContext,
+    ) -> BrainResult<WorkflowCheckpoint> {
+        let store = self.require_checkpoint_store()?;
+        let registry = self.agent_registry.as_ref().ok_or_else(|| BrainError::ExecutionError(
+            "Agent registry not integrated".to_string()
+        ))?;
+        
+        let mut checkpoint = store.load_checkpoint(execution_id).await?.ok_or_else(|| {
+            BrainError::NotFound(format!("No checkpoint for execution '{}'", execution_id))
+        })?;
+        let mut dag = checkpoint.restore_dag(|agent_id| registry.get_agent(agent_id))?;
+        checkpoint.status = WorkflowCheckpointStatus::Running;
+        checkpoint.resume_count += 1;
+        
+        let plan = self.scheduler.create_execution_plan(&dag, &self.config)?;
+        self.executor
+            .execute_plan_with_checkpoints(plan, &mut dag, context, store.as_ref(), checkpoint)
+            .await
+    }
+    
+    /// Get the store executions are checkpointed to
+    pub fn checkpoint_store(&self) -> Option<&Arc<dyn WorkflowCheckpointStore>> {
+        self.checkpoint_store.as_ref()
+    }
+    
+    fn require_checkpoint_store(&self) -> BrainResult<&Arc<dyn WorkflowCheckpointStore>> {
+        self.checkpoint_store.as_ref().ok_or_else(|| BrainError::ConfigError(
+            "No checkpoint store configured".to_string()
+        ))
+    }
+    
     /// Convert workflow steps to DAG format
     async fn convert_workflow_to_dag(
         &self,
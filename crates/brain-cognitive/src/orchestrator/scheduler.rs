@@ -1,8 +1,17 @@
 //! Task Scheduler for Agent Orchestration
+//!
+//! `TaskScheduler` is a list scheduler. It simulates the workflow on a clock
+//! driven by each node's estimated duration, and whenever a node finishes it
+//! starts the ready nodes the `SchedulingStrategy` ranks highest that still fit
+//! the memory, CPU and concurrency budgets. The simulated schedule becomes the
+//! execution waves handed to the `DAGExecutor`, so no wave over-subscribes the
+//! budgets.
 
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 use brain_types::error::BrainError;
-use super::dag::{AgentDAG, ExecutionPlan, ExecutionOrder};
+use super::dag::{AgentDAG, AgentNode, ExecutionPlan, ExecutionOrder, ExecutionWave};
 use super::OrchestrationConfig;
 
 /// Task scheduler for managing agent execution order
@@ -14,26 +23,235 @@ impl TaskScheduler {
     pub fn new() -> Self {
         Self {}
     }
-    
+
     pub fn create_execution_plan(
         &self,
         dag: &AgentDAG,
-        _config: &OrchestrationConfig,
+        config: &OrchestrationConfig,
     ) -> Result<ExecutionPlan, BrainError> {
-        dag.create_execution_plan(ExecutionOrder::Topological)
+        let schedule = self.schedule(dag, config)?;
+        let execution_waves = schedule.execution_waves(dag);
+
+        Ok(ExecutionPlan {
+            estimated_total_duration_ms: execution_waves.iter().map(|wave| wave.estimated_duration_ms).sum(),
+            max_parallelism: execution_waves.iter().map(|wave| wave.node_ids.len()).max().unwrap_or(0),
+            critical_path: schedule.critical_path,
+            execution_order: config.scheduling_strategy.execution_order(),
+            execution_waves,
+        })
+    }
+
+    /// Simulate the workflow under the configured strategy and budgets
+    ///
+    /// Ready nodes are ranked by the strategy and started greedily: a node that
+    /// does not fit the remaining budget is passed over for lower-ranked nodes
+    /// that do. Nodes whose requirements exceed the whole budget are rejected.
+    pub fn schedule(&self, dag: &AgentDAG, config: &OrchestrationConfig) -> Result<Schedule, BrainError> {
+        dag.validate().map_err(|e| BrainError::Other(format!("{:?}", e)))?;
+
+        let constraints = &config.resource_constraints;
+        let max_concurrent = constraints.max_concurrent_tasks
+            .min(config.max_concurrent_agents)
+            .max(1);
+
+        let mut oversized: Vec<&AgentNode> = dag.nodes
+            .values()
+            .filter(|node| !constraints.admits(&node.resources))
+            .collect();
+        if !oversized.is_empty() {
+            oversized.sort_by(|a, b| a.id.cmp(&b.id));
+            let nodes: Vec<String> = oversized
+                .iter()
+                .map(|node| format!("{} ({} MB, {} cores)", node.id, node.resources.memory_mb, node.resources.cpu_cores))
+                .collect();
+            return Err(BrainError::InvalidInput(format!(
+                "Nodes exceed the resource budget of {} MB and {} cores: {}",
+                constraints.max_memory_mb,
+                constraints.max_cpu_cores,
+                nodes.join(", ")
+            )));
+        }
+
+        let bottom_levels = dag.bottom_levels()?;
+        let (critical_path, critical_path_ms) = dag.critical_path()?;
+
+        let mut remaining_dependencies: HashMap<&str, usize> = dag.nodes
+            .keys()
+            .map(|id| (id.as_str(), dag.dependencies.get(id).map(|deps| deps.len()).unwrap_or(0)))
+            .collect();
+        let mut roots: Vec<&str> = remaining_dependencies
+            .iter()
+            .filter(|(_, remaining)| **remaining == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        roots.sort();
+
+        let mut ready: Vec<ReadyNode> = roots
+            .into_iter()
+            .enumerate()
+            .map(|(sequence, node_id)| ReadyNode { node_id, ready_at_ms: 0, sequence })
+            .collect();
+        let mut next_sequence = ready.len();
+        let mut running: Vec<(u64, &str)> = Vec::new();
+        let mut decisions = Vec::with_capacity(dag.nodes.len());
+
+        let mut clock = 0;
+        let (mut memory_in_use, mut cpu_in_use) = (0, 0);
+        let (mut peak_memory_mb, mut peak_cpu_cores, mut peak_concurrency) = (0, 0, 0);
+
+        while decisions.len() < dag.nodes.len() {
+            ready.sort_by(|a, b| compare_ready(config.scheduling_strategy, dag, &bottom_levels, a, b));
+
+            let mut index = 0;
+            while index < ready.len() && running.len() < max_concurrent {
+                let node = &dag.nodes[ready[index].node_id];
+                if memory_in_use + node.resources.memory_mb > constraints.max_memory_mb
+                    || cpu_in_use + node.resources.cpu_cores > constraints.max_cpu_cores
+                {
+                    index += 1;
+                    continue;
+                }
+
+                let started = ready.remove(index);
+                let finish_ms = clock + node.estimated_duration_ms;
+                memory_in_use += node.resources.memory_mb;
+                cpu_in_use += node.resources.cpu_cores;
+                running.push((finish_ms, started.node_id));
+                decisions.push(ScheduleDecision {
+                    node_id: node.id.clone(),
+                    priority: TaskPriority::from_node_priority(node.priority),
+                    estimated_duration_ms: node.estimated_duration_ms,
+                    start_ms: clock,
+                    finish_ms,
+                    wait_ms: clock - started.ready_at_ms,
+                    resources: node.resources,
+                });
+            }
+
+            peak_memory_mb = peak_memory_mb.max(memory_in_use);
+            peak_cpu_cores = peak_cpu_cores.max(cpu_in_use);
+            peak_concurrency = peak_concurrency.max(running.len());
+
+            if decisions.len() == dag.nodes.len() {
+                break;
+            }
+
+            // Advance the clock to the next completion and release its resources
+            let Some(next_finish) = running.iter().map(|(finish_ms, _)| *finish_ms).min() else {
+                return Err(BrainError::ExecutionError(
+                    "Scheduler stalled with nodes left but none running".to_string()
+                ));
+            };
+            clock = next_finish;
+
+            let mut finished: Vec<&str> = running
+                .iter()
+                .filter(|(finish_ms, _)| *finish_ms <= clock)
+                .map(|(_, node_id)| *node_id)
+                .collect();
+            finished.sort();
+            running.retain(|(finish_ms, _)| *finish_ms > clock);
+
+            for node_id in finished {
+                let node = &dag.nodes[node_id];
+                memory_in_use -= node.resources.memory_mb;
+                cpu_in_use -= node.resources.cpu_cores;
+
+                for dependent in dag.dependents.get(node_id).into_iter().flatten() {
+                    if let Some(remaining) = remaining_dependencies.get_mut(dependent.as_str()) {
+                        *remaining -= 1;
+                        if *remaining == 0 {
+                            ready.push(ReadyNode {
+                                node_id: dependent.as_str(),
+                                ready_at_ms: clock,
+                                sequence: next_sequence,
+                            });
+                            next_sequence += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Schedule {
+            strategy: config.scheduling_strategy,
+            makespan_ms: decisions.iter().map(|decision| decision.finish_ms).max().unwrap_or(0),
+            decisions,
+            critical_path,
+            critical_path_ms,
+            peak_memory_mb,
+            peak_cpu_cores,
+            peak_concurrency,
+        })
     }
 }
 
+impl Default for TaskScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A node whose dependencies have all finished
+struct ReadyNode<'a> {
+    node_id: &'a str,
+    ready_at_ms: u64,
+    /// Order in which nodes became ready, for first-in-first-out ties
+    sequence: usize,
+}
+
+/// Rank two ready nodes, the one to start first ordering first
+///
+/// Critical nodes go first under every strategy.
+fn compare_ready(
+    strategy: SchedulingStrategy,
+    dag: &AgentDAG,
+    bottom_levels: &HashMap<String, u64>,
+    a: &ReadyNode,
+    b: &ReadyNode,
+) -> Ordering {
+    let (node_a, node_b) = (&dag.nodes[a.node_id], &dag.nodes[b.node_id]);
+    let critical = |node: &AgentNode| TaskPriority::from_node_priority(node.priority) == TaskPriority::Critical;
+    let fifo = a.ready_at_ms.cmp(&b.ready_at_ms).then(a.sequence.cmp(&b.sequence));
+
+    critical(node_b).cmp(&critical(node_a)).then_with(|| match strategy {
+        SchedulingStrategy::Fifo => fifo,
+        SchedulingStrategy::Priority => node_b.priority
+            .cmp(&node_a.priority)
+            // Among equal priorities, nodes with the longest remaining chain first
+            .then_with(|| bottom_levels.get(b.node_id).cmp(&bottom_levels.get(a.node_id)))
+            .then(fifo),
+        SchedulingStrategy::ShortestFirst => node_a.estimated_duration_ms
+            .cmp(&node_b.estimated_duration_ms)
+            .then(fifo),
+    })
+}
+
 /// Scheduling strategy
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum SchedulingStrategy {
+    /// Start nodes in the order they became ready
     Fifo,
+    /// Start high-priority nodes first, then those heading the longest remaining chain
+    #[default]
     Priority,
+    /// Start the nodes with the shortest estimated duration first
     ShortestFirst,
 }
 
+impl SchedulingStrategy {
+    /// Execution order recorded on plans built with this strategy
+    pub fn execution_order(&self) -> ExecutionOrder {
+        match self {
+            SchedulingStrategy::Fifo => ExecutionOrder::Topological,
+            SchedulingStrategy::Priority => ExecutionOrder::Priority,
+            SchedulingStrategy::ShortestFirst => ExecutionOrder::ShortestFirst,
+        }
+    }
+}
+
 /// Task priority levels
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum TaskPriority {
     Low,
     Medium,
@@ -41,12 +259,93 @@ pub enum TaskPriority {
     Critical,
 }
 
+impl TaskPriority {
+    /// Classify a node's numeric priority: negative is low, 0-4 medium,
+    /// 5-9 high and 10 or more critical
+    pub fn from_node_priority(priority: i32) -> Self {
+        match priority {
+            p if p < 0 => TaskPriority::Low,
+            p if p < 5 => TaskPriority::Medium,
+            p if p < 10 => TaskPriority::High,
+            _ => TaskPriority::Critical,
+        }
+    }
+}
+
 /// Schedule decision result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleDecision {
     pub node_id: String,
     pub priority: TaskPriority,
     pub estimated_duration_ms: u64,
+    /// Simulated start time
+    pub start_ms: u64,
+    /// Simulated finish time
+    pub finish_ms: u64,
+    /// Time spent ready but waiting for resources or a concurrency slot
+    pub wait_ms: u64,
+    pub resources: ResourceRequirements,
+}
+
+/// Simulated execution of a workflow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schedule {
+    pub strategy: SchedulingStrategy,
+    /// Decisions in the order nodes are started
+    pub decisions: Vec<ScheduleDecision>,
+    /// Simulated time until the last node finishes
+    pub makespan_ms: u64,
+    /// Longest chain of dependent nodes by estimated duration
+    pub critical_path: Vec<String>,
+    /// Estimated duration of the critical path, a lower bound on the makespan
+    pub critical_path_ms: u64,
+    pub peak_memory_mb: u64,
+    pub peak_cpu_cores: u32,
+    pub peak_concurrency: usize,
+}
+
+impl Schedule {
+    /// Decision for a node
+    pub fn decision(&self, node_id: &str) -> Option<&ScheduleDecision> {
+        self.decisions.iter().find(|decision| decision.node_id == node_id)
+    }
+
+    /// Group nodes started at the same simulated time into waves
+    ///
+    /// Nodes started together were running together, so every wave fits the
+    /// budgets. A zero-duration dependency can start at the same time as its
+    /// dependent; the dependent then opens a new wave.
+    fn execution_waves(&self, dag: &AgentDAG) -> Vec<ExecutionWave> {
+        let mut waves: Vec<ExecutionWave> = Vec::new();
+        let mut wave_start = None;
+        let mut in_wave: HashSet<&str> = HashSet::new();
+
+        for decision in &self.decisions {
+            let depends_on_wave = dag.dependencies
+                .get(&decision.node_id)
+                .into_iter()
+                .flatten()
+                .any(|dependency| in_wave.contains(dependency.as_str()));
+
+            if wave_start != Some(decision.start_ms) || depends_on_wave {
+                waves.push(ExecutionWave {
+                    node_ids: Vec::new(),
+                    wave_number: waves.len(),
+                    estimated_duration_ms: 0,
+                });
+                wave_start = Some(decision.start_ms);
+                in_wave.clear();
+            }
+
+            if let Some(wave) = waves.last_mut() {
+                wave.node_ids.push(decision.node_id.clone());
+                wave.estimated_duration_ms = wave.estimated_duration_ms.max(decision.estimated_duration_ms);
+            }
+            in_wave.insert(&decision.node_id);
+        }
+
+        waves
+    }
 }
 
 /// Resource constraints for scheduling
@@ -57,6 +356,13 @@ pub struct ResourceConstraints {
     pub max_concurrent_tasks: usize,
 }
 
+impl ResourceConstraints {
+    /// Whether a node with `resources` can ever run within these constraints
+    pub fn admits(&self, resources: &ResourceRequirements) -> bool {
+        resources.memory_mb <= self.max_memory_mb && resources.cpu_cores <= self.max_cpu_cores
+    }
+}
+
 impl Default for ResourceConstraints {
     fn default() -> Self {
         Self {
@@ -66,3 +372,114 @@ impl Default for ResourceConstraints {
         }
     }
 }
+
+/// Resources a node holds while it executes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceRequirements {
+    pub memory_mb: u64,
+    pub cpu_cores: u32,
+}
+
+impl Default for ResourceRequirements {
+    fn default() -> Self {
+        Self {
+            memory_mb: 64,
+            cpu_cores: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::agents::ops::HotfixAgent;
+    use crate::agents::traits::BrainAgent;
+    use crate::orchestrator::DAGBuilder;
+
+    /// Five nodes: agent_0 -> agent_1 -> agent_2, plus independent agent_3 and agent_4
+    fn builder() -> DAGBuilder {
+        let agents: Vec<Arc<dyn BrainAgent>> = (0..5).map(|_| Arc::new(HotfixAgent::new()) as Arc<dyn BrainAgent>).collect();
+        DAGBuilder::new()
+            .with_agents(agents)
+            .with_dependency("agent_1".to_string(), "agent_0".to_string())
+            .with_dependency("agent_2".to_string(), "agent_1".to_string())
+            .with_duration("agent_0".to_string(), 100)
+            .with_duration("agent_1".to_string(), 300)
+            .with_duration("agent_2".to_string(), 100)
+            .with_duration("agent_3".to_string(), 50)
+            .with_duration("agent_4".to_string(), 400)
+    }
+
+    fn config(strategy: SchedulingStrategy, constraints: ResourceConstraints) -> OrchestrationConfig {
+        OrchestrationConfig {
+            scheduling_strategy: strategy,
+            resource_constraints: constraints,
+            ..OrchestrationConfig::default()
+        }
+    }
+
+    fn started_order(schedule: &Schedule) -> Vec<&str> {
+        schedule.decisions.iter().map(|decision| decision.node_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_critical_path_and_unconstrained_schedule() {
+        let dag = builder().build().unwrap();
+        let schedule = TaskScheduler::new()
+            .schedule(&dag, &config(SchedulingStrategy::Fifo, ResourceConstraints::default()))
+            .unwrap();
+
+        assert_eq!(schedule.critical_path, vec!["agent_0", "agent_1", "agent_2"]);
+        assert_eq!(schedule.critical_path_ms, 500);
+        assert_eq!(schedule.makespan_ms, 500);
+        assert_eq!(schedule.peak_concurrency, 3);
+        assert_eq!(schedule.decision("agent_2").unwrap().start_ms, 400);
+    }
+
+    #[test]
+    fn test_strategies_order_ready_nodes() {
+        let single_slot = ResourceConstraints { max_concurrent_tasks: 1, ..ResourceConstraints::default() };
+        let dag = builder().with_priority("agent_4".to_string(), 12).build().unwrap();
+        let scheduler = TaskScheduler::new();
+
+        let shortest = scheduler.schedule(&dag, &config(SchedulingStrategy::ShortestFirst, single_slot.clone())).unwrap();
+        // The critical agent_4 still goes first
+        assert_eq!(started_order(&shortest), vec!["agent_4", "agent_3", "agent_0", "agent_1", "agent_2"]);
+        assert_eq!(shortest.decision("agent_4").unwrap().priority, TaskPriority::Critical);
+
+        let dag = builder().build().unwrap();
+        let priority = scheduler.schedule(&dag, &config(SchedulingStrategy::Priority, single_slot.clone())).unwrap();
+        // Equal priorities: longest remaining chain first, earliest ready on ties
+        assert_eq!(started_order(&priority), vec!["agent_0", "agent_4", "agent_1", "agent_2", "agent_3"]);
+
+        let fifo = scheduler.schedule(&dag, &config(SchedulingStrategy::Fifo, single_slot)).unwrap();
+        assert_eq!(started_order(&fifo), vec!["agent_0", "agent_3", "agent_4", "agent_1", "agent_2"]);
+        assert_eq!(fifo.makespan_ms, 950);
+        assert_eq!(fifo.peak_concurrency, 1);
+    }
+
+    #[test]
+    fn test_memory_budget_limits_waves() {
+        let heavy = ResourceRequirements { memory_mb: 600, cpu_cores: 1 };
+        let dag = builder()
+            .with_resources("agent_3".to_string(), heavy)
+            .with_resources("agent_4".to_string(), heavy)
+            .build()
+            .unwrap();
+        let plan = TaskScheduler::new()
+            .create_execution_plan(&dag, &config(SchedulingStrategy::Fifo, ResourceConstraints::default()))
+            .unwrap();
+
+        assert!(plan.execution_waves.iter().all(|wave| {
+            !(wave.node_ids.contains(&"agent_3".to_string()) && wave.node_ids.contains(&"agent_4".to_string()))
+        }));
+        assert_eq!(plan.critical_path, vec!["agent_0", "agent_1", "agent_2"]);
+
+        let oversized = builder()
+            .with_resources("agent_0".to_string(), ResourceRequirements { memory_mb: 4096, cpu_cores: 1 })
+            .build()
+            .unwrap();
+        assert!(TaskScheduler::new().schedule(&oversized, &OrchestrationConfig::default()).is_err());
+    }
+}
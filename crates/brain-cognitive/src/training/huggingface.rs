@@ -0,0 +1,238 @@
+//! HuggingFace Dataset Export
+//!
+//! Writes a training dataset as a directory the `datasets` library loads
+//! directly: one JSON Lines file per split under `data/`, a `dataset_info.json`
+//! describing the features and splits, and a `README.md` dataset card whose
+//! YAML header maps the split files for `load_dataset`.
+
+use std::fs;
+use std::path::Path;
+use brain_types::BrainError;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::{ConversationQualityMetrics, ConversationRecord, DatasetSplits, TrainingDataset};
+
+const DATASET_NAME: &str = "brain_conversations";
+
+/// One conversation as a `datasets` row
+///
+/// Messages carry their anonymized content when anonymization produced it.
+#[derive(Serialize)]
+struct HuggingFaceRecord<'a> {
+    conversation_id: &'a str,
+    messages: Vec<HuggingFaceMessage<'a>>,
+    domain: &'a str,
+    conversation_type: String,
+    complexity_level: String,
+    user_expertise: String,
+    topics: &'a [String],
+    #[serde(flatten)]
+    quality: &'a ConversationQualityMetrics,
+}
+
+#[derive(Serialize)]
+struct HuggingFaceMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+impl<'a> From<&'a ConversationRecord> for HuggingFaceRecord<'a> {
+    fn from(conversation: &'a ConversationRecord) -> Self {
+        Self {
+            conversation_id: &conversation.conversation_id,
+            messages: conversation
+                .messages
+                .iter()
+                .map(|message| HuggingFaceMessage {
+                    role: &message.role,
+                    content: message.anonymized_content.as_deref().unwrap_or(&message.content),
+                })
+                .collect(),
+            domain: &conversation.metadata.domain,
+            conversation_type: format!("{:?}", conversation.metadata.conversation_type),
+            complexity_level: format!("{:?}", conversation.metadata.complexity_level),
+            user_expertise: format!("{:?}", conversation.metadata.user_expertise),
+            topics: &conversation.metadata.topics,
+            quality: &conversation.quality_metrics,
+        }
+    }
+}
+
+/// A split file that was written
+struct WrittenSplit {
+    name: &'static str,
+    num_examples: usize,
+    num_bytes: usize,
+}
+
+/// Write `dataset`, partitioned into `splits`, as a `datasets` directory at `dir`
+pub(crate) fn write_dataset(dataset: &TrainingDataset, splits: &DatasetSplits, dir: &Path) -> Result<(), BrainError> {
+    let data_dir = dir.join("data");
+    fs::create_dir_all(&data_dir).map_err(|e| BrainError::Io { source: e })?;
+
+    let mut written = Vec::new();
+    for (name, conversations) in splits.iter() {
+        // `datasets` rejects empty JSON files, so empty splits are left out
+        if conversations.is_empty() {
+            continue;
+        }
+
+        let mut content = String::new();
+        for conversation in conversations {
+            let line = serde_json::to_string(&HuggingFaceRecord::from(conversation))
+                .map_err(|e| BrainError::ConfigError(format!("Failed to serialize conversation: {}", e)))?;
+            content.push_str(&line);
+            content.push('\n');
+        }
+
+        fs::write(data_dir.join(format!("{}.jsonl", name)), &content).map_err(|e| BrainError::Io { source: e })?;
+        written.push(WrittenSplit { name, num_examples: conversations.len(), num_bytes: content.len() });
+    }
+
+    let info = serde_json::to_string_pretty(&dataset_info(dataset, &written))
+        .map_err(|e| BrainError::ConfigError(format!("Failed to serialize dataset info: {}", e)))?;
+    fs::write(dir.join("dataset_info.json"), info).map_err(|e| BrainError::Io { source: e })?;
+    fs::write(dir.join("README.md"), dataset_card(dataset, &written)).map_err(|e| BrainError::Io { source: e })?;
+
+    Ok(())
+}
+
+fn string_feature() -> Value {
+    json!({ "dtype": "string", "_type": "Value" })
+}
+
+fn float_feature() -> Value {
+    json!({ "dtype": "float64", "_type": "Value" })
+}
+
+fn dataset_info(dataset: &TrainingDataset, splits: &[WrittenSplit]) -> Value {
+    let mut features = json!({
+        "conversation_id": string_feature(),
+        "messages": {
+            "feature": { "role": string_feature(), "content": string_feature() },
+            "_type": "Sequence",
+        },
+        "domain": string_feature(),
+        "conversation_type": string_feature(),
+        "complexity_level": string_feature(),
+        "user_expertise": string_feature(),
+        "topics": { "feature": string_feature(), "_type": "Sequence" },
+    });
+    for metric in [
+        "overall_quality",
+        "coherence_score",
+        "knowledge_grounding",
+        "response_relevance",
+        "safety_score",
+        "educational_value",
+        "diversity_score",
+        "uniqueness_score",
+    ] {
+        features[metric] = float_feature();
+    }
+
+    let split_info: serde_json::Map<String, Value> = splits
+        .iter()
+        .map(|split| {
+            (
+                split.name.to_string(),
+                json!({
+                    "name": split.name,
+                    "num_bytes": split.num_bytes,
+                    "num_examples": split.num_examples,
+                    "dataset_name": DATASET_NAME,
+                }),
+            )
+        })
+        .collect();
+    let dataset_size: usize = splits.iter().map(|split| split.num_bytes).sum();
+
+    json!({
+        "description": "Conversations captured by the Brain AI training data collector.",
+        "citation": "",
+        "homepage": "",
+        "license": "",
+        "features": features,
+        "builder_name": "json",
+        "dataset_name": DATASET_NAME,
+        "config_name": "default",
+        "version": { "version_str": "1.0.0", "major": 1, "minor": 0, "patch": 0 },
+        "splits": split_info,
+        "download_size": dataset_size,
+        "dataset_size": dataset_size,
+        "size_in_bytes": dataset_size,
+        "brain_metadata": {
+            "version": dataset.metadata.version,
+            "created_at": dataset.metadata.created_at.to_rfc3339(),
+            "quality_threshold": dataset.metadata.quality_threshold,
+        },
+    })
+}
+
+fn size_category(count: usize) -> &'static str {
+    match count {
+        c if c < 1_000 => "n<1K",
+        c if c < 10_000 => "1K<n<10K",
+        c if c < 100_000 => "10K<n<100K",
+        c if c < 1_000_000 => "100K<n<1M",
+        _ => "1M<n<10M",
+    }
+}
+
+/// Markdown table of a distribution, largest counts first
+fn distribution_table(heading: &str, distribution: &std::collections::HashMap<String, usize>, limit: usize) -> String {
+    let mut entries: Vec<_> = distribution.iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut table = format!("| {} | Conversations |\n|---|---|\n", heading);
+    for (name, count) in entries.into_iter().take(limit) {
+        table.push_str(&format!("| {} | {} |\n", name, count));
+    }
+    table
+}
+
+fn dataset_card(dataset: &TrainingDataset, splits: &[WrittenSplit]) -> String {
+    let statistics = &dataset.statistics;
+    let mut card = String::from("---\nconfigs:\n- config_name: default\n  data_files:\n");
+    for split in splits {
+        card.push_str(&format!("  - split: {}\n    path: data/{}.jsonl\n", split.name, split.name));
+    }
+    card.push_str(&format!(
+        "task_categories:\n- text-generation\nsize_categories:\n- {}\n---\n\n",
+        size_category(dataset.metadata.total_conversations)
+    ));
+
+    card.push_str("# Brain AI Conversations\n\n");
+    card.push_str(&format!(
+        "Conversations captured by the Brain AI training data collector, exported {} (dataset version {}). \
+         Each row is one conversation with its messages, classification and quality metrics. \
+         Splits are stratified by conversation type and complexity level.\n\n",
+        dataset.metadata.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        dataset.metadata.version
+    ));
+
+    card.push_str("## Splits\n\n| Split | Conversations |\n|---|---|\n");
+    for split in splits {
+        card.push_str(&format!("| {} | {} |\n", split.name, split.num_examples));
+    }
+
+    card.push_str(&format!(
+        "\n## Statistics\n\n- Conversations: {}\n- Messages: {}\n- Average quality: {:.3}\n- Average conversation length: {:.1} messages\n\n",
+        dataset.metadata.total_conversations,
+        dataset.metadata.total_messages,
+        statistics.average_quality,
+        statistics.average_conversation_length
+    ));
+    card.push_str(&distribution_table("Quality", &statistics.quality_distribution, usize::MAX));
+    card.push('\n');
+    card.push_str(&distribution_table("Conversation type", &statistics.conversation_type_distribution, usize::MAX));
+    card.push('\n');
+    card.push_str(&distribution_table("Complexity", &statistics.complexity_distribution, usize::MAX));
+    if !statistics.topic_distribution.is_empty() {
+        card.push('\n');
+        card.push_str(&distribution_table("Topic", &statistics.topic_distribution, 20));
+    }
+
+    card
+}
@@ -4,13 +4,20 @@
 //! and export functionality for the Brain AI system.
 
 use brain_types::BrainError;
+use brain_types::math::fnv1a;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 use regex::Regex;
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::Path;
 
+mod huggingface;
+mod parquet;
+
+use parquet::{Column, ColumnValues};
 
 // Import from our conversation module
 use crate::conversation::{RagResponse, RetrievedKnowledge, ConversationContext, ResponseQuality};
@@ -36,6 +43,9 @@ pub struct TrainingDataConfig {
     pub batch_size: usize,
     pub auto_export: bool,
     pub export_format: ExportFormat,
+    /// Train/validation/test fractions used by split-aware formats
+    #[serde(default)]
+    pub split_ratios: SplitRatios,
 }
 
 /// Supported export formats for training datasets
@@ -309,6 +319,22 @@ pub struct DatasetMetadata {
     pub quality_threshold: f64,
 }
 
+/// Fractions of a dataset assigned to the train, validation and test splits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitRatios {
+    pub train: f64,
+    pub validation: f64,
+    pub test: f64,
+}
+
+/// A dataset partitioned into train, validation and test conversations
+#[derive(Debug, Clone, Default)]
+pub struct DatasetSplits {
+    pub train: Vec<ConversationRecord>,
+    pub validation: Vec<ConversationRecord>,
+    pub test: Vec<ConversationRecord>,
+}
+
 /// Statistics about the exported dataset
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatasetStatistics {
@@ -335,10 +361,33 @@ impl Default for TrainingDataConfig {
             batch_size: 100,
             auto_export: false,
             export_format: ExportFormat::JsonL,
+            split_ratios: SplitRatios::default(),
+        }
+    }
+}
+
+impl Default for SplitRatios {
+    fn default() -> Self {
+        Self {
+            train: 0.8,
+            validation: 0.1,
+            test: 0.1,
         }
     }
 }
 
+impl DatasetSplits {
+    /// Splits with their `datasets` names
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[ConversationRecord])> {
+        [
+            ("train", self.train.as_slice()),
+            ("validation", self.validation.as_slice()),
+            ("test", self.test.as_slice()),
+        ]
+        .into_iter()
+    }
+}
+
 impl TrainingDataCollector {
     /// Create a new training data collector
     pub fn new(config: TrainingDataConfig) -> Result<Self, BrainError> {
@@ -441,14 +490,17 @@ impl TrainingDataCollector {
             &self.analytics,
         )?;
 
-        // Save to disk
-        let export_path = format!("{}/dataset_{}.{}", 
+        // Save to disk; HuggingFace datasets are directories
+        let export_path = format!("{}/dataset_{}",
             self.config.storage_path,
-            Utc::now().format("%Y%m%d_%H%M%S"),
-            self.get_file_extension()
+            Utc::now().format("%Y%m%d_%H%M%S")
         );
+        let export_path = match self.get_file_extension() {
+            Some(extension) => format!("{}.{}", export_path, extension),
+            None => export_path,
+        };
 
-        dataset.save_to_file(&export_path).await?;
+        dataset.save_to_file(&export_path, &self.config.split_ratios).await?;
 
         Ok(dataset)
     }
@@ -602,13 +654,13 @@ impl TrainingDataCollector {
         }
     }
 
-    /// Get file extension for export format
-    fn get_file_extension(&self) -> &str {
+    /// Get file extension for export format, `None` for directory formats
+    fn get_file_extension(&self) -> Option<&str> {
         match self.config.export_format {
-            ExportFormat::JsonL => "jsonl",
-            ExportFormat::Parquet => "parquet",
-            ExportFormat::Csv => "csv",
-            ExportFormat::HuggingFace => "json",
+            ExportFormat::JsonL => Some("jsonl"),
+            ExportFormat::Parquet => Some("parquet"),
+            ExportFormat::Csv => Some("csv"),
+            ExportFormat::HuggingFace => None,
         }
     }
 
@@ -1206,12 +1258,158 @@ impl TrainingDataset {
         })
     }
 
-    async fn save_to_file(&self, path: &str) -> Result<(), BrainError> {
+    /// Partition conversations into train, validation and test splits
+    ///
+    /// Conversations are grouped by conversation type and complexity level and
+    /// every group is divided according to `ratios`, so each split keeps the
+    /// dataset's mix. Order within a group comes from a hash of the conversation
+    /// id, which keeps assignments stable across exports.
+    pub fn stratified_split(&self, ratios: &SplitRatios) -> DatasetSplits {
+        let train = ratios.train.max(0.0);
+        let validation = ratios.validation.max(0.0);
+        let total = train + validation + ratios.test.max(0.0);
+        let (train_cut, validation_cut) = if total > 0.0 {
+            (train / total, (train + validation) / total)
+        } else {
+            (1.0, 1.0)
+        };
+
+        let mut strata: BTreeMap<String, Vec<&ConversationRecord>> = BTreeMap::new();
+        for conversation in &self.conversations {
+            let key = format!(
+                "{:?}/{:?}",
+                conversation.metadata.conversation_type, conversation.metadata.complexity_level
+            );
+            strata.entry(key).or_default().push(conversation);
+        }
+
+        let mut splits = DatasetSplits::default();
+        for mut stratum in strata.into_values() {
+            stratum.sort_by(|a, b| {
+                fnv1a(a.conversation_id.as_bytes())
+                    .cmp(&fnv1a(b.conversation_id.as_bytes()))
+                    .then_with(|| a.conversation_id.cmp(&b.conversation_id))
+            });
+
+            let size = stratum.len() as f64;
+            for (index, conversation) in stratum.into_iter().enumerate() {
+                let position = (index as f64 + 0.5) / size;
+                let split = if position < train_cut {
+                    &mut splits.train
+                } else if position < validation_cut {
+                    &mut splits.validation
+                } else {
+                    &mut splits.test
+                };
+                split.push(conversation.clone());
+            }
+        }
+
+        splits
+    }
+
+    async fn save_to_file(&self, path: &str, split_ratios: &SplitRatios) -> Result<(), BrainError> {
         match self.metadata.format {
             ExportFormat::JsonL => self.save_as_jsonl(path).await,
             ExportFormat::Csv => self.save_as_csv(path).await,
-            _ => self.save_as_json(path).await,
+            ExportFormat::Parquet => self.save_as_parquet(path).await,
+            ExportFormat::HuggingFace => self.save_as_huggingface(path, split_ratios).await,
+        }
+    }
+
+    /// Save as a Parquet table with one row per message
+    ///
+    /// Each row repeats its conversation's metadata and quality metrics, so the
+    /// table can be filtered and grouped without joins. Topics and knowledge
+    /// sources are stored as JSON strings.
+    pub async fn save_as_parquet(&self, path: &str) -> Result<(), BrainError> {
+        let metadata_json = serde_json::to_string(&self.metadata)
+            .map_err(|e| BrainError::ConfigError(format!("Failed to serialize dataset metadata: {}", e)))?;
+        let file = parquet::write_table(
+            &self.parquet_columns()?,
+            &[("brain.dataset_metadata".to_string(), metadata_json)],
+        )?;
+
+        fs::write(path, file)
+            .map_err(|e| BrainError::Io { source: e })?;
+        Ok(())
+    }
+
+    /// Save as a HuggingFace `datasets` directory with stratified splits
+    pub async fn save_as_huggingface(&self, dir: &str, split_ratios: &SplitRatios) -> Result<(), BrainError> {
+        let splits = self.stratified_split(split_ratios);
+        huggingface::write_dataset(self, &splits, Path::new(dir))
+    }
+
+    fn parquet_columns(&self) -> Result<Vec<Column>, BrainError> {
+        let rows: Vec<(&ConversationRecord, usize, &MessageRecord)> = self.conversations
+            .iter()
+            .flat_map(|conversation| {
+                conversation.messages
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, message)| (conversation, index, message))
+            })
+            .collect();
+
+        let utf8 = |value: fn(&ConversationRecord, &MessageRecord) -> String| {
+            ColumnValues::Utf8(rows.iter().map(|(c, _, m)| Some(value(c, m))).collect())
+        };
+        let metric = |value: fn(&ConversationQualityMetrics) -> f64| {
+            ColumnValues::Double(rows.iter().map(|(c, _, _)| Some(value(&c.quality_metrics))).collect())
+        };
+
+        let mut topics = Vec::with_capacity(rows.len());
+        let mut knowledge_sources = Vec::with_capacity(rows.len());
+        for (conversation, _, message) in &rows {
+            topics.push(Some(serde_json::to_string(&conversation.metadata.topics)
+                .map_err(|e| BrainError::ConfigError(format!("Failed to serialize topics: {}", e)))?));
+            knowledge_sources.push(Some(serde_json::to_string(&message.knowledge_sources)
+                .map_err(|e| BrainError::ConfigError(format!("Failed to serialize knowledge sources: {}", e)))?));
         }
+
+        Ok(vec![
+            Column::required("conversation_id", utf8(|c, _| c.conversation_id.clone())),
+            Column::required("message_index", ColumnValues::Int64(rows.iter().map(|(_, i, _)| Some(*i as i64)).collect())),
+            Column::required("message_id", utf8(|_, m| m.message_id.clone())),
+            Column::required("role", utf8(|_, m| m.role.clone())),
+            Column::required("content", utf8(|_, m| m.content.clone())),
+            Column::optional("anonymized_content", ColumnValues::Utf8(
+                rows.iter().map(|(_, _, m)| m.anonymized_content.clone()).collect(),
+            )),
+            Column::required("timestamp", ColumnValues::TimestampMillis(
+                rows.iter().map(|(_, _, m)| Some(m.timestamp.timestamp_millis())).collect(),
+            )),
+            Column::optional("response_quality", ColumnValues::Double(
+                rows.iter().map(|(_, _, m)| m.response_quality.as_ref().map(|q| q.overall_score())).collect(),
+            )),
+            Column::required("knowledge_sources", ColumnValues::Utf8(knowledge_sources)),
+            Column::optional("user_satisfaction", ColumnValues::Double(
+                rows.iter().map(|(_, _, m)| m.user_feedback.as_ref().map(|f| f.satisfaction_score)).collect(),
+            )),
+            Column::required("domain", utf8(|c, _| c.metadata.domain.clone())),
+            Column::required("conversation_type", utf8(|c, _| format!("{:?}", c.metadata.conversation_type))),
+            Column::required("complexity_level", utf8(|c, _| format!("{:?}", c.metadata.complexity_level))),
+            Column::required("user_expertise", utf8(|c, _| format!("{:?}", c.metadata.user_expertise))),
+            Column::required("topics", ColumnValues::Utf8(topics)),
+            Column::required("turn_count", ColumnValues::Int64(
+                rows.iter().map(|(c, _, _)| Some(c.metadata.turn_count as i64)).collect(),
+            )),
+            Column::required("session_duration_minutes", ColumnValues::Double(
+                rows.iter().map(|(c, _, _)| Some(c.metadata.session_duration_minutes)).collect(),
+            )),
+            Column::required("overall_quality", metric(|q| q.overall_quality)),
+            Column::required("coherence_score", metric(|q| q.coherence_score)),
+            Column::required("knowledge_grounding", metric(|q| q.knowledge_grounding)),
+            Column::required("response_relevance", metric(|q| q.response_relevance)),
+            Column::required("safety_score", metric(|q| q.safety_score)),
+            Column::required("educational_value", metric(|q| q.educational_value)),
+            Column::required("diversity_score", metric(|q| q.diversity_score)),
+            Column::required("uniqueness_score", metric(|q| q.uniqueness_score)),
+            Column::required("conversation_created_at", ColumnValues::TimestampMillis(
+                rows.iter().map(|(c, _, _)| Some(c.created_at.timestamp_millis())).collect(),
+            )),
+        ])
     }
 
     async fn save_as_jsonl(&self, path: &str) -> Result<(), BrainError> {
@@ -1228,15 +1426,6 @@ impl TrainingDataset {
         Ok(())
     }
 
-    async fn save_as_json(&self, path: &str) -> Result<(), BrainError> {
-        let json_data = serde_json::to_string_pretty(self)
-            .map_err(|e| BrainError::ConfigError(format!("Failed to serialize dataset: {}", e)))?;
-        
-        fs::write(path, json_data)
-            .map_err(|e| BrainError::Io { source: e })?;
-        Ok(())
-    }
-
     async fn save_as_csv(&self, path: &str) -> Result<(), BrainError> {
         let mut content = String::new();
         content.push_str("conversation_id,role,content,timestamp,quality_score\n");
@@ -1263,4 +1452,124 @@ impl TrainingDataset {
             .map_err(|e| BrainError::Io { source: e })?;
         Ok(())
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversation(id: &str, conversation_type: ConversationType, complexity_level: ComplexityLevel) -> ConversationRecord {
+        let mut conversation = ConversationRecord::new(id);
+        conversation.metadata.conversation_type = conversation_type;
+        conversation.metadata.complexity_level = complexity_level;
+        conversation.metadata.topics = vec!["rust".to_string()];
+        let mut user = MessageRecord::new_user_message("How do lifetimes work?").unwrap();
+        user.anonymized_content = Some("How do lifetimes work?".to_string());
+        conversation.messages.push(user);
+        conversation.messages.push(MessageRecord::new_user_message("They bound borrows.").unwrap());
+        conversation.messages[1].role = "assistant".to_string();
+        conversation
+    }
+
+    fn dataset(format: ExportFormat) -> TrainingDataset {
+        let mut conversations = Vec::new();
+        for i in 0..20 {
+            conversations.push(conversation(&format!("tutorial-{}", i), ConversationType::Tutorial, ComplexityLevel::Moderate));
+        }
+        for i in 0..10 {
+            conversations.push(conversation(&format!("casual-{}", i), ConversationType::Casual, ComplexityLevel::Simple));
+        }
+        TrainingDataset::new(conversations.iter().collect(), &format, &ConversationAnalytics::new()).unwrap()
+    }
+
+    #[test]
+    fn test_stratified_split_keeps_each_stratum_proportional() {
+        let dataset = dataset(ExportFormat::HuggingFace);
+        let splits = dataset.stratified_split(&SplitRatios::default());
+
+        assert_eq!((splits.train.len(), splits.validation.len(), splits.test.len()), (24, 3, 3));
+        let casual = |records: &[ConversationRecord]| {
+            records.iter().filter(|c| c.metadata.conversation_type == ConversationType::Casual).count()
+        };
+        assert_eq!((casual(&splits.train), casual(&splits.validation), casual(&splits.test)), (8, 1, 1));
+
+        // Assignments are stable across calls
+        let again = dataset.stratified_split(&SplitRatios::default());
+        let ids = |records: &[ConversationRecord]| records.iter().map(|c| c.conversation_id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&splits.test), ids(&again.test));
+    }
+
+    /// Read an exported table back with the reference `parquet` implementation
+    fn assert_parquet_round_trip(path: &Path) {
+        use ::parquet::basic::{ConvertedType, Repetition, Type};
+        use ::parquet::file::reader::{FileReader, SerializedFileReader};
+        use ::parquet::record::{Field, RowAccessor};
+
+        let reader = SerializedFileReader::new(fs::File::open(path).unwrap()).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 60);
+        assert!(metadata.key_value_metadata().unwrap().iter().any(|kv| kv.key == "brain.dataset_metadata"));
+
+        let schema = metadata.schema_descr();
+        assert_eq!(schema.num_columns(), 26);
+        let column = |index: usize| schema.column(index);
+        assert_eq!((column(0).name(), column(0).physical_type()), ("conversation_id", Type::BYTE_ARRAY));
+        assert_eq!(column(0).converted_type(), ConvertedType::UTF8);
+        assert_eq!((column(1).name(), column(1).physical_type()), ("message_index", Type::INT64));
+        assert_eq!(column(5).name(), "anonymized_content");
+        assert_eq!(column(5).self_type().get_basic_info().repetition(), Repetition::OPTIONAL);
+        assert_eq!((column(6).name(), column(6).converted_type()), ("timestamp", ConvertedType::TIMESTAMP_MILLIS));
+        assert_eq!((column(25).name(), column(25).physical_type()), ("conversation_created_at", Type::INT64));
+
+        let rows: Vec<_> = reader.get_row_iter(None).unwrap().map(|row| row.unwrap()).collect();
+        assert_eq!(rows.len(), 60);
+        let (user, assistant) = (&rows[0], &rows[1]);
+        assert_eq!(user.get_string(0).unwrap(), "tutorial-0");
+        assert_eq!((user.get_long(1).unwrap(), assistant.get_long(1).unwrap()), (0, 1));
+        assert_eq!(user.get_string(3).unwrap(), "user");
+        assert_eq!(user.get_string(4).unwrap(), "How do lifetimes work?");
+        assert_eq!(user.get_string(5).unwrap(), "How do lifetimes work?");
+        assert_eq!(assistant.get_string(3).unwrap(), "assistant");
+        assert_eq!(assistant.get_string(4).unwrap(), "They bound borrows.");
+        assert_eq!(assistant.get_column_iter().nth(5).unwrap().1, &Field::Null);
+        assert_eq!(user.get_string(11).unwrap(), "Tutorial");
+        assert_eq!(user.get_string(14).unwrap(), r#"["rust"]"#);
+        assert_eq!(rows[59].get_string(0).unwrap(), "casual-9");
+    }
+
+    #[tokio::test]
+    async fn test_parquet_and_huggingface_exports() {
+        let dir = std::env::temp_dir().join(format!("brain_training_export_{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let parquet_path = dir.join("dataset.parquet");
+        dataset(ExportFormat::Parquet)
+            .save_to_file(parquet_path.to_str().unwrap(), &SplitRatios::default())
+            .await
+            .unwrap();
+        let file = fs::read(&parquet_path).unwrap();
+        assert_eq!(&file[..4], b"PAR1");
+        assert_eq!(&file[file.len() - 4..], b"PAR1");
+        assert_parquet_round_trip(&parquet_path);
+
+        let hf_dir = dir.join("dataset");
+        dataset(ExportFormat::HuggingFace)
+            .save_to_file(hf_dir.to_str().unwrap(), &SplitRatios::default())
+            .await
+            .unwrap();
+        let train = fs::read_to_string(hf_dir.join("data/train.jsonl")).unwrap();
+        assert_eq!(train.lines().count(), 24);
+        let row: serde_json::Value = serde_json::from_str(train.lines().next().unwrap()).unwrap();
+        assert_eq!(row["messages"][1]["role"], "assistant");
+        assert!(row["overall_quality"].is_f64());
+
+        let info: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(hf_dir.join("dataset_info.json")).unwrap()).unwrap();
+        assert_eq!(info["splits"]["validation"]["num_examples"], 3);
+        let card = fs::read_to_string(hf_dir.join("README.md")).unwrap();
+        assert!(card.contains("path: data/test.jsonl"));
+        assert!(card.contains("| Tutorial | 20 |"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
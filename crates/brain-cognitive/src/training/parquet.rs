@@ -0,0 +1,208 @@
+//! Parquet Writer
+//!
+//! Writes flat tables through the `parquet` crate's `SerializedFileWriter`,
+//! enough to hand training data to Arrow-based tooling without pulling in the
+//! Arrow stack. Tables are written as a single uncompressed row group.
+
+use std::sync::Arc;
+
+use ::parquet::basic::{ConvertedType, Repetition, Type as PhysicalType};
+use ::parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int64Type};
+use ::parquet::errors::ParquetError;
+use ::parquet::file::metadata::KeyValue;
+use ::parquet::file::properties::WriterProperties;
+use ::parquet::file::writer::{SerializedColumnWriter, SerializedFileWriter};
+use ::parquet::schema::types::{Type, TypePtr};
+use brain_types::BrainError;
+
+/// Values of a single column, `None` marking nulls
+#[derive(Debug, Clone)]
+pub(crate) enum ColumnValues {
+    Int64(Vec<Option<i64>>),
+    TimestampMillis(Vec<Option<i64>>),
+    Double(Vec<Option<f64>>),
+    Utf8(Vec<Option<String>>),
+}
+
+impl ColumnValues {
+    fn len(&self) -> usize {
+        match self {
+            ColumnValues::Int64(values) | ColumnValues::TimestampMillis(values) => values.len(),
+            ColumnValues::Double(values) => values.len(),
+            ColumnValues::Utf8(values) => values.len(),
+        }
+    }
+
+    fn physical_type(&self) -> PhysicalType {
+        match self {
+            ColumnValues::Int64(_) | ColumnValues::TimestampMillis(_) => PhysicalType::INT64,
+            ColumnValues::Double(_) => PhysicalType::DOUBLE,
+            ColumnValues::Utf8(_) => PhysicalType::BYTE_ARRAY,
+        }
+    }
+
+    fn converted_type(&self) -> ConvertedType {
+        match self {
+            ColumnValues::TimestampMillis(_) => ConvertedType::TIMESTAMP_MILLIS,
+            ColumnValues::Utf8(_) => ConvertedType::UTF8,
+            _ => ConvertedType::NONE,
+        }
+    }
+
+    fn presence(&self) -> Vec<bool> {
+        match self {
+            ColumnValues::Int64(values) | ColumnValues::TimestampMillis(values) => {
+                values.iter().map(Option::is_some).collect()
+            }
+            ColumnValues::Double(values) => values.iter().map(Option::is_some).collect(),
+            ColumnValues::Utf8(values) => values.iter().map(Option::is_some).collect(),
+        }
+    }
+}
+
+/// A named column of a flat table
+#[derive(Debug, Clone)]
+pub(crate) struct Column {
+    pub name: String,
+    pub optional: bool,
+    pub values: ColumnValues,
+}
+
+impl Column {
+    pub fn required(name: &str, values: ColumnValues) -> Self {
+        Self { name: name.to_string(), optional: false, values }
+    }
+
+    pub fn optional(name: &str, values: ColumnValues) -> Self {
+        Self { name: name.to_string(), optional: true, values }
+    }
+
+    fn schema_type(&self) -> Result<TypePtr, ParquetError> {
+        let repetition = if self.optional { Repetition::OPTIONAL } else { Repetition::REQUIRED };
+        let field = Type::primitive_type_builder(&self.name, self.values.physical_type())
+            .with_repetition(repetition)
+            .with_converted_type(self.values.converted_type())
+            .build()?;
+        Ok(Arc::new(field))
+    }
+
+    /// Write the non-null values, with definition levels for optional columns
+    fn write(&self, writer: &mut SerializedColumnWriter<'_>) -> Result<(), ParquetError> {
+        let definition_levels: Option<Vec<i16>> = self
+            .optional
+            .then(|| self.values.presence().into_iter().map(i16::from).collect());
+        let definition_levels = definition_levels.as_deref();
+
+        match &self.values {
+            ColumnValues::Int64(values) | ColumnValues::TimestampMillis(values) => {
+                let values: Vec<i64> = values.iter().flatten().copied().collect();
+                writer.typed::<Int64Type>().write_batch(&values, definition_levels, None)?;
+            }
+            ColumnValues::Double(values) => {
+                let values: Vec<f64> = values.iter().flatten().copied().collect();
+                writer.typed::<DoubleType>().write_batch(&values, definition_levels, None)?;
+            }
+            ColumnValues::Utf8(values) => {
+                let values: Vec<ByteArray> = values.iter().flatten().map(|value| ByteArray::from(value.as_str())).collect();
+                writer.typed::<ByteArrayType>().write_batch(&values, definition_levels, None)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serialize `columns` as a Parquet file with `key_value_metadata` in its footer
+pub(crate) fn write_table(columns: &[Column], key_value_metadata: &[(String, String)]) -> Result<Vec<u8>, BrainError> {
+    let num_rows = columns.first().map(|column| column.values.len()).unwrap_or(0);
+    if let Some(column) = columns.iter().find(|column| column.values.len() != num_rows) {
+        return Err(BrainError::InvalidInput(format!(
+            "Parquet column '{}' has {} values, expected {}",
+            column.name,
+            column.values.len(),
+            num_rows
+        )));
+    }
+    if let Some(column) = columns.iter().find(|column| !column.optional && column.values.presence().contains(&false)) {
+        return Err(BrainError::InvalidInput(format!(
+            "Required Parquet column '{}' contains nulls",
+            column.name
+        )));
+    }
+
+    serialize(columns, key_value_metadata).map_err(|e| BrainError::Serialization { source: Box::new(e) })
+}
+
+fn serialize(columns: &[Column], key_value_metadata: &[(String, String)]) -> Result<Vec<u8>, ParquetError> {
+    let fields = columns.iter().map(Column::schema_type).collect::<Result<Vec<_>, _>>()?;
+    let schema = Type::group_type_builder("schema").with_fields(fields).build()?;
+    let key_value_metadata = key_value_metadata
+        .iter()
+        .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+        .collect();
+    let properties = WriterProperties::builder()
+        .set_key_value_metadata(Some(key_value_metadata))
+        .build();
+
+    let mut writer = SerializedFileWriter::new(Vec::new(), Arc::new(schema), Arc::new(properties))?;
+    let mut row_group = writer.next_row_group()?;
+    for column in columns {
+        let mut column_writer = row_group
+            .next_column()?
+            .ok_or_else(|| ParquetError::General(format!("No column writer for '{}'", column.name)))?;
+        column.write(&mut column_writer)?;
+        column_writer.close()?;
+    }
+    row_group.close()?;
+    writer.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parquet::file::reader::{FileReader, SerializedFileReader};
+    use ::parquet::record::{Field, RowAccessor};
+    use uuid::Uuid;
+
+    fn table() -> Vec<Column> {
+        vec![
+            Column::required("id", ColumnValues::Int64(vec![Some(1), Some(2), Some(3)])),
+            Column::optional("score", ColumnValues::Double(vec![Some(0.5), None, Some(1.0)])),
+            Column::required(
+                "text",
+                ColumnValues::Utf8(vec![Some("a".to_string()), Some("bc".to_string()), Some(String::new())]),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_table_round_trip() {
+        let file = write_table(&table(), &[("origin".to_string(), "test".to_string())]).unwrap();
+
+        let path = std::env::temp_dir().join(format!("brain_parquet_{}.parquet", Uuid::new_v4()));
+        std::fs::write(&path, file).unwrap();
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 3);
+        let origin = metadata.key_value_metadata().unwrap().iter().find(|kv| kv.key == "origin").unwrap();
+        assert_eq!(origin.value.as_deref(), Some("test"));
+        assert_eq!(metadata.schema_descr().column(2).converted_type(), ConvertedType::UTF8);
+
+        let rows: Vec<_> = reader.get_row_iter(None).unwrap().map(|row| row.unwrap()).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!((rows[0].get_long(0).unwrap(), rows[0].get_double(1).unwrap()), (1, 0.5));
+        assert_eq!(rows[1].get_column_iter().nth(1).unwrap().1, &Field::Null);
+        assert_eq!(rows[1].get_string(2).unwrap(), "bc");
+        assert_eq!(rows[2].get_string(2).unwrap(), "");
+    }
+
+    #[test]
+    fn test_rejects_ragged_and_null_required_columns() {
+        let mut columns = table();
+        columns.push(Column::required("short", ColumnValues::Int64(vec![Some(1)])));
+        assert!(write_table(&columns, &[]).is_err());
+
+        let nulls = vec![Column::required("id", ColumnValues::Int64(vec![None]))];
+        assert!(write_table(&nulls, &[]).is_err());
+    }
+}
@@ -7,7 +7,8 @@
 use brain_types::*;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{hash_map::Entry, BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 
 /// Types of concept nodes in the graph
@@ -280,6 +281,8 @@ pub struct TraversalConfig {
     pub min_relationship_weight: f64,
     pub activation_spread_factor: f64,
     pub activation_decay_factor: f64,
+    /// Minimum activation a concept must receive to keep spreading
+    pub activation_threshold: f64,
     pub follow_relationship_types: Vec<RelationshipType>,
 }
 
@@ -291,6 +294,7 @@ impl Default for TraversalConfig {
             min_relationship_weight: 0.1,
             activation_spread_factor: 0.8,
             activation_decay_factor: 0.9,
+            activation_threshold: 0.05,
             follow_relationship_types: Vec::new(),
         }
     }
@@ -319,11 +323,353 @@ pub struct ConceptPath {
     pub path_length: usize,
     pub total_weight: f64,
     pub average_weight: f64,
+    /// Sum of inverse relationship weights; lower means a stronger connection
+    pub total_cost: f64,
+}
+
+impl ConceptPath {
+    /// Build a path from the concepts reached at each hop and the relationship used
+    fn from_hops(source_id: Uuid, hops: &[(Uuid, &ConceptRelationship)]) -> Self {
+        let mut concept_path = vec![source_id];
+        let mut relationship_path = Vec::with_capacity(hops.len());
+        let mut total_weight = 0.0;
+        let mut total_cost = 0.0;
+
+        for (concept_id, relationship) in hops {
+            concept_path.push(*concept_id);
+            relationship_path.push(relationship.id);
+            total_weight += relationship.weight;
+            total_cost += relationship_cost(relationship);
+        }
+
+        let path_length = relationship_path.len();
+        Self {
+            source_id,
+            target_id: *concept_path.last().unwrap_or(&source_id),
+            concept_path,
+            relationship_path,
+            path_length,
+            total_weight,
+            average_weight: if path_length > 0 { total_weight / path_length as f64 } else { 0.0 },
+            total_cost,
+        }
+    }
+}
+
+/// Traversal cost of a relationship: stronger relationships are cheaper to follow
+fn relationship_cost(relationship: &ConceptRelationship) -> f64 {
+    1.0 / relationship.weight.max(f64::EPSILON)
+}
+
+/// Options for weighted path search between concepts
+#[derive(Debug, Clone)]
+pub struct PathSearchConfig {
+    /// Only follow these relationship types (empty follows every type)
+    pub relationship_types: Vec<RelationshipType>,
+    /// Ignore relationships weaker than this
+    pub min_relationship_weight: f64,
+}
+
+impl Default for PathSearchConfig {
+    fn default() -> Self {
+        Self {
+            relationship_types: Vec::new(),
+            min_relationship_weight: 0.1,
+        }
+    }
+}
+
+impl PathSearchConfig {
+    /// Whether a relationship passes the type and weight filters
+    pub fn accepts(&self, relationship: &ConceptRelationship) -> bool {
+        relationship.weight > 0.0
+            && relationship.weight >= self.min_relationship_weight
+            && (self.relationship_types.is_empty()
+                || self.relationship_types.contains(&relationship.relationship_type))
+    }
+}
+
+impl From<&TraversalConfig> for PathSearchConfig {
+    fn from(config: &TraversalConfig) -> Self {
+        Self {
+            relationship_types: config.follow_relationship_types.clone(),
+            min_relationship_weight: config.min_relationship_weight,
+        }
+    }
+}
+
+/// Undirected adjacency snapshot of the concept graph used for weighted path search.
+///
+/// Relationships are followed in both directions with cost `1 / weight`.
+#[derive(Debug, Clone, Default)]
+pub struct ConceptAdjacency {
+    edges: HashMap<Uuid, Vec<(Uuid, ConceptRelationship)>>,
+}
+
+/// Min-heap entry for Dijkstra's algorithm
+struct CostEntry {
+    cost: f64,
+    concept_id: Uuid,
+}
+
+impl PartialEq for CostEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CostEntry {}
+
+impl PartialOrd for CostEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CostEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap pops the cheapest entry first
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.concept_id.cmp(&self.concept_id))
+    }
+}
+
+/// Best known route to a concept during Dijkstra's algorithm
+struct PathLabel<'a> {
+    cost: f64,
+    hops: usize,
+    strength: f64,
+    via: Option<(Uuid, &'a ConceptRelationship)>,
+}
+
+/// Settled labels in the order they were finalised
+struct DijkstraRun<'a> {
+    order: Vec<Uuid>,
+    labels: HashMap<Uuid, PathLabel<'a>>,
+}
+
+impl ConceptAdjacency {
+    /// Build an adjacency snapshot from the relationships accepted by `config`
+    pub fn from_relationships<'a>(
+        relationships: impl IntoIterator<Item = &'a ConceptRelationship>,
+        config: &PathSearchConfig,
+    ) -> Self {
+        let mut edges: HashMap<Uuid, Vec<(Uuid, ConceptRelationship)>> = HashMap::new();
+        for relationship in relationships {
+            if !config.accepts(relationship) || relationship.source_id == relationship.target_id {
+                continue;
+            }
+            edges
+                .entry(relationship.source_id)
+                .or_default()
+                .push((relationship.target_id, relationship.clone()));
+            edges
+                .entry(relationship.target_id)
+                .or_default()
+                .push((relationship.source_id, relationship.clone()));
+        }
+        Self { edges }
+    }
+
+    /// Number of concepts with at least one accepted relationship
+    pub fn concept_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Cheapest path between two concepts (Dijkstra)
+    pub fn shortest_path(&self, source_id: Uuid, target_id: Uuid) -> Option<ConceptPath> {
+        self.constrained_path(source_id, target_id, &HashSet::new(), &HashSet::new())
+            .map(|hops| ConceptPath::from_hops(source_id, &hops))
+    }
+
+    /// Up to `k` loopless paths between two concepts in order of increasing cost (Yen's algorithm)
+    pub fn k_shortest_paths(&self, source_id: Uuid, target_id: Uuid, k: usize) -> Vec<ConceptPath> {
+        let mut accepted: Vec<Vec<(Uuid, &ConceptRelationship)>> = Vec::new();
+        let mut candidates: Vec<(f64, Vec<(Uuid, &ConceptRelationship)>)> = Vec::new();
+
+        if k == 0 {
+            return Vec::new();
+        }
+        match self.constrained_path(source_id, target_id, &HashSet::new(), &HashSet::new()) {
+            Some(first) => accepted.push(first),
+            None => return Vec::new(),
+        }
+
+        while accepted.len() < k {
+            let previous = accepted.last().cloned().unwrap_or_default();
+
+            for spur_index in 0..previous.len() {
+                let root = &previous[..spur_index];
+                let spur_node = if spur_index == 0 { source_id } else { previous[spur_index - 1].0 };
+
+                // Block the next hop of every accepted path that shares this root
+                let excluded_edges: HashSet<Uuid> = accepted
+                    .iter()
+                    .filter(|path| path.len() > spur_index && Self::same_route(&path[..spur_index], root))
+                    .map(|path| path[spur_index].1.id)
+                    .collect();
+                let mut excluded_nodes: HashSet<Uuid> = root.iter().map(|(concept_id, _)| *concept_id).collect();
+                excluded_nodes.insert(source_id);
+                excluded_nodes.remove(&spur_node);
+
+                let Some(spur) = self.constrained_path(spur_node, target_id, &excluded_nodes, &excluded_edges) else {
+                    continue;
+                };
+                let mut candidate = root.to_vec();
+                candidate.extend(spur);
+
+                let known = accepted.iter().any(|path| Self::same_route(path, &candidate))
+                    || candidates.iter().any(|(_, path)| Self::same_route(path, &candidate));
+                if !known {
+                    let cost = candidate.iter().map(|(_, rel)| relationship_cost(rel)).sum();
+                    candidates.push((cost, candidate));
+                }
+            }
+
+            let Some(best) = candidates
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+                .map(|(index, _)| index)
+            else {
+                break;
+            };
+            accepted.push(candidates.swap_remove(best).1);
+        }
+
+        accepted
+            .iter()
+            .map(|hops| ConceptPath::from_hops(source_id, hops))
+            .collect()
+    }
+
+    /// Cheapest-path tree from a start concept, visiting concepts in order of increasing cost.
+    ///
+    /// Activation scores hold the product of relationship weights along the cheapest path.
+    pub fn shortest_path_tree(&self, start_concept_id: Uuid, max_depth: usize, max_nodes: usize) -> TraversalResult {
+        let run = self.dijkstra(start_concept_id, None, &HashSet::new(), &HashSet::new(), Some(max_depth), max_nodes);
+
+        let mut traversed_relationships = Vec::new();
+        let mut activation_scores = HashMap::new();
+        let mut distances = HashMap::new();
+        let mut max_depth_reached = 0;
+        for concept_id in &run.order {
+            let label = &run.labels[concept_id];
+            if let Some((_, relationship)) = label.via {
+                traversed_relationships.push(relationship.id);
+            }
+            activation_scores.insert(*concept_id, label.strength);
+            distances.insert(*concept_id, label.hops);
+            max_depth_reached = max_depth_reached.max(label.hops);
+        }
+
+        TraversalResult {
+            start_concept_id,
+            algorithm: TraversalAlgorithm::ShortestPath,
+            total_nodes_visited: run.order.len(),
+            visited_concepts: run.order,
+            traversed_relationships,
+            activation_scores,
+            distances,
+            max_depth_reached,
+        }
+    }
+
+    fn same_route(a: &[(Uuid, &ConceptRelationship)], b: &[(Uuid, &ConceptRelationship)]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.1.id == y.1.id)
+    }
+
+    /// Cheapest path avoiding the given concepts and relationships, as a list of hops
+    fn constrained_path(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        excluded_nodes: &HashSet<Uuid>,
+        excluded_edges: &HashSet<Uuid>,
+    ) -> Option<Vec<(Uuid, &ConceptRelationship)>> {
+        let run = self.dijkstra(source_id, Some(target_id), excluded_nodes, excluded_edges, None, usize::MAX);
+        run.labels.get(&target_id)?;
+
+        let mut hops = Vec::new();
+        let mut current = target_id;
+        while let Some((parent, relationship)) = run.labels.get(&current).and_then(|label| label.via) {
+            hops.push((current, relationship));
+            current = parent;
+        }
+        hops.reverse();
+        Some(hops)
+    }
+
+    fn dijkstra(
+        &self,
+        source_id: Uuid,
+        target_id: Option<Uuid>,
+        excluded_nodes: &HashSet<Uuid>,
+        excluded_edges: &HashSet<Uuid>,
+        max_hops: Option<usize>,
+        max_nodes: usize,
+    ) -> DijkstraRun<'_> {
+        let mut labels = HashMap::from([(source_id, PathLabel { cost: 0.0, hops: 0, strength: 1.0, via: None })]);
+        let mut settled = HashSet::new();
+        let mut order = Vec::new();
+        let mut heap = BinaryHeap::from([CostEntry { cost: 0.0, concept_id: source_id }]);
+
+        while let Some(CostEntry { cost, concept_id }) = heap.pop() {
+            if order.len() >= max_nodes {
+                break;
+            }
+            if !settled.insert(concept_id) {
+                continue;
+            }
+            order.push(concept_id);
+            if Some(concept_id) == target_id {
+                break;
+            }
+
+            let (hops, strength) = {
+                let label = &labels[&concept_id];
+                (label.hops, label.strength)
+            };
+            if max_hops.is_some_and(|limit| hops >= limit) {
+                continue;
+            }
+
+            for (neighbor, relationship) in self.edges.get(&concept_id).into_iter().flatten() {
+                if settled.contains(neighbor)
+                    || excluded_nodes.contains(neighbor)
+                    || excluded_edges.contains(&relationship.id)
+                {
+                    continue;
+                }
+                let next_cost = cost + relationship_cost(relationship);
+                if labels.get(neighbor).is_some_and(|label| label.cost <= next_cost) {
+                    continue;
+                }
+                labels.insert(
+                    *neighbor,
+                    PathLabel {
+                        cost: next_cost,
+                        hops: hops + 1,
+                        strength: strength * relationship.weight,
+                        via: Some((concept_id, relationship)),
+                    },
+                );
+                heap.push(CostEntry { cost: next_cost, concept_id: *neighbor });
+            }
+        }
+
+        // Only settled concepts have final labels
+        labels.retain(|concept_id, _| settled.contains(concept_id));
+        DijkstraRun { order, labels }
+    }
 }
 
 /// Network metrics for analysis
 #[derive(Debug, Clone)]
 pub struct NetworkMetrics {
+    pub total_concepts: usize,
     pub total_relationships: usize,
     pub relationships_by_type: HashMap<RelationshipType, usize>,
     pub average_weight: f64,
@@ -331,14 +677,29 @@ pub struct NetworkMetrics {
     pub weak_relationships: usize,
     pub prunable_relationships: usize,
     pub average_degree: f64,
+    /// Number of concepts having each degree (relationship count)
+    pub degree_distribution: BTreeMap<usize, usize>,
     pub isolated_concepts: usize,
+    /// Fraction of possible undirected links that exist
+    pub density: f64,
+    /// Mean local clustering coefficient over all concepts
     pub clustering_coefficient: f64,
+    pub connected_components: usize,
+    pub largest_component_size: usize,
+    /// Mean hop count over all connected pairs of concepts
+    pub average_path_length: f64,
+    /// Top concepts by degree centrality
     pub most_connected_concepts: Vec<(Uuid, usize)>,
+    /// Top concepts by normalised betweenness centrality
+    pub most_between_concepts: Vec<(Uuid, f64)>,
+    /// Top concepts by weighted PageRank
+    pub highest_pagerank_concepts: Vec<(Uuid, f64)>,
 }
 
 impl Default for NetworkMetrics {
     fn default() -> Self {
         Self {
+            total_concepts: 0,
             total_relationships: 0,
             relationships_by_type: HashMap::new(),
             average_weight: 0.0,
@@ -346,13 +707,273 @@ impl Default for NetworkMetrics {
             weak_relationships: 0,
             prunable_relationships: 0,
             average_degree: 0.0,
+            degree_distribution: BTreeMap::new(),
             isolated_concepts: 0,
+            density: 0.0,
             clustering_coefficient: 0.0,
+            connected_components: 0,
+            largest_component_size: 0,
+            average_path_length: 0.0,
             most_connected_concepts: Vec::new(),
+            most_between_concepts: Vec::new(),
+            highest_pagerank_concepts: Vec::new(),
         }
     }
 }
 
+impl NetworkMetrics {
+    /// Number of concepts reported in each centrality ranking
+    pub const TOP_CONCEPTS: usize = 10;
+    const PAGERANK_DAMPING: f64 = 0.85;
+    const PAGERANK_MAX_ITERATIONS: usize = 100;
+    const PAGERANK_TOLERANCE: f64 = 1e-9;
+
+    /// Compute metrics for a graph snapshot.
+    ///
+    /// Structural metrics treat the graph as undirected and collapse parallel
+    /// relationships; degree counts every relationship touching a concept.
+    /// Relationship endpoints missing from `concept_ids` are still counted.
+    pub fn from_graph(
+        concept_ids: impl IntoIterator<Item = Uuid>,
+        relationships: &[ConceptRelationship],
+    ) -> Self {
+        let mut ids: Vec<Uuid> = concept_ids.into_iter().collect();
+        ids.extend(relationships.iter().flat_map(|rel| [rel.source_id, rel.target_id]));
+        ids.sort();
+        ids.dedup();
+
+        let node_count = ids.len();
+        let index: HashMap<Uuid, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut degrees = vec![0usize; node_count];
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        let mut weighted: Vec<HashMap<usize, f64>> = vec![HashMap::new(); node_count];
+        let mut relationships_by_type = HashMap::new();
+
+        for relationship in relationships {
+            *relationships_by_type.entry(relationship.relationship_type.clone()).or_insert(0) += 1;
+            let (a, b) = (index[&relationship.source_id], index[&relationship.target_id]);
+            degrees[a] += 1;
+            degrees[b] += 1;
+            if a != b {
+                neighbors[a].push(b);
+                neighbors[b].push(a);
+                *weighted[a].entry(b).or_insert(0.0) += relationship.weight;
+                *weighted[b].entry(a).or_insert(0.0) += relationship.weight;
+            }
+        }
+        for list in neighbors.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        let total_relationships = relationships.len();
+        let average_weight = if total_relationships > 0 {
+            relationships.iter().map(|r| r.weight).sum::<f64>() / total_relationships as f64
+        } else {
+            0.0
+        };
+
+        let mut degree_distribution = BTreeMap::new();
+        for degree in &degrees {
+            *degree_distribution.entry(*degree).or_insert(0) += 1;
+        }
+
+        let undirected_links = neighbors.iter().map(Vec::len).sum::<usize>() / 2;
+        let density = if node_count > 1 {
+            undirected_links as f64 / (node_count * (node_count - 1) / 2) as f64
+        } else {
+            0.0
+        };
+
+        let (connected_components, largest_component_size) = Self::components(&neighbors);
+        let (betweenness, average_path_length) = Self::betweenness_and_path_length(&neighbors);
+        let pagerank = Self::pagerank(&weighted);
+
+        let mut most_connected_concepts: Vec<(Uuid, usize)> = ids
+            .iter()
+            .zip(&degrees)
+            .filter(|(_, degree)| **degree > 0)
+            .map(|(id, degree)| (*id, *degree))
+            .collect();
+        most_connected_concepts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        most_connected_concepts.truncate(Self::TOP_CONCEPTS);
+
+        Self {
+            total_concepts: node_count,
+            total_relationships,
+            relationships_by_type,
+            average_weight,
+            strong_relationships: relationships.iter().filter(|r| r.weight >= 0.7).count(),
+            weak_relationships: relationships.iter().filter(|r| r.weight < 0.3).count(),
+            prunable_relationships: relationships.iter().filter(|r| r.should_prune()).count(),
+            average_degree: if node_count > 0 { (total_relationships * 2) as f64 / node_count as f64 } else { 0.0 },
+            degree_distribution,
+            isolated_concepts: degrees.iter().filter(|degree| **degree == 0).count(),
+            density,
+            clustering_coefficient: Self::average_clustering(&neighbors),
+            connected_components,
+            largest_component_size,
+            average_path_length,
+            most_connected_concepts,
+            most_between_concepts: Self::top_scores(&ids, &betweenness),
+            highest_pagerank_concepts: Self::top_scores(&ids, &pagerank),
+        }
+    }
+
+    fn top_scores(ids: &[Uuid], scores: &[f64]) -> Vec<(Uuid, f64)> {
+        let mut ranked: Vec<(Uuid, f64)> = ids.iter().copied().zip(scores.iter().copied()).collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(Self::TOP_CONCEPTS);
+        ranked
+    }
+
+    fn average_clustering(neighbors: &[Vec<usize>]) -> f64 {
+        if neighbors.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = neighbors
+            .iter()
+            .map(|list| {
+                let k = list.len();
+                if k < 2 {
+                    return 0.0;
+                }
+                let links = list
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, a)| list[i + 1..].iter().map(move |b| (*a, *b)))
+                    .filter(|(a, b)| neighbors[*a].binary_search(b).is_ok())
+                    .count();
+                (2 * links) as f64 / (k * (k - 1)) as f64
+            })
+            .sum();
+        total / neighbors.len() as f64
+    }
+
+    /// Count connected components and the size of the largest one
+    fn components(neighbors: &[Vec<usize>]) -> (usize, usize) {
+        let mut seen = vec![false; neighbors.len()];
+        let mut count = 0;
+        let mut largest = 0;
+
+        for start in 0..neighbors.len() {
+            if seen[start] {
+                continue;
+            }
+            count += 1;
+            seen[start] = true;
+            let mut stack = vec![start];
+            let mut size = 0;
+            while let Some(node) = stack.pop() {
+                size += 1;
+                for &next in &neighbors[node] {
+                    if !seen[next] {
+                        seen[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            largest = largest.max(size);
+        }
+
+        (count, largest)
+    }
+
+    /// Brandes' algorithm for normalised betweenness, collecting hop distances
+    /// between connected pairs along the way
+    fn betweenness_and_path_length(neighbors: &[Vec<usize>]) -> (Vec<f64>, f64) {
+        let n = neighbors.len();
+        let mut centrality = vec![0.0; n];
+        let mut distance_total = 0usize;
+        let mut connected_pairs = 0usize;
+
+        for source in 0..n {
+            let mut order = Vec::with_capacity(n);
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut path_counts = vec![0.0f64; n];
+            let mut distance = vec![usize::MAX; n];
+            path_counts[source] = 1.0;
+            distance[source] = 0;
+
+            let mut queue = VecDeque::from([source]);
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                for &next in &neighbors[node] {
+                    if distance[next] == usize::MAX {
+                        distance[next] = distance[node] + 1;
+                        queue.push_back(next);
+                    }
+                    if distance[next] == distance[node] + 1 {
+                        path_counts[next] += path_counts[node];
+                        predecessors[next].push(node);
+                    }
+                }
+            }
+
+            distance_total += order.iter().map(|node| distance[*node]).sum::<usize>();
+            connected_pairs += order.len() - 1;
+
+            let mut dependency = vec![0.0f64; n];
+            for &node in order.iter().rev() {
+                for &previous in &predecessors[node] {
+                    dependency[previous] += path_counts[previous] / path_counts[node] * (1.0 + dependency[node]);
+                }
+                if node != source {
+                    centrality[node] += dependency[node];
+                }
+            }
+        }
+
+        // Each undirected pair was counted from both ends
+        if n > 2 {
+            let scale = 1.0 / ((n - 1) * (n - 2)) as f64;
+            centrality.iter_mut().for_each(|value| *value *= scale);
+        }
+        let average_path_length = if connected_pairs > 0 {
+            distance_total as f64 / connected_pairs as f64
+        } else {
+            0.0
+        };
+
+        (centrality, average_path_length)
+    }
+
+    /// PageRank over the undirected graph, following relationships in proportion to their weight
+    fn pagerank(weighted: &[HashMap<usize, f64>]) -> Vec<f64> {
+        let n = weighted.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let out_weight: Vec<f64> = weighted.iter().map(|edges| edges.values().sum()).collect();
+        let mut rank = vec![1.0 / n as f64; n];
+
+        for _ in 0..Self::PAGERANK_MAX_ITERATIONS {
+            let dangling: f64 = (0..n).filter(|i| out_weight[*i] <= 0.0).map(|i| rank[i]).sum();
+            let base = (1.0 - Self::PAGERANK_DAMPING + Self::PAGERANK_DAMPING * dangling) / n as f64;
+            let mut next = vec![base; n];
+
+            for (node, edges) in weighted.iter().enumerate() {
+                if out_weight[node] <= 0.0 {
+                    continue;
+                }
+                let share = Self::PAGERANK_DAMPING * rank[node] / out_weight[node];
+                for (target, weight) in edges {
+                    next[*target] += share * weight;
+                }
+            }
+
+            let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if change < Self::PAGERANK_TOLERANCE {
+                break;
+            }
+        }
+
+        rank
+    }
+}
+
 /// Repository trait for concept nodes
 #[async_trait::async_trait]
 #[allow(async_fn_in_trait)]
@@ -464,9 +1085,33 @@ impl ConceptGraphService {
         Ok(activated_count)
     }
 
-    pub async fn find_shortest_path(&self, _source_id: Uuid, _target_id: Uuid) -> Result<Option<ConceptPath>> {
-        // This would implement Dijkstra's algorithm or similar
-        // For now, return None as placeholder
+    /// Find the path with the fewest hops between two concepts.
+    ///
+    /// Relationships are followed in both directions and filtered with the
+    /// default `TraversalConfig` weight threshold.
+    pub async fn find_shortest_path(&self, source_id: Uuid, target_id: Uuid) -> Result<Option<ConceptPath>> {
+        let config = TraversalConfig::default();
+        let mut parents: HashMap<Uuid, (Uuid, ConceptRelationship)> = HashMap::new();
+        let mut seen = HashSet::from([source_id]);
+        let mut queue = VecDeque::from([source_id]);
+
+        if source_id == target_id {
+            return Ok(Some(Self::assemble_path(source_id, target_id, &parents)));
+        }
+
+        while let Some(current) = queue.pop_front() {
+            for (neighbor, relationship) in self.traversable_neighbors(current, &config).await? {
+                if !seen.insert(neighbor) {
+                    continue;
+                }
+                parents.insert(neighbor, (current, relationship));
+                if neighbor == target_id {
+                    return Ok(Some(Self::assemble_path(source_id, target_id, &parents)));
+                }
+                queue.push_back(neighbor);
+            }
+        }
+
         Ok(None)
     }
 
@@ -483,61 +1128,305 @@ impl ConceptGraphService {
             TraversalAlgorithm::DepthFirst => self.depth_first_search(start_concept_id, &config).await,
             TraversalAlgorithm::SpreadingActivation => self.spreading_activation_search(start_concept_id, &config).await,
             TraversalAlgorithm::ShortestPath => {
-                // For shortest path, we need a target - use BFS as fallback
-                self.breadth_first_search(start_concept_id, &config).await
+                let adjacency = self
+                    .load_adjacency(start_concept_id, &PathSearchConfig::from(&config), Some(config.max_depth))
+                    .await?;
+                Ok(adjacency.shortest_path_tree(start_concept_id, config.max_depth, config.max_nodes))
             }
         }
     }
 
-    async fn breadth_first_search(&self, start_concept_id: Uuid, _config: &TraversalConfig) -> Result<TraversalResult> {
-        // Placeholder implementation
+    /// Find the strongest path between two concepts, treating `1 / weight` as the cost of each hop
+    pub async fn find_weighted_shortest_path(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        config: Option<PathSearchConfig>,
+    ) -> Result<Option<ConceptPath>> {
+        let config = config.unwrap_or_default();
+        let adjacency = self.load_adjacency(source_id, &config, None).await?;
+        Ok(adjacency.shortest_path(source_id, target_id))
+    }
+
+    /// Find up to `k` alternative paths between two concepts, strongest first
+    pub async fn find_k_shortest_paths(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        k: usize,
+        config: Option<PathSearchConfig>,
+    ) -> Result<Vec<ConceptPath>> {
+        let config = config.unwrap_or_default();
+        let adjacency = self.load_adjacency(source_id, &config, None).await?;
+        Ok(adjacency.k_shortest_paths(source_id, target_id, k))
+    }
+
+    /// Load the relationships reachable from a concept into an adjacency snapshot,
+    /// optionally stopping after `max_hops` hops
+    async fn load_adjacency(
+        &self,
+        start_concept_id: Uuid,
+        config: &PathSearchConfig,
+        max_hops: Option<usize>,
+    ) -> Result<ConceptAdjacency> {
+        let mut relationships: HashMap<Uuid, ConceptRelationship> = HashMap::new();
+        let mut hops = HashMap::from([(start_concept_id, 0)]);
+        let mut queue = VecDeque::from([start_concept_id]);
+
+        while let Some(current) = queue.pop_front() {
+            let depth = hops[&current];
+            if max_hops.is_some_and(|limit| depth >= limit) {
+                continue;
+            }
+            for relationship in self.relationship_repo.get_concept_relationships(current).await? {
+                if !config.accepts(&relationship) {
+                    continue;
+                }
+                let other = if relationship.source_id == current { relationship.target_id } else { relationship.source_id };
+                if let Entry::Vacant(entry) = hops.entry(other) {
+                    entry.insert(depth + 1);
+                    queue.push_back(other);
+                }
+                relationships.insert(relationship.id, relationship);
+            }
+        }
+
+        Ok(ConceptAdjacency::from_relationships(relationships.values(), config))
+    }
+
+    /// Collect the neighbours reachable from a concept under the traversal filters,
+    /// strongest relationships first so traversal order is deterministic.
+    async fn traversable_neighbors(
+        &self,
+        concept_id: Uuid,
+        config: &TraversalConfig,
+    ) -> Result<Vec<(Uuid, ConceptRelationship)>> {
+        let mut neighbors: Vec<(Uuid, ConceptRelationship)> = self
+            .relationship_repo
+            .get_concept_relationships(concept_id)
+            .await?
+            .into_iter()
+            .filter(|rel| rel.weight >= config.min_relationship_weight)
+            .filter(|rel| {
+                config.follow_relationship_types.is_empty()
+                    || config.follow_relationship_types.contains(&rel.relationship_type)
+            })
+            .filter_map(|rel| {
+                let other = if rel.source_id == concept_id { rel.target_id } else { rel.source_id };
+                (other != concept_id).then_some((other, rel))
+            })
+            .collect();
+
+        neighbors.sort_by(|a, b| {
+            b.1.weight
+                .partial_cmp(&a.1.weight)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+
+        Ok(neighbors)
+    }
+
+    /// Rebuild a path by walking parent links back from the target
+    fn assemble_path(
+        source_id: Uuid,
+        target_id: Uuid,
+        parents: &HashMap<Uuid, (Uuid, ConceptRelationship)>,
+    ) -> ConceptPath {
+        let mut hops = Vec::new();
+        let mut current = target_id;
+
+        while current != source_id {
+            let Some((parent, relationship)) = parents.get(&current) else {
+                break;
+            };
+            hops.push((current, relationship));
+            current = *parent;
+        }
+
+        hops.reverse();
+        ConceptPath::from_hops(source_id, &hops)
+    }
+
+    /// Breadth-first traversal. Activation scores hold the product of relationship
+    /// weights along the discovery path.
+    async fn breadth_first_search(&self, start_concept_id: Uuid, config: &TraversalConfig) -> Result<TraversalResult> {
+        let mut visited_concepts = vec![start_concept_id];
+        let mut traversed_relationships = Vec::new();
+        let mut activation_scores = HashMap::from([(start_concept_id, 1.0)]);
+        let mut distances = HashMap::from([(start_concept_id, 0)]);
+        let mut max_depth_reached = 0;
+        let mut queue = VecDeque::from([start_concept_id]);
+
+        'traversal: while let Some(current) = queue.pop_front() {
+            let depth = distances[&current];
+            if depth >= config.max_depth {
+                continue;
+            }
+            let current_activation = activation_scores[&current];
+
+            for (neighbor, relationship) in self.traversable_neighbors(current, config).await? {
+                if distances.contains_key(&neighbor) {
+                    continue;
+                }
+                if visited_concepts.len() >= config.max_nodes {
+                    break 'traversal;
+                }
+
+                distances.insert(neighbor, depth + 1);
+                activation_scores.insert(neighbor, current_activation * relationship.weight);
+                visited_concepts.push(neighbor);
+                traversed_relationships.push(relationship.id);
+                max_depth_reached = max_depth_reached.max(depth + 1);
+                queue.push_back(neighbor);
+            }
+        }
+
         Ok(TraversalResult {
             start_concept_id,
             algorithm: TraversalAlgorithm::BreadthFirst,
-            visited_concepts: vec![start_concept_id],
-            traversed_relationships: Vec::new(),
-            activation_scores: HashMap::new(),
-            distances: HashMap::new(),
-            total_nodes_visited: 1,
-            max_depth_reached: 0,
+            total_nodes_visited: visited_concepts.len(),
+            visited_concepts,
+            traversed_relationships,
+            activation_scores,
+            distances,
+            max_depth_reached,
         })
     }
 
-    async fn depth_first_search(&self, start_concept_id: Uuid, _config: &TraversalConfig) -> Result<TraversalResult> {
-        // Placeholder implementation
+    /// Depth-first traversal, exploring the strongest relationship first.
+    /// Distances are measured along the DFS tree rather than the shortest route.
+    async fn depth_first_search(&self, start_concept_id: Uuid, config: &TraversalConfig) -> Result<TraversalResult> {
+        let mut visited_concepts = Vec::new();
+        let mut traversed_relationships = Vec::new();
+        let mut activation_scores = HashMap::new();
+        let mut distances = HashMap::new();
+        let mut max_depth_reached = 0;
+        let mut stack: Vec<(Uuid, usize, f64, Option<Uuid>)> = vec![(start_concept_id, 0, 1.0, None)];
+
+        while let Some((current, depth, activation, via_relationship)) = stack.pop() {
+            if distances.contains_key(&current) {
+                continue;
+            }
+            if visited_concepts.len() >= config.max_nodes {
+                break;
+            }
+
+            distances.insert(current, depth);
+            activation_scores.insert(current, activation);
+            visited_concepts.push(current);
+            if let Some(relationship_id) = via_relationship {
+                traversed_relationships.push(relationship_id);
+            }
+            max_depth_reached = max_depth_reached.max(depth);
+
+            if depth >= config.max_depth {
+                continue;
+            }
+
+            // Push in reverse so the strongest neighbour is popped first
+            let neighbors = self.traversable_neighbors(current, config).await?;
+            for (neighbor, relationship) in neighbors.into_iter().rev() {
+                if !distances.contains_key(&neighbor) {
+                    stack.push((neighbor, depth + 1, activation * relationship.weight, Some(relationship.id)));
+                }
+            }
+        }
+
         Ok(TraversalResult {
             start_concept_id,
             algorithm: TraversalAlgorithm::DepthFirst,
-            visited_concepts: vec![start_concept_id],
-            traversed_relationships: Vec::new(),
-            activation_scores: HashMap::new(),
-            distances: HashMap::new(),
-            total_nodes_visited: 1,
-            max_depth_reached: 0,
+            total_nodes_visited: visited_concepts.len(),
+            visited_concepts,
+            traversed_relationships,
+            activation_scores,
+            distances,
+            max_depth_reached,
         })
     }
 
-    async fn spreading_activation_search(&self, start_concept_id: Uuid, _config: &TraversalConfig) -> Result<TraversalResult> {
-        // Placeholder implementation
-        let mut activation_scores = HashMap::new();
-        activation_scores.insert(start_concept_id, 1.0);
+    /// Spreading activation from the start concept.
+    ///
+    /// Each hop passes on `activation * weight * spread_factor * decay_factor`.
+    /// Concepts accumulate activation from every incoming relationship (capped
+    /// at 1.0), and only newly activated concepts spread further. Activation
+    /// below `activation_threshold` is dropped.
+    async fn spreading_activation_search(&self, start_concept_id: Uuid, config: &TraversalConfig) -> Result<TraversalResult> {
+        let mut visited_concepts = vec![start_concept_id];
+        let mut traversed_relationships = Vec::new();
+        let mut used_relationships = HashSet::new();
+        let mut activation_scores = HashMap::from([(start_concept_id, 1.0)]);
+        let mut distances = HashMap::from([(start_concept_id, 0)]);
+        let mut max_depth_reached = 0;
+        let mut frontier = vec![start_concept_id];
+        let mut depth = 0;
+
+        while !frontier.is_empty() && depth < config.max_depth {
+            let mut next_frontier = Vec::new();
+
+            for source in frontier {
+                let source_activation = activation_scores[&source];
+
+                for (neighbor, relationship) in self.traversable_neighbors(source, config).await? {
+                    // Don't let activation bounce straight back along the edge it arrived on
+                    if used_relationships.contains(&relationship.id) {
+                        continue;
+                    }
+
+                    let spread = source_activation
+                        * relationship.weight
+                        * config.activation_spread_factor
+                        * config.activation_decay_factor;
+                    if spread < config.activation_threshold {
+                        continue;
+                    }
+
+                    let newly_activated = !activation_scores.contains_key(&neighbor);
+                    if newly_activated && visited_concepts.len() >= config.max_nodes {
+                        continue;
+                    }
+
+                    let score = activation_scores.entry(neighbor).or_insert(0.0);
+                    *score = (*score + spread).min(1.0);
+                    used_relationships.insert(relationship.id);
+                    traversed_relationships.push(relationship.id);
+
+                    if newly_activated {
+                        distances.insert(neighbor, depth + 1);
+                        visited_concepts.push(neighbor);
+                        next_frontier.push(neighbor);
+                        max_depth_reached = depth + 1;
+                    }
+                }
+            }
+
+            frontier = next_frontier;
+            depth += 1;
+        }
+
+        // Most strongly activated concepts first
+        visited_concepts.sort_by(|a, b| {
+            activation_scores[b]
+                .partial_cmp(&activation_scores[a])
+                .unwrap_or(Ordering::Equal)
+        });
 
         Ok(TraversalResult {
             start_concept_id,
             algorithm: TraversalAlgorithm::SpreadingActivation,
-            visited_concepts: vec![start_concept_id],
-            traversed_relationships: Vec::new(),
+            total_nodes_visited: visited_concepts.len(),
+            visited_concepts,
+            traversed_relationships,
             activation_scores,
-            distances: HashMap::new(),
-            total_nodes_visited: 1,
-            max_depth_reached: 0,
+            distances,
+            max_depth_reached,
         })
     }
 
     pub async fn get_network_metrics(&self) -> Result<NetworkMetrics> {
-        // This would calculate comprehensive network metrics
-        // For now, return default metrics
-        Ok(NetworkMetrics::default())
+        let concepts = self.concept_repo.query_concepts(&ConceptQuery::default()).await?;
+        let relationships = self.relationship_repo.query_relationships(&RelationshipQuery::default()).await?;
+        Ok(NetworkMetrics::from_graph(concepts.iter().map(|c| c.id), &relationships))
     }
 
     pub async fn calculate_concept_similarity(&self, concept1_id: Uuid, concept2_id: Uuid) -> Result<f64> {
@@ -5,9 +5,10 @@
 //! are provided through trait implementations.
 
 use brain_types::*;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// Generic memory trait for all memory types
@@ -292,6 +293,10 @@ pub struct ConsolidationConfig {
     pub importance_threshold: f64,
     pub max_episodic_events: usize,
     pub semantic_extraction_threshold: f64,
+    /// How far back consolidation looks for episodic events to generalise
+    pub semantic_extraction_window_hours: i64,
+    /// Smallest cluster of similar events that becomes a new semantic concept
+    pub min_pattern_events: usize,
     pub decay_rate: f64,
     pub forgetting_threshold: f64,
 }
@@ -304,6 +309,8 @@ impl Default for ConsolidationConfig {
             importance_threshold: 0.5,
             max_episodic_events: 10000,
             semantic_extraction_threshold: 0.7,
+            semantic_extraction_window_hours: 168,
+            min_pattern_events: 2,
             decay_rate: 0.1,
             forgetting_threshold: 0.2,
         }
@@ -332,6 +339,8 @@ pub struct MemoryService {
     episodic_repo: Box<dyn EpisodicMemoryRepository>,
     semantic_repo: Box<dyn SemanticMemoryRepository>,
     consolidation_config: ConsolidationConfig,
+    /// Embeds episodic events for semantic extraction; extraction is off without one
+    embedding_provider: Option<Arc<dyn EmbeddingProvider>>,
 }
 
 impl MemoryService {
@@ -345,9 +354,22 @@ impl MemoryService {
             episodic_repo,
             semantic_repo,
             consolidation_config: ConsolidationConfig::default(),
+            embedding_provider: None,
         }
     }
 
+    /// Embed episodic events with `provider` so consolidation can generalise
+    /// them into semantic concepts; it must be the provider that produced the
+    /// embeddings already in semantic memory
+    pub fn with_embedding_provider(mut self, provider: Arc<dyn EmbeddingProvider>) -> Self {
+        self.embedding_provider = Some(provider);
+        self
+    }
+
+    pub fn embedding_provider(&self) -> Option<&Arc<dyn EmbeddingProvider>> {
+        self.embedding_provider.as_ref()
+    }
+
     pub async fn learn(&mut self, content: String, priority: Priority) -> Result<Uuid> {
         let item = WorkingMemoryItem::new(content, priority);
         let id = item.id;
@@ -420,10 +442,74 @@ impl MemoryService {
         })
     }
 
+    /// Cluster recent, not yet generalised episodic events and fold each
+    /// cluster into semantic memory, either strengthening the closest existing
+    /// concept or creating a new one. Events are linked back to their concept
+    /// through `SEMANTIC_CONCEPT_CONTEXT_KEY` so later runs skip them.
+    ///
+    /// Does nothing without an embedding provider, so semantic memory never
+    /// receives vectors from a space other than the one it is indexed in.
     async fn extract_semantic_patterns(&mut self) -> Result<usize> {
-        // This would implement pattern extraction logic
-        // For now, return 0 as placeholder
-        Ok(0)
+        let Some(provider) = self.embedding_provider.clone() else {
+            return Ok(0);
+        };
+
+        let now = Utc::now();
+        let window = Duration::hours(self.consolidation_config.semantic_extraction_window_hours);
+        let query = EpisodicQuery {
+            time_range: Some((now - window, now)),
+            limit: Some(self.consolidation_config.max_episodic_events),
+            ..Default::default()
+        };
+
+        let mut events: Vec<EpisodicEvent> = self.episodic_repo
+            .query_events(&query)
+            .await?
+            .into_iter()
+            .filter(|event| !event.context.contains_key(SEMANTIC_CONCEPT_CONTEXT_KEY))
+            .collect();
+        events.sort_by_key(|event| event.timestamp);
+
+        let mut embedded = Vec::with_capacity(events.len());
+        for event in events {
+            let embedding = provider.embed(&episodic_event_text(&event)).await?;
+            embedded.push((event, embedding));
+        }
+
+        let threshold = self.consolidation_config.semantic_extraction_threshold;
+        let mut extracted = 0;
+
+        for cluster in cluster_events(embedded, threshold) {
+            let matched = self.semantic_repo
+                .find_similar(&cluster.centroid, threshold, 1)
+                .await?
+                .first()
+                .map(|(id, _)| *id);
+
+            let concept_id = match matched {
+                Some(id) => match self.semantic_repo.get_concept(id).await? {
+                    Some(mut concept) => {
+                        cluster.strengthen(&mut concept);
+                        self.semantic_repo.update_concept(&concept).await?;
+                        id
+                    }
+                    None => continue,
+                },
+                None if cluster.events.len() >= self.consolidation_config.min_pattern_events => {
+                    self.semantic_repo.store_concept(cluster.to_concept()).await?
+                }
+                // Too small to generalise yet; leave the events for a later run
+                None => continue,
+            };
+
+            for mut event in cluster.events {
+                event.context.insert(SEMANTIC_CONCEPT_CONTEXT_KEY.to_string(), concept_id.to_string());
+                self.episodic_repo.update_event(&event).await?;
+            }
+            extracted += 1;
+        }
+
+        Ok(extracted)
     }
 
     pub async fn query_all_memories(&self, content_pattern: &str) -> Result<CrossMemoryResults> {
@@ -462,6 +548,254 @@ impl MemoryService {
     }
 }
 
+/// Context key set on an episodic event once it has been generalised into a
+/// semantic concept; the value is the concept id
+pub const SEMANTIC_CONCEPT_CONTEXT_KEY: &str = "semantic_concept_id";
+
+/// Turns text into embeddings in the vector space semantic memory is indexed in
+///
+/// Everything written to one semantic memory must come from the same provider,
+/// otherwise similarity search compares vectors that mean different things.
+#[async_trait::async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Length of every embedding this provider returns
+    fn dimensions(&self) -> usize;
+
+    /// Embed `text`
+    async fn embed(&self, text: &str) -> Result<Vec<f32>>;
+}
+
+/// Default number of buckets used by `KeywordEmbeddingProvider`
+pub const KEYWORD_EMBEDDING_DIMENSIONS: usize = 64;
+
+/// Words too common to say anything about what an event is about
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "have", "in", "is",
+    "it", "of", "on", "or", "that", "the", "this", "to", "was", "were", "with",
+];
+
+/// Hashed bag-of-words embeddings: content words are hashed into a fixed
+/// number of buckets and the counts normalised
+///
+/// Needs no model, so it suits offline use and tests, but its vectors are not
+/// comparable with model embeddings; use it for a semantic memory only when
+/// nothing else writes embeddings there.
+#[derive(Debug, Clone)]
+pub struct KeywordEmbeddingProvider {
+    dimensions: usize,
+}
+
+impl KeywordEmbeddingProvider {
+    pub fn new(dimensions: usize) -> Self {
+        Self { dimensions: dimensions.max(1) }
+    }
+
+    /// Embed `text` without going through the async trait
+    pub fn embed_text(&self, text: &str) -> Vec<f32> {
+        let mut counts = vec![0.0; self.dimensions];
+        for token in content_keywords(text) {
+            counts[(brain_types::math::fnv1a(token.as_bytes()) % self.dimensions as u64) as usize] += 1.0;
+        }
+        normalized(counts)
+    }
+}
+
+impl Default for KeywordEmbeddingProvider {
+    fn default() -> Self {
+        Self::new(KEYWORD_EMBEDDING_DIMENSIONS)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for KeywordEmbeddingProvider {
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        Ok(self.embed_text(text))
+    }
+}
+
+/// Text an episodic event is embedded from: its content followed by its tags
+pub fn episodic_event_text(event: &EpisodicEvent) -> String {
+    let mut text = event.content.clone();
+    for tag in &event.tags {
+        text.push(' ');
+        text.push_str(tag);
+    }
+    text
+}
+
+/// Lowercased words of `content` with single characters and stop words removed
+pub fn content_keywords(content: &str) -> Vec<String> {
+    content
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.chars().count() > 1 && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+fn normalized(mut embedding: Vec<f32>) -> Vec<f32> {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in &mut embedding {
+            *value /= norm;
+        }
+    }
+    embedding
+}
+
+/// Episodic events judged similar enough to describe one pattern
+struct EventCluster {
+    events: Vec<EpisodicEvent>,
+    embeddings: Vec<Vec<f32>>,
+    centroid: Vec<f32>,
+}
+
+impl EventCluster {
+    fn new(event: EpisodicEvent, embedding: Vec<f32>) -> Self {
+        Self {
+            centroid: embedding.clone(),
+            events: vec![event],
+            embeddings: vec![embedding],
+        }
+    }
+
+    fn add(&mut self, event: EpisodicEvent, embedding: Vec<f32>) {
+        self.events.push(event);
+        self.embeddings.push(embedding);
+        let members: Vec<&Vec<f32>> = self.embeddings.iter().collect();
+        self.centroid = average_embeddings(&members);
+    }
+
+    /// Mean similarity of the members to the centroid
+    fn cohesion(&self) -> f64 {
+        let total: f64 = self.embeddings.iter().map(|e| cosine_similarity(e, &self.centroid)).sum();
+        total / self.embeddings.len() as f64
+    }
+
+    /// Name the pattern after a tag most of its events share, falling back to
+    /// the words that occur in the most events
+    fn name(&self) -> String {
+        let majority = self.events.len() / 2 + 1;
+
+        let mut tag_counts: HashMap<String, usize> = HashMap::new();
+        for event in &self.events {
+            let mut seen: Vec<String> = event.tags.iter().map(|tag| tag.to_lowercase()).collect();
+            seen.sort();
+            seen.dedup();
+            for tag in seen {
+                *tag_counts.entry(tag).or_insert(0) += 1;
+            }
+        }
+        if let Some(tag) = ranked_by_count(tag_counts).into_iter().find(|(_, count)| *count >= majority) {
+            return tag.0;
+        }
+
+        let mut word_counts: HashMap<String, usize> = HashMap::new();
+        for event in &self.events {
+            let mut seen = content_keywords(&event.content);
+            seen.sort();
+            seen.dedup();
+            for word in seen {
+                *word_counts.entry(word).or_insert(0) += 1;
+            }
+        }
+        let words: Vec<String> = ranked_by_count(word_counts).into_iter().take(3).map(|(word, _)| word).collect();
+        if words.is_empty() {
+            "episodic pattern".to_string()
+        } else {
+            words.join(" ")
+        }
+    }
+
+    fn to_concept(&self) -> SemanticConcept {
+        let mut concept = SemanticConcept::new(
+            self.name(),
+            format!("Pattern extracted from {} episodic events", self.events.len()),
+            self.centroid.clone(),
+        );
+        concept.frequency = self.events.len() as u32;
+        concept.confidence = self.cohesion().clamp(0.0, 1.0);
+        concept.source_events = self.events.iter().map(|event| event.id).collect();
+        concept
+    }
+
+    /// Fold this cluster into an existing concept, moving its embedding
+    /// towards the cluster by the share of evidence the cluster adds
+    fn strengthen(&self, concept: &mut SemanticConcept) {
+        let previous = concept.frequency as f32;
+        let added = self.events.len() as f32;
+        if concept.embedding.len() == self.centroid.len() {
+            for (value, centroid) in concept.embedding.iter_mut().zip(&self.centroid) {
+                *value = (*value * previous + centroid * added) / (previous + added);
+            }
+        }
+
+        concept.frequency += self.events.len() as u32;
+        for event in &self.events {
+            if !concept.source_events.contains(&event.id) {
+                concept.source_events.push(event.id);
+            }
+        }
+        concept.update_confidence(true);
+    }
+}
+
+fn ranked_by_count(counts: HashMap<String, usize>) -> Vec<(String, usize)> {
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+}
+
+/// Greedy centroid clustering: each event joins the most similar cluster at
+/// or above `threshold`, otherwise it starts a new one
+fn cluster_events(events: Vec<(EpisodicEvent, Vec<f32>)>, threshold: f64) -> Vec<EventCluster> {
+    let mut clusters: Vec<EventCluster> = Vec::new();
+
+    for (event, embedding) in events {
+        let best = clusters
+            .iter()
+            .enumerate()
+            .map(|(index, cluster)| (index, cosine_similarity(&embedding, &cluster.centroid)))
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match best {
+            Some((index, _)) => clusters[index].add(event, embedding),
+            None => clusters.push(EventCluster::new(event, embedding)),
+        }
+    }
+
+    clusters
+}
+
+/// Average multiple embeddings into one
+pub fn average_embeddings(embeddings: &[&Vec<f32>]) -> Vec<f32> {
+    if embeddings.is_empty() {
+        return Vec::new();
+    }
+
+    let len = embeddings[0].len();
+    let mut result = vec![0.0; len];
+
+    for embedding in embeddings {
+        for (i, &value) in embedding.iter().enumerate() {
+            if i < len {
+                result[i] += value;
+            }
+        }
+    }
+
+    let count = embeddings.len() as f32;
+    for value in &mut result {
+        *value /= count;
+    }
+
+    result
+}
+
 /// Calculate cosine similarity between two vectors
 pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
     if a.len() != b.len() || a.is_empty() {
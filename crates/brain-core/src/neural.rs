@@ -52,6 +52,12 @@ pub struct TransformerConfig {
     pub max_seq_len: usize,
     /// Dropout rate
     pub dropout_rate: f64,
+    /// Let each position attend only to itself and earlier positions
+    ///
+    /// Required for training, where every position predicts its next token.
+    /// Off by default, so untrained predictors attend over the whole input.
+    #[serde(default)]
+    pub causal: bool,
 }
 
 impl Default for TransformerConfig {
@@ -63,6 +69,34 @@ impl Default for TransformerConfig {
             ff_hidden_dim: 2048,
             max_seq_len: 1024,
             dropout_rate: 0.1,
+            causal: false,
+        }
+    }
+}
+
+/// Optimizer settings for training a transformer predictor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformerTrainingConfig {
+    /// Adam step size
+    pub learning_rate: f64,
+    /// Exponential decay rate for the first moment estimates
+    pub beta1: f64,
+    /// Exponential decay rate for the second moment estimates
+    pub beta2: f64,
+    /// Small constant added to the denominator for numerical stability
+    pub epsilon: f64,
+    /// Gradients are rescaled so their global L2 norm never exceeds this
+    pub max_grad_norm: f64,
+}
+
+impl Default for TransformerTrainingConfig {
+    fn default() -> Self {
+        Self {
+            learning_rate: 1e-3,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            max_grad_norm: 1.0,
         }
     }
 }
@@ -250,6 +284,31 @@ pub trait LayerNormService: Send + Sync {
     async fn forward(&self, input: &DMatrix<f64>) -> Result<DMatrix<f64>>;
 }
 
+/// Named weight tensor in a transformer checkpoint
+///
+/// Data is stored column-major, matching nalgebra; vectors are single-column tensors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointTensor {
+    /// Parameter path, e.g. `encoders.0.attention.w_query`
+    pub name: String,
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<f64>,
+}
+
+/// Configuration, vocabulary and weights needed to rebuild a trained transformer predictor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformerCheckpoint {
+    /// Architecture the weights were trained with
+    pub config: TransformerConfig,
+    /// Number of rows in the embedding matrix
+    pub vocab_size: usize,
+    /// Token strings indexed by token id; empty when the caller manages its own vocabulary
+    pub vocab: Vec<String>,
+    /// Trainable parameters
+    pub tensors: Vec<CheckpointTensor>,
+}
+
 /// Repository trait for neural models
 #[async_trait::async_trait]
 pub trait NeuralRepository: Send + Sync {
@@ -267,6 +326,12 @@ pub trait NeuralRepository: Send + Sync {
     
     /// Load developmental state
     async fn load_developmental_state(&self) -> Result<Option<DevelopmentalState>>;
+    
+    /// Save trained transformer weights
+    async fn save_checkpoint(&mut self, checkpoint: &TransformerCheckpoint) -> Result<()>;
+    
+    /// Load trained transformer weights
+    async fn load_checkpoint(&self) -> Result<Option<TransformerCheckpoint>>;
 }
 
 /// Neural service for model management
@@ -303,4 +368,12 @@ impl NeuralService {
     pub async fn get_developmental_state(&self) -> Result<Option<DevelopmentalState>> {
         self.repository.load_developmental_state().await
     }
+
+    pub async fn save_checkpoint(&mut self, checkpoint: TransformerCheckpoint) -> Result<()> {
+        self.repository.save_checkpoint(&checkpoint).await
+    }
+
+    pub async fn get_checkpoint(&self) -> Result<Option<TransformerCheckpoint>> {
+        self.repository.load_checkpoint().await
+    }
 }
@@ -57,6 +57,9 @@ pub struct BranchingConfig {
     pub enable_aggressive_pruning: bool,
     /// Maximum simulation time per branch (seconds)
     pub max_simulation_time_seconds: u64,
+    /// How the branch tree is explored
+    #[serde(default)]
+    pub search_strategy: SearchStrategy,
 }
 
 impl Default for BranchingConfig {
@@ -69,10 +72,64 @@ impl Default for BranchingConfig {
             pruning_threshold: 0.3,
             enable_aggressive_pruning: true,
             max_simulation_time_seconds: 300,
+            search_strategy: SearchStrategy::default(),
         }
     }
 }
 
+/// Strategy for exploring the branch tree of a branching simulation
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum SearchStrategy {
+    /// Expand every surviving branch, one level per step
+    #[default]
+    BreadthFirst,
+    /// Always expand the open branch with the highest accumulated confidence,
+    /// stopping at the first branch that reaches the goal
+    BestFirst,
+    /// Expand level by level, keeping only the `width` most confident branches
+    Beam { width: usize },
+    /// Monte Carlo Tree Search with UCT selection over actions
+    MonteCarlo(MonteCarloConfig),
+}
+
+/// Configuration for Monte Carlo Tree Search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// Number of select-expand-rollout-backpropagate iterations
+    pub iterations: usize,
+    /// Exploration constant of the UCT formula
+    pub exploration_weight: f64,
+    /// Maximum number of actions applied during a rollout
+    pub rollout_depth: usize,
+    /// How rollouts choose actions
+    pub rollout_policy: RolloutPolicy,
+    /// Seed for reproducible searches
+    pub seed: Option<u64>,
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 500,
+            exploration_weight: std::f64::consts::SQRT_2,
+            rollout_depth: 10,
+            rollout_policy: RolloutPolicy::ConfidenceWeighted,
+            seed: None,
+        }
+    }
+}
+
+/// Action selection during Monte Carlo rollouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RolloutPolicy {
+    /// Pick uniformly among applicable actions
+    Random,
+    /// Pick with probability proportional to action confidence
+    ConfidenceWeighted,
+    /// Always pick the highest-priority, most confident action
+    Greedy,
+}
+
 /// Property of a simulation state entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StateProperty {
@@ -291,12 +348,20 @@ pub enum ComparisonOperator {
     GreaterThan,
     /// Less than
     LessThan,
+    /// Greater than or equal to
+    GreaterThanOrEqual,
+    /// Less than or equal to
+    LessThanOrEqual,
     /// Contains substring
     Contains,
     /// Does not contain substring
     NotContains,
     /// Matches regex pattern
     Matches,
+    /// Starts with prefix
+    StartsWith,
+    /// Ends with suffix
+    EndsWith,
 }
 
 /// Effect that an action will have on the simulation state
@@ -394,7 +459,7 @@ pub struct BranchingResult {
     pub branches: HashMap<Uuid, SimulationBranch>,
     /// Root branch ID
     pub root_branch_id: Uuid,
-    /// Most likely outcome branches (sorted by confidence)
+    /// Most likely outcome branches (goal-reaching first, then by confidence)
     pub most_likely_outcomes: Vec<Uuid>,
     /// Total number of branches explored
     pub total_branches_explored: usize,
@@ -406,6 +471,27 @@ pub struct BranchingResult {
     pub execution_time_ms: u64,
     /// Branching statistics
     pub branching_stats: BranchingStats,
+    /// Why branches were pruned
+    #[serde(default)]
+    pub pruning_stats: PruningStatistics,
+    /// Action sequences leading to the most likely outcomes, goal-reaching paths first
+    #[serde(default)]
+    pub most_likely_paths: Vec<SimulationPath>,
+}
+
+/// Path from the root of a branch tree to one of its branches
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationPath {
+    /// Branch the path ends at
+    pub branch_id: Uuid,
+    /// IDs of the actions applied along the path, in order
+    pub action_ids: Vec<Uuid>,
+    /// Names of the actions applied along the path, in order
+    pub action_names: Vec<String>,
+    /// Accumulated confidence of the final branch
+    pub confidence: f64,
+    /// Whether the final state satisfies every goal condition
+    pub reaches_goal: bool,
 }
 
 /// Statistics for branching simulations
@@ -425,6 +511,32 @@ pub struct BranchingStats {
     pub complexity_score: f64,
 }
 
+/// Counts of branches pruned during a branching simulation, by cause
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruningStatistics {
+    /// Branches below the minimum branch confidence or pruning threshold
+    pub low_confidence_pruned: usize,
+    /// Branches beyond the maximum number of active branches
+    pub resource_limit_pruned: usize,
+    /// Branches violating a mandatory constraint
+    pub constraint_violation_pruned: usize,
+    /// Branches left unexpanded when the time limit ran out
+    pub time_limit_pruned: usize,
+    /// Branches dropped by aggressive pruning
+    pub aggressive_pruned: usize,
+}
+
+impl PruningStatistics {
+    /// Total number of pruned branches
+    pub fn total(&self) -> usize {
+        self.low_confidence_pruned
+            + self.resource_limit_pruned
+            + self.constraint_violation_pruned
+            + self.time_limit_pruned
+            + self.aggressive_pruned
+    }
+}
+
 /// Constraint for simulation behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationConstraint {
@@ -0,0 +1,436 @@
+//! Concept Graph Backend Selection
+//!
+//! `ConceptGraphStore` opens whichever store `ConceptGraphConfig::backend`
+//! names and exposes it through the same repository traits, so callers can
+//! swap the in-memory, SQLite and Neo4j graphs without code changes.
+
+use crate::concept_store::SqliteConceptStore;
+use crate::concepts::{ConceptGraphBackend, ConceptGraphConfig, ConceptGraphManager};
+use crate::database::DatabaseManager;
+use crate::neo4j_concepts::Neo4jConceptRepository;
+use brain_core::*;
+use brain_types::*;
+use uuid::Uuid;
+
+/// A concept graph opened from a `ConceptGraphConfig`
+#[derive(Debug)]
+pub enum ConceptGraphStore {
+    /// In-memory graph, written through to SQLite when configured
+    Managed(Box<ConceptGraphManager>),
+    /// Graph held entirely in Neo4j
+    Neo4j(Neo4jConceptRepository),
+}
+
+impl ConceptGraphStore {
+    /// Open the backend selected by `config.backend`
+    pub async fn open(config: ConceptGraphConfig) -> Result<Self> {
+        match config.backend.clone() {
+            ConceptGraphBackend::InMemory => Ok(Self::Managed(Box::new(ConceptGraphManager::new(config).await?))),
+            ConceptGraphBackend::Sqlite { path } => {
+                let database = DatabaseManager::new_file(&path).await?;
+                let store = SqliteConceptStore::new(&database).await?;
+                Ok(Self::Managed(Box::new(ConceptGraphManager::with_sqlite_store(config, store).await?)))
+            }
+            ConceptGraphBackend::Neo4j => Ok(Self::Neo4j(Neo4jConceptRepository::connect(&config).await?)),
+        }
+    }
+
+    /// Short name of the active backend for logging
+    pub fn backend_name(&self) -> &'static str {
+        match self {
+            Self::Managed(manager) if manager.is_persistent() => "sqlite",
+            Self::Managed(_) => "in-memory",
+            Self::Neo4j(_) => "neo4j",
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConceptRepository for ConceptGraphStore {
+    async fn create_concept(&mut self, concept: ConceptNode) -> Result<Uuid> {
+        match self {
+            Self::Managed(manager) => manager.create_concept(concept).await,
+            Self::Neo4j(repository) => repository.create_concept(concept).await,
+        }
+    }
+
+    async fn get_concept(&self, id: Uuid) -> Result<Option<ConceptNode>> {
+        match self {
+            Self::Managed(manager) => manager.get_concept(id).await,
+            Self::Neo4j(repository) => repository.get_concept(id).await,
+        }
+    }
+
+    async fn update_concept(&mut self, concept: &ConceptNode) -> Result<()> {
+        match self {
+            Self::Managed(manager) => manager.update_concept(concept).await,
+            Self::Neo4j(repository) => repository.update_concept(concept).await,
+        }
+    }
+
+    async fn delete_concept(&mut self, id: Uuid) -> Result<bool> {
+        match self {
+            Self::Managed(manager) => manager.delete_concept(id).await,
+            Self::Neo4j(repository) => repository.delete_concept(id).await,
+        }
+    }
+
+    async fn query_concepts(&self, query: &ConceptQuery) -> Result<Vec<ConceptNode>> {
+        match self {
+            Self::Managed(manager) => manager.query_concepts(query).await,
+            Self::Neo4j(repository) => repository.query_concepts(query).await,
+        }
+    }
+
+    async fn mark_concept_accessed(&mut self, id: Uuid) -> Result<bool> {
+        match self {
+            Self::Managed(manager) => manager.mark_concept_accessed(id).await,
+            Self::Neo4j(repository) => repository.mark_concept_accessed(id).await,
+        }
+    }
+
+    async fn get_concept_count(&self) -> Result<usize> {
+        match self {
+            Self::Managed(manager) => manager.get_concept_count().await,
+            Self::Neo4j(repository) => repository.get_concept_count().await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationshipRepository for ConceptGraphStore {
+    async fn create_relationship(&mut self, relationship: ConceptRelationship) -> Result<Uuid> {
+        match self {
+            Self::Managed(manager) => manager.create_relationship(relationship).await,
+            Self::Neo4j(repository) => repository.create_relationship(relationship).await,
+        }
+    }
+
+    async fn get_relationship(&self, id: Uuid) -> Result<Option<ConceptRelationship>> {
+        match self {
+            Self::Managed(manager) => manager.get_relationship(id).await,
+            Self::Neo4j(repository) => repository.get_relationship(id).await,
+        }
+    }
+
+    async fn update_relationship(&mut self, relationship: &ConceptRelationship) -> Result<()> {
+        match self {
+            Self::Managed(manager) => manager.update_relationship(relationship).await,
+            Self::Neo4j(repository) => repository.update_relationship(relationship).await,
+        }
+    }
+
+    async fn delete_relationship(&mut self, id: Uuid) -> Result<bool> {
+        match self {
+            Self::Managed(manager) => manager.delete_relationship(id).await,
+            Self::Neo4j(repository) => repository.delete_relationship(id).await,
+        }
+    }
+
+    async fn query_relationships(&self, query: &RelationshipQuery) -> Result<Vec<ConceptRelationship>> {
+        match self {
+            Self::Managed(manager) => manager.query_relationships(query).await,
+            Self::Neo4j(repository) => repository.query_relationships(query).await,
+        }
+    }
+
+    async fn get_concept_relationships(&self, concept_id: Uuid) -> Result<Vec<ConceptRelationship>> {
+        match self {
+            Self::Managed(manager) => manager.get_concept_relationships(concept_id).await,
+            Self::Neo4j(repository) => repository.get_concept_relationships(concept_id).await,
+        }
+    }
+
+    async fn activate_relationship(&mut self, id: Uuid) -> Result<bool> {
+        match self {
+            Self::Managed(manager) => manager.activate_relationship(id).await,
+            Self::Neo4j(repository) => repository.activate_relationship(id).await,
+        }
+    }
+
+    async fn apply_decay_to_all(&mut self, time_delta_hours: f64) -> Result<usize> {
+        match self {
+            Self::Managed(manager) => manager.apply_decay_to_all(time_delta_hours).await,
+            Self::Neo4j(repository) => repository.apply_decay_to_all(time_delta_hours).await,
+        }
+    }
+
+    async fn prune_weak_relationships(&mut self) -> Result<usize> {
+        match self {
+            Self::Managed(manager) => manager.prune_weak_relationships().await,
+            Self::Neo4j(repository) => repository.prune_weak_relationships().await,
+        }
+    }
+
+    async fn get_relationship_count(&self) -> Result<usize> {
+        match self {
+            Self::Managed(manager) => manager.get_relationship_count().await,
+            Self::Neo4j(repository) => repository.get_relationship_count().await,
+        }
+    }
+}
+
+/// Behaviour every backend must share. The Neo4j run needs a disposable
+/// server (e.g. `docker run -p 7687:7687 -e NEO4J_AUTH=neo4j/password neo4j:5`)
+/// named by `BRAIN_TEST_NEO4J_URI`; it wipes that database and is skipped
+/// when the variable is unset.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neo4j_concepts::{CypherExecutor, CypherStatement};
+    use crate::neo4j_stand_in::Neo4jStandIn;
+    use std::sync::Arc;
+
+    fn concept(concept_type: ConceptType, content: &str, confidence: f64) -> ConceptNode {
+        ConceptNode::new(concept_type, content.to_string(), confidence, None)
+    }
+
+    async fn run_conformance_suite(mut store: ConceptGraphStore) {
+        let backend = store.backend_name();
+
+        // Concept CRUD
+        let mut learning = concept(ConceptType::Abstract, "Machine Learning", 0.9);
+        learning.description = Some("learning from data".to_string());
+        learning.set_metadata("origin".to_string(), "conformance".to_string());
+        let learning_id = store.create_concept(learning).await.unwrap();
+        let data_id = store.create_concept(concept(ConceptType::Entity, "data", 0.6)).await.unwrap();
+        let model_id = store.create_concept(concept(ConceptType::Entity, "model", 0.75)).await.unwrap();
+        let train_id = store.create_concept(concept(ConceptType::Action, "train", 0.4)).await.unwrap();
+        assert_eq!(store.get_concept_count().await.unwrap(), 4, "{}", backend);
+
+        let stored = store.get_concept(learning_id).await.unwrap().unwrap();
+        assert_eq!(stored.concept_type, ConceptType::Abstract, "{}", backend);
+        assert_eq!(stored.description.as_deref(), Some("learning from data"), "{}", backend);
+        assert_eq!(stored.source_reference, None, "{}", backend);
+        assert_eq!(stored.get_metadata("origin").map(String::as_str), Some("conformance"), "{}", backend);
+        assert_eq!(stored.usage_count, 1, "{}", backend);
+        assert!(store.get_concept(Uuid::new_v4()).await.unwrap().is_none(), "{}", backend);
+
+        assert!(store.mark_concept_accessed(learning_id).await.unwrap(), "{}", backend);
+        assert!(!store.mark_concept_accessed(Uuid::new_v4()).await.unwrap(), "{}", backend);
+        assert_eq!(store.get_concept(learning_id).await.unwrap().unwrap().usage_count, 2, "{}", backend);
+
+        let mut model = store.get_concept(model_id).await.unwrap().unwrap();
+        model.update_confidence(0.8);
+        store.update_concept(&model).await.unwrap();
+        assert_eq!(store.get_concept(model_id).await.unwrap().unwrap().confidence_score, 0.8, "{}", backend);
+
+        // Concept queries
+        let entities = store
+            .query_concepts(&ConceptQuery { concept_type: Some(ConceptType::Entity), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(entities.len(), 2, "{}", backend);
+
+        let matched = store
+            .query_concepts(&ConceptQuery { content_pattern: Some("LEARN".to_string()), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(matched.iter().map(|c| c.id).collect::<Vec<_>>(), vec![learning_id], "{}", backend);
+
+        let ranked = store
+            .query_concepts(&ConceptQuery {
+                min_confidence: Some(0.5),
+                sort_by: Some("confidence".to_string()),
+                descending: true,
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(ranked.iter().map(|c| c.id).collect::<Vec<_>>(), vec![learning_id, model_id], "{}", backend);
+
+        // Relationship CRUD and queries
+        let missing = ConceptRelationship::new(learning_id, Uuid::new_v4(), RelationshipType::Uses, 0.5);
+        assert!(matches!(store.create_relationship(missing).await, Err(BrainError::NotFound(_))), "{}", backend);
+
+        let uses = store
+            .create_relationship(ConceptRelationship::new(learning_id, data_id, RelationshipType::Uses, 0.5))
+            .await
+            .unwrap();
+        let produces = store
+            .create_relationship(ConceptRelationship::new(
+                learning_id,
+                model_id,
+                RelationshipType::Custom("PRODUCES".to_string()),
+                0.8,
+            ))
+            .await
+            .unwrap();
+        let weak = store
+            .create_relationship(ConceptRelationship::new(train_id, model_id, RelationshipType::Causes, 0.15))
+            .await
+            .unwrap();
+        assert_eq!(store.get_relationship_count().await.unwrap(), 3, "{}", backend);
+
+        let stored = store.get_relationship(produces).await.unwrap().unwrap();
+        assert_eq!(stored.source_id, learning_id, "{}", backend);
+        assert_eq!(stored.target_id, model_id, "{}", backend);
+        assert_eq!(stored.relationship_type, RelationshipType::Custom("PRODUCES".to_string()), "{}", backend);
+
+        let outgoing = store
+            .query_relationships(&RelationshipQuery {
+                source_id: Some(learning_id),
+                sort_by: Some("weight".to_string()),
+                descending: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(outgoing.iter().map(|r| r.id).collect::<Vec<_>>(), vec![produces, uses], "{}", backend);
+
+        let strong = store
+            .query_relationships(&RelationshipQuery { min_weight: Some(0.6), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(strong.len(), 1, "{}", backend);
+
+        let mut touching_model: Vec<Uuid> =
+            store.get_concept_relationships(model_id).await.unwrap().iter().map(|r| r.id).collect();
+        touching_model.sort();
+        let mut expected = vec![produces, weak];
+        expected.sort();
+        assert_eq!(touching_model, expected, "{}", backend);
+
+        // Hebbian activation
+        assert!(store.activate_relationship(uses).await.unwrap(), "{}", backend);
+        assert!(!store.activate_relationship(Uuid::new_v4()).await.unwrap(), "{}", backend);
+        let activated = store.get_relationship(uses).await.unwrap().unwrap();
+        assert_eq!(activated.activation_count, 1, "{}", backend);
+        assert!((activated.weight - 0.55).abs() < 1e-9, "{}: {}", backend, activated.weight);
+
+        // Decay to the base-weight floor, then prune below the threshold
+        assert_eq!(store.apply_decay_to_all(10.0).await.unwrap(), 3, "{}", backend);
+        let decayed = store.get_relationship(produces).await.unwrap().unwrap();
+        assert!((decayed.weight - 0.8 * (-0.1f64).exp()).abs() < 1e-9, "{}: {}", backend, decayed.weight);
+
+        let mut fading = store.get_relationship(weak).await.unwrap().unwrap();
+        fading.configure_learning(0.1, 1.0, 0.1);
+        store.update_relationship(&fading).await.unwrap();
+        store.apply_decay_to_all(10.0).await.unwrap();
+        let floored = store.get_relationship(weak).await.unwrap().unwrap();
+        assert!((floored.weight - 0.015).abs() < 1e-9, "{}: {}", backend, floored.weight);
+
+        assert_eq!(store.prune_weak_relationships().await.unwrap(), 1, "{}", backend);
+        assert!(store.get_relationship(weak).await.unwrap().is_none(), "{}", backend);
+
+        // Deleting a concept takes its relationships with it
+        assert!(store.delete_relationship(uses).await.unwrap(), "{}", backend);
+        assert!(!store.delete_relationship(uses).await.unwrap(), "{}", backend);
+        assert!(store.delete_concept(model_id).await.unwrap(), "{}", backend);
+        assert!(!store.delete_concept(model_id).await.unwrap(), "{}", backend);
+        assert!(store.get_relationship(produces).await.unwrap().is_none(), "{}", backend);
+        assert_eq!(store.get_relationship_count().await.unwrap(), 0, "{}", backend);
+        assert_eq!(store.get_concept_count().await.unwrap(), 3, "{}", backend);
+    }
+
+    /// Neo4j backend running against the in-process stand-in
+    async fn neo4j_stand_in() -> Neo4jConceptRepository {
+        Neo4jConceptRepository::with_executor(Arc::new(Neo4jStandIn::default()), "neo4j").await.unwrap()
+    }
+
+    /// Connection for the live Neo4j tests, or `None` to skip them
+    ///
+    /// Run them against a scratch database, whose graph they clear, with
+    /// `BRAIN_TEST_NEO4J_URI=bolt://localhost:7687 cargo test -p brain-infra neo4j`.
+    /// `BRAIN_TEST_NEO4J_USER`, `BRAIN_TEST_NEO4J_PASSWORD` and `BRAIN_TEST_NEO4J_DATABASE`
+    /// override the defaults.
+    fn neo4j_test_config() -> Option<ConceptGraphConfig> {
+        let Ok(uri) = std::env::var("BRAIN_TEST_NEO4J_URI") else {
+            eprintln!("skipping live Neo4j test: BRAIN_TEST_NEO4J_URI is not set");
+            return None;
+        };
+        let defaults = ConceptGraphConfig::default();
+        Some(ConceptGraphConfig {
+            uri,
+            username: std::env::var("BRAIN_TEST_NEO4J_USER").unwrap_or(defaults.username),
+            password: std::env::var("BRAIN_TEST_NEO4J_PASSWORD").unwrap_or(defaults.password),
+            database: std::env::var("BRAIN_TEST_NEO4J_DATABASE").ok(),
+            backend: ConceptGraphBackend::Neo4j,
+            ..defaults
+        })
+    }
+
+    /// Neighbourhood and shortest-path queries on a chain a-b-c-d with a weak a-d shortcut
+    async fn check_traversal(mut repository: Neo4jConceptRepository) {
+        let mut ids = Vec::new();
+        for name in ["a", "b", "c", "d"] {
+            ids.push(repository.create_concept(concept(ConceptType::Entity, name, 0.9)).await.unwrap());
+        }
+        for (source, target, weight) in [(0, 1, 0.9), (1, 2, 0.8), (2, 3, 0.7), (0, 3, 0.05)] {
+            repository
+                .create_relationship(ConceptRelationship::new(ids[source], ids[target], RelationshipType::SimilarTo, weight))
+                .await
+                .unwrap();
+        }
+
+        let neighborhood = repository.find_neighborhood(ids[0], 2, 0.1).await.unwrap();
+        assert_eq!(neighborhood, vec![(ids[1], 1), (ids[2], 2)]);
+
+        let path = repository.find_shortest_path(ids[0], ids[3], 5, 0.1).await.unwrap();
+        assert_eq!(path, Some(ids.clone()));
+        let direct = repository.find_shortest_path(ids[0], ids[3], 5, 0.0).await.unwrap();
+        assert_eq!(direct, Some(vec![ids[0], ids[3]]));
+        assert_eq!(repository.find_shortest_path(ids[0], ids[3], 2, 0.1).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend_conformance() {
+        let store = ConceptGraphStore::open(ConceptGraphConfig::default()).await.unwrap();
+        assert_eq!(store.backend_name(), "in-memory");
+        run_conformance_suite(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_backend_conformance() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ConceptGraphConfig {
+            backend: ConceptGraphBackend::Sqlite { path: dir.path().join("graph.db") },
+            ..Default::default()
+        };
+        let store = ConceptGraphStore::open(config.clone()).await.unwrap();
+        assert_eq!(store.backend_name(), "sqlite");
+        run_conformance_suite(store).await;
+
+        let reopened = ConceptGraphStore::open(config).await.unwrap();
+        assert_eq!(reopened.get_concept_count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_stand_in_backend_conformance() {
+        let store = ConceptGraphStore::Neo4j(neo4j_stand_in().await);
+        assert_eq!(store.backend_name(), "neo4j");
+        run_conformance_suite(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_stand_in_traversal_is_pushed_down() {
+        check_traversal(neo4j_stand_in().await).await;
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_stand_in_rejects_unknown_statements() {
+        let stand_in = Neo4jStandIn::default();
+        let error = stand_in.run(CypherStatement::new("MATCH (n) SET n.flag = true")).await.unwrap_err();
+        assert!(matches!(error, BrainError::DatabaseError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_backend_conformance() {
+        let Some(config) = neo4j_test_config() else { return };
+        let store = ConceptGraphStore::open(config).await.unwrap();
+        let ConceptGraphStore::Neo4j(repository) = &store else {
+            panic!("expected the Neo4j backend");
+        };
+        repository.clear().await.unwrap();
+        run_conformance_suite(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_traversal_is_pushed_down() {
+        let Some(config) = neo4j_test_config() else { return };
+        let repository = Neo4jConceptRepository::connect(&config).await.unwrap();
+        repository.clear().await.unwrap();
+        check_traversal(repository).await;
+    }
+}
@@ -0,0 +1,353 @@
+//! Concept Graph Persistence
+//!
+//! SQLite persistence for concept nodes and relationships on the `concepts`
+//! and `relationships` tables created by `DatabaseManager::initialize_schema`.
+//! `ConceptGraphManager` bulk-loads from this store at startup and writes
+//! every change through to it, so the learned graph survives restarts.
+
+use crate::database::{column, parse_timestamp, DatabaseManager};
+use brain_core::*;
+use brain_types::*;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// SQLite-backed store for the concept graph
+#[derive(Debug, Clone)]
+pub struct SqliteConceptStore {
+    pool: SqlitePool,
+}
+
+impl SqliteConceptStore {
+    /// Create a store on an existing database, initializing the schema if needed
+    pub async fn new(database: &DatabaseManager) -> Result<Self> {
+        database.initialize_schema().await?;
+        Ok(Self { pool: database.pool().clone() })
+    }
+
+    /// Load every stored concept
+    pub async fn load_concepts(&self) -> Result<Vec<ConceptNode>> {
+        let rows = sqlx::query(
+            "SELECT id, concept_type, content, description, created_at, last_accessed_at,
+                    usage_count, confidence_score, source_reference, metadata
+             FROM concepts",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to load concepts: {}", e)))?;
+
+        rows.iter().map(row_to_concept).collect()
+    }
+
+    /// Load every stored relationship
+    pub async fn load_relationships(&self) -> Result<Vec<ConceptRelationship>> {
+        let rows = sqlx::query(
+            "SELECT id, source_id, target_id, relationship_type, weight, activation_count,
+                    created_at, last_activated_at, base_weight, learning_rate, decay_rate,
+                    pruning_threshold, metadata
+             FROM relationships",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to load relationships: {}", e)))?;
+
+        rows.iter().map(row_to_relationship).collect()
+    }
+
+    /// Insert a concept or update the stored one in place
+    pub async fn save_concept(&self, concept: &ConceptNode) -> Result<()> {
+        let mut tx = self.begin().await?;
+        upsert_concept(&mut tx, concept).await?;
+        commit(tx).await
+    }
+
+    /// Delete a concept together with every relationship that touches it
+    pub async fn delete_concept(&self, id: Uuid) -> Result<()> {
+        let mut tx = self.begin().await?;
+        let id_str = id.to_string();
+
+        sqlx::query("DELETE FROM relationships WHERE source_id = ?1 OR target_id = ?1")
+            .bind(&id_str)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete concept relationships: {}", e)))?;
+        sqlx::query("DELETE FROM concepts WHERE id = ?1")
+            .bind(&id_str)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete concept: {}", e)))?;
+
+        commit(tx).await
+    }
+
+    /// Insert or replace a batch of relationships in a single transaction.
+    ///
+    /// Used for Hebbian activation and decay so a failed write never leaves
+    /// the stored graph half-updated.
+    pub async fn save_relationships(&self, relationships: &[ConceptRelationship]) -> Result<()> {
+        let mut tx = self.begin().await?;
+        for relationship in relationships {
+            upsert_relationship(&mut tx, relationship).await?;
+        }
+        commit(tx).await
+    }
+
+    /// Delete a batch of relationships in a single transaction
+    pub async fn delete_relationships(&self, ids: &[Uuid]) -> Result<()> {
+        let mut tx = self.begin().await?;
+        for id in ids {
+            sqlx::query("DELETE FROM relationships WHERE id = ?1")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to delete relationship: {}", e)))?;
+        }
+        commit(tx).await
+    }
+
+    async fn begin(&self) -> Result<Transaction<'static, Sqlite>> {
+        self.pool
+            .begin()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to begin transaction: {}", e)))
+    }
+}
+
+async fn commit(tx: Transaction<'static, Sqlite>) -> Result<()> {
+    tx.commit()
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to commit transaction: {}", e)))
+}
+
+async fn upsert_concept(tx: &mut Transaction<'static, Sqlite>, concept: &ConceptNode) -> Result<()> {
+    sqlx::query(
+        // An upsert rather than INSERT OR REPLACE: replacing deletes the row,
+        // which cascades to every relationship of the concept
+        "INSERT INTO concepts
+            (id, concept_type, content, description, created_at, last_accessed_at,
+             usage_count, confidence_score, source_reference, metadata)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+         ON CONFLICT(id) DO UPDATE SET
+            concept_type = excluded.concept_type,
+            content = excluded.content,
+            description = excluded.description,
+            created_at = excluded.created_at,
+            last_accessed_at = excluded.last_accessed_at,
+            usage_count = excluded.usage_count,
+            confidence_score = excluded.confidence_score,
+            source_reference = excluded.source_reference,
+            metadata = excluded.metadata",
+    )
+    .bind(concept.id.to_string())
+    .bind(serde_json::to_string(&concept.concept_type)?)
+    .bind(&concept.content)
+    .bind(&concept.description)
+    .bind(concept.created_at.to_rfc3339())
+    .bind(concept.last_accessed_at.to_rfc3339())
+    .bind(concept.usage_count as i64)
+    .bind(concept.confidence_score)
+    .bind(&concept.source_reference)
+    .bind(serde_json::to_string(&concept.metadata)?)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| BrainError::DatabaseError(format!("Failed to save concept: {}", e)))?;
+
+    Ok(())
+}
+
+async fn upsert_relationship(tx: &mut Transaction<'static, Sqlite>, relationship: &ConceptRelationship) -> Result<()> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO relationships
+            (id, source_id, target_id, relationship_type, weight, activation_count,
+             created_at, last_activated_at, base_weight, learning_rate, decay_rate,
+             pruning_threshold, metadata)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+    )
+    .bind(relationship.id.to_string())
+    .bind(relationship.source_id.to_string())
+    .bind(relationship.target_id.to_string())
+    .bind(serde_json::to_string(&relationship.relationship_type)?)
+    .bind(relationship.weight)
+    .bind(relationship.activation_count as i64)
+    .bind(relationship.created_at.to_rfc3339())
+    .bind(relationship.last_activated_at.to_rfc3339())
+    .bind(relationship.base_weight)
+    .bind(relationship.learning_rate)
+    .bind(relationship.decay_rate)
+    .bind(relationship.pruning_threshold)
+    .bind(serde_json::to_string(&relationship.metadata)?)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| BrainError::DatabaseError(format!("Failed to save relationship: {}", e)))?;
+
+    Ok(())
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| BrainError::ParseError(format!("Invalid UUID {}: {}", value, e)))
+}
+
+fn row_to_concept(row: &SqliteRow) -> Result<ConceptNode> {
+    let metadata: HashMap<String, String> = serde_json::from_str(&column::<String>(row, "metadata")?)?;
+
+    Ok(ConceptNode {
+        id: parse_uuid(&column::<String>(row, "id")?)?,
+        concept_type: serde_json::from_str(&column::<String>(row, "concept_type")?)?,
+        content: column(row, "content")?,
+        description: column(row, "description")?,
+        created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+        last_accessed_at: parse_timestamp(&column::<String>(row, "last_accessed_at")?)?,
+        usage_count: column::<i64>(row, "usage_count")? as u64,
+        confidence_score: column(row, "confidence_score")?,
+        source_reference: column(row, "source_reference")?,
+        metadata,
+    })
+}
+
+fn row_to_relationship(row: &SqliteRow) -> Result<ConceptRelationship> {
+    let metadata: HashMap<String, String> = serde_json::from_str(&column::<String>(row, "metadata")?)?;
+
+    Ok(ConceptRelationship {
+        id: parse_uuid(&column::<String>(row, "id")?)?,
+        source_id: parse_uuid(&column::<String>(row, "source_id")?)?,
+        target_id: parse_uuid(&column::<String>(row, "target_id")?)?,
+        relationship_type: serde_json::from_str(&column::<String>(row, "relationship_type")?)?,
+        weight: column(row, "weight")?,
+        activation_count: column::<i64>(row, "activation_count")? as u64,
+        created_at: parse_timestamp(&column::<String>(row, "created_at")?)?,
+        last_activated_at: parse_timestamp(&column::<String>(row, "last_activated_at")?)?,
+        base_weight: column(row, "base_weight")?,
+        learning_rate: column(row, "learning_rate")?,
+        decay_rate: column(row, "decay_rate")?,
+        pruning_threshold: column(row, "pruning_threshold")?,
+        metadata,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::{ConceptGraphConfig, ConceptGraphManager};
+
+    async fn open_manager(path: &std::path::Path) -> ConceptGraphManager {
+        let database = DatabaseManager::new_file(path).await.unwrap();
+        let store = SqliteConceptStore::new(&database).await.unwrap();
+        ConceptGraphManager::with_sqlite_store(ConceptGraphConfig::default(), store).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_graph_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.db");
+
+        let (kept_id, removed_id, relationship_id, learned_weight) = {
+            let mut manager = open_manager(&path).await;
+            assert!(manager.is_persistent());
+
+            let mut kept = ConceptNode::new(ConceptType::Abstract, "learning".to_string(), 0.8, Some("test".to_string()));
+            kept.description = Some("acquiring knowledge".to_string());
+            kept.set_metadata("origin".to_string(), "unit-test".to_string());
+            let kept_id = manager.create_concept(kept).await.unwrap();
+            let other_id = manager
+                .create_concept(ConceptNode::new(ConceptType::Action, "practice".to_string(), 0.7, None))
+                .await
+                .unwrap();
+            let removed_id = manager
+                .create_concept(ConceptNode::new(ConceptType::Entity, "temporary".to_string(), 0.5, None))
+                .await
+                .unwrap();
+
+            let relationship = ConceptRelationship::new(kept_id, other_id, RelationshipType::Custom("REINFORCES".to_string()), 0.5);
+            let relationship_id = manager.create_relationship(relationship).await.unwrap();
+            manager
+                .create_relationship(ConceptRelationship::new(removed_id, kept_id, RelationshipType::IsA, 0.6))
+                .await
+                .unwrap();
+
+            manager.activate_relationship(relationship_id).await.unwrap();
+            manager.activate_relationship(relationship_id).await.unwrap();
+            manager.apply_decay_to_all(1.0).await.unwrap();
+            manager.delete_concept(removed_id).await.unwrap();
+
+            let learned_weight = manager.get_relationship(relationship_id).await.unwrap().unwrap().weight;
+            (kept_id, removed_id, relationship_id, learned_weight)
+        };
+
+        let manager = open_manager(&path).await;
+        assert_eq!(manager.concept_count(), 2);
+        assert_eq!(manager.relationship_count(), 1);
+        assert!(manager.get_concept(removed_id).await.unwrap().is_none());
+
+        let kept = manager.get_concept(kept_id).await.unwrap().unwrap();
+        assert_eq!(kept.concept_type, ConceptType::Abstract);
+        assert_eq!(kept.description.as_deref(), Some("acquiring knowledge"));
+        assert_eq!(kept.source_reference.as_deref(), Some("test"));
+        assert_eq!(kept.get_metadata("origin").map(String::as_str), Some("unit-test"));
+
+        let relationship = manager.get_relationship(relationship_id).await.unwrap().unwrap();
+        assert_eq!(relationship.relationship_type, RelationshipType::Custom("REINFORCES".to_string()));
+        assert_eq!(relationship.activation_count, 2);
+        assert!((relationship.weight - learned_weight).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_updating_concepts_keeps_relationships() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.db");
+
+        let (a, relationship_id) = {
+            let mut manager = open_manager(&path).await;
+            let a = manager.create_concept(ConceptNode::new(ConceptType::Entity, "a".to_string(), 0.9, None)).await.unwrap();
+            let b = manager.create_concept(ConceptNode::new(ConceptType::Entity, "b".to_string(), 0.9, None)).await.unwrap();
+            let relationship_id = manager
+                .create_relationship(ConceptRelationship::new(a, b, RelationshipType::Uses, 0.7))
+                .await
+                .unwrap();
+
+            let mut concept = manager.get_concept(a).await.unwrap().unwrap();
+            concept.content = "renamed".to_string();
+            manager.update_concept(&concept).await.unwrap();
+            assert!(manager.mark_concept_accessed(b).await.unwrap());
+            (a, relationship_id)
+        };
+
+        let manager = open_manager(&path).await;
+        assert_eq!(manager.relationship_count(), 1);
+        assert!(manager.get_relationship(relationship_id).await.unwrap().is_some());
+        assert_eq!(manager.get_concept(a).await.unwrap().unwrap().content, "renamed");
+    }
+
+    #[tokio::test]
+    async fn test_pruning_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.db");
+
+        {
+            let mut manager = open_manager(&path).await;
+            let a = manager.create_concept(ConceptNode::new(ConceptType::Entity, "a".to_string(), 0.9, None)).await.unwrap();
+            let b = manager.create_concept(ConceptNode::new(ConceptType::Entity, "b".to_string(), 0.9, None)).await.unwrap();
+            manager.create_relationship(ConceptRelationship::new(a, b, RelationshipType::SimilarTo, 0.05)).await.unwrap();
+            manager.create_relationship(ConceptRelationship::new(a, b, RelationshipType::Uses, 0.9)).await.unwrap();
+            assert_eq!(manager.prune_weak_relationships().await.unwrap(), 1);
+        }
+
+        let manager = open_manager(&path).await;
+        assert_eq!(manager.relationship_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_empty_tables_are_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = DatabaseManager::new_file(dir.path().join("legacy.db")).await.unwrap();
+        sqlx::query("CREATE TABLE concepts (id TEXT PRIMARY KEY, name TEXT NOT NULL, current_activation REAL NOT NULL)")
+            .execute(database.pool())
+            .await
+            .unwrap();
+
+        let store = SqliteConceptStore::new(&database).await.unwrap();
+        let concept = ConceptNode::new(ConceptType::Entity, "fresh".to_string(), 0.9, None);
+        store.save_concept(&concept).await.unwrap();
+        assert_eq!(store.load_concepts().await.unwrap().len(), 1);
+    }
+}
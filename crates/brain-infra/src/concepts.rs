@@ -4,11 +4,25 @@
 //! Neo4j integration, Hebbian learning, advanced traversal algorithms,
 //! and comprehensive concept formation capabilities.
 
+use crate::concept_store::SqliteConceptStore;
 use brain_core::*;
 use brain_types::*;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use uuid::Uuid;
 
+/// Storage backend used for the concept graph
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ConceptGraphBackend {
+    /// Process-local maps, lost on restart
+    #[default]
+    InMemory,
+    /// In-memory graph written through to a SQLite database file
+    Sqlite { path: PathBuf },
+    /// Neo4j server reached over Bolt using the connection settings in the config
+    Neo4j,
+}
+
 /// Configuration for the Neo4j concept graph database
 #[derive(Debug, Clone)]
 pub struct ConceptGraphConfig {
@@ -24,6 +38,8 @@ pub struct ConceptGraphConfig {
     pub pool_size: u32,
     /// Connection timeout in seconds
     pub timeout_seconds: u64,
+    /// Which store `ConceptGraphStore::open` connects to
+    pub backend: ConceptGraphBackend,
 }
 
 impl Default for ConceptGraphConfig {
@@ -35,6 +51,7 @@ impl Default for ConceptGraphConfig {
             database: None,
             pool_size: 10,
             timeout_seconds: 30,
+            backend: ConceptGraphBackend::InMemory,
         }
     }
 }
@@ -169,6 +186,9 @@ pub struct GraphStatistics {
     pub relationships_by_type: std::collections::HashMap<RelationshipType, usize>,
     pub newest_concept_age_seconds: Option<u64>,
     pub last_access_age_seconds: Option<u64>,
+    /// Structural metrics (density, clustering, components, path length and
+    /// centrality), filled in by [`ConceptGraphManager::get_statistics_with_metrics`]
+    pub network_metrics: Option<NetworkMetrics>,
 }
 
 /// Advanced concept graph manager with Neo4j integration and sophisticated algorithms
@@ -177,10 +197,12 @@ pub struct ConceptGraphManager {
     /// Connection configuration
     #[allow(dead_code)]
     config: ConceptGraphConfig,
-    /// In-memory storage for concepts (fallback when Neo4j unavailable)
+    /// In-memory concepts (loaded from the store at startup when one is attached)
     concepts: HashMap<Uuid, ConceptNode>,
-    /// In-memory storage for relationships (fallback when Neo4j unavailable)
+    /// In-memory relationships (loaded from the store at startup when one is attached)
     relationships: HashMap<Uuid, ConceptRelationship>,
+    /// Persistent store that every change is written through to
+    store: Option<SqliteConceptStore>,
     /// Hebbian learning configuration
     #[allow(dead_code)]
     hebbian_config: HebbianConfig,
@@ -202,6 +224,7 @@ impl ConceptGraphManager {
             config,
             concepts: HashMap::new(),
             relationships: HashMap::new(),
+            store: None,
             hebbian_config: HebbianConfig::default(),
             formation_config: ConceptFormationConfig::default(),
             traversal_config: TraversalConfig::default(),
@@ -215,6 +238,7 @@ impl ConceptGraphManager {
             config,
             concepts: HashMap::new(),
             relationships: HashMap::new(),
+            store: None,
             hebbian_config,
             formation_config: ConceptFormationConfig::default(),
             traversal_config: TraversalConfig::default(),
@@ -234,6 +258,7 @@ impl ConceptGraphManager {
             config,
             concepts: HashMap::new(),
             relationships: HashMap::new(),
+            store: None,
             hebbian_config,
             formation_config,
             traversal_config,
@@ -241,7 +266,38 @@ impl ConceptGraphManager {
         })
     }
 
+    /// Create a manager backed by a SQLite store, loading the persisted graph
+    pub async fn with_sqlite_store(config: ConceptGraphConfig, store: SqliteConceptStore) -> Result<Self> {
+        let concepts = store.load_concepts().await?
+            .into_iter()
+            .map(|concept| (concept.id, concept))
+            .collect();
+        let relationships = store.load_relationships().await?
+            .into_iter()
+            .map(|relationship| (relationship.id, relationship))
+            .collect();
+
+        Ok(Self {
+            config,
+            concepts,
+            relationships,
+            store: Some(store),
+            hebbian_config: HebbianConfig::default(),
+            formation_config: ConceptFormationConfig::default(),
+            traversal_config: TraversalConfig::default(),
+            similarity_config: SimilarityConfig::default(),
+        })
+    }
+
+    /// Whether changes are persisted to a store
+    pub fn is_persistent(&self) -> bool {
+        self.store.is_some()
+    }
+
     /// Get graph statistics
+    ///
+    /// Only counts and summaries that take a single pass over the graph; use
+    /// [`Self::get_statistics_with_metrics`] to include the structural metrics.
     pub async fn get_statistics(&self) -> Result<GraphStatistics> {
         let total_concepts = self.concepts.len();
         let total_relationships = self.relationships.len();
@@ -283,9 +339,17 @@ impl ConceptGraphManager {
             relationships_by_type,
             newest_concept_age_seconds,
             last_access_age_seconds,
+            network_metrics: None,
         })
     }
 
+    /// Get graph statistics together with the structural network metrics
+    pub async fn get_statistics_with_metrics(&self) -> Result<GraphStatistics> {
+        let mut statistics = self.get_statistics().await?;
+        statistics.network_metrics = Some(self.compute_network_metrics());
+        Ok(statistics)
+    }
+
     /// Get concept count (convenience method)
     pub fn concept_count(&self) -> usize {
         self.concepts.len()
@@ -311,65 +375,44 @@ impl ConceptGraphManager {
         self.apply_decay_to_all(time_delta_hours).await
     }
 
-    /// Get network metrics (simplified version)
+    /// Get network metrics: degree distribution, clustering, components,
+    /// path length, density and degree/betweenness/PageRank centrality
     pub async fn get_network_metrics(&self) -> Result<NetworkMetrics> {
-        let stats = self.get_statistics().await?;
-        
-        let strong_relationships = self.relationships.values()
-            .filter(|r| r.weight >= 0.7)
-            .count();
-            
-        let weak_relationships = self.relationships.values()
-            .filter(|r| r.weight < 0.3)
-            .count();
-            
-        let prunable_relationships = self.relationships.values()
-            .filter(|r| r.should_prune())
-            .count();
-            
-        let average_weight = if stats.total_relationships > 0 {
-            self.relationships.values().map(|r| r.weight).sum::<f64>() / stats.total_relationships as f64
-        } else {
-            0.0
-        };
-        
-        let average_degree = if stats.total_concepts > 0 {
-            (stats.total_relationships * 2) as f64 / stats.total_concepts as f64
-        } else {
-            0.0
-        };
-        
-        // Find isolated concepts (concepts with no relationships)
-        let mut connected_concepts = std::collections::HashSet::new();
-        for relationship in self.relationships.values() {
-            connected_concepts.insert(relationship.source_id);
-            connected_concepts.insert(relationship.target_id);
+        Ok(self.compute_network_metrics())
+    }
+
+    fn compute_network_metrics(&self) -> NetworkMetrics {
+        let relationships: Vec<ConceptRelationship> = self.relationships.values().cloned().collect();
+        NetworkMetrics::from_graph(self.concepts.keys().copied(), &relationships)
+    }
+
+    /// Find the strongest path between two concepts, treating `1 / weight` as the cost of each hop
+    pub fn find_weighted_shortest_path(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        config: &PathSearchConfig,
+    ) -> Result<Option<ConceptPath>> {
+        if !self.concepts.contains_key(&source_id) || !self.concepts.contains_key(&target_id) {
+            return Ok(None);
         }
-        let isolated_concepts = stats.total_concepts - connected_concepts.len();
-        
-        // Find most connected concepts
-        let mut concept_degrees: std::collections::HashMap<Uuid, usize> = std::collections::HashMap::new();
-        for relationship in self.relationships.values() {
-            *concept_degrees.entry(relationship.source_id).or_insert(0) += 1;
-            *concept_degrees.entry(relationship.target_id).or_insert(0) += 1;
-        }
-        
-        let mut most_connected: Vec<(Uuid, usize)> = concept_degrees.into_iter().collect();
-        most_connected.sort_by(|a, b| b.1.cmp(&a.1));
-        most_connected.truncate(10); // Top 10 most connected
-        
-        Ok(NetworkMetrics {
-            total_relationships: stats.total_relationships,
-            relationships_by_type: stats.relationships_by_type,
-            average_weight,
-            strong_relationships,
-            weak_relationships,
-            prunable_relationships,
-            average_degree,
-            isolated_concepts,
-            clustering_coefficient: 0.0, // Simplified - would require complex calculation
-            most_connected_concepts: most_connected,
-        })
+        let adjacency = ConceptAdjacency::from_relationships(self.relationships.values(), config);
+        Ok(adjacency.shortest_path(source_id, target_id))
+    }
+
+    /// Find up to `k` alternative paths between two concepts, strongest first
+    pub fn find_k_shortest_paths(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        k: usize,
+        config: &PathSearchConfig,
+    ) -> Result<Vec<ConceptPath>> {
+        if !self.concepts.contains_key(&source_id) || !self.concepts.contains_key(&target_id) {
+            return Ok(Vec::new());
+        }
+        let adjacency = ConceptAdjacency::from_relationships(self.relationships.values(), config);
+        Ok(adjacency.k_shortest_paths(source_id, target_id, k))
     }
 
     /// Co-activate concepts (simplified version for demo)
@@ -412,39 +455,32 @@ impl ConceptRepository for ConceptGraphManager {
         concept.mark_accessed();
         let id = concept.id;
         
-        // Store in memory (fallback storage)
-        self.concepts.insert(id, concept.clone());
-        
-        // TODO: Store in Neo4j when available
-        // This would involve creating a Cypher query to insert the concept node
+        if let Some(store) = &self.store {
+            store.save_concept(&concept).await?;
+        }
+        self.concepts.insert(id, concept);
         
         Ok(id)
     }
 
     async fn get_concept(&self, id: Uuid) -> Result<Option<ConceptNode>> {
-        // Try in-memory first (fallback)
-        if let Some(concept) = self.concepts.get(&id) {
-            return Ok(Some(concept.clone()));
-        }
-        
-        // TODO: Query Neo4j when available
-        // This would involve a Cypher query to find the concept by ID
-        
-        Ok(None)
+        Ok(self.concepts.get(&id).cloned())
     }
 
     async fn update_concept(&mut self, concept: &ConceptNode) -> Result<()> {
-        // Update in memory
+        if let Some(store) = &self.store {
+            store.save_concept(concept).await?;
+        }
         self.concepts.insert(concept.id, concept.clone());
         
-        // TODO: Update in Neo4j when available
-        // This would involve a Cypher query to update the concept node
-        
         Ok(())
     }
 
     async fn delete_concept(&mut self, id: Uuid) -> Result<bool> {
-        // Remove from memory
+        if let Some(store) = &self.store {
+            store.delete_concept(id).await?;
+        }
+
         let removed = self.concepts.remove(&id).is_some();
         
         // Remove associated relationships
@@ -452,9 +488,6 @@ impl ConceptRepository for ConceptGraphManager {
             rel.source_id != id && rel.target_id != id
         });
         
-        // TODO: Delete from Neo4j when available
-        // This would involve Cypher queries to delete the concept and its relationships
-        
         Ok(removed)
     }
 
@@ -535,12 +568,12 @@ impl ConceptRepository for ConceptGraphManager {
     }
 
     async fn mark_concept_accessed(&mut self, id: Uuid) -> Result<bool> {
-        if let Some(concept) = self.concepts.get_mut(&id) {
-            concept.mark_accessed();
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let Some(mut concept) = self.concepts.get(&id).cloned() else {
+            return Ok(false);
+        };
+        concept.mark_accessed();
+        self.update_concept(&concept).await?;
+        Ok(true)
     }
 
     async fn get_concept_count(&self) -> Result<usize> {
@@ -561,12 +594,11 @@ impl RelationshipRepository for ConceptGraphManager {
             return Err(BrainError::NotFound(format!("Target concept {} not found", relationship.target_id)));
         }
         
-        // Store in memory
+        if let Some(store) = &self.store {
+            store.save_relationships(std::slice::from_ref(&relationship)).await?;
+        }
         self.relationships.insert(id, relationship);
         
-        // TODO: Store in Neo4j when available
-        // This would involve creating a Cypher query to create the relationship
-        
         Ok(id)
     }
 
@@ -575,16 +607,19 @@ impl RelationshipRepository for ConceptGraphManager {
     }
 
     async fn update_relationship(&mut self, relationship: &ConceptRelationship) -> Result<()> {
+        if let Some(store) = &self.store {
+            store.save_relationships(std::slice::from_ref(relationship)).await?;
+        }
         self.relationships.insert(relationship.id, relationship.clone());
         Ok(())
     }
 
     async fn delete_relationship(&mut self, id: Uuid) -> Result<bool> {
+        if let Some(store) = &self.store {
+            store.delete_relationships(&[id]).await?;
+        }
         let removed = self.relationships.remove(&id).is_some();
         
-        // TODO: Delete from Neo4j when available
-        // This would involve a Cypher query to delete the relationship
-        
         Ok(removed)
     }
 
@@ -678,41 +713,51 @@ impl RelationshipRepository for ConceptGraphManager {
     }
 
     async fn activate_relationship(&mut self, id: Uuid) -> Result<bool> {
-        if let Some(relationship) = self.relationships.get_mut(&id) {
-            relationship.activate();
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let Some(mut relationship) = self.relationships.get(&id).cloned() else {
+            return Ok(false);
+        };
+        relationship.activate();
+        self.update_relationship(&relationship).await?;
+        Ok(true)
     }
 
     async fn apply_decay_to_all(&mut self, time_delta_hours: f64) -> Result<usize> {
-        let mut count = 0;
-        
-        for relationship in self.relationships.values_mut() {
-            relationship.apply_decay(time_delta_hours);
-            count += 1;
+        // Decay copies first so the store and memory only change together
+        let decayed: Vec<ConceptRelationship> = self.relationships.values()
+            .cloned()
+            .map(|mut relationship| {
+                relationship.apply_decay(time_delta_hours);
+                relationship
+            })
+            .collect();
+
+        if let Some(store) = &self.store {
+            store.save_relationships(&decayed).await?;
+        }
+
+        let count = decayed.len();
+        for relationship in decayed {
+            self.relationships.insert(relationship.id, relationship);
         }
         
         Ok(count)
     }
 
     async fn prune_weak_relationships(&mut self) -> Result<usize> {
-        let mut pruned_count = 0;
-        let mut to_remove = Vec::new();
-        
-        for (id, relationship) in self.relationships.iter() {
-            if relationship.should_prune() {
-                to_remove.push(*id);
-            }
+        let to_remove: Vec<Uuid> = self.relationships.iter()
+            .filter(|(_, relationship)| relationship.should_prune())
+            .map(|(id, _)| *id)
+            .collect();
+
+        if let Some(store) = &self.store {
+            store.delete_relationships(&to_remove).await?;
         }
         
-        for id in to_remove {
-            self.relationships.remove(&id);
-            pruned_count += 1;
+        for id in &to_remove {
+            self.relationships.remove(id);
         }
         
-        Ok(pruned_count)
+        Ok(to_remove.len())
     }
 
     async fn get_relationship_count(&self) -> Result<usize> {
@@ -990,6 +1035,211 @@ mod tests {
         assert!(manager.get_relationship(rel_id).await.unwrap().is_none());
     }
 
+    /// Build a service over a small chain `a -0.9- b -0.8- c -0.7- d` with a weak
+    /// side branch `a -0.3- e`, returning the concept ids in that order.
+    async fn build_traversal_service() -> (ConceptGraphService, Vec<Uuid>) {
+        let mut graph = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let mut ids = Vec::new();
+        for name in ["a", "b", "c", "d", "e"] {
+            let concept = ConceptNode::new(ConceptType::Entity, name.to_string(), 0.9, None);
+            ids.push(graph.create_concept(concept).await.unwrap());
+        }
+        for (source, target, weight) in [(0, 1, 0.9), (1, 2, 0.8), (2, 3, 0.7), (0, 4, 0.3)] {
+            let relationship = ConceptRelationship::new(ids[source], ids[target], RelationshipType::AssociatedWith, weight);
+            graph.create_relationship(relationship).await.unwrap();
+        }
+
+        let concepts = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        (ConceptGraphService::new(Box::new(concepts), Box::new(graph)), ids)
+    }
+
+    #[tokio::test]
+    async fn test_breadth_first_traversal_respects_limits() {
+        let (service, ids) = build_traversal_service().await;
+
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::BreadthFirst, None).await.unwrap();
+        assert_eq!(result.total_nodes_visited, 5);
+        assert_eq!(result.visited_concepts[..3], [ids[0], ids[1], ids[4]]);
+        assert_eq!(result.distances[&ids[3]], 3);
+        assert_eq!(result.max_depth_reached, 3);
+        assert_eq!(result.traversed_relationships.len(), 4);
+        assert!((result.activation_scores[&ids[2]] - 0.72).abs() < 1e-9);
+
+        let config = TraversalConfig { max_depth: 1, ..Default::default() };
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::BreadthFirst, Some(config)).await.unwrap();
+        assert_eq!(result.total_nodes_visited, 3);
+        assert!(!result.distances.contains_key(&ids[2]));
+
+        let config = TraversalConfig { max_nodes: 2, ..Default::default() };
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::BreadthFirst, Some(config)).await.unwrap();
+        assert_eq!(result.visited_concepts, vec![ids[0], ids[1]]);
+    }
+
+    #[tokio::test]
+    async fn test_depth_first_traversal_follows_strongest_branch() {
+        let (service, ids) = build_traversal_service().await;
+
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::DepthFirst, None).await.unwrap();
+        assert_eq!(result.visited_concepts, vec![ids[0], ids[1], ids[2], ids[3], ids[4]]);
+        assert_eq!(result.distances[&ids[4]], 1);
+        assert_eq!(result.traversed_relationships.len(), 4);
+
+        let config = TraversalConfig { min_relationship_weight: 0.5, ..Default::default() };
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::DepthFirst, Some(config)).await.unwrap();
+        assert!(!result.visited_concepts.contains(&ids[4]));
+    }
+
+    #[tokio::test]
+    async fn test_spreading_activation_decays_with_distance() {
+        let (service, ids) = build_traversal_service().await;
+
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::SpreadingActivation, None).await.unwrap();
+        let scores = &result.activation_scores;
+        assert_eq!(scores[&ids[0]], 1.0);
+        assert!(scores[&ids[1]] > scores[&ids[2]]);
+        assert!(scores[&ids[2]] > scores[&ids[3]]);
+        assert_eq!(result.visited_concepts[0], ids[0]);
+        assert_eq!(result.distances[&ids[3]], 3);
+
+        // A high threshold stops activation after the first strong hop
+        let config = TraversalConfig { activation_threshold: 0.5, ..Default::default() };
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::SpreadingActivation, Some(config)).await.unwrap();
+        assert_eq!(result.visited_concepts, vec![ids[0], ids[1]]);
+        assert_eq!(result.traversed_relationships.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_shortest_path_between_concepts() {
+        let (service, ids) = build_traversal_service().await;
+
+        let path = service.find_shortest_path(ids[4], ids[3]).await.unwrap().unwrap();
+        assert_eq!(path.concept_path, vec![ids[4], ids[0], ids[1], ids[2], ids[3]]);
+        assert_eq!(path.path_length, 4);
+        assert_eq!(path.relationship_path.len(), 4);
+        assert!((path.total_weight - 2.7).abs() < 1e-9);
+
+        let isolated = ConceptNode::new(ConceptType::Entity, "isolated".to_string(), 0.9, None).id;
+        assert!(service.find_shortest_path(ids[0], isolated).await.unwrap().is_none());
+    }
+
+    /// Build a diamond `s -0.9- a -0.9- t` and `s -0.5- b -0.5- t` with a direct
+    /// weak `s -0.2- t` IsA link, returning `[s, a, b, t]`.
+    async fn build_path_graph() -> (ConceptGraphManager, Vec<Uuid>) {
+        let mut manager = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let mut ids = Vec::new();
+        for name in ["s", "a", "b", "t"] {
+            let concept = ConceptNode::new(ConceptType::Entity, name.to_string(), 0.9, None);
+            ids.push(manager.create_concept(concept).await.unwrap());
+        }
+        let links = [
+            (0, 1, 0.9, RelationshipType::AssociatedWith),
+            (1, 3, 0.9, RelationshipType::AssociatedWith),
+            (0, 2, 0.5, RelationshipType::AssociatedWith),
+            (2, 3, 0.5, RelationshipType::AssociatedWith),
+            (0, 3, 0.2, RelationshipType::IsA),
+        ];
+        for (source, target, weight, relationship_type) in links {
+            let relationship = ConceptRelationship::new(ids[source], ids[target], relationship_type, weight);
+            manager.create_relationship(relationship).await.unwrap();
+        }
+        (manager, ids)
+    }
+
+    #[tokio::test]
+    async fn test_weighted_shortest_path_prefers_strong_relationships() {
+        let (manager, ids) = build_path_graph().await;
+
+        let path = manager
+            .find_weighted_shortest_path(ids[0], ids[3], &PathSearchConfig::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.concept_path, vec![ids[0], ids[1], ids[3]]);
+        assert_eq!(path.path_length, 2);
+        assert!((path.total_weight - 1.8).abs() < 1e-9);
+        assert!((path.total_cost - 2.0 / 0.9).abs() < 1e-9);
+
+        // Restricting to IsA leaves only the direct weak link
+        let config = PathSearchConfig {
+            relationship_types: vec![RelationshipType::IsA],
+            ..Default::default()
+        };
+        let path = manager.find_weighted_shortest_path(ids[0], ids[3], &config).unwrap().unwrap();
+        assert_eq!(path.concept_path, vec![ids[0], ids[3]]);
+    }
+
+    #[tokio::test]
+    async fn test_k_shortest_paths_are_ordered_by_cost() {
+        let (manager, ids) = build_path_graph().await;
+
+        let paths = manager
+            .find_k_shortest_paths(ids[0], ids[3], 5, &PathSearchConfig::default())
+            .unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0].concept_path, vec![ids[0], ids[1], ids[3]]);
+        assert_eq!(paths[1].concept_path, vec![ids[0], ids[2], ids[3]]);
+        assert_eq!(paths[2].concept_path, vec![ids[0], ids[3]]);
+        assert!(paths.windows(2).all(|pair| pair[0].total_cost <= pair[1].total_cost));
+    }
+
+    #[tokio::test]
+    async fn test_service_weighted_paths_and_shortest_path_traversal() {
+        let (service, ids) = build_traversal_service().await;
+
+        let paths = service.find_k_shortest_paths(ids[4], ids[3], 3, None).await.unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].concept_path, vec![ids[4], ids[0], ids[1], ids[2], ids[3]]);
+
+        let result = service.traverse_graph(ids[0], TraversalAlgorithm::ShortestPath, None).await.unwrap();
+        assert!(matches!(result.algorithm, TraversalAlgorithm::ShortestPath));
+        // The weak side branch costs more than two strong hops
+        assert_eq!(result.visited_concepts, vec![ids[0], ids[1], ids[2], ids[4], ids[3]]);
+        assert_eq!(result.distances[&ids[3]], 3);
+    }
+
+    #[tokio::test]
+    async fn test_network_metrics_on_star_with_triangle() {
+        let mut manager = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let mut ids = Vec::new();
+        for name in ["hub", "a", "b", "c", "isolated"] {
+            let concept = ConceptNode::new(ConceptType::Entity, name.to_string(), 0.9, None);
+            ids.push(manager.create_concept(concept).await.unwrap());
+        }
+        // hub connects to a, b and c; a and b also link, forming one triangle
+        for (source, target) in [(0, 1), (0, 2), (0, 3), (1, 2)] {
+            let relationship = ConceptRelationship::new(ids[source], ids[target], RelationshipType::AssociatedWith, 0.8);
+            manager.create_relationship(relationship).await.unwrap();
+        }
+
+        let metrics = manager.get_network_metrics().await.unwrap();
+        assert_eq!(metrics.total_concepts, 5);
+        assert_eq!(metrics.total_relationships, 4);
+        assert_eq!(metrics.isolated_concepts, 1);
+        assert_eq!(metrics.connected_components, 2);
+        assert_eq!(metrics.largest_component_size, 4);
+        assert_eq!(metrics.degree_distribution.get(&3), Some(&1));
+        assert_eq!(metrics.degree_distribution.get(&2), Some(&2));
+        assert!((metrics.density - 0.4).abs() < 1e-9);
+        // hub: 1/3, a and b: 1, c and isolated: 0
+        assert!((metrics.clustering_coefficient - (1.0 / 3.0 + 2.0) / 5.0).abs() < 1e-9);
+        // 6 connected pairs: five at distance 1, c to a/b at distance 2 -> 8 / 6
+        assert!((metrics.average_path_length - 8.0 / 6.0).abs() < 1e-9);
+
+        assert_eq!(metrics.most_connected_concepts[0], (ids[0], 3));
+        assert_eq!(metrics.most_between_concepts[0].0, ids[0]);
+        assert!((metrics.most_between_concepts[0].1 - 2.0 / 6.0).abs() < 1e-9);
+        assert_eq!(metrics.highest_pagerank_concepts[0].0, ids[0]);
+        let rank_total: f64 = metrics.highest_pagerank_concepts.iter().map(|(_, rank)| rank).sum();
+        assert!((rank_total - 1.0).abs() < 1e-6);
+
+        let stats = manager.get_statistics().await.unwrap();
+        assert!(stats.network_metrics.is_none());
+        let stats = manager.get_statistics_with_metrics().await.unwrap();
+        let stats_metrics = stats.network_metrics.unwrap();
+        assert_eq!(stats.total_concepts, 5);
+        assert_eq!(stats_metrics.connected_components, 2);
+        assert!((stats_metrics.density - metrics.density).abs() < 1e-12);
+    }
+
     #[tokio::test]
     async fn test_cosine_similarity_function() {
         let vec1 = vec![1.0, 2.0, 3.0];
@@ -0,0 +1,86 @@
+//! Backend Conformance Harness
+//!
+//! Stores with an in-memory and a SQLite backend run the same assertions
+//! against both, and check that SQLite data survives reopening the database
+//! file. `backend_conformance_tests!` generates those tests so each store
+//! module only supplies its store-specific assertions.
+
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// SQLite database file in its own temporary directory, removed on drop
+pub struct TempDatabaseFile {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl TempDatabaseFile {
+    /// Reserve a fresh database path; the file itself is created by whoever opens it
+    pub fn new() -> Self {
+        let dir = std::env::temp_dir().join(format!("brain_store_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("failed to create temporary database directory");
+        let path = dir.join("store.db");
+        Self { dir, path }
+    }
+
+    /// Path of the database file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Default for TempDatabaseFile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TempDatabaseFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Generate the conformance tests for a store's backends
+///
+/// Each clause ends with `;` and may be left out:
+///
+/// - `in_memory: exercise => store;` runs `exercise(store)` against the in-memory backend
+/// - `sqlite: exercise => Store::new;` runs `exercise` against `Store::new(&DatabaseManager)`
+///   over an in-memory SQLite database
+/// - `reopen: Store::open => persist, verify;` opens a SQLite file, runs `persist(store)`,
+///   then runs `verify(reopened, persisted)` against a fresh store on the same file with
+///   whatever `persist` returned
+///
+/// `exercise`, `persist` and `verify` are async functions taking the store by value.
+#[macro_export]
+macro_rules! backend_conformance_tests {
+    () => {};
+    (in_memory: $exercise:path => $store:expr; $($rest:tt)*) => {
+        #[tokio::test]
+        async fn test_in_memory_backend_conformance() {
+            $exercise($store).await;
+        }
+
+        $crate::backend_conformance_tests!($($rest)*);
+    };
+    (sqlite: $exercise:path => $new:path; $($rest:tt)*) => {
+        #[tokio::test]
+        async fn test_sqlite_backend_conformance() {
+            let database = $crate::DatabaseManager::new_in_memory().await.unwrap();
+            $exercise($new(&database).await.unwrap()).await;
+        }
+
+        $crate::backend_conformance_tests!($($rest)*);
+    };
+    (reopen: $open:path => $persist:path, $verify:path; $($rest:tt)*) => {
+        #[tokio::test]
+        async fn test_sqlite_backend_survives_reopen() {
+            let file = $crate::conformance::TempDatabaseFile::new();
+            let persisted = $persist($open(file.path()).await.unwrap()).await;
+            $verify($open(file.path()).await.unwrap(), persisted).await;
+        }
+
+        $crate::backend_conformance_tests!($($rest)*);
+    };
+}
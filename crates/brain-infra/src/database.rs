@@ -3,7 +3,9 @@
 //! Database connection management and utilities for the Brain AI system.
 
 use brain_types::*;
-use sqlx::{SqlitePool, Row};
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteRow};
+use sqlx::{Sqlite, SqlitePool, Row};
 use std::path::Path;
 
 /// Database connection manager
@@ -26,10 +28,17 @@ impl DatabaseManager {
         Self::new("sqlite::memory:").await
     }
 
-    /// Create a new file-based database
+    /// Create a new file-based database, creating the file if it does not exist
     pub async fn new_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let database_url = format!("sqlite:{}", path.as_ref().display());
-        Self::new(&database_url).await
+        let options = SqliteConnectOptions::new()
+            .filename(path.as_ref())
+            .create_if_missing(true)
+            .foreign_keys(true);
+        let pool = SqlitePool::connect_with(options)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to open database file: {}", e)))?;
+
+        Ok(Self { pool })
     }
 
     /// Get a reference to the connection pool
@@ -91,17 +100,20 @@ impl DatabaseManager {
         .map_err(|e| BrainError::DatabaseError(format!("Failed to create semantic_memory table: {}", e)))?;
 
         // Concept graph tables
+        self.drop_legacy_concept_tables().await?;
+
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS concepts (
                 id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                confidence REAL NOT NULL,
-                current_activation REAL NOT NULL,
-                total_activations INTEGER NOT NULL,
+                concept_type TEXT NOT NULL,
+                content TEXT NOT NULL,
+                description TEXT,
                 created_at TEXT NOT NULL,
-                last_activated TEXT NOT NULL,
-                tags TEXT NOT NULL,
+                last_accessed_at TEXT NOT NULL,
+                usage_count INTEGER NOT NULL,
+                confidence_score REAL NOT NULL,
+                source_reference TEXT,
                 metadata TEXT NOT NULL
             )
             "#,
@@ -117,12 +129,17 @@ impl DatabaseManager {
                 source_id TEXT NOT NULL,
                 target_id TEXT NOT NULL,
                 relationship_type TEXT NOT NULL,
-                strength REAL NOT NULL,
+                weight REAL NOT NULL,
                 activation_count INTEGER NOT NULL,
                 created_at TEXT NOT NULL,
-                last_activated TEXT NOT NULL,
-                FOREIGN KEY (source_id) REFERENCES concepts (id),
-                FOREIGN KEY (target_id) REFERENCES concepts (id)
+                last_activated_at TEXT NOT NULL,
+                base_weight REAL NOT NULL,
+                learning_rate REAL NOT NULL,
+                decay_rate REAL NOT NULL,
+                pruning_threshold REAL NOT NULL,
+                metadata TEXT NOT NULL,
+                FOREIGN KEY (source_id) REFERENCES concepts (id) ON DELETE CASCADE,
+                FOREIGN KEY (target_id) REFERENCES concepts (id) ON DELETE CASCADE
             )
             "#,
         )
@@ -130,6 +147,13 @@ impl DatabaseManager {
         .await
         .map_err(|e| BrainError::DatabaseError(format!("Failed to create relationships table: {}", e)))?;
 
+        for (index, column) in [("idx_relationships_source", "source_id"), ("idx_relationships_target", "target_id")] {
+            sqlx::query(&format!("CREATE INDEX IF NOT EXISTS {} ON relationships({})", index, column))
+                .execute(&self.pool)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to create {} index: {}", index, e)))?;
+        }
+
         // Segmentation tables
         sqlx::query(
             r#"
@@ -188,6 +212,45 @@ impl DatabaseManager {
         Ok(())
     }
 
+    /// Drop concept graph tables left over from the original placeholder layout.
+    ///
+    /// Nothing ever wrote to those tables, so they are only dropped while empty.
+    async fn drop_legacy_concept_tables(&self) -> Result<()> {
+        let legacy_columns = [("concepts", "current_activation"), ("relationships", "strength")];
+
+        for (table, legacy_column) in legacy_columns {
+            let columns: Vec<String> = sqlx::query(&format!("PRAGMA table_info({})", table))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to inspect {} table: {}", table, e)))?
+                .iter()
+                .map(|row| row.get::<String, _>("name"))
+                .collect();
+            if !columns.iter().any(|column| column == legacy_column) {
+                continue;
+            }
+
+            let row_count: i64 = sqlx::query(&format!("SELECT COUNT(*) FROM {}", table))
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to count {} rows: {}", table, e)))?
+                .get(0);
+            if row_count > 0 {
+                return Err(BrainError::DatabaseError(format!(
+                    "Table {} uses the legacy layout and contains data; migrate it manually",
+                    table
+                )));
+            }
+
+            sqlx::query(&format!("DROP TABLE {}", table))
+                .execute(&self.pool)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to drop legacy {} table: {}", table, e)))?;
+        }
+
+        Ok(())
+    }
+
     /// Health check for the database connection
     pub async fn health_check(&self) -> Result<bool> {
         let result = sqlx::query("SELECT 1")
@@ -205,6 +268,27 @@ impl DatabaseManager {
     }
 }
 
+/// Read a column from a SQLite row, naming the column in the error
+pub fn column<'r, T>(row: &'r SqliteRow, name: &str) -> Result<T>
+where
+    T: sqlx::Decode<'r, Sqlite> + sqlx::Type<Sqlite>,
+{
+    row.try_get(name)
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to read column {}: {}", name, e)))
+}
+
+/// Bind an unsigned count as SQLite's signed integer, saturating at `i64::MAX`
+pub fn clamp_to_i64<T: TryInto<i64>>(value: T) -> i64 {
+    value.try_into().unwrap_or(i64::MAX)
+}
+
+/// Parse an RFC 3339 timestamp column
+pub fn parse_timestamp(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|e| BrainError::ParseError(format!("Invalid timestamp {}: {}", value, e)))
+}
+
 /// Database configuration
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
@@ -8,10 +8,13 @@
 //! 3. Rule Generalization System - Advanced rule management and generalization
 
 use brain_types::*;
-use brain_core::{Insight, InsightType, InsightRepository};
+use brain_types::math::normal_cdf;
+use brain_core::{
+    ConceptNode, ConceptRelationship, EpisodicEvent, Insight, InsightRepository, InsightType, RelationshipType,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
@@ -161,11 +164,32 @@ impl DetectedPattern {
     }
 
     /// Check if pattern meets significance thresholds
+    ///
+    /// Co-occurrence and similarity patterns count shared appearances, so they
+    /// are held to the co-occurrence minimum rather than the frequency minimum.
     pub fn is_significant(&self, config: &PatternDetectionConfig) -> bool {
-        self.frequency >= config.min_pattern_frequency
+        let min_frequency = match self.pattern_type {
+            PatternType::CoOccurrence | PatternType::Similarity => config.min_co_occurrence_count,
+            _ => config.min_pattern_frequency,
+        };
+        self.frequency >= min_frequency
             && self.confidence >= config.min_confidence_threshold
             && self.significance <= config.significance_threshold
     }
+
+    /// One-line description of the pattern for insight text
+    pub fn describe(&self) -> String {
+        let elements = match (&self.pattern_type, self.elements.split_first()) {
+            (PatternType::Hierarchical, Some((parent, children))) => format!("{} > {}", parent, children.join(", ")),
+            (PatternType::TemporalSequence | PatternType::Causal, _) => self.elements.join(" -> "),
+            (PatternType::Negation, _) => self.elements.join(" -/-> "),
+            _ => self.elements.join(" <-> "),
+        };
+        format!(
+            "{} pattern detected: {} with {} occurrences (confidence: {:.2}, p = {:.3})",
+            self.pattern_type, elements, self.frequency, self.confidence, self.significance
+        )
+    }
 }
 
 /// Results from pattern detection operation
@@ -213,6 +237,517 @@ impl Default for DetectionStats {
     }
 }
 
+/// Maximum evidence IDs kept per element, pair or sequence
+const MAX_EVIDENCE: usize = 50;
+
+/// Running statistics of the delays between two elements
+#[derive(Debug, Clone, Default)]
+struct DelayStats {
+    count: usize,
+    sum: f64,
+    sum_squares: f64,
+    min: f64,
+    max: f64,
+}
+
+impl DelayStats {
+    fn record(&mut self, minutes: f64) {
+        if self.count == 0 {
+            self.min = minutes;
+            self.max = minutes;
+        } else {
+            self.min = self.min.min(minutes);
+            self.max = self.max.max(minutes);
+        }
+        self.count += 1;
+        self.sum += minutes;
+        self.sum_squares += minutes * minutes;
+    }
+
+    fn temporal_info(&self) -> Option<TemporalInfo> {
+        if self.count == 0 {
+            return None;
+        }
+        let mean = self.sum / self.count as f64;
+        let variance = (self.sum_squares / self.count as f64 - mean * mean).max(0.0);
+        Some(TemporalInfo {
+            average_delay_minutes: mean,
+            delay_std_dev: variance.sqrt(),
+            min_delay_minutes: self.min,
+            max_delay_minutes: self.max,
+        })
+    }
+}
+
+/// An ingested event that later events may still follow within the temporal window
+#[derive(Debug, Clone)]
+struct WindowEvent {
+    id: Uuid,
+    timestamp: DateTime<Utc>,
+    elements: Vec<String>,
+    /// Elements already counted as following this event
+    followers: HashSet<String>,
+}
+
+/// Running counts over ingested episodic events
+///
+/// With incremental detection enabled these accumulate across detection runs,
+/// so each run only ingests the events it has not seen before, including ones
+/// that arrive late, and patterns are re-tested against everything seen so far.
+#[derive(Debug, Clone, Default)]
+struct EventStatistics {
+    total_events: usize,
+    /// Events containing each element
+    element_counts: HashMap<String, usize>,
+    /// Events containing both elements, keyed like `ContextMatrix` with the smaller element first
+    co_occurrence: HashMap<(String, String), usize>,
+    /// Events containing the first element followed within the window by one containing the second
+    sequences: HashMap<(String, String), usize>,
+    sequence_delays: HashMap<(String, String), DelayStats>,
+    /// Events followed within the window by an event containing the element
+    followed_by: HashMap<String, usize>,
+    /// Element counts per temporal window, keyed by window index
+    window_counts: HashMap<i64, HashMap<String, usize>>,
+    /// Supporting event IDs by element (`a`), pair (`a|b`) and sequence (`a>b`)
+    evidence: HashMap<String, Vec<Uuid>>,
+    /// Events still inside the temporal window of the latest one, oldest first
+    recent: VecDeque<WindowEvent>,
+    /// IDs of every event ingested, so an event is never counted twice
+    ingested: HashSet<Uuid>,
+}
+
+fn push_evidence(evidence: &mut HashMap<String, Vec<Uuid>>, key: String, id: Uuid) {
+    let ids = evidence.entry(key).or_default();
+    if ids.len() < MAX_EVIDENCE && !ids.contains(&id) {
+        ids.push(id);
+    }
+}
+
+fn ordered_pair(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+impl EventStatistics {
+    /// Whether the event with `id` has been ingested
+    fn has_ingested(&self, id: Uuid) -> bool {
+        self.ingested.contains(&id)
+    }
+
+    /// Add one event, ignoring events already ingested
+    ///
+    /// Events may arrive out of timestamp order. A late event is sequenced with
+    /// the events around it that are still within the temporal window of the
+    /// newest one; sequences with events evicted before it arrived are missed.
+    fn ingest(&mut self, id: Uuid, timestamp: DateTime<Utc>, elements: Vec<String>, window: chrono::Duration) {
+        if !self.ingested.insert(id) {
+            return;
+        }
+        self.total_events += 1;
+        let window_index = timestamp.timestamp().div_euclid(window.num_seconds().max(1));
+
+        for element in &elements {
+            *self.element_counts.entry(element.clone()).or_insert(0) += 1;
+            *self.window_counts.entry(window_index).or_default().entry(element.clone()).or_insert(0) += 1;
+            push_evidence(&mut self.evidence, element.clone(), id);
+        }
+        for (i, a) in elements.iter().enumerate() {
+            for b in &elements[i + 1..] {
+                let key = ordered_pair(a, b);
+                push_evidence(&mut self.evidence, format!("{}|{}", key.0, key.1), id);
+                *self.co_occurrence.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        let newest = self.recent.back().map_or(timestamp, |latest| latest.timestamp.max(timestamp));
+        while self.recent.front().is_some_and(|earlier| newest - earlier.timestamp > window) {
+            self.recent.pop_front();
+        }
+        let position = self.recent.partition_point(|other| other.timestamp <= timestamp);
+
+        for earlier in self.recent.range_mut(..position).filter(|earlier| earlier.timestamp < timestamp) {
+            let delay_minutes = (timestamp - earlier.timestamp).num_seconds() as f64 / 60.0;
+            for b in &elements {
+                // Each earlier event counts a follower once, at its first occurrence
+                if !earlier.followers.insert(b.clone()) {
+                    continue;
+                }
+                *self.followed_by.entry(b.clone()).or_insert(0) += 1;
+                for a in earlier.elements.iter().filter(|a| *a != b) {
+                    let key = (a.clone(), b.clone());
+                    *self.sequences.entry(key.clone()).or_insert(0) += 1;
+                    self.sequence_delays.entry(key).or_default().record(delay_minutes);
+                    push_evidence(&mut self.evidence, format!("{}>{}", a, b), earlier.id);
+                    push_evidence(&mut self.evidence, format!("{}>{}", a, b), id);
+                }
+            }
+        }
+
+        // A late event precedes the later events already ingested within its window
+        let mut followers = HashSet::new();
+        for later in self.recent.range(position..).filter(|later| later.timestamp - timestamp <= window) {
+            let delay_minutes = (later.timestamp - timestamp).num_seconds() as f64 / 60.0;
+            for b in &later.elements {
+                if !followers.insert(b.clone()) {
+                    continue;
+                }
+                *self.followed_by.entry(b.clone()).or_insert(0) += 1;
+                for a in elements.iter().filter(|a| *a != b) {
+                    let key = (a.clone(), b.clone());
+                    *self.sequences.entry(key.clone()).or_insert(0) += 1;
+                    self.sequence_delays.entry(key).or_default().record(delay_minutes);
+                    push_evidence(&mut self.evidence, format!("{}>{}", a, b), id);
+                    push_evidence(&mut self.evidence, format!("{}>{}", a, b), later.id);
+                }
+            }
+        }
+        self.recent.insert(position, WindowEvent { id, timestamp, elements, followers });
+    }
+
+    fn count(&self, element: &str) -> usize {
+        self.element_counts.get(element).copied().unwrap_or(0)
+    }
+
+    fn evidence(&self, key: &str) -> Vec<Uuid> {
+        self.evidence.get(key).cloned().unwrap_or_default()
+    }
+
+    /// Elements seen at least `min_count` times, in a stable order
+    fn frequent_elements(&self, min_count: usize) -> Vec<&String> {
+        let mut elements: Vec<&String> = self.element_counts
+            .iter()
+            .filter(|(_, count)| **count >= min_count)
+            .map(|(element, _)| element)
+            .collect();
+        elements.sort();
+        elements
+    }
+
+    /// Elements occurring unusually often against an even share across all elements
+    fn frequency_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        if self.element_counts.is_empty() {
+            return Vec::new();
+        }
+        let occurrences: usize = self.element_counts.values().sum();
+        let share = occurrences as f64 / self.element_counts.len() as f64 / self.total_events as f64;
+
+        self.frequent_elements(config.min_pattern_frequency)
+            .into_iter()
+            .map(|element| {
+                let count = self.count(element);
+                let mut pattern = DetectedPattern::new(
+                    PatternType::Frequency,
+                    vec![element.clone()],
+                    count,
+                    count as f64 / self.total_events as f64,
+                    self.evidence(element),
+                );
+                pattern.significance = binomial_upper_tail(count, self.total_events, share.min(1.0));
+                pattern.context.insert("expected_share".to_string(), format!("{:.4}", share));
+                pattern
+            })
+            .collect()
+    }
+
+    /// Elements appearing in the same event more often than independence predicts (one-sided Fisher exact test)
+    fn co_occurrence_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        self.co_occurrence
+            .iter()
+            .filter(|(_, together)| **together >= config.min_co_occurrence_count)
+            .map(|((a, b), together)| {
+                let (count_a, count_b) = (self.count(a), self.count(b));
+                let expected = count_a as f64 * count_b as f64 / self.total_events as f64;
+                let mut pattern = DetectedPattern::new(
+                    PatternType::CoOccurrence,
+                    vec![a.clone(), b.clone()],
+                    *together,
+                    *together as f64 / count_a.min(count_b) as f64,
+                    self.evidence(&format!("{}|{}", a, b)),
+                );
+                pattern.significance = hypergeometric_upper_tail(*together, self.total_events, count_a, count_b);
+                pattern.strength = *together as f64 / (count_a + count_b - together) as f64;
+                pattern.context.insert("expected".to_string(), format!("{:.3}", expected));
+                pattern.context.insert("lift".to_string(), format!("{:.3}", *together as f64 / expected));
+                pattern
+            })
+            .collect()
+    }
+
+    /// Ordered sequences and causal links between elements
+    ///
+    /// A sequence is significant when the first element precedes the second
+    /// more often than the reverse (sign test). It is also reported as causal
+    /// when the second element follows the first more often than it follows
+    /// events in general (binomial test against that base rate).
+    fn sequence_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        let mut patterns = Vec::new();
+        for ((a, b), forward) in &self.sequences {
+            if *forward < config.min_pattern_frequency {
+                continue;
+            }
+            let backward = self.sequences.get(&(b.clone(), a.clone())).copied().unwrap_or(0);
+            let order_significance = binomial_upper_tail(*forward, forward + backward, 0.5);
+            let anchors = self.count(a);
+            let conditional_rate = *forward as f64 / anchors as f64;
+            let evidence = self.evidence(&format!("{}>{}", a, b));
+            let temporal_info = self.sequence_delays.get(&(a.clone(), b.clone())).and_then(DelayStats::temporal_info);
+
+            let mut sequence = DetectedPattern::new(
+                PatternType::TemporalSequence,
+                vec![a.clone(), b.clone()],
+                *forward,
+                conditional_rate,
+                evidence.clone(),
+            );
+            sequence.significance = order_significance;
+            sequence.strength = *forward as f64 / (forward + backward) as f64;
+            sequence.temporal_info = temporal_info.clone();
+            sequence.context.insert("reverse_count".to_string(), backward.to_string());
+            patterns.push(sequence);
+
+            let base_rate = self.followed_by.get(b).copied().unwrap_or(0) as f64 / self.total_events as f64;
+            if conditional_rate > base_rate && base_rate < 1.0 {
+                let mut causal = DetectedPattern::new(
+                    PatternType::Causal,
+                    vec![a.clone(), b.clone()],
+                    *forward,
+                    conditional_rate,
+                    evidence,
+                );
+                causal.significance = order_significance.max(binomial_upper_tail(*forward, anchors, base_rate));
+                causal.strength = (conditional_rate - base_rate) / (1.0 - base_rate);
+                causal.temporal_info = temporal_info;
+                causal.context.insert("base_rate".to_string(), format!("{:.4}", base_rate));
+                patterns.push(causal);
+            }
+        }
+        patterns
+    }
+
+    /// Elements that are followed by another less often than its base rate predicts
+    fn negation_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        let frequent = self.frequent_elements(config.min_pattern_frequency);
+        let mut patterns = Vec::new();
+        for a in &frequent {
+            let anchors = self.count(a);
+            for b in frequent.iter().filter(|b| *b != a) {
+                let base_rate = self.followed_by.get(b.as_str()).copied().unwrap_or(0) as f64 / self.total_events as f64;
+                // Too rare to expect it after `a` anyway, so its absence says nothing
+                if anchors as f64 * base_rate < config.min_co_occurrence_count as f64 {
+                    continue;
+                }
+                let followed = self.sequences.get(&(a.to_string(), b.to_string())).copied().unwrap_or(0);
+                let conditional_rate = followed as f64 / anchors as f64;
+                if conditional_rate >= base_rate {
+                    continue;
+                }
+
+                let mut pattern = DetectedPattern::new(
+                    PatternType::Negation,
+                    vec![a.to_string(), b.to_string()],
+                    anchors - followed,
+                    1.0 - conditional_rate / base_rate,
+                    self.evidence(a),
+                );
+                pattern.significance = binomial_lower_tail(followed, anchors, base_rate);
+                pattern.context.insert("base_rate".to_string(), format!("{:.4}", base_rate));
+                pattern.context.insert("conditional_rate".to_string(), format!("{:.4}", conditional_rate));
+                patterns.push(pattern);
+            }
+        }
+        patterns
+    }
+
+    /// Elements whose counts per temporal window rise and fall together (Fisher z-test on Pearson r)
+    fn correlation_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        let (Some(first), Some(last)) = (self.window_counts.keys().min(), self.window_counts.keys().max()) else {
+            return Vec::new();
+        };
+        // Windows without events count as zeros
+        let windows = (last - first + 1) as f64;
+        if windows < 4.0 {
+            return Vec::new();
+        }
+
+        let frequent = self.frequent_elements(config.min_pattern_frequency);
+        let mut series: HashMap<&str, HashMap<i64, f64>> = HashMap::new();
+        for (window, counts) in &self.window_counts {
+            for element in &frequent {
+                if let Some(count) = counts.get(*element) {
+                    series.entry(element.as_str()).or_default().insert(*window, *count as f64);
+                }
+            }
+        }
+
+        let mut patterns = Vec::new();
+        for (i, a) in frequent.iter().enumerate() {
+            for b in &frequent[i + 1..] {
+                let (xs, ys) = (&series[a.as_str()], &series[b.as_str()]);
+                let sum_x: f64 = xs.values().sum();
+                let sum_y: f64 = ys.values().sum();
+                let sum_xx: f64 = xs.values().map(|x| x * x).sum();
+                let sum_yy: f64 = ys.values().map(|y| y * y).sum();
+                let sum_xy: f64 = xs.iter().filter_map(|(window, x)| ys.get(window).map(|y| x * y)).sum();
+                let denominator = ((windows * sum_xx - sum_x * sum_x) * (windows * sum_yy - sum_y * sum_y)).sqrt();
+                if denominator <= f64::EPSILON {
+                    continue;
+                }
+                let r = ((windows * sum_xy - sum_x * sum_y) / denominator).clamp(-1.0, 1.0);
+                let z = r.clamp(-0.999_999, 0.999_999).atanh() * (windows - 3.0).sqrt();
+                let active_windows = xs.len() + ys.keys().filter(|window| !xs.contains_key(*window)).count();
+
+                let mut evidence = self.evidence(a);
+                evidence.extend(self.evidence(b).into_iter().filter(|id| !evidence.contains(id)).collect::<Vec<_>>());
+                let mut pattern = DetectedPattern::new(
+                    PatternType::Correlation,
+                    vec![(*a).clone(), (*b).clone()],
+                    active_windows,
+                    r.abs(),
+                    evidence,
+                );
+                pattern.significance = 2.0 * (1.0 - normal_cdf(z.abs()));
+                pattern.context.insert("correlation".to_string(), format!("{:.4}", r));
+                pattern.context.insert("windows".to_string(), (windows as usize).to_string());
+                patterns.push(pattern);
+            }
+        }
+        patterns
+    }
+
+    /// Elements that keep the same company without necessarily appearing together
+    ///
+    /// Compares the sets of elements each co-occurs with; the overlap is tested
+    /// against a random draw of neighbours (hypergeometric test).
+    fn similarity_patterns(&self, config: &PatternDetectionConfig) -> Vec<DetectedPattern> {
+        let mut neighbours: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for ((a, b), together) in &self.co_occurrence {
+            if *together >= config.min_co_occurrence_count {
+                neighbours.entry(a.as_str()).or_default().insert(b.as_str());
+                neighbours.entry(b.as_str()).or_default().insert(a.as_str());
+            }
+        }
+
+        // Candidate pairs share at least one neighbour
+        let mut candidates: HashSet<(&str, &str)> = HashSet::new();
+        for shared in neighbours.values() {
+            let mut members: Vec<&str> = shared.iter().copied().collect();
+            members.sort_unstable();
+            for (i, a) in members.iter().enumerate() {
+                for b in &members[i + 1..] {
+                    candidates.insert((*a, *b));
+                }
+            }
+        }
+
+        let population = self.element_counts.len().saturating_sub(2);
+        candidates
+            .into_iter()
+            .filter_map(|(a, b)| {
+                let of_a: HashSet<&str> = neighbours[a].iter().copied().filter(|n| *n != b).collect();
+                let of_b: HashSet<&str> = neighbours[b].iter().copied().filter(|n| *n != a).collect();
+                let shared = of_a.intersection(&of_b).count();
+                let union = of_a.union(&of_b).count();
+                if shared == 0 || union == 0 {
+                    return None;
+                }
+
+                let mut pattern = DetectedPattern::new(
+                    PatternType::Similarity,
+                    vec![a.to_string(), b.to_string()],
+                    shared,
+                    shared as f64 / union as f64,
+                    Vec::new(),
+                );
+                pattern.significance = hypergeometric_upper_tail(shared, population, of_a.len(), of_b.len());
+                let mut shared_neighbours: Vec<&str> = of_a.intersection(&of_b).copied().collect();
+                shared_neighbours.sort_unstable();
+                pattern.context.insert("shared_neighbours".to_string(), shared_neighbours.join(","));
+                Some(pattern)
+            })
+            .collect()
+    }
+}
+
+/// ln Γ(x) for x > 0 (Stirling series, shifted up for small x)
+fn ln_gamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut shift = 0.0;
+    while x < 10.0 {
+        shift -= x.ln();
+        x += 1.0;
+    }
+    let inverse = 1.0 / x;
+    let inverse_squared = inverse * inverse;
+    let series = inverse * (1.0 / 12.0 - inverse_squared * (1.0 / 360.0 - inverse_squared * (1.0 / 1260.0 - inverse_squared / 1680.0)));
+    shift + (x - 0.5) * x.ln() - x + 0.5 * (2.0 * std::f64::consts::PI).ln() + series
+}
+
+fn ln_choose(n: usize, k: usize) -> f64 {
+    ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+}
+
+fn binomial_ln_pmf(i: usize, n: usize, p: f64) -> f64 {
+    ln_choose(n, i) + i as f64 * p.ln() + (n - i) as f64 * (1.0 - p).ln()
+}
+
+/// P(X >= k) for X ~ Binomial(n, p)
+fn binomial_upper_tail(k: usize, n: usize, p: f64) -> f64 {
+    if k == 0 {
+        return 1.0;
+    }
+    if k > n || p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+    (k..=n).map(|i| binomial_ln_pmf(i, n, p).exp()).sum::<f64>().min(1.0)
+}
+
+/// P(X <= k) for X ~ Binomial(n, p)
+fn binomial_lower_tail(k: usize, n: usize, p: f64) -> f64 {
+    if k >= n || p <= 0.0 {
+        return 1.0;
+    }
+    if p >= 1.0 {
+        return 0.0;
+    }
+    (0..=k).map(|i| binomial_ln_pmf(i, n, p).exp()).sum::<f64>().min(1.0)
+}
+
+/// P(X >= k) for the overlap X of a draw of `draws` from `population` items, `successes` of them marked
+fn hypergeometric_upper_tail(k: usize, population: usize, successes: usize, draws: usize) -> f64 {
+    if k == 0 {
+        return 1.0;
+    }
+    if successes > population || draws > population {
+        return 0.0;
+    }
+    let ln_total = ln_choose(population, draws);
+    (k..=successes.min(draws))
+        .filter(|i| draws - i <= population - successes)
+        .map(|i| (ln_choose(successes, i) + ln_choose(population - successes, draws - i) - ln_total).exp())
+        .sum::<f64>()
+        .min(1.0)
+}
+
+/// P(X >= k) for X ~ Poisson(lambda)
+fn poisson_upper_tail(k: usize, lambda: f64) -> f64 {
+    if k == 0 {
+        return 1.0;
+    }
+    if lambda <= 0.0 {
+        return 0.0;
+    }
+    let below: f64 = (0..k)
+        .map(|i| (i as f64 * lambda.ln() - lambda - ln_gamma(i as f64 + 1.0)).exp())
+        .sum();
+    (1.0 - below).clamp(0.0, 1.0)
+}
+
 /// Pattern detection system that monitors memory and identifies patterns
 pub struct PatternDetector {
     /// Configuration for pattern detection
@@ -221,8 +756,8 @@ pub struct PatternDetector {
     pattern_cache: HashMap<String, DetectedPattern>,
     /// Statistics tracking
     detection_stats: DetectionStats,
-    /// Last processing timestamp for incremental detection
-    last_processed_at: Option<DateTime<Utc>>,
+    /// Running counts over the episodic events ingested so far
+    event_statistics: EventStatistics,
 }
 
 impl PatternDetector {
@@ -232,7 +767,7 @@ impl PatternDetector {
             config: PatternDetectionConfig::default(),
             pattern_cache: HashMap::new(),
             detection_stats: DetectionStats::default(),
-            last_processed_at: None,
+            event_statistics: EventStatistics::default(),
         }
     }
 
@@ -242,7 +777,7 @@ impl PatternDetector {
             config,
             pattern_cache: HashMap::new(),
             detection_stats: DetectionStats::default(),
-            last_processed_at: None,
+            event_statistics: EventStatistics::default(),
         }
     }
 
@@ -276,28 +811,178 @@ impl PatternDetector {
             }
         }
         
+        Ok(self.finish_detection(detected_patterns, 0, _memory_items.len(), start_time))
+    }
+
+    /// Detect patterns across episodic events
+    ///
+    /// Each event contributes its tags, or the leading words of its content when
+    /// it has none. Frequency, co-occurrence, temporal sequence, causal,
+    /// negation, correlation and similarity patterns are tested against
+    /// everything ingested so far. With incremental detection enabled, only
+    /// events not ingested by an earlier run are, whatever their timestamps.
+    pub async fn detect_patterns_from_events(&mut self, events: &[EpisodicEvent]) -> Result<PatternDetectionResult> {
+        let start_time = std::time::Instant::now();
+        let window = chrono::Duration::hours(self.config.temporal_window_hours.max(1));
+
+        if !self.config.incremental_detection {
+            self.event_statistics = EventStatistics::default();
+        }
+
+        let mut seen = HashSet::new();
+        let mut new_events: Vec<&EpisodicEvent> = events
+            .iter()
+            .filter(|event| !self.event_statistics.has_ingested(event.id) && seen.insert(event.id))
+            .collect();
+        new_events.sort_by_key(|event| event.timestamp);
+        for event in &new_events {
+            let elements = self.event_elements(event);
+            self.event_statistics.ingest(event.id, event.timestamp, elements, window);
+        }
+
+        let statistics = &self.event_statistics;
+        let config = &self.config;
+        let mut candidates = statistics.frequency_patterns(config);
+        candidates.extend(statistics.co_occurrence_patterns(config));
+        candidates.extend(statistics.sequence_patterns(config));
+        candidates.extend(statistics.negation_patterns(config));
+        candidates.extend(statistics.correlation_patterns(config));
+        candidates.extend(statistics.similarity_patterns(config));
+
+        let (detected_patterns, filtered_patterns) = self.select_significant(candidates);
+        Ok(self.finish_detection(detected_patterns, filtered_patterns, new_events.len(), start_time))
+    }
+
+    /// Detect hierarchies in the concept graph
+    ///
+    /// Reports concepts with more `IS_A` or `PART_OF` children than random
+    /// attachment of the graph's hierarchical relationships would give them
+    /// (Poisson test). Run again as the graph grows; patterns found before keep
+    /// their IDs while their children are unchanged.
+    pub async fn detect_hierarchical_patterns(
+        &mut self,
+        concepts: &[ConceptNode],
+        relationships: &[ConceptRelationship],
+    ) -> Result<PatternDetectionResult> {
+        let start_time = std::time::Instant::now();
+        let names: HashMap<Uuid, &str> = concepts.iter().map(|concept| (concept.id, concept.content.as_str())).collect();
+
+        // Children by parent and relationship; IS_A and PART_OF both point from child to parent
+        let mut children: HashMap<(Uuid, String), Vec<&ConceptRelationship>> = HashMap::new();
+        let mut hierarchical_edges = 0;
+        for relationship in relationships {
+            if !matches!(relationship.relationship_type, RelationshipType::IsA | RelationshipType::PartOf)
+                || !names.contains_key(&relationship.source_id)
+                || !names.contains_key(&relationship.target_id)
+            {
+                continue;
+            }
+            hierarchical_edges += 1;
+            children
+                .entry((relationship.target_id, relationship.relationship_type.to_string()))
+                .or_default()
+                .push(relationship);
+        }
+        let expected_children = hierarchical_edges as f64 / concepts.len().max(1) as f64;
+
+        let mut candidates = Vec::new();
+        for ((parent, relation), edges) in &children {
+            let mut child_ids: Vec<Uuid> = edges.iter().map(|edge| edge.source_id).collect();
+            child_ids.sort();
+            child_ids.dedup();
+            let mut child_names: Vec<String> = child_ids.iter().map(|id| names[id].to_string()).collect();
+            child_names.sort();
+
+            let mut elements = vec![names[parent].to_string()];
+            elements.extend(child_names);
+            let mut evidence = vec![*parent];
+            evidence.extend(&child_ids);
+            let average_weight = edges.iter().map(|edge| edge.weight).sum::<f64>() / edges.len() as f64;
+
+            let mut pattern = DetectedPattern::new(
+                PatternType::Hierarchical,
+                elements,
+                child_ids.len(),
+                average_weight.clamp(0.0, 1.0),
+                evidence,
+            );
+            pattern.significance = poisson_upper_tail(child_ids.len(), expected_children);
+            pattern.context.insert("relationship".to_string(), relation.clone());
+            pattern.context.insert("depth".to_string(), hierarchy_depth(*parent, relation, &children).to_string());
+            candidates.push(pattern);
+        }
+
+        let (detected_patterns, filtered_patterns) = self.select_significant(candidates);
+        Ok(self.finish_detection(detected_patterns, filtered_patterns, concepts.len(), start_time))
+    }
+
+    /// Elements an episodic event contributes to pattern detection
+    fn event_elements(&self, event: &EpisodicEvent) -> Vec<String> {
+        let mut elements: Vec<String> = if event.tags.is_empty() {
+            vec![self.extract_content_pattern(&event.content).to_lowercase()]
+        } else {
+            event.tags.iter().map(|tag| tag.trim().to_lowercase()).collect()
+        };
+        elements.retain(|element| !element.is_empty());
+        elements.sort();
+        elements.dedup();
+        elements
+    }
+
+    /// Keep the candidates that pass the configured thresholds, most significant first
+    ///
+    /// Returns the kept patterns and how many were dropped.
+    fn select_significant(&self, candidates: Vec<DetectedPattern>) -> (Vec<DetectedPattern>, usize) {
+        let candidate_count = candidates.len();
+        let mut significant: Vec<DetectedPattern> = candidates
+            .into_iter()
+            .filter(|pattern| pattern.is_significant(&self.config))
+            .collect();
+        significant.sort_by(|a, b| {
+            a.significance
+                .partial_cmp(&b.significance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        significant.truncate(self.config.max_patterns_per_batch);
+        let filtered = candidate_count - significant.len();
+        (significant, filtered)
+    }
+
+    /// Update the cache and statistics with a run's patterns and build its result
+    fn finish_detection(
+        &mut self,
+        mut detected_patterns: Vec<DetectedPattern>,
+        filtered_patterns: usize,
+        items_processed: usize,
+        start_time: std::time::Instant,
+    ) -> PatternDetectionResult {
+        // Patterns found by earlier runs keep their IDs
+        for pattern in &mut detected_patterns {
+            if let Some(cached) = self.pattern_cache.get(&Self::cache_key(pattern)) {
+                pattern.id = cached.id;
+            }
+        }
+
         let processing_time = start_time.elapsed().as_millis() as u64;
-        
-        // Update cache and stats
         self.update_pattern_cache(&detected_patterns);
-        self.update_detection_stats(&detected_patterns, _memory_items.len(), processing_time);
-        
+        self.update_detection_stats(&detected_patterns, items_processed, processing_time);
+
         let mut pattern_type_counts = HashMap::new();
         for pattern in &detected_patterns {
             *pattern_type_counts.entry(pattern.pattern_type.clone()).or_insert(0) += 1;
         }
-        
-        Ok(PatternDetectionResult {
+
+        PatternDetectionResult {
             detected_patterns,
-            items_processed: _memory_items.len(),
+            items_processed,
             processing_time_ms: processing_time,
-            filtered_patterns: 0,
+            filtered_patterns,
             pattern_type_counts,
-        })
+        }
     }
 
     /// Extract content pattern from text
-    #[allow(dead_code)]
     fn extract_content_pattern(&self, content: &str) -> String {
         // Simple pattern extraction - in real implementation would use NLP
         content.split_whitespace()
@@ -309,11 +994,14 @@ impl PatternDetector {
     /// Update pattern cache with new patterns
     fn update_pattern_cache(&mut self, patterns: &[DetectedPattern]) {
         for pattern in patterns {
-            let key = format!("{:?}_{}", pattern.pattern_type, pattern.elements.join("_"));
-            self.pattern_cache.insert(key, pattern.clone());
+            self.pattern_cache.insert(Self::cache_key(pattern), pattern.clone());
         }
     }
 
+    fn cache_key(pattern: &DetectedPattern) -> String {
+        format!("{:?}_{}", pattern.pattern_type, pattern.elements.join("_"))
+    }
+
     /// Update detection statistics
     fn update_detection_stats(&mut self, patterns: &[DetectedPattern], items_processed: usize, processing_time_ms: u64) {
         self.detection_stats.total_patterns_detected += patterns.len();
@@ -357,8 +1045,24 @@ impl PatternDetector {
     /// Reset statistics
     pub fn reset_stats(&mut self) {
         self.detection_stats = DetectionStats::default();
-        self.last_processed_at = None;
+        self.event_statistics = EventStatistics::default();
+    }
+}
+
+/// Levels of the hierarchy below `root` along one relationship type
+fn hierarchy_depth(root: Uuid, relation: &str, children: &HashMap<(Uuid, String), Vec<&ConceptRelationship>>) -> usize {
+    let mut deepest = 0;
+    let mut visited = HashSet::from([root]);
+    let mut stack = vec![(root, 0)];
+    while let Some((node, depth)) = stack.pop() {
+        deepest = deepest.max(depth);
+        for edge in children.get(&(node, relation.to_string())).into_iter().flatten() {
+            if visited.insert(edge.source_id) {
+                stack.push((edge.source_id, depth + 1));
+            }
+        }
     }
+    deepest
 }
 
 impl Default for PatternDetector {
@@ -404,22 +1108,34 @@ impl InsightExtractionManager {
 
     /// Extract insights from content using pattern detection
     pub async fn extract_insights_from_content(&mut self, content: &[String]) -> Result<Vec<Insight>> {
-        // Detect patterns first
         let pattern_result = self.pattern_detector.detect_patterns_from_memory(content).await?;
-        
+        self.store_pattern_insights(&pattern_result).await
+    }
+
+    /// Extract insights from episodic events using pattern detection
+    pub async fn extract_insights_from_events(&mut self, events: &[EpisodicEvent]) -> Result<Vec<Insight>> {
+        let pattern_result = self.pattern_detector.detect_patterns_from_events(events).await?;
+        self.store_pattern_insights(&pattern_result).await
+    }
+
+    /// Extract insights from the hierarchy of the concept graph
+    pub async fn extract_insights_from_concept_graph(
+        &mut self,
+        concepts: &[ConceptNode],
+        relationships: &[ConceptRelationship],
+    ) -> Result<Vec<Insight>> {
+        let pattern_result = self.pattern_detector.detect_hierarchical_patterns(concepts, relationships).await?;
+        self.store_pattern_insights(&pattern_result).await
+    }
+
+    /// Convert significant patterns to stored insights
+    async fn store_pattern_insights(&mut self, pattern_result: &PatternDetectionResult) -> Result<Vec<Insight>> {
         let mut insights = Vec::new();
         let mut pattern_insight_ids = Vec::new();
         
         // Convert significant patterns to insights
         for pattern in &pattern_result.detected_patterns {
             if pattern.is_significant(self.pattern_detector.get_config()) {
-                let insight_content = format!(
-                    "Pattern detected: {} with {} occurrences (confidence: {:.2})",
-                    pattern.elements.join(" -> "),
-                    pattern.frequency,
-                    pattern.confidence
-                );
-                
                 let insight_type = match pattern.pattern_type {
                     PatternType::TemporalSequence => InsightType::Pattern,
                     PatternType::CoOccurrence => InsightType::Relationship,
@@ -433,7 +1149,7 @@ impl InsightExtractionManager {
                 
                 let insight = Insight {
                     id: Uuid::new_v4(),
-                    content: insight_content,
+                    content: pattern.describe(),
                     confidence: pattern.confidence,
                     source: "PatternDetection".to_string(),
                     insight_type,
@@ -944,6 +1660,216 @@ mod tests {
         assert_eq!(stats.detection_operations, 0);
         assert_eq!(stats.average_patterns_per_operation, 0.0);
     }
-}
 
- 
\ No newline at end of file
+    fn event_at(tags: &[&str], minutes: i64) -> EpisodicEvent {
+        let mut event = EpisodicEvent::new(tags.join(" "), HashMap::new(), 0.5, "test".to_string());
+        event.timestamp = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap() + chrono::Duration::minutes(minutes);
+        for tag in tags {
+            event.add_tag(tag.to_string());
+        }
+        event
+    }
+
+    /// Every deploy is followed ten minutes later by an alert, with lunch half a day after
+    fn deploy_cycles(cycles: std::ops::Range<i64>) -> Vec<EpisodicEvent> {
+        cycles
+            .flat_map(|cycle| {
+                let start = cycle * 48 * 60;
+                vec![
+                    event_at(&["deploy"], start),
+                    event_at(&["alert"], start + 10),
+                    event_at(&["lunch"], start + 12 * 60),
+                ]
+            })
+            .collect()
+    }
+
+    fn find<'a>(result: &'a PatternDetectionResult, pattern_type: PatternType, elements: &[&str]) -> Option<&'a DetectedPattern> {
+        result.detected_patterns.iter().find(|pattern| pattern.pattern_type == pattern_type && pattern.elements == elements)
+    }
+
+    #[tokio::test]
+    async fn test_temporal_causal_and_negation_patterns() {
+        let mut detector = PatternDetector::new();
+        let result = detector.detect_patterns_from_events(&deploy_cycles(0..8)).await.unwrap();
+        assert_eq!(result.items_processed, 24);
+
+        let sequence = find(&result, PatternType::TemporalSequence, &["deploy", "alert"]).expect("deploy -> alert");
+        assert_eq!(sequence.frequency, 8);
+        assert!(sequence.significance < 0.01);
+        let timing = sequence.temporal_info.as_ref().unwrap();
+        assert_eq!(timing.average_delay_minutes, 10.0);
+        assert_eq!(timing.delay_std_dev, 0.0);
+        assert_eq!(sequence.evidence.len(), 16);
+
+        let causal = find(&result, PatternType::Causal, &["deploy", "alert"]).expect("deploy causes alert");
+        assert_eq!(causal.confidence, 1.0);
+        assert!(find(&result, PatternType::Causal, &["alert", "deploy"]).is_none());
+
+        // Alerts follow a third of all events but never lunch
+        let negation = find(&result, PatternType::Negation, &["lunch", "alert"]).expect("lunch -/-> alert");
+        assert!(negation.significance <= 0.05);
+        assert!(result.detected_patterns.iter().all(|pattern| pattern.is_significant(detector.get_config())));
+        assert!(result.filtered_patterns > 0);
+    }
+
+    #[tokio::test]
+    async fn test_co_occurrence_and_similarity_patterns() {
+        let mut detector = PatternDetector::new();
+        let fillers = [["rain", "umbrella"], ["train", "ticket"], ["book", "page"]];
+        let mut groups: Vec<&[&str]> = vec![&["rust", "cargo"][..]; 6];
+        groups.extend([
+            &["coffee", "morning", "cup", "hot"][..],
+            &["coffee", "morning", "cup", "hot"][..],
+            &["tea", "morning", "cup", "hot"][..],
+            &["tea", "morning", "cup", "hot"][..],
+        ]);
+        for filler in &fillers {
+            groups.extend([&filler[..], &filler[..]]);
+        }
+        let events: Vec<EpisodicEvent> = groups
+            .iter()
+            .enumerate()
+            .map(|(i, tags)| event_at(tags, i as i64 * 48 * 60))
+            .collect();
+
+        let result = detector.detect_patterns_from_events(&events).await.unwrap();
+
+        let together = find(&result, PatternType::CoOccurrence, &["cargo", "rust"]).expect("rust & cargo");
+        assert_eq!(together.frequency, 6);
+        assert_eq!(together.confidence, 1.0);
+        assert!(together.significance < 0.001);
+
+        // Coffee and tea never appear together but keep the same company
+        assert!(find(&result, PatternType::CoOccurrence, &["coffee", "tea"]).is_none());
+        let similar = find(&result, PatternType::Similarity, &["coffee", "tea"]).expect("coffee ~ tea");
+        assert_eq!(similar.frequency, 3);
+        assert_eq!(similar.context["shared_neighbours"], "cup,hot,morning");
+    }
+
+    #[tokio::test]
+    async fn test_correlation_patterns() {
+        let config = PatternDetectionConfig {
+            temporal_window_hours: 1,
+            ..Default::default()
+        };
+        let mut detector = PatternDetector::with_config(config);
+        let counts = [1, 3, 0, 2, 4, 1, 0, 3, 2, 4, 1, 2];
+        let mut events = Vec::new();
+        for (hour, count) in counts.iter().enumerate() {
+            for i in 0..*count {
+                let minute = hour as i64 * 60 + i * 10;
+                events.push(event_at(&["cpu"], minute));
+                events.push(event_at(&["fan"], minute + 1));
+            }
+        }
+
+        let result = detector.detect_patterns_from_events(&events).await.unwrap();
+        let correlation = find(&result, PatternType::Correlation, &["cpu", "fan"]).expect("cpu ~ fan");
+        assert!(correlation.confidence > 0.99);
+        assert_eq!(correlation.context["windows"], "12");
+        assert_eq!(correlation.frequency, 10);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_event_detection() {
+        let mut detector = PatternDetector::new();
+        let events = deploy_cycles(0..8);
+
+        // Four repeats are not yet enough to rule out chance ordering
+        let first = detector.detect_patterns_from_events(&events[..12]).await.unwrap();
+        assert!(find(&first, PatternType::TemporalSequence, &["deploy", "alert"]).is_none());
+
+        let second = detector.detect_patterns_from_events(&events).await.unwrap();
+        assert_eq!(second.items_processed, 12);
+        let sequence = find(&second, PatternType::TemporalSequence, &["deploy", "alert"]).expect("deploy -> alert");
+        assert_eq!(sequence.frequency, 8);
+
+        // Nothing new to ingest; the pattern keeps its identity
+        let third = detector.detect_patterns_from_events(&events).await.unwrap();
+        assert_eq!(third.items_processed, 0);
+        assert_eq!(find(&third, PatternType::TemporalSequence, &["deploy", "alert"]).unwrap().id, sequence.id);
+
+        // Without incremental detection every run starts over
+        detector.set_config(PatternDetectionConfig {
+            incremental_detection: false,
+            ..Default::default()
+        });
+        let fresh = detector.detect_patterns_from_events(&events[..12]).await.unwrap();
+        assert_eq!(fresh.items_processed, 12);
+        assert!(find(&fresh, PatternType::TemporalSequence, &["deploy", "alert"]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_incremental_detection_ingests_late_events() {
+        let events = deploy_cycles(0..8);
+        let mut batch = PatternDetector::new();
+        let expected = batch.detect_patterns_from_events(&events).await.unwrap();
+        let expected = find(&expected, PatternType::TemporalSequence, &["deploy", "alert"]).unwrap().clone();
+
+        // The first run is missing the last alert and an event sharing its latest timestamp
+        let late_alert = events[22].id;
+        let tied = event_at(&["lunch"], 7 * 48 * 60 + 12 * 60);
+        let first_run: Vec<EpisodicEvent> = events.iter().filter(|event| event.id != late_alert).cloned().collect();
+
+        let mut detector = PatternDetector::new();
+        let first = detector.detect_patterns_from_events(&first_run).await.unwrap();
+        assert_eq!(first.items_processed, 23);
+
+        let mut second_run = events.clone();
+        second_run.push(tied);
+        let second = detector.detect_patterns_from_events(&second_run).await.unwrap();
+        assert_eq!(second.items_processed, 2);
+        let sequence = find(&second, PatternType::TemporalSequence, &["deploy", "alert"]).unwrap();
+        assert_eq!(sequence.frequency, expected.frequency);
+        assert_eq!(sequence.temporal_info.as_ref().unwrap().average_delay_minutes, 10.0);
+
+        // Events already ingested are never counted again
+        let third = detector.detect_patterns_from_events(&second_run).await.unwrap();
+        assert_eq!(third.items_processed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_hierarchical_patterns_from_concept_graph() {
+        let concept = |name: &str| ConceptNode::new(brain_core::ConceptType::Entity, name.to_string(), 0.9, None);
+        let animal = concept("animal");
+        let mammal = concept("mammal");
+        let whale = concept("whale");
+        let children: Vec<ConceptNode> = ["dog", "cat", "bird", "fish"].into_iter().map(concept).collect();
+        let others: Vec<ConceptNode> = ["car", "tree", "rock"].into_iter().map(concept).collect();
+
+        let mut relationships: Vec<ConceptRelationship> = children
+            .iter()
+            .map(|child| ConceptRelationship::new(child.id, animal.id, RelationshipType::IsA, 0.9))
+            .collect();
+        relationships.push(ConceptRelationship::new(mammal.id, animal.id, RelationshipType::IsA, 0.9));
+        relationships.push(ConceptRelationship::new(whale.id, mammal.id, RelationshipType::IsA, 0.9));
+        relationships.push(ConceptRelationship::new(others[0].id, others[1].id, RelationshipType::AssociatedWith, 0.9));
+
+        let mut concepts = vec![animal, mammal, whale];
+        concepts.extend(children);
+        concepts.extend(others);
+
+        let mut manager = InsightExtractionManager::new(Box::new(InMemoryInsightRepository::new()));
+        let insights = manager.extract_insights_from_concept_graph(&concepts, &relationships).await.unwrap();
+
+        assert_eq!(insights.len(), 1);
+        assert_eq!(insights[0].insight_type, InsightType::Relationship);
+        assert!(insights[0].content.starts_with("Hierarchical pattern detected: animal > bird, cat, dog, fish, mammal"));
+        let patterns = manager.pattern_detector.get_cached_patterns();
+        let hierarchy = patterns.iter().find(|pattern| pattern.pattern_type == PatternType::Hierarchical).unwrap();
+        assert_eq!(hierarchy.frequency, 5);
+        assert_eq!(hierarchy.context["relationship"], "IS_A");
+        assert_eq!(hierarchy.context["depth"], "2");
+    }
+
+    #[test]
+    fn test_significance_tests() {
+        assert!((binomial_upper_tail(8, 8, 0.5) - 0.5f64.powi(8)).abs() < 1e-9);
+        assert!((binomial_lower_tail(0, 8, 1.0 / 3.0) - (2.0f64 / 3.0).powi(8)).abs() < 1e-9);
+        assert!((hypergeometric_upper_tail(3, 11, 3, 3) - 1.0 / 165.0).abs() < 1e-9);
+        assert!((poisson_upper_tail(1, 2.0) - (1.0 - (-2.0f64).exp())).abs() < 1e-9);
+        assert!((ln_gamma(5.0) - 24.0f64.ln()).abs() < 1e-9);
+        assert!((normal_cdf(1.959964) - 0.975).abs() < 1e-6);
+    }
+}
@@ -6,13 +6,23 @@
 
 pub mod memory;
 pub mod concepts;
+pub mod concept_store;
+pub mod neo4j_concepts;
+#[cfg(test)]
+mod neo4j_stand_in;
+pub mod concept_backend;
+pub mod semantic_store;
+pub mod vector_index;
 pub mod segmentation;
 pub mod insights;
 pub mod neural;
+pub mod neural_checkpoint;
 pub mod character_ingestion;
 pub mod simulation;
 pub mod simulation_engine;
+pub mod state_grammar;
 pub mod database;
+pub mod conformance;
 pub mod filesystem;
 pub mod http;
 pub mod config;
@@ -26,18 +36,27 @@ pub use memory::{
     average_embeddings
 };
 pub use concepts::{
-    ConceptGraphManager, ConceptGraphConfig, HebbianConfig, GraphStatistics,
+    ConceptGraphManager, ConceptGraphConfig, ConceptGraphBackend, HebbianConfig, GraphStatistics,
     ConceptFormationConfig, ConceptFormationResult, SimilarityConfig,
     ConceptSubgraph, cosine_similarity
 };
+pub use concept_store::SqliteConceptStore;
+pub use neo4j_concepts::{Neo4jConceptRepository, CypherExecutor, CypherStatement};
+pub use concept_backend::ConceptGraphStore;
+pub use semantic_store::SqliteSemanticMemoryRepository;
+pub use vector_index::{HnswIndex, HnswConfig};
 pub use segmentation::{InMemorySegmentRepository, BpeSegmenter, FeedbackBpeSegmenter, ContextMatrix, EntropyAnalyzer};
 pub use insights::*;
 pub use neural::*;
+pub use neural_checkpoint::{
+    FileNeuralRepository, encode_checkpoint, decode_checkpoint, save_checkpoint_file, load_checkpoint_file,
+    CHECKPOINT_MAGIC, CHECKPOINT_VERSION,
+};
 pub use character_ingestion::{
     FileCharacterIngestionRepository, CharacterPredictor, SimplePerformanceTracker, SimpleSegmentProvider
 };
 pub use simulation::{
-    TextToStateParserImpl, StateValidatorImpl, SimulationEngineImpl, ActionConfig, ConfidenceConfig
+    TextToStateParserImpl, StateValidatorImpl, SimulationEngineImpl, ActionConfig, ConfidenceConfig, evaluate_condition
 };
 pub use simulation_engine::{
     SimulationEngine, SimulationState, StateProperty, PropertyType,
@@ -45,6 +64,10 @@ pub use simulation_engine::{
     BranchingConfig, ConfidenceConfig as SimulationConfidenceConfig, SimulationConstraint, ConstraintType, BranchingResult,
     SimulationBranch, PruningStatistics
 };
+pub use state_grammar::{
+    StateGrammar, EntityRule, PropertyRule, RelationRule, PropertyValue, PropertyScope, Attachment,
+    RuleBasedStateParser, ParsedText, ParsedEntity, ParsedRelation, TextSpan
+};
 pub use database::{DatabaseManager, DatabaseConfig as DbConfig};
 pub use filesystem::*;
 pub use http::*;
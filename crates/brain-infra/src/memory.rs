@@ -13,6 +13,8 @@ use std::path::Path;
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
+pub use brain_core::average_embeddings;
+
 /// Advanced working memory implementation with priority queues and decay
 #[derive(Debug)]
 pub struct WorkingMemoryRepository {
@@ -746,31 +748,6 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
     }
 }
 
-/// Average multiple embeddings into one
-pub fn average_embeddings(embeddings: &[&Vec<f32>]) -> Vec<f32> {
-    if embeddings.is_empty() {
-        return Vec::new();
-    }
-
-    let len = embeddings[0].len();
-    let mut result = vec![0.0; len];
-
-    for embedding in embeddings {
-        for (i, &value) in embedding.iter().enumerate() {
-            if i < len {
-                result[i] += value;
-            }
-        }
-    }
-
-    let count = embeddings.len() as f32;
-    for value in &mut result {
-        *value /= count;
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -834,6 +811,93 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_consolidation_extracts_semantic_concepts() -> Result<()> {
+        let temp_file = NamedTempFile::new().unwrap();
+        // A second handle on the same database lets the test seed events the service will see
+        let mut seed_repo = EpisodicMemoryRepository::new(temp_file.path()).await?;
+        let episodic_repo = EpisodicMemoryRepository::new(temp_file.path()).await?;
+
+        let episodes = [
+            ("Rust borrow checker rejected the mutable reference", "rust"),
+            ("Fixed a Rust borrow checker error with a clone", "rust"),
+            ("Borrow checker complained about mutable reference lifetimes", "rust"),
+            ("Baked sourdough bread with a long cold proof", "baking"),
+        ];
+        let mut rust_ids = Vec::new();
+        for (content, tag) in episodes {
+            let mut event = EpisodicEvent::new(content.to_string(), HashMap::new(), 0.9, "test".to_string());
+            event.add_tag(tag.to_string());
+            let id = seed_repo.store_event(event).await?;
+            if tag == "rust" {
+                rust_ids.push(id);
+            }
+        }
+
+        let mut service = MemoryService::new(
+            Box::new(WorkingMemoryRepository::new(10)),
+            Box::new(episodic_repo),
+            Box::new(SemanticMemoryRepository::new()),
+        )
+        .with_embedding_provider(Arc::new(KeywordEmbeddingProvider::default()));
+        let config = ConsolidationConfig { semantic_extraction_threshold: 0.5, ..Default::default() };
+        service.configure_consolidation(config);
+
+        let result = service.consolidate().await?;
+        assert_eq!(result.episodic_to_semantic, 1);
+
+        let concepts = service.query_semantic(&SemanticQuery::default()).await?;
+        assert_eq!(concepts.len(), 1);
+        let concept = &concepts[0];
+        assert_eq!(concept.name, "rust");
+        assert_eq!(concept.frequency, 3);
+        assert_eq!(concept.embedding.len(), KEYWORD_EMBEDDING_DIMENSIONS);
+        let mut linked = concept.source_events.clone();
+        linked.sort();
+        rust_ids.sort();
+        assert_eq!(linked, rust_ids);
+
+        let linked_event = seed_repo.get_event(rust_ids[0]).await?.unwrap();
+        assert_eq!(linked_event.context.get(SEMANTIC_CONCEPT_CONTEXT_KEY), Some(&concept.id.to_string()));
+
+        // A new matching event strengthens the concept instead of creating another
+        let mut follow_up = EpisodicEvent::new("Borrow checker rejected another mutable reference".to_string(), HashMap::new(), 0.9, "test".to_string());
+        follow_up.add_tag("rust".to_string());
+        seed_repo.store_event(follow_up).await?;
+
+        let result = service.consolidate().await?;
+        assert_eq!(result.episodic_to_semantic, 1);
+        let concepts = service.query_semantic(&SemanticQuery::default()).await?;
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].frequency, 4);
+        assert_eq!(concepts[0].source_events.len(), 4);
+        assert!(concepts[0].confidence > concept.confidence);
+
+        // Already generalised events are not counted again
+        assert_eq!(service.consolidate().await?.episodic_to_semantic, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_consolidation_without_embedding_provider_skips_extraction() -> Result<()> {
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut episodic_repo = EpisodicMemoryRepository::new(temp_file.path()).await?;
+        for content in ["Rust borrow checker rejected the reference", "Rust borrow checker rejected a clone"] {
+            episodic_repo.store_event(EpisodicEvent::new(content.to_string(), HashMap::new(), 0.9, "test".to_string())).await?;
+        }
+
+        let mut service = MemoryService::new(
+            Box::new(WorkingMemoryRepository::new(10)),
+            Box::new(episodic_repo),
+            Box::new(SemanticMemoryRepository::new()),
+        );
+        assert_eq!(service.consolidate().await?.episodic_to_semantic, 0);
+        assert!(service.query_semantic(&SemanticQuery::default()).await?.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_cosine_similarity() {
         let a = vec![1.0, 0.0, 0.0];
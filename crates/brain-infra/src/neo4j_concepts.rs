@@ -0,0 +1,680 @@
+//! Neo4j Concept Graph Backend
+//!
+//! Stores concepts as `(:Concept)` nodes and relationships as `[:RELATES_TO]`
+//! edges on a Neo4j server reached over Bolt. Hebbian activation, decay and
+//! pruning run as single Cypher statements on the server, and neighbourhood
+//! and shortest-path traversal are pushed down as variable-length matches
+//! instead of being walked edge by edge from the client.
+//!
+//! Statements go through a [`CypherExecutor`], which is a Bolt connection in
+//! production and can be swapped for a stand-in in tests.
+
+use crate::concepts::ConceptGraphConfig;
+use brain_core::*;
+use brain_types::*;
+use chrono::{DateTime, SecondsFormat, Utc};
+use neo4rs::{BoltType, ConfigBuilder, Graph, Query, Row};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Columns projected for every concept read so rows map onto `ConceptNode`
+const CONCEPT_COLUMNS: &str = "c.id AS id, c.concept_type AS concept_type, c.content AS content, \
+     c.description AS description, c.created_at AS created_at, c.last_accessed_at AS last_accessed_at, \
+     c.usage_count AS usage_count, c.confidence_score AS confidence_score, \
+     c.source_reference AS source_reference, c.metadata AS metadata";
+
+/// Columns projected for every relationship read so rows map onto `ConceptRelationship`
+const RELATIONSHIP_COLUMNS: &str = "r.id AS id, s.id AS source_id, t.id AS target_id, \
+     r.relationship_type AS relationship_type, r.weight AS weight, r.activation_count AS activation_count, \
+     r.created_at AS created_at, r.last_activated_at AS last_activated_at, r.base_weight AS base_weight, \
+     r.learning_rate AS learning_rate, r.decay_rate AS decay_rate, r.pruning_threshold AS pruning_threshold, \
+     r.metadata AS metadata";
+
+/// A Cypher statement and its parameters
+#[derive(Debug, Clone)]
+pub struct CypherStatement {
+    pub text: String,
+    pub params: HashMap<String, BoltType>,
+}
+
+impl CypherStatement {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), params: HashMap::new() }
+    }
+
+    pub fn param(mut self, key: &str, value: impl Into<BoltType>) -> Self {
+        self.params.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+/// Runs Cypher statements for `Neo4jConceptRepository`
+///
+/// Failures are reported as `BrainError::DatabaseError`; the repository adds
+/// which operation failed.
+#[async_trait::async_trait]
+pub trait CypherExecutor: Send + Sync {
+    /// Run a statement, discarding any rows
+    async fn run(&self, statement: CypherStatement) -> Result<()>;
+
+    /// Run a statement and collect its rows
+    async fn fetch(&self, statement: CypherStatement) -> Result<Vec<Row>>;
+}
+
+/// Executor speaking Bolt to a Neo4j server
+struct BoltExecutor {
+    graph: Graph,
+}
+
+impl BoltExecutor {
+    fn query(statement: CypherStatement) -> Query {
+        Query::new(statement.text).params(statement.params)
+    }
+}
+
+#[async_trait::async_trait]
+impl CypherExecutor for BoltExecutor {
+    async fn run(&self, statement: CypherStatement) -> Result<()> {
+        self.graph
+            .run(Self::query(statement))
+            .await
+            .map_err(|e| BrainError::DatabaseError(e.to_string()))
+    }
+
+    async fn fetch(&self, statement: CypherStatement) -> Result<Vec<Row>> {
+        let mut stream = self
+            .graph
+            .execute(Self::query(statement))
+            .await
+            .map_err(|e| BrainError::DatabaseError(e.to_string()))?;
+
+        let mut rows = Vec::new();
+        while let Some(row) = stream.next().await.map_err(|e| BrainError::DatabaseError(e.to_string()))? {
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+}
+
+/// Concept and relationship repository backed by a Neo4j database
+#[derive(Clone)]
+pub struct Neo4jConceptRepository {
+    executor: Arc<dyn CypherExecutor>,
+    database: String,
+}
+
+impl std::fmt::Debug for Neo4jConceptRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Neo4jConceptRepository")
+            .field("database", &self.database)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Neo4jConceptRepository {
+    /// Connect using the URI, credentials and database from the config and
+    /// make sure the id constraint and index exist
+    pub async fn connect(config: &ConceptGraphConfig) -> Result<Self> {
+        let database = config.database.clone().unwrap_or_else(|| "neo4j".to_string());
+        let neo4j_config = ConfigBuilder::new()
+            .uri(config.uri.as_str())
+            .user(config.username.as_str())
+            .password(config.password.as_str())
+            .db(database.as_str())
+            .max_connections(config.pool_size.max(1) as usize)
+            .build()
+            .map_err(|e| BrainError::ConfigError(format!("Invalid Neo4j configuration: {}", e)))?;
+
+        let graph = tokio::time::timeout(Duration::from_secs(config.timeout_seconds), Graph::connect(neo4j_config))
+            .await
+            .map_err(|_| BrainError::NetworkError(format!("Timed out connecting to Neo4j at {}", config.uri)))?
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to connect to Neo4j: {}", e)))?;
+
+        Self::with_executor(Arc::new(BoltExecutor { graph }), database).await
+    }
+
+    /// Use `executor` for every statement against `database` and make sure the
+    /// id constraint and index exist
+    pub async fn with_executor(executor: Arc<dyn CypherExecutor>, database: impl Into<String>) -> Result<Self> {
+        let repository = Self { executor, database: database.into() };
+        repository.initialize_schema().await?;
+        Ok(repository)
+    }
+
+    /// Name of the Neo4j database this repository reads and writes
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    async fn initialize_schema(&self) -> Result<()> {
+        self.run(
+            CypherStatement::new("CREATE CONSTRAINT concept_id IF NOT EXISTS FOR (c:Concept) REQUIRE c.id IS UNIQUE"),
+            "create concept id constraint",
+        )
+        .await?;
+        self.run(
+            CypherStatement::new("CREATE INDEX relates_to_id IF NOT EXISTS FOR ()-[r:RELATES_TO]-() ON (r.id)"),
+            "create relationship id index",
+        )
+        .await
+    }
+
+    /// Delete every concept and relationship in the database
+    pub async fn clear(&self) -> Result<()> {
+        self.run(CypherStatement::new("MATCH (c:Concept) DETACH DELETE c"), "clear concept graph").await
+    }
+
+    /// Concepts reachable from `start` within `max_depth` hops over links of at
+    /// least `min_weight`, paired with their hop distance, nearest first
+    pub async fn find_neighborhood(&self, start: Uuid, max_depth: usize, min_weight: f64) -> Result<Vec<(Uuid, usize)>> {
+        if max_depth == 0 {
+            return Ok(Vec::new());
+        }
+
+        // Variable-length bounds cannot be parameters, so the depth is formatted in
+        let cypher = format!(
+            "MATCH p = (s:Concept {{id: $start}})-[:RELATES_TO*1..{}]-(c:Concept)
+             WHERE c.id <> $start AND all(r IN relationships(p) WHERE r.weight >= $min_weight)
+             RETURN c.id AS id, min(length(p)) AS depth
+             ORDER BY depth, id",
+            max_depth
+        );
+        let rows = self
+            .fetch(
+                CypherStatement::new(cypher).param("start", start.to_string()).param("min_weight", min_weight),
+                "find concept neighborhood",
+            )
+            .await?;
+
+        rows.iter()
+            .map(|row| Ok((parse_uuid(&get::<String>(row, "id")?)?, get::<i64>(row, "depth")? as usize)))
+            .collect()
+    }
+
+    /// Fewest-hop path between two concepts over links of at least `min_weight`,
+    /// as the ordered list of concept ids from source to target
+    pub async fn find_shortest_path(
+        &self,
+        source: Uuid,
+        target: Uuid,
+        max_depth: usize,
+        min_weight: f64,
+    ) -> Result<Option<Vec<Uuid>>> {
+        if source == target {
+            return Ok(self.get_concept(source).await?.map(|_| vec![source]));
+        }
+        if max_depth == 0 {
+            return Ok(None);
+        }
+
+        let cypher = format!(
+            "MATCH (s:Concept {{id: $source}}), (t:Concept {{id: $target}})
+             MATCH p = shortestPath((s)-[:RELATES_TO*..{}]-(t))
+             WHERE all(r IN relationships(p) WHERE r.weight >= $min_weight)
+             RETURN [n IN nodes(p) | n.id] AS ids",
+            max_depth
+        );
+        let rows = self
+            .fetch(
+                CypherStatement::new(cypher)
+                    .param("source", source.to_string())
+                    .param("target", target.to_string())
+                    .param("min_weight", min_weight),
+                "find shortest concept path",
+            )
+            .await?;
+
+        match rows.first() {
+            Some(row) => get::<Vec<String>>(row, "ids")?
+                .iter()
+                .map(|id| parse_uuid(id))
+                .collect::<Result<Vec<_>>>()
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn run(&self, q: CypherStatement, action: &str) -> Result<()> {
+        self.executor.run(q).await.map_err(|e| with_action(e, action))
+    }
+
+    async fn fetch(&self, q: CypherStatement, action: &str) -> Result<Vec<Row>> {
+        self.executor.fetch(q).await.map_err(|e| with_action(e, action))
+    }
+
+    /// Run a statement that returns a single `count` column
+    async fn fetch_count(&self, q: CypherStatement, action: &str) -> Result<usize> {
+        let rows = self.fetch(q, action).await?;
+        match rows.first() {
+            Some(row) => Ok(get::<i64>(row, "count")? as usize),
+            None => Ok(0),
+        }
+    }
+
+    async fn concept_exists(&self, id: Uuid) -> Result<bool> {
+        let count = self
+            .fetch_count(
+                CypherStatement::new("MATCH (c:Concept {id: $id}) RETURN count(c) AS count").param("id", id.to_string()),
+                "check concept",
+            )
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn save_concept(&self, concept: &ConceptNode) -> Result<()> {
+        self.run(
+            CypherStatement::new("MERGE (c:Concept {id: $id}) SET c = $properties")
+                .param("id", concept.id.to_string())
+                .param("properties", concept_properties(concept)?),
+            "save concept",
+        )
+        .await
+    }
+
+    /// Replace the relationship with this id, re-attaching it if its endpoints changed
+    async fn save_relationship(&self, relationship: &ConceptRelationship) -> Result<()> {
+        self.run(
+            CypherStatement::new(
+                "MATCH (s:Concept {id: $source_id}), (t:Concept {id: $target_id})
+                 OPTIONAL MATCH ()-[old:RELATES_TO {id: $id}]->()
+                 DELETE old
+                 CREATE (s)-[r:RELATES_TO]->(t)
+                 SET r = $properties",
+            )
+            .param("id", relationship.id.to_string())
+            .param("source_id", relationship.source_id.to_string())
+            .param("target_id", relationship.target_id.to_string())
+            .param("properties", relationship_properties(relationship)?),
+            "save relationship",
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConceptRepository for Neo4jConceptRepository {
+    async fn create_concept(&mut self, mut concept: ConceptNode) -> Result<Uuid> {
+        concept.mark_accessed();
+        self.save_concept(&concept).await?;
+        Ok(concept.id)
+    }
+
+    async fn get_concept(&self, id: Uuid) -> Result<Option<ConceptNode>> {
+        let cypher = format!("MATCH (c:Concept {{id: $id}}) RETURN {}", CONCEPT_COLUMNS);
+        let rows = self.fetch(CypherStatement::new(cypher).param("id", id.to_string()), "get concept").await?;
+        rows.first().map(row_to_concept).transpose()
+    }
+
+    async fn update_concept(&mut self, concept: &ConceptNode) -> Result<()> {
+        self.save_concept(concept).await
+    }
+
+    async fn delete_concept(&mut self, id: Uuid) -> Result<bool> {
+        let removed = self
+            .fetch_count(
+                CypherStatement::new(
+                    "MATCH (c:Concept {id: $id})
+                     WITH collect(c) AS doomed
+                     FOREACH (c IN doomed | DETACH DELETE c)
+                     RETURN size(doomed) AS count",
+                )
+                .param("id", id.to_string()),
+                "delete concept",
+            )
+            .await?;
+        Ok(removed > 0)
+    }
+
+    async fn query_concepts(&self, concept_query: &ConceptQuery) -> Result<Vec<ConceptNode>> {
+        let mut conditions = Vec::new();
+        let mut params: Vec<(&str, BoltType)> = Vec::new();
+
+        if let Some(concept_type) = &concept_query.concept_type {
+            conditions.push("c.concept_type = $concept_type");
+            params.push(("concept_type", serde_json::to_string(concept_type)?.into()));
+        }
+        if let Some(min_confidence) = concept_query.min_confidence {
+            conditions.push("c.confidence_score >= $min_confidence");
+            params.push(("min_confidence", min_confidence.into()));
+        }
+        if let Some(max_confidence) = concept_query.max_confidence {
+            conditions.push("c.confidence_score <= $max_confidence");
+            params.push(("max_confidence", max_confidence.into()));
+        }
+        if let Some(pattern) = &concept_query.content_pattern {
+            conditions.push("toLower(c.content) CONTAINS $content_pattern");
+            params.push(("content_pattern", pattern.to_lowercase().into()));
+        }
+        if let Some(min_usage) = concept_query.min_usage_count {
+            conditions.push("c.usage_count >= $min_usage_count");
+            params.push(("min_usage_count", (min_usage as i64).into()));
+        }
+
+        let sort_property = match concept_query.sort_by.as_deref() {
+            Some("confidence") => Some("c.confidence_score"),
+            Some("usage_count") => Some("c.usage_count"),
+            Some("created_at") => Some("c.created_at"),
+            Some("last_accessed_at") => Some("c.last_accessed_at"),
+            _ => None,
+        };
+
+        let cypher = build_match(
+            "MATCH (c:Concept)",
+            &conditions,
+            CONCEPT_COLUMNS,
+            sort_property,
+            concept_query.descending,
+            concept_query.limit,
+        );
+        let q = params.into_iter().fold(CypherStatement::new(cypher), |q, (key, value)| q.param(key, value));
+        let rows = self.fetch(q, "query concepts").await?;
+        rows.iter().map(row_to_concept).collect()
+    }
+
+    async fn mark_concept_accessed(&mut self, id: Uuid) -> Result<bool> {
+        let updated = self
+            .fetch_count(
+                CypherStatement::new(
+                    "MATCH (c:Concept {id: $id})
+                     SET c.usage_count = c.usage_count + 1, c.last_accessed_at = $now
+                     RETURN count(c) AS count",
+                )
+                .param("id", id.to_string())
+                .param("now", format_timestamp(&Utc::now())),
+                "mark concept accessed",
+            )
+            .await?;
+        Ok(updated > 0)
+    }
+
+    async fn get_concept_count(&self) -> Result<usize> {
+        self.fetch_count(CypherStatement::new("MATCH (c:Concept) RETURN count(c) AS count"), "count concepts").await
+    }
+}
+
+#[async_trait::async_trait]
+impl RelationshipRepository for Neo4jConceptRepository {
+    async fn create_relationship(&mut self, relationship: ConceptRelationship) -> Result<Uuid> {
+        if !self.concept_exists(relationship.source_id).await? {
+            return Err(BrainError::NotFound(format!("Source concept {} not found", relationship.source_id)));
+        }
+        if !self.concept_exists(relationship.target_id).await? {
+            return Err(BrainError::NotFound(format!("Target concept {} not found", relationship.target_id)));
+        }
+
+        self.save_relationship(&relationship).await?;
+        Ok(relationship.id)
+    }
+
+    async fn get_relationship(&self, id: Uuid) -> Result<Option<ConceptRelationship>> {
+        let cypher = format!(
+            "MATCH (s:Concept)-[r:RELATES_TO {{id: $id}}]->(t:Concept) RETURN {}",
+            RELATIONSHIP_COLUMNS
+        );
+        let rows = self.fetch(CypherStatement::new(cypher).param("id", id.to_string()), "get relationship").await?;
+        rows.first().map(row_to_relationship).transpose()
+    }
+
+    async fn update_relationship(&mut self, relationship: &ConceptRelationship) -> Result<()> {
+        self.save_relationship(relationship).await
+    }
+
+    async fn delete_relationship(&mut self, id: Uuid) -> Result<bool> {
+        let removed = self
+            .fetch_count(
+                CypherStatement::new(
+                    "MATCH ()-[r:RELATES_TO {id: $id}]->()
+                     WITH collect(r) AS doomed
+                     FOREACH (r IN doomed | DELETE r)
+                     RETURN size(doomed) AS count",
+                )
+                .param("id", id.to_string()),
+                "delete relationship",
+            )
+            .await?;
+        Ok(removed > 0)
+    }
+
+    async fn query_relationships(&self, relationship_query: &RelationshipQuery) -> Result<Vec<ConceptRelationship>> {
+        let mut conditions = Vec::new();
+        let mut params: Vec<(&str, BoltType)> = Vec::new();
+
+        if let Some(source_id) = relationship_query.source_id {
+            conditions.push("s.id = $source_id");
+            params.push(("source_id", source_id.to_string().into()));
+        }
+        if let Some(target_id) = relationship_query.target_id {
+            conditions.push("t.id = $target_id");
+            params.push(("target_id", target_id.to_string().into()));
+        }
+        if let Some(relationship_type) = &relationship_query.relationship_type {
+            conditions.push("r.relationship_type = $relationship_type");
+            params.push(("relationship_type", serde_json::to_string(relationship_type)?.into()));
+        }
+        if let Some(min_weight) = relationship_query.min_weight {
+            conditions.push("r.weight >= $min_weight");
+            params.push(("min_weight", min_weight.into()));
+        }
+        if let Some(max_weight) = relationship_query.max_weight {
+            conditions.push("r.weight <= $max_weight");
+            params.push(("max_weight", max_weight.into()));
+        }
+        if let Some(min_activation) = relationship_query.min_activation_count {
+            conditions.push("r.activation_count >= $min_activation_count");
+            params.push(("min_activation_count", (min_activation as i64).into()));
+        }
+
+        let sort_property = match relationship_query.sort_by.as_deref() {
+            Some("weight") => Some("r.weight"),
+            Some("activation_count") => Some("r.activation_count"),
+            Some("created_at") => Some("r.created_at"),
+            Some("last_activated_at") => Some("r.last_activated_at"),
+            _ => None,
+        };
+
+        let cypher = build_match(
+            "MATCH (s:Concept)-[r:RELATES_TO]->(t:Concept)",
+            &conditions,
+            RELATIONSHIP_COLUMNS,
+            sort_property,
+            relationship_query.descending,
+            relationship_query.limit,
+        );
+        let q = params.into_iter().fold(CypherStatement::new(cypher), |q, (key, value)| q.param(key, value));
+        let rows = self.fetch(q, "query relationships").await?;
+        rows.iter().map(row_to_relationship).collect()
+    }
+
+    async fn get_concept_relationships(&self, concept_id: Uuid) -> Result<Vec<ConceptRelationship>> {
+        let cypher = format!(
+            "MATCH (s:Concept)-[r:RELATES_TO]->(t:Concept)
+             WHERE s.id = $id OR t.id = $id
+             RETURN {}",
+            RELATIONSHIP_COLUMNS
+        );
+        let rows = self
+            .fetch(CypherStatement::new(cypher).param("id", concept_id.to_string()), "get concept relationships")
+            .await?;
+        rows.iter().map(row_to_relationship).collect()
+    }
+
+    async fn activate_relationship(&mut self, id: Uuid) -> Result<bool> {
+        // Same Hebbian update as ConceptRelationship::activate, applied server-side
+        let updated = self
+            .fetch_count(
+                CypherStatement::new(
+                    "MATCH ()-[r:RELATES_TO {id: $id}]->()
+                     WITH r, r.weight + r.learning_rate * (1.0 - r.weight) AS learned
+                     SET r.activation_count = r.activation_count + 1,
+                         r.last_activated_at = $now,
+                         r.weight = CASE WHEN learned > 1.0 THEN 1.0 WHEN learned < 0.0 THEN 0.0 ELSE learned END
+                     RETURN count(r) AS count",
+                )
+                .param("id", id.to_string())
+                .param("now", format_timestamp(&Utc::now())),
+                "activate relationship",
+            )
+            .await?;
+        Ok(updated > 0)
+    }
+
+    async fn apply_decay_to_all(&mut self, time_delta_hours: f64) -> Result<usize> {
+        if time_delta_hours <= 0.0 {
+            return self.get_relationship_count().await;
+        }
+
+        // Same decay as ConceptRelationship::apply_decay, floored at a tenth of the base weight
+        self.fetch_count(
+            CypherStatement::new(
+                "MATCH ()-[r:RELATES_TO]->()
+                 WITH r, r.weight * exp(-r.decay_rate * $hours) AS decayed, r.base_weight * 0.1 AS floor
+                 SET r.weight = CASE WHEN decayed > floor THEN decayed ELSE floor END
+                 RETURN count(r) AS count",
+            )
+            .param("hours", time_delta_hours),
+            "apply relationship decay",
+        )
+        .await
+    }
+
+    async fn prune_weak_relationships(&mut self) -> Result<usize> {
+        self.fetch_count(
+            CypherStatement::new(
+                "MATCH ()-[r:RELATES_TO]->()
+                 WHERE r.weight < r.pruning_threshold
+                 WITH collect(r) AS doomed
+                 FOREACH (r IN doomed | DELETE r)
+                 RETURN size(doomed) AS count",
+            ),
+            "prune weak relationships",
+        )
+        .await
+    }
+
+    async fn get_relationship_count(&self) -> Result<usize> {
+        self.fetch_count(CypherStatement::new("MATCH ()-[r:RELATES_TO]->() RETURN count(r) AS count"), "count relationships")
+            .await
+    }
+}
+
+fn with_action(error: BrainError, action: &str) -> BrainError {
+    match error {
+        BrainError::DatabaseError(message) => BrainError::DatabaseError(format!("Failed to {}: {}", action, message)),
+        other => other,
+    }
+}
+
+/// Assemble a filtered, optionally sorted and limited read query
+fn build_match(
+    pattern: &str,
+    conditions: &[&str],
+    columns: &str,
+    sort_property: Option<&str>,
+    descending: bool,
+    limit: Option<usize>,
+) -> String {
+    let mut cypher = pattern.to_string();
+    if !conditions.is_empty() {
+        cypher.push_str(" WHERE ");
+        cypher.push_str(&conditions.join(" AND "));
+    }
+    cypher.push_str(" RETURN ");
+    cypher.push_str(columns);
+    if let Some(property) = sort_property {
+        cypher.push_str(&format!(" ORDER BY {}{}", property, if descending { " DESC" } else { "" }));
+    }
+    if let Some(limit) = limit {
+        cypher.push_str(&format!(" LIMIT {}", limit));
+    }
+    cypher
+}
+
+/// Fixed-width UTC timestamps so Cypher string ordering matches time ordering
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+fn concept_properties(concept: &ConceptNode) -> Result<HashMap<&'static str, BoltType>> {
+    Ok(HashMap::from([
+        ("id", concept.id.to_string().into()),
+        ("concept_type", serde_json::to_string(&concept.concept_type)?.into()),
+        ("content", concept.content.clone().into()),
+        ("description", concept.description.clone().into()),
+        ("created_at", format_timestamp(&concept.created_at).into()),
+        ("last_accessed_at", format_timestamp(&concept.last_accessed_at).into()),
+        ("usage_count", (concept.usage_count as i64).into()),
+        ("confidence_score", concept.confidence_score.into()),
+        ("source_reference", concept.source_reference.clone().into()),
+        ("metadata", serde_json::to_string(&concept.metadata)?.into()),
+    ]))
+}
+
+fn relationship_properties(relationship: &ConceptRelationship) -> Result<HashMap<&'static str, BoltType>> {
+    Ok(HashMap::from([
+        ("id", relationship.id.to_string().into()),
+        ("relationship_type", serde_json::to_string(&relationship.relationship_type)?.into()),
+        ("weight", relationship.weight.into()),
+        ("activation_count", (relationship.activation_count as i64).into()),
+        ("created_at", format_timestamp(&relationship.created_at).into()),
+        ("last_activated_at", format_timestamp(&relationship.last_activated_at).into()),
+        ("base_weight", relationship.base_weight.into()),
+        ("learning_rate", relationship.learning_rate.into()),
+        ("decay_rate", relationship.decay_rate.into()),
+        ("pruning_threshold", relationship.pruning_threshold.into()),
+        ("metadata", serde_json::to_string(&relationship.metadata)?.into()),
+    ]))
+}
+
+fn get<'r, T: serde::Deserialize<'r>>(row: &'r Row, column: &str) -> Result<T> {
+    row.get(column)
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to read column {}: {}", column, e)))
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| BrainError::ParseError(format!("Invalid UUID {}: {}", value, e)))
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|e| BrainError::ParseError(format!("Invalid timestamp {}: {}", value, e)))
+}
+
+fn row_to_concept(row: &Row) -> Result<ConceptNode> {
+    let metadata: HashMap<String, String> = serde_json::from_str(&get::<String>(row, "metadata")?)?;
+
+    Ok(ConceptNode {
+        id: parse_uuid(&get::<String>(row, "id")?)?,
+        concept_type: serde_json::from_str(&get::<String>(row, "concept_type")?)?,
+        content: get(row, "content")?,
+        description: get(row, "description")?,
+        created_at: parse_timestamp(&get::<String>(row, "created_at")?)?,
+        last_accessed_at: parse_timestamp(&get::<String>(row, "last_accessed_at")?)?,
+        usage_count: get::<i64>(row, "usage_count")? as u64,
+        confidence_score: get(row, "confidence_score")?,
+        source_reference: get(row, "source_reference")?,
+        metadata,
+    })
+}
+
+fn row_to_relationship(row: &Row) -> Result<ConceptRelationship> {
+    let metadata: HashMap<String, String> = serde_json::from_str(&get::<String>(row, "metadata")?)?;
+
+    Ok(ConceptRelationship {
+        id: parse_uuid(&get::<String>(row, "id")?)?,
+        source_id: parse_uuid(&get::<String>(row, "source_id")?)?,
+        target_id: parse_uuid(&get::<String>(row, "target_id")?)?,
+        relationship_type: serde_json::from_str(&get::<String>(row, "relationship_type")?)?,
+        weight: get(row, "weight")?,
+        activation_count: get::<i64>(row, "activation_count")? as u64,
+        created_at: parse_timestamp(&get::<String>(row, "created_at")?)?,
+        last_activated_at: parse_timestamp(&get::<String>(row, "last_activated_at")?)?,
+        base_weight: get(row, "base_weight")?,
+        learning_rate: get(row, "learning_rate")?,
+        decay_rate: get(row, "decay_rate")?,
+        pruning_threshold: get(row, "pruning_threshold")?,
+        metadata,
+    })
+}
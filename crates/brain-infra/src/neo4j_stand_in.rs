@@ -0,0 +1,481 @@
+//! In-Process Neo4j Stand-In
+//!
+//! A `CypherExecutor` that runs the statements `Neo4jConceptRepository`
+//! issues against an in-memory property graph, so the backend's Cypher,
+//! parameters and row mapping are exercised without a server. Reads are
+//! interpreted from their MATCH / WHERE / RETURN / ORDER BY / LIMIT clauses;
+//! writes and traversals are recognised by their shape. Any other statement is
+//! rejected, so a new query fails loudly until the stand-in learns it.
+
+use crate::neo4j_concepts::{CypherExecutor, CypherStatement};
+use brain_types::*;
+use neo4rs::{BoltList, BoltMap, BoltType, Row};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+type Properties = HashMap<String, BoltType>;
+
+/// Variable bindings for one match, e.g. `c` or `s`, `r` and `t`
+type Binding<'g> = HashMap<String, &'g Properties>;
+
+/// `(variable, parameter)` pairs from `{id: $parameter}` written inside a pattern
+type InlineIds<'t> = Vec<(&'t str, &'t str)>;
+
+#[derive(Debug)]
+struct StoredRelationship {
+    source: String,
+    target: String,
+    properties: Properties,
+}
+
+#[derive(Debug, Default)]
+struct PropertyGraph {
+    /// Nodes in creation order
+    nodes: Vec<Properties>,
+    /// Relationships in creation order
+    relationships: Vec<StoredRelationship>,
+}
+
+/// In-memory executor understanding the Cypher of the Neo4j concept backend
+#[derive(Debug, Default)]
+pub(crate) struct Neo4jStandIn {
+    graph: Mutex<PropertyGraph>,
+}
+
+#[async_trait::async_trait]
+impl CypherExecutor for Neo4jStandIn {
+    async fn run(&self, statement: CypherStatement) -> Result<()> {
+        self.execute(&statement).map(|_| ())
+    }
+
+    async fn fetch(&self, statement: CypherStatement) -> Result<Vec<Row>> {
+        self.execute(&statement)
+    }
+}
+
+impl Neo4jStandIn {
+    fn execute(&self, statement: &CypherStatement) -> Result<Vec<Row>> {
+        let text = statement.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let params = &statement.params;
+        let mut graph = self.graph.lock().unwrap();
+
+        if text.starts_with("CREATE CONSTRAINT") || text.starts_with("CREATE INDEX") {
+            Ok(Vec::new())
+        } else if text == "MATCH (c:Concept) DETACH DELETE c" {
+            *graph = PropertyGraph::default();
+            Ok(Vec::new())
+        } else if text == "MERGE (c:Concept {id: $id}) SET c = $properties" {
+            graph.save_node(string_param(params, "id")?, map_param(params, "properties")?);
+            Ok(Vec::new())
+        } else if text.starts_with("MATCH (s:Concept {id: $source_id}), (t:Concept {id: $target_id})")
+            && text.contains("DELETE old CREATE (s)-[r:RELATES_TO]->(t) SET r = $properties")
+        {
+            graph.save_relationship(
+                string_param(params, "id")?,
+                string_param(params, "source_id")?,
+                string_param(params, "target_id")?,
+                map_param(params, "properties")?,
+            );
+            Ok(Vec::new())
+        } else if text.contains("FOREACH (c IN doomed | DETACH DELETE c)") {
+            Ok(vec![count_row(graph.delete_node(&string_param(params, "id")?))])
+        } else if text.starts_with("MATCH ()-[r:RELATES_TO {id: $id}]->()") && text.contains("FOREACH (r IN doomed | DELETE r)") {
+            let id = string_param(params, "id")?;
+            Ok(vec![count_row(graph.remove_relationships(|r| text_property(&r.properties, "id") == Some(id.as_str())))])
+        } else if text.contains("WHERE r.weight < r.pruning_threshold") && text.contains("FOREACH (r IN doomed | DELETE r)") {
+            Ok(vec![count_row(graph.remove_relationships(|r| {
+                number_property(&r.properties, "weight") < number_property(&r.properties, "pruning_threshold")
+            }))])
+        } else if text.contains("SET c.usage_count = c.usage_count + 1, c.last_accessed_at = $now") {
+            let id = string_param(params, "id")?;
+            let now = params.get("now").cloned().unwrap_or_else(|| BoltType::from(""));
+            let updated = graph.update_nodes(&id, |node| {
+                let usage = number_property(node, "usage_count") as i64;
+                node.insert("usage_count".to_string(), (usage + 1).into());
+                node.insert("last_accessed_at".to_string(), now.clone());
+            });
+            Ok(vec![count_row(updated)])
+        } else if text.contains("r.weight + r.learning_rate * (1.0 - r.weight) AS learned") {
+            let id = string_param(params, "id")?;
+            let now = params.get("now").cloned().unwrap_or_else(|| BoltType::from(""));
+            let updated = graph.update_relationships(
+                |r| text_property(r, "id") == Some(id.as_str()),
+                |r| {
+                    let weight = number_property(r, "weight");
+                    let learned = weight + number_property(r, "learning_rate") * (1.0 - weight);
+                    let activations = number_property(r, "activation_count") as i64;
+                    r.insert("activation_count".to_string(), (activations + 1).into());
+                    r.insert("last_activated_at".to_string(), now.clone());
+                    r.insert("weight".to_string(), learned.clamp(0.0, 1.0).into());
+                },
+            );
+            Ok(vec![count_row(updated)])
+        } else if text.contains("r.weight * exp(-r.decay_rate * $hours) AS decayed, r.base_weight * 0.1 AS floor") {
+            let hours = number_param(params, "hours")?;
+            let updated = graph.update_relationships(
+                |_| true,
+                |r| {
+                    let decayed = number_property(r, "weight") * (-number_property(r, "decay_rate") * hours).exp();
+                    let floor = number_property(r, "base_weight") * 0.1;
+                    r.insert("weight".to_string(), decayed.max(floor).into());
+                },
+            );
+            Ok(vec![count_row(updated)])
+        } else if let Some(max_depth) = variable_length_bound(&text, "MATCH p = (s:Concept {id: $start})-[:RELATES_TO*1..") {
+            let start = string_param(params, "start")?;
+            let distances = graph.distances(&start, max_depth, number_param(params, "min_weight")?);
+            let mut found: Vec<(&str, usize)> = distances
+                .iter()
+                .filter(|(id, _)| **id != start.as_str())
+                .map(|(id, (depth, _))| (*id, *depth))
+                .collect();
+            found.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+            Ok(found
+                .into_iter()
+                .map(|(id, depth)| row(vec![("id", id.into()), ("depth", (depth as i64).into())]))
+                .collect())
+        } else if let Some(max_depth) = variable_length_bound(&text, "MATCH p = shortestPath((s)-[:RELATES_TO*..") {
+            let source = string_param(params, "source")?;
+            let target = string_param(params, "target")?;
+            if graph.node(&source).is_none() || graph.node(&target).is_none() {
+                return Ok(Vec::new());
+            }
+            let distances = graph.distances(&source, max_depth, number_param(params, "min_weight")?);
+            let mut path = Vec::new();
+            let mut current = target.as_str();
+            while let Some((_, previous)) = distances.get(current) {
+                path.push(BoltType::from(current));
+                match previous {
+                    Some(previous) => current = *previous,
+                    None => break,
+                }
+            }
+            if distances.contains_key(target.as_str()) {
+                path.reverse();
+                Ok(vec![row(vec![("ids", BoltType::List(BoltList { value: path }))])])
+            } else {
+                Ok(Vec::new())
+            }
+        } else {
+            graph.read(&text, params)
+        }
+    }
+}
+
+impl PropertyGraph {
+    fn node(&self, id: &str) -> Option<&Properties> {
+        self.nodes.iter().find(|node| text_property(node, "id") == Some(id))
+    }
+
+    fn save_node(&mut self, id: String, properties: Properties) {
+        match self.nodes.iter_mut().find(|node| text_property(node, "id") == Some(id.as_str())) {
+            Some(node) => *node = properties,
+            None => self.nodes.push(properties),
+        }
+    }
+
+    fn save_relationship(&mut self, id: String, source: String, target: String, properties: Properties) {
+        if self.node(&source).is_none() || self.node(&target).is_none() {
+            return;
+        }
+        self.relationships.retain(|r| text_property(&r.properties, "id") != Some(id.as_str()));
+        self.relationships.push(StoredRelationship { source, target, properties });
+    }
+
+    fn delete_node(&mut self, id: &str) -> usize {
+        let before = self.nodes.len();
+        self.nodes.retain(|node| text_property(node, "id") != Some(id));
+        let removed = before - self.nodes.len();
+        if removed > 0 {
+            self.relationships.retain(|r| r.source != id && r.target != id);
+        }
+        removed
+    }
+
+    fn remove_relationships(&mut self, doomed: impl Fn(&StoredRelationship) -> bool) -> usize {
+        let before = self.relationships.len();
+        self.relationships.retain(|r| !doomed(r));
+        before - self.relationships.len()
+    }
+
+    fn update_nodes(&mut self, id: &str, update: impl Fn(&mut Properties)) -> usize {
+        self.nodes.iter_mut().filter(|node| text_property(node, "id") == Some(id)).map(update).count()
+    }
+
+    fn update_relationships(&mut self, selected: impl Fn(&Properties) -> bool, update: impl Fn(&mut Properties)) -> usize {
+        self.relationships
+            .iter_mut()
+            .map(|r| &mut r.properties)
+            .filter(|properties| selected(properties))
+            .map(update)
+            .count()
+    }
+
+    /// Breadth-first hop distances from `start` over undirected links of at
+    /// least `min_weight`, with each concept's predecessor on a shortest path
+    fn distances<'g>(&'g self, start: &'g str, max_depth: usize, min_weight: f64) -> HashMap<&'g str, (usize, Option<&'g str>)> {
+        let mut distances = HashMap::from([(start, (0, None))]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            let depth = distances[current].0;
+            if depth == max_depth {
+                continue;
+            }
+            for relationship in &self.relationships {
+                if number_property(&relationship.properties, "weight") < min_weight {
+                    continue;
+                }
+                let next = if relationship.source == current {
+                    relationship.target.as_str()
+                } else if relationship.target == current {
+                    relationship.source.as_str()
+                } else {
+                    continue;
+                };
+                if !distances.contains_key(next) {
+                    distances.insert(next, (depth + 1, Some(current)));
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Run `MATCH <pattern> [WHERE ...] RETURN ... [ORDER BY ...] [LIMIT n]`
+    fn read(&self, text: &str, params: &HashMap<String, BoltType>) -> Result<Vec<Row>> {
+        let unsupported = || BrainError::DatabaseError(format!("Neo4j stand-in cannot run: {}", text));
+
+        let body = text.strip_prefix("MATCH ").ok_or_else(unsupported)?;
+        let (matching, projection) = body.split_once(" RETURN ").ok_or_else(unsupported)?;
+        let (pattern, conditions) = match matching.split_once(" WHERE ") {
+            Some((pattern, conditions)) => (pattern, Some(conditions)),
+            None => (matching, None),
+        };
+        let (projection, limit) = match projection.split_once(" LIMIT ") {
+            Some((projection, limit)) => (projection, Some(limit.parse::<usize>().map_err(|_| unsupported())?)),
+            None => (projection, None),
+        };
+        let (columns, order) = match projection.split_once(" ORDER BY ") {
+            Some((columns, order)) => (columns, Some(order)),
+            None => (projection, None),
+        };
+
+        let (mut bindings, inline_ids) = self.bind(pattern).ok_or_else(unsupported)?;
+        for (variable, param) in inline_ids {
+            let id = string_param(params, param)?;
+            bindings.retain(|binding| text_property(binding[variable], "id") == Some(id.as_str()));
+        }
+        if let Some(conditions) = conditions {
+            let mut kept = Vec::new();
+            for binding in bindings {
+                if matches_conditions(conditions, &binding, params).ok_or_else(unsupported)? {
+                    kept.push(binding);
+                }
+            }
+            bindings = kept;
+        }
+
+        if let Some(counted) = columns.strip_prefix("count(") {
+            let (_, alias) = counted.split_once(" AS ").ok_or_else(unsupported)?;
+            return Ok(vec![row(vec![(alias, (bindings.len() as i64).into())])]);
+        }
+
+        if let Some(order) = order {
+            let (expression, descending) = match order.strip_suffix(" DESC") {
+                Some(expression) => (expression, true),
+                None => (order, false),
+            };
+            let mut keyed = Vec::new();
+            for binding in bindings {
+                keyed.push((evaluate(expression, &binding, params).ok_or_else(unsupported)?, binding));
+            }
+            keyed.sort_by(|a, b| {
+                let ordering = compare(&a.0, &b.0).unwrap_or(Ordering::Equal);
+                if descending { ordering.reverse() } else { ordering }
+            });
+            bindings = keyed.into_iter().map(|(_, binding)| binding).collect();
+        }
+        if let Some(limit) = limit {
+            bindings.truncate(limit);
+        }
+
+        let mut rows = Vec::new();
+        for binding in &bindings {
+            let mut values = Vec::new();
+            for column in columns.split(", ") {
+                let (expression, alias) = column.split_once(" AS ").ok_or_else(unsupported)?;
+                values.push((alias, evaluate(expression, binding, params).ok_or_else(unsupported)?));
+            }
+            rows.push(row(values));
+        }
+        Ok(rows)
+    }
+
+    /// Bindings for a node or directed relationship pattern, plus the
+    /// `{id: $param}` constraints written inline in it
+    fn bind<'g, 't>(&'g self, pattern: &'t str) -> Option<(Vec<Binding<'g>>, InlineIds<'t>)> {
+        let mut inline_ids = Vec::new();
+
+        if let Some((source, rest)) = pattern.split_once(")-[") {
+            let (relationship, target) = rest.split_once("]->(")?;
+            let source = element(source.strip_prefix('(')?, &mut inline_ids);
+            let relationship = element(relationship, &mut inline_ids);
+            let target = element(target.strip_suffix(')')?, &mut inline_ids);
+
+            let mut bindings = Vec::new();
+            for stored in &self.relationships {
+                let mut binding = Binding::new();
+                if let Some(variable) = relationship {
+                    binding.insert(variable.to_string(), &stored.properties);
+                }
+                if let Some(variable) = source {
+                    binding.insert(variable.to_string(), self.node(&stored.source)?);
+                }
+                if let Some(variable) = target {
+                    binding.insert(variable.to_string(), self.node(&stored.target)?);
+                }
+                bindings.push(binding);
+            }
+            Some((bindings, inline_ids))
+        } else {
+            let node = element(pattern.strip_prefix('(')?.strip_suffix(')')?, &mut inline_ids)?;
+            let bindings = self.nodes.iter().map(|properties| Binding::from([(node.to_string(), properties)])).collect();
+            Some((bindings, inline_ids))
+        }
+    }
+}
+
+/// Variable of a pattern element such as `c:Concept {id: $id}`, recording its inline id constraint
+fn element<'t>(element: &'t str, inline_ids: &mut InlineIds<'t>) -> Option<&'t str> {
+    let variable = element.split([':', ' ']).next().filter(|variable| !variable.is_empty());
+    if let (Some(variable), Some((_, param))) = (variable, element.split_once("{id: $")) {
+        inline_ids.push((variable, param.trim_end_matches('}')));
+    }
+    variable
+}
+
+/// Evaluate `a AND b AND (c OR d)`-style conditions written without parentheses
+fn matches_conditions(conditions: &str, binding: &Binding, params: &HashMap<String, BoltType>) -> Option<bool> {
+    for clause in conditions.split(" AND ") {
+        let mut any = false;
+        for atom in clause.split(" OR ") {
+            any |= matches_atom(atom, binding, params)?;
+        }
+        if !any {
+            return Some(false);
+        }
+    }
+    Some(true)
+}
+
+fn matches_atom(atom: &str, binding: &Binding, params: &HashMap<String, BoltType>) -> Option<bool> {
+    for operator in [" >= ", " <= ", " <> ", " = ", " CONTAINS "] {
+        if let Some((left, right)) = atom.split_once(operator) {
+            let left = evaluate(left, binding, params)?;
+            let right = evaluate(right, binding, params)?;
+            return Some(match operator {
+                " CONTAINS " => match (&left, &right) {
+                    (BoltType::String(haystack), BoltType::String(needle)) => haystack.value.contains(&needle.value),
+                    _ => false,
+                },
+                _ => match compare(&left, &right) {
+                    Some(ordering) => match operator {
+                        " >= " => ordering != Ordering::Less,
+                        " <= " => ordering != Ordering::Greater,
+                        " <> " => ordering != Ordering::Equal,
+                        _ => ordering == Ordering::Equal,
+                    },
+                    None => false,
+                },
+            });
+        }
+    }
+    None
+}
+
+/// Evaluate `$param`, `var.property` or `toLower(...)`
+fn evaluate(expression: &str, binding: &Binding, params: &HashMap<String, BoltType>) -> Option<BoltType> {
+    if let Some(param) = expression.strip_prefix('$') {
+        return params.get(param).cloned();
+    }
+    if let Some(inner) = expression.strip_prefix("toLower(").and_then(|inner| inner.strip_suffix(')')) {
+        return match evaluate(inner, binding, params)? {
+            BoltType::String(value) => Some(value.value.to_lowercase().into()),
+            other => Some(other),
+        };
+    }
+    let (variable, property) = expression.split_once('.')?;
+    let properties = binding.get(variable)?;
+    Some(properties.get(property).cloned().unwrap_or(BoltType::Null(Default::default())))
+}
+
+/// Cypher comparison of numbers and strings; anything involving null is unordered
+fn compare(left: &BoltType, right: &BoltType) -> Option<Ordering> {
+    match (left, right) {
+        (BoltType::String(a), BoltType::String(b)) => Some(a.value.cmp(&b.value)),
+        _ => number(left)?.partial_cmp(&number(right)?),
+    }
+}
+
+fn number(value: &BoltType) -> Option<f64> {
+    match value {
+        BoltType::Integer(integer) => Some(integer.value as f64),
+        BoltType::Float(float) => Some(float.value),
+        _ => None,
+    }
+}
+
+fn number_property(properties: &Properties, key: &str) -> f64 {
+    properties.get(key).and_then(number).unwrap_or(0.0)
+}
+
+fn text_property<'p>(properties: &'p Properties, key: &str) -> Option<&'p str> {
+    match properties.get(key) {
+        Some(BoltType::String(value)) => Some(value.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Upper bound of a `*1..N` / `*..N` hop range following `prefix`
+fn variable_length_bound(text: &str, prefix: &str) -> Option<usize> {
+    let start = text.find(prefix)? + prefix.len();
+    text[start..].split(']').next()?.parse().ok()
+}
+
+fn missing_param(key: &str) -> BrainError {
+    BrainError::DatabaseError(format!("Neo4j stand-in expected parameter ${}", key))
+}
+
+fn string_param(params: &HashMap<String, BoltType>, key: &str) -> Result<String> {
+    match params.get(key) {
+        Some(BoltType::String(value)) => Ok(value.value.clone()),
+        _ => Err(missing_param(key)),
+    }
+}
+
+fn number_param(params: &HashMap<String, BoltType>, key: &str) -> Result<f64> {
+    params.get(key).and_then(number).ok_or_else(|| missing_param(key))
+}
+
+fn map_param(params: &HashMap<String, BoltType>, key: &str) -> Result<Properties> {
+    match params.get(key) {
+        // Like Neo4j, `SET n = $map` drops null entries instead of storing them
+        Some(BoltType::Map(BoltMap { value })) => Ok(value
+            .iter()
+            .filter(|(_, value)| !matches!(value, BoltType::Null(_)))
+            .map(|(key, value)| (key.value.clone(), value.clone()))
+            .collect()),
+        _ => Err(missing_param(key)),
+    }
+}
+
+fn row(columns: Vec<(&str, BoltType)>) -> Row {
+    let (fields, values): (Vec<BoltType>, Vec<BoltType>) =
+        columns.into_iter().map(|(field, value)| (BoltType::from(field), value)).unzip();
+    Row::new(BoltList { value: fields }, BoltList { value: values })
+}
+
+fn count_row(count: usize) -> Row {
+    row(vec![("count", (count as i64).into())])
+}
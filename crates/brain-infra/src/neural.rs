@@ -9,6 +9,7 @@
 use brain_core::*;
 use brain_types::*;
 use nalgebra::{DMatrix, DVector};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -26,6 +27,8 @@ pub struct SelfAttentionImpl {
     w_output: DMatrix<f64>,
     /// Attention weights (cached for analysis)
     attention_weights: Option<DMatrix<f64>>,
+    /// Whether each position may only attend to itself and earlier positions
+    causal: bool,
 }
 
 impl SelfAttentionImpl {
@@ -46,8 +49,15 @@ impl SelfAttentionImpl {
             w_value,
             w_output,
             attention_weights: None,
+            causal: false,
         })
     }
+
+    /// Restrict attention to current and earlier positions, as next-token
+    /// prediction requires
+    pub fn set_causal(&mut self, causal: bool) {
+        self.causal = causal;
+    }
     
     /// Xavier weight initialization
     fn xavier_init(rows: usize, cols: usize) -> Result<DMatrix<f64>> {
@@ -71,16 +81,7 @@ impl SelfAttentionImpl {
     
     /// Compute attention scores using scaled dot-product
     fn compute_attention_scores(&mut self, queries: &DMatrix<f64>, keys: &DMatrix<f64>) -> Result<DMatrix<f64>> {
-        // Compute Q * K^T
-        let scores = queries * keys.transpose();
-        
-        // Apply scaling if enabled
-        let scaled_scores = if self.config.use_scaling {
-            let scale = 1.0 / (self.config.head_dim as f64).sqrt();
-            &scores * scale
-        } else {
-            scores
-        };
+        let scaled_scores = self.masked_scores(queries, keys);
         
         // Apply softmax to get attention weights
         let attention_weights = self.softmax(&scaled_scores)?;
@@ -91,6 +92,31 @@ impl SelfAttentionImpl {
         Ok(attention_weights)
     }
     
+    /// Scaled Q * K^T with future positions masked out when causal
+    fn masked_scores(&self, queries: &DMatrix<f64>, keys: &DMatrix<f64>) -> DMatrix<f64> {
+        let mut scores = queries * keys.transpose();
+        scores *= self.score_scale();
+        
+        if self.causal {
+            for i in 0..scores.nrows() {
+                for j in (i + 1)..scores.ncols() {
+                    scores[(i, j)] = f64::NEG_INFINITY;
+                }
+            }
+        }
+        
+        scores
+    }
+    
+    /// Factor applied to the raw attention scores
+    fn score_scale(&self) -> f64 {
+        if self.config.use_scaling {
+            1.0 / (self.config.head_dim as f64).sqrt()
+        } else {
+            1.0
+        }
+    }
+    
     /// Apply softmax function
     fn softmax(&self, input: &DMatrix<f64>) -> Result<DMatrix<f64>> {
         let (rows, cols) = input.shape();
@@ -341,6 +367,11 @@ impl TransformerEncoderImpl {
             _dropout_rate: config.dropout_rate,
         })
     }
+    
+    /// Make the self-attention layer causal
+    pub fn set_causal(&mut self, causal: bool) {
+        self.self_attention.set_causal(causal);
+    }
 }
 
 #[async_trait::async_trait]
@@ -375,6 +406,12 @@ pub struct TransformerPredictorImpl {
     config: TransformerConfig,
     /// Vocabulary size
     vocab_size: usize,
+    /// Token strings indexed by token id, carried into checkpoints
+    vocab: Vec<String>,
+    /// Optimizer settings used by training
+    training_config: TransformerTrainingConfig,
+    /// Adam state, created on the first training step
+    optimizer: Option<AdamOptimizer>,
 }
 
 impl TransformerPredictorImpl {
@@ -398,7 +435,9 @@ impl TransformerPredictorImpl {
                 dropout_rate: config.dropout_rate,
                 use_scaling: true,
             };
-            encoders.push(TransformerEncoderImpl::new(attention_config, config.ff_hidden_dim)?);
+            let mut encoder = TransformerEncoderImpl::new(attention_config, config.ff_hidden_dim)?;
+            encoder.set_causal(config.causal);
+            encoders.push(encoder);
         }
         
         // Output projection
@@ -411,6 +450,9 @@ impl TransformerPredictorImpl {
             output_projection,
             config,
             vocab_size,
+            vocab: Vec::new(),
+            training_config: TransformerTrainingConfig::default(),
+            optimizer: None,
         })
     }
     
@@ -470,6 +512,426 @@ impl TransformerPredictorImpl {
 impl TransformerPredictorService for TransformerPredictorImpl {
     /// Forward pass with input token IDs
     async fn forward(&mut self, input_ids: &[usize]) -> Result<DMatrix<f64>> {
+        // Create input embeddings with positional encoding
+        let input_embeddings = self.embed(input_ids)?;
+        
+        // Pass through encoder layers
+        let mut hidden_states = input_embeddings;
+        for encoder in &mut self.encoders {
+            hidden_states = encoder.forward(&hidden_states).await?;
+        }
+        
+        Ok(hidden_states)
+    }
+    
+    /// Predict next token probabilities
+    async fn predict_next(&mut self, input_ids: &[usize]) -> Result<DVector<f64>> {
+        let hidden_states = self.forward(input_ids).await?;
+        
+        // Use the last position for prediction
+        let last_hidden = hidden_states.row(hidden_states.nrows() - 1);
+        
+        // Apply output projection
+        let mut logits = DVector::zeros(self.vocab_size);
+        for i in 0..self.vocab_size {
+            for j in 0..self.config.model_dim {
+                logits[i] += last_hidden[j] * self.output_projection[(j, i)];
+            }
+        }
+        
+        // Apply softmax to get probabilities
+        self.softmax(&logits)
+    }
+    
+    /// Get attention maps from all layers
+    async fn get_attention_maps(&self) -> Vec<Option<DMatrix<f64>>> {
+        self.encoders.iter()
+            .map(|encoder| encoder.self_attention.attention_weights.clone())
+            .collect()
+    }
+}
+
+/// Activations cached by a self-attention forward pass for backpropagation
+struct AttentionCache {
+    input: DMatrix<f64>,
+    queries: DMatrix<f64>,
+    keys: DMatrix<f64>,
+    values: DMatrix<f64>,
+    attention: DMatrix<f64>,
+    context: DMatrix<f64>,
+}
+
+/// Weight gradients of a self-attention layer
+struct AttentionGradients {
+    w_query: DMatrix<f64>,
+    w_key: DMatrix<f64>,
+    w_value: DMatrix<f64>,
+    w_output: DMatrix<f64>,
+}
+
+impl SelfAttentionImpl {
+    /// Forward pass that keeps the intermediate activations
+    fn forward_cached(&self, input: &DMatrix<f64>) -> Result<(DMatrix<f64>, AttentionCache)> {
+        if input.ncols() != self.config.model_dim {
+            return Err(BrainError::InvalidInput(
+                format!("Input dimension {} doesn't match model dimension {}",
+                       input.ncols(), self.config.model_dim)
+            ));
+        }
+        
+        let queries = input * &self.w_query;
+        let keys = input * &self.w_key;
+        let values = input * &self.w_value;
+        let attention = self.softmax(&self.masked_scores(&queries, &keys))?;
+        let context = &attention * &values;
+        let output = &context * &self.w_output;
+        
+        Ok((output, AttentionCache {
+            input: input.clone(),
+            queries,
+            keys,
+            values,
+            attention,
+            context,
+        }))
+    }
+    
+    /// Backward pass returning the input gradient and the weight gradients
+    fn backward(&self, cache: &AttentionCache, grad_output: &DMatrix<f64>) -> (DMatrix<f64>, AttentionGradients) {
+        let w_output = cache.context.transpose() * grad_output;
+        let grad_context = grad_output * self.w_output.transpose();
+        
+        let grad_attention = &grad_context * cache.values.transpose();
+        let grad_values = cache.attention.transpose() * &grad_context;
+        
+        // Softmax backward per row: dS = A * (dA - sum(dA * A)); masked entries have A = 0
+        let mut grad_scores = DMatrix::zeros(cache.attention.nrows(), cache.attention.ncols());
+        for i in 0..cache.attention.nrows() {
+            let dot: f64 = (0..cache.attention.ncols())
+                .map(|j| grad_attention[(i, j)] * cache.attention[(i, j)])
+                .sum();
+            for j in 0..cache.attention.ncols() {
+                grad_scores[(i, j)] = cache.attention[(i, j)] * (grad_attention[(i, j)] - dot);
+            }
+        }
+        grad_scores *= self.score_scale();
+        
+        let grad_queries = &grad_scores * &cache.keys;
+        let grad_keys = grad_scores.transpose() * &cache.queries;
+        
+        let input_t = cache.input.transpose();
+        let gradients = AttentionGradients {
+            w_query: &input_t * &grad_queries,
+            w_key: &input_t * &grad_keys,
+            w_value: &input_t * &grad_values,
+            w_output,
+        };
+        
+        let grad_input = &grad_queries * self.w_query.transpose()
+            + &grad_keys * self.w_key.transpose()
+            + &grad_values * self.w_value.transpose();
+        
+        (grad_input, gradients)
+    }
+}
+
+/// Activations cached by a feed-forward pass for backpropagation
+struct FeedForwardCache {
+    input: DMatrix<f64>,
+    hidden: DMatrix<f64>,
+    activated: DMatrix<f64>,
+}
+
+/// Parameter gradients of a feed-forward network
+struct FeedForwardGradients {
+    linear1: DMatrix<f64>,
+    bias1: DVector<f64>,
+    linear2: DMatrix<f64>,
+    bias2: DVector<f64>,
+}
+
+impl FeedForwardNetworkImpl {
+    /// Forward pass that keeps the intermediate activations
+    fn forward_cached(&self, input: &DMatrix<f64>) -> Result<(DMatrix<f64>, FeedForwardCache)> {
+        let mut hidden = input * &self.linear1;
+        add_row_bias(&mut hidden, &self.bias1);
+        
+        let activated = self.relu(&hidden)?;
+        
+        let mut output = &activated * &self.linear2;
+        add_row_bias(&mut output, &self.bias2);
+        
+        Ok((output, FeedForwardCache {
+            input: input.clone(),
+            hidden,
+            activated,
+        }))
+    }
+    
+    /// Backward pass returning the input gradient and the parameter gradients
+    fn backward(&self, cache: &FeedForwardCache, grad_output: &DMatrix<f64>) -> (DMatrix<f64>, FeedForwardGradients) {
+        let linear2 = cache.activated.transpose() * grad_output;
+        let bias2 = grad_output.row_sum().transpose();
+        
+        let mut grad_hidden = grad_output * self.linear2.transpose();
+        for i in 0..grad_hidden.nrows() {
+            for j in 0..grad_hidden.ncols() {
+                if cache.hidden[(i, j)] <= 0.0 {
+                    grad_hidden[(i, j)] = 0.0;
+                }
+            }
+        }
+        
+        let linear1 = cache.input.transpose() * &grad_hidden;
+        let bias1 = grad_hidden.row_sum().transpose();
+        let grad_input = &grad_hidden * self.linear1.transpose();
+        
+        (grad_input, FeedForwardGradients { linear1, bias1, linear2, bias2 })
+    }
+}
+
+/// Activations cached by a layer normalization pass for backpropagation
+struct LayerNormCache {
+    normalized: DMatrix<f64>,
+    std_devs: Vec<f64>,
+}
+
+impl LayerNormImpl {
+    /// Forward pass that keeps the normalized rows and their standard deviations
+    fn forward_cached(&self, input: &DMatrix<f64>) -> (DMatrix<f64>, LayerNormCache) {
+        let (rows, cols) = input.shape();
+        let mut output = DMatrix::zeros(rows, cols);
+        let mut normalized = DMatrix::zeros(rows, cols);
+        let mut std_devs = Vec::with_capacity(rows);
+        
+        for i in 0..rows {
+            let row = input.row(i);
+            let mean = row.mean();
+            let variance = row.map(|x| (x - mean).powi(2)).mean();
+            let std_dev = (variance + self.epsilon).sqrt();
+            
+            for j in 0..cols {
+                normalized[(i, j)] = (row[j] - mean) / std_dev;
+                output[(i, j)] = self.gamma[j] * normalized[(i, j)] + self.beta[j];
+            }
+            std_devs.push(std_dev);
+        }
+        
+        (output, LayerNormCache { normalized, std_devs })
+    }
+    
+    /// Backward pass returning the input, gamma and beta gradients
+    fn backward(&self, cache: &LayerNormCache, grad_output: &DMatrix<f64>) -> (DMatrix<f64>, DVector<f64>, DVector<f64>) {
+        let (rows, cols) = grad_output.shape();
+        let mut grad_input = DMatrix::zeros(rows, cols);
+        let mut grad_gamma = DVector::zeros(cols);
+        let grad_beta = grad_output.row_sum().transpose();
+        
+        for i in 0..rows {
+            let mut mean_grad = 0.0;
+            let mut mean_grad_normalized = 0.0;
+            for j in 0..cols {
+                let grad_normalized = grad_output[(i, j)] * self.gamma[j];
+                mean_grad += grad_normalized;
+                mean_grad_normalized += grad_normalized * cache.normalized[(i, j)];
+                grad_gamma[j] += grad_output[(i, j)] * cache.normalized[(i, j)];
+            }
+            mean_grad /= cols as f64;
+            mean_grad_normalized /= cols as f64;
+            
+            for j in 0..cols {
+                let grad_normalized = grad_output[(i, j)] * self.gamma[j];
+                grad_input[(i, j)] = (grad_normalized - mean_grad - cache.normalized[(i, j)] * mean_grad_normalized)
+                    / cache.std_devs[i];
+            }
+        }
+        
+        (grad_input, grad_gamma, grad_beta)
+    }
+}
+
+/// Activations cached by an encoder layer for backpropagation
+struct EncoderCache {
+    attention: AttentionCache,
+    norm1: LayerNormCache,
+    feed_forward: FeedForwardCache,
+    norm2: LayerNormCache,
+}
+
+/// Parameter gradients of an encoder layer
+struct EncoderGradients {
+    attention: AttentionGradients,
+    feed_forward: FeedForwardGradients,
+    gamma1: DVector<f64>,
+    beta1: DVector<f64>,
+    gamma2: DVector<f64>,
+    beta2: DVector<f64>,
+}
+
+impl EncoderGradients {
+    /// Flatten in the same order as `TransformerEncoderImpl::parameters_mut`
+    fn into_buffers(self) -> Vec<Vec<f64>> {
+        vec![
+            self.attention.w_query.as_slice().to_vec(),
+            self.attention.w_key.as_slice().to_vec(),
+            self.attention.w_value.as_slice().to_vec(),
+            self.attention.w_output.as_slice().to_vec(),
+            self.feed_forward.linear1.as_slice().to_vec(),
+            self.feed_forward.bias1.as_slice().to_vec(),
+            self.feed_forward.linear2.as_slice().to_vec(),
+            self.feed_forward.bias2.as_slice().to_vec(),
+            self.gamma1.as_slice().to_vec(),
+            self.beta1.as_slice().to_vec(),
+            self.gamma2.as_slice().to_vec(),
+            self.beta2.as_slice().to_vec(),
+        ]
+    }
+}
+
+impl TransformerEncoderImpl {
+    /// Forward pass that keeps the intermediate activations
+    fn forward_cached(&self, input: &DMatrix<f64>) -> Result<(DMatrix<f64>, EncoderCache)> {
+        let (attention_output, attention) = self.self_attention.forward_cached(input)?;
+        let (norm1_output, norm1) = self.layer_norm1.forward_cached(&(input + attention_output));
+        
+        let (ff_output, feed_forward) = self.feed_forward.forward_cached(&norm1_output)?;
+        let (norm2_output, norm2) = self.layer_norm2.forward_cached(&(&norm1_output + ff_output));
+        
+        Ok((norm2_output, EncoderCache { attention, norm1, feed_forward, norm2 }))
+    }
+    
+    /// Backward pass through both residual blocks
+    fn backward(&self, cache: &EncoderCache, grad_output: &DMatrix<f64>) -> (DMatrix<f64>, EncoderGradients) {
+        let (grad_residual2, gamma2, beta2) = self.layer_norm2.backward(&cache.norm2, grad_output);
+        let (grad_ff_input, feed_forward) = self.feed_forward.backward(&cache.feed_forward, &grad_residual2);
+        let grad_norm1 = grad_residual2 + grad_ff_input;
+        
+        let (grad_residual1, gamma1, beta1) = self.layer_norm1.backward(&cache.norm1, &grad_norm1);
+        let (grad_attention_input, attention) = self.self_attention.backward(&cache.attention, &grad_residual1);
+        let grad_input = grad_residual1 + grad_attention_input;
+        
+        (grad_input, EncoderGradients { attention, feed_forward, gamma1, beta1, gamma2, beta2 })
+    }
+    
+    /// Mutable views of every trainable parameter
+    fn parameters_mut(&mut self) -> Vec<&mut [f64]> {
+        vec![
+            self.self_attention.w_query.as_mut_slice(),
+            self.self_attention.w_key.as_mut_slice(),
+            self.self_attention.w_value.as_mut_slice(),
+            self.self_attention.w_output.as_mut_slice(),
+            self.feed_forward.linear1.as_mut_slice(),
+            self.feed_forward.bias1.as_mut_slice(),
+            self.feed_forward.linear2.as_mut_slice(),
+            self.feed_forward.bias2.as_mut_slice(),
+            self.layer_norm1.gamma.as_mut_slice(),
+            self.layer_norm1.beta.as_mut_slice(),
+            self.layer_norm2.gamma.as_mut_slice(),
+            self.layer_norm2.beta.as_mut_slice(),
+        ]
+    }
+}
+
+/// Add a bias vector to every row of a matrix
+fn add_row_bias(matrix: &mut DMatrix<f64>, bias: &DVector<f64>) {
+    for i in 0..matrix.nrows() {
+        for j in 0..matrix.ncols() {
+            matrix[(i, j)] += bias[j];
+        }
+    }
+}
+
+/// Adam optimizer with global gradient-norm clipping over flattened parameter buffers
+pub struct AdamOptimizer {
+    /// Optimizer hyperparameters
+    config: TransformerTrainingConfig,
+    /// Number of updates applied so far
+    step: i32,
+    /// First moment estimates, one buffer per parameter
+    first_moments: Vec<Vec<f64>>,
+    /// Second moment estimates, one buffer per parameter
+    second_moments: Vec<Vec<f64>>,
+}
+
+impl AdamOptimizer {
+    /// Create new optimizer
+    pub fn new(config: TransformerTrainingConfig) -> Self {
+        Self {
+            config,
+            step: 0,
+            first_moments: Vec::new(),
+            second_moments: Vec::new(),
+        }
+    }
+    
+    /// Clip the gradients and apply one Adam update, returning the unclipped gradient norm
+    pub fn step(&mut self, parameters: Vec<&mut [f64]>, gradients: &[Vec<f64>]) -> Result<f64> {
+        if parameters.len() != gradients.len() {
+            return Err(BrainError::InvalidInput(
+                format!("Got {} gradient buffers for {} parameters", gradients.len(), parameters.len())
+            ));
+        }
+        for (param, grad) in parameters.iter().zip(gradients) {
+            if param.len() != grad.len() {
+                return Err(BrainError::InvalidInput(
+                    format!("Gradient of length {} doesn't match parameter of length {}", grad.len(), param.len())
+                ));
+            }
+        }
+        
+        if self.first_moments.is_empty() {
+            self.first_moments = gradients.iter().map(|g| vec![0.0; g.len()]).collect();
+            self.second_moments = self.first_moments.clone();
+        }
+        
+        let grad_norm = gradients.iter()
+            .flat_map(|g| g.iter())
+            .map(|g| g * g)
+            .sum::<f64>()
+            .sqrt();
+        let clip = if grad_norm > self.config.max_grad_norm {
+            self.config.max_grad_norm / grad_norm
+        } else {
+            1.0
+        };
+        
+        self.step += 1;
+        let bias_correction1 = 1.0 - self.config.beta1.powi(self.step);
+        let bias_correction2 = 1.0 - self.config.beta2.powi(self.step);
+        
+        for (k, (param, grad)) in parameters.into_iter().zip(gradients).enumerate() {
+            let m = &mut self.first_moments[k];
+            let v = &mut self.second_moments[k];
+            
+            for i in 0..param.len() {
+                let g = grad[i] * clip;
+                m[i] = self.config.beta1 * m[i] + (1.0 - self.config.beta1) * g;
+                v[i] = self.config.beta2 * v[i] + (1.0 - self.config.beta2) * g * g;
+                
+                let m_hat = m[i] / bias_correction1;
+                let v_hat = v[i] / bias_correction2;
+                param[i] -= self.config.learning_rate * m_hat / (v_hat.sqrt() + self.config.epsilon);
+            }
+        }
+        
+        Ok(grad_norm)
+    }
+}
+
+impl TransformerPredictorImpl {
+    /// Replace the optimizer settings, resetting any accumulated Adam state
+    pub fn set_training_config(&mut self, config: TransformerTrainingConfig) {
+        self.training_config = config;
+        self.optimizer = None;
+    }
+    
+    /// Current optimizer settings
+    pub fn training_config(&self) -> &TransformerTrainingConfig {
+        &self.training_config
+    }
+    
+    /// Look up token embeddings and add positional encoding
+    fn embed(&self, input_ids: &[usize]) -> Result<DMatrix<f64>> {
         if input_ids.is_empty() {
             return Err(BrainError::InvalidInput("Input sequence cannot be empty".to_string()));
         }
@@ -481,7 +943,6 @@ impl TransformerPredictorService for TransformerPredictorImpl {
             ));
         }
         
-        // Create input embeddings
         let mut input_embeddings = DMatrix::zeros(seq_len, self.config.model_dim);
         for (i, &token_id) in input_ids.iter().enumerate() {
             if token_id >= self.vocab_size {
@@ -491,50 +952,463 @@ impl TransformerPredictorService for TransformerPredictorImpl {
             }
             
             for j in 0..self.config.model_dim {
-                input_embeddings[(i, j)] = self.embedding[(token_id, j)];
+                input_embeddings[(i, j)] = self.embedding[(token_id, j)] + self.positional_encoding[(i, j)];
             }
         }
         
-        // Add positional encoding
-        for i in 0..seq_len {
+        Ok(input_embeddings)
+    }
+    
+    /// Split a sequence into model inputs and next-token targets
+    ///
+    /// Every position is scored against the token after it, so without causal
+    /// attention the model could read its targets from the input.
+    fn split_targets<'a>(&self, token_ids: &'a [usize]) -> Result<(&'a [usize], &'a [usize])> {
+        if !self.config.causal {
+            return Err(BrainError::ConfigError(
+                "Training needs causal attention; set TransformerConfig::causal".to_string()
+            ));
+        }
+        if token_ids.len() < 2 {
+            return Err(BrainError::InvalidInput(
+                "Training sequence needs at least two tokens".to_string()
+            ));
+        }
+        if let Some(&token_id) = token_ids.iter().find(|&&id| id >= self.vocab_size) {
+            return Err(BrainError::InvalidInput(
+                format!("Token ID {} exceeds vocabulary size {}", token_id, self.vocab_size)
+            ));
+        }
+        
+        Ok((&token_ids[..token_ids.len() - 1], &token_ids[1..]))
+    }
+    
+    /// Mean cross-entropy of the logits against the targets, with its gradient
+    fn cross_entropy(&self, logits: &DMatrix<f64>, targets: &[usize]) -> Result<(f64, DMatrix<f64>)> {
+        let seq_len = targets.len() as f64;
+        let mut loss = 0.0;
+        let mut grad_logits = DMatrix::zeros(logits.nrows(), logits.ncols());
+        
+        for (i, &target) in targets.iter().enumerate() {
+            let probs = self.softmax(&logits.row(i).transpose())?;
+            loss -= probs[target].max(f64::MIN_POSITIVE).ln();
+            
+            for j in 0..probs.len() {
+                grad_logits[(i, j)] = probs[j] / seq_len;
+            }
+            grad_logits[(i, target)] -= 1.0 / seq_len;
+        }
+        
+        Ok((loss / seq_len, grad_logits))
+    }
+    
+    /// Mean next-token cross-entropy of a sequence without updating the weights
+    pub fn sequence_loss(&self, token_ids: &[usize]) -> Result<f64> {
+        let (inputs, targets) = self.split_targets(token_ids)?;
+        
+        let mut hidden_states = self.embed(inputs)?;
+        for encoder in &self.encoders {
+            hidden_states = encoder.forward_cached(&hidden_states)?.0;
+        }
+        
+        let logits = &hidden_states * &self.output_projection;
+        Ok(self.cross_entropy(&logits, targets)?.0)
+    }
+    
+    /// Loss and gradients for one sequence, in `parameters_mut` order
+    fn compute_gradients(&self, token_ids: &[usize]) -> Result<(f64, Vec<Vec<f64>>)> {
+        let (inputs, targets) = self.split_targets(token_ids)?;
+        
+        let mut hidden_states = self.embed(inputs)?;
+        let mut caches = Vec::with_capacity(self.encoders.len());
+        for encoder in &self.encoders {
+            let (output, cache) = encoder.forward_cached(&hidden_states)?;
+            hidden_states = output;
+            caches.push(cache);
+        }
+        
+        let logits = &hidden_states * &self.output_projection;
+        let (loss, grad_logits) = self.cross_entropy(&logits, targets)?;
+        
+        let grad_output_projection = hidden_states.transpose() * &grad_logits;
+        let mut grad_hidden = &grad_logits * self.output_projection.transpose();
+        
+        let mut encoder_gradients = Vec::with_capacity(self.encoders.len());
+        for (encoder, cache) in self.encoders.iter().zip(&caches).rev() {
+            let (grad_input, gradients) = encoder.backward(cache, &grad_hidden);
+            grad_hidden = grad_input;
+            encoder_gradients.push(gradients);
+        }
+        encoder_gradients.reverse();
+        
+        // Positional encoding is fixed, so the whole input gradient goes to the embeddings
+        let mut grad_embedding = DMatrix::zeros(self.vocab_size, self.config.model_dim);
+        for (i, &token_id) in inputs.iter().enumerate() {
             for j in 0..self.config.model_dim {
-                input_embeddings[(i, j)] += self.positional_encoding[(i, j)];
+                grad_embedding[(token_id, j)] += grad_hidden[(i, j)];
             }
         }
         
-        // Pass through encoder layers
-        let mut hidden_states = input_embeddings;
+        let mut gradients = vec![grad_embedding.as_slice().to_vec()];
+        for encoder_grads in encoder_gradients {
+            gradients.extend(encoder_grads.into_buffers());
+        }
+        gradients.push(grad_output_projection.as_slice().to_vec());
+        
+        Ok((loss, gradients))
+    }
+    
+    /// Mutable views of every trainable parameter
+    fn parameters_mut(&mut self) -> Vec<&mut [f64]> {
+        let mut parameters = vec![self.embedding.as_mut_slice()];
         for encoder in &mut self.encoders {
-            hidden_states = encoder.forward(&hidden_states).await?;
+            parameters.extend(encoder.parameters_mut());
+        }
+        parameters.push(self.output_projection.as_mut_slice());
+        parameters
+    }
+    
+    /// Apply gradients with Adam
+    fn apply_gradients(&mut self, gradients: &[Vec<f64>]) -> Result<()> {
+        let mut optimizer = self.optimizer.take()
+            .unwrap_or_else(|| AdamOptimizer::new(self.training_config.clone()));
+        let result = optimizer.step(self.parameters_mut(), gradients);
+        self.optimizer = Some(optimizer);
+        result.map(|_| ())
+    }
+    
+    /// Run one optimization step on a sequence and return its loss before the update
+    pub fn train_step(&mut self, token_ids: &[usize]) -> Result<f64> {
+        let (loss, gradients) = self.compute_gradients(token_ids)?;
+        self.apply_gradients(&gradients)?;
+        Ok(loss)
+    }
+    
+    /// Train on a token sequence for several epochs, returning the average loss per epoch
+    ///
+    /// The sequence is cut into overlapping windows of `max_seq_len + 1` tokens, and the
+    /// gradients of `batch_size` windows are averaged into each optimizer step.
+    pub fn train_sequence_sync(&mut self, token_ids: &[usize], batch_size: usize, epochs: usize) -> Result<Vec<f64>> {
+        let window_len = self.config.max_seq_len + 1;
+        let mut windows = Vec::new();
+        let mut start = 0;
+        while start + 1 < token_ids.len() {
+            let end = (start + window_len).min(token_ids.len());
+            windows.push(&token_ids[start..end]);
+            start += self.config.max_seq_len;
         }
         
-        Ok(hidden_states)
+        if windows.is_empty() {
+            return Err(BrainError::InvalidInput(
+                "Training sequence needs at least two tokens".to_string()
+            ));
+        }
+        
+        let mut losses = Vec::new();
+        for _epoch in 0..epochs {
+            let mut epoch_loss = 0.0;
+            
+            for batch in windows.chunks(batch_size.max(1)) {
+                let mut batch_gradients: Vec<Vec<f64>> = Vec::new();
+                
+                for window in batch {
+                    let (loss, gradients) = self.compute_gradients(window)?;
+                    epoch_loss += loss;
+                    
+                    if batch_gradients.is_empty() {
+                        batch_gradients = gradients;
+                    } else {
+                        for (total, grad) in batch_gradients.iter_mut().zip(&gradients) {
+                            for (t, g) in total.iter_mut().zip(grad) {
+                                *t += g;
+                            }
+                        }
+                    }
+                }
+                
+                let scale = 1.0 / batch.len() as f64;
+                for grad in batch_gradients.iter_mut().flat_map(|g| g.iter_mut()) {
+                    *grad *= scale;
+                }
+                self.apply_gradients(&batch_gradients)?;
+            }
+            
+            losses.push(epoch_loss / windows.len() as f64);
+        }
+        
+        Ok(losses)
+    }
+}
+
+/// Checkpoint tensors indexed by name for restoring component weights
+pub struct CheckpointTensors<'a> {
+    tensors: HashMap<&'a str, &'a CheckpointTensor>,
+}
+
+impl<'a> CheckpointTensors<'a> {
+    /// Index the tensors of a checkpoint
+    pub fn new(tensors: &'a [CheckpointTensor]) -> Self {
+        Self {
+            tensors: tensors.iter().map(|t| (t.name.as_str(), t)).collect(),
+        }
     }
     
-    /// Predict next token probabilities
-    async fn predict_next(&mut self, input_ids: &[usize]) -> Result<DVector<f64>> {
-        let hidden_states = self.forward(input_ids).await?;
+    /// Look up a tensor, checking it has the expected shape
+    fn checked(&self, name: &str, rows: usize, cols: usize) -> Result<&'a CheckpointTensor> {
+        let tensor = *self.tensors.get(name)
+            .ok_or_else(|| BrainError::NotFound(format!("Checkpoint tensor {} is missing", name)))?;
         
-        // Use the last position for prediction
-        let last_hidden = hidden_states.row(hidden_states.nrows() - 1);
+        if tensor.rows != rows || tensor.cols != cols || rows.checked_mul(cols) != Some(tensor.data.len()) {
+            return Err(BrainError::InvalidInput(
+                format!("Checkpoint tensor {} is {}x{} with {} values, expected {}x{}",
+                       name, tensor.rows, tensor.cols, tensor.data.len(), rows, cols)
+            ));
+        }
         
-        // Apply output projection
-        let mut logits = DVector::zeros(self.vocab_size);
-        for i in 0..self.vocab_size {
-            for j in 0..self.config.model_dim {
-                logits[i] += last_hidden[j] * self.output_projection[(j, i)];
+        Ok(tensor)
+    }
+    
+    /// Look up a matrix, checking it has the expected shape
+    fn matrix(&self, name: &str, rows: usize, cols: usize) -> Result<DMatrix<f64>> {
+        let tensor = self.checked(name, rows, cols)?;
+        Ok(DMatrix::from_column_slice(rows, cols, &tensor.data))
+    }
+    
+    /// Look up a vector, checking it has the expected length
+    fn vector(&self, name: &str, len: usize) -> Result<DVector<f64>> {
+        Ok(DVector::from_column_slice(self.matrix(name, len, 1)?.as_slice()))
+    }
+}
+
+fn matrix_tensor(name: String, matrix: &DMatrix<f64>) -> CheckpointTensor {
+    CheckpointTensor {
+        name,
+        rows: matrix.nrows(),
+        cols: matrix.ncols(),
+        data: matrix.as_slice().to_vec(),
+    }
+}
+
+fn vector_tensor(name: String, vector: &DVector<f64>) -> CheckpointTensor {
+    CheckpointTensor {
+        name,
+        rows: vector.len(),
+        cols: 1,
+        data: vector.as_slice().to_vec(),
+    }
+}
+
+impl SelfAttentionImpl {
+    /// Weights as checkpoint tensors named under `prefix`
+    pub fn checkpoint_tensors(&self, prefix: &str) -> Vec<CheckpointTensor> {
+        vec![
+            matrix_tensor(format!("{}.w_query", prefix), &self.w_query),
+            matrix_tensor(format!("{}.w_key", prefix), &self.w_key),
+            matrix_tensor(format!("{}.w_value", prefix), &self.w_value),
+            matrix_tensor(format!("{}.w_output", prefix), &self.w_output),
+        ]
+    }
+    
+    /// Restore weights from checkpoint tensors named under `prefix`
+    pub fn load_checkpoint_tensors(&mut self, prefix: &str, tensors: &CheckpointTensors) -> Result<()> {
+        let dim = self.config.model_dim;
+        self.w_query = tensors.matrix(&format!("{}.w_query", prefix), dim, dim)?;
+        self.w_key = tensors.matrix(&format!("{}.w_key", prefix), dim, dim)?;
+        self.w_value = tensors.matrix(&format!("{}.w_value", prefix), dim, dim)?;
+        self.w_output = tensors.matrix(&format!("{}.w_output", prefix), dim, dim)?;
+        self.attention_weights = None;
+        Ok(())
+    }
+}
+
+impl FeedForwardNetworkImpl {
+    /// Weights as checkpoint tensors named under `prefix`
+    pub fn checkpoint_tensors(&self, prefix: &str) -> Vec<CheckpointTensor> {
+        vec![
+            matrix_tensor(format!("{}.linear1", prefix), &self.linear1),
+            vector_tensor(format!("{}.bias1", prefix), &self.bias1),
+            matrix_tensor(format!("{}.linear2", prefix), &self.linear2),
+            vector_tensor(format!("{}.bias2", prefix), &self.bias2),
+        ]
+    }
+    
+    /// Restore weights from checkpoint tensors named under `prefix`
+    pub fn load_checkpoint_tensors(&mut self, prefix: &str, tensors: &CheckpointTensors) -> Result<()> {
+        let (input_dim, hidden_dim) = self.linear1.shape();
+        self.linear1 = tensors.matrix(&format!("{}.linear1", prefix), input_dim, hidden_dim)?;
+        self.bias1 = tensors.vector(&format!("{}.bias1", prefix), hidden_dim)?;
+        self.linear2 = tensors.matrix(&format!("{}.linear2", prefix), hidden_dim, input_dim)?;
+        self.bias2 = tensors.vector(&format!("{}.bias2", prefix), input_dim)?;
+        Ok(())
+    }
+}
+
+impl LayerNormImpl {
+    /// Scale and shift as checkpoint tensors named under `prefix`
+    pub fn checkpoint_tensors(&self, prefix: &str) -> Vec<CheckpointTensor> {
+        vec![
+            vector_tensor(format!("{}.gamma", prefix), &self.gamma),
+            vector_tensor(format!("{}.beta", prefix), &self.beta),
+        ]
+    }
+    
+    /// Restore scale and shift from checkpoint tensors named under `prefix`
+    pub fn load_checkpoint_tensors(&mut self, prefix: &str, tensors: &CheckpointTensors) -> Result<()> {
+        let dim = self.gamma.len();
+        self.gamma = tensors.vector(&format!("{}.gamma", prefix), dim)?;
+        self.beta = tensors.vector(&format!("{}.beta", prefix), dim)?;
+        Ok(())
+    }
+}
+
+impl TransformerEncoderImpl {
+    /// Weights of every sublayer as checkpoint tensors named under `prefix`
+    pub fn checkpoint_tensors(&self, prefix: &str) -> Vec<CheckpointTensor> {
+        let mut tensors = self.self_attention.checkpoint_tensors(&format!("{}.attention", prefix));
+        tensors.extend(self.feed_forward.checkpoint_tensors(&format!("{}.feed_forward", prefix)));
+        tensors.extend(self.layer_norm1.checkpoint_tensors(&format!("{}.layer_norm1", prefix)));
+        tensors.extend(self.layer_norm2.checkpoint_tensors(&format!("{}.layer_norm2", prefix)));
+        tensors
+    }
+    
+    /// Restore every sublayer from checkpoint tensors named under `prefix`
+    pub fn load_checkpoint_tensors(&mut self, prefix: &str, tensors: &CheckpointTensors) -> Result<()> {
+        self.self_attention.load_checkpoint_tensors(&format!("{}.attention", prefix), tensors)?;
+        self.feed_forward.load_checkpoint_tensors(&format!("{}.feed_forward", prefix), tensors)?;
+        self.layer_norm1.load_checkpoint_tensors(&format!("{}.layer_norm1", prefix), tensors)?;
+        self.layer_norm2.load_checkpoint_tensors(&format!("{}.layer_norm2", prefix), tensors)?;
+        Ok(())
+    }
+}
+
+impl TransformerPredictorImpl {
+    /// Attach token strings indexed by token id, so checkpoints carry the vocabulary
+    pub fn set_vocabulary(&mut self, vocab: Vec<String>) -> Result<()> {
+        if vocab.len() != self.vocab_size {
+            return Err(BrainError::InvalidInput(
+                format!("Vocabulary has {} tokens but the model expects {}", vocab.len(), self.vocab_size)
+            ));
+        }
+        self.vocab = vocab;
+        Ok(())
+    }
+    
+    /// Token strings indexed by token id, empty if none were attached
+    pub fn vocabulary(&self) -> &[String] {
+        &self.vocab
+    }
+    
+    /// Snapshot configuration, vocabulary and weights
+    ///
+    /// Positional encodings are deterministic and optimizer state is not kept.
+    pub fn to_checkpoint(&self) -> TransformerCheckpoint {
+        let mut tensors = vec![matrix_tensor("embedding".to_string(), &self.embedding)];
+        for (i, encoder) in self.encoders.iter().enumerate() {
+            tensors.extend(encoder.checkpoint_tensors(&format!("encoders.{}", i)));
+        }
+        tensors.push(matrix_tensor("output_projection".to_string(), &self.output_projection));
+        
+        TransformerCheckpoint {
+            config: self.config.clone(),
+            vocab_size: self.vocab_size,
+            vocab: self.vocab.clone(),
+            tensors,
+        }
+    }
+    
+    /// Attention, feed-forward and layer norm tensors written for each encoder layer
+    const TENSORS_PER_LAYER: usize = 12;
+    
+    /// Names and shapes of every tensor `to_checkpoint` writes for this configuration
+    fn checkpoint_shapes(config: &TransformerConfig, vocab_size: usize) -> Vec<(String, usize, usize)> {
+        let (dim, hidden) = (config.model_dim, config.ff_hidden_dim);
+        let mut shapes = vec![("embedding".to_string(), vocab_size, dim)];
+        for i in 0..config.num_layers {
+            let prefix = format!("encoders.{}", i);
+            for weight in ["w_query", "w_key", "w_value", "w_output"] {
+                shapes.push((format!("{}.attention.{}", prefix, weight), dim, dim));
+            }
+            shapes.push((format!("{}.feed_forward.linear1", prefix), dim, hidden));
+            shapes.push((format!("{}.feed_forward.bias1", prefix), hidden, 1));
+            shapes.push((format!("{}.feed_forward.linear2", prefix), hidden, dim));
+            shapes.push((format!("{}.feed_forward.bias2", prefix), dim, 1));
+            for norm in ["layer_norm1", "layer_norm2"] {
+                shapes.push((format!("{}.{}.gamma", prefix, norm), dim, 1));
+                shapes.push((format!("{}.{}.beta", prefix, norm), dim, 1));
             }
         }
+        shapes.push(("output_projection".to_string(), dim, vocab_size));
+        shapes
+    }
+    
+    /// Check a checkpoint describes a well-formed predictor without allocating any weights
+    ///
+    /// The tensor count is checked before the expected shapes are listed, so a
+    /// config declaring more layers than the payload carries is rejected up front.
+    fn validate_checkpoint(checkpoint: &TransformerCheckpoint) -> Result<()> {
+        let config = &checkpoint.config;
+        if config.num_heads == 0 || !config.model_dim.is_multiple_of(config.num_heads) {
+            return Err(BrainError::InvalidInput(
+                format!("Checkpoint model dimension {} is not divisible by {} heads",
+                       config.model_dim, config.num_heads)
+            ));
+        }
+        if config.max_seq_len.checked_mul(config.model_dim).is_none() {
+            return Err(BrainError::InvalidInput(
+                format!("Checkpoint sequence length {} is too large", config.max_seq_len)
+            ));
+        }
+        if !checkpoint.vocab.is_empty() && checkpoint.vocab.len() != checkpoint.vocab_size {
+            return Err(BrainError::InvalidInput(
+                format!("Checkpoint vocabulary has {} tokens but the model expects {}",
+                       checkpoint.vocab.len(), checkpoint.vocab_size)
+            ));
+        }
         
-        // Apply softmax to get probabilities
-        self.softmax(&logits)
+        let expected_count = config.num_layers.checked_mul(Self::TENSORS_PER_LAYER).and_then(|n| n.checked_add(2));
+        if expected_count != Some(checkpoint.tensors.len()) {
+            return Err(BrainError::InvalidInput(
+                format!("Checkpoint has {} tensors but a {}-layer model needs {}",
+                       checkpoint.tensors.len(), config.num_layers,
+                       expected_count.map_or_else(|| "more".to_string(), |n| n.to_string()))
+            ));
+        }
+        
+        let tensors = CheckpointTensors::new(&checkpoint.tensors);
+        for (name, rows, cols) in Self::checkpoint_shapes(config, checkpoint.vocab_size) {
+            tensors.checked(&name, rows, cols)?;
+        }
+        Ok(())
     }
     
-    /// Get attention maps from all layers
-    async fn get_attention_maps(&self) -> Vec<Option<DMatrix<f64>>> {
-        self.encoders.iter()
-            .map(|encoder| encoder.self_attention.attention_weights.clone())
-            .collect()
+    /// Rebuild a ready-to-use predictor from a checkpoint
+    ///
+    /// Every tensor shape is checked against the config before any weights are allocated.
+    pub fn from_checkpoint(checkpoint: &TransformerCheckpoint) -> Result<Self> {
+        Self::validate_checkpoint(checkpoint)?;
+        
+        let mut predictor = Self::new(checkpoint.vocab_size, Some(checkpoint.config.clone()))?;
+        if !checkpoint.vocab.is_empty() {
+            predictor.set_vocabulary(checkpoint.vocab.clone())?;
+        }
+        
+        let tensors = CheckpointTensors::new(&checkpoint.tensors);
+        let (vocab_size, model_dim) = (checkpoint.vocab_size, checkpoint.config.model_dim);
+        predictor.embedding = tensors.matrix("embedding", vocab_size, model_dim)?;
+        for (i, encoder) in predictor.encoders.iter_mut().enumerate() {
+            encoder.load_checkpoint_tensors(&format!("encoders.{}", i), &tensors)?;
+        }
+        predictor.output_projection = tensors.matrix("output_projection", model_dim, vocab_size)?;
+        
+        Ok(predictor)
+    }
+    
+    /// Rebuild the predictor stored in a repository, if it holds a checkpoint
+    pub async fn load_from_repository(repository: &dyn NeuralRepository) -> Result<Option<Self>> {
+        match repository.load_checkpoint().await? {
+            Some(checkpoint) => Ok(Some(Self::from_checkpoint(&checkpoint)?)),
+            None => Ok(None),
+        }
     }
 }
 
@@ -727,6 +1601,7 @@ pub struct InMemoryNeuralRepository {
     model: Arc<RwLock<Option<NeuralArchitecture>>>,
     transformer_config: Arc<RwLock<Option<TransformerConfig>>>,
     developmental_state: Arc<RwLock<Option<DevelopmentalState>>>,
+    checkpoint: Arc<RwLock<Option<TransformerCheckpoint>>>,
 }
 
 impl InMemoryNeuralRepository {
@@ -735,6 +1610,7 @@ impl InMemoryNeuralRepository {
             model: Arc::new(RwLock::new(None)),
             transformer_config: Arc::new(RwLock::new(None)),
             developmental_state: Arc::new(RwLock::new(None)),
+            checkpoint: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -779,6 +1655,17 @@ impl NeuralRepository for InMemoryNeuralRepository {
         let stored_state = self.developmental_state.read().map_err(|_| BrainError::LockError("Failed to acquire read lock".to_string()))?;
         Ok(stored_state.clone())
     }
+    
+    async fn save_checkpoint(&mut self, checkpoint: &TransformerCheckpoint) -> Result<()> {
+        let mut stored_checkpoint = self.checkpoint.write().map_err(|_| BrainError::LockError("Failed to acquire write lock".to_string()))?;
+        *stored_checkpoint = Some(checkpoint.clone());
+        Ok(())
+    }
+    
+    async fn load_checkpoint(&self) -> Result<Option<TransformerCheckpoint>> {
+        let stored_checkpoint = self.checkpoint.read().map_err(|_| BrainError::LockError("Failed to acquire read lock".to_string()))?;
+        Ok(stored_checkpoint.clone())
+    }
 }
 
 impl InMemoryNeuralRepository {
@@ -837,6 +1724,7 @@ mod tests {
             ff_hidden_dim: 256,
             max_seq_len: 50,
             dropout_rate: 0.1,
+            causal: false,
         };
         
         let mut predictor = TransformerPredictorImpl::new(vocab_size, Some(config))?;
@@ -863,6 +1751,7 @@ mod tests {
             ff_hidden_dim: 128,
             max_seq_len: 20,
             dropout_rate: 0.1,
+            causal: false,
         };
         
         let mut predictor = DevelopmentalPredictorImpl::new(vocab_size, Some(transformer_config), None)?;
@@ -935,4 +1824,92 @@ mod tests {
         
         Ok(())
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_transformer_gradients_match_finite_differences() -> Result<()> {
+        let config = TransformerConfig {
+            model_dim: 4,
+            num_layers: 2,
+            num_heads: 1,
+            ff_hidden_dim: 6,
+            max_seq_len: 5,
+            dropout_rate: 0.0,
+            causal: true,
+        };
+        let mut predictor = TransformerPredictorImpl::new(5, Some(config))?;
+        let sequence = [0, 3, 1, 4, 2, 1];
+        
+        let (_, gradients) = predictor.compute_gradients(&sequence)?;
+        let epsilon = 1e-6;
+        
+        for (k, grad) in gradients.iter().enumerate() {
+            for i in [0, grad.len() / 2, grad.len() - 1] {
+                let original = predictor.parameters_mut()[k][i];
+                
+                predictor.parameters_mut()[k][i] = original + epsilon;
+                let loss_plus = predictor.sequence_loss(&sequence)?;
+                predictor.parameters_mut()[k][i] = original - epsilon;
+                let loss_minus = predictor.sequence_loss(&sequence)?;
+                predictor.parameters_mut()[k][i] = original;
+                
+                let numeric = (loss_plus - loss_minus) / (2.0 * epsilon);
+                let tolerance = 1e-5 * (1.0 + numeric.abs().max(grad[i].abs()));
+                assert!(
+                    (numeric - grad[i]).abs() < tolerance,
+                    "parameter {}[{}]: analytic {} vs numeric {}", k, i, grad[i], numeric
+                );
+            }
+        }
+        
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transformer_training_reduces_loss() -> Result<()> {
+        let config = TransformerConfig {
+            model_dim: 16,
+            num_layers: 1,
+            num_heads: 2,
+            ff_hidden_dim: 32,
+            max_seq_len: 8,
+            dropout_rate: 0.0,
+            causal: true,
+        };
+        let mut predictor = TransformerPredictorImpl::new(4, Some(config))?;
+        predictor.set_training_config(TransformerTrainingConfig {
+            learning_rate: 1e-2,
+            ..TransformerTrainingConfig::default()
+        });
+        
+        let sequence: Vec<usize> = (0..40).map(|i| i % 4).collect();
+        let losses = predictor.train_sequence_sync(&sequence, 2, 40)?;
+        
+        assert_eq!(losses.len(), 40);
+        assert!(losses[39] < losses[0] * 0.5, "loss went from {} to {}", losses[0], losses[39]);
+        
+        let probs = predictor.predict_next(&[0, 1, 2]).await?;
+        assert_eq!(probs.argmax().0, 3);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_training_requires_causal_attention() -> Result<()> {
+        let config = TransformerConfig {
+            model_dim: 8,
+            num_layers: 1,
+            num_heads: 2,
+            ff_hidden_dim: 16,
+            max_seq_len: 8,
+            dropout_rate: 0.0,
+            ..TransformerConfig::default()
+        };
+        assert!(!config.causal);
+        let mut predictor = TransformerPredictorImpl::new(4, Some(config))?;
+        
+        let sequence: Vec<usize> = (0..8).map(|i| i % 4).collect();
+        assert!(matches!(predictor.train_sequence_sync(&sequence, 1, 1), Err(BrainError::ConfigError(_))));
+        
+        Ok(())
+    }
+}
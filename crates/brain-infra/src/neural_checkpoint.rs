@@ -0,0 +1,434 @@
+//! Neural Checkpoint Infrastructure
+//!
+//! Versioned binary checkpoints for trained transformer weights and a
+//! file-backed neural repository, so a model can be trained once and served
+//! from disk by any number of processes.
+//!
+//! Checkpoint layout (all integers and floats little-endian):
+//!
+//! ```text
+//! magic "BRAINCKP" | version u32 | payload length u64 | payload | FNV-1a 64 checksum of payload
+//! ```
+//!
+//! The payload holds the transformer config, the vocabulary size, the
+//! vocabulary strings and every named weight tensor. Version 2 adds a causal
+//! attention flag after the dropout rate; version 1 checkpoints were always
+//! trained causally and still load.
+
+use brain_core::*;
+use brain_types::*;
+use brain_types::math::fnv1a;
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs as async_fs;
+
+/// Leading bytes of every checkpoint file
+pub const CHECKPOINT_MAGIC: &[u8; 8] = b"BRAINCKP";
+
+/// Current checkpoint format version
+pub const CHECKPOINT_VERSION: u32 = 2;
+
+const HEADER_LEN: usize = 8 + 4 + 8;
+const CHECKSUM_LEN: usize = 8;
+
+/// Serialize a checkpoint into the versioned binary format
+pub fn encode_checkpoint(checkpoint: &TransformerCheckpoint) -> Vec<u8> {
+    let mut payload = Vec::new();
+
+    let config = &checkpoint.config;
+    for value in [config.model_dim, config.num_layers, config.num_heads, config.ff_hidden_dim, config.max_seq_len] {
+        write_u64(&mut payload, value as u64);
+    }
+    payload.extend_from_slice(&config.dropout_rate.to_le_bytes());
+    payload.push(config.causal as u8);
+    write_u64(&mut payload, checkpoint.vocab_size as u64);
+
+    write_u64(&mut payload, checkpoint.vocab.len() as u64);
+    for token in &checkpoint.vocab {
+        write_str(&mut payload, token);
+    }
+
+    write_u64(&mut payload, checkpoint.tensors.len() as u64);
+    for tensor in &checkpoint.tensors {
+        write_str(&mut payload, &tensor.name);
+        write_u64(&mut payload, tensor.rows as u64);
+        write_u64(&mut payload, tensor.cols as u64);
+        for value in &tensor.data {
+            payload.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len() + CHECKSUM_LEN);
+    bytes.extend_from_slice(CHECKPOINT_MAGIC);
+    bytes.extend_from_slice(&CHECKPOINT_VERSION.to_le_bytes());
+    write_u64(&mut bytes, payload.len() as u64);
+    bytes.extend_from_slice(&payload);
+    write_u64(&mut bytes, fnv1a(&payload));
+    bytes
+}
+
+/// Parse and verify a checkpoint produced by `encode_checkpoint`
+pub fn decode_checkpoint(bytes: &[u8]) -> Result<TransformerCheckpoint> {
+    if bytes.len() < HEADER_LEN + CHECKSUM_LEN || &bytes[..8] != CHECKPOINT_MAGIC {
+        return Err(BrainError::ParseError("Not a Brain checkpoint".to_string()));
+    }
+
+    let mut header = CheckpointReader::new(&bytes[8..HEADER_LEN]);
+    let version = header.read_u32()?;
+    if !(1..=CHECKPOINT_VERSION).contains(&version) {
+        return Err(BrainError::ParseError(
+            format!("Unsupported checkpoint version {} (supported up to {})", version, CHECKPOINT_VERSION)
+        ));
+    }
+
+    let payload_len = header.read_u64()? as usize;
+    if bytes.len() - HEADER_LEN - CHECKSUM_LEN != payload_len {
+        return Err(BrainError::ParseError(
+            format!("Checkpoint payload is {} bytes but the header declares {}",
+                   bytes.len() - HEADER_LEN - CHECKSUM_LEN, payload_len)
+        ));
+    }
+
+    let payload = &bytes[HEADER_LEN..HEADER_LEN + payload_len];
+    let stored_checksum = CheckpointReader::new(&bytes[HEADER_LEN + payload_len..]).read_u64()?;
+    if stored_checksum != fnv1a(payload) {
+        return Err(BrainError::ParseError("Checkpoint checksum mismatch".to_string()));
+    }
+
+    let mut reader = CheckpointReader::new(payload);
+    let mut config = TransformerConfig {
+        model_dim: reader.read_usize()?,
+        num_layers: reader.read_usize()?,
+        num_heads: reader.read_usize()?,
+        ff_hidden_dim: reader.read_usize()?,
+        max_seq_len: reader.read_usize()?,
+        dropout_rate: reader.read_f64()?,
+        causal: true,
+    };
+    if version >= 2 {
+        config.causal = match reader.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            flag => return Err(BrainError::ParseError(format!("Invalid checkpoint causal flag {}", flag))),
+        };
+    }
+    let vocab_size = reader.read_usize()?;
+
+    let vocab_count = reader.read_usize()?;
+    let mut vocab = Vec::new();
+    for _ in 0..vocab_count {
+        vocab.push(reader.read_string()?);
+    }
+
+    let tensor_count = reader.read_usize()?;
+    let mut tensors = Vec::new();
+    for _ in 0..tensor_count {
+        let name = reader.read_string()?;
+        let rows = reader.read_usize()?;
+        let cols = reader.read_usize()?;
+        let values = rows.checked_mul(cols)
+            .filter(|n| n.checked_mul(8).is_some_and(|len| len <= reader.remaining()))
+            .ok_or_else(|| BrainError::ParseError(format!("Checkpoint tensor {} is truncated", name)))?;
+
+        let mut data = Vec::with_capacity(values);
+        for _ in 0..values {
+            data.push(reader.read_f64()?);
+        }
+        tensors.push(CheckpointTensor { name, rows, cols, data });
+    }
+
+    if reader.remaining() != 0 {
+        return Err(BrainError::ParseError(
+            format!("Checkpoint has {} unexpected trailing bytes", reader.remaining())
+        ));
+    }
+
+    Ok(TransformerCheckpoint { config, vocab_size, vocab, tensors })
+}
+
+/// Write a checkpoint to a file, replacing it atomically
+pub async fn save_checkpoint_file<P: AsRef<Path>>(path: P, checkpoint: &TransformerCheckpoint) -> Result<()> {
+    write_atomic(path.as_ref(), &encode_checkpoint(checkpoint)).await
+}
+
+/// Read and verify a checkpoint file
+pub async fn load_checkpoint_file<P: AsRef<Path>>(path: P) -> Result<TransformerCheckpoint> {
+    let bytes = async_fs::read(path.as_ref()).await?;
+    decode_checkpoint(&bytes)
+}
+
+/// Neural repository that keeps each record as a file in one directory
+///
+/// Metadata is stored as JSON and transformer weights in the binary checkpoint format.
+pub struct FileNeuralRepository {
+    base_path: PathBuf,
+}
+
+impl FileNeuralRepository {
+    const MODEL_FILE: &'static str = "model.json";
+    const TRANSFORMER_CONFIG_FILE: &'static str = "transformer_config.json";
+    const DEVELOPMENTAL_STATE_FILE: &'static str = "developmental_state.json";
+    const CHECKPOINT_FILE: &'static str = "transformer.ckpt";
+
+    /// Create a repository rooted at `base_path`; the directory is created on first save
+    pub fn new<P: AsRef<Path>>(base_path: P) -> Self {
+        Self {
+            base_path: base_path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Path of the transformer checkpoint file
+    pub fn checkpoint_path(&self) -> PathBuf {
+        self.base_path.join(Self::CHECKPOINT_FILE)
+    }
+
+    async fn save_json<T: Serialize>(&self, file: &str, value: &T) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(value)?;
+        write_atomic(&self.base_path.join(file), &bytes).await
+    }
+
+    async fn load_json<T: DeserializeOwned>(&self, file: &str) -> Result<Option<T>> {
+        match async_fs::read(self.base_path.join(file)).await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NeuralRepository for FileNeuralRepository {
+    async fn save_model(&mut self, model: &NeuralArchitecture) -> Result<()> {
+        self.save_json(Self::MODEL_FILE, model).await
+    }
+
+    async fn load_model(&self) -> Result<Option<NeuralArchitecture>> {
+        self.load_json(Self::MODEL_FILE).await
+    }
+
+    async fn save_transformer_config(&mut self, config: &TransformerConfig) -> Result<()> {
+        self.save_json(Self::TRANSFORMER_CONFIG_FILE, config).await
+    }
+
+    async fn load_transformer_config(&self) -> Result<Option<TransformerConfig>> {
+        self.load_json(Self::TRANSFORMER_CONFIG_FILE).await
+    }
+
+    async fn save_developmental_state(&mut self, state: &DevelopmentalState) -> Result<()> {
+        self.save_json(Self::DEVELOPMENTAL_STATE_FILE, state).await
+    }
+
+    async fn load_developmental_state(&self) -> Result<Option<DevelopmentalState>> {
+        self.load_json(Self::DEVELOPMENTAL_STATE_FILE).await
+    }
+
+    async fn save_checkpoint(&mut self, checkpoint: &TransformerCheckpoint) -> Result<()> {
+        save_checkpoint_file(self.checkpoint_path(), checkpoint).await
+    }
+
+    async fn load_checkpoint(&self) -> Result<Option<TransformerCheckpoint>> {
+        match load_checkpoint_file(self.checkpoint_path()).await {
+            Ok(checkpoint) => Ok(Some(checkpoint)),
+            Err(BrainError::Io { source }) if source.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Write through a temporary sibling file so readers never see a partial write
+async fn write_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        async_fs::create_dir_all(parent).await?;
+    }
+
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    async_fs::write(&temp_path, bytes).await?;
+    async_fs::rename(&temp_path, path).await?;
+    Ok(())
+}
+
+fn write_u64(buffer: &mut Vec<u8>, value: u64) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_str(buffer: &mut Vec<u8>, value: &str) {
+    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+/// Bounds-checked little-endian reader over checkpoint bytes
+struct CheckpointReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> CheckpointReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.take_slice(N)?;
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    fn take_slice(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(BrainError::ParseError(
+                format!("Checkpoint truncated at byte {}", self.position)
+            ));
+        }
+        let slice = &self.bytes[self.position..self.position + len];
+        self.position += len;
+        Ok(slice)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    fn read_usize(&mut self) -> Result<usize> {
+        let value = self.read_u64()?;
+        usize::try_from(value)
+            .map_err(|_| BrainError::ParseError(format!("Checkpoint value {} does not fit in memory", value)))
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.take()?))
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.take_slice(len)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|e| BrainError::ParseError(format!("Checkpoint string is not UTF-8: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::TransformerPredictorImpl;
+    use tempfile::TempDir;
+
+    fn small_predictor() -> Result<TransformerPredictorImpl> {
+        let config = TransformerConfig {
+            model_dim: 8,
+            num_layers: 2,
+            num_heads: 2,
+            ff_hidden_dim: 16,
+            max_seq_len: 6,
+            dropout_rate: 0.0,
+            causal: true,
+        };
+        let mut predictor = TransformerPredictorImpl::new(4, Some(config))?;
+        predictor.set_vocabulary(vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()])?;
+        Ok(predictor)
+    }
+
+    #[tokio::test]
+    async fn test_trained_predictor_round_trips_through_file_repository() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut predictor = small_predictor()?;
+        let sequence: Vec<usize> = (0..24).map(|i| i % 4).collect();
+        predictor.train_sequence_sync(&sequence, 1, 5)?;
+
+        let mut repository = FileNeuralRepository::new(temp_dir.path());
+        assert!(TransformerPredictorImpl::load_from_repository(&repository).await?.is_none());
+        repository.save_checkpoint(&predictor.to_checkpoint()).await?;
+        repository.save_transformer_config(&predictor.to_checkpoint().config).await?;
+
+        // A fresh repository over the same directory serves the trained model
+        let reopened = FileNeuralRepository::new(temp_dir.path());
+        let mut restored = TransformerPredictorImpl::load_from_repository(&reopened).await?
+            .expect("checkpoint should be stored");
+        assert!(reopened.load_transformer_config().await?.is_some());
+
+        assert_eq!(restored.vocabulary(), predictor.vocabulary());
+        assert_eq!(restored.sequence_loss(&sequence[..7])?, predictor.sequence_loss(&sequence[..7])?);
+        assert_eq!(restored.predict_next(&[0, 1]).await?, predictor.predict_next(&[0, 1]).await?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_corrupted_checkpoints_are_rejected() -> Result<()> {
+        let mut bytes = encode_checkpoint(&small_predictor()?.to_checkpoint());
+        assert!(decode_checkpoint(&bytes).is_ok());
+
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0xff;
+        assert!(matches!(decode_checkpoint(&bytes), Err(BrainError::ParseError(msg)) if msg.contains("checksum")));
+        bytes[middle] ^= 0xff;
+
+        bytes[8] = 3;
+        assert!(matches!(decode_checkpoint(&bytes), Err(BrainError::ParseError(msg)) if msg.contains("version")));
+        bytes[8] = 2;
+
+        assert!(decode_checkpoint(&bytes[..bytes.len() - 3]).is_err());
+        assert!(decode_checkpoint(b"not a checkpoint at all").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkpoints_not_matching_their_config_are_rejected() -> Result<()> {
+        let checkpoint = small_predictor()?.to_checkpoint();
+        assert!(TransformerPredictorImpl::from_checkpoint(&checkpoint).is_ok());
+
+        // A config claiming far more layers than the payload holds fails before any allocation
+        let mut oversized = checkpoint.clone();
+        oversized.config.num_layers = usize::MAX / 2;
+        assert!(matches!(TransformerPredictorImpl::from_checkpoint(&oversized), Err(BrainError::InvalidInput(_))));
+
+        let mut wider = checkpoint.clone();
+        wider.config.ff_hidden_dim = 1 << 40;
+        assert!(matches!(TransformerPredictorImpl::from_checkpoint(&wider), Err(BrainError::InvalidInput(_))));
+
+        let mut headless = checkpoint.clone();
+        headless.config.num_heads = 0;
+        assert!(matches!(TransformerPredictorImpl::from_checkpoint(&headless), Err(BrainError::InvalidInput(_))));
+
+        let mut renamed = checkpoint;
+        renamed.tensors[1].name = "encoders.9.attention.w_query".to_string();
+        assert!(matches!(TransformerPredictorImpl::from_checkpoint(&renamed), Err(BrainError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_one_checkpoints_load_as_causal() -> Result<()> {
+        let checkpoint = small_predictor()?.to_checkpoint();
+        let bytes = encode_checkpoint(&checkpoint);
+
+        // Rebuild the same checkpoint without the causal flag byte
+        let flag_offset = HEADER_LEN + 5 * 8 + 8;
+        let mut payload = bytes[HEADER_LEN..bytes.len() - CHECKSUM_LEN].to_vec();
+        payload.remove(flag_offset - HEADER_LEN);
+        let mut legacy = Vec::new();
+        legacy.extend_from_slice(CHECKPOINT_MAGIC);
+        legacy.extend_from_slice(&1u32.to_le_bytes());
+        write_u64(&mut legacy, payload.len() as u64);
+        legacy.extend_from_slice(&payload);
+        write_u64(&mut legacy, fnv1a(&payload));
+
+        let decoded = decode_checkpoint(&legacy)?;
+        assert!(decoded.config.causal);
+        assert_eq!(decoded.tensors.len(), checkpoint.tensors.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,404 @@
+//! Semantic Memory Persistence
+//!
+//! SQLite-backed semantic memory on the `semantic_memory` table created by
+//! `DatabaseManager::initialize_schema`. Embeddings are also held in HNSW
+//! indexes, one per embedding size, which are rebuilt from the table at
+//! startup and kept in step with every store, update, merge and remove so
+//! similarity search never scans the whole table.
+
+use crate::database::{column, DatabaseManager};
+use crate::memory::average_embeddings;
+use crate::vector_index::{HnswConfig, HnswIndex};
+use brain_core::*;
+use brain_types::*;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const CONCEPT_COLUMNS: &str = "id, name, description, embedding, confidence, frequency, last_updated, source_events";
+
+/// Persistent semantic memory with approximate nearest-neighbour search
+pub struct SqliteSemanticMemoryRepository {
+    pool: SqlitePool,
+    index_config: HnswConfig,
+    /// One index per embedding size, since vectors of different sizes never match
+    indexes: HashMap<usize, HnswIndex>,
+    /// Embedding size of every stored concept, to find its index on removal
+    dimensions: HashMap<Uuid, usize>,
+    stats: MemoryStats,
+}
+
+impl SqliteSemanticMemoryRepository {
+    /// Open semantic memory on an existing database and index every stored concept
+    pub async fn new(database: &DatabaseManager) -> Result<Self> {
+        Self::with_index_config(database, HnswConfig::default()).await
+    }
+
+    /// Open with custom HNSW parameters
+    pub async fn with_index_config(database: &DatabaseManager, index_config: HnswConfig) -> Result<Self> {
+        database.initialize_schema().await?;
+
+        let mut repository = Self {
+            pool: database.pool().clone(),
+            index_config,
+            indexes: HashMap::new(),
+            dimensions: HashMap::new(),
+            stats: MemoryStats {
+                total_items: 0,
+                size_bytes: 0,
+                last_access: Utc::now(),
+                access_count: 0,
+                consolidation_count: 0,
+            },
+        };
+
+        let rows = sqlx::query("SELECT id, embedding FROM semantic_memory")
+            .fetch_all(&repository.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to load semantic embeddings: {}", e)))?;
+        for row in &rows {
+            let id = parse_uuid(&column::<String>(row, "id")?)?;
+            let embedding = decode_embedding(&column::<Vec<u8>>(row, "embedding")?)?;
+            repository.index(id, &embedding)?;
+        }
+
+        repository.update_stats();
+        Ok(repository)
+    }
+
+    /// Number of stored concepts tracked by the similarity indexes
+    pub fn indexed_count(&self) -> usize {
+        self.dimensions.len()
+    }
+
+    /// Find similar concepts through the HNSW index
+    pub async fn find_similar(&self, embedding: &[f32], threshold: f64, limit: usize) -> Result<Vec<(Uuid, f64)>> {
+        let Some(index) = self.indexes.get(&embedding.len()) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(index
+            .search(embedding, limit)
+            .into_iter()
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .collect())
+    }
+
+    /// Merge two concepts into one stored under a new id
+    pub async fn merge_concepts(&mut self, id1: Uuid, id2: Uuid) -> Result<Uuid> {
+        let concept1 = self.load_concept(id1).await?
+            .ok_or_else(|| BrainError::NotFound(format!("Concept {} not found", id1)))?;
+        let concept2 = self.load_concept(id2).await?
+            .ok_or_else(|| BrainError::NotFound(format!("Concept {} not found", id2)))?;
+
+        let mut merged_source_events = concept1.source_events;
+        merged_source_events.extend(concept2.source_events);
+
+        let merged_concept = SemanticConcept {
+            id: Uuid::new_v4(),
+            name: format!("{}/{}", concept1.name, concept2.name),
+            description: format!("{}; {}", concept1.description, concept2.description),
+            embedding: average_embeddings(&[&concept1.embedding, &concept2.embedding]),
+            frequency: concept1.frequency + concept2.frequency,
+            confidence: (concept1.confidence + concept2.confidence) / 2.0,
+            last_updated: Utc::now(),
+            source_events: merged_source_events,
+        };
+
+        let mut tx = self.begin().await?;
+        for id in [id1, id2] {
+            sqlx::query("DELETE FROM semantic_memory WHERE id = ?1")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| BrainError::DatabaseError(format!("Failed to delete merged concept: {}", e)))?;
+        }
+        upsert_concept(&mut tx, &merged_concept).await?;
+        commit(tx).await?;
+
+        self.unindex(id1);
+        self.unindex(id2);
+        self.index(merged_concept.id, &merged_concept.embedding)?;
+        self.update_stats();
+        Ok(merged_concept.id)
+    }
+
+    async fn load_concept(&self, id: Uuid) -> Result<Option<SemanticConcept>> {
+        let row = sqlx::query(&format!("SELECT {} FROM semantic_memory WHERE id = ?1", CONCEPT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to get semantic concept: {}", e)))?;
+
+        row.as_ref().map(row_to_concept).transpose()
+    }
+
+    async fn save_concept(&mut self, concept: &SemanticConcept) -> Result<()> {
+        let mut tx = self.begin().await?;
+        upsert_concept(&mut tx, concept).await?;
+        commit(tx).await?;
+
+        self.index(concept.id, &concept.embedding)?;
+        self.update_stats();
+        Ok(())
+    }
+
+    fn index(&mut self, id: Uuid, embedding: &[f32]) -> Result<()> {
+        self.unindex(id);
+
+        let dimensions = embedding.len();
+        if dimensions > 0 {
+            let config = &self.index_config;
+            self.indexes
+                .entry(dimensions)
+                .or_insert_with(|| HnswIndex::new(dimensions, config.clone()))
+                .insert(id, embedding)?;
+        }
+        self.dimensions.insert(id, dimensions);
+        Ok(())
+    }
+
+    fn unindex(&mut self, id: Uuid) {
+        if let Some(dimensions) = self.dimensions.remove(&id) {
+            if let Some(index) = self.indexes.get_mut(&dimensions) {
+                index.remove(id);
+                if index.is_empty() {
+                    self.indexes.remove(&dimensions);
+                }
+            }
+        }
+    }
+
+    fn update_stats(&mut self) {
+        self.stats.total_items = self.dimensions.len();
+        self.stats.size_bytes = self.dimensions.len() * std::mem::size_of::<SemanticConcept>();
+        self.stats.last_access = Utc::now();
+    }
+
+    async fn begin(&self) -> Result<Transaction<'static, Sqlite>> {
+        self.pool
+            .begin()
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to begin transaction: {}", e)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SemanticMemoryRepository for SqliteSemanticMemoryRepository {
+    async fn store_concept(&mut self, concept: SemanticConcept) -> Result<Uuid> {
+        self.save_concept(&concept).await?;
+        Ok(concept.id)
+    }
+
+    async fn get_concept(&self, id: Uuid) -> Result<Option<SemanticConcept>> {
+        self.load_concept(id).await
+    }
+
+    async fn update_concept(&mut self, concept: &SemanticConcept) -> Result<()> {
+        self.save_concept(concept).await
+    }
+
+    async fn remove_concept(&mut self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM semantic_memory WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to delete semantic concept: {}", e)))?;
+
+        self.unindex(id);
+        self.update_stats();
+        Ok(())
+    }
+
+    async fn query_concepts(&self, query: &SemanticQuery) -> Result<Vec<SemanticConcept>> {
+        let mut sql = format!("SELECT {} FROM semantic_memory WHERE 1=1", CONCEPT_COLUMNS);
+        if query.name_pattern.is_some() {
+            // instr() keeps the match case-sensitive like the in-memory repository
+            sql.push_str(" AND instr(name, ?) > 0");
+        }
+        if query.min_confidence.is_some() {
+            sql.push_str(" AND confidence >= ?");
+        }
+
+        let mut statement = sqlx::query(&sql);
+        if let Some(ref pattern) = query.name_pattern {
+            statement = statement.bind(pattern);
+        }
+        if let Some(min_confidence) = query.min_confidence {
+            statement = statement.bind(min_confidence);
+        }
+        let rows = statement
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BrainError::DatabaseError(format!("Failed to query semantic concepts: {}", e)))?;
+        let mut results = rows.iter().map(row_to_concept).collect::<Result<Vec<_>>>()?;
+
+        if let Some(ref embedding) = query.embedding {
+            let mut scored: Vec<(f64, SemanticConcept)> = results
+                .into_iter()
+                .map(|concept| (cosine_similarity(embedding, &concept.embedding), concept))
+                .filter(|(similarity, _)| query.min_similarity.is_none_or(|min| *similarity >= min))
+                .collect();
+            scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+            results = scored.into_iter().map(|(_, concept)| concept).collect();
+        } else {
+            results.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        }
+
+        if let Some(limit) = query.limit {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+
+    async fn find_similar(&self, embedding: &[f32], threshold: f64, limit: usize) -> Result<Vec<(Uuid, f64)>> {
+        self.find_similar(embedding, threshold, limit).await
+    }
+
+    async fn merge_concepts(&mut self, id1: Uuid, id2: Uuid) -> Result<Uuid> {
+        self.merge_concepts(id1, id2).await
+    }
+
+    async fn stats(&self) -> Result<MemoryStats> {
+        Ok(self.stats.clone())
+    }
+}
+
+async fn commit(tx: Transaction<'static, Sqlite>) -> Result<()> {
+    tx.commit()
+        .await
+        .map_err(|e| BrainError::DatabaseError(format!("Failed to commit transaction: {}", e)))
+}
+
+async fn upsert_concept(tx: &mut Transaction<'static, Sqlite>, concept: &SemanticConcept) -> Result<()> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO semantic_memory
+            (id, name, description, embedding, confidence, frequency, last_updated, source_events)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )
+    .bind(concept.id.to_string())
+    .bind(&concept.name)
+    .bind(&concept.description)
+    .bind(encode_embedding(&concept.embedding))
+    .bind(concept.confidence)
+    .bind(concept.frequency as i64)
+    .bind(concept.last_updated.to_rfc3339())
+    .bind(serde_json::to_string(&concept.source_events)?)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| BrainError::DatabaseError(format!("Failed to save semantic concept: {}", e)))?;
+
+    Ok(())
+}
+
+/// Little-endian f32 components, four bytes each
+fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+fn decode_embedding(bytes: &[u8]) -> Result<Vec<f32>> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(BrainError::ParseError(format!("Embedding blob of {} bytes is not a list of f32", bytes.len())));
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| BrainError::ParseError(format!("Invalid UUID {}: {}", value, e)))
+}
+
+fn row_to_concept(row: &SqliteRow) -> Result<SemanticConcept> {
+    let last_updated = column::<String>(row, "last_updated")?;
+
+    Ok(SemanticConcept {
+        id: parse_uuid(&column::<String>(row, "id")?)?,
+        name: column(row, "name")?,
+        description: column(row, "description")?,
+        embedding: decode_embedding(&column::<Vec<u8>>(row, "embedding")?)?,
+        frequency: column::<i64>(row, "frequency")? as u32,
+        confidence: column(row, "confidence")?,
+        last_updated: DateTime::parse_from_rfc3339(&last_updated)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|e| BrainError::ParseError(format!("Invalid timestamp {}: {}", last_updated, e)))?,
+        source_events: serde_json::from_str(&column::<String>(row, "source_events")?)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn open(path: &std::path::Path) -> SqliteSemanticMemoryRepository {
+        let database = DatabaseManager::new_file(path).await.unwrap();
+        SqliteSemanticMemoryRepository::new(&database).await.unwrap()
+    }
+
+    fn concept(name: &str, embedding: Vec<f32>) -> SemanticConcept {
+        SemanticConcept::new(name.to_string(), format!("{} description", name), embedding)
+    }
+
+    #[tokio::test]
+    async fn test_concepts_and_index_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("semantic.db");
+
+        let (kept, removed) = {
+            let mut repo = open(&path).await;
+            let mut kept = concept("rust", vec![1.0, 0.0, 0.0]);
+            kept.source_events = vec![Uuid::new_v4()];
+            let kept = repo.store_concept(kept).await.unwrap();
+            let removed = repo.store_concept(concept("python", vec![0.0, 1.0, 0.0])).await.unwrap();
+            repo.store_concept(concept("wide", vec![0.5; 8])).await.unwrap();
+            repo.remove_concept(removed).await.unwrap();
+            (kept, removed)
+        };
+
+        let repo = open(&path).await;
+        assert_eq!(repo.indexed_count(), 2);
+        assert_eq!(repo.stats().await.unwrap().total_items, 2);
+        assert!(repo.get_concept(removed).await.unwrap().is_none());
+
+        let stored = repo.get_concept(kept).await.unwrap().unwrap();
+        assert_eq!(stored.embedding, vec![1.0, 0.0, 0.0]);
+        assert_eq!(stored.source_events.len(), 1);
+
+        let similar = repo.find_similar(&[0.9, 0.1, 0.0], 0.5, 5).await.unwrap();
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].0, kept);
+        assert!(repo.find_similar(&[0.0, 1.0, 0.0], 0.5, 5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_and_merge_keep_index_in_sync() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut repo = open(&dir.path().join("semantic.db")).await;
+
+        let a = repo.store_concept(concept("a", vec![1.0, 0.0])).await.unwrap();
+        let b = repo.store_concept(concept("b", vec![0.0, 1.0])).await.unwrap();
+
+        let mut moved = repo.get_concept(a).await.unwrap().unwrap();
+        moved.embedding = vec![0.0, 1.0];
+        repo.update_concept(&moved).await.unwrap();
+        assert!(repo.find_similar(&[1.0, 0.0], 0.5, 5).await.unwrap().is_empty());
+        assert_eq!(repo.find_similar(&[0.0, 1.0], 0.5, 5).await.unwrap().len(), 2);
+
+        let merged = repo.merge_concepts(a, b).await.unwrap();
+        assert_eq!(repo.indexed_count(), 1);
+        assert!(repo.get_concept(a).await.unwrap().is_none());
+        let similar = repo.find_similar(&[0.0, 1.0], 0.5, 5).await.unwrap();
+        assert_eq!(similar.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![merged]);
+
+        let named = repo
+            .query_concepts(&SemanticQuery { name_pattern: Some("a/b".to_string()), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(named.len(), 1);
+        assert_eq!(named[0].frequency, 2);
+    }
+}
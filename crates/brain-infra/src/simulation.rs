@@ -1,18 +1,24 @@
 //! Advanced Simulation Engine Infrastructure
 //! 
 //! This module implements the sophisticated simulation engine that converts text to state-action graphs
-//! and simulates temporal transitions using concept nodes from the concept graph.
+//! and simulates temporal transitions using concept nodes from the concept graph. It is the single
+//! engine behind both the `brain_core` simulation traits and the legacy `simulation_engine` API.
 
 use brain_types::*;
 use brain_core::{
     SimulationConfig, BranchingConfig, SimulationState, StateProperty, PropertyType,
-    RelationshipInfo, StateTransition, Action, ActionResult, BranchingResult, BranchingStats, SimulationConstraint,
-    TextToStateParser, StateValidator, SimulationEngine as SimulationEngineTrait, ConceptNode, RelationshipType, ConceptRepository,
+    RelationshipInfo, StateTransition, StateChange, ChangeType, Action, ActionResult, Effect, EffectType,
+    Condition, ConditionType, ComparisonOperator, BranchingResult, BranchingStats, PruningStatistics,
+    SimulationBranch, SimulationConstraint, ConstraintType, TextToStateParser, StateValidator,
+    SimulationEngine as SimulationEngineTrait, BranchingSimulation, SearchStrategy, MonteCarloConfig, RolloutPolicy,
+    SimulationPath, ConceptNode, ConceptType, RelationshipType, ConceptRepository,
 };
-
-#[cfg(test)]
-use brain_core::{ConstraintType, Condition, ConditionType, ComparisonOperator};
 use crate::concepts::ConceptGraphManager;
+use crate::state_grammar::{ParsedText, RuleBasedStateParser, StateGrammar, TextSpan};
+use chrono::Utc;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -457,11 +463,18 @@ impl StateValidator for StateValidatorImpl {
 }
 
 /// Main simulation engine implementation
+///
+/// Parses scenarios with a [`StateGrammar`], applies actions to the resulting
+/// state and explores their consequences as a tree of branches that is pruned
+/// by confidence, mandatory constraints and a beam of active branches. The
+/// tree is searched breadth-first, best-first, with a fixed-width beam or by
+/// Monte Carlo Tree Search, as set by [`BranchingConfig::search_strategy`],
+/// optionally towards goal conditions.
 pub struct SimulationEngineImpl {
     /// Configuration
     config: SimulationConfig,
     /// Text-to-state parser
-    parser: TextToStateParserImpl,
+    parser: RuleBasedStateParser,
     /// State validator
     validator: StateValidatorImpl,
     /// Current simulation state
@@ -480,17 +493,53 @@ pub struct SimulationEngineImpl {
     confidence_config: ConfidenceConfig,
     /// Current simulation constraints
     constraints: Vec<SimulationConstraint>,
+    /// Conditions a state must satisfy to reach the goal of a goal-directed search
+    goal_conditions: Vec<Condition>,
+    /// Branch tree of the current branching simulation
+    branches: HashMap<Uuid, SimulationBranch>,
+    /// Root of the branch tree
+    root_branch_id: Option<Uuid>,
+    /// Pruning counts for the current branch tree
+    pruning_statistics: PruningStatistics,
+}
+
+/// Visit statistics of a branch in a Monte Carlo search tree
+struct SearchNode {
+    visits: usize,
+    total_reward: f64,
+    /// Applicable actions not yet expanded into child branches
+    untried_actions: Vec<Uuid>,
+}
+
+/// Why a branch was pruned
+#[derive(Debug, Clone, Copy)]
+enum PruneReason {
+    LowConfidence,
+    ConstraintViolation,
+    Aggressive,
+    ResourceLimit,
+}
+
+impl PruneReason {
+    fn describe(self) -> &'static str {
+        match self {
+            PruneReason::LowConfidence => "confidence below threshold",
+            PruneReason::ConstraintViolation => "mandatory constraint violated",
+            PruneReason::Aggressive => "aggressive pruning",
+            PruneReason::ResourceLimit => "active branch limit reached",
+        }
+    }
 }
 
 impl SimulationEngineImpl {
     /// Create a new simulation engine
     pub fn new(concept_graph: Arc<RwLock<ConceptGraphManager>>) -> Self {
         let config = SimulationConfig::default();
-        let parser = TextToStateParserImpl::new(concept_graph);
-        let validator = StateValidatorImpl::new();
-        
+        let parser = RuleBasedStateParser::with_config(concept_graph, StateGrammar::default(), config.clone());
+        let validator = StateValidatorImpl::with_config(config.clone());
+
         Self {
-            config: config.clone(),
+            config,
             parser,
             validator,
             current_state: None,
@@ -501,6 +550,10 @@ impl SimulationEngineImpl {
             branching_config: BranchingConfig::default(),
             confidence_config: ConfidenceConfig::default(),
             constraints: Vec::new(),
+            goal_conditions: Vec::new(),
+            branches: HashMap::new(),
+            root_branch_id: None,
+            pruning_statistics: PruningStatistics::default(),
         }
     }
 
@@ -565,6 +618,939 @@ impl SimulationEngineImpl {
     pub fn clear_constraints(&mut self) {
         self.constraints.clear();
     }
+
+    /// Add a condition the goal state must satisfy
+    ///
+    /// Branches reaching a state that satisfies every goal condition end their
+    /// path and rank ahead of all other outcomes.
+    pub fn add_goal_condition(&mut self, condition: Condition) {
+        self.goal_conditions.push(condition);
+    }
+
+    /// Get goal conditions
+    pub fn get_goal_conditions(&self) -> &[Condition] {
+        &self.goal_conditions
+    }
+
+    /// Clear all goal conditions
+    pub fn clear_goal_conditions(&mut self) {
+        self.goal_conditions.clear();
+    }
+
+    /// Grammar used to parse text descriptions
+    pub fn grammar(&self) -> &StateGrammar {
+        self.parser.grammar()
+    }
+
+    /// Set the grammar used to parse text descriptions
+    pub fn set_grammar(&mut self, grammar: StateGrammar) {
+        self.parser.set_grammar(grammar);
+    }
+
+    /// Load the grammar used to parse text descriptions from a JSON file
+    pub fn load_grammar(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        self.parser.set_grammar(StateGrammar::load(path)?);
+        Ok(())
+    }
+
+    /// Result of parsing the most recent text description
+    pub fn last_parse(&self) -> Option<&ParsedText> {
+        self.parser.last_parse()
+    }
+
+    /// Parts of the most recent text description the grammar could not parse
+    pub fn unparsed_spans(&self) -> &[TextSpan] {
+        self.parser
+            .last_parse()
+            .map(|parsed| parsed.unparsed_spans.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Pruning counts for the current branch tree
+    pub fn pruning_statistics(&self) -> &PruningStatistics {
+        &self.pruning_statistics
+    }
+
+    /// Branches that end a path through the tree: unpruned, with no unpruned children
+    pub fn outcome_branches(&self) -> Vec<&SimulationBranch> {
+        self.branches
+            .values()
+            .filter(|branch| {
+                branch.pruning_reason.is_none()
+                    && branch.child_ids.iter().all(|child_id| {
+                        self.branches
+                            .get(child_id)
+                            .is_none_or(|child| child.pruning_reason.is_some())
+                    })
+            })
+            .collect()
+    }
+
+    /// Actions whose preconditions hold in `state`, highest priority and confidence first
+    fn applicable_actions(&self, state: &SimulationState) -> Vec<&Action> {
+        let mut actions: Vec<&Action> = self
+            .available_actions
+            .iter()
+            .filter(|action| action.confidence >= self.action_config.min_action_confidence)
+            .filter(|action| action.preconditions.iter().all(|condition| evaluate_condition(state, condition)))
+            .collect();
+        actions.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then_with(|| b.confidence.total_cmp(&a.confidence))
+        });
+        actions
+    }
+
+    /// Apply `action` to a copy of `state`
+    ///
+    /// Returns the new state, the transition leading to it and the effects of
+    /// any actions the action triggered.
+    fn transition(&self, state: &SimulationState, action: &Action) -> (SimulationState, StateTransition, Vec<Effect>) {
+        let mut next = state.clone();
+        next.id = Uuid::new_v4();
+        next.created_at = Utc::now();
+
+        let mut changes = Vec::new();
+        let mut side_effects = Vec::new();
+        self.apply_effects(&mut next, action, 0, &mut changes, &mut side_effects);
+
+        // Effects are applied deterministically; their probabilities discount the transition
+        let confidence = action.confidence * action.effects.iter().map(|effect| effect.probability).product::<f64>();
+        let transition = StateTransition {
+            id: Uuid::new_v4(),
+            from_state_id: state.id,
+            to_state_id: next.id,
+            applied_rules: vec![action.id],
+            changes,
+            confidence: confidence.clamp(0.0, 1.0),
+            timestamp: Utc::now(),
+            duration_ms: action.duration_ms,
+        };
+
+        (next, transition, side_effects)
+    }
+
+    fn apply_effects(
+        &self,
+        state: &mut SimulationState,
+        action: &Action,
+        chain_depth: usize,
+        changes: &mut Vec<StateChange>,
+        side_effects: &mut Vec<Effect>,
+    ) {
+        let source = format!("action:{}", action.name);
+
+        for effect in &action.effects {
+            let confidence = effect.probability;
+            match effect.effect_type {
+                EffectType::SetProperty | EffectType::ModifyProperty | EffectType::SetGlobalProperty => {
+                    let (Some(name), Some(value)) = (&effect.property_name, &effect.new_value) else { continue };
+                    let targets = match (effect.effect_type == EffectType::SetGlobalProperty, effect.entity_id) {
+                        (true, _) => vec![None],
+                        (false, Some(entity_id)) => vec![Some(entity_id)],
+                        (false, None) => unscoped_targets(state, name),
+                    };
+                    for target in targets {
+                        set_state_property(state, target, effect, name, value, &source, changes);
+                    }
+                }
+                EffectType::AddEntity => {
+                    let Some(content) = &effect.new_value else { continue };
+                    let mut concept = ConceptNode::new(ConceptType::Entity, content.clone(), confidence, Some(source.clone()));
+                    if let Some(entity_id) = effect.entity_id {
+                        concept.id = entity_id;
+                    }
+                    if state.entities.contains_key(&concept.id) {
+                        continue;
+                    }
+                    let entity_id = state.add_entity(concept, Vec::new());
+                    changes.push(StateChange {
+                        change_type: ChangeType::EntityAdded,
+                        entity_id: Some(entity_id),
+                        property_name: None,
+                        old_value: None,
+                        new_value: Some(content.clone()),
+                        confidence,
+                    });
+                }
+                EffectType::RemoveEntity => {
+                    let Some(entity_id) = effect.entity_id else { continue };
+                    if let Some(concept) = state.entities.remove(&entity_id) {
+                        state.entity_properties.remove(&entity_id);
+                        state.relationships.retain(|(source_id, target_id), _| *source_id != entity_id && *target_id != entity_id);
+                        changes.push(StateChange {
+                            change_type: ChangeType::EntityRemoved,
+                            entity_id: Some(entity_id),
+                            property_name: None,
+                            old_value: Some(concept.content),
+                            new_value: None,
+                            confidence,
+                        });
+                    }
+                }
+                EffectType::AddRelationship | EffectType::RemoveRelationship | EffectType::ModifyRelationship => {
+                    // Relationship effects name the target entity's ID in `property_name`
+                    let target = effect.property_name.as_deref().and_then(|target| Uuid::parse_str(target).ok());
+                    let (Some(source_id), Some(target_id)) = (effect.entity_id, target) else { continue };
+                    let key = (source_id, target_id);
+
+                    let change = match effect.effect_type {
+                        EffectType::AddRelationship => {
+                            let relationship_type = effect
+                                .new_value
+                                .as_deref()
+                                .map(parse_relationship_type)
+                                .unwrap_or(RelationshipType::AssociatedWith);
+                            let relationship_info = RelationshipInfo {
+                                relationship_type: relationship_type.clone(),
+                                strength: confidence,
+                                properties: Vec::new(),
+                                confidence,
+                            };
+                            state
+                                .add_relationship(source_id, target_id, relationship_info)
+                                .ok()
+                                .map(|_| (ChangeType::RelationshipAdded, None, Some(relationship_type.to_string())))
+                        }
+                        EffectType::RemoveRelationship => state
+                            .relationships
+                            .remove(&key)
+                            .map(|info| (ChangeType::RelationshipRemoved, Some(info.relationship_type.to_string()), None)),
+                        _ => {
+                            let strength = effect.new_value.as_deref().and_then(|value| value.parse::<f64>().ok());
+                            match (strength, state.relationships.get_mut(&key)) {
+                                (Some(strength), Some(info)) => {
+                                    let old_strength = info.strength;
+                                    info.strength = strength.clamp(0.0, 1.0);
+                                    Some((
+                                        ChangeType::RelationshipModified,
+                                        Some(old_strength.to_string()),
+                                        Some(info.strength.to_string()),
+                                    ))
+                                }
+                                _ => None,
+                            }
+                        }
+                    };
+
+                    if let Some((change_type, old_value, new_value)) = change {
+                        changes.push(StateChange {
+                            change_type,
+                            entity_id: Some(source_id),
+                            property_name: Some(target_id.to_string()),
+                            old_value,
+                            new_value,
+                            confidence,
+                        });
+                    }
+                }
+                EffectType::TriggerAction => {
+                    if chain_depth >= self.action_config.max_action_chain_depth {
+                        continue;
+                    }
+                    let Some(name) = &effect.new_value else { continue };
+                    let triggered = self.available_actions.iter().find(|candidate| &candidate.name == name);
+                    if let Some(triggered) = triggered {
+                        if triggered.preconditions.iter().all(|condition| evaluate_condition(state, condition)) {
+                            side_effects.extend(triggered.effects.iter().cloned());
+                            self.apply_effects(state, triggered, chain_depth + 1, changes, side_effects);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Weighted share of the constraints `state` satisfies, 1.0 without constraints
+    fn constraint_satisfaction(&self, state: &SimulationState) -> f64 {
+        let total_weight: f64 = self.constraints.iter().map(|constraint| constraint.weight).sum();
+        if total_weight <= 0.0 {
+            return 1.0;
+        }
+
+        self.constraints
+            .iter()
+            .map(|constraint| constraint_score(state, constraint) * constraint.weight)
+            .sum::<f64>()
+            / total_weight
+    }
+
+    /// Whether `state` breaks a mandatory avoidance or maintenance constraint
+    fn violates_mandatory_constraint(&self, state: &SimulationState) -> bool {
+        self.constraints.iter().any(|constraint| {
+            constraint.is_mandatory
+                && matches!(constraint.constraint_type, ConstraintType::Avoidance | ConstraintType::Maintenance)
+                && constraint_score(state, constraint) < 0.5
+        })
+    }
+
+    /// Confidence of the branch reached from `parent` by `action`
+    fn branch_confidence(
+        &self,
+        state: &SimulationState,
+        action: &Action,
+        parent: &SimulationBranch,
+        constraint_satisfaction: f64,
+    ) -> f64 {
+        let weights = &self.confidence_config;
+        let base = action.confidence * weights.rule_confidence_weight
+            + parent.accumulated_confidence * weights.path_likelihood_weight
+            + state_consistency(state) * weights.state_consistency_weight;
+        let decay = weights.confidence_decay_factor.powf(parent.depth as f64);
+
+        ((base + constraint_satisfaction * weights.constraint_satisfaction_bonus) * decay).clamp(0.0, 1.0)
+    }
+
+    /// Child of `parent` reached by applying `action`
+    fn child_branch(&self, parent: &SimulationBranch, action: &Action) -> SimulationBranch {
+        let (state, transition, _) = self.transition(&parent.current_state, action);
+        let constraint_satisfaction = self.constraint_satisfaction(&state);
+        let confidence = self.branch_confidence(&state, action, parent, constraint_satisfaction);
+
+        let mut child = SimulationBranch::new(state, Some(parent.id));
+        child.depth = parent.depth + 1;
+        child.transition_history = parent.transition_history.clone();
+        child.transition_history.push(transition);
+        child.update_confidence(confidence);
+        child.metadata.insert("action".to_string(), action.name.clone());
+        child.metadata.insert("constraint_satisfaction".to_string(), constraint_satisfaction.to_string());
+        child
+    }
+
+    /// Expand a branch with one child per applicable action, returning the children's IDs
+    fn expand_branch(&mut self, branch_id: Uuid) -> Vec<Uuid> {
+        let Some(branch) = self.branches.get(&branch_id) else { return Vec::new() };
+        if branch.depth >= self.branching_config.max_branching_depth {
+            return Vec::new();
+        }
+
+        let children: Vec<SimulationBranch> = self
+            .applicable_actions(&branch.current_state)
+            .into_iter()
+            .take(self.branching_config.max_branches_per_step)
+            .map(|action| self.child_branch(branch, action))
+            .collect();
+
+        children.into_iter().map(|child| self.insert_child(branch_id, child)).collect()
+    }
+
+    /// Add `child` to the tree below `parent_id`
+    fn insert_child(&mut self, parent_id: Uuid, child: SimulationBranch) -> Uuid {
+        let child_id = child.id;
+        if let Some(parent) = self.branches.get_mut(&parent_id) {
+            parent.add_child(child_id);
+            // Expanded branches stay in the tree but are no longer on the frontier
+            parent.is_active = false;
+        }
+        self.branches.insert(child_id, child);
+        child_id
+    }
+
+    /// Why a newly created branch should be pruned, if it should
+    fn prune_reason(&self, branch: &SimulationBranch) -> Option<PruneReason> {
+        let minimum_confidence = self
+            .branching_config
+            .min_branch_confidence
+            .max(self.branching_config.pruning_threshold);
+
+        if branch.accumulated_confidence < minimum_confidence {
+            Some(PruneReason::LowConfidence)
+        } else if self.violates_mandatory_constraint(&branch.current_state) {
+            Some(PruneReason::ConstraintViolation)
+        } else {
+            None
+        }
+    }
+
+    fn prune_branch(&mut self, branch_id: Uuid, reason: PruneReason) {
+        if let Some(branch) = self.branches.get_mut(&branch_id) {
+            branch.prune(reason.describe().to_string());
+        }
+        let counter = match reason {
+            PruneReason::LowConfidence => &mut self.pruning_statistics.low_confidence_pruned,
+            PruneReason::ConstraintViolation => &mut self.pruning_statistics.constraint_violation_pruned,
+            PruneReason::Aggressive => &mut self.pruning_statistics.aggressive_pruned,
+            PruneReason::ResourceLimit => &mut self.pruning_statistics.resource_limit_pruned,
+        };
+        *counter += 1;
+    }
+
+    /// Prune newly created branches and return the surviving frontier, best first
+    ///
+    /// Branches are dropped for low confidence or a mandatory constraint
+    /// violation, then the frontier is cut to the beam of active branches.
+    fn prune_frontier(&mut self, branch_ids: Vec<Uuid>) -> Vec<Uuid> {
+        let mut survivors = Vec::new();
+        for branch_id in branch_ids {
+            let Some(branch) = self.branches.get(&branch_id) else { continue };
+            match self.prune_reason(branch) {
+                Some(reason) => self.prune_branch(branch_id, reason),
+                None => survivors.push(branch_id),
+            }
+        }
+
+        survivors.sort_by(|a, b| {
+            self.branches[b]
+                .accumulated_confidence
+                .total_cmp(&self.branches[a].accumulated_confidence)
+        });
+        let max_active = self.branching_config.max_active_branches;
+        if survivors.len() > max_active {
+            let (beam, reason) = if self.branching_config.enable_aggressive_pruning {
+                ((max_active / 2).max(1), PruneReason::Aggressive)
+            } else {
+                (max_active.max(1), PruneReason::ResourceLimit)
+            };
+            for branch_id in survivors.split_off(beam) {
+                self.prune_branch(branch_id, reason);
+            }
+        }
+
+        survivors
+    }
+
+    /// Whether the branching simulation has used up its time budget
+    fn out_of_time(&self, started: std::time::Instant) -> bool {
+        started.elapsed().as_secs() > self.branching_config.max_simulation_time_seconds
+    }
+
+    /// Expand the tree one level per step, optionally keeping only `beam_width` branches per level
+    fn level_search(&mut self, root_id: Uuid, max_steps: usize, beam_width: Option<usize>, started: std::time::Instant) {
+        let mut frontier = vec![root_id];
+        for _ in 0..max_steps {
+            // Branches that reach the goal end their path
+            frontier.retain(|branch_id| !self.branch_reaches_goal(*branch_id));
+            if frontier.is_empty() {
+                break;
+            }
+            if self.out_of_time(started) {
+                self.pruning_statistics.time_limit_pruned += frontier.len();
+                break;
+            }
+
+            let mut children = Vec::new();
+            for branch_id in frontier {
+                children.extend(self.expand_branch(branch_id));
+            }
+            frontier = self.prune_frontier(children);
+
+            if let Some(width) = beam_width {
+                if frontier.len() > width {
+                    for branch_id in frontier.split_off(width.max(1)) {
+                        self.prune_branch(branch_id, PruneReason::ResourceLimit);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Repeatedly expand the most confident open branch until one reaches the goal
+    fn best_first_search(&mut self, root_id: Uuid, max_steps: usize, started: std::time::Instant) {
+        let mut open = vec![root_id];
+        while !open.is_empty() {
+            if self.out_of_time(started) {
+                self.pruning_statistics.time_limit_pruned += open.len();
+                break;
+            }
+
+            // `open` is kept sorted best first
+            let branch_id = open.remove(0);
+            if self.branch_reaches_goal(branch_id) {
+                break;
+            }
+            if self.branches[&branch_id].depth >= max_steps {
+                continue;
+            }
+
+            let children = self.expand_branch(branch_id);
+            open.extend(self.prune_frontier(children));
+            open.sort_by(|a, b| {
+                self.branches[b]
+                    .accumulated_confidence
+                    .total_cmp(&self.branches[a].accumulated_confidence)
+            });
+            let max_active = self.branching_config.max_active_branches.max(1);
+            if open.len() > max_active {
+                for branch_id in open.split_off(max_active) {
+                    self.prune_branch(branch_id, PruneReason::ResourceLimit);
+                }
+            }
+        }
+    }
+
+    /// Monte Carlo Tree Search from the root branch
+    ///
+    /// Each iteration descends the tree by UCT, expands one untried action,
+    /// plays a rollout from the new branch and backs its reward up to the root.
+    /// Rewards are the rollout's confidence scaled by goal progress and
+    /// constraint satisfaction, and zero when a mandatory constraint is broken.
+    fn monte_carlo_search(&mut self, root_id: Uuid, max_steps: usize, config: &MonteCarloConfig, started: std::time::Instant) {
+        let mut rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let mut nodes = HashMap::new();
+        nodes.insert(root_id, self.search_node(root_id, max_steps));
+
+        for _ in 0..config.iterations {
+            if self.out_of_time(started) {
+                break;
+            }
+
+            // Selection
+            let mut node_id = root_id;
+            while nodes[&node_id].untried_actions.is_empty() {
+                let parent_visits = nodes[&node_id].visits.max(1) as f64;
+                let uct = |child: &SearchNode| {
+                    child.total_reward / child.visits as f64
+                        + config.exploration_weight * (parent_visits.ln() / child.visits as f64).sqrt()
+                };
+                let best_child = self.branches[&node_id]
+                    .child_ids
+                    .iter()
+                    .filter_map(|child_id| nodes.get(child_id).map(|child| (*child_id, uct(child))))
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b));
+                match best_child {
+                    Some((child_id, _)) => node_id = child_id,
+                    None => break,
+                }
+            }
+
+            // Expansion
+            let untried = nodes.get_mut(&node_id).and_then(|node| node.untried_actions.pop());
+            let action = untried.and_then(|action_id| self.available_actions.iter().find(|action| action.id == action_id));
+            if let Some(action) = action {
+                let child = self.child_branch(&self.branches[&node_id], action);
+                let reason = self.prune_reason(&child);
+                let child_id = self.insert_child(node_id, child);
+                match reason {
+                    // Pruned branches never enter the search tree and score nothing
+                    Some(reason) => self.prune_branch(child_id, reason),
+                    None => {
+                        nodes.insert(child_id, self.search_node(child_id, max_steps));
+                        node_id = child_id;
+                    }
+                }
+            }
+
+            // Rollout and backpropagation
+            let reward = self.rollout(&self.branches[&node_id], config, &mut rng);
+            let mut current = Some(node_id);
+            while let Some(branch_id) = current {
+                if let Some(node) = nodes.get_mut(&branch_id) {
+                    node.visits += 1;
+                    node.total_reward += reward;
+                }
+                current = self.branches[&branch_id].parent_id;
+            }
+        }
+    }
+
+    /// Search statistics for a branch entering the Monte Carlo tree
+    fn search_node(&self, branch_id: Uuid, max_steps: usize) -> SearchNode {
+        let branch = &self.branches[&branch_id];
+        let terminal = branch.depth >= max_steps.min(self.branching_config.max_branching_depth)
+            || self.reaches_goal(&branch.current_state);
+        let untried_actions = if terminal {
+            Vec::new()
+        } else {
+            // Reversed so popping yields the preferred action first
+            self.applicable_actions(&branch.current_state)
+                .into_iter()
+                .take(self.branching_config.max_branches_per_step)
+                .map(|action| action.id)
+                .rev()
+                .collect()
+        };
+
+        SearchNode {
+            visits: 0,
+            total_reward: 0.0,
+            untried_actions,
+        }
+    }
+
+    /// Play actions from `branch` by the rollout policy and score where they lead
+    fn rollout(&self, branch: &SimulationBranch, config: &MonteCarloConfig, rng: &mut StdRng) -> f64 {
+        let mut state = branch.current_state.clone();
+        let mut confidence = branch.accumulated_confidence;
+
+        for _ in 0..config.rollout_depth {
+            if self.reaches_goal(&state) || self.violates_mandatory_constraint(&state) {
+                break;
+            }
+            let actions = self.applicable_actions(&state);
+            let action = match config.rollout_policy {
+                RolloutPolicy::Random => actions.choose(rng).copied(),
+                RolloutPolicy::ConfidenceWeighted => actions.choose_weighted(rng, |action| action.confidence).ok().copied(),
+                RolloutPolicy::Greedy => actions.first().copied(),
+            };
+            let Some(action) = action else { break };
+
+            let (next, transition, _) = self.transition(&state, action);
+            confidence *= transition.confidence;
+            state = next;
+        }
+
+        if self.violates_mandatory_constraint(&state) {
+            return 0.0;
+        }
+        confidence * self.goal_progress(&state) * self.constraint_satisfaction(&state)
+    }
+
+    /// Paths to the most likely outcome branches of the current branch tree
+    ///
+    /// Paths that reach the goal come first, then the most confident, then the
+    /// deepest.
+    pub fn most_likely_paths(&self, limit: usize) -> Vec<SimulationPath> {
+        self.ranked_outcomes()
+            .into_iter()
+            .take(limit)
+            .map(|branch| {
+                let action_ids: Vec<Uuid> = branch
+                    .transition_history
+                    .iter()
+                    .filter_map(|transition| transition.applied_rules.first().copied())
+                    .collect();
+                let action_names = action_ids
+                    .iter()
+                    .map(|action_id| {
+                        self.available_actions
+                            .iter()
+                            .find(|action| action.id == *action_id)
+                            .map_or_else(|| action_id.to_string(), |action| action.name.clone())
+                    })
+                    .collect();
+
+                SimulationPath {
+                    branch_id: branch.id,
+                    action_ids,
+                    action_names,
+                    confidence: branch.accumulated_confidence,
+                    reaches_goal: self.reaches_goal(&branch.current_state),
+                }
+            })
+            .collect()
+    }
+
+    fn ranked_outcomes(&self) -> Vec<&SimulationBranch> {
+        let mut outcomes: Vec<(bool, &SimulationBranch)> = self
+            .outcome_branches()
+            .into_iter()
+            .map(|branch| (self.reaches_goal(&branch.current_state), branch))
+            .collect();
+        outcomes.sort_by(|(a_goal, a), (b_goal, b)| {
+            b_goal
+                .cmp(a_goal)
+                .then_with(|| b.accumulated_confidence.total_cmp(&a.accumulated_confidence))
+                .then_with(|| b.depth.cmp(&a.depth))
+        });
+        outcomes.into_iter().map(|(_, branch)| branch).collect()
+    }
+
+    fn most_likely_outcomes(&self, limit: usize) -> Vec<Uuid> {
+        self.ranked_outcomes().into_iter().take(limit).map(|branch| branch.id).collect()
+    }
+
+    /// Whether `state` satisfies every goal condition; never true without goals
+    fn reaches_goal(&self, state: &SimulationState) -> bool {
+        !self.goal_conditions.is_empty()
+            && self.goal_conditions.iter().all(|condition| evaluate_condition(state, condition))
+    }
+
+    /// Share of the goal conditions `state` satisfies, 1.0 without goals
+    fn goal_progress(&self, state: &SimulationState) -> f64 {
+        if self.goal_conditions.is_empty() {
+            return 1.0;
+        }
+        let satisfied = self
+            .goal_conditions
+            .iter()
+            .filter(|condition| evaluate_condition(state, condition))
+            .count();
+        satisfied as f64 / self.goal_conditions.len() as f64
+    }
+
+    fn branch_reaches_goal(&self, branch_id: Uuid) -> bool {
+        self.branches
+            .get(&branch_id)
+            .is_some_and(|branch| self.reaches_goal(&branch.current_state))
+    }
+
+    fn branching_stats(&self) -> BranchingStats {
+        let mean = |values: &[f64]| {
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<f64>() / values.len() as f64
+            }
+        };
+        let confidences: Vec<f64> = self.branches.values().map(|branch| branch.accumulated_confidence).collect();
+        let active_depths: Vec<f64> = self
+            .branches
+            .values()
+            .filter(|branch| branch.is_active)
+            .map(|branch| branch.depth as f64)
+            .collect();
+        let outcomes = self.outcome_branches();
+        let distinct_outcomes: std::collections::HashSet<String> =
+            outcomes.iter().map(|branch| state_signature(&branch.current_state)).collect();
+        let complexities: Vec<f64> = outcomes
+            .iter()
+            .map(|branch| branch.current_state.complexity() as f64 / self.config.max_state_complexity.max(1) as f64)
+            .collect();
+
+        BranchingStats {
+            average_confidence: mean(&confidences),
+            max_depth_reached: self.branches.values().map(|branch| branch.depth).max().unwrap_or(0),
+            average_depth: mean(&active_depths),
+            terminal_branches: outcomes.len(),
+            diversity_score: if outcomes.is_empty() {
+                0.0
+            } else {
+                distinct_outcomes.len() as f64 / outcomes.len() as f64
+            },
+            complexity_score: mean(&complexities).min(1.0),
+        }
+    }
+}
+
+/// Check a condition against a simulation state
+///
+/// Conditions without an entity ID look at global properties first and then
+/// at every entity, and `EntityExists` without an ID compares entity names.
+pub fn evaluate_condition(state: &SimulationState, condition: &Condition) -> bool {
+    let expected = condition.expected_value.as_str();
+    let property_matches = |property: &&StateProperty| {
+        property.confidence >= condition.required_confidence
+            && compare_values(&property.value, expected, &condition.operator)
+    };
+
+    match condition.condition_type {
+        ConditionType::EntityExists => entity_matches(state, condition),
+        ConditionType::EntityNotExists => !entity_matches(state, condition),
+        ConditionType::PropertyEquals => scoped_properties(state, condition).iter().any(property_matches),
+        ConditionType::PropertyNotEquals => !scoped_properties(state, condition).iter().any(property_matches),
+        ConditionType::GlobalProperty => condition.property_name.as_ref().is_some_and(|name| {
+            state
+                .global_properties
+                .iter()
+                .filter(|property| &property.name == name)
+                .any(|property| property_matches(&property))
+        }),
+        ConditionType::RelationshipExists => relationship_matches(state, condition),
+        ConditionType::RelationshipNotExists => !relationship_matches(state, condition),
+        ConditionType::CustomPattern => compare_values(&state.description, expected, &condition.operator),
+    }
+}
+
+fn compare_values(actual: &str, expected: &str, operator: &ComparisonOperator) -> bool {
+    // Numbers compare numerically, anything else lexicographically
+    let ordering = || match (actual.trim().parse::<f64>(), expected.trim().parse::<f64>()) {
+        (Ok(actual), Ok(expected)) => actual.partial_cmp(&expected),
+        _ => Some(actual.cmp(expected)),
+    };
+
+    match operator {
+        ComparisonOperator::Equals => actual == expected,
+        ComparisonOperator::NotEquals => actual != expected,
+        ComparisonOperator::GreaterThan => ordering() == Some(std::cmp::Ordering::Greater),
+        ComparisonOperator::GreaterThanOrEqual => ordering().is_some_and(|ordering| ordering.is_ge()),
+        ComparisonOperator::LessThan => ordering() == Some(std::cmp::Ordering::Less),
+        ComparisonOperator::LessThanOrEqual => ordering().is_some_and(|ordering| ordering.is_le()),
+        ComparisonOperator::Contains => actual.contains(expected),
+        ComparisonOperator::NotContains => !actual.contains(expected),
+        ComparisonOperator::StartsWith => actual.starts_with(expected),
+        ComparisonOperator::EndsWith => actual.ends_with(expected),
+        ComparisonOperator::Matches => regex::Regex::new(expected).is_ok_and(|pattern| pattern.is_match(actual)),
+    }
+}
+
+/// Properties a condition refers to
+fn scoped_properties<'a>(state: &'a SimulationState, condition: &Condition) -> Vec<&'a StateProperty> {
+    let Some(name) = &condition.property_name else { return Vec::new() };
+    let named = |properties: &'a [StateProperty]| properties.iter().filter(move |property| &property.name == name);
+
+    match condition.entity_id {
+        Some(entity_id) => state
+            .entity_properties
+            .get(&entity_id)
+            .map(|properties| named(properties).collect())
+            .unwrap_or_default(),
+        None => {
+            let global: Vec<&StateProperty> = named(&state.global_properties).collect();
+            if !global.is_empty() {
+                return global;
+            }
+            state.entity_properties.values().flat_map(|properties| named(properties.as_slice())).collect()
+        }
+    }
+}
+
+fn entity_matches(state: &SimulationState, condition: &Condition) -> bool {
+    match condition.entity_id {
+        Some(entity_id) => state
+            .entities
+            .get(&entity_id)
+            .is_some_and(|entity| entity.confidence_score >= condition.required_confidence),
+        None => state.entities.values().any(|entity| {
+            entity.confidence_score >= condition.required_confidence
+                && compare_values(&entity.content, &condition.expected_value, &condition.operator)
+        }),
+    }
+}
+
+fn relationship_matches(state: &SimulationState, condition: &Condition) -> bool {
+    state.relationships.iter().any(|((source_id, target_id), info)| {
+        condition
+            .entity_id
+            .is_none_or(|entity_id| *source_id == entity_id || *target_id == entity_id)
+            && info.confidence >= condition.required_confidence
+            && (condition.expected_value.is_empty()
+                || compare_values(&info.relationship_type.to_string(), &condition.expected_value, &condition.operator))
+    })
+}
+
+/// Where an effect without an entity ID applies: the global property of that
+/// name, else every entity that has it, else a new global property
+fn unscoped_targets(state: &SimulationState, name: &str) -> Vec<Option<Uuid>> {
+    if state.global_properties.iter().any(|property| property.name == name) {
+        return vec![None];
+    }
+
+    let mut entities: Vec<Uuid> = state
+        .entity_properties
+        .iter()
+        .filter(|(_, properties)| properties.iter().any(|property| property.name == name))
+        .map(|(entity_id, _)| *entity_id)
+        .collect();
+    if entities.is_empty() {
+        return vec![None];
+    }
+    entities.sort();
+    entities.into_iter().map(Some).collect()
+}
+
+/// Set or, for `ModifyProperty` on numbers, increment a property of an entity or the scene
+fn set_state_property(
+    state: &mut SimulationState,
+    entity_id: Option<Uuid>,
+    effect: &Effect,
+    name: &str,
+    value: &str,
+    source: &str,
+    changes: &mut Vec<StateChange>,
+) {
+    let properties = match entity_id {
+        Some(entity_id) => match state.entity_properties.get_mut(&entity_id) {
+            Some(properties) => properties,
+            None => return,
+        },
+        None => &mut state.global_properties,
+    };
+
+    let existing = properties.iter().position(|property| property.name == name);
+    let old_value = existing.map(|index| properties[index].value.clone());
+    let new_value = match (
+        effect.effect_type == EffectType::ModifyProperty,
+        old_value.as_deref().and_then(|old| old.parse::<f64>().ok()),
+        value.parse::<f64>().ok(),
+    ) {
+        (true, Some(current), Some(delta)) => (current + delta).to_string(),
+        _ => value.to_string(),
+    };
+    let property = StateProperty {
+        name: name.to_string(),
+        value: new_value.clone(),
+        property_type: existing
+            .map(|index| properties[index].property_type.clone())
+            .unwrap_or(PropertyType::State),
+        confidence: effect.probability,
+        source: source.to_string(),
+    };
+    match existing {
+        Some(index) => properties[index] = property,
+        None => properties.push(property),
+    }
+
+    changes.push(StateChange {
+        change_type: if entity_id.is_some() { ChangeType::PropertyChanged } else { ChangeType::GlobalPropertyChanged },
+        entity_id,
+        property_name: Some(name.to_string()),
+        old_value,
+        new_value: Some(new_value),
+        confidence: effect.probability,
+    });
+}
+
+/// Relationship type from its display name, e.g. `LOCATED_AT`
+fn parse_relationship_type(name: &str) -> RelationshipType {
+    match name.trim().to_uppercase().as_str() {
+        "IS_A" => RelationshipType::IsA,
+        "PART_OF" => RelationshipType::PartOf,
+        "CAUSES" => RelationshipType::Causes,
+        "SIMILAR_TO" => RelationshipType::SimilarTo,
+        "BEFORE" => RelationshipType::Before,
+        "AFTER" => RelationshipType::After,
+        "LOCATED_AT" => RelationshipType::LocatedAt,
+        "HAS" => RelationshipType::Has,
+        "USES" => RelationshipType::Uses,
+        "OPPOSITE_OF" => RelationshipType::OppositeOf,
+        "ASSOCIATED_WITH" => RelationshipType::AssociatedWith,
+        _ => RelationshipType::Custom(name.trim().to_string()),
+    }
+}
+
+/// How well `state` satisfies one constraint (0.0 to 1.0)
+fn constraint_score(state: &SimulationState, constraint: &SimulationConstraint) -> f64 {
+    let holds = || evaluate_condition(state, &constraint.condition);
+    match constraint.constraint_type {
+        ConstraintType::Achievement | ConstraintType::Maintenance => {
+            if holds() {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        ConstraintType::Avoidance => {
+            if holds() {
+                0.0
+            } else {
+                1.0
+            }
+        }
+        ConstraintType::Probabilistic => state_consistency(state),
+        // Sequences and resources are judged over whole paths, not single states
+        ConstraintType::Sequence | ConstraintType::Resource => 0.5,
+    }
+}
+
+/// Consistency of a state, discounted for every low-confidence entity property
+fn state_consistency(state: &SimulationState) -> f64 {
+    let uncertain = state
+        .entity_properties
+        .values()
+        .flatten()
+        .filter(|property| property.confidence < 0.5)
+        .count();
+    0.9f64.powi(uncertain as i32)
+}
+
+/// Canonical description of a state's properties, for telling outcomes apart
+fn state_signature(state: &SimulationState) -> String {
+    let mut facts: Vec<String> = state
+        .entity_properties
+        .iter()
+        .flat_map(|(entity_id, properties)| {
+            let entity = state.entities.get(entity_id).map_or("", |entity| entity.content.as_str());
+            properties.iter().map(move |property| format!("{}.{}={}", entity, property.name, property.value))
+        })
+        .chain(state.global_properties.iter().map(|property| format!("{}={}", property.name, property.value)))
+        .collect();
+    facts.extend(state.entities.values().map(|entity| entity.content.clone()));
+    facts.sort();
+    facts.join(";")
 }
 
 #[async_trait::async_trait]
@@ -584,48 +1570,122 @@ impl SimulationEngineTrait for SimulationEngineImpl {
         self.current_state.as_ref()
     }
     
-    async fn apply_action(&mut self, _action_id: Uuid) -> Result<ActionResult> {
-        // Placeholder implementation - would apply action to current state
-        Ok(ActionResult {
-            action_id: _action_id,
-            success: false,
-            changes: Vec::new(),
-            confidence: 0.0,
-            execution_time_ms: 0,
-            errors: vec!["Action application not yet implemented".to_string()],
-            side_effects: Vec::new(),
-        })
+    async fn apply_action(&mut self, action_id: Uuid) -> Result<ActionResult> {
+        let started = std::time::Instant::now();
+        let state = self
+            .current_state
+            .as_ref()
+            .ok_or_else(|| BrainError::InvalidInput("No initial state set".to_string()))?;
+        let action = self
+            .available_actions
+            .iter()
+            .find(|action| action.id == action_id)
+            .ok_or_else(|| BrainError::NotFound(format!("Action {} not found", action_id)))?;
+
+        if !action.preconditions.iter().all(|condition| evaluate_condition(state, condition)) {
+            return Ok(ActionResult {
+                action_id,
+                success: false,
+                changes: Vec::new(),
+                confidence: 0.0,
+                execution_time_ms: started.elapsed().as_millis() as u64,
+                errors: vec![format!("Preconditions of action '{}' are not met", action.name)],
+                side_effects: Vec::new(),
+            });
+        }
+
+        let (next_state, transition, side_effects) = self.transition(state, action);
+        let result = ActionResult {
+            action_id,
+            success: true,
+            changes: transition.changes.clone(),
+            confidence: transition.confidence,
+            execution_time_ms: started.elapsed().as_millis() as u64,
+            errors: Vec::new(),
+            side_effects,
+        };
+
+        self.transition_history.push(transition);
+        self.state_history.push(next_state.clone());
+        self.current_state = Some(next_state);
+        Ok(result)
     }
     
     async fn step(&mut self) -> Result<Vec<ActionResult>> {
-        // Placeholder implementation - would execute one simulation step
-        Ok(Vec::new())
+        let state = self
+            .current_state
+            .as_ref()
+            .ok_or_else(|| BrainError::InvalidInput("No initial state set".to_string()))?;
+        let action_ids: Vec<Uuid> = self
+            .applicable_actions(state)
+            .into_iter()
+            .take(self.action_config.max_actions_per_step)
+            .map(|action| action.id)
+            .collect();
+
+        // Earlier actions in a step can invalidate later ones, which then report failure
+        let mut results = Vec::with_capacity(action_ids.len());
+        for action_id in action_ids {
+            results.push(self.apply_action(action_id).await?);
+        }
+        Ok(results)
     }
     
-    async fn run_branching_simulation(&mut self, _max_steps: usize) -> Result<BranchingResult> {
-        // Placeholder implementation - would run branching simulation
+    async fn run_branching_simulation(&mut self, max_steps: usize) -> Result<BranchingResult> {
+        let started = std::time::Instant::now();
+        let initial_state = self
+            .current_state
+            .clone()
+            .ok_or_else(|| BrainError::InvalidInput("No initial state set".to_string()))?;
+
+        self.branches.clear();
+        self.pruning_statistics = PruningStatistics::default();
+        let mut root = SimulationBranch::new(initial_state, None);
+        let root_satisfaction = self.constraint_satisfaction(&root.current_state);
+        root.metadata.insert("constraint_satisfaction".to_string(), root_satisfaction.to_string());
+        let root_branch_id = root.id;
+        self.root_branch_id = Some(root_branch_id);
+        self.branches.insert(root_branch_id, root);
+
+        match self.branching_config.search_strategy.clone() {
+            SearchStrategy::BreadthFirst => self.level_search(root_branch_id, max_steps, None, started),
+            SearchStrategy::Beam { width } => self.level_search(root_branch_id, max_steps, Some(width), started),
+            SearchStrategy::BestFirst => self.best_first_search(root_branch_id, max_steps, started),
+            SearchStrategy::MonteCarlo(config) => self.monte_carlo_search(root_branch_id, max_steps, &config, started),
+        }
+
+        let unpruned: Vec<f64> = self
+            .branches
+            .values()
+            .filter(|branch| branch.pruning_reason.is_none())
+            .map(|branch| branch.accumulated_confidence)
+            .collect();
+        let overall_confidence = if unpruned.is_empty() {
+            0.0
+        } else {
+            unpruned.iter().sum::<f64>() / unpruned.len() as f64
+        };
+
         Ok(BranchingResult {
-            branches: HashMap::new(),
-            root_branch_id: Uuid::new_v4(),
-            most_likely_outcomes: Vec::new(),
-            total_branches_explored: 0,
-            total_branches_pruned: 0,
-            overall_confidence: 0.0,
-            execution_time_ms: 0,
-            branching_stats: BranchingStats {
-                average_confidence: 0.0,
-                max_depth_reached: 0,
-                average_depth: 0.0,
-                terminal_branches: 0,
-                diversity_score: 0.0,
-                complexity_score: 0.0,
-            },
+            branches: self.branches.clone(),
+            root_branch_id,
+            most_likely_outcomes: self.most_likely_outcomes(5),
+            total_branches_explored: self.branches.len(),
+            total_branches_pruned: self.pruning_statistics.total(),
+            overall_confidence,
+            execution_time_ms: started.elapsed().as_millis() as u64,
+            branching_stats: self.branching_stats(),
+            pruning_stats: self.pruning_statistics.clone(),
+            most_likely_paths: self.most_likely_paths(5),
         })
     }
     
     fn find_applicable_actions(&self) -> Result<Vec<Uuid>> {
-        // Placeholder implementation - would find applicable actions
-        Ok(Vec::new())
+        Ok(self
+            .current_state
+            .as_ref()
+            .map(|state| self.applicable_actions(state).into_iter().map(|action| action.id).collect())
+            .unwrap_or_default())
     }
     
     fn add_action(&mut self, action: Action) {
@@ -640,6 +1700,9 @@ impl SimulationEngineTrait for SimulationEngineImpl {
         self.current_state = None;
         self.state_history.clear();
         self.transition_history.clear();
+        self.branches.clear();
+        self.root_branch_id = None;
+        self.pruning_statistics = PruningStatistics::default();
     }
     
     fn config(&self) -> &SimulationConfig {
@@ -653,6 +1716,64 @@ impl SimulationEngineTrait for SimulationEngineImpl {
     }
 }
 
+#[async_trait::async_trait]
+impl BranchingSimulation for SimulationEngineImpl {
+    async fn create_branch(&mut self, parent_id: Option<Uuid>, state: SimulationState) -> Result<Uuid> {
+        let mut branch = SimulationBranch::new(state, parent_id);
+        match parent_id {
+            Some(parent_id) => {
+                let parent = self
+                    .branches
+                    .get_mut(&parent_id)
+                    .ok_or_else(|| BrainError::NotFound(format!("Branch {} not found", parent_id)))?;
+                parent.add_child(branch.id);
+                branch.depth = parent.depth + 1;
+                branch.accumulated_confidence = parent.accumulated_confidence;
+                branch.transition_history = parent.transition_history.clone();
+            }
+            None if self.root_branch_id.is_none() => self.root_branch_id = Some(branch.id),
+            None => {}
+        }
+
+        let branch_id = branch.id;
+        self.branches.insert(branch_id, branch);
+        Ok(branch_id)
+    }
+
+    fn prune_branches(&mut self, threshold: f64) -> Result<usize> {
+        let pruned: Vec<Uuid> = self
+            .branches
+            .values()
+            .filter(|branch| branch.is_active && branch.accumulated_confidence < threshold)
+            .map(|branch| branch.id)
+            .collect();
+        for branch_id in &pruned {
+            self.prune_branch(*branch_id, PruneReason::LowConfidence);
+        }
+        Ok(pruned.len())
+    }
+
+    fn get_most_likely_outcomes(&self, limit: usize) -> Result<Vec<Uuid>> {
+        Ok(self.most_likely_outcomes(limit))
+    }
+
+    fn calculate_stats(&self) -> Result<BranchingStats> {
+        Ok(self.branching_stats())
+    }
+
+    fn get_branch(&self, branch_id: Uuid) -> Option<&SimulationBranch> {
+        self.branches.get(&branch_id)
+    }
+
+    fn get_active_branches(&self) -> Vec<Uuid> {
+        self.branches
+            .values()
+            .filter(|branch| branch.is_active)
+            .map(|branch| branch.id)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -819,4 +1940,155 @@ mod tests {
         // Relationships might be extracted based on proximity
         // This is a basic test since relationship extraction is heuristic-based
     }
-} 
\ No newline at end of file
+
+    fn door_action(name: &str, from: &str, to: &str, confidence: f64) -> Action {
+        let mut action = Action::new(name.to_string(), format!("Door goes from {} to {}", from, to));
+        action.add_precondition(Condition {
+            condition_type: ConditionType::PropertyEquals,
+            entity_id: None,
+            property_name: Some("state".to_string()),
+            expected_value: from.to_string(),
+            operator: ComparisonOperator::Equals,
+            required_confidence: 0.0,
+        });
+        action.add_effect(Effect {
+            effect_type: EffectType::SetProperty,
+            entity_id: None,
+            property_name: Some("state".to_string()),
+            new_value: Some(to.to_string()),
+            probability: 1.0,
+            delay_ms: 0,
+        });
+        action.set_confidence(confidence);
+        action
+    }
+
+    fn door_state(state: &SimulationState) -> Option<&str> {
+        state
+            .entity_properties
+            .values()
+            .flatten()
+            .find(|property| property.name == "state")
+            .map(|property| property.value.as_str())
+    }
+
+    #[tokio::test]
+    async fn test_apply_action_and_step() {
+        let concept_graph = create_test_concept_graph().await;
+        let mut engine = SimulationEngineImpl::new(concept_graph);
+        engine.initialize_from_text("A person stands near the door").await.unwrap();
+        assert!(engine.unparsed_spans().is_empty());
+
+        let open = door_action("open_door", "closed", "open", 0.9);
+        let open_id = open.id;
+        engine.add_action(open);
+        engine.add_action(door_action("close_door", "open", "closed", 0.9));
+        assert_eq!(engine.find_applicable_actions().unwrap(), vec![open_id]);
+
+        let result = engine.apply_action(open_id).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.changes.len(), 1);
+        assert_eq!(result.changes[0].old_value.as_deref(), Some("closed"));
+        assert_eq!(door_state(engine.get_current_state().unwrap()), Some("open"));
+        assert_eq!(engine.get_transition_history().len(), 1);
+
+        // The door is already open
+        let result = engine.apply_action(open_id).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(engine.get_state_history().len(), 2);
+
+        let results = engine.step().await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(door_state(engine.get_current_state().unwrap()), Some("closed"));
+        assert!(engine.apply_action(Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_branching_simulation_prunes_violations() {
+        let concept_graph = create_test_concept_graph().await;
+        let mut engine = SimulationEngineImpl::new(concept_graph);
+        engine.initialize_from_text("A person stands near the door").await.unwrap();
+        engine.add_action(door_action("open_door", "closed", "open", 0.9));
+        engine.add_action(door_action("close_door", "open", "closed", 0.9));
+        engine.add_action(door_action("break_door", "closed", "broken", 0.8));
+        engine.add_constraint(SimulationConstraint {
+            id: Uuid::new_v4(),
+            constraint_type: ConstraintType::Avoidance,
+            description: "Keep the door intact".to_string(),
+            condition: Condition {
+                condition_type: ConditionType::PropertyEquals,
+                entity_id: None,
+                property_name: Some("state".to_string()),
+                expected_value: "broken".to_string(),
+                operator: ComparisonOperator::Equals,
+                required_confidence: 0.0,
+            },
+            weight: 1.0,
+            is_mandatory: true,
+        });
+
+        let result = engine.run_branching_simulation(3).await.unwrap();
+        assert_eq!(result.pruning_stats.constraint_violation_pruned, 2);
+        assert_eq!(result.total_branches_pruned, result.pruning_stats.total());
+        assert_eq!(result.branching_stats.max_depth_reached, 3);
+        assert!(!result.most_likely_outcomes.is_empty());
+        for outcome_id in &result.most_likely_outcomes {
+            let outcome = &result.branches[outcome_id];
+            assert_ne!(door_state(&outcome.current_state), Some("broken"));
+            assert_eq!(outcome.transition_history.len(), outcome.depth);
+        }
+
+        let best = engine.get_branch(result.most_likely_outcomes[0]).unwrap();
+        assert_eq!(best.metadata.get("action").map(String::as_str), Some("open_door"));
+        let active = engine.get_active_branches().len();
+        assert_eq!(engine.prune_branches(1.1).unwrap(), active);
+        assert!(engine.get_active_branches().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_goal_directed_search_strategies() {
+        let concept_graph = create_test_concept_graph().await;
+        let mut engine = SimulationEngineImpl::new(concept_graph);
+        engine.initialize_from_text("A person stands near the door").await.unwrap();
+        engine.add_action(door_action("open_door", "closed", "open", 0.9));
+        engine.add_action(door_action("close_door", "open", "closed", 0.9));
+        engine.add_action(door_action("lock_door", "closed", "locked", 0.6));
+        engine.add_goal_condition(Condition {
+            condition_type: ConditionType::PropertyEquals,
+            entity_id: None,
+            property_name: Some("state".to_string()),
+            expected_value: "locked".to_string(),
+            operator: ComparisonOperator::Equals,
+            required_confidence: 0.0,
+        });
+
+        let monte_carlo = MonteCarloConfig {
+            iterations: 100,
+            seed: Some(7),
+            ..MonteCarloConfig::default()
+        };
+        for search_strategy in [SearchStrategy::BreadthFirst, SearchStrategy::BestFirst, SearchStrategy::MonteCarlo(monte_carlo)] {
+            engine.set_branching_config(BranchingConfig {
+                search_strategy: search_strategy.clone(),
+                ..BranchingConfig::default()
+            });
+            let result = engine.run_branching_simulation(3).await.unwrap();
+
+            let best = &result.most_likely_paths[0];
+            assert!(best.reaches_goal, "{:?}", search_strategy);
+            assert_eq!(best.action_names, vec!["lock_door".to_string()], "{:?}", search_strategy);
+            assert_eq!(result.most_likely_outcomes[0], best.branch_id);
+        }
+
+        // A beam of one follows the most confident action and never locks the door
+        engine.set_branching_config(BranchingConfig {
+            search_strategy: SearchStrategy::Beam { width: 1 },
+            ..BranchingConfig::default()
+        });
+        let result = engine.run_branching_simulation(3).await.unwrap();
+        assert_eq!(result.pruning_stats.resource_limit_pruned, 2);
+        let best = &result.most_likely_paths[0];
+        assert!(!best.reaches_goal);
+        assert_eq!(best.action_names, vec!["open_door", "close_door", "open_door"]);
+    }
+}
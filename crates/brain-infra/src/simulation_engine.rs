@@ -6,15 +6,24 @@
 //! - Tree-based branching exploration with confidence scoring
 //! - Intelligent pruning mechanisms for optimization
 //! - Constraint-guided simulation for targeted exploration
+//!
+//! These are the original simulation types, kept for existing callers. The
+//! engine itself is [`SimulationEngineImpl`]; [`SimulationEngine`] converts
+//! actions and constraints to the `brain_core` types on the way in and
+//! branches and states back on the way out.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use anyhow::Result;
 use uuid::Uuid;
-use brain_types::BrainError;
-use brain_core::{ConceptNode, ConceptType};
-use tokio::time::Instant;
+use brain_core::{ConceptNode, SimulationEngine as SimulationEngineTrait};
+use tokio::sync::RwLock;
+use crate::simulation::SimulationEngineImpl;
+use crate::state_grammar::{ParsedText, StateGrammar, TextSpan};
+
+pub use brain_core::PruningStatistics;
 
 /// Property types for simulation state entities
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,6 +48,31 @@ pub struct StateProperty {
     pub source: String,
 }
 
+impl From<brain_core::PropertyType> for PropertyType {
+    fn from(property_type: brain_core::PropertyType) -> Self {
+        match property_type {
+            brain_core::PropertyType::Physical | brain_core::PropertyType::Abstract => PropertyType::Attribute,
+            brain_core::PropertyType::Location => PropertyType::Location,
+            brain_core::PropertyType::Temporal => PropertyType::Temporal,
+            brain_core::PropertyType::State | brain_core::PropertyType::Action => PropertyType::State,
+            brain_core::PropertyType::Relationship => PropertyType::Relationship,
+            brain_core::PropertyType::Quantity => PropertyType::Numeric,
+        }
+    }
+}
+
+impl From<brain_core::StateProperty> for StateProperty {
+    fn from(property: brain_core::StateProperty) -> Self {
+        Self {
+            name: property.name,
+            value: property.value,
+            property_type: property.property_type.into(),
+            confidence: property.confidence,
+            source: property.source,
+        }
+    }
+}
+
 /// Priority levels for actions
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ActionPriority {
@@ -123,6 +157,105 @@ pub struct Action {
     pub context: HashMap<String, String>,
 }
 
+impl From<ActionPriority> for brain_core::ActionPriority {
+    fn from(priority: ActionPriority) -> Self {
+        match priority {
+            ActionPriority::Low => brain_core::ActionPriority::Low,
+            ActionPriority::Medium => brain_core::ActionPriority::Medium,
+            ActionPriority::High => brain_core::ActionPriority::High,
+            ActionPriority::Critical => brain_core::ActionPriority::Critical,
+        }
+    }
+}
+
+impl From<EffectType> for brain_core::EffectType {
+    fn from(effect_type: EffectType) -> Self {
+        match effect_type {
+            EffectType::SetProperty => brain_core::EffectType::SetProperty,
+            EffectType::ModifyProperty => brain_core::EffectType::ModifyProperty,
+            EffectType::AddEntity => brain_core::EffectType::AddEntity,
+            EffectType::RemoveEntity => brain_core::EffectType::RemoveEntity,
+            EffectType::CreateRelationship => brain_core::EffectType::AddRelationship,
+            EffectType::RemoveRelationship => brain_core::EffectType::RemoveRelationship,
+            EffectType::TriggerEvent => brain_core::EffectType::TriggerAction,
+        }
+    }
+}
+
+impl From<Effect> for brain_core::Effect {
+    fn from(effect: Effect) -> Self {
+        Self {
+            effect_type: effect.effect_type.into(),
+            entity_id: effect.entity_id,
+            property_name: effect.property_name,
+            new_value: effect.new_value,
+            probability: effect.probability,
+            delay_ms: effect.delay_ms,
+        }
+    }
+}
+
+impl From<ComparisonOperator> for brain_core::ComparisonOperator {
+    fn from(operator: ComparisonOperator) -> Self {
+        match operator {
+            ComparisonOperator::Equals => brain_core::ComparisonOperator::Equals,
+            ComparisonOperator::NotEquals => brain_core::ComparisonOperator::NotEquals,
+            ComparisonOperator::GreaterThan => brain_core::ComparisonOperator::GreaterThan,
+            ComparisonOperator::LessThan => brain_core::ComparisonOperator::LessThan,
+            ComparisonOperator::GreaterThanOrEqual => brain_core::ComparisonOperator::GreaterThanOrEqual,
+            ComparisonOperator::LessThanOrEqual => brain_core::ComparisonOperator::LessThanOrEqual,
+            ComparisonOperator::Contains => brain_core::ComparisonOperator::Contains,
+            ComparisonOperator::StartsWith => brain_core::ComparisonOperator::StartsWith,
+            ComparisonOperator::EndsWith => brain_core::ComparisonOperator::EndsWith,
+        }
+    }
+}
+
+impl From<Condition> for brain_core::Condition {
+    fn from(condition: Condition) -> Self {
+        // Ordered comparisons are property checks with an ordering operator
+        let (condition_type, operator) = match condition.condition_type {
+            ConditionType::PropertyEquals => (brain_core::ConditionType::PropertyEquals, condition.operator.into()),
+            ConditionType::PropertyNotEquals => (brain_core::ConditionType::PropertyNotEquals, condition.operator.into()),
+            ConditionType::PropertyGreaterThan => {
+                (brain_core::ConditionType::PropertyEquals, brain_core::ComparisonOperator::GreaterThan)
+            }
+            ConditionType::PropertyLessThan => {
+                (brain_core::ConditionType::PropertyEquals, brain_core::ComparisonOperator::LessThan)
+            }
+            ConditionType::EntityExists => (brain_core::ConditionType::EntityExists, condition.operator.into()),
+            ConditionType::EntityNotExists => (brain_core::ConditionType::EntityNotExists, condition.operator.into()),
+            ConditionType::RelationshipExists => (brain_core::ConditionType::RelationshipExists, condition.operator.into()),
+            ConditionType::CustomPredicate => (brain_core::ConditionType::CustomPattern, condition.operator.into()),
+        };
+
+        Self {
+            condition_type,
+            entity_id: condition.entity_id,
+            property_name: condition.property_name,
+            expected_value: condition.expected_value,
+            operator,
+            required_confidence: condition.required_confidence,
+        }
+    }
+}
+
+impl From<Action> for brain_core::Action {
+    fn from(action: Action) -> Self {
+        Self {
+            id: action.id,
+            name: action.name,
+            description: action.description,
+            preconditions: action.preconditions.into_iter().map(Into::into).collect(),
+            effects: action.effects.into_iter().map(Into::into).collect(),
+            confidence: action.confidence,
+            duration_ms: action.duration_ms,
+            priority: action.priority.into(),
+            context: action.context,
+        }
+    }
+}
+
 /// Configuration for branching simulation behavior
 #[derive(Debug, Clone)]
 pub struct BranchingConfig {
@@ -149,6 +282,21 @@ impl Default for BranchingConfig {
     }
 }
 
+impl From<BranchingConfig> for brain_core::BranchingConfig {
+    fn from(config: BranchingConfig) -> Self {
+        Self {
+            max_branches_per_step: config.max_branches_per_step,
+            max_branching_depth: config.max_branching_depth,
+            min_branch_confidence: config.min_branch_confidence,
+            max_active_branches: config.max_active_branches,
+            pruning_threshold: config.pruning_threshold,
+            enable_aggressive_pruning: config.enable_aggressive_pruning,
+            max_simulation_time_seconds: config.max_simulation_time_seconds,
+            search_strategy: brain_core::SearchStrategy::BreadthFirst,
+        }
+    }
+}
+
 /// Configuration for confidence scoring
 #[derive(Debug, Clone)]
 pub struct ConfidenceConfig {
@@ -173,6 +321,19 @@ impl Default for ConfidenceConfig {
     }
 }
 
+impl From<ConfidenceConfig> for crate::simulation::ConfidenceConfig {
+    fn from(config: ConfidenceConfig) -> Self {
+        Self {
+            rule_confidence_weight: config.rule_confidence_weight,
+            path_likelihood_weight: config.path_likelihood_weight,
+            state_consistency_weight: config.state_consistency_weight,
+            historical_accuracy_weight: config.historical_accuracy_weight,
+            confidence_decay_factor: config.confidence_decay_factor,
+            constraint_satisfaction_bonus: config.constraint_satisfaction_bonus,
+        }
+    }
+}
+
 /// Types of simulation constraints
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConstraintType {
@@ -201,6 +362,43 @@ pub struct SimulationConstraint {
     pub description: String,
 }
 
+impl From<SimulationConstraint> for brain_core::SimulationConstraint {
+    fn from(constraint: SimulationConstraint) -> Self {
+        let constraint_type = match constraint.constraint_type {
+            ConstraintType::MustReach | ConstraintType::CustomGoal => brain_core::ConstraintType::Achievement,
+            ConstraintType::MustAvoid | ConstraintType::Avoidance => brain_core::ConstraintType::Avoidance,
+            ConstraintType::Maintenance => brain_core::ConstraintType::Maintenance,
+            ConstraintType::PreferPath => brain_core::ConstraintType::Sequence,
+            ConstraintType::MinimizeSteps | ConstraintType::ResourceLimit | ConstraintType::TimeLimit => {
+                brain_core::ConstraintType::Resource
+            }
+            ConstraintType::MaximizeConfidence => brain_core::ConstraintType::Probabilistic,
+        };
+        // A target property and value make a property check, a bare target an existence check
+        let condition_type = if constraint.target_property.is_some() && constraint.target_value.is_some() {
+            brain_core::ConditionType::PropertyEquals
+        } else {
+            brain_core::ConditionType::EntityExists
+        };
+
+        Self {
+            id: constraint.id,
+            constraint_type,
+            description: constraint.description,
+            condition: brain_core::Condition {
+                condition_type,
+                entity_id: constraint.target_entity,
+                property_name: constraint.target_property,
+                expected_value: constraint.target_value.unwrap_or_default(),
+                operator: brain_core::ComparisonOperator::Equals,
+                required_confidence: 0.0,
+            },
+            weight: constraint.weight,
+            is_mandatory: constraint.priority == ActionPriority::Critical,
+        }
+    }
+}
+
 /// Branch in the simulation tree
 #[derive(Debug, Clone)]
 pub struct SimulationBranch {
@@ -228,15 +426,6 @@ pub struct BranchingResult {
     pub pruning_statistics: PruningStatistics,
 }
 
-/// Statistics about pruning operations
-#[derive(Debug, Clone)]
-pub struct PruningStatistics {
-    pub low_confidence_pruned: usize,
-    pub resource_limit_pruned: usize,
-    pub constraint_violation_pruned: usize,
-    pub time_limit_pruned: usize,
-    pub aggressive_pruned: usize,
-}
 
 /// Simulation state containing entities and their properties
 #[derive(Debug, Clone)]
@@ -324,142 +513,121 @@ impl SimulationState {
     }
 }
 
-/// Main simulation engine with branching capabilities
+impl From<brain_core::SimulationState> for SimulationState {
+    /// Relationships the parser did not already record as a property of their
+    /// source entity become relationship properties named after their type.
+    fn from(state: brain_core::SimulationState) -> Self {
+        let mut entity_properties: HashMap<Uuid, Vec<StateProperty>> = state
+            .entity_properties
+            .into_iter()
+            .map(|(entity_id, properties)| (entity_id, properties.into_iter().map(StateProperty::from).collect()))
+            .collect();
+
+        for ((source_id, target_id), relationship) in &state.relationships {
+            let (Some(target), Some(properties)) = (state.entities.get(target_id), entity_properties.get_mut(source_id)) else {
+                continue;
+            };
+            if properties.iter().any(|property| property.value == target.content) {
+                continue;
+            }
+            properties.push(StateProperty {
+                name: relationship.relationship_type.to_string().to_lowercase(),
+                value: target.content.clone(),
+                property_type: PropertyType::Relationship,
+                confidence: relationship.confidence,
+                source: "text_parsing".to_string(),
+            });
+        }
+
+        Self {
+            id: state.id,
+            description: state.description,
+            entities: state.entities,
+            entity_properties,
+            global_properties: state.global_properties.into_iter().map(StateProperty::from).collect(),
+            timestamp: state.created_at,
+            step_number: 0,
+        }
+    }
+}
+
+/// Simulation engine with branching capabilities
+///
+/// Compatibility wrapper that keeps this module's types while delegating
+/// parsing, action application and branch exploration to
+/// [`SimulationEngineImpl`].
 pub struct SimulationEngine {
-    #[allow(dead_code)]
-    concept_graph: crate::ConceptGraphManager,
-    current_state: Option<SimulationState>,
+    engine: SimulationEngineImpl,
     available_actions: Vec<Action>,
     constraints: Vec<SimulationConstraint>,
-    branching_config: BranchingConfig,
-    confidence_config: ConfidenceConfig,
-    active_branches: Vec<SimulationBranch>,
-    simulation_history: Vec<SimulationBranch>,
 }
 
 impl SimulationEngine {
     pub fn new(concept_graph: crate::ConceptGraphManager) -> Self {
+        let mut engine = SimulationEngineImpl::new(Arc::new(RwLock::new(concept_graph)));
+        engine.set_branching_config(BranchingConfig::default().into());
+        engine.set_confidence_config(ConfidenceConfig::default().into());
+
         Self {
-            concept_graph,
-            current_state: None,
+            engine,
             available_actions: Vec::new(),
             constraints: Vec::new(),
-            branching_config: BranchingConfig::default(),
-            confidence_config: ConfidenceConfig::default(),
-            active_branches: Vec::new(),
-            simulation_history: Vec::new(),
         }
     }
 
+    /// The engine this wrapper delegates to
+    pub fn engine(&self) -> &SimulationEngineImpl {
+        &self.engine
+    }
+
     pub fn set_branching_config(&mut self, config: BranchingConfig) {
-        self.branching_config = config;
+        self.engine.set_branching_config(config.into());
     }
 
     pub fn set_confidence_config(&mut self, config: ConfidenceConfig) {
-        self.confidence_config = config;
+        self.engine.set_confidence_config(config.into());
     }
 
     pub fn reset(&mut self) {
-        self.current_state = None;
-        self.active_branches.clear();
-        self.simulation_history.clear();
+        self.engine.reset();
     }
 
     /// Initialize simulation from text description
     pub async fn initialize_from_text(&mut self, description: &str) -> Result<Uuid> {
-        let mut state = SimulationState::new();
-        state.set_description(description.to_string());
-
-        // Parse description to extract entities and properties
-        // This is a simplified implementation - in practice would use NLP
-        self.parse_initial_state(&mut state, description).await?;
-
-        let state_id = state.id;
-        self.current_state = Some(state);
-
-        Ok(state_id)
-    }
-
-    /// Parse text description to populate initial state
-    async fn parse_initial_state(&mut self, state: &mut SimulationState, description: &str) -> Result<()> {
-        // Simple keyword-based parsing for demo
-        if description.contains("person") {
-            let _person_id = Uuid::new_v4();
-            let person_concept = ConceptNode::new(
-                ConceptType::Entity,
-                "person".to_string(),
-                0.95,
-                Some("simulation_entity".to_string()),
-            );
-
-            let mut properties = Vec::new();
-            
-            if description.contains("room") {
-                properties.push(StateProperty {
-                    name: "location".to_string(),
-                    value: "room".to_string(),
-                    property_type: PropertyType::Location,
-                    confidence: 0.9,
-                    source: "text_parsing".to_string(),
-                });
-            }
+        Ok(self.engine.initialize_from_text(description).await?)
+    }
 
-            if description.contains("stands") {
-                properties.push(StateProperty {
-                    name: "position".to_string(),
-                    value: "center".to_string(),
-                    property_type: PropertyType::State,
-                    confidence: 0.8,
-                    source: "text_parsing".to_string(),
-                });
-            }
+    /// Grammar used to parse text descriptions
+    pub fn grammar(&self) -> &StateGrammar {
+        self.engine.grammar()
+    }
 
-            state.add_entity(person_concept, properties);
-        }
+    pub fn set_grammar(&mut self, grammar: StateGrammar) {
+        self.engine.set_grammar(grammar);
+    }
 
-        // Add room entities if mentioned
-        if description.contains("door") {
-            let _door_id = Uuid::new_v4();
-            let door_concept = ConceptNode::new(
-                ConceptType::Entity,
-                "door".to_string(),
-                0.9,
-                Some("simulation_entity".to_string()),
-            );
-            state.add_entity(door_concept, vec![
-                StateProperty {
-                    name: "state".to_string(),
-                    value: "closed".to_string(),
-                    property_type: PropertyType::State,
-                    confidence: 0.7,
-                    source: "text_parsing".to_string(),
-                }
-            ]);
-        }
+    /// Load the grammar used to parse text descriptions from a JSON file
+    pub fn load_grammar(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        Ok(self.engine.load_grammar(path)?)
+    }
 
-        if description.contains("window") {
-            let _window_id = Uuid::new_v4();
-            let window_concept = ConceptNode::new(
-                ConceptType::Entity,
-                "window".to_string(),
-                0.9,
-                Some("simulation_entity".to_string()),
-            );
-            state.add_entity(window_concept, vec![
-                StateProperty {
-                    name: "state".to_string(),
-                    value: "closed".to_string(),
-                    property_type: PropertyType::State,
-                    confidence: 0.7,
-                    source: "text_parsing".to_string(),
-                }
-            ]);
-        }
+    /// Set the entity limit and concept confidence threshold used while parsing
+    pub fn set_parsing_config(&mut self, config: brain_core::SimulationConfig) {
+        self.engine.set_config(config);
+    }
 
-        Ok(())
+    /// Result of parsing the most recent text description
+    pub fn last_parse(&self) -> Option<&ParsedText> {
+        self.engine.last_parse()
+    }
+
+    /// Parts of the most recent text description the grammar could not parse
+    pub fn unparsed_spans(&self) -> &[TextSpan] {
+        self.engine.unparsed_spans()
     }
 
     pub fn add_action(&mut self, action: Action) {
+        self.engine.add_action(action.clone().into());
         self.available_actions.push(action);
     }
 
@@ -468,6 +636,7 @@ impl SimulationEngine {
     }
 
     pub fn add_constraint(&mut self, constraint: SimulationConstraint) {
+        self.engine.add_constraint(constraint.clone().into());
         self.constraints.push(constraint);
     }
 
@@ -477,364 +646,122 @@ impl SimulationEngine {
 
     /// Run branching simulation for specified number of steps
     pub async fn run_branching_simulation(&mut self, max_steps: usize) -> Result<BranchingResult> {
-        let start_time = Instant::now();
-        
-        if self.current_state.is_none() {
-            return Err(BrainError::Other("No initial state set".to_string()).into());
-        }
-
-        // Initialize with current state as root branch
-        let initial_state = self.current_state.as_ref().unwrap().clone();
-        let root_branch = SimulationBranch {
-            id: Uuid::new_v4(),
-            parent_id: None,
-            state: initial_state,
-            action_taken: None,
-            confidence: 1.0,
-            depth: 0,
-            created_at: Utc::now(),
-            is_pruned: false,
-            constraint_satisfaction: self.calculate_constraint_satisfaction(&self.current_state.as_ref().unwrap()),
-        };
-
-        self.active_branches = vec![root_branch];
-        self.simulation_history.clear();
-
-        let mut total_branches_explored = 1;
-        let mut pruning_stats = PruningStatistics {
-            low_confidence_pruned: 0,
-            resource_limit_pruned: 0,
-            constraint_violation_pruned: 0,
-            time_limit_pruned: 0,
-            aggressive_pruned: 0,
+        let result = self.engine.run_branching_simulation(max_steps).await?;
+
+        let satisfaction = |branch: &brain_core::SimulationBranch| {
+            branch
+                .metadata
+                .get("constraint_satisfaction")
+                .and_then(|value| value.parse::<f64>().ok())
+                .unwrap_or(1.0)
         };
-
-        // Main simulation loop
-        for _step in 0..max_steps {
-            if self.active_branches.is_empty() {
-                break;
-            }
-
-            let mut new_branches = Vec::new();
-            
-            // Extract branches to process to avoid borrowing conflicts
-            let branches_to_process: Vec<SimulationBranch> = self.active_branches.drain(..).collect();
-
-            // Process each active branch
-            for branch in branches_to_process {
-                if branch.depth >= self.branching_config.max_branching_depth {
-                    self.simulation_history.push(branch);
-                    continue;
-                }
-
-                // Find applicable actions for this branch
-                let applicable_actions = self.find_applicable_actions(&branch.state);
-
-                // Create new branches for each applicable action
-                let mut branch_count = 0;
-                for action in applicable_actions {
-                    if branch_count >= self.branching_config.max_branches_per_step {
-                        break;
-                    }
-
-                    // Apply action to create new state
-                    let mut new_state = branch.state.clone_for_branch();
-                    self.apply_action(&mut new_state, &action).await?;
-
-                    // Calculate confidence for new branch
-                    let confidence = self.calculate_branch_confidence(&new_state, &action, &branch);
-                    
-                    if confidence < self.branching_config.min_branch_confidence {
-                        pruning_stats.low_confidence_pruned += 1;
-                        continue;
-                    }
-
-                    let constraint_satisfaction = self.calculate_constraint_satisfaction(&new_state);
-
-                    let new_branch = SimulationBranch {
-                        id: Uuid::new_v4(),
-                        parent_id: Some(branch.id),
-                        state: new_state,
-                        action_taken: Some(action),
-                        confidence,
-                        depth: branch.depth + 1,
-                        created_at: Utc::now(),
-                        is_pruned: false,
-                        constraint_satisfaction,
-                    };
-
-                    new_branches.push(new_branch);
-                    total_branches_explored += 1;
-                    branch_count += 1;
-                }
-
-                self.simulation_history.push(branch);
-            }
-
-            // Apply pruning
-            self.prune_branches(&mut new_branches, &mut pruning_stats);
-
-            // Check branch limits
-            if new_branches.len() > self.branching_config.max_active_branches {
-                new_branches.sort_by(|a, b| {
-                    b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal)
-                });
-                new_branches.truncate(self.branching_config.max_active_branches);
-                pruning_stats.resource_limit_pruned += total_branches_explored - new_branches.len() - self.simulation_history.len();
-            }
-
-            self.active_branches = new_branches;
-
-            // Check time limit
-            if start_time.elapsed().as_secs() > self.branching_config.max_simulation_time_seconds {
-                pruning_stats.time_limit_pruned += self.active_branches.len();
-                break;
-            }
-        }
-
-        // Move remaining active branches to history
-        self.simulation_history.extend(self.active_branches.drain(..));
-
-        // Calculate results
-        let execution_time_ms = start_time.elapsed().as_millis() as u64;
-        let total_branches_pruned = pruning_stats.low_confidence_pruned 
-            + pruning_stats.resource_limit_pruned 
-            + pruning_stats.constraint_violation_pruned 
-            + pruning_stats.time_limit_pruned 
-            + pruning_stats.aggressive_pruned;
-
-        let overall_confidence = if self.simulation_history.is_empty() {
+        let unpruned: Vec<&brain_core::SimulationBranch> =
+            result.branches.values().filter(|branch| branch.pruning_reason.is_none()).collect();
+        let constraint_satisfaction_score = if unpruned.is_empty() {
             0.0
         } else {
-            self.simulation_history.iter().map(|b| b.confidence).sum::<f64>() / self.simulation_history.len() as f64
+            unpruned.iter().map(|branch| satisfaction(branch)).sum::<f64>() / unpruned.len() as f64
         };
 
-        // Find most likely outcomes (top 5 by confidence)
-        let mut most_likely_outcomes = self.simulation_history.clone();
-        most_likely_outcomes.sort_by(|a, b| {
-            b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal)
-        });
-        most_likely_outcomes.truncate(5);
-
-        let final_states = self.simulation_history
+        let most_likely_outcomes = result
+            .most_likely_outcomes
             .iter()
-            .filter(|b| b.depth == self.branching_config.max_branching_depth || 
-                      self.simulation_history.iter().all(|other| other.parent_id != Some(b.id)))
-            .map(|b| b.state.clone())
+            .filter_map(|branch_id| result.branches.get(branch_id))
+            .map(|branch| self.legacy_branch(branch, satisfaction(branch)))
+            .collect();
+        let final_states = self
+            .engine
+            .outcome_branches()
+            .into_iter()
+            .map(|branch| self.legacy_state(branch))
             .collect();
-
-        let constraint_satisfaction_score = if self.simulation_history.is_empty() {
-            0.0
-        } else {
-            self.simulation_history.iter().map(|b| b.constraint_satisfaction).sum::<f64>() / self.simulation_history.len() as f64
-        };
 
         Ok(BranchingResult {
-            total_branches_explored,
-            total_branches_pruned,
-            overall_confidence,
-            execution_time_ms,
+            total_branches_explored: result.total_branches_explored,
+            total_branches_pruned: result.total_branches_pruned,
+            overall_confidence: result.overall_confidence,
+            execution_time_ms: result.execution_time_ms,
             most_likely_outcomes,
             constraint_satisfaction_score,
             final_states,
-            pruning_statistics: pruning_stats,
+            pruning_statistics: result.pruning_stats,
         })
     }
 
-    /// Find actions applicable to the current state
-    fn find_applicable_actions(&self, state: &SimulationState) -> Vec<Action> {
-        self.available_actions
-            .iter()
-            .filter(|action| self.check_preconditions(state, &action.preconditions))
-            .cloned()
-            .collect()
-    }
-
-    /// Check if all preconditions are satisfied
-    fn check_preconditions(&self, state: &SimulationState, preconditions: &[Condition]) -> bool {
-        preconditions.iter().all(|condition| self.evaluate_condition(state, condition))
-    }
-
-    /// Evaluate a single condition
-    fn evaluate_condition(&self, state: &SimulationState, condition: &Condition) -> bool {
-        match condition.condition_type {
-            ConditionType::PropertyEquals => {
-                if let Some(property_name) = &condition.property_name {
-                    if let Some(entity_id) = condition.entity_id {
-                        if let Some(property) = state.get_entity_property(entity_id, property_name) {
-                            return property.confidence >= condition.required_confidence &&
-                                   self.compare_values(&property.value, &condition.expected_value, &condition.operator);
-                        }
-                    } else {
-                        // Check global properties
-                        if let Some(property) = state.global_properties.iter().find(|p| p.name == *property_name) {
-                            return property.confidence >= condition.required_confidence &&
-                                   self.compare_values(&property.value, &condition.expected_value, &condition.operator);
-                        }
-                    }
-                }
-            }
-            ConditionType::EntityExists => {
-                if let Some(entity_id) = condition.entity_id {
-                    return state.entities.contains_key(&entity_id);
-                }
-            }
-            // Add more condition types as needed
-            _ => {}
-        }
-
-        false
+    fn legacy_state(&self, branch: &brain_core::SimulationBranch) -> SimulationState {
+        let mut state = SimulationState::from(branch.current_state.clone());
+        state.step_number = branch.depth;
+        state
     }
 
-    /// Compare values using the specified operator
-    fn compare_values(&self, actual: &str, expected: &str, operator: &ComparisonOperator) -> bool {
-        match operator {
-            ComparisonOperator::Equals => actual == expected,
-            ComparisonOperator::NotEquals => actual != expected,
-            ComparisonOperator::Contains => actual.contains(expected),
-            ComparisonOperator::StartsWith => actual.starts_with(expected),
-            ComparisonOperator::EndsWith => actual.ends_with(expected),
-            // Add numeric comparisons if needed
-            _ => false,
+    fn legacy_branch(&self, branch: &brain_core::SimulationBranch, constraint_satisfaction: f64) -> SimulationBranch {
+        let action_taken = branch
+            .transition_history
+            .last()
+            .and_then(|transition| transition.applied_rules.first())
+            .and_then(|action_id| self.available_actions.iter().find(|action| action.id == *action_id))
+            .cloned();
+
+        SimulationBranch {
+            id: branch.id,
+            parent_id: branch.parent_id,
+            state: self.legacy_state(branch),
+            action_taken,
+            confidence: branch.accumulated_confidence,
+            depth: branch.depth,
+            created_at: branch.created_at,
+            is_pruned: branch.pruning_reason.is_some(),
+            constraint_satisfaction,
         }
     }
+}
 
-    /// Apply an action to a state
-    async fn apply_action(&mut self, state: &mut SimulationState, action: &Action) -> Result<()> {
-        for effect in &action.effects {
-            match effect.effect_type {
-                EffectType::SetProperty => {
-                    if let (Some(property_name), Some(new_value)) = (&effect.property_name, &effect.new_value) {
-                        if let Some(entity_id) = effect.entity_id {
-                            let property = StateProperty {
-                                name: property_name.clone(),
-                                value: new_value.clone(),
-                                property_type: PropertyType::State,
-                                confidence: effect.probability,
-                                source: format!("action:{}", action.name),
-                            };
-                            state.set_entity_property(entity_id, property);
-                        } else {
-                            let property = StateProperty {
-                                name: property_name.clone(),
-                                value: new_value.clone(),
-                                property_type: PropertyType::State,
-                                confidence: effect.probability,
-                                source: format!("action:{}", action.name),
-                            };
-                            state.add_global_property(property);
-                        }
-                    }
-                }
-                // Add more effect types as needed
-                _ => {}
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Calculate confidence for a new branch
-    fn calculate_branch_confidence(&self, state: &SimulationState, action: &Action, parent_branch: &SimulationBranch) -> f64 {
-        let rule_confidence = action.confidence * self.confidence_config.rule_confidence_weight;
-        let path_likelihood = parent_branch.confidence * self.confidence_config.path_likelihood_weight;
-        let state_consistency = state.calculate_consistency() * self.confidence_config.state_consistency_weight;
-        let constraint_bonus = self.calculate_constraint_satisfaction(state) * self.confidence_config.constraint_satisfaction_bonus;
-
-        let base_confidence = rule_confidence + path_likelihood + state_consistency;
-        let decay = self.confidence_config.confidence_decay_factor.powf(parent_branch.depth as f64);
-        
-        ((base_confidence + constraint_bonus) * decay).min(1.0).max(0.0)
-    }
-
-    /// Calculate constraint satisfaction score for a state
-    fn calculate_constraint_satisfaction(&self, state: &SimulationState) -> f64 {
-        if self.constraints.is_empty() {
-            return 1.0;
-        }
-
-        let mut total_score = 0.0;
-        let mut total_weight = 0.0;
-
-        for constraint in &self.constraints {
-            let satisfaction = self.evaluate_constraint_satisfaction(state, constraint);
-            total_score += satisfaction * constraint.weight;
-            total_weight += constraint.weight;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::ConceptGraphConfig;
 
-        if total_weight > 0.0 {
-            total_score / total_weight
-        } else {
-            1.0
-        }
-    }
+    #[tokio::test]
+    async fn test_legacy_engine_delegates_to_core_engine() {
+        let concept_graph = crate::ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let mut engine = SimulationEngine::new(concept_graph);
+        engine.initialize_from_text("A person stands near the door").await.unwrap();
 
-    /// Evaluate how well a single constraint is satisfied
-    fn evaluate_constraint_satisfaction(&self, state: &SimulationState, constraint: &SimulationConstraint) -> f64 {
-        match constraint.constraint_type {
-            ConstraintType::MustReach => {
-                // Check if target state/property is reached
-                if let (Some(target_property), Some(target_value)) = (&constraint.target_property, &constraint.target_value) {
-                    if let Some(entity_id) = constraint.target_entity {
-                        if let Some(property) = state.get_entity_property(entity_id, target_property) {
-                            return if property.value == *target_value { 1.0 } else { 0.0 };
-                        }
-                    }
-                }
-                0.0
-            }
-            ConstraintType::MustAvoid | ConstraintType::Avoidance => {
-                // Check if forbidden state is avoided
-                if let (Some(target_property), Some(target_value)) = (&constraint.target_property, &constraint.target_value) {
-                    if let Some(entity_id) = constraint.target_entity {
-                        if let Some(property) = state.get_entity_property(entity_id, target_property) {
-                            return if property.value != *target_value { 1.0 } else { 0.0 };
-                        }
-                    }
-                }
-                1.0 // If property doesn't exist, it's avoided
-            }
-            ConstraintType::Maintenance => {
-                // Check if required state is maintained
-                if let (Some(target_property), Some(target_value)) = (&constraint.target_property, &constraint.target_value) {
-                    if let Some(entity_id) = constraint.target_entity {
-                        if let Some(property) = state.get_entity_property(entity_id, target_property) {
-                            return if property.value == *target_value { 1.0 } else { 0.0 };
-                        }
-                    }
-                }
-                0.0
-            }
-            ConstraintType::MaximizeConfidence => {
-                state.calculate_consistency()
-            }
-            // Add more constraint types as needed
-            _ => 0.5, // Neutral satisfaction for unimplemented constraints
-        }
-    }
+        let open_door = Action {
+            id: Uuid::new_v4(),
+            name: "open_door".to_string(),
+            description: "Open the door".to_string(),
+            preconditions: vec![Condition {
+                condition_type: ConditionType::PropertyEquals,
+                entity_id: None,
+                property_name: Some("state".to_string()),
+                expected_value: "closed".to_string(),
+                operator: ComparisonOperator::Equals,
+                required_confidence: 0.0,
+            }],
+            effects: vec![Effect {
+                effect_type: EffectType::SetProperty,
+                entity_id: None,
+                property_name: Some("state".to_string()),
+                new_value: Some("open".to_string()),
+                probability: 0.9,
+                delay_ms: 0,
+            }],
+            confidence: 0.9,
+            duration_ms: 100,
+            priority: ActionPriority::Medium,
+            context: HashMap::new(),
+        };
+        engine.add_action(open_door.clone());
 
-    /// Prune branches based on various criteria
-    fn prune_branches(&self, branches: &mut Vec<SimulationBranch>, stats: &mut PruningStatistics) {
-        // Low confidence pruning
-        branches.retain(|branch| {
-            if branch.confidence < self.branching_config.pruning_threshold {
-                stats.low_confidence_pruned += 1;
-                false
-            } else {
-                true
-            }
-        });
+        let result = engine.run_branching_simulation(3).await.unwrap();
+        assert_eq!(result.total_branches_explored, 2);
+        assert_eq!(result.final_states.len(), 1);
 
-        // Aggressive pruning if enabled
-        if self.branching_config.enable_aggressive_pruning && branches.len() > self.branching_config.max_active_branches {
-            let target_size = self.branching_config.max_active_branches / 2;
-            branches.sort_by(|a, b| {
-                b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal)
-            });
-            if branches.len() > target_size {
-                stats.aggressive_pruned += branches.len() - target_size;
-                branches.truncate(target_size);
-            }
-        }
+        let outcome = &result.most_likely_outcomes[0];
+        assert_eq!(outcome.action_taken.as_ref().map(|action| action.id), Some(open_door.id));
+        assert_eq!(outcome.state.step_number, 1);
+        let door_state = outcome.state.entity_properties.values().flatten().find(|property| property.name == "state");
+        assert_eq!(door_state.map(|property| property.value.as_str()), Some("open"));
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,1027 @@
+//! Rule-Based Text-to-State Parsing
+//!
+//! Turns scenario descriptions into simulation states using a grammar of entity,
+//! property and relation rules that is loaded from JSON. Mentions are matched
+//! longest phrase first, entities are resolved against concepts already in the
+//! concept graph, and any text that no rule accounts for is reported as an
+//! unparsed span so a grammar can be grown for a new domain.
+
+use brain_types::*;
+use brain_core::{
+    ConceptNode, ConceptQuery, ConceptRepository, ConceptType, PropertyType, RelationshipInfo, RelationshipType,
+    SimulationConfig, SimulationState, StateProperty, TextToStateParser,
+};
+use crate::concepts::ConceptGraphManager;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Source recorded on concepts and properties produced by the parser
+const PARSER_SOURCE: &str = "text_parsing";
+
+/// Longest concept name, in words, that is matched as an entity mention
+const MAX_CONCEPT_PHRASE_WORDS: usize = 4;
+
+fn default_confidence() -> f64 {
+    0.8
+}
+
+fn default_concept_type() -> ConceptType {
+    ConceptType::Entity
+}
+
+fn default_relation_property_type() -> PropertyType {
+    PropertyType::Relationship
+}
+
+/// What an entity-scoped property attaches to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyScope {
+    /// A property of an entity mentioned in the same sentence
+    #[default]
+    Entity,
+    /// A property of the whole scene (time of day, weather, ...)
+    Global,
+}
+
+/// Which entity mention an entity-scoped property attaches to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Attachment {
+    /// The closest entity in the sentence, preferring the preceding one on ties
+    #[default]
+    Nearest,
+    /// The closest entity before the trigger ("the door is open")
+    Preceding,
+    /// The closest entity after the trigger ("an open door")
+    Following,
+}
+
+/// A property with a fixed value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropertyValue {
+    pub name: String,
+    pub value: String,
+    pub property_type: PropertyType,
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+}
+
+impl PropertyValue {
+    fn to_property(&self, source: &str) -> StateProperty {
+        StateProperty {
+            name: self.name.clone(),
+            value: self.value.clone(),
+            property_type: self.property_type.clone(),
+            confidence: self.confidence,
+            source: source.to_string(),
+        }
+    }
+}
+
+/// Rule recognising an entity by name or alias
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityRule {
+    /// Canonical name, used as the concept content when no concept matches
+    pub name: String,
+    /// Other phrases that mention the same entity
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default = "default_concept_type")]
+    pub concept_type: ConceptType,
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+    /// Properties the entity has unless the text says otherwise
+    #[serde(default)]
+    pub defaults: Vec<PropertyValue>,
+}
+
+/// Rule extracting a property from trigger phrases
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropertyRule {
+    pub name: String,
+    pub triggers: Vec<String>,
+    /// Value to assign; the matched trigger when absent
+    #[serde(default)]
+    pub value: Option<String>,
+    pub property_type: PropertyType,
+    #[serde(default)]
+    pub scope: PropertyScope,
+    #[serde(default)]
+    pub attach: Attachment,
+    /// Entity names the property may attach to; any entity when empty
+    #[serde(default)]
+    pub applies_to: Vec<String>,
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+}
+
+impl PropertyRule {
+    fn accepts(&self, entity: &ParsedEntity) -> bool {
+        self.applies_to.is_empty()
+            || self.applies_to.iter().any(|name| {
+                let name = phrase_key(name);
+                entity.rule.as_deref().is_some_and(|rule| phrase_key(rule) == name)
+                    || phrase_key(&entity.concept.content) == name
+            })
+    }
+}
+
+/// Rule relating the entity before a trigger phrase to the entities after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationRule {
+    pub triggers: Vec<String>,
+    pub relationship_type: RelationshipType,
+    /// Property set on the subject naming the object, e.g. `location`
+    #[serde(default)]
+    pub property: Option<String>,
+    #[serde(default = "default_relation_property_type")]
+    pub property_type: PropertyType,
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+}
+
+/// Lexicon of extraction rules for turning text into simulation state
+///
+/// Grammars are plain JSON, so a domain can ship its own vocabulary; the
+/// default grammar covers simple indoor scenes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateGrammar {
+    #[serde(default)]
+    pub entities: Vec<EntityRule>,
+    #[serde(default)]
+    pub properties: Vec<PropertyRule>,
+    #[serde(default)]
+    pub relations: Vec<RelationRule>,
+    /// Words that let a relation continue to further objects ("a door and a window")
+    #[serde(default)]
+    pub conjunctions: Vec<String>,
+    /// Words consumed without producing anything (articles, copulas, ...)
+    #[serde(default)]
+    pub ignored_words: Vec<String>,
+}
+
+impl Default for StateGrammar {
+    fn default() -> Self {
+        let entity = |name: &str, aliases: &[&str], defaults: Vec<PropertyValue>| EntityRule {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            concept_type: ConceptType::Entity,
+            confidence: 0.9,
+            defaults,
+        };
+        let closed = || {
+            vec![PropertyValue {
+                name: "state".to_string(),
+                value: "closed".to_string(),
+                property_type: PropertyType::State,
+                confidence: 0.7,
+            }]
+        };
+        let property = |name: &str, triggers: &[&str], value: Option<&str>, property_type: PropertyType, scope: PropertyScope, attach: Attachment| PropertyRule {
+            name: name.to_string(),
+            triggers: triggers.iter().map(|trigger| trigger.to_string()).collect(),
+            value: value.map(str::to_string),
+            property_type,
+            scope,
+            attach,
+            applies_to: Vec::new(),
+            confidence: 0.8,
+        };
+        let relation = |triggers: &[&str], relationship_type: RelationshipType, property: Option<&str>, property_type: PropertyType| RelationRule {
+            triggers: triggers.iter().map(|trigger| trigger.to_string()).collect(),
+            relationship_type,
+            property: property.map(str::to_string),
+            property_type,
+            confidence: 0.8,
+        };
+        let words = |words: &[&str]| -> Vec<String> { words.iter().map(|word| word.to_string()).collect() };
+
+        Self {
+            entities: vec![
+                entity("person", &["man", "woman", "child", "people"], Vec::new()),
+                entity("room", &["kitchen", "hall", "hallway"], Vec::new()),
+                entity("door", &["doorway"], closed()),
+                entity("window", &[], closed()),
+                entity("table", &["desk"], Vec::new()),
+                entity("chair", &[], Vec::new()),
+            ],
+            properties: vec![
+                property("position", &["stands", "standing"], Some("center"), PropertyType::State, PropertyScope::Entity, Attachment::Preceding),
+                property("posture", &["sits", "sitting"], Some("sitting"), PropertyType::State, PropertyScope::Entity, Attachment::Preceding),
+                property("state", &["open", "closed", "locked"], None, PropertyType::State, PropertyScope::Entity, Attachment::Nearest),
+                property("color", &["red", "green", "blue", "white", "black"], None, PropertyType::Physical, PropertyScope::Entity, Attachment::Following),
+                property("time_of_day", &["morning", "afternoon", "evening", "night", "dawn", "dusk"], None, PropertyType::Temporal, PropertyScope::Global, Attachment::Nearest),
+                property("weather", &["sunny", "rainy", "cloudy", "stormy", "foggy", "snowy"], None, PropertyType::Physical, PropertyScope::Global, Attachment::Nearest),
+            ],
+            relations: vec![
+                relation(&["in", "inside", "at"], RelationshipType::LocatedAt, Some("location"), PropertyType::Location),
+                relation(&["near", "next to", "beside", "by"], RelationshipType::AssociatedWith, Some("near"), PropertyType::Location),
+                relation(&["with", "has"], RelationshipType::Has, None, PropertyType::Relationship),
+            ],
+            conjunctions: words(&["and"]),
+            ignored_words: words(&["a", "an", "the", "is", "are", "was", "were", "there", "of", "to", "its", "their"]),
+        }
+    }
+}
+
+impl StateGrammar {
+    /// Parse a grammar from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        let grammar: Self = serde_json::from_str(json)
+            .map_err(|e| BrainError::ConfigError(format!("Failed to parse state grammar: {}", e)))?;
+        grammar.validate()?;
+        Ok(grammar)
+    }
+
+    /// Load a grammar from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| BrainError::ConfigError(format!("Failed to read state grammar {}: {}", path.display(), e)))?;
+        Self::from_json(&content)
+    }
+
+    /// Serialize the grammar to JSON, e.g. to start a domain grammar from the default one
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| BrainError::ConfigError(format!("Failed to serialize state grammar: {}", e)))
+    }
+
+    /// Check that every rule can match something and has a valid confidence
+    pub fn validate(&self) -> Result<()> {
+        let matchable = |phrases: &[String]| phrases.iter().any(|phrase| !phrase_key(phrase).is_empty());
+        let check_confidence = |what: &str, confidence: f64| {
+            if (0.0..=1.0).contains(&confidence) {
+                Ok(())
+            } else {
+                Err(BrainError::ConfigError(format!("{} has confidence {} outside [0, 1]", what, confidence)))
+            }
+        };
+
+        for rule in &self.entities {
+            if phrase_key(&rule.name).is_empty() {
+                return Err(BrainError::ConfigError("Entity rule has an empty name".to_string()));
+            }
+            check_confidence(&format!("Entity rule '{}'", rule.name), rule.confidence)?;
+            for default in &rule.defaults {
+                check_confidence(&format!("Default '{}' of entity rule '{}'", default.name, rule.name), default.confidence)?;
+            }
+        }
+        for rule in &self.properties {
+            if rule.name.trim().is_empty() || !matchable(&rule.triggers) {
+                return Err(BrainError::ConfigError(format!(
+                    "Property rule '{}' needs a name and at least one trigger",
+                    rule.name
+                )));
+            }
+            check_confidence(&format!("Property rule '{}'", rule.name), rule.confidence)?;
+        }
+        for rule in &self.relations {
+            if !matchable(&rule.triggers) {
+                return Err(BrainError::ConfigError(format!(
+                    "Relation rule {} needs at least one trigger",
+                    rule.relationship_type
+                )));
+            }
+            check_confidence(&format!("Relation rule {}", rule.relationship_type), rule.confidence)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse `text`, resolving entity mentions against concepts in the graph
+    ///
+    /// Only concepts at or above `config.min_concept_confidence` are considered,
+    /// and at most `config.max_entities_per_state` entities are extracted.
+    pub async fn parse_with_graph(
+        &self,
+        text: &str,
+        concept_graph: &ConceptGraphManager,
+        config: &SimulationConfig,
+    ) -> Result<ParsedText> {
+        let query = ConceptQuery {
+            min_confidence: Some(config.min_concept_confidence),
+            ..Default::default()
+        };
+        let concepts = concept_graph.query_concepts(&query).await?;
+        Ok(self.parse(text, &concepts, config.max_entities_per_state))
+    }
+
+    /// Parse `text`, resolving entity mentions against `concepts`
+    ///
+    /// Entity rules resolve to the concept whose content equals the rule name or
+    /// one of its aliases. Entity concepts are also recognised by name even when
+    /// no rule mentions them.
+    pub fn parse(&self, text: &str, concepts: &[ConceptNode], max_entities: usize) -> ParsedText {
+        let lexicon = Lexicon::new(self, concepts);
+        let tokens = tokenize(text);
+        let (mentions, mut unparsed) = lexicon.scan(&tokens);
+        let mut parsed = ParsedText::default();
+
+        // Entities, one per rule or concept however often it is mentioned
+        let mut entity_keys: HashMap<EntityKey, usize> = HashMap::new();
+        let mut mention_entities: Vec<Option<usize>> = vec![None; mentions.len()];
+        for (index, mention) in mentions.iter().enumerate() {
+            let (rule, existing) = match mention.lexeme {
+                Lexeme::Entity(rule) => (Some(&self.entities[rule]), lexicon.resolve_rule(&self.entities[rule])),
+                Lexeme::Concept(concept) => (None, Some(&lexicon.concepts[concept])),
+                _ => continue,
+            };
+            let key = match (existing, mention.lexeme) {
+                (Some(concept), _) => EntityKey::Concept(concept.id),
+                (None, Lexeme::Entity(rule)) => EntityKey::Rule(rule),
+                _ => continue,
+            };
+
+            let span = span(text, &tokens[mention.start..mention.end]);
+            if let Some(&entity) = entity_keys.get(&key) {
+                parsed.entities[entity].mentions.push(span);
+                mention_entities[index] = Some(entity);
+                continue;
+            }
+            if parsed.entities.len() >= max_entities {
+                unparsed.extend(mention.start..mention.end);
+                continue;
+            }
+
+            let concept = match (existing, rule) {
+                (Some(concept), _) => concept.clone(),
+                (None, Some(rule)) => ConceptNode::new(
+                    rule.concept_type.clone(),
+                    rule.name.clone(),
+                    rule.confidence,
+                    Some(PARSER_SOURCE.to_string()),
+                ),
+                (None, None) => continue,
+            };
+            let properties = rule
+                .map(|rule| rule.defaults.iter().map(|default| default.to_property(PARSER_SOURCE)).collect())
+                .unwrap_or_default();
+
+            entity_keys.insert(key, parsed.entities.len());
+            mention_entities[index] = Some(parsed.entities.len());
+            parsed.entities.push(ParsedEntity {
+                concept,
+                rule: rule.map(|rule| rule.name.clone()),
+                resolved: existing.is_some(),
+                mentions: vec![span],
+                properties,
+            });
+        }
+
+        // Properties, attached to an entity in the same sentence or to the scene
+        for (index, mention) in mentions.iter().enumerate() {
+            let Lexeme::Property(rule) = mention.lexeme else { continue };
+            let rule = &self.properties[rule];
+            let span = span(text, &tokens[mention.start..mention.end]);
+            let property = StateProperty {
+                name: rule.name.clone(),
+                value: rule.value.clone().unwrap_or_else(|| span.text.to_lowercase()),
+                property_type: rule.property_type.clone(),
+                confidence: rule.confidence,
+                source: span.source(),
+            };
+
+            match rule.scope {
+                PropertyScope::Global => set_property(&mut parsed.global_properties, property),
+                PropertyScope::Entity => {
+                    match attachment_target(&mentions, &mention_entities, index, rule, &parsed.entities) {
+                        Some(entity) => set_property(&mut parsed.entities[entity].properties, property),
+                        None => unparsed.extend(mention.start..mention.end),
+                    }
+                }
+            }
+        }
+
+        // Relations, from the nearest preceding entity to the entities that follow
+        for (index, mention) in mentions.iter().enumerate() {
+            let Lexeme::Relation(rule) = mention.lexeme else { continue };
+            let rule = &self.relations[rule];
+            let subject = (0..index)
+                .rev()
+                .take_while(|&candidate| mentions[candidate].sentence == mention.sentence)
+                .find_map(|candidate| mention_entities[candidate]);
+            let objects: Vec<usize> = relation_objects(&mentions, &mention_entities, index)
+                .into_iter()
+                .filter(|&object| Some(object) != subject)
+                .collect();
+            let Some(subject) = subject.filter(|_| !objects.is_empty()) else {
+                unparsed.extend(mention.start..mention.end);
+                continue;
+            };
+
+            let span = span(text, &tokens[mention.start..mention.end]);
+            for object in objects {
+                if let Some(name) = &rule.property {
+                    let property = StateProperty {
+                        name: name.clone(),
+                        value: parsed.entities[object].concept.content.clone(),
+                        property_type: rule.property_type.clone(),
+                        confidence: rule.confidence,
+                        source: span.source(),
+                    };
+                    set_property(&mut parsed.entities[subject].properties, property);
+                }
+                parsed.relations.push(ParsedRelation {
+                    subject: parsed.entities[subject].concept.id,
+                    object: parsed.entities[object].concept.id,
+                    relationship_type: rule.relationship_type.clone(),
+                    property: rule.property.clone(),
+                    confidence: rule.confidence,
+                    span: span.clone(),
+                });
+            }
+        }
+
+        // Unparsed spans, merging adjacent unmatched words within a sentence
+        unparsed.sort_unstable();
+        unparsed.dedup();
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for &token in &unparsed {
+            match ranges.last_mut() {
+                Some((_, end)) if *end == token && tokens[token].sentence == tokens[token - 1].sentence => *end = token + 1,
+                _ => ranges.push((token, token + 1)),
+            }
+        }
+        parsed.unparsed_spans = ranges.into_iter().map(|(start, end)| span(text, &tokens[start..end])).collect();
+
+        let total: usize = tokens.iter().map(|token| token.end - token.start).sum();
+        let missed: usize = unparsed.iter().map(|&token| tokens[token].end - tokens[token].start).sum();
+        parsed.coverage = if total == 0 { 1.0 } else { 1.0 - missed as f64 / total as f64 };
+
+        parsed
+    }
+}
+
+/// Byte range of the source text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl TextSpan {
+    fn source(&self) -> String {
+        format!("{}:{}-{}", PARSER_SOURCE, self.start, self.end)
+    }
+}
+
+/// An entity extracted from text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedEntity {
+    pub concept: ConceptNode,
+    /// Name of the entity rule that matched, if any
+    pub rule: Option<String>,
+    /// Whether the concept already existed in the concept graph
+    pub resolved: bool,
+    pub mentions: Vec<TextSpan>,
+    pub properties: Vec<StateProperty>,
+}
+
+/// A relation between two extracted entities
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedRelation {
+    pub subject: Uuid,
+    pub object: Uuid,
+    pub relationship_type: RelationshipType,
+    /// Subject property the relation also set, if the rule names one
+    pub property: Option<String>,
+    pub confidence: f64,
+    pub span: TextSpan,
+}
+
+/// Everything a grammar extracted from a text, plus what it could not parse
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParsedText {
+    pub entities: Vec<ParsedEntity>,
+    pub relations: Vec<ParsedRelation>,
+    pub global_properties: Vec<StateProperty>,
+    /// Text no rule accounted for, in order of appearance
+    pub unparsed_spans: Vec<TextSpan>,
+    /// Fraction of the words' characters that were parsed (0.0 to 1.0)
+    pub coverage: f64,
+}
+
+impl ParsedText {
+    /// Find an extracted entity by concept content or rule name
+    pub fn entity(&self, name: &str) -> Option<&ParsedEntity> {
+        let name = phrase_key(name);
+        self.entities.iter().find(|entity| {
+            phrase_key(&entity.concept.content) == name || entity.rule.as_deref().is_some_and(|rule| phrase_key(rule) == name)
+        })
+    }
+
+    /// Mean confidence over entities, their properties and relations
+    pub fn confidence(&self) -> f64 {
+        if self.entities.is_empty() {
+            return 0.0;
+        }
+
+        let confidences: Vec<f64> = self
+            .entities
+            .iter()
+            .flat_map(|entity| {
+                std::iter::once(entity.concept.confidence_score)
+                    .chain(entity.properties.iter().map(|property| property.confidence))
+            })
+            .chain(self.relations.iter().map(|relation| relation.confidence))
+            .collect();
+        confidences.iter().sum::<f64>() / confidences.len() as f64
+    }
+
+    /// Build a simulation state for the parsed `text`
+    pub fn to_state(&self, text: &str) -> Result<SimulationState> {
+        let mut state = SimulationState::new();
+        state.set_source_text(text.to_string());
+        state.set_description(format!("State parsed from: {}", text.chars().take(50).collect::<String>()));
+
+        for entity in &self.entities {
+            state.add_entity(entity.concept.clone(), entity.properties.clone());
+        }
+        for relation in &self.relations {
+            let relationship_info = RelationshipInfo {
+                relationship_type: relation.relationship_type.clone(),
+                strength: relation.confidence,
+                properties: Vec::new(),
+                confidence: relation.confidence,
+            };
+            state.add_relationship(relation.subject, relation.object, relationship_info)?;
+        }
+        state.global_properties = self.global_properties.clone();
+        state.confidence = self.confidence();
+
+        Ok(state)
+    }
+}
+
+/// Text-to-state parser driven by a [`StateGrammar`]
+pub struct RuleBasedStateParser {
+    grammar: StateGrammar,
+    config: SimulationConfig,
+    /// Concept graph mentions are resolved against
+    concept_graph: Arc<RwLock<ConceptGraphManager>>,
+    last_parse: Option<ParsedText>,
+}
+
+impl RuleBasedStateParser {
+    /// Create a parser using `grammar`
+    pub fn new(concept_graph: Arc<RwLock<ConceptGraphManager>>, grammar: StateGrammar) -> Self {
+        Self::with_config(concept_graph, grammar, SimulationConfig::default())
+    }
+
+    /// Create a parser with custom configuration
+    pub fn with_config(
+        concept_graph: Arc<RwLock<ConceptGraphManager>>,
+        grammar: StateGrammar,
+        config: SimulationConfig,
+    ) -> Self {
+        Self {
+            grammar,
+            config,
+            concept_graph,
+            last_parse: None,
+        }
+    }
+
+    pub fn grammar(&self) -> &StateGrammar {
+        &self.grammar
+    }
+
+    pub fn set_grammar(&mut self, grammar: StateGrammar) {
+        self.grammar = grammar;
+    }
+
+    /// Result of the most recent `parse_text_to_state`, including unparsed spans
+    pub fn last_parse(&self) -> Option<&ParsedText> {
+        self.last_parse.as_ref()
+    }
+
+    /// Parse `text` without building a state
+    pub async fn parse(&self, text: &str) -> Result<ParsedText> {
+        let concept_graph = self.concept_graph.read().await;
+        self.grammar.parse_with_graph(text, &concept_graph, &self.config).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TextToStateParser for RuleBasedStateParser {
+    async fn parse_text_to_state(&mut self, text: &str) -> Result<SimulationState> {
+        let parsed = self.parse(text).await?;
+        let state = parsed.to_state(text)?;
+        self.last_parse = Some(parsed);
+        Ok(state)
+    }
+
+    fn config(&self) -> &SimulationConfig {
+        &self.config
+    }
+
+    fn set_config(&mut self, config: SimulationConfig) {
+        self.config = config;
+    }
+}
+
+/// A word of the source text
+#[derive(Debug, Clone)]
+struct Token {
+    /// Lowercased word
+    text: String,
+    start: usize,
+    end: usize,
+    sentence: usize,
+}
+
+/// Split `text` into lowercased words, numbering sentences as it goes
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut sentence = 0;
+    let mut word_start: Option<usize> = None;
+
+    let push = |tokens: &mut Vec<Token>, start: usize, end: usize, sentence: usize| {
+        let word = text[start..end].trim_end_matches(['\'', '-']);
+        if !word.is_empty() {
+            tokens.push(Token {
+                text: word.to_lowercase(),
+                start,
+                end: start + word.len(),
+                sentence,
+            });
+        }
+    };
+
+    for (index, ch) in text.char_indices() {
+        if ch.is_alphanumeric() || (word_start.is_some() && (ch == '\'' || ch == '-')) {
+            word_start.get_or_insert(index);
+            continue;
+        }
+        if let Some(start) = word_start.take() {
+            push(&mut tokens, start, index, sentence);
+        }
+        if matches!(ch, '.' | '!' | '?' | ';') {
+            sentence += 1;
+        }
+    }
+    if let Some(start) = word_start {
+        push(&mut tokens, start, text.len(), sentence);
+    }
+
+    tokens
+}
+
+/// Normalized form of a phrase: lowercased words joined by single spaces
+fn phrase_key(phrase: &str) -> String {
+    tokenize(phrase).into_iter().map(|token| token.text).collect::<Vec<_>>().join(" ")
+}
+
+fn span(text: &str, tokens: &[Token]) -> TextSpan {
+    let start = tokens.first().map_or(0, |token| token.start);
+    let end = tokens.last().map_or(start, |token| token.end);
+    TextSpan {
+        start,
+        end,
+        text: text[start..end].to_string(),
+    }
+}
+
+fn set_property(properties: &mut Vec<StateProperty>, property: StateProperty) {
+    properties.retain(|existing| existing.name != property.name);
+    properties.push(property);
+}
+
+/// What a matched phrase stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lexeme {
+    Entity(usize),
+    Concept(usize),
+    Property(usize),
+    Relation(usize),
+    Conjunction,
+    Ignored,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum EntityKey {
+    Rule(usize),
+    Concept(Uuid),
+}
+
+/// A matched phrase, as a range of token indices
+#[derive(Debug, Clone, Copy)]
+struct Mention {
+    lexeme: Lexeme,
+    start: usize,
+    end: usize,
+    sentence: usize,
+}
+
+/// Phrase table combining a grammar with the concepts mentions may resolve to
+struct Lexicon {
+    phrases: HashMap<String, Lexeme>,
+    longest_phrase: usize,
+    concepts: Vec<ConceptNode>,
+    concept_index: HashMap<String, usize>,
+}
+
+impl Lexicon {
+    fn new(grammar: &StateGrammar, concepts: &[ConceptNode]) -> Self {
+        let mut lexicon = Self {
+            phrases: HashMap::new(),
+            longest_phrase: 0,
+            concepts: Vec::new(),
+            concept_index: HashMap::new(),
+        };
+
+        // Earlier insertions win, so grammar rules take precedence over concept names
+        for (index, rule) in grammar.entities.iter().enumerate() {
+            for phrase in std::iter::once(&rule.name).chain(&rule.aliases) {
+                lexicon.insert(phrase, Lexeme::Entity(index));
+            }
+        }
+        for (index, rule) in grammar.relations.iter().enumerate() {
+            for phrase in &rule.triggers {
+                lexicon.insert(phrase, Lexeme::Relation(index));
+            }
+        }
+        for (index, rule) in grammar.properties.iter().enumerate() {
+            for phrase in &rule.triggers {
+                lexicon.insert(phrase, Lexeme::Property(index));
+            }
+        }
+        for phrase in &grammar.conjunctions {
+            lexicon.insert(phrase, Lexeme::Conjunction);
+        }
+        for phrase in &grammar.ignored_words {
+            lexicon.insert(phrase, Lexeme::Ignored);
+        }
+
+        // The most confident concept per name
+        for concept in concepts {
+            let key = phrase_key(&concept.content);
+            if key.is_empty() || key.split(' ').count() > MAX_CONCEPT_PHRASE_WORDS {
+                continue;
+            }
+            match lexicon.concept_index.get(&key) {
+                Some(&existing) if lexicon.concepts[existing].confidence_score >= concept.confidence_score => {}
+                Some(&existing) => lexicon.concepts[existing] = concept.clone(),
+                None => {
+                    lexicon.concept_index.insert(key, lexicon.concepts.len());
+                    lexicon.concepts.push(concept.clone());
+                }
+            }
+        }
+        let entity_concepts: Vec<(String, usize)> = lexicon
+            .concept_index
+            .iter()
+            .filter(|&(_, &index)| lexicon.concepts[index].concept_type == ConceptType::Entity)
+            .map(|(key, &index)| (key.clone(), index))
+            .collect();
+        for (key, index) in entity_concepts {
+            lexicon.insert(&key, Lexeme::Concept(index));
+        }
+
+        lexicon
+    }
+
+    fn insert(&mut self, phrase: &str, lexeme: Lexeme) {
+        let key = phrase_key(phrase);
+        if key.is_empty() {
+            return;
+        }
+        self.longest_phrase = self.longest_phrase.max(key.split(' ').count());
+        self.phrases.entry(key).or_insert(lexeme);
+    }
+
+    /// The concept an entity rule refers to, by name first and then by alias
+    fn resolve_rule(&self, rule: &EntityRule) -> Option<&ConceptNode> {
+        std::iter::once(&rule.name)
+            .chain(&rule.aliases)
+            .find_map(|phrase| self.concept_index.get(&phrase_key(phrase)))
+            .map(|&index| &self.concepts[index])
+    }
+
+    /// Match phrases greedily, longest first, without crossing sentence boundaries
+    ///
+    /// Returns the mentions and the indices of tokens no phrase matched.
+    fn scan(&self, tokens: &[Token]) -> (Vec<Mention>, Vec<usize>) {
+        let mut mentions = Vec::new();
+        let mut unmatched = Vec::new();
+        let mut position = 0;
+
+        while position < tokens.len() {
+            let sentence = tokens[position].sentence;
+            let available = tokens[position..]
+                .iter()
+                .take(self.longest_phrase)
+                .take_while(|token| token.sentence == sentence)
+                .count();
+            let matched = (1..=available).rev().find_map(|length| {
+                let key = tokens[position..position + length]
+                    .iter()
+                    .map(|token| token.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                self.phrases.get(&key).map(|&lexeme| (lexeme, length))
+            });
+
+            match matched {
+                Some((Lexeme::Ignored, length)) => position += length,
+                Some((lexeme, length)) => {
+                    mentions.push(Mention {
+                        lexeme,
+                        start: position,
+                        end: position + length,
+                        sentence,
+                    });
+                    position += length;
+                }
+                None => {
+                    unmatched.push(position);
+                    position += 1;
+                }
+            }
+        }
+
+        (mentions, unmatched)
+    }
+}
+
+/// Entity a property mention attaches to, following the rule's attachment
+fn attachment_target(
+    mentions: &[Mention],
+    mention_entities: &[Option<usize>],
+    index: usize,
+    rule: &PropertyRule,
+    entities: &[ParsedEntity],
+) -> Option<usize> {
+    let mention = &mentions[index];
+    let candidate = |other: usize| {
+        mention_entities[other]
+            .filter(|&entity| mentions[other].sentence == mention.sentence && rule.accepts(&entities[entity]))
+    };
+    let preceding = (0..index)
+        .rev()
+        .find_map(|other| candidate(other).map(|entity| (mention.start - mentions[other].end, entity)));
+    let following = (index + 1..mentions.len())
+        .find_map(|other| candidate(other).map(|entity| (mentions[other].start - mention.end, entity)));
+
+    match rule.attach {
+        Attachment::Preceding => preceding.map(|(_, entity)| entity),
+        Attachment::Following => following.map(|(_, entity)| entity),
+        Attachment::Nearest => match (preceding, following) {
+            (Some((before, _)), Some((after, entity))) if after < before => Some(entity),
+            (Some((_, entity)), _) => Some(entity),
+            (None, following) => following.map(|(_, entity)| entity),
+        },
+    }
+}
+
+/// Entities following a relation trigger: the first one, then one more per conjunction
+fn relation_objects(mentions: &[Mention], mention_entities: &[Option<usize>], index: usize) -> Vec<usize> {
+    let sentence = mentions[index].sentence;
+    let mut objects = Vec::new();
+    let mut expecting_object = true;
+
+    for (mention, entity) in mentions
+        .iter()
+        .zip(mention_entities)
+        .skip(index + 1)
+        .take_while(|(mention, _)| mention.sentence == sentence)
+    {
+        match (mention.lexeme, entity) {
+            (_, Some(entity)) if expecting_object => {
+                objects.push(*entity);
+                expecting_object = false;
+            }
+            (Lexeme::Conjunction, _) if !expecting_object => expecting_object = true,
+            (Lexeme::Property(_), _) => {}
+            _ => break,
+        }
+    }
+
+    objects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::concepts::ConceptGraphConfig;
+
+    fn property<'a>(entity: &'a ParsedEntity, name: &str) -> Option<&'a str> {
+        entity
+            .properties
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| property.value.as_str())
+    }
+
+    #[test]
+    fn test_default_grammar_parses_scene() {
+        let grammar = StateGrammar::default();
+        grammar.validate().unwrap();
+
+        let parsed = grammar.parse("A person stands in a room with a door and a window", &[], 50);
+
+        assert_eq!(parsed.entities.len(), 4);
+        assert!(parsed.unparsed_spans.is_empty());
+        assert_eq!(parsed.coverage, 1.0);
+
+        let person = parsed.entity("person").unwrap();
+        assert_eq!(property(person, "location"), Some("room"));
+        assert_eq!(property(person, "position"), Some("center"));
+        assert!(!person.resolved);
+        assert_eq!(property(parsed.entity("door").unwrap(), "state"), Some("closed"));
+
+        let room = parsed.entity("room").unwrap().concept.id;
+        let has: Vec<_> = parsed
+            .relations
+            .iter()
+            .filter(|relation| relation.relationship_type == RelationshipType::Has)
+            .collect();
+        assert_eq!(has.len(), 2);
+        assert!(has.iter().all(|relation| relation.subject == room));
+    }
+
+    #[test]
+    fn test_domain_grammar_resolves_concepts_and_reports_unparsed() {
+        let grammar = StateGrammar::from_json(
+            r#"{
+                "entities": [{ "name": "oven", "aliases": ["stove"], "defaults": [
+                    { "name": "power", "value": "off", "property_type": "State" }
+                ] }],
+                "properties": [{ "name": "temperature", "triggers": ["hot", "cold"], "property_type": "Physical" }],
+                "relations": [{ "triggers": ["next to"], "relationship_type": "LocatedAt", "property": "location", "property_type": "Location" }],
+                "conjunctions": ["and"],
+                "ignored_words": ["the", "is"]
+            }"#,
+        )
+        .unwrap();
+        let kettle = ConceptNode::new(ConceptType::Entity, "Kettle".to_string(), 0.9, None);
+        let oven = ConceptNode::new(ConceptType::Entity, "oven".to_string(), 0.8, None);
+        let text = "The stove is hot and the kettle sits next to the oven. It whistles loudly.";
+
+        let parsed = grammar.parse(text, &[kettle.clone(), oven.clone()], 50);
+
+        assert_eq!(parsed.entities.len(), 2);
+        let parsed_oven = parsed.entity("oven").unwrap();
+        assert!(parsed_oven.resolved);
+        assert_eq!(parsed_oven.concept.id, oven.id);
+        assert_eq!(parsed_oven.mentions.len(), 2);
+        assert_eq!(property(parsed_oven, "temperature"), Some("hot"));
+        assert_eq!(property(parsed_oven, "power"), Some("off"));
+
+        let parsed_kettle = parsed.entity("kettle").unwrap();
+        assert_eq!(parsed_kettle.concept.id, kettle.id);
+        assert_eq!(property(parsed_kettle, "location"), Some("oven"));
+
+        let unparsed: Vec<&str> = parsed.unparsed_spans.iter().map(|span| span.text.as_str()).collect();
+        assert_eq!(unparsed, vec!["sits", "It whistles loudly"]);
+        assert_eq!(&text[parsed.unparsed_spans[0].start..parsed.unparsed_spans[0].end], "sits");
+        assert!(parsed.coverage < 1.0);
+    }
+
+    #[test]
+    fn test_grammar_loading_and_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("grammar.json");
+        std::fs::write(&path, StateGrammar::default().to_json().unwrap()).unwrap();
+
+        let loaded = StateGrammar::load(&path).unwrap();
+        assert_eq!(loaded.entities.len(), StateGrammar::default().entities.len());
+
+        let invalid = r#"{ "properties": [{ "name": "mood", "triggers": [], "property_type": "Abstract" }] }"#;
+        assert!(matches!(StateGrammar::from_json(invalid), Err(BrainError::ConfigError(_))));
+        assert!(StateGrammar::load(dir.path().join("missing.json")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_parser_builds_state() {
+        let mut manager = ConceptGraphManager::new(ConceptGraphConfig::default()).await.unwrap();
+        let table = ConceptNode::new(ConceptType::Entity, "table".to_string(), 0.9, None);
+        manager.create_concept(table.clone()).await.unwrap();
+
+        let concept_graph = Arc::new(RwLock::new(manager));
+        let mut parser = RuleBasedStateParser::new(concept_graph, StateGrammar::default());
+        let text = "In the evening a woman sits at the table near the open window";
+        let state = parser.parse_text_to_state(text).await.unwrap();
+
+        assert_eq!(state.source_text.as_deref(), Some(text));
+        assert!(state.entities.contains_key(&table.id));
+        assert_eq!(state.entities.len(), 3);
+        assert_eq!(state.relationships.len(), 2);
+        assert_eq!(state.global_properties[0].value, "evening");
+        assert!(state.confidence > 0.0);
+
+        let parsed = parser.last_parse().unwrap();
+        let window = parsed.entity("window").unwrap();
+        assert_eq!(property(window, "state"), Some("open"));
+        // "In" has no preceding entity to relate
+        assert_eq!(parsed.unparsed_spans.len(), 1);
+        assert_eq!(parsed.unparsed_spans[0].text, "In");
+    }
+}
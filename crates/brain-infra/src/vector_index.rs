@@ -0,0 +1,453 @@
+//! Approximate Nearest-Neighbour Index
+//!
+//! An in-process HNSW (Hierarchical Navigable Small World) graph over cosine
+//! similarity. Vectors are normalised on insert so similarity is a dot
+//! product. Removal tombstones a node so the graph stays navigable, and the
+//! index rebuilds itself once tombstones make up a large share of it.
+
+use brain_types::{BrainError, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use uuid::Uuid;
+
+/// Tuning parameters for the HNSW graph
+#[derive(Debug, Clone)]
+pub struct HnswConfig {
+    /// Links kept per node on the upper layers (twice this on the base layer)
+    pub max_connections: usize,
+    /// Candidate list size while inserting; larger builds a better graph more slowly
+    pub ef_construction: usize,
+    /// Candidate list size while searching; larger improves recall at some latency
+    pub ef_search: usize,
+    /// Share of tombstoned nodes that triggers a rebuild
+    pub rebuild_tombstone_ratio: f64,
+    /// Seed for level assignment so builds are reproducible
+    pub seed: u64,
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 16,
+            ef_construction: 200,
+            ef_search: 64,
+            rebuild_tombstone_ratio: 0.25,
+            seed: 42,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HnswNode {
+    id: Uuid,
+    vector: Vec<f32>,
+    /// Neighbour lists, one per layer from 0 up to the node's level
+    links: Vec<Vec<usize>>,
+    deleted: bool,
+}
+
+/// Candidate ordered by similarity (max-heap pops the most similar first)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    similarity: f32,
+    node: usize,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity.total_cmp(&other.similarity).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed ordering so a max-heap pops the least similar first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Farthest(Candidate);
+
+impl Ord for Farthest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for Farthest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// HNSW index mapping ids to embeddings of a single dimensionality
+#[derive(Debug, Clone)]
+pub struct HnswIndex {
+    config: HnswConfig,
+    dimensions: usize,
+    nodes: Vec<HnswNode>,
+    /// Live node for each id
+    positions: HashMap<Uuid, usize>,
+    entry_point: Option<usize>,
+    level_multiplier: f64,
+    rng: StdRng,
+}
+
+impl HnswIndex {
+    /// Create an empty index for vectors of `dimensions` components
+    pub fn new(dimensions: usize, config: HnswConfig) -> Self {
+        let max_connections = config.max_connections.max(2);
+        Self {
+            level_multiplier: 1.0 / (max_connections as f64).ln(),
+            rng: StdRng::seed_from_u64(config.seed),
+            config: HnswConfig { max_connections, ..config },
+            dimensions,
+            nodes: Vec::new(),
+            positions: HashMap::new(),
+            entry_point: None,
+        }
+    }
+
+    /// Number of components every indexed vector has
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Number of live (not removed) vectors
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn contains(&self, id: Uuid) -> bool {
+        self.positions.contains_key(&id)
+    }
+
+    /// Insert a vector, replacing any vector already stored under `id`
+    ///
+    /// Fails without touching the index when the vector does not have
+    /// `dimensions` components.
+    pub fn insert(&mut self, id: Uuid, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimensions {
+            return Err(BrainError::InvalidInput(format!(
+                "Vector for {} has {} dimensions, index expects {}",
+                id,
+                vector.len(),
+                self.dimensions
+            )));
+        }
+        self.insert_unchecked(id, vector);
+        Ok(())
+    }
+
+    fn insert_unchecked(&mut self, id: Uuid, vector: &[f32]) {
+        self.remove(id);
+
+        let vector = normalize(vector);
+        let level = self.random_level();
+        let node = self.nodes.len();
+        self.nodes.push(HnswNode {
+            id,
+            vector,
+            links: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+        self.positions.insert(id, node);
+
+        let Some(entry) = self.entry_point else {
+            self.entry_point = Some(node);
+            return;
+        };
+
+        let query = self.nodes[node].vector.clone();
+        let top_level = self.nodes[entry].links.len() - 1;
+        let mut nearest = entry;
+
+        // Greedy descent through the layers above the new node
+        for layer in (level + 1..=top_level).rev() {
+            nearest = self.greedy_closest(&query, nearest, layer);
+        }
+
+        let mut entries = vec![nearest];
+        for layer in (0..=level.min(top_level)).rev() {
+            let candidates = self.search_layer(&query, &entries, self.config.ef_construction, layer);
+            let neighbors: Vec<usize> = candidates
+                .iter()
+                .take(self.layer_capacity(layer))
+                .map(|candidate| candidate.node)
+                .collect();
+
+            for &neighbor in &neighbors {
+                self.nodes[neighbor].links[layer].push(node);
+                self.shrink_links(neighbor, layer);
+            }
+            self.nodes[node].links[layer] = neighbors;
+            entries = candidates.iter().map(|candidate| candidate.node).collect();
+        }
+
+        if level > top_level {
+            self.entry_point = Some(node);
+        }
+    }
+
+    /// Remove the vector stored under `id`, returning whether one existed
+    pub fn remove(&mut self, id: Uuid) -> bool {
+        let Some(node) = self.positions.remove(&id) else {
+            return false;
+        };
+        self.nodes[node].deleted = true;
+
+        let tombstones = self.nodes.len() - self.positions.len();
+        if self.positions.is_empty() {
+            self.nodes.clear();
+            self.entry_point = None;
+        } else if tombstones as f64 > self.nodes.len() as f64 * self.config.rebuild_tombstone_ratio {
+            self.rebuild();
+        }
+        true
+    }
+
+    /// Up to `limit` live ids most similar to `query`, most similar first
+    pub fn search(&self, query: &[f32], limit: usize) -> Vec<(Uuid, f64)> {
+        if limit == 0 || query.len() != self.dimensions {
+            return Vec::new();
+        }
+        let Some(entry) = self.entry_point else {
+            return Vec::new();
+        };
+
+        let query = normalize(query);
+        let mut nearest = entry;
+        for layer in (1..self.nodes[entry].links.len()).rev() {
+            nearest = self.greedy_closest(&query, nearest, layer);
+        }
+
+        // Tombstones still route the search, so widen the beam to cover them
+        let tombstones = self.nodes.len() - self.positions.len();
+        let ef = self.config.ef_search.max(limit) + tombstones.min(limit);
+        self.search_layer(&query, &[nearest], ef, 0)
+            .into_iter()
+            .filter(|candidate| !self.nodes[candidate.node].deleted)
+            .take(limit)
+            .map(|candidate| (self.nodes[candidate.node].id, candidate.similarity as f64))
+            .collect()
+    }
+
+    /// Rebuild the graph from the live vectors, dropping tombstones
+    pub fn rebuild(&mut self) {
+        let live: Vec<(Uuid, Vec<f32>)> = self
+            .nodes
+            .iter()
+            .filter(|node| !node.deleted)
+            .map(|node| (node.id, node.vector.clone()))
+            .collect();
+
+        self.nodes.clear();
+        self.positions.clear();
+        self.entry_point = None;
+        for (id, vector) in live {
+            self.insert_unchecked(id, &vector);
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        let uniform: f64 = self.rng.gen_range(f64::EPSILON..1.0);
+        (-uniform.ln() * self.level_multiplier).floor() as usize
+    }
+
+    fn layer_capacity(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.config.max_connections * 2
+        } else {
+            self.config.max_connections
+        }
+    }
+
+    fn similarity(&self, query: &[f32], node: usize) -> f32 {
+        dot(query, &self.nodes[node].vector)
+    }
+
+    fn greedy_closest(&self, query: &[f32], start: usize, layer: usize) -> usize {
+        let mut current = start;
+        let mut best = self.similarity(query, current);
+        loop {
+            let mut improved = false;
+            for &neighbor in &self.nodes[current].links[layer] {
+                let similarity = self.similarity(query, neighbor);
+                if similarity > best {
+                    best = similarity;
+                    current = neighbor;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return current;
+            }
+        }
+    }
+
+    /// Beam search on one layer, returning up to `ef` candidates most similar first
+    fn search_layer(&self, query: &[f32], entries: &[usize], ef: usize, layer: usize) -> Vec<Candidate> {
+        let mut visited: HashSet<usize> = entries.iter().copied().collect();
+        let mut frontier: BinaryHeap<Candidate> = BinaryHeap::new();
+        let mut found: BinaryHeap<Farthest> = BinaryHeap::new();
+
+        for &entry in entries {
+            let candidate = Candidate { similarity: self.similarity(query, entry), node: entry };
+            frontier.push(candidate);
+            found.push(Farthest(candidate));
+            if found.len() > ef {
+                found.pop();
+            }
+        }
+
+        while let Some(current) = frontier.pop() {
+            let worst = found.peek().map(|farthest| farthest.0.similarity).unwrap_or(f32::NEG_INFINITY);
+            if found.len() >= ef && current.similarity < worst {
+                break;
+            }
+
+            for &neighbor in &self.nodes[current.node].links[layer] {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate { similarity: self.similarity(query, neighbor), node: neighbor };
+                let worst = found.peek().map(|farthest| farthest.0.similarity).unwrap_or(f32::NEG_INFINITY);
+                if found.len() < ef || candidate.similarity > worst {
+                    frontier.push(candidate);
+                    found.push(Farthest(candidate));
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        let mut results: Vec<Candidate> = found.into_iter().map(|farthest| farthest.0).collect();
+        results.sort_by(|a, b| b.cmp(a));
+        results
+    }
+
+    /// Keep only the closest links once a node exceeds its layer capacity
+    fn shrink_links(&mut self, node: usize, layer: usize) {
+        let capacity = self.layer_capacity(layer);
+        if self.nodes[node].links[layer].len() <= capacity {
+            return;
+        }
+
+        let vector = self.nodes[node].vector.clone();
+        let mut links: Vec<Candidate> = self.nodes[node].links[layer]
+            .iter()
+            .map(|&neighbor| Candidate { similarity: self.similarity(&vector, neighbor), node: neighbor })
+            .collect();
+        links.sort_by(|a, b| b.cmp(a));
+        links.truncate(capacity);
+        self.nodes[node].links[layer] = links.into_iter().map(|candidate| candidate.node).collect();
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Scale to unit length; zero vectors stay zero and match nothing
+fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        vector.to_vec()
+    } else {
+        vector.iter().map(|x| x / norm).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::cosine_similarity;
+
+    fn random_vectors(count: usize, dimensions: usize, seed: u64) -> Vec<(Uuid, Vec<f32>)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| (Uuid::new_v4(), (0..dimensions).map(|_| rng.gen_range(-1.0..1.0)).collect()))
+            .collect()
+    }
+
+    fn exact_top(vectors: &[(Uuid, Vec<f32>)], query: &[f32], limit: usize) -> Vec<Uuid> {
+        let mut scored: Vec<(Uuid, f64)> = vectors.iter().map(|(id, v)| (*id, cosine_similarity(query, v))).collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.into_iter().take(limit).map(|(id, _)| id).collect()
+    }
+
+    #[test]
+    fn test_search_recall_against_exact_scan() {
+        let vectors = random_vectors(1000, 32, 7);
+        let mut index = HnswIndex::new(32, HnswConfig::default());
+        for (id, vector) in &vectors {
+            index.insert(*id, vector).unwrap();
+        }
+        assert_eq!(index.len(), 1000);
+
+        let queries = random_vectors(50, 32, 11);
+        let mut hits = 0;
+        for (_, query) in &queries {
+            let expected: HashSet<Uuid> = exact_top(&vectors, query, 10).into_iter().collect();
+            let found = index.search(query, 10);
+            assert_eq!(found.len(), 10);
+            assert!(found.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+            hits += found.iter().filter(|(id, _)| expected.contains(id)).count();
+        }
+
+        let recall = hits as f64 / (queries.len() * 10) as f64;
+        assert!(recall > 0.9, "recall {}", recall);
+    }
+
+    #[test]
+    fn test_remove_and_reinsert_keep_index_in_sync() {
+        let vectors = random_vectors(300, 8, 3);
+        let mut index = HnswIndex::new(8, HnswConfig::default());
+        for (id, vector) in &vectors {
+            index.insert(*id, vector).unwrap();
+        }
+
+        // Removing most vectors forces at least one rebuild along the way
+        for (id, _) in vectors.iter().take(200) {
+            assert!(index.remove(*id));
+        }
+        assert!(!index.remove(vectors[0].0));
+        assert_eq!(index.len(), 100);
+
+        for (id, vector) in vectors.iter().skip(200) {
+            let found = index.search(vector, 1);
+            assert_eq!(found[0].0, *id);
+            assert!((found[0].1 - 1.0).abs() < 1e-5);
+        }
+        let removed = &vectors[5];
+        assert!(index.search(&removed.1, 100).iter().all(|(id, _)| *id != removed.0));
+
+        // Re-inserting under an existing id replaces the old vector
+        let moved = vectors[250].0;
+        index.insert(moved, &vectors[5].1).unwrap();
+        assert_eq!(index.len(), 100);
+        assert_eq!(index.search(&vectors[5].1, 1)[0].0, moved);
+    }
+
+    #[test]
+    fn test_insert_rejects_wrong_dimensions() {
+        let mut index = HnswIndex::new(4, HnswConfig::default());
+        let id = Uuid::new_v4();
+        index.insert(id, &[1.0, 0.0, 0.0, 0.0]).unwrap();
+
+        assert!(matches!(index.insert(id, &[1.0, 0.0]), Err(BrainError::InvalidInput(_))));
+        assert!(matches!(index.insert(Uuid::new_v4(), &[0.5; 5]), Err(BrainError::InvalidInput(_))));
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.search(&[1.0, 0.0, 0.0, 0.0], 1)[0].0, id);
+    }
+}
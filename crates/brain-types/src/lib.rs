@@ -6,6 +6,7 @@
 pub mod error;
 pub mod common;
 pub mod config;
+pub mod math;
 
 // Re-export everything for easy access
 pub use error::*;
@@ -20,7 +20,7 @@ async fn main() -> Result<()> {
     println!("----------------------------------");
 
     let auth_config = AuthConfig::default();
-    let mut auth_manager = AuthManager::new(auth_config)?;
+    let auth_manager = AuthManager::new(auth_config)?;
 
     // Create users with different roles
     let admin_user = User {
@@ -33,7 +33,7 @@ async fn main() -> Result<()> {
         active: true,
         metadata: HashMap::new(),
     };
-    auth_manager.add_user(admin_user.clone())?;
+    auth_manager.add_user(admin_user.clone()).await?;
     println!("✅ Created admin user: {}", admin_user.id);
 
     let developer_user = User {
@@ -46,25 +46,25 @@ async fn main() -> Result<()> {
         active: true,
         metadata: HashMap::new(),
     };
-    auth_manager.add_user(developer_user.clone())?;
+    auth_manager.add_user(developer_user.clone()).await?;
     println!("✅ Created developer user: {}", developer_user.id);
 
     // Generate API keys
-    let admin_api_key = auth_manager.generate_api_key(&admin_user.id, UserRole::Admin, "Demo admin key")?;
-    let _dev_api_key = auth_manager.generate_api_key(&developer_user.id, UserRole::Developer, "Demo dev key")?;
+    let admin_api_key = auth_manager.generate_api_key(&admin_user.id, UserRole::Admin, "Demo admin key").await?;
+    let _dev_api_key = auth_manager.generate_api_key(&developer_user.id, UserRole::Developer, "Demo dev key").await?;
     println!("🔑 Generated API keys for admin and developer");
 
     // Generate JWT tokens
-    let _admin_token = auth_manager.generate_token(&admin_user.id, UserRole::Admin)?;
-    let dev_token = auth_manager.generate_token(&developer_user.id, UserRole::Developer)?;
+    let _admin_token = auth_manager.generate_token(&admin_user.id, UserRole::Admin).await?;
+    let dev_token = auth_manager.generate_token(&developer_user.id, UserRole::Developer).await?;
     println!("🎫 Generated JWT tokens for admin and developer");
 
     // Test authentication methods
     println!("\n🔍 Testing Authentication Methods:");
     
     // Test API key authentication
-    let (api_user_id, api_role) = auth_manager.validate_api_key(&admin_api_key)?;
-    println!("  ✅ API Key Auth: User {} (Role: {:?})", api_user_id, api_role);
+    let api_auth = auth_manager.validate_api_key(&admin_api_key).await?;
+    println!("  ✅ API Key Auth: User {} (Role: {:?})", api_auth.user_id, api_auth.role);
 
     // Test JWT authentication
     let jwt_claims = auth_manager.validate_token(&dev_token)?;
@@ -146,7 +146,7 @@ async fn main() -> Result<()> {
     );
 
     // Complete the request
-    let auth_result = AuthenticationResult::new(api_user_id.clone(), api_role);
+    let auth_result = AuthenticationResult::new(api_auth.user_id.clone(), api_auth.role.clone());
     let mut metadata = HashMap::new();
     metadata.insert("query_type".to_string(), "concept_search".to_string());
     metadata.insert("result_count".to_string(), "25".to_string());
@@ -169,7 +169,7 @@ async fn main() -> Result<()> {
         Some("Missing WHERE clause".to_string()),
         error_context,
         Some("req_001".to_string()),
-        Some(api_user_id.clone()),
+        Some(api_auth.user_id.clone()),
     );
 
     logging_manager.log_error(
@@ -179,7 +179,7 @@ async fn main() -> Result<()> {
         Some("Token issued too long ago".to_string()),
         HashMap::new(),
         None,
-        Some(api_user_id),
+        Some(api_auth.user_id.clone()),
     );
 
     // Log an audit event
@@ -220,7 +220,7 @@ async fn main() -> Result<()> {
     println!("----------------------------");
 
     // Get authentication statistics
-    let auth_stats = auth_manager.get_stats();
+    let auth_stats = auth_manager.get_stats().await?;
     println!("👥 Authentication Statistics:");
     println!("  Total Users: {}", auth_stats.total_users);
     println!("  Active Users: {}", auth_stats.active_users);
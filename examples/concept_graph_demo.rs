@@ -1,6 +1,6 @@
 use anyhow::Result;
 use brain::concept_graph::{
-    ConceptGraphManager, ConceptGraphConfig, ConceptNode, ConceptType, ConceptQuery,
+    ConceptGraphManager, ConceptGraphConfig, ConceptGraphBackend, ConceptNode, ConceptType, ConceptQuery,
     RelationshipType, RelationshipQuery, HebbianConfig, ConceptRepository, 
     RelationshipRepository, ConceptRelationship
 };
@@ -21,6 +21,7 @@ async fn main() -> Result<()> {
         database: Some("brain_demo".to_string()),
         pool_size: 5,
         timeout_seconds: 30,
+        backend: ConceptGraphBackend::InMemory,
     };
 
     println!("📡 Attempting to connect to Neo4j database...");
@@ -287,6 +288,9 @@ async fn run_relationship_demo(manager: &mut ConceptGraphManager, concept_ids: &
     println!("    • Isolated Concepts: {}", metrics.isolated_concepts);
     println!("    • Average Degree: {:.2}", metrics.average_degree);
     println!("    • Clustering Coefficient: {:.3}", metrics.clustering_coefficient);
+    println!("    • Density: {:.3}", metrics.density);
+    println!("    • Connected Components: {}", metrics.connected_components);
+    println!("    • Average Path Length: {:.2}", metrics.average_path_length);
     
     println!("  📋 Relationships by Type:");
     for (rel_type, count) in &metrics.relationships_by_type {
@@ -88,6 +88,7 @@ impl NoveltyDetector for SimpleNoveltyDetector {
                 format!("Pattern complexity: {:.2}", words.len() as f64 / 10.0),
             ],
             assessment_confidence: 0.8,
+            source_breakdown: Vec::new(),
         })
     }
     
@@ -281,9 +282,9 @@ async fn main() -> Result<()> {
     println!("✅ Novelty detector initialized with basic patterns");
     
     // Create meta-memory service
-    let meta_memory_repo = Arc::new(SimpleMetaMemoryRepository::new());
+    let meta_memory_repo = Arc::new(RwLock::new(SimpleMetaMemoryRepository::new()));
     let meta_memory_analytics = Arc::new(SimpleMetaMemoryAnalytics);
-    let meta_memory_maintenance = Arc::new(SimpleMetaMemoryMaintenance);
+    let meta_memory_maintenance = Arc::new(RwLock::new(SimpleMetaMemoryMaintenance));
     let meta_memory_config = MetaMemoryConfig::default();
     
     let meta_memory = Arc::new(MetaMemoryService::new(
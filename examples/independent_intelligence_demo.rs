@@ -378,6 +378,7 @@ async fn main() -> Result<()> {
             conversation_id: Some(format!("demo_conv_{}", i + 1)),
             context_limit: Some(10),
             retrieval_threshold: Some(0.3),
+            user_id: None,
         };
         
         // Simulate retrieved knowledge
@@ -87,6 +87,7 @@ async fn main() -> Result<()> {
         ff_hidden_dim: 256,
         max_seq_len: 32,
         dropout_rate: 0.1,
+        causal: false,
     };
     
     let mut transformer = TransformerPredictor::new(vocab_size, Some(transformer_config.clone()))?;
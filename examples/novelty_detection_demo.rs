@@ -619,9 +619,9 @@ async fn main() -> Result<()> {
     println!("{}", "-".repeat(50));
     
     // Initialize meta-memory system
-    let meta_memory_repo = Arc::new(SimpleMetaMemoryRepository::new());
+    let meta_memory_repo = Arc::new(RwLock::new(SimpleMetaMemoryRepository::new()));
     let meta_memory_analytics = Arc::new(SimpleMetaMemoryAnalytics);
-    let meta_memory_maintenance = Arc::new(SimpleMetaMemoryMaintenance);
+    let meta_memory_maintenance = Arc::new(RwLock::new(SimpleMetaMemoryMaintenance));
     let meta_memory_config = MetaMemoryConfig::default();
     
     let meta_memory = Arc::new(MetaMemoryService::new(
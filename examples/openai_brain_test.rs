@@ -69,6 +69,7 @@ async fn main() -> Result<()> {
             conversation_id: Some("openai_test_session".to_string()),
             context_limit: Some(5),
             retrieval_threshold: Some(0.3),
+            user_id: None,
         };
         
         match rag_orchestrator.process_conversation(
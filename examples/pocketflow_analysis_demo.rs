@@ -78,6 +78,7 @@ async fn main() -> Result<()> {
             conversation_id: Some("analysis_session".to_string()),
             context_limit: Some(7),
             retrieval_threshold: Some(0.25),
+            user_id: None,
         };
         
         match rag_orchestrator.process_conversation(
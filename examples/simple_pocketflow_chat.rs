@@ -78,6 +78,7 @@ async fn main() -> Result<()> {
             conversation_id: Some("demo_session".to_string()),
             context_limit: Some(5),
             retrieval_threshold: Some(0.3),
+            user_id: None,
         };
         
         // Process with Brain AI
@@ -77,6 +77,7 @@ async fn main() -> Result<()> {
             conversation_id: Some("training_session".to_string()),
             context_limit: Some(6),
             retrieval_threshold: Some(0.3),
+            user_id: None,
         };
         
         match rag_orchestrator.process_conversation(
@@ -140,6 +141,7 @@ async fn main() -> Result<()> {
         conversation_id: Some("post_training_session".to_string()),
         context_limit: Some(8),
         retrieval_threshold: Some(0.25),
+        user_id: None,
     };
     
     match rag_orchestrator.process_conversation(
@@ -85,6 +85,7 @@ async fn main() -> Result<()> {
             conversation_id: Some("enhanced_test_session".to_string()),
             context_limit: Some(8),
             retrieval_threshold: Some(0.2),
+            user_id: None,
         };
         
         match rag_orchestrator.process_conversation(
@@ -34,6 +34,7 @@ async fn main() -> Result<()> {
         batch_size: 50,
         auto_export: true,
         export_format: ExportFormat::JsonL,
+        split_ratios: Default::default(),
     };
     
     println!("\n📊 Step 1: Initialize Training Data Collector");
@@ -61,6 +62,7 @@ async fn main() -> Result<()> {
             conversation_id: Some(format!("demo_conv_{}", i + 1)),
             context_limit: Some(10),
             retrieval_threshold: Some(0.5),
+            user_id: None,
         };
         
         // Note: For demonstration purposes, we simulate the conversation processing
@@ -352,7 +352,10 @@ pub mod memory {
 
 /// Legacy compatibility for concept graph
 pub mod concept_graph {
-    pub use brain_infra::{ConceptGraphManager, ConceptGraphConfig, HebbianConfig, GraphStatistics};
+    pub use brain_infra::{
+        ConceptGraphManager, ConceptGraphConfig, ConceptGraphBackend, ConceptGraphStore,
+        Neo4jConceptRepository, HebbianConfig, GraphStatistics
+    };
     pub use brain_core::{
         ConceptNode, ConceptType, ConceptRepository, RelationshipRepository,
         ConceptQuery, RelationshipQuery, RelationshipType, ConceptRelationship
@@ -373,7 +376,8 @@ pub mod simulation_engine {
         SimulationEngine, SimulationState, StateProperty, PropertyType,
         Action, ActionPriority, Effect, EffectType, Condition, ConditionType, ComparisonOperator,
         BranchingConfig, SimulationConfidenceConfig as ConfidenceConfig, SimulationConstraint, ConstraintType, BranchingResult,
-        SimulationBranch, PruningStatistics
+        SimulationBranch, PruningStatistics,
+        StateGrammar, RuleBasedStateParser, ParsedText, TextSpan
     };
 }
 
@@ -383,7 +387,7 @@ pub mod neural_architecture {
     pub use brain_core::{
         AttentionConfig, TransformerConfig, GrowthConfig, DevelopmentalStage, LearningEvent, LearningType,
         CapacityTracker, DevelopmentalState, LayerConfig, ActivationType, NeuralArchitecture,
-        SelfAttentionService, TransformerEncoderService, TransformerPredictorService, 
+        TransformerTrainingConfig, TransformerCheckpoint, CheckpointTensor, SelfAttentionService, TransformerEncoderService, TransformerPredictorService, 
         DevelopmentalPredictorService, FeedForwardService, LayerNormService, NeuralRepository
     };
     
@@ -395,7 +399,8 @@ pub mod neural_architecture {
         TransformerEncoderImpl as TransformerEncoder,
         FeedForwardNetworkImpl as FeedForwardNetwork,
         LayerNormImpl as LayerNorm,
-        InMemoryNeuralRepository
+        AdamOptimizer,
+        InMemoryNeuralRepository, FileNeuralRepository
     };
 }
 
@@ -455,6 +460,7 @@ pub use brain_cognitive::{
 // Re-export API components including authentication and logging
 pub use brain_api::{
     AuthManager, AuthConfig, User, UserRole, Permission, 
+    ApiKey, ApiKeyRequest, AuthStore, InMemoryAuthStore, SqliteAuthStore,
     RateLimitManager, RateLimitConfig, RequestContext, create_request_context,
     LoggingManager, LoggingConfig, ErrorCategory, ErrorSeverity
 };